@@ -0,0 +1,7 @@
+#![no_main]
+use beatsaver_rs::playlist::Playlist;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Playlist::read(data);
+});
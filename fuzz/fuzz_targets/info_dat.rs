@@ -0,0 +1,18 @@
+#![no_main]
+use beatsaver_rs::hash::hash_map_zip;
+use libfuzzer_sys::fuzz_target;
+use std::io::{Cursor, Write};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+fuzz_target!(|data: &[u8]| {
+    let options = FileOptions::default().compression_method(CompressionMethod::Stored);
+    let mut zip = ZipWriter::new(Cursor::new(vec![]));
+    zip.start_file("Info.dat", options).unwrap();
+    zip.write_all(data).unwrap();
+    zip.start_file("Expert.dat", options).unwrap();
+    zip.write_all(b"{}").unwrap();
+    let archive = zip.finish().unwrap().into_inner();
+
+    let _ = hash_map_zip(archive.into());
+});
@@ -0,0 +1,8 @@
+#![no_main]
+use beatsaver_rs::MapId;
+use libfuzzer_sys::fuzz_target;
+use std::convert::TryFrom;
+
+fuzz_target!(|data: &str| {
+    let _ = MapId::try_from(data);
+});
@@ -0,0 +1,7 @@
+#![no_main]
+use beatsaver_rs::ws::decode_event;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = decode_event(data);
+});
@@ -0,0 +1,260 @@
+//! # Archive
+//!
+//! This module contains a minimal reader for the zip archives maps are distributed as.
+//!
+//! Archives can be extracted to disk using [extract][crate::install::extract].
+//!
+//! Only the features used by map archives are supported: stored and deflated entries, without
+//! encryption or ZIP64 extensions.
+//!
+//! Example:
+//! ```no_run
+//! use beatsaver_rs::archive::Archive;
+//!
+//! let data = std::fs::read("2144.zip").unwrap();
+//! let archive = Archive::new(data.into()).unwrap();
+//! for name in archive.file_names() {
+//!     println!("{}", name);
+//! }
+//! let info = archive.read("Info.dat").unwrap();
+//! ```
+use bytes::Bytes;
+use flate2::read::DeflateDecoder;
+use flate2::Crc;
+use std::io::Read;
+use thiserror::Error;
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+/// Size of the end of central directory record, without the trailing comment
+const END_OF_CENTRAL_DIRECTORY_LEN: usize = 22;
+
+/// Error type for reading an archive
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ArchiveError {
+    /// Data is not a valid zip archive
+    #[error("Invalid archive: {0}")]
+    Invalid(&'static str),
+    /// Entry uses a feature that isn't supported (e.g. encryption or an unknown compression method)
+    #[error("Unsupported archive: {0}")]
+    Unsupported(&'static str),
+    /// Archive doesn't contain the requested file
+    #[error("Archive does not contain {0}")]
+    NotFound(String),
+    /// Contents of an entry don't match its checksum
+    #[error("Checksum of {0} does not match")]
+    ChecksumMismatch(String),
+    /// Decompressed size of an entry doesn't match the size recorded in the archive
+    #[error("Size of {0} does not match")]
+    SizeMismatch(String),
+    /// Entry would be extracted outside of the destination folder
+    #[error("Entry {0} escapes the destination folder")]
+    UnsafePath(String),
+    /// Error in IO while decompressing an entry
+    #[error("Couldn't decompress entry: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+fn u16_at(data: &[u8], offset: usize) -> Result<u16, ArchiveError> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or(ArchiveError::Invalid("unexpected end of data"))
+}
+fn u32_at(data: &[u8], offset: usize) -> Result<u32, ArchiveError> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(ArchiveError::Invalid("unexpected end of data"))
+}
+
+/// File stored in an [Archive][crate::archive::Archive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveEntry {
+    /// Path of the file inside the archive
+    pub name: String,
+    /// Size of the file once decompressed
+    pub size: u64,
+    method: u16,
+    crc: u32,
+    compressed_size: usize,
+    header_offset: usize,
+}
+impl ArchiveEntry {
+    /// Returns `true` if the entry is a directory
+    pub fn is_dir(&self) -> bool {
+        self.name.ends_with('/')
+    }
+}
+
+/// Zip archive held in memory
+#[derive(Debug, Clone)]
+pub struct Archive {
+    data: Bytes,
+    entries: Vec<ArchiveEntry>,
+}
+impl Archive {
+    /// Parses the central directory of a zip archive
+    pub fn new(data: Bytes) -> Result<Self, ArchiveError> {
+        // The end of central directory record is followed by a comment of up to 64KiB
+        let search_start = data
+            .len()
+            .saturating_sub(END_OF_CENTRAL_DIRECTORY_LEN + u16::MAX as usize);
+        let eocd = (search_start..=data.len().saturating_sub(END_OF_CENTRAL_DIRECTORY_LEN))
+            .rev()
+            .find(|&i| u32_at(&data, i).ok() == Some(END_OF_CENTRAL_DIRECTORY_SIGNATURE))
+            .ok_or(ArchiveError::Invalid("missing end of central directory"))?;
+        let count = u16_at(&data, eocd + 10)? as usize;
+        let mut offset = u32_at(&data, eocd + 16)? as usize;
+        if count == u16::MAX as usize || offset == u32::MAX as usize {
+            return Err(ArchiveError::Unsupported("ZIP64 archives"));
+        }
+
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            if u32_at(&data, offset)? != CENTRAL_HEADER_SIGNATURE {
+                return Err(ArchiveError::Invalid("bad central directory header"));
+            }
+            let name_len = u16_at(&data, offset + 28)? as usize;
+            let extra_len = u16_at(&data, offset + 30)? as usize;
+            let comment_len = u16_at(&data, offset + 32)? as usize;
+            let name = data
+                .get(offset + 46..offset + 46 + name_len)
+                .ok_or(ArchiveError::Invalid("unexpected end of data"))?;
+            entries.push(ArchiveEntry {
+                name: String::from_utf8_lossy(name).replace('\\', "/"),
+                size: u32_at(&data, offset + 24)? as u64,
+                method: u16_at(&data, offset + 10)?,
+                crc: u32_at(&data, offset + 16)?,
+                compressed_size: u32_at(&data, offset + 20)? as usize,
+                header_offset: u32_at(&data, offset + 42)? as usize,
+            });
+            if u16_at(&data, offset + 8)? & 1 != 0 {
+                return Err(ArchiveError::Unsupported("encrypted entries"));
+            }
+            offset += 46 + name_len + extra_len + comment_len;
+        }
+        Ok(Self { data, entries })
+    }
+    /// Returns the entries contained in the archive
+    pub fn entries(&self) -> &[ArchiveEntry] {
+        &self.entries
+    }
+    /// Returns the paths of the files contained in the archive, excluding directories
+    pub fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(|e| !e.is_dir())
+            .map(|e| e.name.as_str())
+    }
+    /// Finds an entry by path, falling back to a case-insensitive match
+    ///
+    /// Map archives aren't consistent about casing (e.g. `Info.dat` and `info.dat`).
+    pub fn entry(&self, name: &str) -> Option<&ArchiveEntry> {
+        self.entries.iter().find(|e| e.name == name).or_else(|| {
+            self.entries
+                .iter()
+                .find(|e| e.name.eq_ignore_ascii_case(name))
+        })
+    }
+    /// Decompresses the contents of a file
+    pub fn read(&self, name: &str) -> Result<Vec<u8>, ArchiveError> {
+        let entry = self
+            .entry(name)
+            .ok_or_else(|| ArchiveError::NotFound(name.to_string()))?;
+        self.read_entry(entry)
+    }
+    /// Decompresses the contents of an entry, verifying its checksum
+    pub fn read_entry(&self, entry: &ArchiveEntry) -> Result<Vec<u8>, ArchiveError> {
+        let offset = entry.header_offset;
+        if u32_at(&self.data, offset)? != LOCAL_HEADER_SIGNATURE {
+            return Err(ArchiveError::Invalid("bad local file header"));
+        }
+        let start = offset
+            + 30
+            + u16_at(&self.data, offset + 26)? as usize
+            + u16_at(&self.data, offset + 28)? as usize;
+        let compressed = self
+            .data
+            .get(start..start + entry.compressed_size)
+            .ok_or(ArchiveError::Invalid("unexpected end of data"))?;
+
+        let mut contents = vec![];
+        match entry.method {
+            0 => contents.extend_from_slice(compressed),
+            8 => {
+                // Entries are decompressed from untrusted downloads, so stop one byte past the
+                // recorded size instead of inflating whatever the data expands to
+                DeflateDecoder::new(compressed)
+                    .take(entry.size + 1)
+                    .read_to_end(&mut contents)?;
+            }
+            _ => return Err(ArchiveError::Unsupported("compression method")),
+        }
+        if contents.len() as u64 != entry.size {
+            return Err(ArchiveError::SizeMismatch(entry.name.clone()));
+        }
+
+        let mut crc = Crc::new();
+        crc.update(&contents);
+        if crc.sum() != entry.crc {
+            return Err(ArchiveError::ChecksumMismatch(entry.name.clone()));
+        }
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::archive::{Archive, ArchiveError};
+    use crate::tests::zip_archive;
+
+    #[test]
+    fn test_archive() {
+        let data = zip_archive(&[
+            ("Info.dat", b"{}".as_ref()),
+            ("songs/", b"".as_ref()),
+            ("ExpertPlus.dat", &[b'x'; 1024]),
+        ]);
+        let archive = Archive::new(data).unwrap();
+        assert_eq!(
+            archive.file_names().collect::<Vec<_>>(),
+            vec!["Info.dat", "ExpertPlus.dat"]
+        );
+        assert_eq!(archive.read("info.dat").unwrap(), b"{}");
+        assert_eq!(archive.read("ExpertPlus.dat").unwrap(), vec![b'x'; 1024]);
+        assert!(matches!(
+            archive.read("Hard.dat"),
+            Err(ArchiveError::NotFound(_))
+        ));
+    }
+    #[test]
+    fn test_archive_invalid() {
+        assert!(matches!(
+            Archive::new(b"not a zip".as_ref().into()),
+            Err(ArchiveError::Invalid(_))
+        ));
+
+        let mut data = zip_archive(&[("Info.dat", b"{}".as_ref())]).to_vec();
+        // Corrupt the stored contents of the only entry
+        data[30 + "Info.dat".len()] = b'[';
+        let archive = Archive::new(data.into()).unwrap();
+        assert!(matches!(
+            archive.read("Info.dat"),
+            Err(ArchiveError::ChecksumMismatch(_))
+        ));
+    }
+    #[test]
+    fn test_archive_size_mismatch() {
+        let mut data = zip_archive(&[("Expert.dat", &[b'x'; 4096])]).to_vec();
+        // Record a smaller size than the entry inflates to in the central directory
+        let central = data.windows(4).position(|w| w == b"PK\x01\x02").unwrap();
+        data[central + 24..central + 28].copy_from_slice(&16u32.to_le_bytes());
+        let archive = Archive::new(data.into()).unwrap();
+        assert_eq!(archive.entries()[0].size, 16);
+        assert!(matches!(
+            archive.read("Expert.dat"),
+            Err(ArchiveError::SizeMismatch(_))
+        ));
+    }
+}
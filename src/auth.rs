@@ -0,0 +1,266 @@
+//! # Auth
+//!
+//! This module implements BeatSaver's OAuth2 authorization code flow.
+//!
+//! [OAuthConfig][crate::auth::OAuthConfig] builds the URL users are sent to in order to grant
+//! access, and the resulting code is exchanged for an [OAuthToken][crate::auth::OAuthToken]
+//! through any client using `oauth_exchange`. Tokens can later be renewed with `oauth_refresh`.
+//!
+//! Clients authenticate every request once [Credentials][crate::auth::Credentials] are attached
+//! with `with_credentials`.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+//! # mod main {
+//! use beatsaver_rs::auth::OAuthConfig;
+//! use beatsaver_rs::client::BeatSaver;
+//! use beatsaver_rs::BeatSaverApiAsync;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = OAuthConfig::new("client-id", "client-secret", "http://localhost/callback")
+//!         .with_scope("identity");
+//!     println!("Visit {}", config.authorize_url("random-state"));
+//!
+//!     // ... receive the code on the redirect uri ...
+//!     let client = BeatSaver::new();
+//!     let token = client.oauth_exchange(&config, "code").await.unwrap();
+//!     println!("Token expires at {}", token.expires_at);
+//! }
+//! # }
+//! ```
+use crate::{BEATSAVER_URL, USER_AGENT};
+use bytes::Bytes;
+use chrono::{DateTime, Duration, Utc};
+use http::header::{self, HeaderValue};
+use http::Request;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use url::Url;
+use urlencoding::encode;
+
+lazy_static! {
+    /// URL users are sent to in order to authorize an application
+    pub static ref AUTHORIZE_URL: Url = Url::parse("https://beatsaver.com/oauth2/authorize").unwrap();
+    /// URL used to exchange codes and refresh tokens
+    pub static ref TOKEN_URL: Url = BEATSAVER_URL.join("oauth2/token").unwrap();
+}
+
+/// OAuth2 application credentials
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuthConfig {
+    /// Client ID assigned to the application
+    pub client_id: String,
+    /// Client secret assigned to the application
+    pub client_secret: String,
+    /// URI the user is redirected to after authorizing the application
+    pub redirect_uri: String,
+    /// Scopes requested when authorizing
+    pub scopes: Vec<String>,
+}
+impl OAuthConfig {
+    /// Creates a new [OAuthConfig][crate::auth::OAuthConfig] without any scopes
+    pub fn new<I: Into<String>, S: Into<String>, R: Into<String>>(
+        client_id: I,
+        client_secret: S,
+        redirect_uri: R,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            redirect_uri: redirect_uri.into(),
+            scopes: Vec::new(),
+        }
+    }
+    /// Adds a scope to request when authorizing (e.g. `identity`)
+    pub fn with_scope<S: Into<String>>(mut self, scope: S) -> Self {
+        self.scopes.push(scope.into());
+        self
+    }
+    /// Builds the URL the user should be sent to in order to authorize the application
+    ///
+    /// `state` is passed back to the redirect URI unchanged, and should be checked to prevent CSRF.
+    pub fn authorize_url(&self, state: &str) -> Url {
+        let mut url = AUTHORIZE_URL.clone();
+        url.query_pairs_mut()
+            .append_pair("client_id", self.client_id.as_str())
+            .append_pair("response_type", "code")
+            .append_pair("redirect_uri", self.redirect_uri.as_str())
+            .append_pair("scope", self.scopes.join(" ").as_str())
+            .append_pair("state", state);
+        url
+    }
+    /// Builds the request exchanging an authorization code for a token
+    pub(crate) fn exchange_request(&self, code: &str) -> Request<Bytes> {
+        self.token_request(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", self.redirect_uri.as_str()),
+        ])
+    }
+    /// Builds the request exchanging a refresh token for a new token
+    pub(crate) fn refresh_request(&self, refresh_token: &str) -> Request<Bytes> {
+        self.token_request(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+    }
+    fn token_request(&self, params: &[(&str, &str)]) -> Request<Bytes> {
+        let body = params
+            .iter()
+            .chain(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+            ])
+            .map(|(k, v)| format!("{}={}", k, encode(v)))
+            .collect::<Vec<String>>()
+            .join("&");
+        Request::post(TOKEN_URL.as_str())
+            .header(header::USER_AGENT, USER_AGENT)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(body.into())
+            .unwrap()
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    expires_in: i64,
+    #[serde(default)]
+    scope: Option<String>,
+}
+
+/// OAuth2 token granted to an application
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuthToken {
+    /// Token sent with authenticated requests
+    pub access_token: String,
+    /// Token used to renew the access token once it expires
+    pub refresh_token: Option<String>,
+    /// Time at which the access token expires
+    pub expires_at: DateTime<Utc>,
+    /// Scopes granted to the application
+    pub scopes: Vec<String>,
+}
+impl OAuthToken {
+    /// Returns `true` if the access token has expired
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
+impl From<TokenResponse> for OAuthToken {
+    fn from(r: TokenResponse) -> Self {
+        Self {
+            access_token: r.access_token,
+            refresh_token: r.refresh_token,
+            expires_at: Utc::now() + Duration::seconds(r.expires_in),
+            scopes: r
+                .scope
+                .map(|s| s.split_whitespace().map(String::from).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Credentials attached to every request made by a client
+#[derive(Debug, Clone, PartialEq)]
+pub enum Credentials {
+    /// OAuth2 access token, sent as a bearer token
+    Bearer(String),
+    /// Value of the `BMSESSIONID` cookie of a logged in BeatSaver session
+    Session(String),
+}
+impl Credentials {
+    /// Adds the credentials to the headers of a request
+    pub(crate) fn apply(&self, request: &mut Request<Bytes>) {
+        let (name, value) = match self {
+            Self::Bearer(t) => (header::AUTHORIZATION, format!("Bearer {}", t)),
+            Self::Session(s) => (header::COOKIE, format!("BMSESSIONID={}", s)),
+        };
+        // Values containing invalid header characters are skipped, the API will reject the request
+        if let Ok(value) = HeaderValue::from_str(value.as_str()) {
+            request.headers_mut().insert(name, value);
+        }
+    }
+}
+impl From<&OAuthToken> for Credentials {
+    fn from(token: &OAuthToken) -> Self {
+        Self::Bearer(token.access_token.clone())
+    }
+}
+
+/// Adds the credentials (if any) to the headers of a request
+pub(crate) fn authorize(
+    mut request: Request<Bytes>,
+    credentials: Option<&Credentials>,
+) -> Request<Bytes> {
+    if let Some(c) = credentials {
+        c.apply(&mut request);
+    }
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::{authorize, Credentials, OAuthConfig, OAuthToken, TokenResponse, TOKEN_URL};
+    use crate::{get_request, BEATSAVER_URL};
+    use http::header;
+    use http::Method;
+
+    #[test]
+    fn test_authorize_url() {
+        let config = OAuthConfig::new("id", "secret", "http://localhost/callback")
+            .with_scope("identity")
+            .with_scope("bookmarks");
+        assert_eq!(
+            config.authorize_url("xyz").as_str(),
+            "https://beatsaver.com/oauth2/authorize?client_id=id&response_type=code&redirect_uri=http%3A%2F%2Flocalhost%2Fcallback&scope=identity+bookmarks&state=xyz"
+        );
+    }
+    #[test]
+    fn test_exchange_request() {
+        let config = OAuthConfig::new("id", "secret", "http://localhost/callback");
+        let request = config.exchange_request("a b");
+        assert_eq!(request.method(), Method::POST);
+        assert_eq!(request.uri(), TOKEN_URL.as_str());
+        assert_eq!(
+            request.body(),
+            "grant_type=authorization_code&code=a%20b&redirect_uri=http%3A%2F%2Flocalhost%2Fcallback&client_id=id&client_secret=secret"
+        );
+        let request = config.refresh_request("refresh");
+        assert_eq!(
+            request.body(),
+            "grant_type=refresh_token&refresh_token=refresh&client_id=id&client_secret=secret"
+        );
+    }
+    #[test]
+    fn test_token() {
+        let response: TokenResponse = serde_json::from_str(
+            r#"{"access_token":"abc","token_type":"Bearer","expires_in":3600,"refresh_token":"def","scope":"identity bookmarks"}"#,
+        )
+        .unwrap();
+        let token = OAuthToken::from(response);
+        assert_eq!(token.access_token, "abc");
+        assert_eq!(token.refresh_token, Some("def".into()));
+        assert_eq!(token.scopes, vec!["identity", "bookmarks"]);
+        assert!(!token.is_expired());
+    }
+    #[test]
+    fn test_authorize() {
+        let url = BEATSAVER_URL.join("users/me").unwrap();
+        let request = authorize(get_request(url.clone()), None);
+        assert!(request.headers().get(header::AUTHORIZATION).is_none());
+
+        let bearer = Credentials::Bearer("abc".into());
+        let request = authorize(get_request(url.clone()), Some(&bearer));
+        assert_eq!(request.headers()[header::AUTHORIZATION], "Bearer abc");
+
+        let session = Credentials::Session("def".into());
+        let request = authorize(get_request(url), Some(&session));
+        assert_eq!(request.headers()[header::COOKIE], "BMSESSIONID=def");
+    }
+}
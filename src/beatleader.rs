@@ -0,0 +1,320 @@
+//! # BeatLeader
+//!
+//! This module joins ranking information from [BeatLeader](https://beatleader.xyz/) onto maps
+//! retrieved from BeatSaver, filling the [bl_ranked][crate::model::map::MapDetail::bl_ranked],
+//! [bl_qualified][crate::model::map::MapDetail::bl_qualified] and
+//! [bl_stars][crate::model::map::MapDifficulty::bl_stars] fields with up to date values.
+//!
+//! The leaderboards of every difficulty of the latest version of a map are retrieved in a single
+//! request, without the credentials attached to the client.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+//! # mod main {
+//! use beatsaver_rs::beatleader::ranked_difficulties;
+//! use beatsaver_rs::client::BeatSaver;
+//! use beatsaver_rs::BeatSaverApiAsync;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BeatSaver::new();
+//!     let mut map = client.map_by_key("2144").await.unwrap();
+//!     client.join_beatleader(&mut map).await.unwrap();
+//!     for diff in ranked_difficulties(&map) {
+//!         println!("{} {}: {} stars", diff.characteristic, diff.difficulty, diff.bl_stars.unwrap());
+//!     }
+//! }
+//! # }
+//! ```
+use crate::model::map::{MapDetail, MapDifficulty};
+use crate::{response_body, BeatSaverApiError};
+use bytes::Bytes;
+use http::{Response, StatusCode};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use url::Url;
+
+lazy_static! {
+    /// Base URL for the BeatLeader API
+    pub static ref BEATLEADER_URL: Url = Url::parse("https://api.beatleader.xyz/").unwrap();
+}
+
+/// Ranking status of a difficulty on BeatLeader
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum DifficultyStatus {
+    /// Difficulty isn't ranked
+    Unranked,
+    /// Difficulty was nominated for ranking
+    Nominated,
+    /// Difficulty is qualified for ranking
+    Qualified,
+    /// Difficulty is ranked
+    Ranked,
+    /// Difficulty can't be ranked
+    Unrankable,
+    /// Difficulty was ranked, but the map was updated since
+    Outdated,
+    /// Difficulty is part of an event
+    InEvent,
+    /// Status added after this version of the library
+    Unknown(u8),
+}
+impl From<u8> for DifficultyStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => Self::Unranked,
+            1 => Self::Nominated,
+            2 => Self::Qualified,
+            3 => Self::Ranked,
+            4 => Self::Unrankable,
+            5 => Self::Outdated,
+            6 => Self::InEvent,
+            s => Self::Unknown(s),
+        }
+    }
+}
+impl From<DifficultyStatus> for u8 {
+    fn from(status: DifficultyStatus) -> Self {
+        match status {
+            DifficultyStatus::Unranked => 0,
+            DifficultyStatus::Nominated => 1,
+            DifficultyStatus::Qualified => 2,
+            DifficultyStatus::Ranked => 3,
+            DifficultyStatus::Unrankable => 4,
+            DifficultyStatus::Outdated => 5,
+            DifficultyStatus::InEvent => 6,
+            DifficultyStatus::Unknown(s) => s,
+        }
+    }
+}
+
+/// Difficulty a BeatLeader leaderboard is for
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardDifficulty {
+    /// ID of the difficulty
+    pub id: usize,
+    /// Difficulty number (1 for `Easy` to 9 for `ExpertPlus`)
+    pub value: u8,
+    /// Name of the difficulty (e.g. `ExpertPlus`)
+    #[serde(alias = "difficultyName")]
+    pub difficulty_name: String,
+    /// Name of the characteristic (e.g. `Standard`)
+    #[serde(alias = "modeName")]
+    pub mode_name: String,
+    /// Ranking status
+    pub status: DifficultyStatus,
+    /// Star rating, if the difficulty was rated
+    #[serde(default)]
+    pub stars: Option<f32>,
+    /// Rating of how hard the difficulty is to pass
+    #[serde(alias = "passRating", default)]
+    pub pass_rating: Option<f32>,
+    /// Rating of how hard the difficulty is to play accurately
+    #[serde(alias = "accRating", default)]
+    pub acc_rating: Option<f32>,
+    /// Rating of how technical the difficulty is
+    #[serde(alias = "techRating", default)]
+    pub tech_rating: Option<f32>,
+    /// Maximum possible score
+    #[serde(alias = "maxScore")]
+    pub max_score: usize,
+}
+
+/// BeatLeader leaderboard of a single difficulty
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Leaderboard {
+    /// ID of the leaderboard
+    pub id: String,
+    /// Difficulty of the leaderboard
+    pub difficulty: LeaderboardDifficulty,
+}
+impl Leaderboard {
+    /// Returns `true` if the leaderboard is for the provided difficulty
+    pub fn matches(&self, diff: &MapDifficulty) -> bool {
+        self.difficulty
+            .difficulty_name
+            .eq_ignore_ascii_case(&diff.difficulty)
+            && self
+                .difficulty
+                .mode_name
+                .eq_ignore_ascii_case(&diff.characteristic)
+    }
+}
+
+/// Response of the `leaderboards/hash` endpoint
+#[derive(Debug, Deserialize)]
+struct HashLeaderboards {
+    leaderboards: Vec<Leaderboard>,
+}
+
+/// Builds the URL of the leaderboards of a map version
+pub(crate) fn leaderboards_url(hash: &str) -> Url {
+    BEATLEADER_URL
+        .join(format!("leaderboards/hash/{}", hash.to_uppercase()).as_str())
+        .unwrap()
+}
+
+/// Parses a leaderboards response, versions without leaderboards return an empty list
+pub(crate) fn parse_leaderboards<T: Error>(
+    url: &Url,
+    response: Response<Bytes>,
+) -> Result<Vec<Leaderboard>, BeatSaverApiError<T>> {
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(vec![]);
+    }
+    let data = response_body(url, response)?;
+    let leaderboards: HashLeaderboards = serde_json::from_slice(data.as_ref())?;
+    Ok(leaderboards.leaderboards)
+}
+
+/// Joins leaderboards of the latest version of a map onto it
+///
+/// Difficulties get the star rating of their leaderboard if it is ranked or qualified, and no
+/// star rating otherwise. The map is ranked (or qualified) if any of its difficulties is.
+pub fn join(map: &mut MapDetail, leaderboards: &[Leaderboard]) {
+    let version = match map.versions.iter_mut().max_by_key(|v| v.created_at) {
+        Some(version) => version,
+        None => return,
+    };
+    for diff in version.diffs.iter_mut() {
+        diff.bl_stars = leaderboards
+            .iter()
+            .find(|l| l.matches(diff))
+            .map(|l| &l.difficulty)
+            .filter(|d| {
+                matches!(
+                    d.status,
+                    DifficultyStatus::Ranked | DifficultyStatus::Qualified
+                )
+            })
+            .and_then(|d| d.stars);
+    }
+    let has_status = |status| leaderboards.iter().any(|l| l.difficulty.status == status);
+    map.bl_ranked = has_status(DifficultyStatus::Ranked);
+    map.bl_qualified = has_status(DifficultyStatus::Qualified);
+}
+
+/// Difficulties of the latest version of a map that have a BeatLeader star rating
+pub fn ranked_difficulties(map: &MapDetail) -> impl Iterator<Item = &MapDifficulty> {
+    map.latest_version()
+        .into_iter()
+        .flat_map(|v| v.diffs.iter())
+        .filter(|d| d.bl_stars.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::beatleader::{
+        join, leaderboards_url, ranked_difficulties, DifficultyStatus, Leaderboard,
+    };
+    use crate::model::map::MapDetail;
+    use crate::tests::{map_hash, map_json};
+
+    /// Fixture map 1, with an `Expert` and an `ExpertPlus` difficulty
+    fn map() -> MapDetail {
+        let mut map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        let mut expert_plus = map.versions[0].diffs[0].clone();
+        expert_plus.difficulty = "ExpertPlus".to_string();
+        map.versions[0].diffs.push(expert_plus);
+        map
+    }
+    /// Leaderboards response for fixture map 1, with a ranked `Expert` and a nominated
+    /// `ExpertPlus` difficulty
+    fn leaderboards_json() -> String {
+        let leaderboard = |value, name: &str, status, stars: &str| {
+            format!(
+                r#"{{"id":"1{value}1","song":{{}},"difficulty":{{"id":{value},"value":{value},"mode":1,"difficultyName":"{name}","modeName":"Standard","status":{status},"stars":{stars},"passRating":null,"accRating":null,"techRating":null,"maxScore":450000}}}}"#,
+                value = value,
+                name = name,
+                status = status,
+                stars = stars
+            )
+        };
+        format!(
+            r#"{{"song":{{"hash":"{}"}},"leaderboards":[{},{}]}}"#,
+            map_hash("1").to_uppercase(),
+            leaderboard(7, "Expert", 3, "6.25"),
+            leaderboard(9, "ExpertPlus", 1, "8.5")
+        )
+    }
+    fn leaderboards() -> Vec<Leaderboard> {
+        let data: serde_json::Value = serde_json::from_str(&leaderboards_json()).unwrap();
+        serde_json::from_value(data["leaderboards"].clone()).unwrap()
+    }
+
+    #[test]
+    fn test_leaderboards_url() {
+        assert_eq!(
+            leaderboards_url(map_hash("1").as_str()).as_str(),
+            format!(
+                "https://api.beatleader.xyz/leaderboards/hash/{}",
+                map_hash("1")
+            )
+        );
+    }
+    #[test]
+    fn test_difficulty_status() {
+        let leaderboards = leaderboards();
+        assert_eq!(leaderboards[0].difficulty.status, DifficultyStatus::Ranked);
+        assert_eq!(
+            leaderboards[1].difficulty.status,
+            DifficultyStatus::Nominated
+        );
+        let status: DifficultyStatus = serde_json::from_str("42").unwrap();
+        assert_eq!(status, DifficultyStatus::Unknown(42));
+        assert_eq!(serde_json::to_string(&status).unwrap(), "42");
+    }
+    #[test]
+    fn test_join() {
+        let mut map = map();
+        join(&mut map, &leaderboards());
+        assert!(map.bl_ranked);
+        assert!(!map.bl_qualified);
+        assert_eq!(map.versions[0].diffs[0].bl_stars, Some(6.25));
+        assert_eq!(map.versions[0].diffs[1].bl_stars, None);
+        assert_eq!(ranked_difficulties(&map).count(), 1);
+
+        join(&mut map, &[]);
+        assert!(!map.bl_ranked);
+        assert_eq!(ranked_difficulties(&map).count(), 0);
+    }
+    /// Client answering the leaderboards of fixture map 1
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn client() -> crate::testing::MockClient {
+        use crate::testing::MockClient;
+
+        let url = leaderboards_url(map_hash("1").as_str());
+        MockClient::new().with_response(url, leaderboards_json())
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_join_beatleader_blocking() {
+        use crate::BeatSaverApiSync;
+
+        let client = client();
+        let mut map = map();
+        assert_eq!(client.beatleader_leaderboards(&map).unwrap().len(), 2);
+        client.join_beatleader(&mut map).unwrap();
+        assert!(map.bl_ranked);
+        assert_eq!(map.versions[0].diffs[0].bl_stars, Some(6.25));
+
+        let mut unknown: MapDetail = serde_json::from_str(map_json("2").as_str()).unwrap();
+        client.join_beatleader(&mut unknown).unwrap();
+        assert!(!unknown.bl_ranked);
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_join_beatleader() {
+        use crate::BeatSaverApiAsync;
+        use futures::executor::block_on;
+
+        let client = client();
+        let mut map = map();
+        block_on(client.join_beatleader(&mut map)).unwrap();
+        assert!(map.bl_ranked);
+        assert_eq!(ranked_difficulties(&map).count(), 1);
+    }
+}
@@ -0,0 +1,390 @@
+//! # beatsaver
+//!
+//! Command line client for [BeatSaver](https://beatsaver.com/), built on
+//! [BeatSaverBlocking][beatsaver_rs::client::BeatSaverBlocking].
+//!
+//! ```text
+//! beatsaver search <query> [-n <count>]
+//! beatsaver info <key|hash|url>
+//! beatsaver download <key|hash|url> [-o <dir>]
+//! beatsaver install <key|hash|url> [--custom-levels <dir>]
+//! beatsaver mirror --db <path> [--no-follow]
+//! beatsaver playlist --query <query> [--min-nps <nps>] [-n <count>] [--title <title>] --out <path>
+//! ```
+mod mirror;
+mod playlist;
+
+use beatsaver_rs::client::BeatSaverBlocking;
+use beatsaver_rs::install::{map_folder_name, InstallOutcome, InstallTarget};
+use beatsaver_rs::model::map::MapDetail;
+use beatsaver_rs::query::SearchOptions;
+use beatsaver_rs::{BeatSaverApiSync, MapId};
+use std::convert::TryInto;
+use std::env;
+use std::error::Error;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::process;
+use url::Url;
+
+const USAGE: &str = "\
+Usage: beatsaver [--base-url <url>] <command>
+
+Commands:
+  search <query> [-n <count>]        Searches maps, printing the first <count> results (default 10)
+  info <key|hash|url>                Prints the details of a map
+  download <key|hash|url> [-o <dir>] Downloads the archive of a map into <dir> (default .)
+  install <key|hash|url> [--custom-levels <dir>]
+                                     Installs a map into the CustomLevels folder of the detected
+                                     Beat Saber install, or into <dir>
+  mirror --db <path> [--no-follow]   Backfills a local mirror of the catalog stored at <path>, then
+                                     keeps it up to date with the map websocket
+  playlist --query <query> [--min-nps <nps>] [-n <count>] [--title <title>] --out <path>
+                                     Writes the first <count> search results (default 20) with a
+                                     difficulty of at least <nps> notes per second to a .bplist
+  help                               Prints this message";
+
+/// Number of search results printed by default
+const DEFAULT_SEARCH_COUNT: usize = 10;
+/// Number of maps in a generated playlist by default
+const DEFAULT_PLAYLIST_COUNT: usize = 20;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Search {
+        query: String,
+        count: usize,
+    },
+    Info {
+        id: MapId,
+    },
+    Download {
+        id: MapId,
+        dir: PathBuf,
+    },
+    Install {
+        id: MapId,
+        target: InstallTarget,
+    },
+    Mirror {
+        db: PathBuf,
+        follow: bool,
+    },
+    Playlist {
+        options: SearchOptions,
+        count: usize,
+        title: String,
+        out: PathBuf,
+    },
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    base_url: Option<Url>,
+    command: Command,
+}
+
+/// Parses the command line arguments, without the program name
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+    let mut args = args.into_iter();
+    let mut base_url = None;
+    let mut positional = vec![];
+    let mut count = None;
+    let mut output = None;
+    let mut query = None;
+    let mut min_nps = None;
+    let mut title = None;
+    let mut custom_levels = None;
+    let mut db = None;
+    let mut follow = true;
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{} requires a value", name));
+        match arg.as_str() {
+            "--base-url" => {
+                let url = value("--base-url")?;
+                base_url = Some(Url::parse(&url).map_err(|e| format!("invalid URL: {}", e))?);
+            }
+            "-n" | "--count" => {
+                let n = value("--count")?;
+                count = Some(n.parse().map_err(|_| format!("invalid count: {}", n))?);
+            }
+            "-o" | "--output" | "--out" => output = Some(PathBuf::from(value("--output")?)),
+            "--query" => query = Some(value("--query")?),
+            "--min-nps" => {
+                let nps = value("--min-nps")?;
+                min_nps = Some(nps.parse().map_err(|_| format!("invalid NPS: {}", nps))?);
+            }
+            "--title" => title = Some(value("--title")?),
+            "--custom-levels" => custom_levels = Some(PathBuf::from(value("--custom-levels")?)),
+            "--db" => db = Some(PathBuf::from(value("--db")?)),
+            "--no-follow" => follow = false,
+            "-h" | "--help" => positional.insert(0, "help".to_string()),
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                return Err(format!("unknown option: {}", arg))
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let map_id = |id: Option<&String>| -> Result<MapId, String> {
+        let id = id.ok_or("missing map key, hash or URL")?;
+        id.parse()
+            .map_err(|e| format!("invalid map id {}: {}", id, e))
+    };
+    let command = match positional.first().map(String::as_str) {
+        Some("search") if positional.len() > 1 => Command::Search {
+            query: positional[1..].join(" "),
+            count: count.unwrap_or(DEFAULT_SEARCH_COUNT),
+        },
+        Some("search") => return Err("missing search query".to_string()),
+        Some("info") => Command::Info {
+            id: map_id(positional.get(1))?,
+        },
+        Some("download") => Command::Download {
+            id: map_id(positional.get(1))?,
+            dir: output.unwrap_or_else(|| PathBuf::from(".")),
+        },
+        Some("install") => Command::Install {
+            id: map_id(positional.get(1))?,
+            target: custom_levels.map_or(InstallTarget::Auto, InstallTarget::CustomLevels),
+        },
+        Some("mirror") => Command::Mirror {
+            db: db.ok_or("mirror requires --db <path>")?,
+            follow,
+        },
+        Some("playlist") => {
+            let query = query.ok_or("playlist requires --query <query>")?;
+            let mut options = SearchOptions::new(query.as_str());
+            if let Some(nps) = min_nps {
+                options = options.with_min_nps(nps);
+            }
+            Command::Playlist {
+                options,
+                count: count.unwrap_or(DEFAULT_PLAYLIST_COUNT),
+                title: title.unwrap_or(query),
+                out: output.ok_or("playlist requires --out <path>")?,
+            }
+        }
+        Some("help") | None => Command::Help,
+        Some(command) => return Err(format!("unknown command: {}", command)),
+    };
+    Ok(Args { base_url, command })
+}
+
+/// Formats a map as a single line of search results
+fn summary(map: &MapDetail) -> String {
+    format!(
+        "{:<6} {} ({} - {}) [{:.0}%]",
+        map.id,
+        map.name,
+        map.metadata.song_author_name,
+        map.metadata.level_author_name,
+        map.stats.score * 100.0
+    )
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let mut client = BeatSaverBlocking::new();
+    if let Some(url) = args.base_url {
+        client = client.with_base_url(url);
+    }
+    match args.command {
+        Command::Search { query, count } => {
+            for map in client.search(&query).take(count) {
+                println!("{}", summary(&map?));
+            }
+        }
+        Command::Info { id } => {
+            let map = client.map(&id)?;
+            println!("{}", map.name);
+            println!("Key:      {}", map.id);
+            println!("Hash:     {}", map.hash().unwrap_or("-"));
+            let song = format!(
+                "{} - {} {}",
+                map.metadata.song_author_name, map.metadata.song_name, map.metadata.song_sub_name
+            );
+            println!("Song:     {}", song.trim_end());
+            println!("Mapper:   {}", map.metadata.level_author_name);
+            println!("Uploaded: {}", map.uploaded.format("%Y-%m-%d"));
+            println!("BPM:      {}", map.metadata.bpm);
+            println!(
+                "Duration: {}:{:02}",
+                map.metadata.duration / 60,
+                map.metadata.duration % 60
+            );
+            println!(
+                "Rating:   {:.0}% ({} up, {} down)",
+                map.stats.score * 100.0,
+                map.stats.upvotes,
+                map.stats.downvotes
+            );
+            if let Some(version) = map.latest_version() {
+                let diffs: Vec<String> = version
+                    .diffs
+                    .iter()
+                    .map(|d| format!("{} {}", d.characteristic, d.difficulty))
+                    .collect();
+                println!("Diffs:    {}", diffs.join(", "));
+            }
+            if !map.description.is_empty() {
+                println!("\n{}", map.description);
+            }
+        }
+        Command::Download { id, dir } => {
+            let map = client.map(&id)?;
+            let path = dir.join(format!("{}.zip", map_folder_name(&map)));
+            let mut file = BufWriter::new(File::create(&path)?);
+            let size = client.download_to((&map).try_into()?, &mut file, |_, _| {})?;
+            println!("Downloaded {} ({} bytes)", path.display(), size);
+        }
+        Command::Install { id, target } => {
+            if target.custom_levels().is_none() {
+                return Err("Beat Saber install not found, use --custom-levels <dir>".into());
+            }
+            match client.install_map(&id, &target)? {
+                InstallOutcome::Installed(path) => println!("Installed {}", path.display()),
+                InstallOutcome::AlreadyInstalled(path) => {
+                    println!("Already installed in {}", path.display())
+                }
+            }
+        }
+        Command::Mirror { db, follow } => mirror::run(&client, &db, follow)?,
+        Command::Playlist {
+            options,
+            count,
+            title,
+            out,
+        } => playlist::run(&client, &options, count, &title, &out)?,
+        Command::Help => println!("{}", USAGE),
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(e) = run(args) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_args, Args, Command};
+    use beatsaver_rs::install::InstallTarget;
+    use beatsaver_rs::query::SearchOptions;
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse(&["search", "camellia", "ghost", "-n", "3"]).unwrap(),
+            Args {
+                base_url: None,
+                command: Command::Search {
+                    query: "camellia ghost".to_string(),
+                    count: 3
+                }
+            }
+        );
+        let args = parse(&["--base-url", "http://localhost/", "info", "2144"]).unwrap();
+        assert_eq!(args.base_url.unwrap().as_str(), "http://localhost/");
+        assert_eq!(
+            args.command,
+            Command::Info {
+                id: "2144".parse().unwrap()
+            }
+        );
+        assert_eq!(
+            parse(&["download", "https://beatsaver.com/maps/2144", "-o", "maps"])
+                .unwrap()
+                .command,
+            Command::Download {
+                id: "2144".parse().unwrap(),
+                dir: PathBuf::from("maps")
+            }
+        );
+        assert_eq!(
+            parse(&["mirror", "--db", "mirror.db", "--no-follow"])
+                .unwrap()
+                .command,
+            Command::Mirror {
+                db: PathBuf::from("mirror.db"),
+                follow: false
+            }
+        );
+        assert_eq!(
+            parse(&[
+                "playlist",
+                "--query",
+                "tech",
+                "--min-nps",
+                "5",
+                "--out",
+                "a.bplist"
+            ])
+            .unwrap()
+            .command,
+            Command::Playlist {
+                options: SearchOptions::new("tech").with_min_nps(5.0),
+                count: 20,
+                title: "tech".to_string(),
+                out: PathBuf::from("a.bplist")
+            }
+        );
+        assert_eq!(
+            parse(&["install", "2144"]).unwrap().command,
+            Command::Install {
+                id: "2144".parse().unwrap(),
+                target: InstallTarget::Auto
+            }
+        );
+        assert_eq!(
+            parse(&["install", "2144", "--custom-levels", "levels"])
+                .unwrap()
+                .command,
+            Command::Install {
+                id: "2144".parse().unwrap(),
+                target: InstallTarget::CustomLevels(PathBuf::from("levels"))
+            }
+        );
+        assert_eq!(parse(&[]).unwrap().command, Command::Help);
+        assert_eq!(parse(&["info", "-h"]).unwrap().command, Command::Help);
+    }
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse(&["search"]).is_err());
+        assert!(parse(&["info"]).is_err());
+        assert!(parse(&["info", "not a key"]).is_err());
+        assert!(parse(&["search", "x", "-n"]).is_err());
+        assert!(parse(&["search", "x", "-n", "many"]).is_err());
+        assert!(parse(&["search", "x", "--verbose"]).is_err());
+        assert!(parse(&["install"]).is_err());
+        assert!(parse(&["mirror"]).is_err());
+        assert!(parse(&["playlist", "--out", "a.bplist"]).is_err());
+        assert!(parse(&["playlist", "--query", "tech"]).is_err());
+        assert!(parse(&[
+            "playlist",
+            "--query",
+            "x",
+            "--min-nps",
+            "fast",
+            "--out",
+            "a"
+        ])
+        .is_err());
+        assert!(parse(&["upload"]).is_err());
+    }
+}
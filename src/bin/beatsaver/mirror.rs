@@ -0,0 +1,49 @@
+//! `beatsaver mirror`: keeps a local [Mirror][beatsaver_rs::mirror::Mirror] of the catalog
+//!
+//! This follows the recommended mirroring pattern:
+//! 1. [backfill][beatsaver_rs::mirror::Mirror::backfill_blocking] the catalog, resuming from
+//!    the checkpoint of a previous run
+//! 2. follow the map websocket, applying every event to the mirror as it happens
+use beatsaver_rs::client::BeatSaverBlocking;
+use beatsaver_rs::mirror::Mirror;
+use beatsaver_rs::ws::{MapEvent, ReconnectOptions};
+use std::error::Error;
+use std::path::Path;
+
+/// Backfills the mirror stored in the SQLite database at `db`, then applies websocket events to it if `follow` is set
+pub fn run(client: &BeatSaverBlocking, db: &Path, follow: bool) -> Result<(), Box<dyn Error>> {
+    let mut mirror = Mirror::open(db)?;
+    match mirror.checkpoint()? {
+        Some(_) => println!("Resuming backfill of {}", db.display()),
+        None => println!("Starting backfill of {}", db.display()),
+    }
+    let report = mirror.backfill_blocking(client)?;
+    println!(
+        "Backfill complete: {} added, {} updated, {} maps mirrored",
+        report.added,
+        report.updated,
+        mirror.len()?
+    );
+    if !follow {
+        return Ok(());
+    }
+
+    println!("Following map events");
+    for event in client.map_events_reconnecting(ReconnectOptions::new()) {
+        let event = event?;
+        if mirror.apply_event(&event)? {
+            println!("{}", describe(&event));
+        }
+    }
+    Ok(())
+}
+
+/// Describes an event applied to the mirror
+fn describe(event: &MapEvent) -> String {
+    match event {
+        MapEvent::Create(map) => format!("Added {} ({})", map.id, map.name),
+        MapEvent::Update(map) => format!("Updated {} ({})", map.id, map.name),
+        MapEvent::Delete(key) => format!("Deleted {}", key),
+        MapEvent::Unknown(kind, _) => format!("Ignored {} event", kind),
+    }
+}
@@ -0,0 +1,52 @@
+//! `beatsaver playlist`: builds a `.bplist` playlist from search results
+use beatsaver_rs::client::BeatSaverBlocking;
+use beatsaver_rs::model::map::MapDetail;
+use beatsaver_rs::playlist::{Playlist, PlaylistSong};
+use beatsaver_rs::query::SearchOptions;
+use beatsaver_rs::BeatSaverApiSync;
+use std::error::Error;
+use std::path::Path;
+
+/// Searches maps matching the options, and writes the first `count` of them to a playlist at
+/// `out`
+///
+/// If the options have a minimum NPS, only the difficulties reaching it are listed in the
+/// playlist, and maps without any are skipped.
+pub fn run(
+    client: &BeatSaverBlocking,
+    options: &SearchOptions,
+    count: usize,
+    title: &str,
+    out: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let mut playlist = Playlist::new(title)
+        .with_author("beatsaver-rs")
+        .with_description(format!("Search results for \"{}\"", options.query).as_str());
+    for map in client.search_filtered(options) {
+        if playlist.songs.len() >= count {
+            break;
+        }
+        if let Some(song) = song(&map?, options.min_nps) {
+            playlist.songs.push(song);
+        }
+    }
+    playlist.save(out)?;
+    println!("Wrote {} maps to {}", playlist.songs.len(), out.display());
+    Ok(())
+}
+
+/// Builds the playlist entry of the latest version of a map
+fn song(map: &MapDetail, min_nps: Option<f32>) -> Option<PlaylistSong> {
+    let mut song = PlaylistSong::from_map(map)?;
+    if let Some(min_nps) = min_nps {
+        for d in &map.latest_version()?.diffs {
+            if d.nps >= min_nps {
+                song = song.with_difficulty(&d.characteristic, &d.difficulty);
+            }
+        }
+        if song.difficulties.is_empty() {
+            return None;
+        }
+    }
+    Some(song)
+}
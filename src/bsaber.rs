@@ -0,0 +1,171 @@
+//! # BeastSaber
+//!
+//! This module reads the curator recommendations of [BeastSaber](https://bsaber.com/), and
+//! resolves them back to BeatSaver maps.
+//!
+//! Recommendations are paged, most recent first. Pages are requested without the credentials
+//! attached to the client, and their maps are retrieved from BeatSaver in a single request.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaver;
+//! use beatsaver_rs::BeatSaverApiAsync;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BeatSaver::new();
+//!     for map in client.bsaber_curated_maps(1).await.unwrap() {
+//!         println!("{} ({})", map.name, map.id);
+//!     }
+//! }
+//! # }
+//! ```
+use crate::{response_body, BeatSaverApiError};
+use bytes::Bytes;
+use http::Response;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use url::Url;
+
+lazy_static! {
+    /// Base URL for the BeastSaber API
+    pub static ref BSABER_URL: Url = Url::parse("https://bsaber.com/wp-json/bsaber-api/").unwrap();
+}
+
+/// Map recommended by a BeastSaber curator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CuratedSong {
+    /// Title of the map
+    pub title: String,
+    /// BeatSaver key of the map
+    pub song_key: String,
+    /// Hash of the recommended version
+    pub hash: String,
+    /// Name of the mapper
+    #[serde(default)]
+    pub level_author_name: String,
+    /// Name of the curator who recommended the map
+    #[serde(default)]
+    pub curated_by: Option<String>,
+}
+
+/// Page of curator recommendations
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CuratedPage {
+    /// Recommended maps, most recent first
+    pub songs: Vec<CuratedSong>,
+    /// Number of the following page, `None` on the last page
+    #[serde(default)]
+    pub next_page: Option<usize>,
+}
+impl CuratedPage {
+    /// Lowercase hashes of the recommended versions
+    pub fn hashes(&self) -> Vec<String> {
+        self.songs.iter().map(|s| s.hash.to_lowercase()).collect()
+    }
+}
+
+/// Builds the URL of a page of curator recommendations, pages start at 1
+pub(crate) fn curated_url(page: usize) -> Url {
+    BSABER_URL
+        .join(format!("songs/?bookmarked_by=curatorrecommended&page={}", page).as_str())
+        .unwrap()
+}
+
+/// Parses a page of curator recommendations
+pub(crate) fn parse_curated<T: Error>(
+    url: &Url,
+    response: Response<Bytes>,
+) -> Result<CuratedPage, BeatSaverApiError<T>> {
+    let data = response_body(url, response)?;
+    Ok(serde_json::from_slice(data.as_ref())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bsaber::{curated_url, CuratedPage};
+    use crate::tests::map_hash;
+
+    /// Page recommending the fixture maps with the specified keys
+    fn curated_json(keys: &[&str], next_page: Option<usize>) -> String {
+        let songs: Vec<String> = keys
+            .iter()
+            .map(|k| {
+                format!(
+                    r#"{{"title":"Map {key}","song_key":"{key}","hash":"{hash}","level_author_name":"bennydabeast","curated_by":"Joetastic"}}"#,
+                    key = k,
+                    hash = map_hash(k).to_uppercase()
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"songs":[{}],"next_page":{}}}"#,
+            songs.join(","),
+            serde_json::to_string(&next_page).unwrap()
+        )
+    }
+
+    #[test]
+    fn test_curated_page() {
+        assert_eq!(
+            curated_url(2).as_str(),
+            "https://bsaber.com/wp-json/bsaber-api/songs/?bookmarked_by=curatorrecommended&page=2"
+        );
+        let page: CuratedPage = serde_json::from_str(&curated_json(&["1", "2"], Some(2))).unwrap();
+        assert_eq!(page.songs[0].song_key, "1");
+        assert_eq!(page.songs[1].curated_by.as_deref(), Some("Joetastic"));
+        assert_eq!(page.hashes(), [map_hash("1"), map_hash("2")]);
+        assert_eq!(page.next_page, Some(2));
+    }
+    /// Client recommending fixture maps 1, 2 and 3, map 2 being unknown to BeatSaver
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn client() -> crate::testing::MockClient {
+        use crate::testing::MockClient;
+        use crate::tests::map_json;
+        use crate::BEATSAVER_URL;
+
+        let hashes = [map_hash("1"), map_hash("2"), map_hash("3")];
+        let url = BEATSAVER_URL.join(format!("maps/hash/{}", hashes.join(",")).as_str());
+        let body = format!(
+            r#"{{"{}":{},"{}":null,"{}":{}}}"#,
+            hashes[0],
+            map_json("1"),
+            hashes[1],
+            hashes[2],
+            map_json("3")
+        );
+        MockClient::new()
+            .with_response(curated_url(1), curated_json(&["1", "2", "3"], Some(2)))
+            .with_response(curated_url(2), curated_json(&[], None))
+            .with_response(url.unwrap(), body)
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_curated_maps_blocking() {
+        use crate::BeatSaverApiSync;
+
+        let client = client();
+        let keys: Vec<String> = client
+            .bsaber_curated_maps(1)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(keys, ["1", "3"]);
+        assert!(client.bsaber_curated_maps(2).unwrap().is_empty());
+        assert_eq!(client.requests().len(), 3);
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_curated_maps() {
+        use crate::BeatSaverApiAsync;
+        use futures::executor::block_on;
+
+        let client = client();
+        assert_eq!(block_on(client.bsaber_curated(1)).unwrap().songs.len(), 3);
+        assert_eq!(block_on(client.bsaber_curated_maps(1)).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,549 @@
+//! # Cache
+//!
+//! This module contains the caches clients use to avoid downloading unchanged responses again.
+//!
+//! Responses that carry an `ETag` or `Last-Modified` header are stored, and requesting the same
+//! URL again sends `If-None-Match`/`If-Modified-Since`. If the server answers with
+//! `304 Not Modified`, the cached body is returned instead.
+//!
+//! Caches with a time to live also return responses younger than it without sending a request,
+//! e.g. so a bot looking up the same map many times only requests it once:
+//! ```no_run
+//! # #[cfg(feature = "reqwest_backend")]
+//! # mod main {
+//! use beatsaver_rs::cache::MemoryCache;
+//! use beatsaver_rs::client::BeatSaverReqwest;
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! # fn main() {
+//! let cache = MemoryCache::new()
+//!     .with_capacity(1000)
+//!     .with_ttl(Duration::from_secs(300));
+//! let client = BeatSaverReqwest::new().with_response_cache(Arc::new(cache));
+//! # }
+//! # }
+//! ```
+//!
+//! [DiskCache][crate::cache::DiskCache] stores responses in a directory instead, so they survive
+//! restarts of the application.
+//!
+//! Map events received from the [websocket][crate::ws] evict the updated and deleted maps from
+//! the cache of the client they were received with, see [invalidate][crate::cache::invalidate].
+use crate::model::map::MapDetail;
+use crate::ws::MapEvent;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::header::{self, HeaderMap, HeaderValue};
+use http::{Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use url::Url;
+
+/// Response stored in a [ResponseCache][crate::cache::ResponseCache]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedResponse {
+    /// Body of the response
+    pub body: Bytes,
+    /// Value of the `ETag` header
+    pub etag: Option<String>,
+    /// Value of the `Last-Modified` header
+    pub last_modified: Option<String>,
+    /// Time the response was received
+    pub stored_at: DateTime<Utc>,
+}
+impl CachedResponse {
+    /// Creates a [CachedResponse][crate::cache::CachedResponse] from a response
+    pub fn from_response(response: &Response<Bytes>) -> Self {
+        let header = |name| {
+            let value = response.headers().get(name)?.to_str().ok()?;
+            Some(value.to_string())
+        };
+        Self {
+            body: response.body().clone(),
+            etag: header(header::ETAG),
+            last_modified: header(header::LAST_MODIFIED),
+            stored_at: Utc::now(),
+        }
+    }
+    /// Returns `true` if the response has an `ETag` or `Last-Modified` header to revalidate it
+    pub fn is_revalidatable(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+    /// Returns `true` if the response is younger than the time to live
+    pub fn is_fresh(&self, ttl: Option<Duration>) -> bool {
+        match ttl {
+            Some(ttl) => (Utc::now() - self.stored_at).to_std().unwrap_or_default() < ttl,
+            None => false,
+        }
+    }
+    /// Headers asking the server to only send the response if it changed
+    pub(crate) fn conditional_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let mut insert = |name, value: &Option<String>| {
+            if let Some(value) = value.as_deref().and_then(|v| HeaderValue::from_str(v).ok()) {
+                headers.insert(name, value);
+            }
+        };
+        insert(header::IF_NONE_MATCH, &self.etag);
+        insert(header::IF_MODIFIED_SINCE, &self.last_modified);
+        headers
+    }
+}
+
+/// Storage for responses, keyed by URL
+pub trait ResponseCache: fmt::Debug + Send + Sync {
+    /// Returns the response stored for the URL, if any
+    fn get(&self, url: &Url) -> Option<CachedResponse>;
+    /// Stores the response for the URL, replacing the previous one
+    fn insert(&self, url: &Url, response: CachedResponse);
+    /// Removes the response stored for the URL
+    fn remove(&self, url: &Url);
+    /// Returns how long responses are returned without revalidating them, defaults to always
+    /// revalidating
+    ///
+    /// Responses without an `ETag` or `Last-Modified` header are only stored if this is set.
+    fn ttl(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    /// Responses along with the tick they were last used at
+    responses: HashMap<Url, (CachedResponse, u64)>,
+    tick: u64,
+}
+impl Entries {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// [ResponseCache][crate::cache::ResponseCache] keeping responses in memory
+///
+/// Once the capacity is reached, the least recently used response is evicted.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<Entries>,
+    capacity: Option<usize>,
+    ttl: Option<Duration>,
+}
+impl MemoryCache {
+    /// Creates a new, empty [MemoryCache][crate::cache::MemoryCache] without capacity or time to
+    /// live
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the maximum number of responses stored in the cache
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+    /// Sets how long responses are returned without revalidating them
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+    /// Number of responses stored in the cache
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().responses.len()
+    }
+    /// Returns `true` if no responses are stored in the cache
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Removes every response from the cache
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().responses.clear();
+    }
+}
+impl ResponseCache for MemoryCache {
+    fn get(&self, url: &Url) -> Option<CachedResponse> {
+        let mut entries = self.entries.lock().unwrap();
+        let tick = entries.next_tick();
+        let (response, used) = entries.responses.get_mut(url)?;
+        *used = tick;
+        Some(response.clone())
+    }
+    fn insert(&self, url: &Url, response: CachedResponse) {
+        let mut entries = self.entries.lock().unwrap();
+        let tick = entries.next_tick();
+        entries.responses.insert(url.clone(), (response, tick));
+        if let Some(capacity) = self.capacity {
+            while entries.responses.len() > capacity {
+                let oldest = entries
+                    .responses
+                    .iter()
+                    .min_by_key(|(_, (_, used))| *used)
+                    .map(|(url, _)| url.clone())
+                    .unwrap();
+                entries.responses.remove(&oldest);
+            }
+        }
+    }
+    fn remove(&self, url: &Url) {
+        self.entries.lock().unwrap().responses.remove(url);
+    }
+    fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+}
+
+/// Metadata of a response stored by a [DiskCache][crate::cache::DiskCache]
+#[derive(Debug, Serialize, Deserialize)]
+struct DiskEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    stored_at: DateTime<Utc>,
+}
+
+/// [ResponseCache][crate::cache::ResponseCache] storing responses as files in a directory
+///
+/// Every response is stored as two files named after a hash of its URL: the body, and its
+/// metadata as JSON. The directory is created when the first response is stored.
+///
+/// Note: IO errors are ignored, responses that can't be read are treated as missing
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl: Option<Duration>,
+}
+impl DiskCache {
+    /// Creates a new [DiskCache][crate::cache::DiskCache] storing responses in the provided
+    /// directory, without time to live
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl: None,
+        }
+    }
+    /// Sets how long responses are returned without revalidating them
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+    /// Directory the responses are stored in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+    /// Removes every response from the cache
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+    /// Paths of the metadata and body files of the response stored for the URL
+    fn paths(&self, url: &Url) -> (PathBuf, PathBuf) {
+        let name = format!("{:016x}", fnv1a(url.as_str().as_bytes()));
+        (
+            self.dir.join(format!("{}.json", name)),
+            self.dir.join(format!("{}.body", name)),
+        )
+    }
+    fn read(&self, url: &Url) -> io::Result<CachedResponse> {
+        let (meta, body) = self.paths(url);
+        let entry: DiskEntry = serde_json::from_slice(&fs::read(meta)?)?;
+        if entry.url != url.as_str() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "hash collision"));
+        }
+        Ok(CachedResponse {
+            body: fs::read(body)?.into(),
+            etag: entry.etag,
+            last_modified: entry.last_modified,
+            stored_at: entry.stored_at,
+        })
+    }
+    fn write(&self, url: &Url, response: CachedResponse) -> io::Result<()> {
+        let (meta, body) = self.paths(url);
+        let entry = DiskEntry {
+            url: url.to_string(),
+            etag: response.etag,
+            last_modified: response.last_modified,
+            stored_at: response.stored_at,
+        };
+        fs::create_dir_all(&self.dir)?;
+        // The metadata is removed first, so a partially written response is never read
+        remove_file(&meta)?;
+        write_file(&body, &response.body)?;
+        write_file(&meta, &serde_json::to_vec(&entry)?)
+    }
+}
+impl ResponseCache for DiskCache {
+    fn get(&self, url: &Url) -> Option<CachedResponse> {
+        self.read(url).ok()
+    }
+    fn insert(&self, url: &Url, response: CachedResponse) {
+        let _ = self.write(url, response);
+    }
+    fn remove(&self, url: &Url) {
+        let (meta, body) = self.paths(url);
+        let _ = remove_file(&meta).and_then(|_| remove_file(&body));
+    }
+    fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+}
+
+/// 64 bit FNV-1a hash, used to name files as it is stable across versions and platforms
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Writes a file through a temporary file, so it's either fully written or left untouched
+fn write_file(path: &Path, data: &[u8]) -> io::Result<()> {
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, data)?;
+    fs::rename(tmp, path)
+}
+
+/// Removes a file, ignoring missing files
+fn remove_file(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+/// Removes the responses describing the map of a [MapEvent][crate::ws::MapEvent] from the cache
+///
+/// Update and delete events evict the `maps/id` response of the map, relative to the base URL
+/// requests are sent to, along with the `maps/hash` responses of its versions. Create events
+/// don't affect cached responses.
+pub fn invalidate(cache: &dyn ResponseCache, base_url: &Url, event: &MapEvent) {
+    let (key, mut hashes) = match event {
+        MapEvent::Update(map) => (map.id.as_str(), version_hashes(map)),
+        MapEvent::Delete(key) => (key.as_str(), Vec::new()),
+        _ => return,
+    };
+    let id_url = match base_url.join(format!("maps/id/{}", key).as_str()) {
+        Ok(url) => url,
+        Err(_) => return,
+    };
+    // The cached map lists the hashes of versions that may not be part of the event
+    if let Some(cached) = cache.get(&id_url) {
+        if let Ok(map) = serde_json::from_slice::<MapDetail>(&cached.body) {
+            hashes.extend(version_hashes(&map));
+        }
+    }
+    cache.remove(&id_url);
+    for hash in hashes {
+        if let Ok(url) = base_url.join(format!("maps/hash/{}", hash).as_str()) {
+            cache.remove(&url);
+        }
+    }
+}
+
+fn version_hashes(map: &MapDetail) -> Vec<String> {
+    map.versions.iter().map(|v| v.hash.to_lowercase()).collect()
+}
+
+/// Cached response to a request
+pub(crate) enum Lookup {
+    /// Fresh response, returned without sending the request
+    Fresh(Bytes),
+    /// Response that needs to be revalidated, if any
+    Stale(Option<CachedResponse>),
+}
+impl Lookup {
+    /// Looks up the response stored for the URL
+    pub(crate) fn new(cache: &dyn ResponseCache, url: &Url) -> Self {
+        match cache.get(url) {
+            Some(cached) if cached.is_fresh(cache.ttl()) => Self::Fresh(cached.body),
+            cached => Self::Stale(cached),
+        }
+    }
+}
+
+/// Updates the cache with the response to a request sent with the
+/// [conditional headers][crate::cache::CachedResponse::conditional_headers] of the stale response
+///
+/// `304 Not Modified` responses are replaced with the stale response.
+pub(crate) fn update(
+    cache: &dyn ResponseCache,
+    url: &Url,
+    stale: Option<CachedResponse>,
+    response: Response<Bytes>,
+) -> Response<Bytes> {
+    match stale {
+        Some(mut cached) if response.status() == StatusCode::NOT_MODIFIED => {
+            // Restarts the time to live of the response
+            cached.stored_at = Utc::now();
+            cache.insert(url, cached.clone());
+            let mut response = response.map(|_| cached.body);
+            *response.status_mut() = StatusCode::OK;
+            response
+        }
+        _ => {
+            store(cache, url, &response);
+            response
+        }
+    }
+}
+
+/// Stores a successful response in the cache, if it can be revalidated or the cache has a time to
+/// live
+fn store(cache: &dyn ResponseCache, url: &Url, response: &Response<Bytes>) {
+    if !response.status().is_success() {
+        return;
+    }
+    let cached = CachedResponse::from_response(response);
+    if cached.is_revalidatable() || cache.ttl().is_some() {
+        cache.insert(url, cached);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::{
+        invalidate, store, update, CachedResponse, DiskCache, MemoryCache, ResponseCache,
+    };
+    use crate::model::map::MapDetail;
+    use crate::tests::{map_hash, map_json};
+    use crate::ws::MapEvent;
+    use crate::BEATSAVER_URL;
+    use bytes::Bytes;
+    use chrono::Utc;
+    use http::header;
+    use http::{Response, StatusCode};
+    use std::time::Duration;
+    use url::Url;
+
+    fn response(status: StatusCode, etag: Option<&str>) -> Response<Bytes> {
+        let mut response = Response::builder().status(status);
+        if let Some(etag) = etag {
+            response = response.header(header::ETAG, etag);
+        }
+        response.body(Bytes::from("{}")).unwrap()
+    }
+
+    #[test]
+    fn test_cached_response() {
+        assert!(!CachedResponse::from_response(&response(StatusCode::OK, None)).is_revalidatable());
+        let cached = CachedResponse::from_response(&response(StatusCode::OK, Some("\"1\"")));
+        assert!(cached.is_revalidatable());
+        let headers = cached.conditional_headers();
+        assert_eq!(headers.get(header::IF_NONE_MATCH).unwrap(), "\"1\"");
+        assert!(headers.get(header::IF_MODIFIED_SINCE).is_none());
+    }
+    #[test]
+    fn test_memory_cache() {
+        let url = Url::parse("https://api.beatsaver.com/maps/id/2144").unwrap();
+        let cache = MemoryCache::new();
+        store(
+            &cache,
+            &url,
+            &response(StatusCode::NOT_FOUND, Some("\"1\"")),
+        );
+        store(&cache, &url, &response(StatusCode::OK, None));
+        assert!(cache.is_empty());
+        store(&cache, &url, &response(StatusCode::OK, Some("\"1\"")));
+        assert_eq!(cache.get(&url).unwrap().etag.as_deref(), Some("\"1\""));
+        cache.remove(&url);
+        assert!(cache.get(&url).is_none());
+
+        let cache = MemoryCache::new().with_ttl(Duration::from_secs(60));
+        store(&cache, &url, &response(StatusCode::OK, None));
+        assert!(cache.get(&url).unwrap().is_fresh(cache.ttl()));
+    }
+    #[test]
+    fn test_memory_cache_lru() {
+        let url = |id| Url::parse(&format!("https://api.beatsaver.com/maps/id/{}", id)).unwrap();
+        let cache = MemoryCache::new().with_capacity(2);
+        let cached = CachedResponse::from_response(&response(StatusCode::OK, Some("\"1\"")));
+        cache.insert(&url(1), cached.clone());
+        cache.insert(&url(2), cached.clone());
+        assert!(cache.get(&url(1)).is_some());
+        cache.insert(&url(3), cached);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&url(1)).is_some());
+        assert!(cache.get(&url(2)).is_none());
+        assert!(cache.get(&url(3)).is_some());
+    }
+    #[test]
+    fn test_is_fresh() {
+        let mut cached = CachedResponse::from_response(&response(StatusCode::OK, None));
+        assert!(!cached.is_fresh(None));
+        assert!(cached.is_fresh(Some(Duration::from_secs(60))));
+        cached.stored_at = Utc::now() - chrono::Duration::seconds(120);
+        assert!(!cached.is_fresh(Some(Duration::from_secs(60))));
+    }
+    #[test]
+    fn test_update() {
+        let url = Url::parse("https://api.beatsaver.com/maps/id/2144").unwrap();
+        let cache = MemoryCache::new();
+        let mut cached = CachedResponse::from_response(&response(StatusCode::OK, Some("\"1\"")));
+        cached.stored_at = Utc::now() - chrono::Duration::seconds(120);
+        let not_modified = Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Bytes::new())
+            .unwrap();
+        let response = update(&cache, &url, Some(cached.clone()), not_modified);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), &cached.body);
+        assert!(cache.get(&url).unwrap().stored_at > cached.stored_at);
+    }
+    #[test]
+    fn test_disk_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = Url::parse("https://api.beatsaver.com/maps/id/2144").unwrap();
+        let cache = DiskCache::new(dir.path().join("cache"));
+        assert!(cache.get(&url).is_none());
+        let cached = CachedResponse::from_response(&response(StatusCode::OK, Some("\"1\"")));
+        cache.insert(&url, cached.clone());
+        assert_eq!(cache.get(&url).unwrap(), cached);
+
+        // Responses survive restarts
+        let cache = DiskCache::new(dir.path().join("cache"));
+        assert_eq!(cache.get(&url).unwrap(), cached);
+        let other = Url::parse("https://api.beatsaver.com/maps/id/2145").unwrap();
+        assert!(cache.get(&other).is_none());
+        cache.remove(&url);
+        assert!(cache.get(&url).is_none());
+
+        cache.insert(&url, cached);
+        cache.clear().unwrap();
+        assert!(!cache.dir().exists());
+        cache.clear().unwrap();
+    }
+    #[test]
+    fn test_invalidate() {
+        let id_url = BEATSAVER_URL.join("maps/id/1").unwrap();
+        let hash_url = BEATSAVER_URL
+            .join(format!("maps/hash/{}", map_hash("1")).as_str())
+            .unwrap();
+        let other_url = BEATSAVER_URL.join("maps/id/2").unwrap();
+        let cache = MemoryCache::new().with_ttl(Duration::from_secs(60));
+        let fill = || {
+            for url in [&id_url, &hash_url, &other_url].iter() {
+                let response = Response::new(Bytes::from(map_json("1")));
+                cache.insert(url, CachedResponse::from_response(&response));
+            }
+        };
+        let map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+
+        fill();
+        invalidate(&cache, &BEATSAVER_URL, &MapEvent::Create(map.clone()));
+        assert_eq!(cache.len(), 3);
+        invalidate(&cache, &BEATSAVER_URL, &MapEvent::Update(map));
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&other_url).is_some());
+
+        fill();
+        invalidate(&cache, &BEATSAVER_URL, &MapEvent::Delete("1".into()));
+        assert!(cache.get(&id_url).is_none());
+        assert!(cache.get(&hash_url).is_none());
+        assert!(cache.get(&other_url).is_some());
+    }
+}
@@ -4,276 +4,3615 @@
 //!
 //! The following backends are implemented:
 //! * [Reqwest](https://crates.io/crates/reqwest) => `reqwest_backend` feature (asynchronous, uses [Tokio](https://crates.io/crates/tokio))
+//! * [hyper](https://crates.io/crates/hyper) => `hyper_backend` feature (asynchronous, uses [Tokio](https://crates.io/crates/tokio))
+//! * [isahc](https://crates.io/crates/isahc) => `isahc_backend` feature (asynchronous, runtime-agnostic, uses [libcurl](https://curl.se/libcurl/))
 //! * [Surf](https://crates.io/crates/surf) => `surf_backend` feature (asynchronous, uses [async-std](https://crates.io/crates/async-std))
 //! * [ureq](https://crates.io/crates/ureq) => `ureq_backend` feature (synchronous)
+//! * [attohttpc](https://crates.io/crates/attohttpc) => `attohttpc_backend` feature (synchronous, minimal dependencies)
+//! * [Fetch API](https://developer.mozilla.org/docs/Web/API/Fetch_API) => `wasm_backend` feature (asynchronous, `wasm32` targets only)
+//! * [Reqwest](https://crates.io/crates/reqwest) on an internal runtime => `blocking` feature (synchronous)
+//!
+//! Each backend is an [HttpBackend][crate::client::HttpBackend] (or
+//! [HttpBackendSync][crate::client::HttpBackendSync]) wrapped in a
+//! [BeatSaverClient][crate::client::BeatSaverClient], which implements the API on top of it.
 //!
 //! If only one backend is specified, it will be aliased to `BeatSaver`
+//!
+//! [DynBeatSaver][crate::client::DynBeatSaver] wraps any of the asynchronous backends, for the
+//! backend to be selected at runtime (e.g. from a configuration file).
+//! [AsyncAdapter][crate::client::AsyncAdapter] (`sync_adapter` feature) exposes synchronous
+//! clients through the asynchronous API.
+//!
+//! Backends are configured with [ClientOptions][crate::client::ClientOptions]:
+//! ```no_run
+//! # #[cfg(feature = "reqwest_backend")]
+//! # mod main {
+//! use beatsaver_rs::client::{BeatSaverReqwest, ClientOptions};
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let options = ClientOptions::new()
+//!     .with_connect_timeout(Duration::from_secs(5))
+//!     .with_read_timeout(Duration::from_secs(30));
+//! let client = BeatSaverReqwest::from_options(options)?;
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+use std::time::Duration;
+use url::Url;
+
+/// Options used by the backends to initialize their HTTP client
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientOptions {
+    /// Maximum time to establish a connection
+    ///
+    /// Note: Surf doesn't expose connection timeouts, it bounds the wait for the response instead
+    pub connect_timeout: Option<Duration>,
+    /// Maximum time to wait for the response, and for every chunk of its body
+    ///
+    /// Note: bounds every wait rather than the whole request, so large downloads don't time out as
+    /// long as data keeps coming
+    pub read_timeout: Option<Duration>,
+    /// Proxy every request is sent through (e.g. `http://proxy:8080` or `socks5://proxy:1080`)
+    ///
+    /// Note: SOCKS proxies require the `socks` feature of Reqwest or the `socks-proxy` feature of
+    /// ureq to be enabled, attohttpc only supports HTTP proxies and hyper doesn't support proxies
+    pub proxy: Option<Url>,
+    /// Uses the proxy configured in the environment (`HTTP_PROXY`, `HTTPS_PROXY`, `ALL_PROXY` and
+    /// `NO_PROXY`) if no proxy is set, enabled by default
+    pub proxy_from_env: bool,
+    /// Asks for gzip or deflate compressed responses and decompresses them, enabled by default
+    ///
+    /// Note: the curl based backends (isahc and Surf) also accept brotli if libcurl supports it
+    pub compression: bool,
+    /// How long idle connections are kept in the pool
+    ///
+    /// Note: connection pool options are used by the Reqwest and hyper backends, ureq only
+    /// supports `pool_max_idle_per_host` and attohttpc doesn't keep connections alive
+    pub pool_idle_timeout: Option<Duration>,
+    /// Maximum number of idle connections kept in the pool for each host
+    pub pool_max_idle_per_host: Option<usize>,
+    /// Interval of the TCP keep-alive probes sent on idle connections
+    pub tcp_keepalive: Option<Duration>,
+    /// Interval of the pings sent to keep HTTP/2 connections alive, even while idle
+    pub http2_keep_alive_interval: Option<Duration>,
+}
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            connect_timeout: None,
+            read_timeout: None,
+            proxy: None,
+            proxy_from_env: true,
+            compression: true,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive: None,
+            http2_keep_alive_interval: None,
+        }
+    }
+}
+impl ClientOptions {
+    /// Creates a new [ClientOptions][crate::client::ClientOptions] using the defaults of the backend
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the maximum time to establish a connection
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+    /// Sets the maximum time to wait for the response, and for every chunk of its body
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+    /// Sends every request through the provided proxy
+    pub fn with_proxy(mut self, proxy: Url) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+    /// Enables or disables the use of the proxy configured in the environment
+    pub fn with_proxy_from_env(mut self, proxy_from_env: bool) -> Self {
+        self.proxy_from_env = proxy_from_env;
+        self
+    }
+    /// Enables or disables compressed responses
+    pub fn with_compression(mut self, compression: bool) -> Self {
+        self.compression = compression;
+        self
+    }
+    /// Sets how long idle connections are kept in the pool
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+    /// Sets the maximum number of idle connections kept in the pool for each host
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+    /// Sets the interval of the TCP keep-alive probes
+    pub fn with_tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+    /// Sets the interval of the pings sent to keep HTTP/2 connections alive
+    pub fn with_http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+    /// Maximum time to wait for the response headers, including the time to connect
+    #[cfg(any(
+        feature = "reqwest_backend",
+        feature = "hyper_backend",
+        feature = "isahc_backend",
+        feature = "surf_backend"
+    ))]
+    fn response_timeout(&self) -> Option<Duration> {
+        self.read_timeout
+            .map(|t| t + self.connect_timeout.unwrap_or_default())
+    }
+}
 
-const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+/// Adds the trailing slash a base URL needs for relative paths to be joined onto it
+#[cfg(any(feature = "async", feature = "sync"))]
+fn base_url(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+        url.set_path(format!("{}/", url.path()).as_str());
+    }
+    url
+}
 
-#[cfg(feature = "reqwest_backend")]
-mod reqwest_client {
-    use super::USER_AGENT;
-    use crate::{rate_limit, BeatSaverApiAsync, BeatSaverApiError};
+/// HTTP transport of a [BeatSaverClient][crate::client::BeatSaverClient], for asynchronous clients
+///
+/// Backends only need to send requests, everything else (rate limits, retries, caching and error
+/// handling) is implemented once by the [BeatSaverApiAsync][crate::BeatSaverApiAsync] trait:
+/// ```
+/// # #[cfg(feature = "async")]
+/// # mod example {
+/// use async_trait::async_trait;
+/// use beatsaver_rs::client::{BeatSaverClient, HttpBackend};
+/// use beatsaver_rs::BeatSaverApiError;
+/// use bytes::Bytes;
+/// use http::{Request, Response};
+/// use std::fmt;
+///
+/// #[derive(Debug)]
+/// struct MyError;
+/// impl fmt::Display for MyError {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "request failed")
+///     }
+/// }
+/// impl std::error::Error for MyError {}
+/// impl From<MyError> for BeatSaverApiError<MyError> {
+///     fn from(e: MyError) -> Self {
+///         Self::RequestError(e)
+///     }
+/// }
+///
+/// struct MyBackend;
+/// #[async_trait]
+/// impl HttpBackend for MyBackend {
+///     type Error = MyError;
+///
+///     async fn send(
+///         &self,
+///         request: Request<Bytes>,
+///     ) -> Result<Response<Bytes>, BeatSaverApiError<MyError>> {
+///         // Hand the request to your HTTP library of choice here
+///         Err(MyError.into())
+///     }
+/// }
+///
+/// # fn main() {
+/// let client = BeatSaverClient::from_backend(MyBackend);
+/// # }
+/// # }
+/// ```
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait HttpBackend: Send + Sync {
+    /// Error type of the HTTP library
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Sends an HTTP request and returns the response, regardless of its status code
+    async fn send(
+        &self,
+        request: http::Request<bytes::Bytes>,
+    ) -> Result<http::Response<bytes::Bytes>, crate::BeatSaverApiError<Self::Error>>;
+    /// Sends an HTTP request and returns the response, streaming its body
+    ///
+    /// Backends that can't stream responses return the whole body as a single chunk.
+    async fn send_streaming<'a>(
+        &'a self,
+        request: http::Request<bytes::Bytes>,
+    ) -> Result<
+        http::Response<crate::ByteStream<'a, Self::Error>>,
+        crate::BeatSaverApiError<Self::Error>,
+    > {
+        use futures::{future, stream};
+
+        let response = self.send(request).await?;
+        Ok(response.map(|b| {
+            Box::pin(stream::once(future::ready(Ok(b)))) as crate::ByteStream<'a, Self::Error>
+        }))
+    }
+    /// Waits for the specified duration, see [sleep][crate::BeatSaverApiAsync::sleep]
+    async fn sleep(&self, duration: Duration) {
+        crate::async_api::thread_sleep(duration).await
+    }
+    /// Returns `true` if a request that failed with the error can succeed when sent again (e.g.
+    /// the connection failed or timed out), defaults to `false`
+    fn is_transient(&self, error: &Self::Error) -> bool {
+        let _ = error;
+        false
+    }
+}
+
+/// HTTP transport of a [BeatSaverClient][crate::client::BeatSaverClient], for synchronous clients
+///
+/// Like [HttpBackend][crate::client::HttpBackend], backends only need to send requests.
+#[cfg(feature = "sync")]
+pub trait HttpBackendSync {
+    /// Error type of the HTTP library
+    type Error: std::error::Error + 'static;
+
+    /// Sends an HTTP request and returns the response, regardless of its status code
+    fn send(
+        &self,
+        request: http::Request<bytes::Bytes>,
+    ) -> Result<http::Response<bytes::Bytes>, crate::BeatSaverApiError<Self::Error>>;
+    /// Sends an HTTP request and returns the response, with a reader streaming its body
+    ///
+    /// Backends that can't stream responses return a reader over the whole body.
+    fn send_streaming<'a>(
+        &'a self,
+        request: http::Request<bytes::Bytes>,
+    ) -> Result<http::Response<Box<dyn std::io::Read + 'a>>, crate::BeatSaverApiError<Self::Error>>
+    {
+        let response = self.send(request)?;
+        Ok(response.map(|b| Box::new(std::io::Cursor::new(b)) as Box<dyn std::io::Read + 'a>))
+    }
+    /// Waits for the specified duration, see [sleep][crate::BeatSaverApiSync::sleep]
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+    /// Returns `true` if a request that failed with the error can succeed when sent again (e.g.
+    /// the connection failed or timed out), defaults to `false`
+    fn is_transient(&self, error: &Self::Error) -> bool {
+        let _ = error;
+        false
+    }
+}
+
+#[cfg(any(feature = "async", feature = "sync"))]
+mod generic_client {
+    use crate::auth::Credentials;
+    use crate::cache::ResponseCache;
+    use crate::client::base_url;
+    #[cfg(feature = "async")]
+    use crate::client::HttpBackend;
+    #[cfg(feature = "sync")]
+    use crate::client::HttpBackendSync;
+    use crate::index::KeyIndex;
+    use crate::intercept::{intercept_request, intercept_response, Interceptor};
+    use crate::retry::RetryPolicy;
+    #[cfg(feature = "sync")]
+    use crate::BeatSaverApiSync;
+    #[cfg(feature = "async")]
+    use crate::{BeatSaverApiAsync, ByteStream};
+    use crate::{
+        BeatSaverApiError, DownloadRoute, RateLimitPolicy, RateLimitTracker, BEATSAVER_URL,
+    };
+    #[cfg(feature = "async")]
     use async_trait::async_trait;
     use bytes::Bytes;
-    use reqwest::Client;
-    use reqwest::StatusCode;
-    use std::convert::From;
+    use http::{Request, Response};
+    #[cfg(feature = "sync")]
+    use std::io::Read;
+    use std::sync::Arc;
+    use std::time::Duration;
     use url::Url;
 
-    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented for [Reqwest][reqwest]
+    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented on top of an HTTP backend
+    ///
+    /// Holds the configuration of the client (credentials, rate limit and retry policies, caches,
+    /// ...) and hands the requests to an [HttpBackend][crate::client::HttpBackend] (or
+    /// [HttpBackendSync][crate::client::HttpBackendSync] for synchronous clients). The clients
+    /// of this module (e.g. [BeatSaverReqwest][crate::client::BeatSaverReqwest]) are aliases of
+    /// this type.
     #[derive(Debug, Clone)]
-    pub struct BeatSaverReqwest {
-        client: Client,
+    pub struct BeatSaverClient<B> {
+        backend: B,
+        index: Option<Arc<KeyIndex>>,
+        credentials: Option<Credentials>,
+        rate_limit_policy: RateLimitPolicy,
+        rate_limit_tracker: Arc<RateLimitTracker>,
+        retry_policy: RetryPolicy,
+        download_route: DownloadRoute,
+        base_url: Url,
+        cache: Option<Arc<dyn ResponseCache>>,
+        interceptors: Vec<Arc<dyn Interceptor>>,
     }
-    impl BeatSaverReqwest {
-        /// Creates a new [BeatSaverReqwest][crate::client::BeatSaverReqwest] object, initiailizing a [Reqwest Client][reqwest::Client]
-        ///
-        /// Example:
-        /// ```no_run
-        /// use beatsaver_rs::client::BeatSaverReqwest;
-        ///
-        /// let client = BeatSaverReqwest::new();
-        /// ```
-        // TODO: Allow user to specify client
-        pub fn new() -> Self {
-            let client = Client::builder().user_agent(USER_AGENT).build().unwrap();
-            Self { client }
+    impl<B> BeatSaverClient<B> {
+        /// Creates a new [BeatSaverClient][crate::client::BeatSaverClient] sending requests with
+        /// the provided backend
+        pub fn from_backend(backend: B) -> Self {
+            Self {
+                backend,
+                index: None,
+                credentials: None,
+                rate_limit_policy: RateLimitPolicy::default(),
+                rate_limit_tracker: Arc::default(),
+                retry_policy: RetryPolicy::default(),
+                download_route: DownloadRoute::default(),
+                base_url: BEATSAVER_URL.clone(),
+                cache: None,
+                interceptors: vec![],
+            }
         }
-    }
-    impl From<Client> for BeatSaverReqwest {
-        fn from(client: Client) -> Self {
-            Self { client }
+        /// Returns the backend requests are sent with
+        pub fn backend(&self) -> &B {
+            &self.backend
         }
-    }
-    impl From<reqwest::Error> for BeatSaverApiError<reqwest::Error> {
-        fn from(e: reqwest::Error) -> Self {
-            Self::RequestError(e)
+        /// Attaches a [KeyIndex][crate::index::KeyIndex] that records every map retrieved by this client
+        pub fn with_key_index(mut self, index: Arc<KeyIndex>) -> Self {
+            self.index = Some(index);
+            self
+        }
+        /// Attaches [Credentials][crate::auth::Credentials] that are sent with every request
+        pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+            self.credentials = Some(credentials);
+            self
+        }
+        /// Sets how requests that hit a rate limit are handled
+        pub fn with_rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+            self.rate_limit_policy = policy;
+            self
+        }
+        /// Records the rate limit quota in the provided [RateLimitTracker][crate::RateLimitTracker],
+        /// e.g. to share it with other clients
+        pub fn with_rate_limit_tracker(mut self, tracker: Arc<RateLimitTracker>) -> Self {
+            self.rate_limit_tracker = tracker;
+            self
+        }
+        /// Sets how requests that fail due to transient errors are retried
+        pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+            self.retry_policy = policy;
+            self
+        }
+        /// Sets the route map archives are downloaded through
+        pub fn with_download_route(mut self, route: DownloadRoute) -> Self {
+            self.download_route = route;
+            self
+        }
+        /// Sends requests to the provided base URL instead of [BEATSAVER_URL][crate::BEATSAVER_URL],
+        /// e.g. to use a self-hosted instance or a mock server
+        pub fn with_base_url(mut self, url: Url) -> Self {
+            self.base_url = base_url(url);
+            self
+        }
+        /// Attaches a [ResponseCache][crate::cache::ResponseCache] used to revalidate responses
+        /// instead of downloading them again
+        pub fn with_response_cache(mut self, cache: Arc<dyn ResponseCache>) -> Self {
+            self.cache = Some(cache);
+            self
+        }
+        /// Attaches an [Interceptor][crate::intercept::Interceptor] called around every request,
+        /// after the interceptors already attached
+        pub fn with_interceptor(mut self, interceptor: Arc<dyn Interceptor>) -> Self {
+            self.interceptors.push(interceptor);
+            self
+        }
+        /// Returns the attached [ResponseCache][crate::cache::ResponseCache], for websocket events
+        /// to evict maps from
+        #[cfg(any(feature = "ws", feature = "ws_async_std"))]
+        pub(crate) fn shared_response_cache(&self) -> Option<&Arc<dyn ResponseCache>> {
+            self.cache.as_ref()
         }
     }
-    #[async_trait]
-    impl<'a> BeatSaverApiAsync<'a, reqwest::Error> for BeatSaverReqwest {
-        async fn request_raw(
+    #[cfg(feature = "async")]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl<B: HttpBackend> BeatSaverApiAsync<B::Error> for BeatSaverClient<B>
+    where
+        BeatSaverApiError<B::Error>: From<B::Error>,
+    {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            self.index.as_deref()
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            self.credentials.as_ref()
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            Some(&self.rate_limit_tracker)
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.retry_policy
+        }
+        fn is_transient(&self, error: &BeatSaverApiError<B::Error>) -> bool {
+            match error {
+                BeatSaverApiError::RequestError(e) => self.backend.is_transient(e),
+                e => e.is_retryable(),
+            }
+        }
+        fn download_route(&self) -> DownloadRoute {
+            self.download_route
+        }
+        fn base_url(&self) -> &Url {
+            &self.base_url
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.cache.as_deref()
+        }
+        async fn sleep(&self, duration: Duration) {
+            self.backend.sleep(duration).await
+        }
+        async fn send(
+            &self,
+            mut request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<B::Error>> {
+            let uri = intercept_request(&self.interceptors, &mut request);
+            let response = self.backend.send(request).await?;
+            Ok(intercept_response(&self.interceptors, &uri, response))
+        }
+        async fn send_streaming<'a>(
             &'a self,
-            url: Url,
-        ) -> Result<Bytes, BeatSaverApiError<reqwest::Error>> {
-            let resp = self.client.get(url).send().await?;
-            let status = resp.status();
-            let data = resp.bytes().await?;
-
-            match status {
-                StatusCode::TOO_MANY_REQUESTS => Err(rate_limit(data)),
-                _ => Ok(data),
+            mut request: Request<Bytes>,
+        ) -> Result<Response<ByteStream<'a, B::Error>>, BeatSaverApiError<B::Error>> {
+            let uri = intercept_request(&self.interceptors, &mut request);
+            let response = self.backend.send_streaming(request).await?;
+            Ok(intercept_response(&self.interceptors, &uri, response))
+        }
+    }
+    #[cfg(feature = "sync")]
+    impl<B: HttpBackendSync> BeatSaverApiSync<B::Error> for BeatSaverClient<B>
+    where
+        BeatSaverApiError<B::Error>: From<B::Error>,
+    {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            self.index.as_deref()
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            self.credentials.as_ref()
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            Some(&self.rate_limit_tracker)
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.retry_policy
+        }
+        fn is_transient(&self, error: &BeatSaverApiError<B::Error>) -> bool {
+            match error {
+                BeatSaverApiError::RequestError(e) => self.backend.is_transient(e),
+                e => e.is_retryable(),
             }
         }
+        fn download_route(&self) -> DownloadRoute {
+            self.download_route
+        }
+        fn base_url(&self) -> &Url {
+            &self.base_url
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.cache.as_deref()
+        }
+        fn sleep(&self, duration: Duration) {
+            self.backend.sleep(duration)
+        }
+        fn send(
+            &self,
+            mut request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<B::Error>> {
+            let uri = intercept_request(&self.interceptors, &mut request);
+            let response = self.backend.send(request)?;
+            Ok(intercept_response(&self.interceptors, &uri, response))
+        }
+        fn send_streaming<'a>(
+            &'a self,
+            mut request: Request<Bytes>,
+        ) -> Result<Response<Box<dyn Read + 'a>>, BeatSaverApiError<B::Error>> {
+            let uri = intercept_request(&self.interceptors, &mut request);
+            let response = self.backend.send_streaming(request)?;
+            Ok(intercept_response(&self.interceptors, &uri, response))
+        }
     }
 }
-#[cfg(feature = "reqwest_backend")]
-pub use reqwest_client::BeatSaverReqwest;
-#[cfg(all(
-    feature = "reqwest_backend",
-    not(feature = "surf_backend"),
-    not(feature = "ureq_backend")
-))]
-pub use reqwest_client::BeatSaverReqwest as BeatSaver;
+#[cfg(any(feature = "async", feature = "sync"))]
+pub use generic_client::BeatSaverClient;
 
-#[cfg(feature = "surf_backend")]
-mod surf_client {
-    use super::USER_AGENT;
-    use crate::{rate_limit, BeatSaverApiAsync, BeatSaverApiError};
-    use async_trait::async_trait;
-    use bytes::Bytes;
-    use std::convert::From;
-    use std::error::Error;
-    use std::fmt::{self, Display, Formatter};
-    use surf::{Client, StatusCode};
-    use url::Url;
+/// Decompresses response bodies for backends that don't handle `Content-Encoding` themselves
+#[cfg(any(feature = "reqwest_backend", feature = "hyper_backend"))]
+enum Decoder {
+    Gzip(flate2::write::GzDecoder<Vec<u8>>),
+    Deflate(flate2::write::ZlibDecoder<Vec<u8>>),
+}
+#[cfg(any(feature = "reqwest_backend", feature = "hyper_backend"))]
+impl Decoder {
+    /// Returns a decoder for the encoding of a response, and removes the headers that don't apply
+    /// to the decoded body
+    fn from_headers(headers: &mut http::HeaderMap) -> Option<Self> {
+        use http::header::{CONTENT_ENCODING, CONTENT_LENGTH};
 
-    /// [Error][std::error::Error] wrapper type for [surf::Error]
-    #[derive(Debug)]
-    pub enum SurfError {
-        /// Surf error
-        Error(surf::Error),
+        let encoding = headers.get(CONTENT_ENCODING)?.to_str().ok()?.trim();
+        let decoder = match encoding.to_ascii_lowercase().as_str() {
+            "gzip" | "x-gzip" => Self::Gzip(flate2::write::GzDecoder::new(vec![])),
+            "deflate" => Self::Deflate(flate2::write::ZlibDecoder::new(vec![])),
+            _ => return None,
+        };
+        headers.remove(CONTENT_ENCODING);
+        headers.remove(CONTENT_LENGTH);
+        Some(decoder)
     }
-    impl Display for SurfError {
-        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-            match self {
-                Self::Error(e) => e.fmt(f),
+    /// Decodes a chunk of the body, returning the data decoded so far
+    fn decode(&mut self, chunk: &[u8]) -> std::io::Result<bytes::Bytes> {
+        use std::io::Write;
+
+        let output = match self {
+            Self::Gzip(d) => {
+                d.write_all(chunk)?;
+                d.get_mut()
+            }
+            Self::Deflate(d) => {
+                d.write_all(chunk)?;
+                d.get_mut()
             }
+        };
+        Ok(std::mem::take(output).into())
+    }
+    /// Decodes the end of the body, failing if it is truncated
+    fn finish(self) -> std::io::Result<bytes::Bytes> {
+        match self {
+            Self::Gzip(d) => d.finish(),
+            Self::Deflate(d) => d.finish(),
         }
+        .map(bytes::Bytes::from)
     }
-    impl Error for SurfError {}
-    impl From<surf::Error> for SurfError {
-        fn from(e: surf::Error) -> Self {
-            Self::Error(e)
+}
+
+/// Decodes a whole response body according to its headers
+#[cfg(any(feature = "reqwest_backend", feature = "hyper_backend"))]
+fn decode_body(headers: &mut http::HeaderMap, body: Vec<u8>) -> std::io::Result<bytes::Bytes> {
+    match Decoder::from_headers(headers) {
+        Some(mut decoder) => {
+            let mut data = decoder.decode(&body)?.to_vec();
+            data.extend_from_slice(&decoder.finish()?);
+            Ok(data.into())
         }
+        None => Ok(body.into()),
     }
-    impl From<SurfError> for BeatSaverApiError<SurfError> {
-        fn from(e: SurfError) -> Self {
-            Self::RequestError(e)
+}
+
+/// Asks for a compressed response, unless the request already specifies an encoding
+#[cfg(any(feature = "reqwest_backend", feature = "hyper_backend"))]
+fn accept_encoding(
+    mut request: http::Request<bytes::Bytes>,
+    options: &ClientOptions,
+) -> http::Request<bytes::Bytes> {
+    use http::header::{HeaderValue, ACCEPT_ENCODING};
+
+    if options.compression {
+        request
+            .headers_mut()
+            .entry(ACCEPT_ENCODING)
+            .or_insert(HeaderValue::from_static("gzip, deflate"));
+    }
+    request
+}
+
+/// Decodes the chunks of a response body with the provided decoder, if any
+#[cfg(any(feature = "reqwest_backend", feature = "hyper_backend"))]
+fn decode_stream<'a, S, E>(body: S, decoder: Option<Decoder>) -> crate::ByteStream<'a, E>
+where
+    S: futures::Stream<Item = Result<bytes::Bytes, crate::BeatSaverApiError<E>>> + Send + 'a,
+    E: std::fmt::Display + Send + 'a,
+{
+    use futures::{stream, TryStreamExt};
+
+    let decoder = match decoder {
+        Some(decoder) => decoder,
+        None => return Box::pin(body),
+    };
+    let body = Box::pin(body);
+    Box::pin(stream::try_unfold(
+        (body, Some(decoder)),
+        |(mut body, mut decoder)| async move {
+            while let Some(d) = decoder.as_mut() {
+                let data = match body.try_next().await? {
+                    Some(chunk) => d.decode(&chunk)?,
+                    None => decoder.take().unwrap().finish()?,
+                };
+                if !data.is_empty() {
+                    return Ok(Some((data, (body, decoder))));
+                }
+            }
+            Ok(None)
+        },
+    ))
+}
+
+/// Proxy schemes supported by curl, which isahc is built on
+#[cfg(any(feature = "isahc_backend", feature = "surf_backend"))]
+const CURL_PROXY_SCHEMES: &[&str] = &["http", "https", "socks4", "socks4a", "socks5", "socks5h"];
+
+/// Returns `true` if an isahc request failed due to the connection rather than the request
+#[cfg(any(feature = "isahc_backend", feature = "surf_backend"))]
+fn isahc_is_transient(error: &isahc::Error) -> bool {
+    match error {
+        isahc::Error::ConnectFailed | isahc::Error::NoResponse | isahc::Error::Timeout => true,
+        isahc::Error::Io(e) => crate::retry::is_transient_io(e),
+        _ => false,
+    }
+}
+
+/// Creates an [isahc HttpClientBuilder][isahc::HttpClientBuilder] using the proxy and compression
+/// options
+///
+/// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy isn't a
+/// valid URI or its scheme isn't supported
+#[cfg(any(feature = "isahc_backend", feature = "surf_backend"))]
+fn isahc_builder(
+    options: &ClientOptions,
+) -> Result<isahc::HttpClientBuilder, crate::BeatSaverError> {
+    use crate::BeatSaverApiError;
+    use isahc::config::Configurable;
+
+    let mut builder = isahc::HttpClient::builder().automatic_decompression(options.compression);
+    if let Some(proxy) = &options.proxy {
+        if !CURL_PROXY_SCHEMES.contains(&proxy.scheme()) {
+            return Err(BeatSaverApiError::ArgumentError("proxy"));
         }
+        let proxy: http::Uri = proxy
+            .as_str()
+            .parse()
+            .map_err(|_| BeatSaverApiError::ArgumentError("proxy"))?;
+        builder = builder.proxy(proxy);
+    } else if !options.proxy_from_env {
+        builder = builder.proxy(None);
     }
-    impl From<surf::Error> for BeatSaverApiError<SurfError> {
-        fn from(e: surf::Error) -> Self {
-            Self::RequestError(e.into())
+    Ok(builder)
+}
+
+#[cfg(feature = "reqwest_backend")]
+mod reqwest_client {
+    use crate::client::{
+        accept_encoding, decode_body, decode_stream, BeatSaverClient, ClientOptions, Decoder,
+        HttpBackend,
+    };
+    use crate::{BackendError, BeatSaverApiError, BeatSaverError, ByteStream};
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use futures::{stream, Future};
+    use http::{Request, Response};
+    use reqwest::Client;
+    use std::convert::{From, TryInto};
+    use std::io;
+    use std::time::Duration;
+
+    /// Fails with a [TimedOut][std::io::ErrorKind::TimedOut] error if the future doesn't complete in time
+    async fn timeout<F: Future>(timeout: Option<Duration>, future: F) -> io::Result<F::Output> {
+        match timeout {
+            Some(t) => tokio::time::timeout(t, future)
+                .await
+                .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "request timed out")),
+            None => Ok(future.await),
         }
     }
 
-    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented for [Surf][surf]
+    /// [HttpBackend][crate::client::HttpBackend] implemented for [Reqwest][reqwest]
     #[derive(Debug, Clone)]
-    pub struct BeatSaverSurf {
+    pub struct ReqwestBackend {
         client: Client,
+        options: ClientOptions,
     }
-    impl BeatSaverSurf {
-        /// Creates a new [BeatSaverSurf][crate::client::BeatSaverSurf] object, initiailizing a [Surf Client][surf::Client]
-        ///
-        /// Example:
-        /// ```no_run
-        /// use beatsaver_rs::client::BeatSaverSurf;
+    impl ReqwestBackend {
+        /// Creates a new [ReqwestBackend][crate::client::ReqwestBackend], initializing a
+        /// [Reqwest Client][reqwest::Client] with the provided options
         ///
-        /// let client = BeatSaverSurf::new();
-        /// ```
-        // TODO: Allow user to specify client
-        pub fn new() -> Self {
-            let client = Client::new();
-            Self { client }
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            let mut builder = Client::builder();
+            if let Some(timeout) = options.connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+            if let Some(timeout) = options.pool_idle_timeout {
+                builder = builder.pool_idle_timeout(timeout);
+            }
+            if let Some(max) = options.pool_max_idle_per_host {
+                builder = builder.pool_max_idle_per_host(max);
+            }
+            if let Some(interval) = options.http2_keep_alive_interval {
+                builder = builder
+                    .http2_keep_alive_interval(interval)
+                    .http2_keep_alive_while_idle(true);
+            }
+            builder = builder.tcp_keepalive(options.tcp_keepalive);
+            if let Some(proxy) = &options.proxy {
+                let proxy = reqwest::Proxy::all(proxy.clone())
+                    .map_err(|_| BeatSaverApiError::ArgumentError("proxy"))?;
+                builder = builder.proxy(proxy);
+            } else if !options.proxy_from_env {
+                builder = builder.no_proxy();
+            }
+            let client = builder.build().map_err(BackendError::new)?;
+            Ok(Self { client, options })
         }
     }
-    impl From<Client> for BeatSaverSurf {
+    impl From<Client> for ReqwestBackend {
         fn from(client: Client) -> Self {
-            Self { client }
+            Self {
+                client,
+                options: ClientOptions::default(),
+            }
         }
     }
-    #[async_trait]
-    impl<'a> BeatSaverApiAsync<'a, SurfError> for BeatSaverSurf {
-        async fn request_raw(&'a self, url: Url) -> Result<Bytes, BeatSaverApiError<SurfError>> {
-            let mut resp = self
-                .client
-                .get(url)
-                .header("User-Agent", USER_AGENT)
-                .await?;
-            let data = resp.body_bytes().await?.into();
-            match resp.status() {
-                StatusCode::TooManyRequests => Err(rate_limit(data)),
-                _ => Ok(data),
-            }
+    impl From<reqwest::Error> for BeatSaverApiError<reqwest::Error> {
+        fn from(e: reqwest::Error) -> Self {
+            Self::RequestError(e)
         }
     }
-}
-#[cfg(feature = "surf_backend")]
-pub use surf_client::BeatSaverSurf;
-#[cfg(all(
-    feature = "surf_backend",
-    not(feature = "reqwest_backend"),
-    not(feature = "ureq_backend")
-))]
-pub use surf_client::BeatSaverSurf as BeatSaver;
+    #[async_trait]
+    impl HttpBackend for ReqwestBackend {
+        type Error = reqwest::Error;
 
-#[cfg(feature = "ureq_backend")]
-mod ureq_client {
-    use super::USER_AGENT;
-    use crate::{rate_limit, BeatSaverApiError, BeatSaverApiSync};
-    use bytes::Bytes;
-    use std::convert::From;
-    use std::io::Read;
-    use ureq;
-    use url::Url;
+        async fn sleep(&self, duration: Duration) {
+            tokio::time::sleep(duration).await
+        }
+        fn is_transient(&self, error: &reqwest::Error) -> bool {
+            error.is_connect() || error.is_timeout()
+        }
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<reqwest::Error>> {
+            let request = accept_encoding(request, &self.options);
+            let request = self.client.execute(request.try_into()?);
+            let mut resp = timeout(self.options.response_timeout(), request).await??;
+            let status = resp.status();
+            let mut headers = resp.headers().clone();
 
-    impl From<ureq::Error> for BeatSaverApiError<ureq::Error> {
-        fn from(e: ureq::Error) -> Self {
-            Self::RequestError(e)
+            let mut body = vec![];
+            while let Some(chunk) = timeout(self.options.read_timeout, resp.chunk()).await?? {
+                body.extend_from_slice(chunk.as_ref());
+            }
+            let mut response = Response::new(decode_body(&mut headers, body)?);
+            *response.status_mut() = status;
+            *response.headers_mut() = headers;
+            Ok(response)
+        }
+        async fn send_streaming<'a>(
+            &'a self,
+            request: Request<Bytes>,
+        ) -> Result<Response<ByteStream<'a, reqwest::Error>>, BeatSaverApiError<reqwest::Error>>
+        {
+            let request = accept_encoding(request, &self.options);
+            let request = self.client.execute(request.try_into()?);
+            let resp = timeout(self.options.response_timeout(), request).await??;
+            let status = resp.status();
+            let mut headers = resp.headers().clone();
+            let decoder = Decoder::from_headers(&mut headers);
+
+            let read_timeout = self.options.read_timeout;
+            let body = stream::try_unfold(resp, move |mut resp| async move {
+                let chunk = timeout(read_timeout, resp.chunk()).await??;
+                Ok(chunk.map(|chunk| (chunk, resp)))
+            });
+            let mut response = Response::new(decode_stream(body, decoder));
+            *response.status_mut() = status;
+            *response.headers_mut() = headers;
+            Ok(response)
         }
     }
 
-    /// [BeatSaverApi][crate::BeatSaverApiSync] implemented for [ureq]
-    #[derive(Debug)]
-    pub struct BeatSaverUreq {}
-    impl BeatSaverUreq {
-        /// Creates a new [BeatSaverUreq][crate::client::BeatSaverUreq] object
+    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented for [Reqwest][reqwest]
+    pub type BeatSaverReqwest = BeatSaverClient<ReqwestBackend>;
+    impl BeatSaverReqwest {
+        /// Creates a new [BeatSaverReqwest][crate::client::BeatSaverReqwest] object, initiailizing a [Reqwest Client][reqwest::Client]
         ///
         /// Example:
         /// ```no_run
-        /// use beatsaver_rs::client::BeatSaverUreq;
+        /// use beatsaver_rs::client::BeatSaverReqwest;
         ///
-        /// let client = BeatSaverUreq::new();
+        /// let client = BeatSaverReqwest::new();
         /// ```
-        // TODO: Allow user to specify client
         pub fn new() -> Self {
-            Self {}
+            Self::from_options(ClientOptions::new())
+                .expect("failed to initialize the reqwest client")
+        }
+        /// Creates a new [BeatSaverReqwest][crate::client::BeatSaverReqwest] object, initializing a
+        /// [Reqwest Client][reqwest::Client] with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            Ok(Self::from_backend(ReqwestBackend::from_options(options)?))
         }
     }
-    impl<'a> BeatSaverApiSync<'a, ureq::Error> for BeatSaverUreq {
-        fn request_raw(&'a self, url: Url) -> Result<Bytes, BeatSaverApiError<ureq::Error>> {
-            let mut contents = vec![];
-            match ureq::get(url.as_str()).set("User-Agent", USER_AGENT).call() {
-                Ok(resp) => {
-                    let mut reader = resp.into_reader();
-                    reader.read_to_end(&mut contents)?;
-                    Ok(contents.into())
-                }
-                Err(ureq::Error::Status(code, resp)) => {
-                    let mut reader = resp.into_reader();
-                    reader.read_to_end(&mut contents)?;
-                    match code {
-                        429 => Err(rate_limit(contents.into())),
-                        // TODO: req doesn't have an error type for HTTP errors, might need
-                        // to do some extra checks with the http crate in the future
-                        _ => Ok(contents.into()),
-                    }
-                }
-                Err(e) => {
-                    Err(e.into())
-                }
-            }
+    impl Default for BeatSaverReqwest {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl From<Client> for BeatSaverReqwest {
+        fn from(client: Client) -> Self {
+            Self::from_backend(client.into())
         }
     }
 }
-#[cfg(feature = "ureq_backend")]
-pub use ureq_client::BeatSaverUreq;
 #[cfg(all(
-    feature = "ureq_backend",
-    not(feature = "reqwest_backend"),
-    not(feature = "surf_backend")
+    feature = "reqwest_backend",
+    not(feature = "hyper_backend"),
+    not(feature = "isahc_backend"),
+    not(feature = "surf_backend"),
+    not(feature = "ureq_backend"),
+    not(feature = "attohttpc_backend")
 ))]
-pub use ureq_client::BeatSaverUreq as BeatSaver;
+pub use reqwest_client::BeatSaverReqwest as BeatSaver;
+#[cfg(feature = "reqwest_backend")]
+pub use reqwest_client::{BeatSaverReqwest, ReqwestBackend};
 
-#[cfg(test)]
-mod tests {
-    #[cfg(feature = "surf_backend")]
-    #[async_std::test]
-    async fn test_surf_map() {
-        use crate::client::BeatSaverSurf;
-        use crate::BeatSaverApiAsync;
-        use std::convert::TryInto;
+#[cfg(feature = "blocking")]
+mod blocking_client {
+    use crate::client::{
+        BeatSaverClient, ClientOptions, HttpBackend, HttpBackendSync, ReqwestBackend,
+    };
+    use crate::{BeatSaverApiError, BeatSaverError, ByteStream};
+    use bytes::{Buf, Bytes};
+    use futures::{Stream, StreamExt};
+    use http::{Request, Response};
+    use std::io::{self, Read};
+    use std::sync::Arc;
+    use tokio::runtime::Runtime;
 
-        let client = BeatSaverSurf::new();
-        let map = client.map(&"2144".try_into().unwrap()).await.unwrap();
+    /// [HttpBackendSync][crate::client::HttpBackendSync] sending requests with a
+    /// [ReqwestBackend][crate::client::ReqwestBackend] on an internal Tokio runtime
+    ///
+    /// Note: requests block the calling thread, which panics if it is driving an asynchronous
+    /// runtime itself
+    #[derive(Debug, Clone)]
+    pub struct BlockingBackend {
+        backend: ReqwestBackend,
+        runtime: Arc<Runtime>,
+    }
+    impl BlockingBackend {
+        /// Creates a new [BlockingBackend][crate::client::BlockingBackend], starting a runtime
+        /// with a single worker thread
+        ///
+        /// Fails with an [IoError][crate::BeatSaverApiError::IoError] if the runtime can't be
+        /// started, or an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            let backend = ReqwestBackend::from_options(options)?;
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(1)
+                .enable_all()
+                .build()?;
+            Ok(Self::new(backend, Arc::new(runtime)))
+        }
+        /// Creates a new [BlockingBackend][crate::client::BlockingBackend] sending requests with
+        /// the provided backend on the provided runtime
+        pub fn new(backend: ReqwestBackend, runtime: Arc<Runtime>) -> Self {
+            Self { backend, runtime }
+        }
+        /// Returns the runtime requests are sent on
+        pub fn runtime(&self) -> &Arc<Runtime> {
+            &self.runtime
+        }
+    }
+    impl HttpBackendSync for BlockingBackend {
+        type Error = reqwest::Error;
+
+        fn is_transient(&self, error: &reqwest::Error) -> bool {
+            self.backend.is_transient(error)
+        }
+        fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<reqwest::Error>> {
+            self.runtime.block_on(self.backend.send(request))
+        }
+        fn send_streaming<'a>(
+            &'a self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Box<dyn Read + 'a>>, BeatSaverApiError<reqwest::Error>> {
+            let response = self
+                .runtime
+                .block_on(self.backend.send_streaming(request))?;
+            Ok(response.map(|body| {
+                Box::new(StreamReader {
+                    runtime: &self.runtime,
+                    body,
+                    chunk: Bytes::new(),
+                }) as Box<dyn Read + 'a>
+            }))
+        }
+    }
+
+    /// [Read][std::io::Read] implementation over a streamed response body
+    struct StreamReader<'a> {
+        runtime: &'a Runtime,
+        body: ByteStream<'a, reqwest::Error>,
+        chunk: Bytes,
+    }
+    impl<'a> Read for StreamReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            while self.chunk.is_empty() {
+                match self.runtime.block_on(self.body.next()) {
+                    Some(Ok(chunk)) => self.chunk = chunk,
+                    Some(Err(BeatSaverApiError::IoError(e))) => return Err(e),
+                    Some(Err(e)) => return Err(io::Error::other(e)),
+                    None => return Ok(0),
+                }
+            }
+            let n = buf.len().min(self.chunk.len());
+            buf[..n].copy_from_slice(&self.chunk[..n]);
+            self.chunk.advance(n);
+            Ok(n)
+        }
+    }
+
+    /// [Iterator] over a [Stream][futures::Stream], polled on the runtime of a
+    /// [BeatSaverBlocking][crate::client::BeatSaverBlocking]
+    pub struct BlockingStream<S> {
+        runtime: Arc<Runtime>,
+        stream: S,
+    }
+    impl<S> BlockingStream<S> {
+        /// Creates an iterator polling the stream on the provided runtime
+        pub fn new(runtime: Arc<Runtime>, stream: S) -> Self {
+            Self { runtime, stream }
+        }
+    }
+    impl<S: Stream + Unpin> Iterator for BlockingStream<S> {
+        type Item = S::Item;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.runtime.block_on(self.stream.next())
+        }
+    }
+
+    /// [BeatSaverApi][crate::BeatSaverApiSync] implemented over [Reqwest][reqwest], for
+    /// synchronous applications
+    ///
+    /// Requests are sent by a [BeatSaverReqwest][crate::client::BeatSaverReqwest] backend on an
+    /// internal Tokio runtime, so the client behaves like the asynchronous one (connection pooling,
+    /// compression, timeouts) without requiring a runtime. See
+    /// [BlockingBackend][crate::client::BlockingBackend] for the restrictions.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # mod main {
+    /// use beatsaver_rs::client::BeatSaverBlocking;
+    /// use beatsaver_rs::BeatSaverApiSync;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = BeatSaverBlocking::new();
+    /// let map = client.map(&"2144".parse()?)?;
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub type BeatSaverBlocking = BeatSaverClient<BlockingBackend>;
+    impl BeatSaverBlocking {
+        /// Creates a new [BeatSaverBlocking][crate::client::BeatSaverBlocking] object, starting its
+        /// runtime
+        pub fn new() -> Self {
+            Self::from_options(ClientOptions::new()).expect("failed to start the blocking client")
+        }
+        /// Creates a new [BeatSaverBlocking][crate::client::BeatSaverBlocking] object, initializing
+        /// a [Reqwest Client][reqwest::Client] with the provided options
+        ///
+        /// Fails if the runtime can't be started, or if the proxy scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            Ok(Self::from_backend(BlockingBackend::from_options(options)?))
+        }
+    }
+    impl Default for BeatSaverBlocking {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+#[cfg(feature = "blocking")]
+pub use blocking_client::{BeatSaverBlocking, BlockingBackend, BlockingStream};
+
+#[cfg(feature = "hyper_backend")]
+mod hyper_client {
+    use crate::client::{
+        accept_encoding, decode_body, decode_stream, BeatSaverClient, ClientOptions, Decoder,
+        HttpBackend,
+    };
+    use crate::{BeatSaverApiError, ByteStream};
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use futures::{stream, Future};
+    use http::{Request, Response};
+    use hyper::body::HttpBody;
+    use hyper::client::HttpConnector;
+    use hyper::{Body, Client};
+    use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+    use std::convert::From;
+    use std::io;
+    use std::time::Duration;
+
+    /// Fails with a [TimedOut][std::io::ErrorKind::TimedOut] error if the future doesn't complete in time
+    async fn timeout<F: Future>(timeout: Option<Duration>, future: F) -> io::Result<F::Output> {
+        match timeout {
+            Some(t) => tokio::time::timeout(t, future)
+                .await
+                .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "request timed out")),
+            None => Ok(future.await),
+        }
+    }
+
+    /// [HttpBackend][crate::client::HttpBackend] implemented for [hyper], with TLS handled by
+    /// [rustls][hyper_rustls] and the Mozilla root certificates
+    ///
+    /// Note: hyper doesn't support proxies, the proxy options are ignored
+    #[derive(Debug, Clone)]
+    pub struct HyperBackend {
+        client: Client<HttpsConnector<HttpConnector>>,
+        options: ClientOptions,
+    }
+    impl HyperBackend {
+        /// Creates a new [HyperBackend][crate::client::HyperBackend], initializing a
+        /// [hyper Client][hyper::Client] with the provided options
+        pub fn from_options(options: ClientOptions) -> Self {
+            let mut http = HttpConnector::new();
+            http.enforce_http(false);
+            http.set_connect_timeout(options.connect_timeout);
+            http.set_keepalive(options.tcp_keepalive);
+            let mut builder = Client::builder();
+            if let Some(timeout) = options.pool_idle_timeout {
+                builder.pool_idle_timeout(timeout);
+            }
+            if let Some(max) = options.pool_max_idle_per_host {
+                builder.pool_max_idle_per_host(max);
+            }
+            if let Some(interval) = options.http2_keep_alive_interval {
+                builder
+                    .http2_keep_alive_interval(interval)
+                    .http2_keep_alive_while_idle(true);
+            }
+            let https = HttpsConnectorBuilder::new()
+                .with_webpki_roots()
+                .https_or_http()
+                .enable_http1()
+                .enable_http2()
+                .wrap_connector(http);
+            let client = builder.build(https);
+            Self { client, options }
+        }
+        /// Sends a request, waiting for the response headers
+        async fn response(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Body>, BeatSaverApiError<hyper::Error>> {
+            let request = accept_encoding(request, &self.options);
+            let response = self.client.request(request.map(Body::from));
+            Ok(timeout(self.options.response_timeout(), response).await??)
+        }
+    }
+    impl From<Client<HttpsConnector<HttpConnector>>> for HyperBackend {
+        fn from(client: Client<HttpsConnector<HttpConnector>>) -> Self {
+            Self {
+                client,
+                options: ClientOptions::default(),
+            }
+        }
+    }
+    impl From<hyper::Error> for BeatSaverApiError<hyper::Error> {
+        fn from(e: hyper::Error) -> Self {
+            Self::RequestError(e)
+        }
+    }
+    #[async_trait]
+    impl HttpBackend for HyperBackend {
+        type Error = hyper::Error;
+
+        async fn sleep(&self, duration: Duration) {
+            tokio::time::sleep(duration).await
+        }
+        fn is_transient(&self, error: &hyper::Error) -> bool {
+            error.is_connect() || error.is_timeout() || error.is_incomplete_message()
+        }
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<hyper::Error>> {
+            let (mut parts, mut body) = self.response(request).await?.into_parts();
+
+            let mut data = vec![];
+            while let Some(chunk) = timeout(self.options.read_timeout, body.data()).await? {
+                data.extend_from_slice(chunk?.as_ref());
+            }
+            let data = decode_body(&mut parts.headers, data)?;
+            Ok(Response::from_parts(parts, data))
+        }
+        async fn send_streaming<'a>(
+            &'a self,
+            request: Request<Bytes>,
+        ) -> Result<Response<ByteStream<'a, hyper::Error>>, BeatSaverApiError<hyper::Error>>
+        {
+            let (mut parts, body) = self.response(request).await?.into_parts();
+            let decoder = Decoder::from_headers(&mut parts.headers);
+
+            let read_timeout = self.options.read_timeout;
+            let body = stream::try_unfold(body, move |mut body| async move {
+                match timeout(read_timeout, body.data()).await? {
+                    Some(chunk) => Ok(Some((chunk?, body))),
+                    None => Ok(None),
+                }
+            });
+            Ok(Response::from_parts(parts, decode_stream(body, decoder)))
+        }
+    }
+
+    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented for [hyper]
+    ///
+    /// Note: hyper doesn't support proxies, the proxy options are ignored
+    pub type BeatSaverHyper = BeatSaverClient<HyperBackend>;
+    impl BeatSaverHyper {
+        /// Creates a new [BeatSaverHyper][crate::client::BeatSaverHyper] object, initiailizing a [hyper Client][hyper::Client]
+        ///
+        /// Example:
+        /// ```no_run
+        /// use beatsaver_rs::client::BeatSaverHyper;
+        ///
+        /// let client = BeatSaverHyper::new();
+        /// ```
+        pub fn new() -> Self {
+            Self::from_options(ClientOptions::new())
+        }
+        /// Creates a new [BeatSaverHyper][crate::client::BeatSaverHyper] object, initializing a
+        /// [hyper Client][hyper::Client] with the provided options
+        pub fn from_options(options: ClientOptions) -> Self {
+            Self::from_backend(HyperBackend::from_options(options))
+        }
+    }
+    impl Default for BeatSaverHyper {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl From<Client<HttpsConnector<HttpConnector>>> for BeatSaverHyper {
+        fn from(client: Client<HttpsConnector<HttpConnector>>) -> Self {
+            Self::from_backend(client.into())
+        }
+    }
+}
+#[cfg(all(
+    feature = "hyper_backend",
+    not(feature = "reqwest_backend"),
+    not(feature = "isahc_backend"),
+    not(feature = "surf_backend"),
+    not(feature = "ureq_backend"),
+    not(feature = "attohttpc_backend")
+))]
+pub use hyper_client::BeatSaverHyper as BeatSaver;
+#[cfg(feature = "hyper_backend")]
+pub use hyper_client::{BeatSaverHyper, HyperBackend};
+
+#[cfg(feature = "surf_backend")]
+mod surf_client {
+    use crate::client::{
+        isahc_builder, isahc_is_transient, BeatSaverClient, ClientOptions, HttpBackend,
+    };
+    use crate::{BackendError, BeatSaverApiError, BeatSaverError, ByteStream};
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use futures::io::AsyncReadExt;
+    use futures::{stream, Future};
+    use http::{response, Request, Response};
+    use http_client::isahc::IsahcClient;
+    use std::convert::From;
+    use std::error::Error;
+    use std::fmt::{self, Display, Formatter};
+    use std::io;
+    use std::time::Duration;
+    use surf::http::Method;
+    use surf::Client;
+    use url::Url;
+
+    /// [Error][std::error::Error] wrapper type for [surf::Error]
+    ///
+    /// Note: [surf::Error] doesn't implement [Error][std::error::Error] itself, so this type can't
+    /// be derived with `thiserror`. Like the other errors wrapped by
+    /// [BeatSaverApiError][crate::BeatSaverApiError], both the message and the
+    /// [source][std::error::Error::source] are forwarded to the error wrapped by [surf::Error].
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub enum SurfError {
+        /// Surf error
+        Error(surf::Error),
+    }
+    impl Display for SurfError {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            match self {
+                Self::Error(e) => e.fmt(f),
+            }
+        }
+    }
+    impl Error for SurfError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                Self::Error(e) => AsRef::<dyn Error>::as_ref(e).source(),
+            }
+        }
+    }
+    impl From<surf::Error> for SurfError {
+        fn from(e: surf::Error) -> Self {
+            Self::Error(e)
+        }
+    }
+    impl From<SurfError> for BeatSaverApiError<SurfError> {
+        fn from(e: SurfError) -> Self {
+            Self::RequestError(e)
+        }
+    }
+    impl From<surf::Error> for BeatSaverApiError<SurfError> {
+        fn from(e: surf::Error) -> Self {
+            Self::RequestError(e.into())
+        }
+    }
+
+    /// Size of the buffer used when streaming responses
+    const CHUNK_SIZE: usize = 16 * 1024;
+
+    /// Converts a request to a [surf::Request]
+    fn surf_request(
+        request: Request<Bytes>,
+    ) -> Result<surf::Request, BeatSaverApiError<SurfError>> {
+        let (parts, body) = request.into_parts();
+        let method: Method = parts.method.as_str().parse()?;
+        let url = Url::parse(parts.uri.to_string().as_str())
+            .map_err(|_| BeatSaverApiError::ArgumentError("uri"))?;
+
+        let mut req = surf::Request::new(method, url);
+        for (name, value) in parts.headers.iter() {
+            req.append_header(name.as_str(), &*String::from_utf8_lossy(value.as_bytes()));
+        }
+        if !body.is_empty() {
+            req.set_body(body.to_vec());
+        }
+        Ok(req)
+    }
+
+    /// Fails with a [TimedOut][std::io::ErrorKind::TimedOut] error if the future doesn't complete in time
+    async fn timeout<F: Future>(timeout: Option<Duration>, future: F) -> io::Result<F::Output> {
+        match timeout {
+            Some(t) => async_std::future::timeout(t, future)
+                .await
+                .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "request timed out")),
+            None => Ok(future.await),
+        }
+    }
+
+    /// Copies the status and headers of a [surf::Response]
+    fn response_builder(resp: &surf::Response) -> response::Builder {
+        let mut response = Response::builder().status(u16::from(resp.status()));
+        for (name, values) in resp.iter() {
+            for value in values.iter() {
+                response = response.header(name.as_str(), value.as_str());
+            }
+        }
+        response
+    }
+
+    /// [HttpBackend][crate::client::HttpBackend] implemented for [Surf][surf]
+    #[derive(Debug, Clone)]
+    pub struct SurfBackend {
+        client: Client,
+        options: ClientOptions,
+    }
+    impl SurfBackend {
+        /// Creates a new [SurfBackend][crate::client::SurfBackend], initializing a
+        /// [Surf Client][surf::Client] with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// isn't a valid URI or its scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            let client = isahc_builder(&options)?
+                .build()
+                .map_err(BackendError::new)?;
+            Ok(Self {
+                client: Client::with_http_client(IsahcClient::from_client(client)),
+                options,
+            })
+        }
+        /// Sends a request, waiting for the response headers
+        async fn response(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<surf::Response, BeatSaverApiError<SurfError>> {
+            let options = &self.options;
+            let response = self.client.send(surf_request(request)?);
+            let response_timeout = options.response_timeout().or(options.connect_timeout);
+            Ok(timeout(response_timeout, response).await??)
+        }
+    }
+    impl From<Client> for SurfBackend {
+        fn from(client: Client) -> Self {
+            Self {
+                client,
+                options: ClientOptions::default(),
+            }
+        }
+    }
+    #[async_trait]
+    impl HttpBackend for SurfBackend {
+        type Error = SurfError;
+
+        async fn sleep(&self, duration: Duration) {
+            async_std::task::sleep(duration).await
+        }
+        fn is_transient(&self, error: &SurfError) -> bool {
+            match error {
+                SurfError::Error(e) => e.downcast_ref().is_some_and(isahc_is_transient),
+            }
+        }
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<SurfError>> {
+            let mut resp = self.response(request).await?;
+            let mut body = resp.take_body();
+            let (mut data, mut buf) = (vec![], vec![0; CHUNK_SIZE]);
+            loop {
+                let n = timeout(self.options.read_timeout, body.read(&mut buf)).await??;
+                if n == 0 {
+                    break;
+                }
+                data.extend_from_slice(&buf[..n]);
+            }
+            Ok(response_builder(&resp).body(Bytes::from(data)).unwrap())
+        }
+        async fn send_streaming<'a>(
+            &'a self,
+            request: Request<Bytes>,
+        ) -> Result<Response<ByteStream<'a, SurfError>>, BeatSaverApiError<SurfError>> {
+            let mut resp = self.response(request).await?;
+            let read_timeout = self.options.read_timeout;
+            let body = stream::try_unfold(resp.take_body(), move |mut body| async move {
+                let mut buf = vec![0; CHUNK_SIZE];
+                let n = timeout(read_timeout, body.read(&mut buf)).await??;
+                if n == 0 {
+                    return Ok(None);
+                }
+                buf.truncate(n);
+                Ok(Some((Bytes::from(buf), body)))
+            });
+            Ok(response_builder(&resp)
+                .body(Box::pin(body) as ByteStream<'a, SurfError>)
+                .unwrap())
+        }
+    }
+
+    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented for [Surf][surf]
+    pub type BeatSaverSurf = BeatSaverClient<SurfBackend>;
+    impl BeatSaverSurf {
+        /// Creates a new [BeatSaverSurf][crate::client::BeatSaverSurf] object, initiailizing a [Surf Client][surf::Client]
+        ///
+        /// Example:
+        /// ```no_run
+        /// use beatsaver_rs::client::BeatSaverSurf;
+        ///
+        /// let client = BeatSaverSurf::new();
+        /// ```
+        pub fn new() -> Self {
+            Self::from_options(ClientOptions::new()).expect("failed to initialize the isahc client")
+        }
+        /// Creates a new [BeatSaverSurf][crate::client::BeatSaverSurf] object, initializing a
+        /// [Surf Client][surf::Client] with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// isn't a valid URI or its scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            Ok(Self::from_backend(SurfBackend::from_options(options)?))
+        }
+    }
+    impl Default for BeatSaverSurf {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl From<Client> for BeatSaverSurf {
+        fn from(client: Client) -> Self {
+            Self::from_backend(client.into())
+        }
+    }
+}
+#[cfg(all(
+    feature = "surf_backend",
+    not(feature = "reqwest_backend"),
+    not(feature = "hyper_backend"),
+    not(feature = "isahc_backend"),
+    not(feature = "ureq_backend"),
+    not(feature = "attohttpc_backend")
+))]
+pub use surf_client::BeatSaverSurf as BeatSaver;
+#[cfg(feature = "surf_backend")]
+pub use surf_client::{BeatSaverSurf, SurfBackend, SurfError};
+
+#[cfg(feature = "isahc_backend")]
+mod isahc_client {
+    use crate::client::{
+        isahc_builder, isahc_is_transient, BeatSaverClient, ClientOptions, HttpBackend,
+    };
+    use crate::{BackendError, BeatSaverApiError, BeatSaverError, ByteStream};
+    use async_io::Timer;
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use futures::future::{self, Either};
+    use futures::io::AsyncReadExt;
+    use futures::{pin_mut, stream, Future};
+    use http::{Request, Response};
+    use isahc::config::Configurable;
+    use isahc::{Body, HttpClient};
+    use std::convert::From;
+    use std::io;
+    use std::time::Duration;
+
+    /// Size of the buffer used when reading responses
+    const CHUNK_SIZE: usize = 16 * 1024;
+
+    /// Fails with a [TimedOut][std::io::ErrorKind::TimedOut] error if the future doesn't complete in time
+    async fn timeout<F: Future>(timeout: Option<Duration>, future: F) -> io::Result<F::Output> {
+        match timeout {
+            Some(t) => {
+                pin_mut!(future);
+                match future::select(future, Timer::after(t)).await {
+                    Either::Left((output, _)) => Ok(output),
+                    Either::Right(_) => {
+                        Err(io::Error::new(io::ErrorKind::TimedOut, "request timed out"))
+                    }
+                }
+            }
+            None => Ok(future.await),
+        }
+    }
+
+    /// [HttpBackend][crate::client::HttpBackend] implemented for [isahc]
+    #[derive(Debug, Clone)]
+    pub struct IsahcBackend {
+        client: HttpClient,
+        options: ClientOptions,
+    }
+    impl IsahcBackend {
+        /// Creates a new [IsahcBackend][crate::client::IsahcBackend], initializing an
+        /// [isahc HttpClient][isahc::HttpClient] with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// isn't a valid URI or its scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            let mut builder = isahc_builder(&options)?;
+            if let Some(timeout) = options.connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+            let client = builder.build().map_err(BackendError::new)?;
+            Ok(Self { client, options })
+        }
+        /// Sends a request, waiting for the response headers
+        async fn response(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Body>, BeatSaverApiError<isahc::Error>> {
+            let response = self.client.send_async(request.map(|body| body.to_vec()));
+            Ok(timeout(self.options.response_timeout(), response).await??)
+        }
+    }
+    impl From<HttpClient> for IsahcBackend {
+        fn from(client: HttpClient) -> Self {
+            Self {
+                client,
+                options: ClientOptions::default(),
+            }
+        }
+    }
+    impl From<isahc::Error> for BeatSaverApiError<isahc::Error> {
+        fn from(e: isahc::Error) -> Self {
+            Self::RequestError(e)
+        }
+    }
+    #[async_trait]
+    impl HttpBackend for IsahcBackend {
+        type Error = isahc::Error;
+
+        async fn sleep(&self, duration: Duration) {
+            Timer::after(duration).await;
+        }
+        fn is_transient(&self, error: &isahc::Error) -> bool {
+            isahc_is_transient(error)
+        }
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<isahc::Error>> {
+            let (parts, mut body) = self.response(request).await?.into_parts();
+
+            let (mut data, mut buf) = (vec![], vec![0; CHUNK_SIZE]);
+            loop {
+                let n = timeout(self.options.read_timeout, body.read(&mut buf)).await??;
+                if n == 0 {
+                    break;
+                }
+                data.extend_from_slice(&buf[..n]);
+            }
+            Ok(Response::from_parts(parts, Bytes::from(data)))
+        }
+        async fn send_streaming<'a>(
+            &'a self,
+            request: Request<Bytes>,
+        ) -> Result<Response<ByteStream<'a, isahc::Error>>, BeatSaverApiError<isahc::Error>>
+        {
+            let (parts, body) = self.response(request).await?.into_parts();
+
+            let read_timeout = self.options.read_timeout;
+            let body = stream::try_unfold(body, move |mut body| async move {
+                let mut buf = vec![0; CHUNK_SIZE];
+                let n = timeout(read_timeout, body.read(&mut buf)).await??;
+                if n == 0 {
+                    return Ok(None);
+                }
+                buf.truncate(n);
+                Ok(Some((Bytes::from(buf), body)))
+            });
+            Ok(Response::from_parts(
+                parts,
+                Box::pin(body) as ByteStream<'a, isahc::Error>,
+            ))
+        }
+    }
+
+    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented for [isahc]
+    ///
+    /// Requests are multiplexed over HTTP/2 when the server supports it, so concurrent requests
+    /// (e.g. bulk fetching with [maps_by_ids][crate::BeatSaverApiAsync::maps_by_ids]) share a
+    /// single connection.
+    pub type BeatSaverIsahc = BeatSaverClient<IsahcBackend>;
+    impl BeatSaverIsahc {
+        /// Creates a new [BeatSaverIsahc][crate::client::BeatSaverIsahc] object, initiailizing an [isahc HttpClient][isahc::HttpClient]
+        ///
+        /// Example:
+        /// ```no_run
+        /// use beatsaver_rs::client::BeatSaverIsahc;
+        ///
+        /// let client = BeatSaverIsahc::new();
+        /// ```
+        pub fn new() -> Self {
+            Self::from_options(ClientOptions::new()).expect("failed to initialize the isahc client")
+        }
+        /// Creates a new [BeatSaverIsahc][crate::client::BeatSaverIsahc] object, initializing an
+        /// [isahc HttpClient][isahc::HttpClient] with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// isn't a valid URI or its scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            Ok(Self::from_backend(IsahcBackend::from_options(options)?))
+        }
+    }
+    impl Default for BeatSaverIsahc {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl From<HttpClient> for BeatSaverIsahc {
+        fn from(client: HttpClient) -> Self {
+            Self::from_backend(client.into())
+        }
+    }
+}
+#[cfg(all(
+    feature = "isahc_backend",
+    not(feature = "reqwest_backend"),
+    not(feature = "hyper_backend"),
+    not(feature = "surf_backend"),
+    not(feature = "ureq_backend"),
+    not(feature = "attohttpc_backend")
+))]
+pub use isahc_client::BeatSaverIsahc as BeatSaver;
+#[cfg(feature = "isahc_backend")]
+pub use isahc_client::{BeatSaverIsahc, IsahcBackend};
+
+#[cfg(feature = "ureq_backend")]
+mod ureq_client {
+    use crate::client::{BeatSaverClient, ClientOptions, HttpBackendSync};
+    use crate::{BeatSaverApiError, BeatSaverError};
+    use bytes::Bytes;
+    use http::header::ACCEPT_ENCODING;
+    use http::{Request, Response};
+    use std::convert::From;
+    use std::io::Read;
+    use ureq;
+
+    impl From<ureq::Error> for BeatSaverApiError<ureq::Error> {
+        fn from(e: ureq::Error) -> Self {
+            Self::RequestError(e)
+        }
+    }
+
+    /// [HttpBackendSync][crate::client::HttpBackendSync] implemented for [ureq]
+    #[derive(Debug, Clone)]
+    pub struct UreqBackend {
+        agent: ureq::Agent,
+        options: ClientOptions,
+    }
+    impl UreqBackend {
+        /// Creates a new [UreqBackend][crate::client::UreqBackend], initializing a
+        /// [ureq Agent][ureq::Agent] with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            let mut builder = ureq::AgentBuilder::new();
+            if let Some(timeout) = options.connect_timeout {
+                builder = builder.timeout_connect(timeout);
+            }
+            if let Some(timeout) = options.read_timeout {
+                builder = builder.timeout_read(timeout);
+            }
+            if let Some(max) = options.pool_max_idle_per_host {
+                builder = builder.max_idle_connections_per_host(max);
+            }
+            if let Some(proxy) = &options.proxy {
+                let proxy = ureq::Proxy::new(proxy.as_str())
+                    .map_err(|_| BeatSaverApiError::ArgumentError("proxy"))?;
+                builder = builder.proxy(proxy);
+            }
+            builder = builder.try_proxy_from_env(options.proxy_from_env);
+            Ok(Self {
+                agent: builder.build(),
+                options,
+            })
+        }
+    }
+    impl From<ureq::Agent> for UreqBackend {
+        fn from(agent: ureq::Agent) -> Self {
+            Self {
+                agent,
+                options: ClientOptions::default(),
+            }
+        }
+    }
+    impl HttpBackendSync for UreqBackend {
+        type Error = ureq::Error;
+
+        fn is_transient(&self, error: &ureq::Error) -> bool {
+            use ureq::ErrorKind;
+
+            matches!(error, ureq::Error::Transport(t)
+                if matches!(t.kind(), ErrorKind::ConnectionFailed | ErrorKind::Io))
+        }
+        fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<ureq::Error>> {
+            let response = self.send_streaming(request)?;
+            let (parts, mut reader) = response.into_parts();
+            let mut contents = vec![];
+            reader.read_to_end(&mut contents)?;
+            Ok(Response::from_parts(parts, contents.into()))
+        }
+        fn send_streaming(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Box<dyn Read + '_>>, BeatSaverApiError<ureq::Error>> {
+            let (parts, body) = request.into_parts();
+            let mut req = self
+                .agent
+                .request(parts.method.as_str(), parts.uri.to_string().as_str());
+            for (name, value) in parts.headers.iter() {
+                req = req.set(name.as_str(), &String::from_utf8_lossy(value.as_bytes()));
+            }
+            // ureq asks for gzip unless an encoding is set
+            if !self.options.compression && !parts.headers.contains_key(ACCEPT_ENCODING) {
+                req = req.set(ACCEPT_ENCODING.as_str(), "identity");
+            }
+            let result = match body.is_empty() {
+                true => req.call(),
+                false => req.send_bytes(body.as_ref()),
+            };
+            let resp = match result {
+                // ureq treats 4xx/5xx as errors, status handling is done by the API traits
+                Ok(resp) | Err(ureq::Error::Status(_, resp)) => resp,
+                Err(e) => return Err(e.into()),
+            };
+
+            let mut response = Response::builder().status(resp.status());
+            for name in resp.headers_names() {
+                for value in resp.all(name.as_str()) {
+                    response = response.header(name.as_str(), value);
+                }
+            }
+            Ok(response.body(resp.into_reader() as Box<dyn Read>).unwrap())
+        }
+    }
+
+    /// [BeatSaverApi][crate::BeatSaverApiSync] implemented for [ureq]
+    ///
+    /// Requests are sent through a single [ureq Agent][ureq::Agent], so connections are kept alive
+    /// and reused. Clones share the same connection pool.
+    pub type BeatSaverUreq = BeatSaverClient<UreqBackend>;
+    impl BeatSaverUreq {
+        /// Creates a new [BeatSaverUreq][crate::client::BeatSaverUreq] object
+        ///
+        /// Example:
+        /// ```no_run
+        /// use beatsaver_rs::client::BeatSaverUreq;
+        ///
+        /// let client = BeatSaverUreq::new();
+        /// ```
+        pub fn new() -> Self {
+            Self::from_options(ClientOptions::new()).expect("failed to initialize the ureq agent")
+        }
+        /// Creates a new [BeatSaverUreq][crate::client::BeatSaverUreq] object, initializing a
+        /// [ureq Agent][ureq::Agent] with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// scheme isn't supported
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            Ok(Self::from_backend(UreqBackend::from_options(options)?))
+        }
+    }
+    impl Default for BeatSaverUreq {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl From<ureq::Agent> for BeatSaverUreq {
+        fn from(agent: ureq::Agent) -> Self {
+            Self::from_backend(agent.into())
+        }
+    }
+}
+#[cfg(all(
+    feature = "ureq_backend",
+    not(feature = "reqwest_backend"),
+    not(feature = "hyper_backend"),
+    not(feature = "isahc_backend"),
+    not(feature = "surf_backend"),
+    not(feature = "attohttpc_backend")
+))]
+pub use ureq_client::BeatSaverUreq as BeatSaver;
+#[cfg(feature = "ureq_backend")]
+pub use ureq_client::{BeatSaverUreq, UreqBackend};
+
+#[cfg(feature = "attohttpc_backend")]
+mod attohttpc_client {
+    use crate::client::{BeatSaverClient, ClientOptions, HttpBackendSync};
+    use crate::{BeatSaverApiError, BeatSaverError};
+    use attohttpc::header::{HeaderName, HeaderValue};
+    use attohttpc::{Method, ProxySettings, RequestBuilder};
+    use bytes::Bytes;
+    use http::{Request, Response};
+    use std::convert::From;
+    use std::io::{self, Read};
+
+    impl From<attohttpc::Error> for BeatSaverApiError<attohttpc::Error> {
+        fn from(e: attohttpc::Error) -> Self {
+            Self::RequestError(e)
+        }
+    }
+
+    /// [HttpBackendSync][crate::client::HttpBackendSync] implemented for [attohttpc]
+    ///
+    /// attohttpc opens a new connection for every request, in exchange for a small dependency tree.
+    #[derive(Debug, Clone)]
+    pub struct AttohttpcBackend {
+        proxy: ProxySettings,
+        options: ClientOptions,
+    }
+    impl AttohttpcBackend {
+        /// Creates a new [AttohttpcBackend][crate::client::AttohttpcBackend] sending requests
+        /// with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// scheme isn't `http`
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            let proxy = match &options.proxy {
+                Some(proxy) if proxy.scheme() != "http" => {
+                    return Err(BeatSaverApiError::ArgumentError("proxy"))
+                }
+                Some(proxy) => ProxySettings::builder()
+                    .http_proxy(proxy.clone())
+                    .https_proxy(proxy.clone())
+                    .build(),
+                None if options.proxy_from_env => ProxySettings::from_env(),
+                None => ProxySettings::builder().build(),
+            };
+            Ok(Self { proxy, options })
+        }
+        /// Creates a request with the options of the backend
+        fn request(
+            &self,
+            request: &http::request::Parts,
+        ) -> Result<RequestBuilder, BeatSaverApiError<attohttpc::Error>> {
+            let method = Method::from_bytes(request.method.as_str().as_bytes())
+                .map_err(|_| BeatSaverApiError::ArgumentError("method"))?;
+            let mut req = RequestBuilder::try_new(method, request.uri.to_string())?
+                .proxy_settings(self.proxy.clone())
+                .allow_compression(self.options.compression);
+            if let Some(timeout) = self.options.connect_timeout {
+                req = req.connect_timeout(timeout);
+            }
+            if let Some(timeout) = self.options.read_timeout {
+                req = req.read_timeout(timeout);
+            }
+            for (name, value) in request.headers.iter() {
+                let name = HeaderName::from_bytes(name.as_str().as_bytes())
+                    .map_err(|_| BeatSaverApiError::ArgumentError("header"))?;
+                let value = HeaderValue::from_bytes(value.as_bytes())
+                    .map_err(|_| BeatSaverApiError::ArgumentError("header"))?;
+                req.headers_mut().append(name, value);
+            }
+            Ok(req)
+        }
+    }
+    impl Default for AttohttpcBackend {
+        fn default() -> Self {
+            Self::from_options(ClientOptions::default()).unwrap()
+        }
+    }
+    impl HttpBackendSync for AttohttpcBackend {
+        type Error = attohttpc::Error;
+
+        fn is_transient(&self, error: &attohttpc::Error) -> bool {
+            match error.kind() {
+                attohttpc::ErrorKind::Io(e) => {
+                    e.kind() == io::ErrorKind::ConnectionRefused || crate::retry::is_transient_io(e)
+                }
+                _ => false,
+            }
+        }
+        fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<attohttpc::Error>> {
+            let response = self.send_streaming(request)?;
+            let (parts, mut reader) = response.into_parts();
+            let mut contents = vec![];
+            reader.read_to_end(&mut contents)?;
+            Ok(Response::from_parts(parts, contents.into()))
+        }
+        fn send_streaming(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Box<dyn Read + '_>>, BeatSaverApiError<attohttpc::Error>> {
+            let (parts, body) = request.into_parts();
+            let req = self.request(&parts)?;
+            // attohttpc doesn't treat 4xx/5xx as errors, status handling is done by the API traits
+            let resp = match body.is_empty() {
+                true => req.send()?,
+                false => req.bytes(body.as_ref()).send()?,
+            };
+
+            let (status, headers, reader) = resp.split();
+            let mut response = Response::builder().status(status.as_u16());
+            for (name, value) in headers.iter() {
+                response = response.header(name.as_str(), value.as_bytes());
+            }
+            Ok(response.body(Box::new(reader) as Box<dyn Read>).unwrap())
+        }
+    }
+
+    /// [BeatSaverApi][crate::BeatSaverApiSync] implemented for [attohttpc]
+    ///
+    /// A lightweight alternative to [BeatSaverUreq][crate::client::BeatSaverUreq] for small
+    /// synchronous binaries, connections aren't reused between requests.
+    pub type BeatSaverAttohttpc = BeatSaverClient<AttohttpcBackend>;
+    impl BeatSaverAttohttpc {
+        /// Creates a new [BeatSaverAttohttpc][crate::client::BeatSaverAttohttpc] object
+        ///
+        /// Example:
+        /// ```no_run
+        /// use beatsaver_rs::client::BeatSaverAttohttpc;
+        ///
+        /// let client = BeatSaverAttohttpc::new();
+        /// ```
+        pub fn new() -> Self {
+            Self::from_backend(AttohttpcBackend::default())
+        }
+        /// Creates a new [BeatSaverAttohttpc][crate::client::BeatSaverAttohttpc] object, sending
+        /// requests with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the proxy
+        /// scheme isn't `http`
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            Ok(Self::from_backend(AttohttpcBackend::from_options(options)?))
+        }
+    }
+    impl Default for BeatSaverAttohttpc {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+#[cfg(all(
+    feature = "attohttpc_backend",
+    not(feature = "reqwest_backend"),
+    not(feature = "hyper_backend"),
+    not(feature = "isahc_backend"),
+    not(feature = "surf_backend"),
+    not(feature = "ureq_backend")
+))]
+pub use attohttpc_client::BeatSaverAttohttpc as BeatSaver;
+#[cfg(feature = "attohttpc_backend")]
+pub use attohttpc_client::{AttohttpcBackend, BeatSaverAttohttpc};
+
+#[cfg(all(feature = "wasm_backend", target_arch = "wasm32"))]
+mod fetch_client {
+    use crate::client::{BeatSaverClient, ClientOptions, HttpBackend};
+    use crate::{BeatSaverApiError, BeatSaverError};
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::{Request, Response};
+    use js_sys::{Array, Function, Promise, Reflect, Uint8Array};
+    use std::convert::From;
+    use std::fmt::{self, Display, Formatter};
+    use std::time::Duration;
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{AbortController, Headers, RequestInit, WorkerGlobalScope};
+
+    /// Error raised by the [Fetch API](https://developer.mozilla.org/docs/Web/API/Fetch_API)
+    ///
+    /// Note: JavaScript errors can't be sent between threads, so only their name and message are
+    /// kept.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FetchError {
+        /// Name of the JavaScript error (e.g. `TypeError` for network failures, `AbortError` for
+        /// timeouts)
+        pub name: String,
+        /// Message of the JavaScript error
+        pub message: String,
+    }
+    impl Display for FetchError {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "{}: {}", self.name, self.message)
+        }
+    }
+    impl std::error::Error for FetchError {}
+    impl From<JsValue> for FetchError {
+        fn from(value: JsValue) -> Self {
+            match value.dyn_ref::<js_sys::Error>() {
+                Some(e) => Self {
+                    name: e.name().into(),
+                    message: e.message().into(),
+                },
+                None => Self {
+                    name: "Error".into(),
+                    message: format!("{:?}", value),
+                },
+            }
+        }
+    }
+    impl From<FetchError> for BeatSaverApiError<FetchError> {
+        fn from(e: FetchError) -> Self {
+            Self::RequestError(e)
+        }
+    }
+    impl From<JsValue> for BeatSaverApiError<FetchError> {
+        fn from(value: JsValue) -> Self {
+            Self::RequestError(value.into())
+        }
+    }
+
+    /// Global scope of the page or worker the backend runs in
+    enum Scope {
+        Window(web_sys::Window),
+        Worker(WorkerGlobalScope),
+    }
+    impl Scope {
+        fn get() -> Result<Self, FetchError> {
+            let global = js_sys::global();
+            if let Some(window) = global.dyn_ref::<web_sys::Window>() {
+                return Ok(Self::Window(window.clone()));
+            }
+            match global.dyn_into::<WorkerGlobalScope>() {
+                Ok(worker) => Ok(Self::Worker(worker)),
+                Err(_) => Err(FetchError {
+                    name: "Error".into(),
+                    message: "fetch is only available in windows and workers".into(),
+                }),
+            }
+        }
+        fn fetch(&self, request: &web_sys::Request) -> Promise {
+            match self {
+                Self::Window(w) => w.fetch_with_request(request),
+                Self::Worker(w) => w.fetch_with_request(request),
+            }
+        }
+        fn set_timeout(&self, callback: &Function, duration: Duration) -> Result<i32, JsValue> {
+            let ms = duration.as_millis().min(i32::MAX as u128) as i32;
+            match self {
+                Self::Window(w) => {
+                    w.set_timeout_with_callback_and_timeout_and_arguments_0(callback, ms)
+                }
+                Self::Worker(w) => {
+                    w.set_timeout_with_callback_and_timeout_and_arguments_0(callback, ms)
+                }
+            }
+        }
+        fn clear_timeout(&self, handle: i32) {
+            match self {
+                Self::Window(w) => w.clear_timeout_with_handle(handle),
+                Self::Worker(w) => w.clear_timeout_with_handle(handle),
+            }
+        }
+    }
+
+    /// [HttpBackend][crate::client::HttpBackend] sending requests with the
+    /// [Fetch API](https://developer.mozilla.org/docs/Web/API/Fetch_API), for browsers and web
+    /// workers
+    ///
+    /// Connections, proxies and compression are handled by the browser, so only the
+    /// `read_timeout` of the [ClientOptions][crate::client::ClientOptions] is used: it bounds
+    /// the whole request.
+    #[derive(Debug, Clone, Default)]
+    pub struct FetchBackend {
+        timeout: Option<Duration>,
+    }
+    impl FetchBackend {
+        /// Creates a new [FetchBackend][crate::client::FetchBackend] with the provided options
+        pub fn from_options(options: ClientOptions) -> Self {
+            Self {
+                timeout: options.read_timeout,
+            }
+        }
+        /// Sends the request, aborting it if the whole response isn't read before the timeout
+        async fn fetch(
+            &self,
+            scope: &Scope,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<FetchError>> {
+            let (parts, body) = request.into_parts();
+            let init = RequestInit::new();
+            init.set_method(parts.method.as_str());
+            let headers = Headers::new()?;
+            for (name, value) in parts.headers.iter() {
+                headers.append(name.as_str(), &String::from_utf8_lossy(value.as_bytes()))?;
+            }
+            init.set_headers(&headers);
+            if !body.is_empty() {
+                init.set_body(&Uint8Array::from(body.as_ref()));
+            }
+            let controller = AbortController::new()?;
+            init.set_signal(Some(&controller.signal()));
+            let request = web_sys::Request::new_with_str_and_init(&parts.uri.to_string(), &init)?;
+
+            let timeout = match self.timeout {
+                Some(timeout) => {
+                    let abort: Function =
+                        Reflect::get(&controller, &JsValue::from_str("abort"))?.dyn_into()?;
+                    Some(scope.set_timeout(&abort.bind0(&controller), timeout)?)
+                }
+                None => None,
+            };
+            let response = read_response(scope, &request).await;
+            if let Some(handle) = timeout {
+                scope.clear_timeout(handle);
+            }
+            response
+        }
+    }
+    #[async_trait(?Send)]
+    impl HttpBackend for FetchBackend {
+        type Error = FetchError;
+
+        fn is_transient(&self, error: &FetchError) -> bool {
+            // Network failures reject with a TypeError, timeouts abort the request
+            matches!(error.name.as_str(), "TypeError" | "AbortError")
+        }
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<FetchError>> {
+            self.fetch(&Scope::get()?, request).await
+        }
+        async fn sleep(&self, duration: Duration) {
+            let scope = match Scope::get() {
+                Ok(scope) => scope,
+                Err(_) => return,
+            };
+            let promise = Promise::new(&mut |resolve, _| {
+                let _ = scope.set_timeout(&resolve, duration);
+            });
+            let _ = JsFuture::from(promise).await;
+        }
+    }
+
+    /// Sends the request and reads the whole response
+    async fn read_response(
+        scope: &Scope,
+        request: &web_sys::Request,
+    ) -> Result<Response<Bytes>, BeatSaverApiError<FetchError>> {
+        let resp: web_sys::Response = JsFuture::from(scope.fetch(request)).await?.dyn_into()?;
+        let mut response = Response::builder().status(resp.status());
+        if let Some(entries) = js_sys::try_iter(&resp.headers())? {
+            for entry in entries {
+                let entry: Array = entry?.dyn_into()?;
+                if let (Some(name), Some(value)) =
+                    (entry.get(0).as_string(), entry.get(1).as_string())
+                {
+                    response = response.header(name.as_str(), value.as_str());
+                }
+            }
+        }
+        let body = JsFuture::from(resp.array_buffer()?).await?;
+        Ok(response
+            .body(Uint8Array::new(&body).to_vec().into())
+            .unwrap())
+    }
+
+    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented with the
+    /// [Fetch API](https://developer.mozilla.org/docs/Web/API/Fetch_API), for browser-based tools
+    ///
+    /// Only available on `wasm32` targets, where the futures of the API aren't `Send`.
+    pub type BeatSaverFetch = BeatSaverClient<FetchBackend>;
+    impl BeatSaverFetch {
+        /// Creates a new [BeatSaverFetch][crate::client::BeatSaverFetch] object
+        ///
+        /// Example:
+        /// ```no_run
+        /// # #[cfg(target_arch = "wasm32")]
+        /// # mod main {
+        /// use beatsaver_rs::client::BeatSaverFetch;
+        ///
+        /// # fn main() {
+        /// let client = BeatSaverFetch::new();
+        /// # }
+        /// # }
+        /// ```
+        pub fn new() -> Self {
+            Self::from_backend(FetchBackend::default())
+        }
+        /// Creates a new [BeatSaverFetch][crate::client::BeatSaverFetch] object with the provided
+        /// options
+        pub fn from_options(options: ClientOptions) -> Result<Self, BeatSaverError> {
+            Ok(Self::from_backend(FetchBackend::from_options(options)))
+        }
+    }
+    impl Default for BeatSaverFetch {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+#[cfg(all(
+    feature = "wasm_backend",
+    target_arch = "wasm32",
+    not(feature = "reqwest_backend"),
+    not(feature = "hyper_backend"),
+    not(feature = "isahc_backend"),
+    not(feature = "surf_backend"),
+    not(feature = "ureq_backend"),
+    not(feature = "attohttpc_backend")
+))]
+pub use fetch_client::BeatSaverFetch as BeatSaver;
+#[cfg(all(feature = "wasm_backend", target_arch = "wasm32"))]
+pub use fetch_client::{BeatSaverFetch, FetchBackend, FetchError};
+
+#[cfg(feature = "sync_adapter")]
+mod sync_adapter {
+    use crate::auth::Credentials;
+    use crate::cache::ResponseCache;
+    use crate::index::KeyIndex;
+    use crate::retry::RetryPolicy;
+    use crate::{
+        BeatSaverApiAsync, BeatSaverApiError, BeatSaverApiSync, DownloadRoute, RateLimitPolicy,
+        RateLimitTracker,
+    };
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::{Request, Response};
+    use std::error::Error;
+    use std::io;
+    use std::panic;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use url::Url;
+
+    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented over a synchronous client
+    ///
+    /// Requests are sent with the [send][crate::BeatSaverApiSync::send] method of the wrapped
+    /// client on Tokio's blocking thread pool, everything else (rate limits, retries, caching) is
+    /// handled asynchronously using the configuration of the wrapped client. This makes
+    /// synchronous backends such as [BeatSaverUreq][crate::client::BeatSaverUreq] usable from
+    /// asynchronous code.
+    ///
+    /// Note: requests must be made from within a Tokio runtime
+    ///
+    /// Example:
+    /// ```no_run
+    /// # #[cfg(all(feature = "sync_adapter", feature = "ureq_backend"))]
+    /// # mod main {
+    /// use beatsaver_rs::client::{AsyncAdapter, BeatSaverUreq};
+    /// use beatsaver_rs::BeatSaverApiAsync;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = AsyncAdapter::new(BeatSaverUreq::new());
+    /// let map = client.map(&"2144".parse()?).await?;
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    #[derive(Debug, Default)]
+    pub struct AsyncAdapter<C> {
+        client: Arc<C>,
+    }
+    impl<C> AsyncAdapter<C> {
+        /// Creates a new [AsyncAdapter][crate::client::AsyncAdapter] wrapping the provided client
+        pub fn new(client: C) -> Self {
+            Self {
+                client: Arc::new(client),
+            }
+        }
+        /// Returns the wrapped client
+        pub fn inner(&self) -> &C {
+            &self.client
+        }
+    }
+    impl<C> Clone for AsyncAdapter<C> {
+        fn clone(&self) -> Self {
+            Self {
+                client: self.client.clone(),
+            }
+        }
+    }
+    impl<C> From<Arc<C>> for AsyncAdapter<C> {
+        fn from(client: Arc<C>) -> Self {
+            Self { client }
+        }
+    }
+    #[async_trait]
+    impl<C, T> BeatSaverApiAsync<T> for AsyncAdapter<C>
+    where
+        C: BeatSaverApiSync<T> + Send + Sync + 'static,
+        T: Error + Send + 'static,
+        BeatSaverApiError<T>: From<T>,
+    {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            BeatSaverApiSync::key_index(self.inner())
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            BeatSaverApiSync::credentials(self.inner())
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            BeatSaverApiSync::rate_limit_policy(self.inner())
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            BeatSaverApiSync::rate_limit_tracker(self.inner())
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            BeatSaverApiSync::retry_policy(self.inner())
+        }
+        fn is_transient(&self, error: &BeatSaverApiError<T>) -> bool {
+            BeatSaverApiSync::is_transient(self.inner(), error)
+        }
+        fn download_route(&self) -> DownloadRoute {
+            BeatSaverApiSync::download_route(self.inner())
+        }
+        fn base_url(&self) -> &Url {
+            BeatSaverApiSync::base_url(self.inner())
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            BeatSaverApiSync::response_cache(self.inner())
+        }
+        async fn sleep(&self, duration: Duration) {
+            tokio::time::sleep(duration).await
+        }
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<T>> {
+            let client = self.client.clone();
+            match tokio::task::spawn_blocking(move || client.send(request)).await {
+                Ok(response) => response,
+                Err(e) if e.is_panic() => panic::resume_unwind(e.into_panic()),
+                // The runtime is shutting down
+                Err(e) => Err(io::Error::other(e).into()),
+            }
+        }
+    }
+}
+#[cfg(feature = "sync_adapter")]
+pub use sync_adapter::AsyncAdapter;
+
+#[cfg(any(
+    feature = "reqwest_backend",
+    feature = "hyper_backend",
+    feature = "isahc_backend",
+    feature = "surf_backend"
+))]
+mod dyn_client {
+    use crate::auth::Credentials;
+    use crate::cache::ResponseCache;
+    #[cfg(feature = "hyper_backend")]
+    use crate::client::BeatSaverHyper;
+    #[cfg(feature = "isahc_backend")]
+    use crate::client::BeatSaverIsahc;
+    #[cfg(feature = "reqwest_backend")]
+    use crate::client::BeatSaverReqwest;
+    #[cfg(feature = "surf_backend")]
+    use crate::client::BeatSaverSurf;
+    use crate::client::{ClientOptions, HttpBackend};
+    use crate::index::KeyIndex;
+    use crate::intercept::Interceptor;
+    use crate::retry::RetryPolicy;
+    use crate::{
+        BackendError, BeatSaverApiAsync, BeatSaverApiError, BeatSaverError, ByteStream,
+        DownloadRoute, RateLimitPolicy, RateLimitTracker,
+    };
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use futures::TryStreamExt;
+    use http::{Request, Response};
+    use std::fmt;
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use url::Url;
+
+    /// Calls `$e` with `$c` bound to the backend wrapped by a [DynBeatSaver]
+    macro_rules! dispatch {
+        ($client:expr, $c:ident => $e:expr) => {
+            match $client {
+                #[cfg(feature = "reqwest_backend")]
+                DynBeatSaver::Reqwest($c) => $e,
+                #[cfg(feature = "hyper_backend")]
+                DynBeatSaver::Hyper($c) => $e,
+                #[cfg(feature = "isahc_backend")]
+                DynBeatSaver::Isahc($c) => $e,
+                #[cfg(feature = "surf_backend")]
+                DynBeatSaver::Surf($c) => $e,
+            }
+        };
+    }
+    /// Replaces the backend wrapped by a [DynBeatSaver] with the result of `$e`
+    macro_rules! rewrap {
+        ($client:expr, $c:ident => $e:expr) => {
+            match $client {
+                #[cfg(feature = "reqwest_backend")]
+                DynBeatSaver::Reqwest($c) => DynBeatSaver::Reqwest($e),
+                #[cfg(feature = "hyper_backend")]
+                DynBeatSaver::Hyper($c) => DynBeatSaver::Hyper($e),
+                #[cfg(feature = "isahc_backend")]
+                DynBeatSaver::Isahc($c) => DynBeatSaver::Isahc($e),
+                #[cfg(feature = "surf_backend")]
+                DynBeatSaver::Surf($c) => DynBeatSaver::Surf($e),
+            }
+        };
+    }
+
+    /// Asynchronous backends a [DynBeatSaver][crate::client::DynBeatSaver] can be created with
+    ///
+    /// Only the backends enabled through their feature are available. Backends can be parsed from
+    /// their lowercase name (e.g. `reqwest`), for them to be selected in a configuration file.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub enum Backend {
+        /// [BeatSaverReqwest][crate::client::BeatSaverReqwest]
+        #[cfg(feature = "reqwest_backend")]
+        Reqwest,
+        /// [BeatSaverHyper][crate::client::BeatSaverHyper]
+        #[cfg(feature = "hyper_backend")]
+        Hyper,
+        /// [BeatSaverIsahc][crate::client::BeatSaverIsahc]
+        #[cfg(feature = "isahc_backend")]
+        Isahc,
+        /// [BeatSaverSurf][crate::client::BeatSaverSurf]
+        #[cfg(feature = "surf_backend")]
+        Surf,
+    }
+    impl Backend {
+        /// Backends enabled in this build
+        pub const ENABLED: &'static [Backend] = &[
+            #[cfg(feature = "reqwest_backend")]
+            Backend::Reqwest,
+            #[cfg(feature = "hyper_backend")]
+            Backend::Hyper,
+            #[cfg(feature = "isahc_backend")]
+            Backend::Isahc,
+            #[cfg(feature = "surf_backend")]
+            Backend::Surf,
+        ];
+
+        /// Name the backend is parsed from
+        pub fn name(self) -> &'static str {
+            match self {
+                #[cfg(feature = "reqwest_backend")]
+                Backend::Reqwest => "reqwest",
+                #[cfg(feature = "hyper_backend")]
+                Backend::Hyper => "hyper",
+                #[cfg(feature = "isahc_backend")]
+                Backend::Isahc => "isahc",
+                #[cfg(feature = "surf_backend")]
+                Backend::Surf => "surf",
+            }
+        }
+    }
+    impl fmt::Display for Backend {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", self.name())
+        }
+    }
+    impl FromStr for Backend {
+        type Err = UnknownBackend;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::ENABLED
+                .iter()
+                .copied()
+                .find(|b| b.name().eq_ignore_ascii_case(s))
+                .ok_or_else(|| UnknownBackend(s.to_string()))
+        }
+    }
+
+    /// Error returned when parsing the name of a backend that doesn't exist or isn't enabled
+    #[derive(Debug, Clone, PartialEq, thiserror::Error)]
+    #[error("Unknown or disabled backend: {0}")]
+    pub struct UnknownBackend(pub String);
+
+    /// [BeatSaverApi][crate::BeatSaverApiAsync] implemented over a backend selected at runtime
+    ///
+    /// Requests are handed to the wrapped backend, errors of the backend are erased into
+    /// [BeatSaverError][crate::BeatSaverError]. Backends configured beyond what `DynBeatSaver`
+    /// exposes can be wrapped with [From][std::convert::From].
+    ///
+    /// Example:
+    /// ```no_run
+    /// # #[cfg(feature = "reqwest_backend")]
+    /// # mod main {
+    /// use beatsaver_rs::client::{Backend, ClientOptions, DynBeatSaver};
+    /// use beatsaver_rs::BeatSaverApiAsync;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // e.g. read from a configuration file
+    /// let backend: Backend = "reqwest".parse()?;
+    /// let client = DynBeatSaver::from_options(backend, ClientOptions::new())?;
+    /// let map = client.map(&"2144".parse()?).await?;
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    #[derive(Debug, Clone)]
+    #[non_exhaustive]
+    pub enum DynBeatSaver {
+        /// Wraps a [BeatSaverReqwest][crate::client::BeatSaverReqwest]
+        #[cfg(feature = "reqwest_backend")]
+        Reqwest(BeatSaverReqwest),
+        /// Wraps a [BeatSaverHyper][crate::client::BeatSaverHyper]
+        #[cfg(feature = "hyper_backend")]
+        Hyper(BeatSaverHyper),
+        /// Wraps a [BeatSaverIsahc][crate::client::BeatSaverIsahc]
+        #[cfg(feature = "isahc_backend")]
+        Isahc(BeatSaverIsahc),
+        /// Wraps a [BeatSaverSurf][crate::client::BeatSaverSurf]
+        #[cfg(feature = "surf_backend")]
+        Surf(BeatSaverSurf),
+    }
+    impl DynBeatSaver {
+        /// Creates a new [DynBeatSaver][crate::client::DynBeatSaver] using the provided backend
+        pub fn new(backend: Backend) -> Self {
+            Self::from_options(backend, ClientOptions::new())
+                .expect("failed to initialize the client")
+        }
+        /// Creates a new [DynBeatSaver][crate::client::DynBeatSaver] using the provided backend,
+        /// initialized with the provided options
+        ///
+        /// Fails with an [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the backend
+        /// doesn't support the proxy
+        pub fn from_options(
+            backend: Backend,
+            options: ClientOptions,
+        ) -> Result<Self, BeatSaverError> {
+            Ok(match backend {
+                #[cfg(feature = "reqwest_backend")]
+                Backend::Reqwest => Self::Reqwest(BeatSaverReqwest::from_options(options)?),
+                #[cfg(feature = "hyper_backend")]
+                Backend::Hyper => Self::Hyper(BeatSaverHyper::from_options(options)),
+                #[cfg(feature = "isahc_backend")]
+                Backend::Isahc => Self::Isahc(BeatSaverIsahc::from_options(options)?),
+                #[cfg(feature = "surf_backend")]
+                Backend::Surf => Self::Surf(BeatSaverSurf::from_options(options)?),
+            })
+        }
+        /// Backend requests are sent with
+        pub fn backend(&self) -> Backend {
+            match self {
+                #[cfg(feature = "reqwest_backend")]
+                Self::Reqwest(_) => Backend::Reqwest,
+                #[cfg(feature = "hyper_backend")]
+                Self::Hyper(_) => Backend::Hyper,
+                #[cfg(feature = "isahc_backend")]
+                Self::Isahc(_) => Backend::Isahc,
+                #[cfg(feature = "surf_backend")]
+                Self::Surf(_) => Backend::Surf,
+            }
+        }
+        /// Attaches a [KeyIndex][crate::index::KeyIndex] that records every map retrieved by this client
+        pub fn with_key_index(self, index: Arc<KeyIndex>) -> Self {
+            rewrap!(self, c => c.with_key_index(index))
+        }
+        /// Attaches [Credentials][crate::auth::Credentials] that are sent with every request
+        pub fn with_credentials(self, credentials: Credentials) -> Self {
+            rewrap!(self, c => c.with_credentials(credentials))
+        }
+        /// Sets how requests that hit a rate limit are handled
+        pub fn with_rate_limit_policy(self, policy: RateLimitPolicy) -> Self {
+            rewrap!(self, c => c.with_rate_limit_policy(policy))
+        }
+        /// Records the rate limit quota in the provided [RateLimitTracker][crate::RateLimitTracker]
+        pub fn with_rate_limit_tracker(self, tracker: Arc<RateLimitTracker>) -> Self {
+            rewrap!(self, c => c.with_rate_limit_tracker(tracker))
+        }
+        /// Sets how requests that fail due to transient errors are retried
+        pub fn with_retry_policy(self, policy: RetryPolicy) -> Self {
+            rewrap!(self, c => c.with_retry_policy(policy))
+        }
+        /// Sets the route map archives are downloaded through
+        pub fn with_download_route(self, route: DownloadRoute) -> Self {
+            rewrap!(self, c => c.with_download_route(route))
+        }
+        /// Sends requests to the provided base URL instead of [BEATSAVER_URL][crate::BEATSAVER_URL]
+        pub fn with_base_url(self, url: Url) -> Self {
+            rewrap!(self, c => c.with_base_url(url))
+        }
+        /// Attaches a [ResponseCache][crate::cache::ResponseCache] used to revalidate responses
+        pub fn with_response_cache(self, cache: Arc<dyn ResponseCache>) -> Self {
+            rewrap!(self, c => c.with_response_cache(cache))
+        }
+        /// Attaches an [Interceptor][crate::intercept::Interceptor] called around every request
+        pub fn with_interceptor(self, interceptor: Arc<dyn Interceptor>) -> Self {
+            rewrap!(self, c => c.with_interceptor(interceptor))
+        }
+    }
+    #[cfg(feature = "reqwest_backend")]
+    impl From<BeatSaverReqwest> for DynBeatSaver {
+        fn from(client: BeatSaverReqwest) -> Self {
+            Self::Reqwest(client)
+        }
+    }
+    #[cfg(feature = "hyper_backend")]
+    impl From<BeatSaverHyper> for DynBeatSaver {
+        fn from(client: BeatSaverHyper) -> Self {
+            Self::Hyper(client)
+        }
+    }
+    #[cfg(feature = "isahc_backend")]
+    impl From<BeatSaverIsahc> for DynBeatSaver {
+        fn from(client: BeatSaverIsahc) -> Self {
+            Self::Isahc(client)
+        }
+    }
+    #[cfg(feature = "surf_backend")]
+    impl From<BeatSaverSurf> for DynBeatSaver {
+        fn from(client: BeatSaverSurf) -> Self {
+            Self::Surf(client)
+        }
+    }
+    #[async_trait]
+    impl BeatSaverApiAsync<BackendError> for DynBeatSaver {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            dispatch!(self, c => c.key_index())
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            dispatch!(self, c => c.credentials())
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            dispatch!(self, c => c.rate_limit_policy())
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            dispatch!(self, c => c.rate_limit_tracker())
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            dispatch!(self, c => c.retry_policy())
+        }
+        fn is_transient(&self, error: &BeatSaverError) -> bool {
+            match error {
+                BeatSaverApiError::RequestError(e) => dispatch!(self, c => e
+                    .downcast_ref()
+                    .is_some_and(|e| c.backend().is_transient(e))),
+                e => e.is_retryable(),
+            }
+        }
+        fn download_route(&self) -> DownloadRoute {
+            dispatch!(self, c => c.download_route())
+        }
+        fn base_url(&self) -> &Url {
+            dispatch!(self, c => c.base_url())
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            dispatch!(self, c => c.response_cache())
+        }
+        async fn sleep(&self, duration: Duration) {
+            dispatch!(self, c => c.sleep(duration).await)
+        }
+        async fn send(&self, request: Request<Bytes>) -> Result<Response<Bytes>, BeatSaverError> {
+            dispatch!(self, c => c.send(request).await.map_err(|e| e.erase()))
+        }
+        async fn send_streaming<'a>(
+            &'a self,
+            request: Request<Bytes>,
+        ) -> Result<Response<ByteStream<'a, BackendError>>, BeatSaverError> {
+            dispatch!(self, c => {
+                let response = c.send_streaming(request).await.map_err(|e| e.erase())?;
+                Ok(response.map(|body| {
+                    Box::pin(body.map_err(|e| e.erase())) as ByteStream<'a, BackendError>
+                }))
+            })
+        }
+    }
+}
+#[cfg(any(
+    feature = "reqwest_backend",
+    feature = "hyper_backend",
+    feature = "isahc_backend",
+    feature = "surf_backend"
+))]
+pub use dyn_client::{Backend, DynBeatSaver, UnknownBackend};
+
+#[cfg(test)]
+mod tests {
+    use crate::client::ClientOptions;
+    use std::time::Duration;
+
+    /// Starts a server that accepts connections but never responds
+    #[cfg(any(
+        feature = "reqwest_backend",
+        feature = "hyper_backend",
+        feature = "isahc_backend",
+        feature = "surf_backend",
+        feature = "ureq_backend",
+        feature = "attohttpc_backend"
+    ))]
+    fn stalled_server() -> url::Url {
+        use std::net::TcpListener;
+        use std::thread;
+        use url::Url;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(format!("http://{}/", listener.local_addr().unwrap()).as_str());
+        thread::spawn(move || {
+            let _connections: Vec<_> = listener.incoming().collect();
+        });
+        url.unwrap()
+    }
+    /// Returns a URL of a closed port, connections to it are refused
+    #[cfg(any(
+        feature = "reqwest_backend",
+        feature = "hyper_backend",
+        feature = "isahc_backend",
+        feature = "surf_backend",
+        feature = "ureq_backend",
+        feature = "attohttpc_backend"
+    ))]
+    fn refused_server() -> url::Url {
+        use std::net::TcpListener;
+        use url::Url;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        Url::parse(format!("http://{}/", listener.local_addr().unwrap()).as_str()).unwrap()
+    }
+    /// Starts a server that answers the first request with `ok`, and returns the received request
+    #[cfg(any(
+        feature = "reqwest_backend",
+        feature = "hyper_backend",
+        feature = "isahc_backend",
+        feature = "surf_backend",
+        feature = "ureq_backend",
+        feature = "attohttpc_backend"
+    ))]
+    fn recording_server() -> (url::Url, std::sync::mpsc::Receiver<String>) {
+        use std::net::TcpListener;
+        use std::sync::mpsc;
+        use std::thread;
+        use url::Url;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(format!("http://{}/", listener.local_addr().unwrap()).as_str());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            tx.send(respond_ok(&mut stream)).unwrap();
+        });
+        (url.unwrap(), rx)
+    }
+    /// Reads a request from the stream and answers it with `ok`, returning the request head
+    #[cfg(any(
+        feature = "reqwest_backend",
+        feature = "hyper_backend",
+        feature = "isahc_backend",
+        feature = "surf_backend",
+        feature = "ureq_backend",
+        feature = "attohttpc_backend"
+    ))]
+    fn respond_ok(stream: &mut std::net::TcpStream) -> String {
+        use std::io::Write;
+
+        let request = read_request(stream);
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+            .unwrap();
+        request
+    }
+    /// Starts a server that answers two requests with `ok` on a single connection, so the second
+    /// request fails unless the connection is reused
+    #[cfg(any(feature = "reqwest_backend", feature = "ureq_backend"))]
+    fn keep_alive_server() -> url::Url {
+        use std::net::TcpListener;
+        use std::thread;
+        use url::Url;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(format!("http://{}/", listener.local_addr().unwrap()).as_str());
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            respond_ok(&mut stream);
+            respond_ok(&mut stream);
+            // Keeps the listener open, so new connections hang instead of being refused
+            let _connections: Vec<_> = listener.incoming().collect();
+        });
+        url.unwrap()
+    }
+    /// Reads the head of a request from the stream
+    #[cfg(any(
+        feature = "reqwest_backend",
+        feature = "hyper_backend",
+        feature = "isahc_backend",
+        feature = "surf_backend",
+        feature = "ureq_backend",
+        feature = "attohttpc_backend"
+    ))]
+    fn read_request(stream: &mut std::net::TcpStream) -> String {
+        use std::io::Read;
+
+        let (mut request, mut buf) = (vec![], [0; 1]);
+        while !request.ends_with(b"\r\n\r\n") {
+            stream.read_exact(&mut buf).unwrap();
+            request.extend_from_slice(&buf);
+        }
+        String::from_utf8_lossy(&request).into_owned()
+    }
+    /// Body sent by the [gzip_server]
+    #[cfg(all(
+        feature = "flate2",
+        any(
+            feature = "reqwest_backend",
+            feature = "hyper_backend",
+            feature = "isahc_backend",
+            feature = "surf_backend",
+            feature = "ureq_backend",
+            feature = "attohttpc_backend"
+        )
+    ))]
+    const GZIP_BODY: &[u8] = b"compressed compressed compressed compressed";
+    /// Starts a server that answers the first request with a gzip compressed body if the request
+    /// accepts it
+    #[cfg(all(
+        feature = "flate2",
+        any(
+            feature = "reqwest_backend",
+            feature = "hyper_backend",
+            feature = "isahc_backend",
+            feature = "surf_backend",
+            feature = "ureq_backend",
+            feature = "attohttpc_backend"
+        )
+    ))]
+    fn gzip_server() -> url::Url {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+        use std::net::TcpListener;
+        use std::thread;
+        use url::Url;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(format!("http://{}/", listener.local_addr().unwrap()).as_str());
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = read_request(&mut stream).to_ascii_lowercase();
+            let gzip = request
+                .lines()
+                .any(|l| l.starts_with("accept-encoding:") && l.contains("gzip"));
+            let (encoding, body) = match gzip {
+                true => {
+                    let mut encoder = GzEncoder::new(vec![], Compression::default());
+                    encoder.write_all(GZIP_BODY).unwrap();
+                    ("Content-Encoding: gzip\r\n", encoder.finish().unwrap())
+                }
+                false => ("", GZIP_BODY.to_vec()),
+            };
+            let head = format!(
+                "HTTP/1.1 200 OK\r\n{}Content-Length: {}\r\n\r\n",
+                encoding,
+                body.len()
+            );
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(&body).unwrap();
+        });
+        url.unwrap()
+    }
+    /// URL that can only be reached through the proxy
+    #[cfg(any(
+        feature = "reqwest_backend",
+        feature = "isahc_backend",
+        feature = "surf_backend",
+        feature = "ureq_backend",
+        feature = "attohttpc_backend"
+    ))]
+    fn proxied_url() -> url::Url {
+        url::Url::parse("http://beatsaver.invalid/api/maps/id/2144").unwrap()
+    }
+    fn timeout_options() -> ClientOptions {
+        ClientOptions::new()
+            .with_connect_timeout(Duration::from_millis(100))
+            .with_read_timeout(Duration::from_millis(200))
+    }
+
+    #[test]
+    fn test_client_options() {
+        assert_eq!(ClientOptions::new().read_timeout, None);
+        let options = timeout_options();
+        assert_eq!(options.connect_timeout, Some(Duration::from_millis(100)));
+        assert_eq!(options.read_timeout, Some(Duration::from_millis(200)));
+        assert!(ClientOptions::new().proxy_from_env);
+        assert!(ClientOptions::new().compression);
+        let options = ClientOptions::new()
+            .with_pool_idle_timeout(Duration::from_secs(60))
+            .with_pool_max_idle_per_host(4);
+        assert_eq!(options.pool_idle_timeout, Some(Duration::from_secs(60)));
+        assert_eq!(options.pool_max_idle_per_host, Some(4));
+        let options = ClientOptions::new().with_proxy_from_env(false);
+        assert!(!options.proxy_from_env);
+    }
+    #[cfg(feature = "surf_backend")]
+    #[async_std::test]
+    async fn test_surf_proxy() {
+        use crate::client::BeatSaverSurf;
+        use crate::{get_request, BeatSaverApiAsync};
 
-        assert_eq!(map.key, "2144");
+        let (proxy, requests) = recording_server();
+        let client = BeatSaverSurf::from_options(ClientOptions::new().with_proxy(proxy)).unwrap();
+        let response = client.send(get_request(proxied_url())).await.unwrap();
+        assert_eq!(response.body().as_ref(), b"ok");
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET http://beatsaver.invalid/"));
     }
     #[cfg(feature = "reqwest_backend")]
     #[tokio::test]
-    async fn test_reqwest_map() {
+    async fn test_reqwest_proxy() {
+        use crate::client::BeatSaverReqwest;
+        use crate::{get_request, BeatSaverApiAsync};
+
+        let (proxy, requests) = recording_server();
+        let client =
+            BeatSaverReqwest::from_options(ClientOptions::new().with_proxy(proxy)).unwrap();
+        let response = client.send(get_request(proxied_url())).await.unwrap();
+        assert_eq!(response.body().as_ref(), b"ok");
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET http://beatsaver.invalid/"));
+    }
+    #[cfg(feature = "isahc_backend")]
+    #[test]
+    fn test_isahc_proxy() {
+        use crate::client::BeatSaverIsahc;
+        use crate::{get_request, BeatSaverApiAsync};
+        use futures::executor::block_on;
+
+        let (proxy, requests) = recording_server();
+        let client = BeatSaverIsahc::from_options(ClientOptions::new().with_proxy(proxy)).unwrap();
+        let response = block_on(client.send(get_request(proxied_url()))).unwrap();
+        assert_eq!(response.body().as_ref(), b"ok");
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET http://beatsaver.invalid/"));
+    }
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_ureq_proxy() {
+        use crate::client::BeatSaverUreq;
+        use crate::{get_request, BeatSaverApiSync};
+
+        let (proxy, requests) = recording_server();
+        let client = BeatSaverUreq::from_options(ClientOptions::new().with_proxy(proxy)).unwrap();
+        let response = client.send(get_request(proxied_url())).unwrap();
+        assert_eq!(response.body().as_ref(), b"ok");
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET http://beatsaver.invalid/"));
+    }
+    #[cfg(feature = "attohttpc_backend")]
+    #[test]
+    fn test_attohttpc_proxy() {
+        use crate::client::BeatSaverAttohttpc;
+        use crate::{get_request, BeatSaverApiSync};
+
+        let (proxy, requests) = recording_server();
+        let client =
+            BeatSaverAttohttpc::from_options(ClientOptions::new().with_proxy(proxy)).unwrap();
+        let response = client.send(get_request(proxied_url())).unwrap();
+        assert_eq!(response.body().as_ref(), b"ok");
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET http://beatsaver.invalid/"));
+    }
+    #[cfg(any(
+        feature = "reqwest_backend",
+        feature = "isahc_backend",
+        feature = "surf_backend",
+        feature = "ureq_backend",
+        feature = "attohttpc_backend"
+    ))]
+    #[test]
+    fn test_unsupported_proxy() {
+        use crate::BeatSaverApiError;
+
+        let options = ClientOptions::new().with_proxy("ftp://proxy.invalid:21".parse().unwrap());
+        #[cfg(feature = "reqwest_backend")]
+        assert!(matches!(
+            crate::client::BeatSaverReqwest::from_options(options.clone()),
+            Err(BeatSaverApiError::ArgumentError("proxy"))
+        ));
+        #[cfg(feature = "isahc_backend")]
+        assert!(matches!(
+            crate::client::BeatSaverIsahc::from_options(options.clone()),
+            Err(BeatSaverApiError::ArgumentError("proxy"))
+        ));
+        #[cfg(feature = "surf_backend")]
+        assert!(matches!(
+            crate::client::BeatSaverSurf::from_options(options.clone()),
+            Err(BeatSaverApiError::ArgumentError("proxy"))
+        ));
+        #[cfg(feature = "ureq_backend")]
+        assert!(matches!(
+            crate::client::BeatSaverUreq::from_options(options.clone()),
+            Err(BeatSaverApiError::ArgumentError("proxy"))
+        ));
+        #[cfg(feature = "attohttpc_backend")]
+        assert!(matches!(
+            crate::client::BeatSaverAttohttpc::from_options(options),
+            Err(BeatSaverApiError::ArgumentError("proxy"))
+        ));
+    }
+    #[cfg(feature = "surf_backend")]
+    #[async_std::test]
+    async fn test_surf_timeout() {
+        use crate::client::BeatSaverSurf;
+        use crate::{get_request, BeatSaverApiAsync, BeatSaverApiError};
+
+        let client = BeatSaverSurf::from_options(timeout_options()).unwrap();
+        let result = client.send(get_request(stalled_server())).await;
+        assert!(matches!(result, Err(BeatSaverApiError::IoError(_))));
+    }
+    #[cfg(feature = "reqwest_backend")]
+    #[tokio::test]
+    async fn test_reqwest_timeout() {
+        use crate::client::BeatSaverReqwest;
+        use crate::{get_request, BeatSaverApiAsync, BeatSaverApiError};
+
+        let client = BeatSaverReqwest::from_options(timeout_options()).unwrap();
+        let result = client.send(get_request(stalled_server())).await;
+        assert!(matches!(result, Err(BeatSaverApiError::IoError(_))));
+    }
+    #[cfg(feature = "hyper_backend")]
+    #[tokio::test]
+    async fn test_hyper_timeout() {
+        use crate::client::BeatSaverHyper;
+        use crate::{get_request, BeatSaverApiAsync, BeatSaverApiError};
+
+        let client = BeatSaverHyper::from_options(timeout_options());
+        let result = client.send(get_request(stalled_server())).await;
+        assert!(matches!(result, Err(BeatSaverApiError::IoError(_))));
+    }
+    #[cfg(feature = "isahc_backend")]
+    #[test]
+    fn test_isahc_timeout() {
+        use crate::client::BeatSaverIsahc;
+        use crate::{get_request, BeatSaverApiAsync, BeatSaverApiError};
+        use futures::executor::block_on;
+
+        let client = BeatSaverIsahc::from_options(timeout_options()).unwrap();
+        let result = block_on(client.send(get_request(stalled_server())));
+        assert!(matches!(result, Err(BeatSaverApiError::IoError(_))));
+    }
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_ureq_timeout() {
+        use crate::client::BeatSaverUreq;
+        use crate::{get_request, BeatSaverApiSync};
+
+        let client = BeatSaverUreq::from_options(timeout_options()).unwrap();
+        assert!(client.send(get_request(stalled_server())).is_err());
+    }
+    #[cfg(feature = "attohttpc_backend")]
+    #[test]
+    fn test_attohttpc_timeout() {
+        use crate::client::BeatSaverAttohttpc;
+        use crate::{get_request, BeatSaverApiSync};
+
+        let client = BeatSaverAttohttpc::from_options(timeout_options()).unwrap();
+        assert!(client.send(get_request(stalled_server())).is_err());
+    }
+    #[cfg(feature = "surf_backend")]
+    #[async_std::test]
+    async fn test_surf_transient() {
+        use crate::client::BeatSaverSurf;
+        use crate::{get_request, BeatSaverApiAsync, BeatSaverApiError, MapId};
+
+        let client = BeatSaverSurf::new();
+        let e = client
+            .send(get_request(refused_server()))
+            .await
+            .unwrap_err();
+        assert!(matches!(e, BeatSaverApiError::RequestError(_)));
+        assert!(client.is_transient(&e));
+        assert!(!client.is_transient(&BeatSaverApiError::NotFound(MapId::from(1))));
+    }
+    #[cfg(feature = "reqwest_backend")]
+    #[tokio::test]
+    async fn test_reqwest_transient() {
+        use crate::client::BeatSaverReqwest;
+        use crate::{get_request, BeatSaverApiAsync, BeatSaverApiError, MapId};
+
+        let client = BeatSaverReqwest::new();
+        let e = client
+            .send(get_request(refused_server()))
+            .await
+            .unwrap_err();
+        assert!(matches!(e, BeatSaverApiError::RequestError(_)));
+        assert!(client.is_transient(&e));
+        assert!(!client.is_transient(&BeatSaverApiError::NotFound(MapId::from(1))));
+    }
+    #[cfg(feature = "hyper_backend")]
+    #[tokio::test]
+    async fn test_hyper_transient() {
+        use crate::client::BeatSaverHyper;
+        use crate::{get_request, BeatSaverApiAsync, BeatSaverApiError};
+
+        let client = BeatSaverHyper::new();
+        let e = client
+            .send(get_request(refused_server()))
+            .await
+            .unwrap_err();
+        assert!(matches!(e, BeatSaverApiError::RequestError(_)));
+        assert!(client.is_transient(&e));
+    }
+    #[cfg(feature = "isahc_backend")]
+    #[test]
+    fn test_isahc_transient() {
+        use crate::client::BeatSaverIsahc;
+        use crate::{get_request, BeatSaverApiAsync, BeatSaverApiError};
+        use futures::executor::block_on;
+
+        let client = BeatSaverIsahc::new();
+        let e = block_on(client.send(get_request(refused_server()))).unwrap_err();
+        assert!(matches!(e, BeatSaverApiError::RequestError(_)));
+        assert!(client.is_transient(&e));
+    }
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_ureq_transient() {
+        use crate::client::BeatSaverUreq;
+        use crate::{get_request, BeatSaverApiError, BeatSaverApiSync};
+
+        let client = BeatSaverUreq::new();
+        let e = client.send(get_request(refused_server())).unwrap_err();
+        assert!(matches!(e, BeatSaverApiError::RequestError(_)));
+        assert!(client.is_transient(&e));
+    }
+    #[cfg(feature = "attohttpc_backend")]
+    #[test]
+    fn test_attohttpc_transient() {
+        use crate::client::BeatSaverAttohttpc;
+        use crate::{get_request, BeatSaverApiError, BeatSaverApiSync, MapId};
+
+        let client = BeatSaverAttohttpc::new();
+        let e = client.send(get_request(refused_server())).unwrap_err();
+        assert!(matches!(e, BeatSaverApiError::RequestError(_)));
+        assert!(client.is_transient(&e));
+        assert!(!client.is_transient(&BeatSaverApiError::NotFound(MapId::from(1))));
+    }
+    #[cfg(all(feature = "reqwest_backend", feature = "hyper_backend"))]
+    #[tokio::test]
+    async fn test_dyn_transient() {
+        use crate::client::{Backend, DynBeatSaver};
+        use crate::{get_request, BeatSaverApiAsync};
+
+        for backend in [Backend::Reqwest, Backend::Hyper] {
+            let client = DynBeatSaver::new(backend);
+            let e = client
+                .send(get_request(refused_server()))
+                .await
+                .unwrap_err();
+            assert!(client.is_transient(&e));
+        }
+    }
+    #[cfg(feature = "surf_backend")]
+    #[async_std::test]
+    async fn test_surf_base_url() {
+        use crate::client::BeatSaverSurf;
+        use crate::BeatSaverApiAsync;
+        use std::convert::TryInto;
+
+        let (server, requests) = recording_server();
+        let client = BeatSaverSurf::new().with_base_url(server.join("api").unwrap());
+        assert!(client.map(&"2144".try_into().unwrap()).await.is_err());
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /api/maps/id/2144 "));
+    }
+    #[cfg(feature = "reqwest_backend")]
+    #[tokio::test]
+    async fn test_reqwest_base_url() {
         use crate::client::BeatSaverReqwest;
         use crate::BeatSaverApiAsync;
         use std::convert::TryInto;
 
+        let (server, requests) = recording_server();
+        let client = BeatSaverReqwest::new().with_base_url(server.join("api").unwrap());
+        assert!(client.map(&"2144".try_into().unwrap()).await.is_err());
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /api/maps/id/2144 "));
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_http_backend_sync() {
+        use crate::client::{BeatSaverClient, HttpBackendSync};
+        use crate::tests::FakeError;
+        use crate::{BeatSaverApiError, BeatSaverApiSync};
+        use bytes::Bytes;
+        use http::{Request, Response, StatusCode};
+        use std::convert::TryInto;
+
+        struct NotFoundBackend;
+        impl HttpBackendSync for NotFoundBackend {
+            type Error = FakeError;
+
+            fn send(
+                &self,
+                _request: Request<Bytes>,
+            ) -> Result<Response<Bytes>, BeatSaverApiError<FakeError>> {
+                let mut response = Response::new(Bytes::new());
+                *response.status_mut() = StatusCode::NOT_FOUND;
+                Ok(response)
+            }
+        }
+
+        let client = BeatSaverClient::from_backend(NotFoundBackend);
+        assert!(matches!(
+            client.map(&"2144".try_into().unwrap()),
+            Err(BeatSaverApiError::NotFound(_))
+        ));
+    }
+    #[cfg(feature = "sync_adapter")]
+    #[tokio::test]
+    async fn test_async_adapter() {
+        use crate::client::AsyncAdapter;
+        use crate::tests::{map_json, FakeClient, FakeClientPaged};
+        use crate::{BeatSaverApiAsync, BeatSaverApiError, BEATSAVER_URL};
+        use std::collections::HashMap;
+        use std::convert::TryInto;
+
+        let client = AsyncAdapter::new(FakeClient::new(
+            BEATSAVER_URL.join("maps/id/2144").unwrap(),
+            map_json("2144").into(),
+        ));
+        let map = client.map(&"2144".try_into().unwrap()).await.unwrap();
+        assert_eq!(map.id, "2144");
+        // The configuration of the wrapped client is used
+        assert!(client
+            .inner()
+            .index
+            .resolve(&map.versions[0].hash.parse().unwrap())
+            .is_some());
+
+        let mut client = FakeClientPaged::new(HashMap::new());
+        client
+            .deleted
+            .insert(BEATSAVER_URL.join("maps/id/2144").unwrap());
+        let client = AsyncAdapter::new(client);
+        assert!(matches!(
+            client.map_by_key("2144").await,
+            Err(BeatSaverApiError::NotFound(_))
+        ));
+    }
+    #[cfg(feature = "reqwest_backend")]
+    #[test]
+    fn test_backend_from_str() {
+        use crate::client::Backend;
+
+        assert_eq!("reqwest".parse(), Ok(Backend::Reqwest));
+        assert_eq!("Reqwest".parse(), Ok(Backend::Reqwest));
+        assert_eq!(Backend::Reqwest.to_string(), "reqwest");
+        assert!(Backend::ENABLED.contains(&Backend::Reqwest));
+        assert!("curl".parse::<Backend>().is_err());
+    }
+    #[cfg(feature = "reqwest_backend")]
+    #[tokio::test]
+    async fn test_dyn_base_url() {
+        use crate::client::{Backend, DynBeatSaver};
+        use crate::{BeatSaverApiAsync, BeatSaverApiError};
+        use std::convert::TryInto;
+
+        let (server, requests) = recording_server();
+        let client = DynBeatSaver::new(Backend::Reqwest).with_base_url(server.join("api").unwrap());
+        assert_eq!(client.backend(), Backend::Reqwest);
+        // The server answers `ok`, which isn't a map
+        assert!(matches!(
+            client.map(&"2144".try_into().unwrap()).await,
+            Err(BeatSaverApiError::SerializeError(_))
+        ));
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /api/maps/id/2144 "));
+    }
+    #[cfg(feature = "hyper_backend")]
+    #[tokio::test]
+    async fn test_hyper_base_url() {
+        use crate::client::BeatSaverHyper;
+        use crate::BeatSaverApiAsync;
+        use std::convert::TryInto;
+
+        let (server, requests) = recording_server();
+        let client = BeatSaverHyper::new().with_base_url(server.join("api").unwrap());
+        assert!(client.map(&"2144".try_into().unwrap()).await.is_err());
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /api/maps/id/2144 "));
+    }
+    #[cfg(feature = "isahc_backend")]
+    #[test]
+    fn test_isahc_base_url() {
+        use crate::client::BeatSaverIsahc;
+        use crate::BeatSaverApiAsync;
+        use futures::executor::block_on;
+        use std::convert::TryInto;
+
+        let (server, requests) = recording_server();
+        let client = BeatSaverIsahc::new().with_base_url(server.join("api").unwrap());
+        assert!(block_on(client.map(&"2144".try_into().unwrap())).is_err());
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /api/maps/id/2144 "));
+    }
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_ureq_base_url() {
+        use crate::client::BeatSaverUreq;
+        use crate::BeatSaverApiSync;
+        use std::convert::TryInto;
+
+        let (server, requests) = recording_server();
+        let client = BeatSaverUreq::new().with_base_url(server.join("api").unwrap());
+        assert!(client.map(&"2144".try_into().unwrap()).is_err());
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /api/maps/id/2144 "));
+    }
+    #[cfg(feature = "attohttpc_backend")]
+    #[test]
+    fn test_attohttpc_base_url() {
+        use crate::client::BeatSaverAttohttpc;
+        use crate::BeatSaverApiSync;
+        use std::convert::TryInto;
+
+        let (server, requests) = recording_server();
+        let client = BeatSaverAttohttpc::new().with_base_url(server.join("api").unwrap());
+        assert!(client.map(&"2144".try_into().unwrap()).is_err());
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /api/maps/id/2144 "));
+    }
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_blocking_base_url() {
+        use crate::client::BeatSaverBlocking;
+        use crate::BeatSaverApiSync;
+        use std::convert::TryInto;
+
+        let (server, requests) = recording_server();
+        let client = BeatSaverBlocking::new().with_base_url(server.join("api").unwrap());
+        assert!(client.map(&"2144".try_into().unwrap()).is_err());
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /api/maps/id/2144 "));
+    }
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_blocking_streaming() {
+        use crate::client::{BeatSaverBlocking, BlockingStream};
+        use crate::{get_request, BeatSaverApiSync};
+        use futures::stream;
+        use std::io::Read;
+
+        let client = BeatSaverBlocking::new();
+        let response = client.send_streaming(get_request(gzip_server())).unwrap();
+        let mut body = vec![];
+        response.into_body().read_to_end(&mut body).unwrap();
+        assert_eq!(body, GZIP_BODY);
+
+        let runtime = client.backend().runtime().clone();
+        let items: Vec<_> = BlockingStream::new(runtime, stream::iter(1..4)).collect();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_ureq_agent() {
+        use crate::client::BeatSaverUreq;
+        use crate::{get_request, BeatSaverApiSync};
+
+        let url = keep_alive_server();
+        let agent = ureq::AgentBuilder::new()
+            .timeout_read(Duration::from_secs(1))
+            .build();
+        let client = BeatSaverUreq::from(agent);
+        for _ in 0..2 {
+            let response = client.send(get_request(url.clone())).unwrap();
+            assert_eq!(response.body().as_ref(), b"ok");
+        }
+    }
+    #[cfg(any(feature = "reqwest_backend", feature = "hyper_backend"))]
+    #[test]
+    fn test_decode_stream() {
+        use crate::client::{decode_stream, Decoder};
+        use crate::tests::FakeError;
+        use crate::BeatSaverApiError;
+        use bytes::Bytes;
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use futures::executor::block_on;
+        use futures::{stream, TryStreamExt};
+        use http::header::{HeaderMap, HeaderValue, CONTENT_ENCODING, CONTENT_LENGTH};
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(GZIP_BODY).unwrap();
+        let encoded = encoder.finish().unwrap();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("GZIP"));
+        headers.insert(CONTENT_LENGTH, HeaderValue::from(encoded.len()));
+        let decoder = Decoder::from_headers(&mut headers);
+        assert!(decoder.is_some());
+        assert!(headers.is_empty());
+
+        let chunks: Vec<Result<Bytes, BeatSaverApiError<FakeError>>> = encoded
+            .chunks(5)
+            .map(|c| Ok(Bytes::copy_from_slice(c)))
+            .collect();
+        let body = decode_stream(stream::iter(chunks), decoder);
+        let decoded: Vec<Bytes> = block_on(body.try_collect()).unwrap();
+        assert_eq!(decoded.concat(), GZIP_BODY);
+
+        let truncated = vec![Ok(Bytes::copy_from_slice(&encoded[..10]))];
+        let decoder = Decoder::from_headers(&mut {
+            let mut headers = HeaderMap::new();
+            headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+            headers
+        });
+        let body = decode_stream::<_, FakeError>(stream::iter(truncated), decoder);
+        assert!(block_on(body.try_collect::<Vec<Bytes>>()).is_err());
+    }
+    #[cfg(feature = "reqwest_backend")]
+    #[tokio::test]
+    async fn test_reqwest_compression() {
+        use crate::client::BeatSaverReqwest;
+        use crate::{get_request, BeatSaverApiAsync};
+        use futures::TryStreamExt;
+
         let client = BeatSaverReqwest::new();
+        let response = client.send(get_request(gzip_server())).await.unwrap();
+        assert_eq!(response.body().as_ref(), GZIP_BODY);
+        let response = client.send_streaming(get_request(gzip_server())).await;
+        let chunks: Vec<_> = response.unwrap().into_body().try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), GZIP_BODY);
+    }
+    #[cfg(feature = "hyper_backend")]
+    #[tokio::test]
+    async fn test_hyper_compression() {
+        use crate::client::BeatSaverHyper;
+        use crate::{get_request, BeatSaverApiAsync};
+        use futures::TryStreamExt;
+
+        let client = BeatSaverHyper::new();
+        let response = client.send(get_request(gzip_server())).await.unwrap();
+        assert_eq!(response.body().as_ref(), GZIP_BODY);
+        let response = client.send_streaming(get_request(gzip_server())).await;
+        let chunks: Vec<_> = response.unwrap().into_body().try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), GZIP_BODY);
+    }
+    #[cfg(all(feature = "isahc_backend", feature = "flate2"))]
+    #[test]
+    fn test_isahc_compression() {
+        use crate::client::BeatSaverIsahc;
+        use crate::{get_request, BeatSaverApiAsync};
+        use futures::executor::block_on;
+
+        let client = BeatSaverIsahc::new();
+        let response = block_on(client.send(get_request(gzip_server()))).unwrap();
+        assert_eq!(response.body().as_ref(), GZIP_BODY);
+    }
+    #[cfg(all(feature = "surf_backend", feature = "flate2"))]
+    #[async_std::test]
+    async fn test_surf_compression() {
+        use crate::client::BeatSaverSurf;
+        use crate::{get_request, BeatSaverApiAsync};
+
+        let client = BeatSaverSurf::new();
+        let response = client.send(get_request(gzip_server())).await.unwrap();
+        assert_eq!(response.body().as_ref(), GZIP_BODY);
+    }
+    #[cfg(all(feature = "ureq_backend", feature = "flate2"))]
+    #[test]
+    fn test_ureq_compression() {
+        use crate::client::BeatSaverUreq;
+        use crate::{get_request, BeatSaverApiSync};
+
+        let client = BeatSaverUreq::new();
+        let response = client.send(get_request(gzip_server())).unwrap();
+        assert_eq!(response.body().as_ref(), GZIP_BODY);
+
+        let client =
+            BeatSaverUreq::from_options(ClientOptions::new().with_compression(false)).unwrap();
+        let (server, requests) = recording_server();
+        client.send(get_request(server)).unwrap();
+        assert!(requests
+            .recv()
+            .unwrap()
+            .to_ascii_lowercase()
+            .contains("accept-encoding: identity"));
+    }
+    #[cfg(all(feature = "attohttpc_backend", feature = "flate2"))]
+    #[test]
+    fn test_attohttpc_compression() {
+        use crate::client::BeatSaverAttohttpc;
+        use crate::{get_request, BeatSaverApiSync};
+        use std::io::Read;
+
+        let client = BeatSaverAttohttpc::new();
+        let response = client.send(get_request(gzip_server())).unwrap();
+        assert_eq!(response.body().as_ref(), GZIP_BODY);
+        let response = client.send_streaming(get_request(gzip_server())).unwrap();
+        let mut body = vec![];
+        response.into_body().read_to_end(&mut body).unwrap();
+        assert_eq!(body, GZIP_BODY);
+    }
+    #[cfg(feature = "reqwest_backend")]
+    #[tokio::test]
+    async fn test_reqwest_pool() {
+        use crate::client::BeatSaverReqwest;
+        use crate::{get_request, BeatSaverApiAsync};
+
+        let options = timeout_options()
+            .with_pool_idle_timeout(Duration::from_secs(60))
+            .with_tcp_keepalive(Duration::from_secs(30))
+            .with_http2_keep_alive_interval(Duration::from_secs(30));
+        let client = BeatSaverReqwest::from_options(options.clone()).unwrap();
+        let url = keep_alive_server();
+        for _ in 0..2 {
+            let response = client.send(get_request(url.clone())).await.unwrap();
+            assert_eq!(response.body().as_ref(), b"ok");
+        }
+
+        let client =
+            BeatSaverReqwest::from_options(options.with_pool_max_idle_per_host(0)).unwrap();
+        let url = keep_alive_server();
+        assert!(client.send(get_request(url.clone())).await.is_ok());
+        assert!(client.send(get_request(url)).await.is_err());
+    }
+    #[cfg(feature = "surf_backend")]
+    #[async_std::test]
+    async fn test_surf_map() {
+        use crate::client::BeatSaverSurf;
+        use crate::testing::TestServer;
+        use crate::BeatSaverApiAsync;
+        use std::convert::TryInto;
+
+        let server = TestServer::start().unwrap().with_maps(&["2144"]);
+        let client = BeatSaverSurf::new().with_base_url(server.url());
+        let map = client.map(&"2144".try_into().unwrap()).await.unwrap();
+
+        assert_eq!(map.id, "2144");
+    }
+    #[cfg(feature = "reqwest_backend")]
+    #[tokio::test]
+    async fn test_reqwest_map() {
+        use crate::client::BeatSaverReqwest;
+        use crate::testing::TestServer;
+        use crate::BeatSaverApiAsync;
+        use std::convert::TryInto;
+
+        let server = TestServer::start().unwrap().with_maps(&["2144"]);
+        let client = BeatSaverReqwest::new().with_base_url(server.url());
+        let map = client.map(&"2144".try_into().unwrap()).await.unwrap();
+
+        assert_eq!(map.id, "2144");
+    }
+    #[cfg(feature = "hyper_backend")]
+    #[tokio::test]
+    async fn test_hyper_map() {
+        use crate::client::BeatSaverHyper;
+        use crate::testing::TestServer;
+        use crate::BeatSaverApiAsync;
+        use std::convert::TryInto;
+
+        let server = TestServer::start().unwrap().with_maps(&["2144"]);
+        let client = BeatSaverHyper::new().with_base_url(server.url());
         let map = client.map(&"2144".try_into().unwrap()).await.unwrap();
 
-        assert_eq!(map.key, "2144");
+        assert_eq!(map.id, "2144");
+    }
+    #[cfg(feature = "isahc_backend")]
+    #[test]
+    fn test_isahc_map() {
+        use crate::client::BeatSaverIsahc;
+        use crate::testing::TestServer;
+        use crate::BeatSaverApiAsync;
+        use futures::executor::block_on;
+        use std::convert::TryInto;
+
+        let server = TestServer::start().unwrap().with_maps(&["2144"]);
+        let client = BeatSaverIsahc::new().with_base_url(server.url());
+        let map = block_on(client.map(&"2144".try_into().unwrap())).unwrap();
+
+        assert_eq!(map.id, "2144");
     }
     #[cfg(feature = "ureq_backend")]
     #[test]
     fn test_ureq_map() {
         use crate::client::BeatSaverUreq;
+        use crate::testing::TestServer;
         use crate::BeatSaverApiSync;
         use std::convert::TryInto;
 
-        let client = BeatSaverUreq::new();
+        let server = TestServer::start().unwrap().with_maps(&["2144"]);
+        let client = BeatSaverUreq::new().with_base_url(server.url());
+        let map = client.map(&"2144".try_into().unwrap()).unwrap();
+
+        assert_eq!(map.id, "2144");
+    }
+    #[cfg(feature = "attohttpc_backend")]
+    #[test]
+    fn test_attohttpc_map() {
+        use crate::client::BeatSaverAttohttpc;
+        use crate::testing::TestServer;
+        use crate::BeatSaverApiSync;
+        use std::convert::TryInto;
+
+        let server = TestServer::start().unwrap().with_maps(&["2144"]);
+        let client = BeatSaverAttohttpc::new().with_base_url(server.url());
         let map = client.map(&"2144".try_into().unwrap()).unwrap();
 
-        assert_eq!(map.key, "2144");
+        assert_eq!(map.id, "2144");
     }
 }
@@ -0,0 +1,240 @@
+//! # Hash
+//!
+//! This module computes the hash BeatSaver and Beat Saber use to identify a map version.
+//!
+//! The hash is the SHA1 of `Info.dat` followed by every difficulty file, in the order they are
+//! listed in `Info.dat`. Downloads can be checked against [MapVersion.hash][crate::model::map::MapVersion::hash]
+//! to detect corrupted or tampered archives.
+//!
+//! Example:
+//! ```no_run
+//! use beatsaver_rs::hash::{hash_map_folder, hash_map_zip};
+//! use std::path::Path;
+//!
+//! let data = std::fs::read("2144.zip").unwrap();
+//! println!("Zip hash: {}", hash_map_zip(data.into()).unwrap());
+//! let folder = Path::new("CustomLevels/2144 (Believer - rinkusenpai)");
+//! println!("Folder hash: {}", hash_map_folder(folder).unwrap());
+//! ```
+use crate::archive::{Archive, ArchiveError};
+use bytes::Bytes;
+use serde::de::Error as _;
+use serde::Deserialize;
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::io;
+use std::path::Path;
+use thiserror::Error;
+
+/// Contents of `Info.dat` used for hashing
+///
+/// Files up to v2 list their difficulties in `_difficultyBeatmapSets`, v4 files list them in
+/// `difficultyBeatmaps` along with the lightshow of each difficulty.
+#[derive(Deserialize)]
+struct Info {
+    /// Schema version of v4 files, older files use `_version`
+    version: Option<String>,
+    #[serde(rename = "_difficultyBeatmapSets")]
+    sets: Option<Vec<BeatmapSet>>,
+    #[serde(rename = "difficultyBeatmaps")]
+    beatmaps: Option<Vec<BeatmapV4>>,
+}
+impl Info {
+    /// Returns the files hashed after `Info.dat`, in order
+    fn files(&self) -> Result<Vec<&str>, serde_json::Error> {
+        match (&self.version, &self.sets, &self.beatmaps) {
+            (Some(version), _, Some(beatmaps)) if version.starts_with("4.") => Ok(beatmaps
+                .iter()
+                .flat_map(|b| vec![b.beatmap.as_str(), b.lightshow.as_str()])
+                .collect()),
+            (None, Some(sets), _) => Ok(sets
+                .iter()
+                .flat_map(|s| s.beatmaps.iter())
+                .map(|b| b.filename.as_str())
+                .collect()),
+            (version, ..) => Err(serde_json::Error::custom(format!(
+                "unsupported version {}",
+                version.as_deref().unwrap_or("unknown")
+            ))),
+        }
+    }
+}
+#[derive(Deserialize)]
+struct BeatmapSet {
+    #[serde(rename = "_difficultyBeatmaps", default)]
+    beatmaps: Vec<Beatmap>,
+}
+#[derive(Deserialize)]
+struct Beatmap {
+    #[serde(rename = "_beatmapFilename")]
+    filename: String,
+}
+#[derive(Deserialize)]
+struct BeatmapV4 {
+    #[serde(rename = "beatmapDataFilename")]
+    beatmap: String,
+    #[serde(rename = "lightshowDataFilename")]
+    lightshow: String,
+}
+
+/// Error type for hashing a map archive
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum HashError {
+    /// Archive couldn't be read, or is missing a file listed in `Info.dat`
+    #[error(transparent)]
+    ArchiveError(#[from] ArchiveError),
+    /// `Info.dat` couldn't be parsed, or uses an unsupported schema version
+    #[error("Invalid Info.dat: {0}")]
+    InfoError(#[from] serde_json::Error),
+    /// Error in IO while reading a map folder
+    #[error("Couldn't read map folder: {0}")]
+    IoError(#[from] io::Error),
+    /// Hash of the archive doesn't match the expected hash
+    #[error("Expected hash {expected}, found {actual}")]
+    Mismatch {
+        /// Hash the archive was expected to have
+        expected: String,
+        /// Hash computed from the archive
+        actual: String,
+    },
+}
+
+/// Hashes `Info.dat` and the difficulty files it lists, reading files with the provided function
+fn hash_files<F>(mut read: F) -> Result<String, HashError>
+where
+    F: FnMut(&str) -> Result<Vec<u8>, HashError>,
+{
+    let info = read("Info.dat")?;
+    let parsed: Info = serde_json::from_slice(&info)?;
+
+    let mut hasher = Sha1::new();
+    hasher.update(&info);
+    for file in parsed.files()? {
+        hasher.update(read(file)?);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Computes the hash of a map archive, as lowercase hex
+pub fn song_hash(archive: &Archive) -> Result<String, HashError> {
+    hash_files(|name| Ok(archive.read(name)?))
+}
+
+/// Computes the hash of a zipped map (e.g. a downloaded archive), as lowercase hex
+pub fn hash_map_zip(data: Bytes) -> Result<String, HashError> {
+    song_hash(&Archive::new(data)?)
+}
+
+/// Reads a file from a map folder, falling back to a case-insensitive match
+fn read_map_file(dir: &Path, name: &str) -> Result<Vec<u8>, HashError> {
+    let path = dir.join(name);
+    if path.is_file() {
+        return Ok(fs::read(path)?);
+    }
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry
+            .file_name()
+            .to_string_lossy()
+            .eq_ignore_ascii_case(name)
+        {
+            return Ok(fs::read(entry.path())?);
+        }
+    }
+    Err(io::Error::new(io::ErrorKind::NotFound, name).into())
+}
+
+/// Computes the hash of an extracted map folder (e.g. inside `CustomLevels`), as lowercase hex
+pub fn hash_map_folder(dir: &Path) -> Result<String, HashError> {
+    hash_files(|name| read_map_file(dir, name))
+}
+
+/// Checks that the hash of a map archive matches the expected hash
+///
+/// Note: the comparison is case-insensitive
+pub fn verify(archive: &Archive, expected: &str) -> Result<(), HashError> {
+    let actual = song_hash(archive)?;
+    match actual.eq_ignore_ascii_case(expected) {
+        true => Ok(()),
+        false => Err(HashError::Mismatch {
+            expected: expected.to_lowercase(),
+            actual,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::archive::Archive;
+    use crate::hash::{hash_map_folder, hash_map_zip, song_hash, verify, HashError};
+    use crate::install::extract;
+    use crate::tests::{map_archive, zip_archive, MAP_ARCHIVE_HASH};
+    use sha1::{Digest, Sha1};
+
+    #[test]
+    fn test_song_hash() {
+        let archive = Archive::new(map_archive()).unwrap();
+        assert_eq!(song_hash(&archive).unwrap(), MAP_ARCHIVE_HASH);
+        assert_eq!(hash_map_zip(map_archive()).unwrap(), MAP_ARCHIVE_HASH);
+        assert!(matches!(
+            hash_map_zip(b"not a zip".as_ref().into()),
+            Err(HashError::ArchiveError(_))
+        ));
+        assert!(verify(&archive, MAP_ARCHIVE_HASH.to_uppercase().as_str()).is_ok());
+        assert!(matches!(
+            verify(&archive, "89cf8bb07afb3c59ae7b5ac00337d62261c36fb4"),
+            Err(HashError::Mismatch { .. })
+        ));
+    }
+    #[test]
+    fn test_song_hash_v4() {
+        let info = br#"{"version":"4.0.0","difficultyBeatmaps":[{"difficulty":"Expert","beatmapDataFilename":"Expert.dat","lightshowDataFilename":"Lightshow.dat"},{"difficulty":"ExpertPlus","beatmapDataFilename":"ExpertPlus.dat","lightshowDataFilename":"Lightshow.dat"}]}"#;
+        let archive = Archive::new(zip_archive(&[
+            ("Info.dat", info.as_ref()),
+            ("Expert.dat", b"expert".as_ref()),
+            ("ExpertPlus.dat", b"expert plus".as_ref()),
+            ("Lightshow.dat", b"lightshow".as_ref()),
+        ]))
+        .unwrap();
+
+        let mut hasher = Sha1::new();
+        for data in [
+            info.as_ref(),
+            b"expert",
+            b"lightshow",
+            b"expert plus",
+            b"lightshow",
+        ] {
+            hasher.update(data);
+        }
+        assert_eq!(song_hash(&archive).unwrap(), hex::encode(hasher.finalize()));
+    }
+    #[test]
+    fn test_song_hash_unsupported() {
+        for info in [
+            r#"{}"#,
+            r#"{"version":"5.0.0","difficultyBeatmaps":[]}"#,
+            r#"{"version":"4.0.0","_difficultyBeatmapSets":[]}"#,
+        ] {
+            let archive = Archive::new(zip_archive(&[("Info.dat", info.as_bytes())])).unwrap();
+            assert!(matches!(song_hash(&archive), Err(HashError::InfoError(_))));
+        }
+    }
+    #[test]
+    fn test_hash_map_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = Archive::new(map_archive()).unwrap();
+        extract(&archive, dir.path()).unwrap();
+        assert_eq!(hash_map_folder(dir.path()).unwrap(), MAP_ARCHIVE_HASH);
+
+        std::fs::rename(dir.path().join("Info.dat"), dir.path().join("info.dat")).unwrap();
+        assert_eq!(hash_map_folder(dir.path()).unwrap(), MAP_ARCHIVE_HASH);
+
+        std::fs::remove_file(dir.path().join("Expert.dat")).unwrap();
+        assert!(matches!(
+            hash_map_folder(dir.path()),
+            Err(HashError::IoError(_))
+        ));
+    }
+}
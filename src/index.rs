@@ -0,0 +1,197 @@
+//! # Index
+//!
+//! This module contains a reverse lookup index from map hashes to map keys.
+//!
+//! Clients with an attached [KeyIndex][crate::index::KeyIndex] record the hashes of every version
+//! of every map they fetch, so tools that mostly work with hashes can render keys and URLs without extra requests.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(feature = "reqwest_backend")]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaverReqwest;
+//! use beatsaver_rs::index::KeyIndex;
+//! use std::sync::Arc;
+//!
+//! # fn main() {
+//! let index = Arc::new(KeyIndex::load("keys.json").unwrap_or_default());
+//! let client = BeatSaverReqwest::new().with_key_index(index.clone());
+//! // ... use the client ...
+//! index.save("keys.json").unwrap();
+//! # }
+//! # }
+//! ```
+use crate::model::map::MapDetail;
+use crate::{MapId, MapKey};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+use std::sync::RwLock;
+
+/// Thread-safe index mapping map hashes to map keys
+///
+/// Hashes are stored in lowercase, lookups are case-insensitive.
+#[derive(Debug, Default)]
+pub struct KeyIndex {
+    entries: RwLock<HashMap<String, String>>,
+}
+impl KeyIndex {
+    /// Creates a new, empty [KeyIndex][crate::index::KeyIndex]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Loads an index previously written with [save][crate::index::KeyIndex::save]
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        let entries: HashMap<String, String> = serde_json::from_reader(reader)?;
+        Ok(entries.into_iter().collect())
+    }
+    /// Writes the index to the specified path as JSON
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        let entries = self.entries.read().unwrap();
+        serde_json::to_writer(writer, &*entries)?;
+        Ok(())
+    }
+    /// Records a hash/key pair
+    pub fn insert(&self, hash: &str, key: &str) {
+        self.entries
+            .write()
+            .unwrap()
+            .insert(hash.to_lowercase(), key.to_string());
+    }
+    /// Records the hashes of every version of a [MapDetail][crate::model::map::MapDetail]
+    pub fn insert_map(&self, map: &MapDetail) {
+        self.insert_maps(std::iter::once(map));
+    }
+    /// Records the hashes of every version of multiple [MapDetails][crate::model::map::MapDetail]
+    pub fn insert_maps<'m, I: IntoIterator<Item = &'m MapDetail>>(&self, maps: I) {
+        let mut entries = self.entries.write().unwrap();
+        for map in maps {
+            for version in &map.versions {
+                entries.insert(version.hash.to_lowercase(), map.id.clone());
+            }
+        }
+    }
+    /// Looks up the key of the map with the specified hash
+    pub fn key(&self, hash: &str) -> Option<String> {
+        self.entries
+            .read()
+            .unwrap()
+            .get(&hash.to_lowercase())
+            .cloned()
+    }
+    /// Resolves a [MapId][crate::MapId] to a [MapId::Key][crate::MapId::Key] if the key is known
+    ///
+    /// Keys are returned unchanged, unknown hashes return `None`.
+    pub fn resolve(&self, id: &MapId) -> Option<MapId> {
+        match id {
+            MapId::Key(_) => Some(id.clone()),
+            MapId::Hash(h) => {
+                let key = self.key(h.as_str())?;
+                MapKey::new(key.as_str()).ok().map(MapId::from)
+            }
+        }
+    }
+    /// Number of hashes in the index
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().len()
+    }
+    /// Returns `true` if the index contains no hashes
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().unwrap().is_empty()
+    }
+}
+impl std::iter::FromIterator<(String, String)> for KeyIndex {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        let entries = iter
+            .into_iter()
+            .map(|(hash, key)| (hash.to_lowercase(), key))
+            .collect();
+        Self {
+            entries: RwLock::new(entries),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::index::KeyIndex;
+    use crate::model::map::MapDetail;
+    use crate::tests::{map_hash, map_json};
+    use crate::MapId;
+    use std::env;
+
+    #[test]
+    fn test_key_index() {
+        let index = KeyIndex::new();
+        assert!(index.is_empty());
+        index.insert("FDA568FC27C20D21F8DC6F3709B49B5CC96723BE", "1");
+        index.insert("89cf8bb07afb3c59ae7b5ac00337d62261c36fb4", "2144");
+        assert_eq!(index.len(), 2);
+
+        assert_eq!(
+            index.key("fda568fc27c20d21f8dc6f3709b49b5cc96723be"),
+            Some("1".to_string())
+        );
+        assert_eq!(
+            index.key("89CF8BB07AFB3C59AE7B5AC00337D62261C36FB4"),
+            Some("2144".to_string())
+        );
+        assert_eq!(index.key("0000000000000000000000000000000000000000"), None);
+    }
+    #[test]
+    fn test_key_index_insert_map() {
+        let mut map: MapDetail = serde_json::from_str(map_json("2144").as_str()).unwrap();
+        let mut version = map.versions[0].clone();
+        version.hash = "89CF8BB07AFB3C59AE7B5AC00337D62261C36FB4".into();
+        map.versions.push(version);
+
+        let index = KeyIndex::new();
+        index.insert_map(&map);
+        assert_eq!(index.len(), 2);
+        assert_eq!(
+            index.key(map_hash("2144").as_str()),
+            Some("2144".to_string())
+        );
+        assert_eq!(
+            index.key("89cf8bb07afb3c59ae7b5ac00337d62261c36fb4"),
+            Some("2144".to_string())
+        );
+    }
+    #[test]
+    fn test_key_index_resolve() {
+        let index = KeyIndex::new();
+        index.insert("89cf8bb07afb3c59ae7b5ac00337d62261c36fb4", "2144");
+
+        assert_eq!(
+            index.resolve(&MapId::Hash(
+                "89cf8bb07afb3c59ae7b5ac00337d62261c36fb4".into()
+            )),
+            Some(MapId::from(0x2144))
+        );
+        assert_eq!(index.resolve(&MapId::from(1)), Some(MapId::from(1)));
+        assert_eq!(
+            index.resolve(&MapId::Hash(
+                "fda568fc27c20d21f8dc6f3709b49b5cc96723be".into()
+            )),
+            None
+        );
+    }
+    #[test]
+    fn test_key_index_persist() {
+        let path = env::temp_dir().join(format!("beatsaver-rs-index-{}.json", std::process::id()));
+        let index = KeyIndex::new();
+        index.insert("89cf8bb07afb3c59ae7b5ac00337d62261c36fb4", "2144");
+        index.save(&path).unwrap();
+
+        let loaded = KeyIndex::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(
+            loaded.key("89cf8bb07afb3c59ae7b5ac00337d62261c36fb4"),
+            Some("2144".to_string())
+        );
+    }
+}
@@ -0,0 +1,493 @@
+//! # Install
+//!
+//! This module contains helpers for installing maps into a Beat Saber `CustomLevels` folder.
+//!
+//! Folder names follow the convention used by SongCore and most mod installers:
+//! `"<key> (<songName> - <levelAuthorName>)"`
+//!
+//! With the `archive` feature, downloaded maps can be extracted with
+//! [extract_map][crate::install::extract_map].
+#[cfg(feature = "archive")]
+use crate::archive::{Archive, ArchiveError};
+#[cfg(feature = "hash")]
+use crate::hash::hash_map_folder;
+use crate::model::map::MapDetail;
+#[cfg(feature = "archive")]
+use bytes::Bytes;
+#[cfg(feature = "hash")]
+use std::collections::HashMap;
+use std::fs;
+#[cfg(feature = "hash")]
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Maximum length (in characters) of a generated folder name
+///
+/// Windows limits full paths to 260 characters, and `CustomLevels` is usually already nested
+/// fairly deep, so folder names are kept well below the 255 character component limit.
+pub const MAX_FOLDER_NAME_LEN: usize = 100;
+
+/// Folder of the Steam version of Beat Saber, relative to a Steam library
+const STEAM_GAME_DIR: &str = "steamapps/common/Beat Saber";
+/// Folder of the Oculus version of Beat Saber, relative to an Oculus library
+const OCULUS_GAME_DIR: &str = "Software/hyperbolic-magnetism-beat-saber";
+/// Folder containing custom maps, relative to the game folder
+const CUSTOM_LEVELS_DIR: &str = "Beat Saber_Data/CustomLevels";
+
+/// Characters that are not allowed in a folder name on at least one supported platform
+const INVALID_CHARS: &[char] = &['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+fn is_valid_char(c: char) -> bool {
+    !c.is_control() && !INVALID_CHARS.contains(&c)
+}
+
+/// Generates the conventional folder name for a map
+///
+/// Invalid path characters are removed and the `<songName> - <levelAuthorName>` part is
+/// truncated so the name never exceeds [MAX_FOLDER_NAME_LEN][crate::install::MAX_FOLDER_NAME_LEN]
+/// characters. The key and the surrounding parentheses are always kept intact.
+///
+/// Example:
+/// ```
+/// use beatsaver_rs::install::folder_name;
+///
+/// let name = folder_name("2144", "Shut Up and Dance", "bennydabeast");
+/// assert_eq!(name, "2144 (Shut Up and Dance - bennydabeast)");
+/// ```
+pub fn folder_name(key: &str, song_name: &str, level_author: &str) -> String {
+    let key: String = key.chars().filter(|c| is_valid_char(*c)).collect();
+    let title: String = format!("{} - {}", song_name, level_author)
+        .chars()
+        .filter(|c| is_valid_char(*c))
+        .collect();
+
+    // Space for the key, the separating space and both parentheses
+    let available = MAX_FOLDER_NAME_LEN.saturating_sub(key.chars().count() + 3);
+    let title: String = title.chars().take(available).collect();
+    // Windows does not allow names ending in a dot or space
+    let title = title.trim_end_matches(['.', ' ']);
+
+    format!("{} ({})", key, title)
+}
+
+/// Generates the conventional folder name for a given [MapDetail][crate::model::map::MapDetail]
+///
+/// See [folder_name][crate::install::folder_name] for details.
+pub fn map_folder_name(map: &MapDetail) -> String {
+    folder_name(
+        map.id.as_str(),
+        map.metadata.song_name.as_str(),
+        map.metadata.level_author_name.as_str(),
+    )
+}
+
+/// Beat Saber install that maps are installed into
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstallTarget {
+    /// Steam install, detected through the Steam library folders
+    Steam,
+    /// Oculus install, detected through the Oculus libraries (Windows only)
+    Oculus,
+    /// Steam install if one is found, Oculus install otherwise
+    Auto,
+    /// Explicit `CustomLevels` folder
+    CustomLevels(PathBuf),
+}
+impl InstallTarget {
+    /// Locates the `CustomLevels` folder of the install, if the game could be found
+    ///
+    /// Note: the folder itself may not exist yet if no custom maps were ever installed
+    pub fn custom_levels(&self) -> Option<PathBuf> {
+        let game = match self {
+            Self::CustomLevels(p) => return Some(p.clone()),
+            Self::Auto => {
+                return Self::Steam
+                    .custom_levels()
+                    .or_else(|| Self::Oculus.custom_levels())
+            }
+            Self::Steam => steam_roots()
+                .iter()
+                .flat_map(|r| steam_libraries(r))
+                .map(|l| l.join(STEAM_GAME_DIR))
+                .find(|g| g.is_dir()),
+            Self::Oculus => oculus_libraries()
+                .into_iter()
+                .map(|l| l.join(OCULUS_GAME_DIR))
+                .find(|g| g.is_dir()),
+        };
+        game.map(|g| g.join(CUSTOM_LEVELS_DIR))
+    }
+}
+
+/// Reads string values from the Windows registry using `reg query`
+#[cfg(windows)]
+fn reg_query(key: &str, value: &str, recursive: bool) -> Vec<String> {
+    let mut command = std::process::Command::new("reg");
+    command.args(["query", key, "/v", value]);
+    if recursive {
+        command.arg("/s");
+    }
+    let output = match command.output() {
+        Ok(o) if o.status.success() => o.stdout,
+        _ => return vec![],
+    };
+    String::from_utf8_lossy(&output)
+        .lines()
+        .filter_map(|l| l.split_once("REG_SZ"))
+        .map(|(_, v)| v.trim().to_string())
+        .collect()
+}
+
+/// Possible locations of the Steam installation
+fn steam_roots() -> Vec<PathBuf> {
+    #[cfg(windows)]
+    {
+        let mut roots: Vec<PathBuf> = reg_query("HKCU\\Software\\Valve\\Steam", "SteamPath", false)
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+        roots.push(PathBuf::from("C:\\Program Files (x86)\\Steam"));
+        roots
+    }
+    #[cfg(not(windows))]
+    {
+        let home = match std::env::var_os("HOME") {
+            Some(h) => PathBuf::from(h),
+            None => return vec![],
+        };
+        vec![
+            home.join(".steam/steam"),
+            home.join(".local/share/Steam"),
+            home.join(".var/app/com.valvesoftware.Steam/.local/share/Steam"),
+        ]
+    }
+}
+
+/// Parses the library folders listed in Steam's `libraryfolders.vdf`
+///
+/// Supports both the current format (`"path"` keys inside numbered blocks) and the legacy format
+/// (numbered keys with the path as value).
+fn parse_library_folders(vdf: &str) -> Vec<PathBuf> {
+    let pairs = vdf.lines().filter_map(|line| {
+        let tokens: Vec<&str> = line.split('"').skip(1).step_by(2).collect();
+        match tokens.as_slice() {
+            [key, value] => Some((*key, value.replace("\\\\", "\\"))),
+            _ => None,
+        }
+    });
+    // Numbered keys are also used for the app ids of the current format
+    let legacy = !vdf.contains("\"path\"");
+    pairs
+        .filter(|(key, _)| match legacy {
+            true => key.chars().all(|c| c.is_ascii_digit()),
+            false => *key == "path",
+        })
+        .map(|(_, value)| PathBuf::from(value))
+        .collect()
+}
+
+/// Steam libraries of the Steam installation at `root`, including `root` itself
+fn steam_libraries(root: &Path) -> Vec<PathBuf> {
+    let mut libraries = vec![root.to_path_buf()];
+    if let Ok(vdf) = fs::read_to_string(root.join("steamapps/libraryfolders.vdf")) {
+        libraries.extend(parse_library_folders(vdf.as_str()));
+    }
+    libraries
+}
+
+/// Oculus libraries, read from the registry
+fn oculus_libraries() -> Vec<PathBuf> {
+    #[cfg(windows)]
+    {
+        let mut libraries: Vec<PathBuf> = reg_query(
+            "HKCU\\Software\\Oculus VR, LLC\\Oculus\\Libraries",
+            "OriginalPath",
+            true,
+        )
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+        libraries.push(PathBuf::from("C:\\Program Files\\Oculus"));
+        libraries
+    }
+    #[cfg(not(windows))]
+    {
+        vec![]
+    }
+}
+
+/// Computes the hashes of the maps installed in a `CustomLevels` folder
+///
+/// Folders that don't contain a valid map are skipped. Returns a map of hash to map folder.
+#[cfg(feature = "hash")]
+pub fn installed_hashes(custom_levels: &Path) -> io::Result<HashMap<String, PathBuf>> {
+    let mut hashes = HashMap::new();
+    if !custom_levels.is_dir() {
+        return Ok(hashes);
+    }
+    for entry in fs::read_dir(custom_levels)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if let Ok(hash) = hash_map_folder(&path) {
+            hashes.insert(hash, path);
+        }
+    }
+    Ok(hashes)
+}
+
+/// Result of installing a map
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstallOutcome {
+    /// Map was downloaded and extracted into the folder
+    Installed(PathBuf),
+    /// Latest version of the map was already present in the folder
+    AlreadyInstalled(PathBuf),
+}
+impl InstallOutcome {
+    /// Returns the folder containing the map
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Installed(p) | Self::AlreadyInstalled(p) => p,
+        }
+    }
+}
+
+/// Converts the path of an archive entry to a relative path that is safe to create on disk
+///
+/// Every component is sanitized like folder names. Returns `None` if nothing is left of the path.
+#[cfg(feature = "archive")]
+fn entry_path(name: &str) -> Result<Option<PathBuf>, ArchiveError> {
+    let mut path = PathBuf::new();
+    for component in name.split('/') {
+        if component == ".." {
+            return Err(ArchiveError::UnsafePath(name.to_string()));
+        }
+        let component: String = component.chars().filter(|c| is_valid_char(*c)).collect();
+        let component = component.trim_end_matches(['.', ' ']);
+        if !component.is_empty() {
+            path.push(component);
+        }
+    }
+    Ok(match path.as_os_str().is_empty() {
+        true => None,
+        false => Some(path),
+    })
+}
+
+/// Extracts every file of an archive into a folder
+///
+/// File names are sanitized, and entries that would be written outside of `dir` (e.g.
+/// `../../evil.dll`) are rejected with [UnsafePath][crate::archive::ArchiveError::UnsafePath]
+/// before anything is written. Returns the paths of the extracted files.
+#[cfg(feature = "archive")]
+pub fn extract(archive: &Archive, dir: &Path) -> Result<Vec<PathBuf>, ArchiveError> {
+    let mut files = vec![];
+    for entry in archive.entries().iter().filter(|e| !e.is_dir()) {
+        if let Some(path) = entry_path(entry.name.as_str())? {
+            files.push((entry, dir.join(path)));
+        }
+    }
+
+    let mut extracted = vec![];
+    for (entry, path) in files {
+        let contents = archive.read_entry(entry)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, contents)?;
+        extracted.push(path);
+    }
+    Ok(extracted)
+}
+
+/// Extracts a downloaded map into its conventional folder inside `custom_levels`
+///
+/// Returns the path of the map folder.
+///
+/// Example:
+/// ```no_run
+/// # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+/// # mod main {
+/// use beatsaver_rs::client::BeatSaver;
+/// use beatsaver_rs::install::extract_map;
+/// use beatsaver_rs::BeatSaverApiAsync;
+/// use std::convert::TryInto;
+/// use std::path::Path;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = BeatSaver::new();
+///     let map = client.map(&"2144".try_into().unwrap()).await.unwrap();
+///     let data = client.download((&map).try_into().unwrap()).await.unwrap();
+///     let folder = extract_map(&map, data, Path::new("CustomLevels")).unwrap();
+///     println!("Installed to {}", folder.display());
+/// }
+/// # }
+/// ```
+#[cfg(feature = "archive")]
+pub fn extract_map(
+    map: &MapDetail,
+    data: Bytes,
+    custom_levels: &Path,
+) -> Result<PathBuf, ArchiveError> {
+    let archive = Archive::new(data)?;
+    let dir = custom_levels.join(map_folder_name(map));
+    fs::create_dir_all(&dir)?;
+    extract(&archive, &dir)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::install::{
+        folder_name, map_folder_name, parse_library_folders, InstallTarget, MAX_FOLDER_NAME_LEN,
+    };
+    use crate::tests::map_json;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_folder_name() {
+        assert_eq!(
+            folder_name("2144", "Shut Up and Dance", "bennydabeast"),
+            "2144 (Shut Up and Dance - bennydabeast)"
+        );
+    }
+    #[test]
+    fn test_map_folder_name() {
+        let map = serde_json::from_str(map_json("2144").as_str()).unwrap();
+        assert_eq!(map_folder_name(&map), "2144 (Song 2144 - bennydabeast)");
+    }
+    #[test]
+    fn test_folder_name_invalid_chars() {
+        assert_eq!(
+            folder_name("1a2b", "What? <Remix>: \"A/B\"", "map|per*\\"),
+            "1a2b (What Remix AB - mapper)"
+        );
+        assert_eq!(
+            folder_name("1", "Song\tName\n", "a\0b"),
+            "1 (SongName - ab)"
+        );
+    }
+    #[test]
+    fn test_folder_name_trailing() {
+        assert_eq!(folder_name("1", "Song", "Author..."), "1 (Song - Author)");
+        assert_eq!(folder_name("1", "Song", "?"), "1 (Song -)");
+    }
+    #[test]
+    fn test_folder_name_length() {
+        let long = "a".repeat(500);
+        let name = folder_name("11b7a", long.as_str(), "mapper");
+        assert_eq!(name.chars().count(), MAX_FOLDER_NAME_LEN);
+        assert!(name.starts_with("11b7a (aaaa"));
+        assert!(name.ends_with("a)"));
+
+        let long = "é".repeat(500);
+        let name = folder_name("1", long.as_str(), "mapper");
+        assert_eq!(name.chars().count(), MAX_FOLDER_NAME_LEN);
+    }
+    #[test]
+    fn test_parse_library_folders() {
+        let vdf = r#"
+"libraryfolders"
+{
+	"0"
+	{
+		"path"		"C:\\Program Files (x86)\\Steam"
+		"apps"
+		{
+			"620980"		"12345"
+		}
+	}
+	"1"
+	{
+		"path"		"/mnt/games/SteamLibrary"
+	}
+}"#;
+        assert_eq!(
+            parse_library_folders(vdf),
+            vec![
+                PathBuf::from("C:\\Program Files (x86)\\Steam"),
+                PathBuf::from("/mnt/games/SteamLibrary")
+            ]
+        );
+
+        let legacy = "\"LibraryFolders\"\n{\n\t\"TimeNextStatsReport\"\t\t\"1\"\n\t\"1\"\t\t\"D:\\\\SteamLibrary\"\n}";
+        assert_eq!(
+            parse_library_folders(legacy),
+            vec![PathBuf::from("D:\\SteamLibrary")]
+        );
+    }
+    #[test]
+    fn test_install_target() {
+        let target = InstallTarget::CustomLevels(PathBuf::from("CustomLevels"));
+        assert_eq!(target.custom_levels(), Some(PathBuf::from("CustomLevels")));
+    }
+    #[cfg(feature = "archive")]
+    #[test]
+    fn test_extract_map() {
+        use crate::install::extract_map;
+        use crate::tests::map_archive;
+
+        let dir = tempfile::tempdir().unwrap();
+        let map = serde_json::from_str(map_json("2144").as_str()).unwrap();
+        let folder = extract_map(&map, map_archive(), dir.path()).unwrap();
+        assert_eq!(folder, dir.path().join("2144 (Song 2144 - bennydabeast)"));
+        assert!(folder.join("Info.dat").is_file());
+        assert_eq!(std::fs::read(folder.join("cover.jpg")).unwrap(), b"cover");
+    }
+    #[cfg(feature = "hash")]
+    #[test]
+    fn test_installed_hashes() {
+        use crate::install::{extract_map, installed_hashes};
+        use crate::tests::{map_archive, zip_archive, MAP_ARCHIVE_HASH};
+
+        let dir = tempfile::tempdir().unwrap();
+        let first = serde_json::from_str(map_json("1").as_str()).unwrap();
+        let first = extract_map(&first, map_archive(), dir.path()).unwrap();
+        let second = serde_json::from_str(map_json("2").as_str()).unwrap();
+        let info = br#"{"_version":"2.0.0","_difficultyBeatmapSets":[]}"#;
+        let second = extract_map(&second, zip_archive(&[("Info.dat", info)]), dir.path()).unwrap();
+        std::fs::create_dir(dir.path().join("not a map")).unwrap();
+        std::fs::write(dir.path().join("readme.txt"), b"").unwrap();
+
+        let hashes = installed_hashes(dir.path()).unwrap();
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(hashes.get(MAP_ARCHIVE_HASH), Some(&first));
+        assert_eq!(
+            hashes.get("449a4175652460bd94e0cd75c95fd67ff974d884"),
+            Some(&second)
+        );
+        assert!(installed_hashes(&dir.path().join("missing"))
+            .unwrap()
+            .is_empty());
+    }
+    #[cfg(feature = "archive")]
+    #[test]
+    fn test_extract_unsafe() {
+        use crate::archive::{Archive, ArchiveError};
+        use crate::install::extract;
+        use crate::tests::zip_archive;
+
+        let dir = tempfile::tempdir().unwrap();
+        let archive = Archive::new(zip_archive(&[
+            ("/sub\\what?.dat", b"a".as_ref()),
+            ("???", b"b".as_ref()),
+        ]))
+        .unwrap();
+        let files = extract(&archive, dir.path()).unwrap();
+        assert_eq!(files, vec![dir.path().join("sub").join("what.dat")]);
+
+        let archive = Archive::new(zip_archive(&[
+            ("Info.dat", b"{}".as_ref()),
+            ("../evil.dll", b"c".as_ref()),
+        ]))
+        .unwrap();
+        assert!(matches!(
+            extract(&archive, dir.path()),
+            Err(ArchiveError::UnsafePath(_))
+        ));
+        assert!(!dir.path().join("Info.dat").exists());
+    }
+}
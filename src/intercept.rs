@@ -0,0 +1,199 @@
+//! # Intercept
+//!
+//! This module contains the hooks clients call around every request they send, to add headers,
+//! log traffic or alter requests without writing a new backend.
+//!
+//! Interceptors are attached to a client, and called for every attempt of every request,
+//! including retries:
+//! ```no_run
+//! # #[cfg(feature = "reqwest_backend")]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaverReqwest;
+//! use beatsaver_rs::intercept::{DefaultHeaders, Interceptor};
+//! use bytes::Bytes;
+//! use http::{response, HeaderValue, Request, Uri};
+//! use std::sync::Arc;
+//!
+//! #[derive(Debug)]
+//! struct Logger;
+//! impl Interceptor for Logger {
+//!     fn on_request(&self, request: &mut Request<Bytes>) {
+//!         println!("{} {}", request.method(), request.uri());
+//!     }
+//!     fn on_response(&self, uri: &Uri, response: &mut response::Parts) {
+//!         println!("{} returned {}", uri, response.status);
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let headers = DefaultHeaders::new().with_header("x-app", HeaderValue::from_static("my-app"));
+//! let client = BeatSaverReqwest::new()
+//!     .with_interceptor(Arc::new(headers))
+//!     .with_interceptor(Arc::new(Logger));
+//! # }
+//! # }
+//! ```
+use bytes::Bytes;
+use http::header::{HeaderMap, HeaderValue, IntoHeaderName};
+use http::{response, Request, Response, Uri};
+use std::fmt;
+use std::sync::Arc;
+
+/// Hooks called by a client around every request it sends
+///
+/// When several interceptors are attached to a client, [on_request][Interceptor::on_request] is
+/// called in the order they were attached, and [on_response][Interceptor::on_response] in the
+/// reverse order.
+pub trait Interceptor: fmt::Debug + Send + Sync {
+    /// Called before a request is sent, e.g. to add headers
+    fn on_request(&self, _request: &mut Request<Bytes>) {}
+    /// Called with the status and headers of every response, before its body is read
+    ///
+    /// Status handling (rate limits, retries, errors) happens after this hook, so changing the
+    /// status changes how the response is handled.
+    fn on_response(&self, _uri: &Uri, _response: &mut response::Parts) {}
+}
+
+/// [Interceptor][crate::intercept::Interceptor] adding headers to every request
+///
+/// Headers already set on a request are left untouched.
+#[derive(Debug, Clone, Default)]
+pub struct DefaultHeaders {
+    headers: HeaderMap,
+}
+impl DefaultHeaders {
+    /// Creates a new [DefaultHeaders][crate::intercept::DefaultHeaders] without any header
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds a header to every request
+    pub fn with_header<K: IntoHeaderName>(mut self, name: K, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+}
+impl From<HeaderMap> for DefaultHeaders {
+    fn from(headers: HeaderMap) -> Self {
+        Self { headers }
+    }
+}
+impl Interceptor for DefaultHeaders {
+    fn on_request(&self, request: &mut Request<Bytes>) {
+        for name in self.headers.keys() {
+            if !request.headers().contains_key(name) {
+                for value in self.headers.get_all(name) {
+                    request.headers_mut().append(name, value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Runs the request hooks of the interceptors, returning the URI of the request
+pub(crate) fn intercept_request(
+    interceptors: &[Arc<dyn Interceptor>],
+    request: &mut Request<Bytes>,
+) -> Uri {
+    for interceptor in interceptors {
+        interceptor.on_request(request);
+    }
+    request.uri().clone()
+}
+
+/// Runs the response hooks of the interceptors, in reverse order
+pub(crate) fn intercept_response<B>(
+    interceptors: &[Arc<dyn Interceptor>],
+    uri: &Uri,
+    response: Response<B>,
+) -> Response<B> {
+    if interceptors.is_empty() {
+        return response;
+    }
+    let (mut parts, body) = response.into_parts();
+    for interceptor in interceptors.iter().rev() {
+        interceptor.on_response(uri, &mut parts);
+    }
+    Response::from_parts(parts, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intercept::{intercept_request, intercept_response, DefaultHeaders, Interceptor};
+    use bytes::Bytes;
+    use http::header::{HeaderValue, USER_AGENT};
+    use http::{response, Request, Response, StatusCode, Uri};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct Named {
+        name: &'static str,
+        trace: Arc<Mutex<Vec<String>>>,
+    }
+    impl Interceptor for Named {
+        fn on_request(&self, _request: &mut Request<Bytes>) {
+            let mut trace = self.trace.lock().unwrap();
+            trace.push(format!("request {}", self.name));
+        }
+        fn on_response(&self, _uri: &Uri, response: &mut response::Parts) {
+            let mut trace = self.trace.lock().unwrap();
+            trace.push(format!("response {}", self.name));
+            response.status = StatusCode::NOT_FOUND;
+        }
+    }
+
+    #[test]
+    fn test_intercept_order() {
+        let trace = Arc::new(Mutex::new(vec![]));
+        let named = |name| {
+            let trace = trace.clone();
+            Arc::new(Named { name, trace }) as Arc<dyn Interceptor>
+        };
+        let interceptors = vec![named("a"), named("b")];
+        let mut request = Request::new(Bytes::new());
+        let uri = intercept_request(&interceptors, &mut request);
+        let response = intercept_response(&interceptors, &uri, Response::new(()));
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            *trace.lock().unwrap(),
+            ["request a", "request b", "response b", "response a"]
+        );
+    }
+    #[test]
+    fn test_default_headers() {
+        let headers = DefaultHeaders::new()
+            .with_header(USER_AGENT, HeaderValue::from_static("default"))
+            .with_header("x-app", HeaderValue::from_static("a"))
+            .with_header("x-app", HeaderValue::from_static("b"));
+        let mut request = Request::new(Bytes::new());
+        request
+            .headers_mut()
+            .insert(USER_AGENT, HeaderValue::from_static("custom"));
+        headers.on_request(&mut request);
+        assert_eq!(request.headers()[USER_AGENT], "custom");
+        assert_eq!(request.headers().get_all("x-app").iter().count(), 2);
+    }
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_client_interceptors() {
+        use crate::client::BeatSaverUreq;
+        use crate::testing::TestServer;
+        use crate::{BeatSaverApiError, BeatSaverApiSync};
+
+        let server = TestServer::start().unwrap().with_maps(&["2144"]);
+        let trace = Arc::new(Mutex::new(vec![]));
+        let named = Named {
+            name: "a",
+            trace: trace.clone(),
+        };
+        let client = BeatSaverUreq::new()
+            .with_base_url(server.url())
+            .with_interceptor(Arc::new(DefaultHeaders::new()));
+        assert!(client.map_by_key("2144").is_ok());
+        let client = client.with_interceptor(Arc::new(named));
+        assert!(matches!(
+            client.map_by_key("2144"),
+            Err(BeatSaverApiError::NotFound(_))
+        ));
+        assert_eq!(*trace.lock().unwrap(), ["request a", "response a"]);
+    }
+}
@@ -1,4 +1,6 @@
 #![warn(missing_docs)]
+// Request helpers are only used by the client traits
+#![cfg_attr(not(any(feature = "sync", feature = "async")), allow(dead_code))]
 //! # beatsaver-rs
 //!
 //! This probject is a Rust library for interacting with the [BeatSaver](https://beatsaver.com/) api.
@@ -6,89 +8,221 @@
 //! The library is a work in progress and the API has not been stablized, so expect breaking changes.
 //!
 //! See also:
-//! * [BeatSaver API Docs](https://docs.beatsaver.com/)
+//! * [BeatSaver API Docs](https://api.beatsaver.com/docs/)
 //!
 //! # Using the API
 //!
 //! ```no_run
-//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend")))]
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "hyper_backend"), not(feature = "isahc_backend"), not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
 //! # mod main {
-//! use beatsaver_rs::BeatSaverApi;
+//! use beatsaver_rs::BeatSaverApiAsync;
 //! use beatsaver_rs::client::BeatSaver;
-//! use beatsaver_rs::map::Map;
+//! use beatsaver_rs::model::map::MapDetail;
 //! use bytes::Bytes;
 //! use std::convert::TryInto;
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     let client = BeatSaver::new();
-//!     let map: Map = client.map(&"1".try_into().unwrap()).await.unwrap();
+//!     let map: MapDetail = client.map(&"1".try_into().unwrap()).await.unwrap();
 //!     println!("Map by key: {}", map.name);
-//!     let map: Map = client.map(&"fda568fc27c20d21f8dc6f3709b49b5cc96723be".try_into().unwrap()).await.unwrap();
+//!     let map: MapDetail = client.map(&"fda568fc27c20d21f8dc6f3709b49b5cc96723be".try_into().unwrap()).await.unwrap();
 //!     println!("Map by hash: {}", map.name);
-//!     let map_download: Bytes = client.download((&map).into()).await.unwrap();
+//!     let map_download: Bytes = client.download((&map).try_into().unwrap()).await.unwrap();
 //!     // save map somewhere
 //! }
 //! # }
 //! ```
 use bytes::Bytes;
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use hex::{self, FromHexError};
+use http::{header, HeaderMap, Request, Response, StatusCode};
+use index::KeyIndex;
 use lazy_static::lazy_static;
-use map::Map;
+use model::error::ApiErrorBody;
+use model::map::MapDetail;
+use serde::de::DeserializeOwned;
 use serde::{de, Deserialize, Serialize};
-use serde_json;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::convert::{From, TryFrom, TryInto};
 use std::error::Error;
 use std::fmt;
 use std::num::ParseIntError;
+use std::str::FromStr;
 use std::string::FromUtf8Error;
+use std::sync::Mutex;
 use std::time::Duration;
 use url::Url;
 
+#[cfg(feature = "archive")]
+pub mod archive;
 mod async_api;
+pub mod auth;
+#[cfg(feature = "beatleader")]
+pub mod beatleader;
+#[cfg(feature = "bsaber")]
+pub mod bsaber;
+pub mod cache;
 pub mod client;
-pub mod map;
+#[cfg(feature = "hash")]
+pub mod hash;
+pub mod index;
+pub mod install;
+pub mod intercept;
+#[cfg(feature = "hash")]
+pub mod library;
+pub mod lucene;
+#[cfg(feature = "mirror")]
+pub mod mirror;
+pub mod model;
+pub mod playlist;
+pub mod preview;
+pub mod query;
+pub mod retry;
+#[cfg(feature = "scoresaber")]
+pub mod scoresaber;
+#[cfg(feature = "tower")]
+pub mod service;
 mod sync_api;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
+pub mod ws;
 
 lazy_static! {
     /// Base URL for the beatsaver API
-    pub static ref BEATSAVER_URL: Url = Url::parse("https://beatsaver.com/").unwrap();
+    pub static ref BEATSAVER_URL: Url = Url::parse("https://api.beatsaver.com/").unwrap();
+    /// Base URL of the CDN serving map archives
+    pub static ref CDN_URL: Url = Url::parse("https://cdn.beatsaver.com/").unwrap();
 }
 
-/// Holds data for a beatsaver user
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BeatSaverUser {
-    /// User ID (e.g. `5fbe7cd60192c700062b2a1f`)
-    #[serde(alias = "_id")]
-    pub id: String,
-    /// User name (e.g. `qwerty01`)
-    pub username: String,
-}
+/// Maximum number of maps that can be requested at once with `maps_by_ids`
+pub const MAPS_BY_IDS_LIMIT: usize = 50;
+
+/// Maximum number of maps that can be requested at once with `maps_by_hashes`
+pub const MAPS_BY_HASHES_LIMIT: usize = 50;
+
+/// User agent sent with every request
+pub(crate) const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
 /// Page metadata for APIs that paginate results
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Page<T: Serialize> {
     /// List of documents in the page
     pub docs: VecDeque<T>,
     /// Total number of documents
-    #[serde(alias = "totalDocs")]
-    pub total_docs: usize,
+    ///
+    /// Note: Set to `None` if the API doesn't report totals for this endpoint
+    pub total_docs: Option<usize>,
     /// Last page available
-    #[serde(alias = "lastPage")]
-    pub last_page: usize,
+    ///
+    /// Note: Set to `None` if the API doesn't report totals for this endpoint
+    pub last_page: Option<usize>,
     /// Previous page number
     ///
     /// Note: Set to `None` if you are on the first page
-    #[serde(alias = "prevPage")]
     pub prev_page: Option<usize>,
     /// Next page number
     ///
     /// Note: Set to `None` if you are on the last page
-    #[serde(alias = "nextPage")]
     pub next_page: Option<usize>,
 }
+impl<T: Serialize + DeserializeOwned> Page<T> {
+    /// Parses a paged API response for the specified page number
+    ///
+    /// Endpoints that don't report totals are treated as exhausted once an empty page is returned.
+    pub(crate) fn parse(data: &str, page: usize) -> Result<Self, serde_json::Error> {
+        let response: PageResponse<T> = serde_json::from_str(data)?;
+        Ok(Self::from_response(response, page))
+    }
+}
+impl<T: Serialize> Page<T> {
+    /// Builds a page from documents returned by an endpoint that doesn't report totals
+    pub(crate) fn from_docs(docs: VecDeque<T>, page: usize) -> Self {
+        Self::from_response(PageResponse { docs, info: None }, page)
+    }
+    /// Renumbers a page for walking the pages backwards, reversing its documents
+    ///
+    /// `index` is the number of pages between this page and the `last` page.
+    pub(crate) fn reversed(self, index: usize, last: usize) -> Self {
+        Self {
+            docs: self.docs.into_iter().rev().collect(),
+            total_docs: self.total_docs,
+            last_page: Some(last),
+            prev_page: index.checked_sub(1),
+            next_page: Some(index + 1).filter(|&i| i <= last),
+        }
+    }
+    fn from_response(response: PageResponse<T>, page: usize) -> Self {
+        let last_page = response.info.as_ref().and_then(|i| i.pages.checked_sub(1));
+        let next_page = match last_page {
+            _ if response.docs.is_empty() => None,
+            Some(last) if page >= last => None,
+            _ => Some(page + 1),
+        };
+        Self {
+            docs: response.docs,
+            total_docs: response.info.map(|i| i.total),
+            last_page,
+            prev_page: page.checked_sub(1),
+            next_page,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PageResponse<T> {
+    docs: VecDeque<T>,
+    #[serde(default)]
+    info: Option<PageInfo>,
+}
+
+#[derive(Deserialize)]
+struct PageInfo {
+    total: usize,
+    pages: usize,
+}
+
+/// Behaviour of paged streams and iterators when requesting a page fails
+///
+/// Set with [PageStream::error_policy][crate::PageStream::error_policy] or
+/// [PageIterator::error_policy][crate::PageIterator::error_policy].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IterErrorPolicy {
+    /// Yields the error, the failed page is requested again on the next call
+    ///
+    /// Note: a page that keeps failing (e.g. due to rate limits) keeps yielding errors
+    #[default]
+    RetryForever,
+    /// Yields the error and ends the iteration
+    FailFast,
+    /// Requests the failed page again up to the specified number of times without yielding the
+    /// errors, then yields the last error and ends the iteration
+    Retry(usize),
+    /// Yields the error and moves on to the following page
+    SkipPage,
+}
+
+/// Progress through a paged API, e.g. to render "12345 / 35367 maps"
+///
+/// Returned by [PageStream::progress][crate::PageStream::progress] and
+/// [PageIterator::progress][crate::PageIterator::progress].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PageProgress {
+    /// Number of documents yielded so far
+    pub yielded: usize,
+    /// Total number of documents
+    ///
+    /// Note: Set to `None` if the API doesn't report totals for this endpoint
+    pub total_docs: Option<usize>,
+    /// Page the documents are currently taken from
+    ///
+    /// Note: Set to `None` until the first page is received
+    pub current_page: Option<usize>,
+    /// Last page available
+    ///
+    /// Note: Set to `None` if the API doesn't report totals for this endpoint
+    pub last_page: Option<usize>,
+}
 
 struct DateTimeVisitor;
 impl DateTimeVisitor {
@@ -96,9 +230,7 @@ impl DateTimeVisitor {
     where
         T: Into<i64>,
     {
-        let ts: i64 = v.into();
-        let nts = NaiveDateTime::from_timestamp(ts / 1000, ((ts % 1000) as u32) * 1_000_000);
-        DateTime::from_utc(nts, Utc)
+        Utc.timestamp_millis_opt(v.into()).unwrap()
     }
 }
 impl<'a> de::Visitor<'a> for DateTimeVisitor {
@@ -173,6 +305,126 @@ pub struct BeatSaverRateLimit {
     pub reset_after: Duration,
 }
 
+/// How a client handles requests that hit a rate limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitPolicy {
+    /// Returns a [RateLimitError][crate::BeatSaverApiError::RateLimitError] right away
+    #[default]
+    Fail,
+    /// Waits for the rate limit to expire and retries the request
+    WaitAndRetry {
+        /// Number of times to wait before returning the
+        /// [RateLimitError][crate::BeatSaverApiError::RateLimitError]
+        max_waits: usize,
+    },
+}
+impl RateLimitPolicy {
+    /// Returns `true` if the request should be retried after waiting `waits` times already
+    pub(crate) fn should_wait(&self, waits: usize) -> bool {
+        match self {
+            Self::Fail => false,
+            Self::WaitAndRetry { max_waits } => waits < *max_waits,
+        }
+    }
+}
+
+/// Route map archives are downloaded through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DownloadRoute {
+    /// Downloads from the `download` routes of the API
+    #[default]
+    Api,
+    /// Downloads from the [CDN][crate::CDN_URL], falling back to the API if the CDN fails
+    ///
+    /// The CDN isn't rate limited, which makes it better suited to bulk downloads. Maps requested
+    /// by key are always downloaded from the API, since their archive name is their hash.
+    Cdn,
+}
+
+/// Rate limit quota reported by the `x-ratelimit-*` headers of the last response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Number of requests left until the rate limit is hit
+    pub remaining: Option<usize>,
+    /// DateTime when the quota resets
+    pub reset: Option<DateTime<Utc>>,
+}
+impl RateLimitStatus {
+    /// Parses the `x-ratelimit-remaining` and `x-ratelimit-reset` headers, `None` if neither is set
+    ///
+    /// The reset can either be a unix timestamp (in seconds or milliseconds), or a number of
+    /// seconds from now.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::trim)
+        };
+        let remaining = header("x-ratelimit-remaining").and_then(|v| v.parse().ok());
+        let reset = header("x-ratelimit-reset")
+            .and_then(|v| v.parse::<f64>().ok())
+            .filter(|v| v.is_finite() && *v >= 0.0)
+            .and_then(|v| {
+                let millis = match v {
+                    v if v >= 1e12 => v as i64,
+                    v if v >= 1e9 => (v * 1000.0) as i64,
+                    v => Utc::now().timestamp_millis() + (v * 1000.0) as i64,
+                };
+                Utc.timestamp_millis_opt(millis).single()
+            });
+        match (remaining, reset) {
+            (None, None) => None,
+            (remaining, reset) => Some(Self { remaining, reset }),
+        }
+    }
+}
+
+/// Thread-safe record of the last [RateLimitStatus][crate::RateLimitStatus] reported by the API
+///
+/// Clients share their tracker between clones, as the quota applies to all of them.
+#[derive(Debug, Default)]
+pub struct RateLimitTracker {
+    status: Mutex<Option<RateLimitStatus>>,
+    paused_until: Mutex<Option<DateTime<Utc>>>,
+}
+impl RateLimitTracker {
+    /// Creates a new [RateLimitTracker][crate::RateLimitTracker] without a status
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Returns the last status recorded, if any
+    pub fn status(&self) -> Option<RateLimitStatus> {
+        *self.status.lock().unwrap()
+    }
+    /// Records the rate limit headers of a response, responses without them are ignored
+    pub fn update(&self, headers: &HeaderMap) {
+        if let Some(status) = RateLimitStatus::from_headers(headers) {
+            *self.status.lock().unwrap() = Some(status);
+        }
+    }
+    /// Pauses the requests of the clients sharing the tracker while a rate limit expires
+    pub(crate) fn pause(&self, duration: Duration) {
+        let until = chrono::Duration::from_std(duration)
+            .ok()
+            .and_then(|d| Utc::now().checked_add_signed(d));
+        let mut paused_until = self.paused_until.lock().unwrap();
+        *paused_until = (*paused_until).max(until);
+    }
+    /// Returns how long requests are paused for, `None` once the rate limit expired
+    pub(crate) fn paused_for(&self) -> Option<Duration> {
+        let until = (*self.paused_until.lock().unwrap())?;
+        (until - Utc::now()).to_std().ok().filter(|d| !d.is_zero())
+    }
+}
+
+/// Records the rate limit headers of a response in the tracker of a client, if it has one
+pub(crate) fn track_rate_limit(tracker: Option<&RateLimitTracker>, headers: &HeaderMap) {
+    if let Some(tracker) = tracker {
+        tracker.update(headers);
+    }
+}
+
 /// Converts the body of a 429 response to a BeatSaverApiError::RateLimitError
 pub fn rate_limit<T: Error>(data: Bytes) -> BeatSaverApiError<T> {
     let s = match String::from_utf8(data.as_ref().to_vec()) {
@@ -186,60 +438,408 @@ pub fn rate_limit<T: Error>(data: Bytes) -> BeatSaverApiError<T> {
     BeatSaverApiError::RateLimitError(limit)
 }
 
+/// Builds a GET request for the provided [Url][url::Url]
+pub(crate) fn get_request(url: Url) -> Request<Bytes> {
+    Request::get(url.as_str())
+        .header(header::USER_AGENT, USER_AGENT)
+        .body(Bytes::new())
+        .unwrap()
+}
+
+/// Builds a POST request with a JSON body for the provided [Url][url::Url]
+pub(crate) fn post_request(url: Url, body: Bytes) -> Request<Bytes> {
+    Request::post(url.as_str())
+        .header(header::USER_AGENT, USER_AGENT)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(body)
+        .unwrap()
+}
+
+/// Parses the `Retry-After` header, either a number of seconds or an HTTP date
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    match value.parse::<u64>() {
+        Ok(secs) => Some(Duration::from_secs(secs)),
+        Err(_) => {
+            let date = DateTime::parse_from_rfc2822(value).ok()?;
+            Some(
+                (date.with_timezone(&Utc) - Utc::now())
+                    .to_std()
+                    .unwrap_or_default(),
+            )
+        }
+    }
+}
+
+/// Converts a failed response sent by the provided [Url][url::Url] to a BeatSaverApiError, 429
+/// responses become a BeatSaverApiError::RateLimitError and other statuses a
+/// BeatSaverApiError::HttpError
+///
+/// The rate limit is read from the body, or from the `Retry-After` header for 429 responses that
+/// don't come from the API (e.g. sent by a proxy). 429 responses with neither become a
+/// BeatSaverApiError::HttpError.
+pub(crate) fn status_error<T: Error>(url: &Url, response: Response<Bytes>) -> BeatSaverApiError<T> {
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        if let Ok(limit) = serde_json::from_slice(response.body()) {
+            return BeatSaverApiError::RateLimitError(limit);
+        }
+        if let Some(reset_after) = retry_after(response.headers()) {
+            return BeatSaverApiError::RateLimitError(BeatSaverRateLimit {
+                reset: Utc::now() + chrono::Duration::from_std(reset_after).unwrap_or_default(),
+                reset_after,
+            });
+        }
+    }
+    BeatSaverApiError::HttpError {
+        status,
+        url: url.clone(),
+        body: response.into_body(),
+    }
+}
+
+/// Extracts the body of a response sent by the provided [Url][url::Url], converting non-2xx
+/// responses to a BeatSaverApiError
+pub(crate) fn response_body<T: Error>(
+    url: &Url,
+    response: Response<Bytes>,
+) -> Result<Bytes, BeatSaverApiError<T>> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(response.into_body())
+    } else {
+        Err(status_error(url, response))
+    }
+}
+
+/// Builds the `maps/id` or `maps/hash` URL for the provided [MapId][crate::MapId]
+pub(crate) fn map_url(base: &Url, id: &MapId) -> Url {
+    base.join(
+        match id {
+            MapId::Key(k) => format!("maps/id/{}", k),
+            MapId::Hash(h) => format!("maps/hash/{}", h),
+        }
+        .as_str(),
+    )
+    .unwrap()
+}
+
+/// Parses the response of the `maps/id` or `maps/hash` endpoint, converting 404 responses to a
+/// BeatSaverApiError::NotFound
+pub(crate) fn parse_map<T: Error>(
+    url: &Url,
+    response: Response<Bytes>,
+    id: &MapId,
+) -> Result<MapDetail, BeatSaverApiError<T>> {
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(BeatSaverApiError::NotFound(id.clone()));
+    }
+    let data = response_body(url, response)?;
+    Ok(serde_json::from_slice(data.as_ref())?)
+}
+
+/// Builds the CDN URL of the archive of a map version
+pub(crate) fn cdn_download_url(hash: &str) -> Url {
+    CDN_URL
+        .join(format!("{}.zip", hash.to_lowercase()).as_str())
+        .unwrap()
+}
+
+/// Builds the download URL for the provided [MapId][crate::MapId]
+pub(crate) fn download_url(base: &Url, id: &MapId) -> Url {
+    base.join(
+        match id {
+            MapId::Key(k) => format!("download/key/{}", k),
+            MapId::Hash(h) => format!("download/hash/{}", h),
+        }
+        .as_str(),
+    )
+    .unwrap()
+}
+
+/// Returns the value of the `Content-Length` header of a response, if it is set and valid
+pub(crate) fn content_length<B>(response: &Response<B>) -> Option<u64> {
+    response
+        .headers()
+        .get(header::CONTENT_LENGTH)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Builds the `maps/ids` URL for the provided [MapIds][crate::MapId]
+///
+/// Hashes are resolved to keys through the [KeyIndex][crate::index::KeyIndex] if one is provided,
+/// since the endpoint only accepts keys.
+pub(crate) fn maps_by_ids_url<T: Error>(
+    base: &Url,
+    ids: &[MapId],
+    index: Option<&KeyIndex>,
+) -> Result<Url, BeatSaverApiError<T>> {
+    if ids.is_empty() {
+        return Err(BeatSaverApiError::ArgumentError("no map ids provided"));
+    }
+    if ids.len() > MAPS_BY_IDS_LIMIT {
+        return Err(BeatSaverApiError::ArgumentError(
+            "too many map ids provided",
+        ));
+    }
+    let keys = ids
+        .iter()
+        .map(|id| {
+            let resolved = match id {
+                MapId::Key(_) => Some(id.clone()),
+                MapId::Hash(_) => index.and_then(|i| i.resolve(id)),
+            };
+            match resolved {
+                Some(MapId::Key(k)) => Ok(k.as_str().to_string()),
+                _ => Err(BeatSaverApiError::ArgumentError(
+                    "map hash could not be resolved to a key",
+                )),
+            }
+        })
+        .collect::<Result<Vec<String>, BeatSaverApiError<T>>>()?;
+    Ok(base
+        .join(format!("maps/ids/{}", keys.join(",")).as_str())
+        .unwrap())
+}
+
+/// Builds the `maps/hash` URL used to look up multiple hashes at once
+pub(crate) fn maps_by_hashes_url<T: Error>(
+    base: &Url,
+    hashes: &[String],
+) -> Result<Url, BeatSaverApiError<T>> {
+    if hashes.is_empty() {
+        return Err(BeatSaverApiError::ArgumentError("no map hashes provided"));
+    }
+    if hashes.len() > MAPS_BY_HASHES_LIMIT {
+        return Err(BeatSaverApiError::ArgumentError(
+            "too many map hashes provided",
+        ));
+    }
+    if hashes
+        .iter()
+        .any(|h| h.len() != 40 || hex::decode(h).is_err())
+    {
+        return Err(BeatSaverApiError::ArgumentError("invalid map hash"));
+    }
+    let hashes: Vec<String> = hashes.iter().map(|h| h.to_lowercase()).collect();
+    Ok(base
+        .join(format!("maps/hash/{}", hashes.join(",")).as_str())
+        .unwrap())
+}
+
+/// Parses the response of the `maps/hash` endpoint, keyed by lowercase hash
+///
+/// The endpoint returns the map itself rather than an object when a single hash is requested.
+pub(crate) fn parse_maps_by_hashes<T: Error>(
+    hashes: &[String],
+    data: &str,
+) -> Result<HashMap<String, MapDetail>, BeatSaverApiError<T>> {
+    if let [hash] = hashes {
+        let map: MapDetail = serde_json::from_str(data)?;
+        let mut maps = HashMap::new();
+        maps.insert(hash.to_lowercase(), map);
+        return Ok(maps);
+    }
+    let maps: HashMap<String, Option<MapDetail>> = serde_json::from_str(data)?;
+    Ok(maps
+        .into_iter()
+        .filter_map(|(h, m)| m.map(|m| (h.to_lowercase(), m)))
+        .collect())
+}
+
 /// Error type for parsing a Map ID
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[non_exhaustive]
 pub enum MapIdError {
     /// Error returned when the provided hash is invalid
     ///
     /// This can occur in the following conditions:
     /// * The length of the hash is not 24
     /// * The hash contains non-hex characters
+    #[error("Specified hash is invalid")]
     InvalidHash,
-    /// Error returned if the provided key is invalid
+    /// Error returned if a numeric key couldn't be parsed
     ///
     /// This can occur in the following conditions:
     /// * Key is larger than a [usize][std::usize]
     /// * Key contains non-hex characters
-    ParseIntError(ParseIntError),
+    #[error(transparent)]
+    ParseIntError(#[from] ParseIntError),
+    /// Error returned if the provided key is invalid
+    ///
+    /// This can occur in the following conditions:
+    /// * Key is empty
+    /// * Key contains characters other than ASCII letters and digits
+    #[error("Specified key is invalid")]
+    InvalidKey,
+    /// Error returned if the provided URL doesn't point to a map
+    #[error("Specified URL is not a map URL")]
+    InvalidUrl,
 }
-impl fmt::Display for MapIdError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Self::InvalidHash => write!(f, "Specified hash is invalid"),
-            Self::ParseIntError(e) => e.fmt(f),
+impl From<FromHexError> for MapIdError {
+    fn from(_: FromHexError) -> Self {
+        Self::InvalidHash
+    }
+}
+
+/// Scheme of the OneClick install URLs registered by mod managers (e.g. ModAssistant)
+pub const ONE_CLICK_SCHEME: &str = "beatsaver://";
+
+/// Key of a map (e.g. `2144`)
+///
+/// Keys are kept as the string BeatSaver uses, since newer keys aren't guaranteed to be hex numbers
+/// that fit in a [usize][std::usize]. Numeric keys from older APIs can still be converted using
+/// the [From][std::convert::From] trait.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MapKey(String);
+impl MapKey {
+    /// Validates a map key, keys must be non-empty and only contain ASCII letters and digits
+    pub fn new(key: &str) -> Result<Self, MapIdError> {
+        match !key.is_empty() && key.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            true => Ok(Self(key.to_string())),
+            false => Err(MapIdError::InvalidKey),
         }
     }
+    /// Returns the key as a string
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+    /// Returns the numeric value of the key, if it is a hex number that fits in a [usize][std::usize]
+    pub fn to_number(&self) -> Option<usize> {
+        usize::from_str_radix(self.0.as_str(), 16).ok()
+    }
 }
-impl Error for MapIdError {}
-impl From<ParseIntError> for MapIdError {
-    fn from(e: ParseIntError) -> Self {
-        Self::ParseIntError(e)
+impl fmt::Display for MapKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0.as_str())
     }
 }
-impl From<FromHexError> for MapIdError {
-    fn from(_: FromHexError) -> Self {
-        Self::InvalidHash
+impl FromStr for MapKey {
+    type Err = MapIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+impl From<usize> for MapKey {
+    fn from(key: usize) -> Self {
+        Self(format!("{:x}", key))
+    }
+}
+impl TryFrom<&str> for MapKey {
+    type Error = MapIdError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+impl TryFrom<String> for MapKey {
+    type Error = MapIdError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(s.as_str())
     }
 }
 
 /// Specifier used to index a map
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Map IDs can be parsed from keys, hashes, OneClick install URLs and map URLs from BeatSaver or
+/// BeastSaber using the [TryFrom][std::convert::TryFrom] trait.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MapId {
     /// Identifier is a map key (e.g. `1`)
-    Key(usize),
+    Key(MapKey),
     /// Identifier is a map hash (e.g. `fda568fc27c20d21f8dc6f3709b49b5cc96723be`)
     Hash(String),
 }
+/// Hosts of the sites whose map URLs can be parsed into a [MapId][crate::MapId]
+const MAP_URL_HOSTS: &[&str] = &["beatsaver.com", "bsaber.com"];
+/// Path segments that precede a key or hash in map URLs (e.g. `/maps/2144` or `/songs/2144`)
+const MAP_URL_PATHS: &[&str] = &["maps", "beatmap", "songs", "id", "key", "hash"];
+
+/// Returns the part of a OneClick install URL following the scheme
+fn strip_one_click(url: &str) -> Option<&str> {
+    match url.get(..ONE_CLICK_SCHEME.len()) {
+        Some(scheme) if scheme.eq_ignore_ascii_case(ONE_CLICK_SCHEME) => {
+            Some(url[ONE_CLICK_SCHEME.len()..].trim_end_matches('/'))
+        }
+        _ => None,
+    }
+}
+
+impl MapId {
+    /// Parses a OneClick install URL (e.g. `beatsaver://2144`)
+    pub fn from_one_click(url: &str) -> Result<Self, MapIdError> {
+        let key = strip_one_click(url).ok_or(MapIdError::InvalidUrl)?;
+        Ok(Self::Key(MapKey::new(key)?))
+    }
+    /// Parses a map URL from BeatSaver or BeastSaber (e.g. `https://beatsaver.com/maps/2144`)
+    ///
+    /// OneClick install URLs are also accepted.
+    pub fn from_url(url: &str) -> Result<Self, MapIdError> {
+        let url = url.trim();
+        if strip_one_click(url).is_some() {
+            return Self::from_one_click(url);
+        }
+        let parsed = Url::parse(url).map_err(|_| MapIdError::InvalidUrl)?;
+        let host = parsed.host_str().ok_or(MapIdError::InvalidUrl)?;
+        if !MAP_URL_HOSTS
+            .iter()
+            .any(|h| host == *h || host.strip_suffix(h).is_some_and(|s| s.ends_with('.')))
+        {
+            return Err(MapIdError::InvalidUrl);
+        }
+        let segments: Vec<&str> = parsed
+            .path_segments()
+            .ok_or(MapIdError::InvalidUrl)?
+            .filter(|s| !s.is_empty())
+            .collect();
+        match segments.as_slice() {
+            [.., prefix, id] if MAP_URL_PATHS.contains(prefix) => (*id).try_into(),
+            _ => Err(MapIdError::InvalidUrl),
+        }
+    }
+    /// Builds the OneClick install URL of the map (e.g. `beatsaver://2144`)
+    ///
+    /// Note: OneClick URLs only support keys, `None` is returned for hashes
+    pub fn one_click_url(&self) -> Option<String> {
+        match self {
+            Self::Key(key) => Some(format!("{}{}", ONE_CLICK_SCHEME, key)),
+            Self::Hash(_) => None,
+        }
+    }
+}
+impl fmt::Display for MapId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Key(key) => key.fmt(f),
+            Self::Hash(hash) => f.write_str(hash.as_str()),
+        }
+    }
+}
+impl FromStr for MapId {
+    type Err = MapIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
 impl TryFrom<String> for MapId {
     type Error = MapIdError;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains("://") {
+            return Self::from_url(s.as_str());
+        }
         match s.len() {
             40 => {
                 hex::decode(&s)?;
                 Ok(Self::Hash(s))
             }
-            _ => Ok(Self::Key(usize::from_str_radix(s.as_str(), 16)?)),
+            _ => Ok(Self::Key(MapKey::new(s.as_str())?)),
         }
     }
 }
@@ -250,64 +850,237 @@ impl TryFrom<&str> for MapId {
         s.to_string().try_into()
     }
 }
-impl Into<MapId> for Map {
-    fn into(self) -> MapId {
-        MapId::Hash(self.hash)
+impl TryFrom<&MapDetail> for MapId {
+    type Error = MapIdError;
+
+    fn try_from(map: &MapDetail) -> Result<Self, Self::Error> {
+        Ok(Self::Key(MapKey::new(map.id.as_str())?))
     }
 }
-impl Into<MapId> for &Map {
-    fn into(self) -> MapId {
-        MapId::Hash(self.hash.clone())
+impl TryFrom<MapDetail> for MapId {
+    type Error = MapIdError;
+
+    fn try_from(map: MapDetail) -> Result<Self, Self::Error> {
+        (&map).try_into()
+    }
+}
+impl From<MapKey> for MapId {
+    fn from(key: MapKey) -> Self {
+        Self::Key(key)
+    }
+}
+impl From<usize> for MapId {
+    fn from(key: usize) -> Self {
+        Self::Key(key.into())
     }
 }
 
+/// Result of downloading a map in a batch that skips installed maps
+#[derive(Debug, Clone, PartialEq)]
+pub enum DownloadOutcome {
+    /// Map was downloaded
+    Downloaded(Bytes),
+    /// Latest version of the map is already installed, so it wasn't downloaded
+    Skipped,
+}
+
 /// Error that could occur when querying the API
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum BeatSaverApiError<T: fmt::Display> {
     /// Error originated from the request backend
+    #[error(transparent)]
     RequestError(T),
     /// Error originated from deserializing the api response
-    SerializeError(serde_json::Error),
+    #[error(transparent)]
+    SerializeError(#[from] serde_json::Error),
     /// Argument provided is invalid
+    #[error("Invalid argument: {0}")]
     ArgumentError(&'static str),
     /// Conversion to a [String][std::string::String] failed
-    Utf8Error(FromUtf8Error),
+    #[error(transparent)]
+    Utf8Error(#[from] FromUtf8Error),
     /// Error in IO
-    IoError(std::io::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
     /// Rate limit was hit while making the request
+    #[error("API rate limit hit (retry in {} ms)", .0.reset_after.as_millis())]
     RateLimitError(BeatSaverRateLimit),
+    /// Endpoint requires authentication but the client has no credentials attached
+    #[error("Endpoint requires authentication")]
+    AuthenticationRequired,
+    /// No map exists with the requested id
+    #[error("Map {0} not found")]
+    NotFound(MapId),
+    /// Server answered with an unexpected non-2xx status
+    #[error("{url} returned {status}{}", api_error_suffix(.body))]
+    HttpError {
+        /// Status code of the response
+        status: StatusCode,
+        /// URL the request was sent to
+        url: Url,
+        /// Body of the response, see [api_error][crate::BeatSaverApiError::api_error] to parse
+        /// the error payload it contains
+        body: Bytes,
+    },
+    /// Downloaded archive doesn't match the hash of the version
+    #[error("Expected hash {expected}, found {actual}")]
+    HashMismatch {
+        /// Hash of the version
+        expected: String,
+        /// Hash computed from the downloaded archive
+        actual: String,
+    },
+    /// Downloaded archive couldn't be read
+    #[cfg(feature = "archive")]
+    #[error(transparent)]
+    ArchiveError(#[from] archive::ArchiveError),
+    /// Local mirror couldn't be read or written
+    #[cfg(feature = "mirror")]
+    #[error(transparent)]
+    MirrorError(#[from] mirror::MirrorError),
 }
-impl<T: fmt::Display> fmt::Display for BeatSaverApiError<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+/// Formats the message of the [ApiErrorBody][crate::model::error::ApiErrorBody] contained in a
+/// response body to append it to the message of a BeatSaverApiError::HttpError
+fn api_error_suffix(body: &Bytes) -> String {
+    match ApiErrorBody::parse(body.as_ref()) {
+        Some(e) => format!(": {}", e),
+        None => String::new(),
+    }
+}
+impl<T: fmt::Display> BeatSaverApiError<T> {
+    /// Returns the status code of the response that caused the error, if any
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::HttpError { status, .. } => Some(*status),
+            Self::NotFound(_) => Some(StatusCode::NOT_FOUND),
+            Self::RateLimitError(_) => Some(StatusCode::TOO_MANY_REQUESTS),
+            _ => None,
+        }
+    }
+    /// Parses the error payload sent by the API along with a failed response, if any
+    pub fn api_error(&self) -> Option<ApiErrorBody> {
         match self {
-            Self::RequestError(e) => <T as fmt::Display>::fmt(e, f),
-            Self::SerializeError(e) => e.fmt(f),
-            Self::ArgumentError(a) => write!(f, "Invalid argument: {}", a),
-            Self::Utf8Error(e) => e.fmt(f),
-            Self::IoError(e) => e.fmt(f),
-            Self::RateLimitError(e) => {
-                write!(
-                    f,
-                    "API rate limit hit (retry in {} ms)",
-                    e.reset_after.as_millis()
-                )
+            Self::HttpError { body, .. } => ApiErrorBody::parse(body.as_ref()),
+            _ => None,
+        }
+    }
+    /// Returns `true` if the request that caused the error can succeed when sent again
+    ///
+    /// IO errors caused by dropped connections, rate limits, and 5xx or 408 responses are
+    /// retryable. Other errors, such as missing maps or invalid arguments, fail the same way every
+    /// time.
+    ///
+    /// Note: errors from the request backend aren't retryable, as only the backend knows which of
+    /// them are transient, see [is_transient][crate::client::HttpBackend::is_transient]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RateLimitError(_) => true,
+            Self::IoError(e) => retry::is_transient_io(e),
+            Self::HttpError { status, .. } => retry::is_retryable_status(*status),
+            _ => false,
+        }
+    }
+    /// Returns how long to wait before sending the request again, if the API specified it
+    ///
+    /// Note: only rate limits specify a delay, other [retryable][crate::BeatSaverApiError::is_retryable]
+    /// errors should be retried using a backoff such as the one of
+    /// [RetryPolicy][crate::retry::RetryPolicy].
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimitError(limit) => Some(limit.reset_after),
+            _ => None,
+        }
+    }
+    /// Returns the URL of the request that caused the error, if known
+    pub fn url(&self) -> Option<&Url> {
+        match self {
+            Self::HttpError { url, .. } => Some(url),
+            _ => None,
+        }
+    }
+}
+#[cfg(feature = "hash")]
+impl<T: fmt::Display> From<hash::HashError> for BeatSaverApiError<T> {
+    fn from(e: hash::HashError) -> Self {
+        match e {
+            hash::HashError::ArchiveError(e) => Self::ArchiveError(e),
+            hash::HashError::InfoError(e) => Self::SerializeError(e),
+            hash::HashError::IoError(e) => Self::IoError(e),
+            hash::HashError::Mismatch { expected, actual } => {
+                Self::HashMismatch { expected, actual }
             }
         }
     }
 }
-impl<T: fmt::Display> From<serde_json::Error> for BeatSaverApiError<T> {
-    fn from(e: serde_json::Error) -> Self {
-        Self::SerializeError(e)
+
+/// Error of a request backend with its type erased
+///
+/// Used by [BeatSaverError][crate::BeatSaverError], so libraries can expose a single error type
+/// whichever backend their users enable.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BackendError(Box<dyn Error + Send + Sync>);
+impl BackendError {
+    /// Wraps the error of a request backend
+    pub fn new<E: Error + Send + Sync + 'static>(e: E) -> Self {
+        Self(Box::new(e))
+    }
+    /// Returns the error of the request backend if it is of type `E`
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        self.0.downcast_ref()
+    }
+    /// Returns the error of the request backend
+    pub fn into_inner(self) -> Box<dyn Error + Send + Sync> {
+        self.0
     }
 }
-impl<T: fmt::Display> From<FromUtf8Error> for BeatSaverApiError<T> {
-    fn from(e: FromUtf8Error) -> Self {
-        Self::Utf8Error(e)
+impl From<BackendError> for BeatSaverApiError<BackendError> {
+    fn from(e: BackendError) -> Self {
+        Self::RequestError(e)
     }
 }
-impl<T: fmt::Display> From<std::io::Error> for BeatSaverApiError<T> {
-    fn from(e: std::io::Error) -> Self {
-        Self::IoError(e)
+
+/// [BeatSaverApiError][crate::BeatSaverApiError] with the error type of the request backend erased
+///
+/// Errors of every backend can be converted with [erase][crate::BeatSaverApiError::erase]:
+/// ```no_run
+/// # #[cfg(feature = "reqwest_backend")]
+/// # mod main {
+/// use beatsaver_rs::client::BeatSaverReqwest;
+/// use beatsaver_rs::{BeatSaverApiAsync, BeatSaverApiError, BeatSaverError, MapId};
+///
+/// async fn map_name(id: &MapId) -> Result<String, BeatSaverError> {
+///     let client = BeatSaverReqwest::new();
+///     let map = client.map(id).await.map_err(BeatSaverApiError::erase)?;
+///     Ok(map.name)
+/// }
+/// # }
+/// ```
+pub type BeatSaverError = BeatSaverApiError<BackendError>;
+impl<T: Error + Send + Sync + 'static> BeatSaverApiError<T> {
+    /// Erases the error type of the request backend, see [BeatSaverError][crate::BeatSaverError]
+    pub fn erase(self) -> BeatSaverError {
+        match self {
+            Self::RequestError(e) => BeatSaverApiError::RequestError(BackendError::new(e)),
+            Self::SerializeError(e) => BeatSaverApiError::SerializeError(e),
+            Self::ArgumentError(a) => BeatSaverApiError::ArgumentError(a),
+            Self::Utf8Error(e) => BeatSaverApiError::Utf8Error(e),
+            Self::IoError(e) => BeatSaverApiError::IoError(e),
+            Self::RateLimitError(limit) => BeatSaverApiError::RateLimitError(limit),
+            Self::AuthenticationRequired => BeatSaverApiError::AuthenticationRequired,
+            Self::NotFound(id) => BeatSaverApiError::NotFound(id),
+            Self::HttpError { status, url, body } => {
+                BeatSaverApiError::HttpError { status, url, body }
+            }
+            Self::HashMismatch { expected, actual } => {
+                BeatSaverApiError::HashMismatch { expected, actual }
+            }
+            #[cfg(feature = "archive")]
+            Self::ArchiveError(e) => BeatSaverApiError::ArchiveError(e),
+            #[cfg(feature = "mirror")]
+            Self::MirrorError(e) => BeatSaverApiError::MirrorError(e),
+        }
     }
 }
 
@@ -315,20 +1088,44 @@ impl<T: fmt::Display> From<std::io::Error> for BeatSaverApiError<T> {
 pub use async_api::BeatSaverApiAsync as BeatSaverApi;
 #[cfg(feature = "async")]
 pub use async_api::BeatSaverApiAsync;
+#[cfg(feature = "async")]
+pub use async_api::{
+    merge_feeds, BatchStream, ByteStream, DownloadStream, MapStream, MergeOrder, PageStream,
+    PlaylistStream, VoteStream,
+};
 
 #[cfg(all(feature = "sync", not(feature = "async")))]
 pub use sync_api::BeatSaverApiSync as BeatSaverApi;
 #[cfg(feature = "sync")]
 pub use sync_api::BeatSaverApiSync;
+#[cfg(feature = "sync")]
+pub use sync_api::{
+    LatestIterator, LatestPagesIterator, LatestPlaylistIterator, MapIterator, PageIterator,
+    PagesIterator, PlaylistIterator,
+};
 
 #[cfg(test)]
 mod tests {
-    use crate::map::Map;
-    use crate::{BeatSaverApiError, Page};
+    use crate::cache::ResponseCache;
+    use crate::index::KeyIndex;
+    use crate::model::map::MapDetail;
+    use crate::playlist::{Playlist, PlaylistSong};
+    use crate::retry::RetryPolicy;
+    use crate::{
+        content_length, download_url, get_request, response_body, BeatSaverApiError,
+        BeatSaverError, MapId, MapIdError, MapKey, Page, RateLimitPolicy, RateLimitStatus,
+        RateLimitTracker, BEATSAVER_URL,
+    };
     use bytes::Bytes;
-    use std::collections::HashMap;
+    use chrono::Utc;
+    use http::{header, HeaderMap, Request, Response, StatusCode};
+    use proptest::prelude::*;
+    use std::collections::{HashMap, HashSet};
+    use std::convert::TryFrom;
     use std::error::Error;
     use std::fmt::{self, Display, Formatter};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
     use url::Url;
 
     #[derive(Debug)]
@@ -344,34 +1141,546 @@ mod tests {
             Self::RequestError(e)
         }
     }
+    /// Client answering a single URL, used by the tests of both API traits
+    #[cfg(any(feature = "sync", feature = "tokio", feature = "async-std"))]
     pub struct FakeClient {
         pub url: Url,
         pub data: Bytes,
+        pub index: KeyIndex,
+        pub credentials: Option<crate::auth::Credentials>,
     }
+    #[cfg(any(feature = "sync", feature = "tokio", feature = "async-std"))]
     impl FakeClient {
         pub fn new(url: Url, data: Bytes) -> Self {
-            Self { url, data }
+            Self {
+                url,
+                data,
+                index: KeyIndex::new(),
+                credentials: None,
+            }
         }
     }
     pub struct FakeClientPaged {
         pub pages: HashMap<Url, Bytes>,
+        pub index: KeyIndex,
+        /// Number of upcoming requests answered with a 429
+        pub rate_limits: AtomicUsize,
+        pub rate_limit_policy: RateLimitPolicy,
+        pub rate_limit_tracker: RateLimitTracker,
+        /// Number of upcoming requests answered with a 503
+        pub server_errors: AtomicUsize,
+        pub retry_policy: RetryPolicy,
+        pub cache: Option<Box<dyn ResponseCache>>,
+        /// Number of requests answered with a 304
+        pub not_modified: AtomicUsize,
+        /// URLs answered with a 404
+        pub deleted: HashSet<Url>,
     }
     impl FakeClientPaged {
         pub fn new(pages: HashMap<Url, Bytes>) -> Self {
-            Self { pages }
+            Self {
+                pages,
+                index: KeyIndex::new(),
+                rate_limits: AtomicUsize::new(0),
+                rate_limit_policy: RateLimitPolicy::default(),
+                rate_limit_tracker: RateLimitTracker::new(),
+                server_errors: AtomicUsize::new(0),
+                retry_policy: RetryPolicy::default(),
+                cache: None,
+                not_modified: AtomicUsize::new(0),
+                deleted: HashSet::new(),
+            }
+        }
+        /// Returns a 404 response if the requested URL was deleted
+        pub fn not_found(&self, request: &Request<Bytes>) -> Option<Response<Bytes>> {
+            let url = Url::parse(request.uri().to_string().as_str()).unwrap();
+            if !self.deleted.contains(&url) {
+                return None;
+            }
+            let mut response = Response::new(Bytes::from("Not Found"));
+            *response.status_mut() = StatusCode::NOT_FOUND;
+            Some(response)
+        }
+        /// Returns the page with an ETag, or a 304 response if the request has a matching
+        /// `If-None-Match` header
+        pub fn page(&self, request: &Request<Bytes>, data: &Bytes) -> Response<Bytes> {
+            let etag = format!("\"{}\"", data.len());
+            if request.headers().get(header::IF_NONE_MATCH) == Some(&etag.parse().unwrap()) {
+                self.not_modified.fetch_add(1, Ordering::SeqCst);
+                let mut response = Response::new(Bytes::new());
+                *response.status_mut() = StatusCode::NOT_MODIFIED;
+                return response;
+            }
+            let mut response = Response::new(data.clone());
+            response
+                .headers_mut()
+                .insert(header::ETAG, etag.parse().unwrap());
+            response
+        }
+        /// Returns a 429 response if the request should be rate limited
+        pub fn rate_limited(&self) -> Option<Response<Bytes>> {
+            self.rate_limits
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .ok()?;
+            Some(crate::testing::fixtures::rate_limit_response(
+                Duration::from_millis(10),
+            ))
+        }
+        /// Returns a 503 response if the request should fail
+        pub fn server_error(&self) -> Option<Response<Bytes>> {
+            self.server_errors
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .ok()?;
+            let mut response = Response::new(Bytes::from("Service Unavailable"));
+            *response.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+            response
+                .headers_mut()
+                .insert("x-ratelimit-remaining", "7".parse().unwrap());
+            Some(response)
+        }
+    }
+
+    #[cfg(any(feature = "sync", feature = "tokio", feature = "async-std"))]
+    pub use crate::testing::fixtures::playlist_page_json;
+    pub use crate::testing::fixtures::{map_hash, map_json, page_json, playlist_maps_json};
+    /// Builds a zip archive containing the specified files, files over 256 bytes are deflated
+    #[cfg(feature = "archive")]
+    pub fn zip_archive(files: &[(&str, &[u8])]) -> Bytes {
+        use flate2::write::DeflateEncoder;
+        use flate2::{Compression, Crc};
+        use std::io::Write;
+
+        let mut data = vec![];
+        let mut central = vec![];
+        for (name, contents) in files {
+            let mut crc = Crc::new();
+            crc.update(contents);
+            let (method, compressed) = match contents.len() > 256 {
+                true => {
+                    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+                    encoder.write_all(contents).unwrap();
+                    (8u16, encoder.finish().unwrap())
+                }
+                false => (0u16, contents.to_vec()),
+            };
+            // Fields shared by the local and central headers: version, flags, method, time, date,
+            // crc, sizes, name and extra field lengths
+            let mut fields = vec![];
+            fields.extend_from_slice(&20u16.to_le_bytes());
+            fields.extend_from_slice(&0u16.to_le_bytes());
+            fields.extend_from_slice(&method.to_le_bytes());
+            fields.extend_from_slice(&0u32.to_le_bytes());
+            fields.extend_from_slice(&crc.sum().to_le_bytes());
+            fields.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+            fields.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+            fields.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            fields.extend_from_slice(&0u16.to_le_bytes());
+
+            central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+            central.extend_from_slice(&20u16.to_le_bytes());
+            central.extend_from_slice(&fields);
+            // Comment length, disk number, internal and external attributes
+            central.extend_from_slice(&[0; 10]);
+            central.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            central.extend_from_slice(name.as_bytes());
+
+            data.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+            data.extend_from_slice(&fields);
+            data.extend_from_slice(name.as_bytes());
+            data.extend_from_slice(&compressed);
         }
+        let offset = data.len() as u32;
+        data.extend_from_slice(&central);
+        data.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&(files.len() as u16).to_le_bytes());
+        data.extend_from_slice(&(files.len() as u16).to_le_bytes());
+        data.extend_from_slice(&(central.len() as u32).to_le_bytes());
+        data.extend_from_slice(&offset.to_le_bytes());
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.into()
+    }
+    /// Hash of the archive built by [map_archive]
+    #[cfg(feature = "hash")]
+    pub const MAP_ARCHIVE_HASH: &str = "5918e3b54a744493480b3483744b1ad595bfae60";
+    /// Builds a map archive with two difficulties
+    #[cfg(feature = "archive")]
+    pub fn map_archive() -> Bytes {
+        zip_archive(&[
+            ("Info.dat", br#"{"_version":"2.0.0","_songName":"Test","_difficultyBeatmapSets":[{"_beatmapCharacteristicName":"Standard","_difficultyBeatmaps":[{"_difficulty":"Expert","_beatmapFilename":"Expert.dat"},{"_difficulty":"ExpertPlus","_beatmapFilename":"ExpertPlus.dat"}]}]}"#.as_ref()),
+            ("Expert.dat", br#"{"_notes":[]}"#.as_ref()),
+            ("ExpertPlus.dat", br#"{"_notes":[{"_time":1}]}"#.as_ref()),
+            ("cover.jpg", b"cover".as_ref()),
+        ])
     }
 
     #[test]
     fn test_page() {
-        let data = r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":417,"length":195,"bombs":4,"notes":301,"obstacles":24,"njs":10,"njsOffset":0},"hard":{"duration":417,"length":195,"bombs":4,"notes":486,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":417.5,"length":195,"bombs":4,"notes":620,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":{"duration":417.5,"length":195,"bombs":0,"notes":894,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Shut Up and Dance","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":418854,"plays":558,"downVotes":133,"upVotes":10763,"heat":395.8225333,"rating":0.9580848467461356},"description":"Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY","deletedAt":null,"_id":"5cff621148229f7d88fc77c9","key":"2144","name":"Shut Up and Dance - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-21T01:27:00.000Z","hash":"89cf8bb07afb3c59ae7b5ac00337d62261c36fb4","directDownload":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip","downloadURL":"/api/download/key/2144","coverURL":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":623.3125,"length":214,"bombs":0,"notes":462,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":623.3125,"length":214,"bombs":0,"notes":639,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":623.3125,"length":214,"bombs":0,"notes":825,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Mr. Blue Sky","songSubName":"Electric Light Orchestra","songAuthorName":"GreatYazer","levelAuthorName":"greatyazer","bpm":174},"stats":{"downloads":924827,"plays":39426,"downVotes":482,"upVotes":22614,"heat":94.0164429,"rating":0.9558554197954805},"description":"Channel your inner Baby Groot.  Normal, Hard, Expert\r\nSpecial thanks to BennydaBeast for his help on this track!","deletedAt":null,"_id":"5cff620d48229f7d88fc65f7","key":"570","name":"Mr. Blue Sky | Electric Light Orchestra","uploader":{"_id":"5cff0b7298cc5a672c84ea71","username":"greatyazer"},"uploaded":"2018-06-16T16:53:34.000Z","hash":"236173d5ba7dc379d480b9cb5fb6b4fa5abe77da","directDownload":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.zip","downloadURL":"/api/download/key/570","coverURL":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":476.7637634277344,"length":173,"bombs":52,"notes":722,"obstacles":28,"njs":14,"njsOffset":0},"expertPlus":null}}],"songName":"Caramelldansen (Speedcake Remix)","songSubName":"Caramell","songAuthorName":"Dack","levelAuthorName":"Dack","bpm":165},"stats":{"downloads":255909,"plays":0,"downVotes":247,"upVotes":13125,"heat":604.9830484,"rating":0.953954336380672},"description":"Preview: https://youtu.be/V5p0HOzunY0\n\n\nPatreon: https://www.patreon.com/Dack","deletedAt":null,"_id":"5cff621548229f7d88fc8904","key":"3cf5","name":"Caramelldansen","uploader":{"_id":"5cff0b7598cc5a672c852c6f","username":"dack"},"uploaded":"2019-03-09T22:54:54.000Z","hash":"cf5e32d6b7f30095f7198da5894139c92336cad7","directDownload":"/cdn/3cf5/cf5e32d6b7f30095f7198da5894139c92336cad7.zip","downloadURL":"/api/download/key/3cf5","coverURL":"/cdn/3cf5/cf5e32d6b7f30095f7198da5894139c92336cad7.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":475,"length":226,"bombs":0,"notes":620,"obstacles":10,"njs":10,"njsOffset":0},"expert":{"duration":475,"length":226,"bombs":0,"notes":738,"obstacles":11,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Feel Invincible","songSubName":"Skillet","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":126},"stats":{"downloads":264718,"plays":3479,"downVotes":90,"upVotes":7118,"heat":231.7496095,"rating":0.9538897573416698},"description":"Expert / Hard\r\nhttps://www.youtube.com/watch?v=nq-Qul4XxbE","deletedAt":null,"_id":"5cff620f48229f7d88fc6eba","key":"121f","name":"Skillet - Feel Invincible","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-08-27T16:47:05.000Z","hash":"2e9ab6e1fb8055649e241cade98b018926cc93a8","directDownload":"/cdn/121f/2e9ab6e1fb8055649e241cade98b018926cc93a8.zip","downloadURL":"/api/download/key/121f","coverURL":"/cdn/121f/2e9ab6e1fb8055649e241cade98b018926cc93a8.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":418,"length":200,"bombs":0,"notes":216,"obstacles":0,"njs":10,"njsOffset":0},"normal":{"duration":418,"length":200,"bombs":0,"notes":388,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":418,"length":200,"bombs":0,"notes":514,"obstacles":10,"njs":11,"njsOffset":0},"expert":{"duration":418,"length":200,"bombs":0,"notes":560,"obstacles":12,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Believer (100k ver.)","songSubName":"Imagine Dragons","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":125},"stats":{"downloads":511866,"plays":9381,"downVotes":215,"upVotes":11898,"heat":379.8995099,"rating":0.9538005825373931},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!","deletedAt":null,"_id":"5cff621148229f7d88fc76ec","key":"1fef","name":"Imagine Dragons - Believer (100k ver.) | 100k Contest","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-11-12T17:53:58.000Z","hash":"9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390","directDownload":"/cdn/1fef/9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390.zip","downloadURL":"/api/download/key/1fef","coverURL":"/cdn/1fef/9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":501.19000244140625,"length":227,"bombs":0,"notes":671,"obstacles":16,"njs":10,"njsOffset":0},"expert":{"duration":501.19000244140625,"length":227,"bombs":0,"notes":831,"obstacles":16,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Daddy","songSubName":"PSY","songAuthorName":"Fafurion","levelAuthorName":"fafurion","bpm":132},"stats":{"downloads":249499,"plays":2330,"downVotes":122,"upVotes":8354,"heat":243.8938387,"rating":0.9537082538199915},"description":"Insanely fun dance map! Enjoy!\r\nSee the map in action (Expert): https://www.youtube.com/watch?v=cWz6flYGs20\r\n\r\nThank you to my playtesters:\r\nQTPop (https://www.twitch.tv/qtpop)\r\nDuoVR (https://www.twitch.tv/duovr)\r\nSourgurl (https://www.twitch.tv/sourgurl)\r\nRexxxzi (https://www.twitch.tv/rexxxzi)\r\nAshleyriott (https://www.twitch.tv/ashleyriott)\r\n\r\nDiscord: @Fufu#5452","deletedAt":null,"_id":"5cff620f48229f7d88fc6f6c","key":"133b","name":"Daddy - PSY","uploader":{"_id":"5cff0b7398cc5a672c84f945","username":"fafurion"},"uploaded":"2018-09-02T23:43:45.000Z","hash":"dc489921185f92dfecb9cb07b84fc556123bd134","directDownload":"/cdn/133b/dc489921185f92dfecb9cb07b84fc556123bd134.zip","downloadURL":"/api/download/key/133b","coverURL":"/cdn/133b/dc489921185f92dfecb9cb07b84fc556123bd134.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":523.625,"length":245,"bombs":0,"notes":633,"obstacles":13,"njs":10,"njsOffset":0},"expert":{"duration":523.5650024414062,"length":245,"bombs":0,"notes":880,"obstacles":19,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Uprising","songSubName":"Muse","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":128},"stats":{"downloads":455551,"plays":11598,"downVotes":163,"upVotes":9190,"heat":84.4191212,"rating":0.9517991988617799},"description":"Expert / Hard\r\nhttps://youtu.be/WpFUt3UNp7c","deletedAt":null,"_id":"5cff620d48229f7d88fc655e","key":"4c6","name":"Muse - Uprising","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-06-11T21:47:41.000Z","hash":"00e5671e594a6fe621c3605fcc5a0e4466ba6478","directDownload":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.zip","downloadURL":"/api/download/key/4c6","coverURL":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":547,"length":234,"njs":12,"njsOffset":0,"bombs":0,"notes":705,"obstacles":10},"expertPlus":{"duration":547,"length":234,"njs":16,"njsOffset":0,"bombs":0,"notes":876,"obstacles":10},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"KikaeAeon","songAuthorName":"League of Legends & Against The Current","songName":"Legends Never Die","songSubName":"","bpm":140},"stats":{"downloads":141233,"plays":0,"downVotes":86,"upVotes":6203,"heat":997.2528853,"rating":0.9513775893989509},"description":"A special request from Prima1URGE","deletedAt":null,"_id":"5d91d6c1871b1a0006f9b3e7","key":"66e6","name":"League of Legends - Legends never die (ft. Against The Current)","uploader":{"_id":"5cff0b7498cc5a672c85109b","username":"kikaeaeon"},"hash":"732bd4072b89d4b3bf0e63db812a7ffc3096e837","uploaded":"2019-09-30T10:19:45.606Z","directDownload":"/cdn/66e6/732bd4072b89d4b3bf0e63db812a7ffc3096e837.zip","downloadURL":"/api/download/key/66e6","coverURL":"/cdn/66e6/732bd4072b89d4b3bf0e63db812a7ffc3096e837.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":578,"length":194,"bombs":8,"notes":519,"obstacles":86,"njs":13,"njsOffset":0},"expert":{"duration":578,"length":194,"bombs":10,"notes":679,"obstacles":86,"njs":16,"njsOffset":0},"expertPlus":null}}],"songName":"Flamingo","songSubName":"Kero Kero Bonito","songAuthorName":"ETAN","levelAuthorName":"ETAN","bpm":178},"stats":{"downloads":141034,"plays":0,"downVotes":89,"upVotes":6307,"heat":744.9760285,"rating":0.9513314992226289},"description":"edited: NJS was a tad slow my b\r\nfuramingo \r\noh oh ooh oh\r\nthis needed to be remapped\r\n \r\nPlease check out Kero Kero Bonito and the rest of their songs\r\nI'll be mappin more of em soon\r\n\r\nBPM 178\r\nFlamingo (Expert)\r\nShrimp (Hard)\r\n\r\nhave fun \r\n\r\ngimme feedback on Discord\r\nETAN#8341","deletedAt":null,"_id":"5cff621748229f7d88fc93fc","key":"4e6f","name":"Kero Kero Bonito - Flamingo","uploader":{"_id":"5cff0b7798cc5a672c855775","username":"etan"},"uploaded":"2019-05-22T00:46:47.000Z","hash":"585ee25e654ebf5db5aa0ec02c3bcecbaccf3e0b","directDownload":"/cdn/4e6f/585ee25e654ebf5db5aa0ec02c3bcecbaccf3e0b.zip","downloadURL":"/api/download/key/4e6f","coverURL":"/cdn/4e6f/585ee25e654ebf5db5aa0ec02c3bcecbaccf3e0b.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":689.8004760742188,"length":242,"bombs":16,"notes":1093,"obstacles":16,"njs":14,"njsOffset":0},"expertPlus":null}}],"songName":"IGNITE","songSubName":"Aoi Eir","songAuthorName":"Joetastic","levelAuthorName":"Joetastic","bpm":171},"stats":{"downloads":264817,"plays":0,"downVotes":141,"upVotes":7891,"heat":451.3583461,"rating":0.9502372997935349},"description":"Trying to get this version ranked! New version with changes according to the ranking criteria: Double directional notes fixed, flow improvements, removed fast dodge walls on bridge section.","deletedAt":null,"_id":"5cff621248229f7d88fc7b21","key":"26f6","name":"IGNITE (Ranked Version) [Sword Art Online Season 2 Opening] - Aoi Eir","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"uploaded":"2018-12-20T01:21:47.000Z","hash":"125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c","directDownload":"/cdn/26f6/125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c.zip","downloadURL":"/api/download/key/26f6","coverURL":"/cdn/26f6/125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c.jpg"}],"totalDocs":35367,"lastPage":3536,"prevPage":null,"nextPage":1}"#;
-
-        let page: Page<Map> = serde_json::from_str(data).unwrap();
+        let data = page_json(&["2144", "570"]);
+        let page: Page<MapDetail> = Page::parse(std::str::from_utf8(&data).unwrap(), 0).unwrap();
 
-        assert_eq!(page.docs.len(), 10);
-        assert_eq!(page.total_docs, 35367);
-        assert_eq!(page.last_page, 3536);
+        assert_eq!(page.docs.len(), 2);
+        assert_eq!(page.docs[0].id, "2144");
+        assert_eq!(page.total_docs, None);
+        assert_eq!(page.last_page, None);
         assert_eq!(page.prev_page, None);
         assert_eq!(page.next_page, Some(1));
+
+        let page: Page<MapDetail> = Page::parse(r#"{"docs":[]}"#, 3).unwrap();
+        assert_eq!(page.prev_page, Some(2));
+        assert_eq!(page.next_page, None);
+    }
+    #[test]
+    fn test_page_info() {
+        let data = format!(
+            r#"{{"docs":[{}],"info":{{"total":41,"pages":3,"duration":0.01}}}}"#,
+            map_json("2144")
+        );
+        let page: Page<MapDetail> = Page::parse(data.as_str(), 1).unwrap();
+        assert_eq!(page.total_docs, Some(41));
+        assert_eq!(page.last_page, Some(2));
+        assert_eq!(page.prev_page, Some(0));
+        assert_eq!(page.next_page, Some(2));
+
+        let page: Page<MapDetail> = Page::parse(data.as_str(), 2).unwrap();
+        assert_eq!(page.next_page, None);
+    }
+    #[test]
+    fn test_get_request() {
+        let request = get_request(BEATSAVER_URL.join("maps/id/1").unwrap());
+
+        assert_eq!(request.method(), "GET");
+        assert_eq!(request.uri(), "https://api.beatsaver.com/maps/id/1");
+        assert!(request.headers().contains_key(header::USER_AGENT));
+        assert!(request.body().is_empty());
+    }
+    #[test]
+    fn test_error_source() {
+        let e = MapKey::new("z-z").unwrap_err();
+        assert_eq!(e, MapIdError::InvalidKey);
+        let e: BeatSaverApiError<FakeError> =
+            serde_json::from_str::<MapDetail>("{}").unwrap_err().into();
+        assert!(matches!(e, BeatSaverApiError::SerializeError(_)));
+        assert!(e.to_string().starts_with("missing field"));
+
+        let e: Box<dyn Error + Send + Sync> = Box::new(BeatSaverApiError::<FakeError>::NotFound(
+            MapId::from(0x2144),
+        ));
+        assert_eq!(e.to_string(), "Map 2144 not found");
+        assert!(e.source().is_none());
+
+        let e = MapIdError::from("1g".parse::<usize>().unwrap_err());
+        assert_eq!(e.to_string(), "invalid digit found in string");
+    }
+    #[test]
+    fn test_erase() {
+        #[derive(Debug)]
+        struct BackendFailure;
+        impl Display for BackendFailure {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "connection refused")
+            }
+        }
+        impl Error for BackendFailure {}
+
+        let e = BeatSaverApiError::RequestError(BackendFailure).erase();
+        assert_eq!(e.to_string(), "connection refused");
+        match &e {
+            BeatSaverApiError::RequestError(e) => {
+                assert!(e.downcast_ref::<BackendFailure>().is_some());
+                assert!(e.downcast_ref::<std::io::Error>().is_none());
+            }
+            _ => panic!("expected a request error"),
+        }
+        let e: BeatSaverError = BeatSaverApiError::<FakeError>::NotFound(MapId::from(1)).erase();
+        assert!(matches!(e, BeatSaverApiError::NotFound(_)));
+        let e: Box<dyn Error + Send + Sync> = Box::new(e);
+        assert_eq!(e.to_string(), "Map 1 not found");
+    }
+    #[test]
+    fn test_response_body() {
+        let url = BEATSAVER_URL.join("maps/id/1").unwrap();
+        let response = Response::new(Bytes::from("map #1"));
+        let data = response_body::<FakeError>(&url, response).unwrap();
+        assert_eq!(data, "map #1");
+
+        let mut response =
+            Response::new(Bytes::from(r#"{"reset":1608854400000,"resetAfter":5000}"#));
+        *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+        match response_body::<FakeError>(&url, response) {
+            Err(BeatSaverApiError::RateLimitError(r)) => {
+                assert_eq!(r.reset.timestamp(), 1608854400);
+                assert_eq!(r.reset_after.as_millis(), 5000);
+            }
+            _ => panic!("expected a rate limit error"),
+        }
+
+        let mut response = Response::new(Bytes::from("Internal error"));
+        *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+        let e = response_body::<FakeError>(&url, response).unwrap_err();
+        match &e {
+            BeatSaverApiError::HttpError { status, url, body } => {
+                assert_eq!(*status, StatusCode::INTERNAL_SERVER_ERROR);
+                assert_eq!(url.as_str(), "https://api.beatsaver.com/maps/id/1");
+                assert_eq!(body, "Internal error");
+            }
+            _ => panic!("expected an HTTP error"),
+        }
+        assert_eq!(e.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+        assert_eq!(e.url(), Some(&url));
+        assert_eq!(
+            e.to_string(),
+            "https://api.beatsaver.com/maps/id/1 returned 500 Internal Server Error"
+        );
+        assert_eq!(e.api_error(), None);
+
+        let mut response = Response::new(Bytes::from(r#"{"error":"Unknown user"}"#));
+        *response.status_mut() = StatusCode::BAD_REQUEST;
+        let e = response_body::<FakeError>(&url, response).unwrap_err();
+        assert_eq!(e.api_error().unwrap().error, Some("Unknown user".into()));
+        assert_eq!(
+            e.to_string(),
+            "https://api.beatsaver.com/maps/id/1 returned 400 Bad Request: Unknown user"
+        );
+    }
+    #[test]
+    fn test_rate_limit_fallback() {
+        let url = BEATSAVER_URL.join("maps/id/1").unwrap();
+        let too_many_requests = |body: &'static str, retry_after: Option<&'static str>| {
+            let mut response = Response::new(Bytes::from(body));
+            *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+            if let Some(v) = retry_after {
+                response
+                    .headers_mut()
+                    .insert(header::RETRY_AFTER, v.parse().unwrap());
+            }
+            response_body::<FakeError>(&url, response).unwrap_err()
+        };
+
+        match too_many_requests("<html>Too Many Requests</html>", Some("30")) {
+            BeatSaverApiError::RateLimitError(r) => {
+                assert_eq!(r.reset_after, Duration::from_secs(30));
+                assert!(r.reset > Utc::now());
+            }
+            e => panic!("expected a rate limit error, got {:?}", e),
+        }
+        match too_many_requests("", Some("Thu, 01 Jan 1970 00:00:00 GMT")) {
+            BeatSaverApiError::RateLimitError(r) => assert_eq!(r.reset_after, Duration::ZERO),
+            e => panic!("expected a rate limit error, got {:?}", e),
+        }
+        match too_many_requests("<html>Too Many Requests</html>", None) {
+            BeatSaverApiError::HttpError { status, body, .. } => {
+                assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+                assert_eq!(body, "<html>Too Many Requests</html>");
+            }
+            e => panic!("expected an HTTP error, got {:?}", e),
+        }
+        assert!(matches!(
+            too_many_requests("\u{ff}", Some("invalid")),
+            BeatSaverApiError::HttpError { .. }
+        ));
+    }
+    #[test]
+    fn test_rate_limit_status() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(RateLimitStatus::from_headers(&headers), None);
+        headers.insert("x-ratelimit-remaining", "42".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "1608854400".parse().unwrap());
+        let status = RateLimitStatus::from_headers(&headers).unwrap();
+        assert_eq!(status.remaining, Some(42));
+        assert_eq!(status.reset.unwrap().timestamp(), 1608854400);
+
+        headers.insert("x-ratelimit-reset", "1608854400500".parse().unwrap());
+        let status = RateLimitStatus::from_headers(&headers).unwrap();
+        assert_eq!(status.reset.unwrap().timestamp_millis(), 1608854400500);
+        headers.insert("x-ratelimit-reset", "30".parse().unwrap());
+        let reset = RateLimitStatus::from_headers(&headers)
+            .unwrap()
+            .reset
+            .unwrap();
+        assert!(reset > Utc::now() && reset <= Utc::now() + chrono::Duration::seconds(30));
+
+        let tracker = RateLimitTracker::new();
+        tracker.update(&headers);
+        tracker.update(&HeaderMap::new());
+        assert_eq!(tracker.status().unwrap().remaining, Some(42));
+
+        assert_eq!(tracker.paused_for(), None);
+        tracker.pause(Duration::from_secs(30));
+        tracker.pause(Duration::from_secs(10));
+        assert!(tracker.paused_for().unwrap() > Duration::from_secs(20));
+    }
+    #[test]
+    fn test_content_length() {
+        let mut response = Response::new(Bytes::new());
+        assert_eq!(content_length(&response), None);
+        response
+            .headers_mut()
+            .insert(header::CONTENT_LENGTH, "1024".parse().unwrap());
+        assert_eq!(content_length(&response), Some(1024));
+    }
+
+    #[test]
+    fn test_map_key() {
+        assert_eq!(MapKey::from(0x2144).as_str(), "2144");
+        assert_eq!(MapKey::new("2144").unwrap().to_number(), Some(0x2144));
+
+        // Keys that don't fit the hex format are preserved
+        let key = MapKey::new("1xyz9").unwrap();
+        assert_eq!(key.as_str(), "1xyz9");
+        assert_eq!(key.to_number(), None);
+        assert_eq!(MapId::try_from("1xyz9"), Ok(MapId::Key(key.clone())));
+        assert_eq!(
+            download_url(&BEATSAVER_URL, &MapId::Key(key)).as_str(),
+            "https://api.beatsaver.com/download/key/1xyz9"
+        );
+
+        assert_eq!(MapKey::new(""), Err(MapIdError::InvalidKey));
+        assert_eq!(MapKey::new("21 44"), Err(MapIdError::InvalidKey));
+        assert_eq!(MapKey::new("../1"), Err(MapIdError::InvalidKey));
+    }
+    #[test]
+    fn test_map_id_display() {
+        for s in &["2144", "1xyz9", map_hash("1").as_str()] {
+            let id: MapId = s.parse().unwrap();
+            assert_eq!(id.to_string(), *s);
+        }
+        assert_eq!(MapKey::from(0x2144).to_string(), "2144");
+        assert!("21 44".parse::<MapKey>().is_err());
+
+        let ids: HashSet<MapId> = vec![MapId::from(1), "1".parse().unwrap(), MapId::from(2)]
+            .into_iter()
+            .collect();
+        assert_eq!(ids.len(), 2);
+    }
+    #[test]
+    fn test_one_click() {
+        assert_eq!(
+            MapId::from(0x2144).one_click_url().unwrap(),
+            "beatsaver://2144"
+        );
+        assert_eq!(MapId::Hash(map_hash("1")).one_click_url(), None);
+        assert_eq!(
+            MapId::from_one_click("beatsaver://2144").unwrap(),
+            MapId::from(0x2144)
+        );
+        assert_eq!(
+            MapId::from_one_click("BeatSaver://1a2b/").unwrap(),
+            MapId::from(0x1a2b)
+        );
+        assert_eq!(
+            MapId::from_one_click("https://beatsaver.com/maps/2144"),
+            Err(MapIdError::InvalidUrl)
+        );
+        assert!(MapId::from_one_click("beatsaver://").is_err());
+
+        let map: MapDetail = serde_json::from_str(map_json("2144").as_str()).unwrap();
+        assert_eq!(map.one_click_url(), "beatsaver://2144");
+    }
+
+    #[test]
+    fn test_map_id_url() {
+        for url in &[
+            "https://beatsaver.com/maps/2144",
+            "https://beatsaver.com/beatmap/2144/",
+            "https://bsaber.com/songs/2144/",
+            "https://www.bsaber.com/songs/2144/?tab=comments",
+            "https://api.beatsaver.com/maps/id/2144",
+            " beatsaver://2144 ",
+        ] {
+            assert_eq!(MapId::try_from(*url), Ok(MapId::from(0x2144)), "{}", url);
+        }
+        let url = format!("https://api.beatsaver.com/maps/hash/{}", map_hash("1"));
+        assert_eq!(
+            MapId::try_from(url.as_str()),
+            Ok(MapId::Hash(map_hash("1")))
+        );
+
+        for url in &[
+            "https://beatsaver.com/",
+            "https://beatsaver.com/profile/58338",
+            "https://notbeatsaver.com/maps/2144",
+            "https://example.com/maps/2144",
+        ] {
+            assert_eq!(
+                MapId::try_from(*url),
+                Err(MapIdError::InvalidUrl),
+                "{}",
+                url
+            );
+        }
+        assert!(MapId::try_from("https://beatsaver.com/maps/x_y").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_map_id_never_panics(s in "\\PC*") {
+            let _ = MapId::try_from(s.as_str());
+        }
+        #[test]
+        fn test_map_id_key(key in any::<usize>()) {
+            let id = MapId::try_from(format!("{:x}", key)).unwrap();
+            prop_assert_eq!(id, MapId::from(key));
+        }
+        #[test]
+        fn test_map_id_hash(hash in "[0-9a-fA-F]{40}") {
+            let id = MapId::try_from(hash.as_str()).unwrap();
+            prop_assert_eq!(id, MapId::Hash(hash));
+        }
+        #[test]
+        fn test_map_id_invalid(s in "[0-9a-z]{0,39}[^0-9a-zA-Z][0-9a-z]{0,39}") {
+            prop_assert!(MapId::try_from(s.as_str()).is_err());
+        }
+        #[test]
+        fn test_playlist_never_panics(s in "\\PC*") {
+            let _ = Playlist::read(s.as_bytes());
+            let _ = Playlist::from_data_url(s.as_str());
+        }
+        #[test]
+        fn test_playlist_roundtrip(
+            title in "\\PC*[a-z]\\PC*",
+            hashes in prop::collection::hash_set("[0-9a-f]{40}", 0..8),
+        ) {
+            let playlist = hashes
+                .iter()
+                .fold(Playlist::new(title.as_str()), |p, h| p.with_song(PlaylistSong::new(h)));
+            let mut data = vec![];
+            playlist.write(&mut data).unwrap();
+            prop_assert_eq!(Playlist::read(data.as_slice()).unwrap(), playlist);
+        }
+        #[cfg(feature = "hash")]
+        #[test]
+        fn test_info_dat_never_panics(info in any::<Vec<u8>>()) {
+            let _ = crate::hash::hash_map_zip(zip_archive(&[("Info.dat", info.as_slice())]));
+        }
+        #[cfg(feature = "hash")]
+        #[test]
+        fn test_info_dat_hash(diffs in prop::collection::vec(any::<Vec<u8>>(), 0..4)) {
+            use sha1::{Digest, Sha1};
+
+            let names: Vec<String> = (0..diffs.len()).map(|i| format!("{}.dat", i)).collect();
+            let beatmaps: Vec<String> = names
+                .iter()
+                .map(|n| format!(r#"{{"_beatmapFilename":"{}"}}"#, n))
+                .collect();
+            let info = format!(
+                r#"{{"_difficultyBeatmapSets":[{{"_difficultyBeatmaps":[{}]}}]}}"#,
+                beatmaps.join(",")
+            );
+            let mut files = vec![("Info.dat", info.as_bytes())];
+            files.extend(names.iter().map(|n| n.as_str()).zip(diffs.iter().map(|d| d.as_slice())));
+
+            let mut hasher = Sha1::new();
+            hasher.update(info.as_bytes());
+            for diff in diffs.iter() {
+                hasher.update(diff);
+            }
+            let hash = crate::hash::hash_map_zip(zip_archive(&files)).unwrap();
+            prop_assert_eq!(hash, hex::encode(hasher.finalize()));
+        }
+        #[test]
+        fn test_ws_event_never_panics(s in "\\PC*") {
+            let _ = crate::ws::decode_event(s.as_str());
+        }
     }
 }
@@ -0,0 +1,128 @@
+//! # Library
+//!
+//! This module scans the maps installed in a `CustomLevels` folder and reconciles them against
+//! BeatSaver, to find maps that are unknown, were deleted upstream, or have newer versions.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaver;
+//! use beatsaver_rs::BeatSaverApiAsync;
+//! use std::path::Path;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BeatSaver::new();
+//!     let library = client
+//!         .reconcile_library(Path::new("CustomLevels"))
+//!         .await
+//!         .unwrap();
+//!     for (local, map) in library.outdated {
+//!         println!("{} has a newer version ({})", local.path.display(), map.id);
+//!     }
+//! }
+//! # }
+//! ```
+use crate::install::installed_hashes;
+use crate::model::map::MapDetail;
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Map installed in a `CustomLevels` folder
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalMap {
+    /// Folder containing the map
+    pub path: PathBuf,
+    /// Hash of the installed version, as lowercase hex
+    pub hash: String,
+}
+
+/// Scans a `CustomLevels` folder, computing the hash of every installed map
+///
+/// Folders that don't contain a valid map are skipped. Maps are sorted by path.
+pub fn scan(custom_levels: &Path) -> io::Result<Vec<LocalMap>> {
+    let mut maps: Vec<LocalMap> = installed_hashes(custom_levels)?
+        .into_iter()
+        .map(|(hash, path)| LocalMap { path, hash })
+        .collect();
+    maps.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(maps)
+}
+
+/// Installed maps grouped by their state on BeatSaver
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Reconciliation {
+    /// Maps whose installed version is the latest version
+    pub up_to_date: Vec<(LocalMap, MapDetail)>,
+    /// Maps that have a newer version than the installed one
+    pub outdated: Vec<(LocalMap, MapDetail)>,
+    /// Maps that were deleted from BeatSaver
+    pub deleted: Vec<(LocalMap, MapDetail)>,
+    /// Maps that BeatSaver doesn't know about (e.g. WIPs or maps from other sources)
+    pub unknown: Vec<LocalMap>,
+}
+
+/// Groups installed maps using the maps BeatSaver returned for their hashes
+///
+/// `remote` is keyed by lowercase hash, as returned by `maps_by_hashes`.
+pub fn reconcile(local: Vec<LocalMap>, remote: &HashMap<String, MapDetail>) -> Reconciliation {
+    let mut reconciliation = Reconciliation::default();
+    for map in local {
+        let detail = match remote.get(&map.hash) {
+            Some(d) => d.clone(),
+            None => {
+                reconciliation.unknown.push(map);
+                continue;
+            }
+        };
+        let latest = detail.hash().map(str::to_lowercase);
+        if detail.deleted_at.is_some() {
+            reconciliation.deleted.push((map, detail));
+        } else if latest.as_deref() == Some(map.hash.as_str()) {
+            reconciliation.up_to_date.push((map, detail));
+        } else {
+            reconciliation.outdated.push((map, detail));
+        }
+    }
+    reconciliation
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::library::{reconcile, LocalMap};
+    use crate::model::map::MapDetail;
+    use crate::tests::{map_hash, map_json};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn local(key: &str) -> LocalMap {
+        LocalMap {
+            path: PathBuf::from(key),
+            hash: map_hash(key),
+        }
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let mut remote = HashMap::new();
+        let map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        remote.insert(map_hash("1"), map.clone());
+        // Installed version "2" is an older version of map "1"
+        remote.insert(map_hash("2"), map.clone());
+        let mut deleted = map;
+        deleted.deleted_at = Some("2022-01-01T00:00:00Z".parse().unwrap());
+        remote.insert(map_hash("3"), deleted);
+
+        let result = reconcile(
+            vec![local("1"), local("2"), local("3"), local("4")],
+            &remote,
+        );
+        assert_eq!(result.up_to_date.len(), 1);
+        assert_eq!(result.up_to_date[0].0, local("1"));
+        assert_eq!(result.outdated[0].0, local("2"));
+        assert_eq!(result.deleted[0].0, local("3"));
+        assert_eq!(result.unknown, vec![local("4")]);
+    }
+}
@@ -0,0 +1,318 @@
+//! # Lucene
+//!
+//! This module contains a small builder for the [Apache Lucene](https://lucene.apache.org/core/2_9_4/queryparsersyntax.html)
+//! queries accepted by the advanced search, along with a validator that rejects malformed queries
+//! before they are sent to the API.
+//!
+//! Example:
+//! ```
+//! use beatsaver_rs::lucene::{field, term};
+//!
+//! let query = field("songName")
+//!     .contains("shut up")
+//!     .and(field("levelAuthorName").is("bennydabeast"))
+//!     .and(!term("remix"));
+//! assert_eq!(
+//!     query.to_string(),
+//!     r#"((songName:"shut up" AND levelAuthorName:"bennydabeast") AND NOT remix)"#
+//! );
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::ops;
+
+/// Characters that have a special meaning in Lucene and have to be escaped in terms
+const SPECIAL_CHARS: &[char] = &[
+    '+', '-', '&', '|', '!', '(', ')', '{', '}', '[', ']', '^', '"', '~', '*', '?', ':', '\\', '/',
+];
+
+fn quote(value: &str) -> String {
+    // Phrases only need quotes and backslashes escaped
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{}\"", escaped)
+}
+
+fn escape(value: &str) -> String {
+    if value.chars().any(char::is_whitespace) {
+        return quote(value);
+    }
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if SPECIAL_CHARS.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Lucene query, built using [field][crate::lucene::field] and [term][crate::lucene::term]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query(String);
+impl Query {
+    /// Matches documents matching both queries
+    pub fn and(self, other: Query) -> Query {
+        Query(format!("({} AND {})", self.0, other.0))
+    }
+    /// Matches documents matching either query
+    pub fn or(self, other: Query) -> Query {
+        Query(format!("({} OR {})", self.0, other.0))
+    }
+    /// Returns the query as a string
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+impl ops::Not for Query {
+    type Output = Query;
+
+    /// Matches documents not matching the query
+    fn not(self) -> Query {
+        Query(format!("NOT {}", self.0))
+    }
+}
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0.as_str())
+    }
+}
+impl From<Query> for String {
+    fn from(q: Query) -> Self {
+        q.0
+    }
+}
+
+/// Field of a document, created with [field][crate::lucene::field]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field(String);
+impl Field {
+    /// Matches documents where the field contains the value
+    ///
+    /// Values containing whitespace are searched as a phrase.
+    pub fn contains<S: AsRef<str>>(self, value: S) -> Query {
+        Query(format!("{}:{}", self.0, escape(value.as_ref())))
+    }
+    /// Matches documents where the field contains the value as an exact phrase
+    pub fn is<S: AsRef<str>>(self, value: S) -> Query {
+        Query(format!("{}:{}", self.0, quote(value.as_ref())))
+    }
+    /// Matches documents where the field is between `from` and `to` (inclusive)
+    pub fn range<F: fmt::Display, T: fmt::Display>(self, from: F, to: T) -> Query {
+        Query(format!("{}:[{} TO {}]", self.0, from, to))
+    }
+}
+
+/// Creates a [Field][crate::lucene::Field] used to build field queries (e.g. `songName`)
+pub fn field<S: Into<String>>(name: S) -> Field {
+    Field(name.into())
+}
+
+/// Creates a [Query][crate::lucene::Query] matching the value in any field
+pub fn term<S: AsRef<str>>(value: S) -> Query {
+    Query(escape(value.as_ref()))
+}
+
+/// Error type for validating a Lucene query
+#[derive(Debug, Clone, PartialEq)]
+pub enum LuceneError {
+    /// Query doesn't contain any terms
+    Empty,
+    /// Query contains a `(` without a matching `)` or vice versa
+    UnbalancedParentheses,
+    /// Query contains a range (`[`/`{`) that isn't closed or vice versa
+    UnbalancedRange,
+    /// Query contains a `"` without a closing `"`
+    UnterminatedQuote,
+    /// Query ends with a `\`
+    DanglingEscape,
+    /// Operator is missing an operand (e.g. `AND` at the start of the query)
+    MissingOperand(String),
+}
+impl fmt::Display for LuceneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Query is empty"),
+            Self::UnbalancedParentheses => write!(f, "Query contains unbalanced parentheses"),
+            Self::UnbalancedRange => write!(f, "Query contains an unbalanced range"),
+            Self::UnterminatedQuote => write!(f, "Query contains an unterminated quote"),
+            Self::DanglingEscape => write!(f, "Query ends with an escape character"),
+            Self::MissingOperand(o) => write!(f, "Operator {} is missing an operand", o),
+        }
+    }
+}
+impl Error for LuceneError {}
+
+fn is_binary(token: &str) -> bool {
+    matches!(token, "AND" | "OR" | "&&" | "||")
+}
+fn is_unary(token: &str) -> bool {
+    matches!(token, "NOT" | "!")
+}
+
+/// Splits a query into terms, operators and parentheses, checking quotes, ranges and escapes
+fn tokenize(query: &str) -> Result<Vec<String>, LuceneError> {
+    let mut tokens = vec![];
+    let mut current = String::new();
+    let mut chars = query.chars();
+    let mut quoted = false;
+    let mut range = 0usize;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                current.push(c);
+                current.push(chars.next().ok_or(LuceneError::DanglingEscape)?);
+            }
+            '"' => {
+                quoted = !quoted;
+                current.push(c);
+            }
+            _ if quoted => current.push(c),
+            '[' | '{' => {
+                range += 1;
+                current.push(c);
+            }
+            ']' | '}' => {
+                range = range.checked_sub(1).ok_or(LuceneError::UnbalancedRange)?;
+                current.push(c);
+            }
+            _ if range > 0 => current.push(c),
+            '(' | ')' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if quoted {
+        return Err(LuceneError::UnterminatedQuote);
+    }
+    if range > 0 {
+        return Err(LuceneError::UnbalancedRange);
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+/// Checks that a query is valid Lucene syntax
+///
+/// Example:
+/// ```
+/// use beatsaver_rs::lucene::{validate, LuceneError};
+///
+/// assert!(validate("songName:test AND NOT remix").is_ok());
+/// assert_eq!(validate("(songName:test"), Err(LuceneError::UnbalancedParentheses));
+/// ```
+pub fn validate(query: &str) -> Result<(), LuceneError> {
+    let tokens = tokenize(query)?;
+    let mut depth = 0usize;
+    // Whether the previous token requires an operand to follow it
+    let mut expecting = true;
+    let mut last_operator = None;
+    let mut terms = 0;
+    for token in tokens.iter().map(String::as_str) {
+        match token {
+            "(" => {
+                depth += 1;
+                expecting = true;
+            }
+            ")" => {
+                if expecting {
+                    if let Some(o) = last_operator {
+                        return Err(LuceneError::MissingOperand(String::from(o)));
+                    }
+                }
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(LuceneError::UnbalancedParentheses)?;
+                expecting = false;
+            }
+            t if is_binary(t) => {
+                if expecting {
+                    return Err(LuceneError::MissingOperand(t.into()));
+                }
+                expecting = true;
+                last_operator = Some(t);
+            }
+            t if is_unary(t) => {
+                expecting = true;
+                last_operator = Some(t);
+            }
+            _ => {
+                terms += 1;
+                expecting = false;
+                last_operator = None;
+            }
+        }
+    }
+    if depth > 0 {
+        return Err(LuceneError::UnbalancedParentheses);
+    }
+    if let (true, Some(o)) = (expecting, last_operator) {
+        return Err(LuceneError::MissingOperand(o.into()));
+    }
+    if terms == 0 {
+        return Err(LuceneError::Empty);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lucene::{field, term, validate, LuceneError};
+
+    #[test]
+    fn test_builder() {
+        assert_eq!(field("songName").contains("a:b").as_str(), r"songName:a\:b");
+        assert_eq!(
+            field("songName").contains(r#"say "hi""#).as_str(),
+            r#"songName:"say \"hi\"""#
+        );
+        assert_eq!(
+            field("bpm").range(120, 140).or(term("fast")).as_str(),
+            "(bpm:[120 TO 140] OR fast)"
+        );
+        let query = field("songName").contains("x").and(!term("y"));
+        assert_eq!(validate(query.as_str()), Ok(()));
+    }
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("test"), Ok(()));
+        assert_eq!(validate(r#"songName:"a (b" AND bpm:[1 TO 2]"#), Ok(()));
+        assert_eq!(validate(r"a\(b"), Ok(()));
+        assert_eq!(validate(""), Err(LuceneError::Empty));
+        assert_eq!(validate("()"), Err(LuceneError::Empty));
+        assert_eq!(validate("(a"), Err(LuceneError::UnbalancedParentheses));
+        assert_eq!(validate("a)"), Err(LuceneError::UnbalancedParentheses));
+        assert_eq!(validate("bpm:[1 TO 2"), Err(LuceneError::UnbalancedRange));
+        assert_eq!(
+            validate(r#"songName:"a"#),
+            Err(LuceneError::UnterminatedQuote)
+        );
+        assert_eq!(validate(r"a\"), Err(LuceneError::DanglingEscape));
+        assert_eq!(
+            validate("AND a"),
+            Err(LuceneError::MissingOperand("AND".into()))
+        );
+        assert_eq!(
+            validate("a OR"),
+            Err(LuceneError::MissingOperand("OR".into()))
+        );
+        assert_eq!(
+            validate("a AND NOT"),
+            Err(LuceneError::MissingOperand("NOT".into()))
+        );
+        assert_eq!(
+            validate("(a AND) b"),
+            Err(LuceneError::MissingOperand("AND".into()))
+        );
+    }
+}
@@ -0,0 +1,1134 @@
+//! # Mirror
+//!
+//! This module keeps a local copy of BeatSaver maps and their uploaders in a SQLite database, e.g.
+//! to serve searches without hitting the API or to analyze the catalog offline.
+//!
+//! Maps, their versions and users are stored in their own tables. Databases store the version of
+//! their schema, and are migrated to the current one when opened.
+//!
+//! The whole catalog is retrieved with [backfill][crate::mirror::Mirror::backfill], which records
+//! a [Checkpoint][crate::mirror::Checkpoint] as it goes so an interrupted backfill resumes where it
+//! stopped. Long running mirrors can then apply the changes since their last run with
+//! [sync_since][crate::mirror::Mirror::sync_since].
+//!
+//! Maps deleted upstream are kept, with their `deleted_at` time set. Deletions are detected by
+//! [refresh][crate::mirror::Mirror::refresh], and by applying websocket events with
+//! [apply_event][crate::mirror::Mirror::apply_event].
+//!
+//! Mirrored maps can be [exported][crate::mirror::Mirror::export] as JSON lines or CSV for data
+//! analysis tools and spreadsheets.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaver;
+//! use beatsaver_rs::mirror::Mirror;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BeatSaver::new();
+//!     let mut mirror = Mirror::open("mirror.db").unwrap();
+//!     let report = mirror.sync(&client).await.unwrap();
+//!     println!("{} maps added, {} updated", report.added, report.updated);
+//! }
+//! # }
+//! ```
+use crate::model::map::MapDetail;
+use crate::model::user::UserDetail;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::model::vote::VoteSummary;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::query::{LatestOptions, LatestSort};
+use crate::ws::MapEvent;
+#[cfg(feature = "async")]
+use crate::BeatSaverApiAsync;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::BeatSaverApiError;
+#[cfg(feature = "sync")]
+use crate::BeatSaverApiSync;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::MapId;
+use chrono::{DateTime, TimeZone, Utc};
+#[cfg(feature = "async")]
+use futures::StreamExt;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+#[cfg(any(feature = "async", feature = "sync"))]
+use std::collections::VecDeque;
+#[cfg(any(feature = "async", feature = "sync"))]
+use std::error::Error;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+#[cfg(any(feature = "async", feature = "sync"))]
+use std::time::UNIX_EPOCH;
+use thiserror::Error;
+
+/// Version of the schema of the databases written by [Mirror][crate::mirror::Mirror]
+pub const SCHEMA_VERSION: usize = MIGRATIONS.len();
+
+/// Migrations of databases to the following schema version, starting at version 0 (empty)
+const MIGRATIONS: &[&str] = &[
+    // 1: maps, their versions and users
+    "CREATE TABLE users (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE maps (
+        key TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        uploader_id INTEGER NOT NULL REFERENCES users (id),
+        updated_at INTEGER NOT NULL,
+        deleted_at INTEGER,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX maps_updated_at ON maps (updated_at, key);
+    CREATE TABLE versions (
+        hash TEXT PRIMARY KEY,
+        map_key TEXT NOT NULL REFERENCES maps (key) ON DELETE CASCADE,
+        created_at INTEGER NOT NULL
+    );
+    CREATE INDEX versions_map_key ON versions (map_key);",
+    // 2: the backfill checkpoint
+    "CREATE TABLE checkpoint (
+        id INTEGER PRIMARY KEY CHECK (id = 0),
+        updated_at INTEGER NOT NULL,
+        key TEXT NOT NULL
+    );",
+];
+
+/// Error returned when reading or writing a [Mirror][crate::mirror::Mirror]
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum MirrorError {
+    /// Error originated from SQLite
+    #[error(transparent)]
+    DatabaseError(#[from] rusqlite::Error),
+    /// A stored model couldn't be serialized or deserialized
+    #[error(transparent)]
+    SerializeError(#[from] serde_json::Error),
+    /// Error in IO while exporting the mirror
+    #[error(transparent)]
+    IoError(#[from] io::Error),
+    /// Database was written by a newer version of the library
+    #[error("Unsupported mirror schema version {0}")]
+    UnsupportedVersion(usize),
+}
+
+/// Progress of a [backfill][crate::mirror::Mirror::backfill]
+///
+/// Maps are retrieved oldest first by update time, the checkpoint is the last map of the last
+/// page written.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Update time of the last map retrieved, the backfill resumes from this time
+    pub updated_at: DateTime<Utc>,
+    /// Key of the last map retrieved
+    pub key: String,
+}
+
+/// Format of an [export][crate::mirror::Mirror::export]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One [MapDetail][crate::model::map::MapDetail] JSON document per line
+    JsonLines,
+    /// Comma separated values with a header row, containing the metadata of the latest version of
+    /// every map
+    Csv,
+}
+
+/// Columns of CSV exports
+const CSV_HEADER: &[&str] = &[
+    "key",
+    "hash",
+    "name",
+    "song_name",
+    "song_sub_name",
+    "song_author_name",
+    "level_author_name",
+    "uploader_id",
+    "uploader_name",
+    "bpm",
+    "duration",
+    "plays",
+    "downloads",
+    "upvotes",
+    "downvotes",
+    "score",
+    "tags",
+    "automapper",
+    "ranked",
+    "qualified",
+    "uploaded",
+    "updated_at",
+];
+
+/// Number of maps changed by a sync
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Maps that weren't mirrored yet
+    pub added: usize,
+    /// Mirrored maps that were updated upstream
+    pub updated: usize,
+    /// Mirrored maps whose vote totals changed
+    pub votes: usize,
+    /// Mirrored maps that were deleted upstream
+    pub deleted: usize,
+}
+
+/// Local copy of BeatSaver maps and users, stored in SQLite
+#[derive(Debug)]
+pub struct Mirror {
+    path: Option<PathBuf>,
+    conn: Connection,
+}
+impl Mirror {
+    /// Creates a new, empty [Mirror][crate::mirror::Mirror] that is only kept in memory
+    pub fn new() -> Result<Self, MirrorError> {
+        Self::from_connection(Connection::open_in_memory()?, None)
+    }
+    /// Opens the [Mirror][crate::mirror::Mirror] stored in the database at the provided path,
+    /// creating the database if it doesn't exist
+    ///
+    /// Fails with [UnsupportedVersion][crate::mirror::MirrorError::UnsupportedVersion] if the
+    /// database was written by a newer version of the library.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self, MirrorError> {
+        let path = path.into();
+        Self::from_connection(Connection::open(&path)?, Some(path))
+    }
+    fn from_connection(mut conn: Connection, path: Option<PathBuf>) -> Result<Self, MirrorError> {
+        conn.pragma_update(None, "foreign_keys", true)?;
+        migrate(&mut conn)?;
+        Ok(Self { path, conn })
+    }
+    /// Path of the database, `None` if the mirror is only kept in memory
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+    /// Number of mirrored maps
+    pub fn len(&self) -> Result<usize, MirrorError> {
+        Ok(self
+            .conn
+            .query_row("SELECT COUNT(*) FROM maps", [], |row| row.get(0))?)
+    }
+    /// Returns `true` if no maps are mirrored
+    pub fn is_empty(&self) -> Result<bool, MirrorError> {
+        Ok(self.len()? == 0)
+    }
+    /// Returns the mirrored map with the specified key
+    pub fn map(&self, key: &str) -> Result<Option<MapDetail>, MirrorError> {
+        get_map(&self.conn, key)
+    }
+    /// Returns the mirrored map that has a version with the specified hash
+    pub fn map_by_hash(&self, hash: &str) -> Result<Option<MapDetail>, MirrorError> {
+        let data: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT maps.data FROM versions JOIN maps ON maps.key = versions.map_key
+                WHERE versions.hash = ?1",
+                [hash.to_lowercase()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(data.map(|d| serde_json::from_str(&d)).transpose()?)
+    }
+    /// Returns the mirrored user with the specified ID
+    pub fn user(&self, id: usize) -> Result<Option<UserDetail>, MirrorError> {
+        let data: Option<String> = self
+            .conn
+            .query_row("SELECT data FROM users WHERE id = ?1", [id], |row| {
+                row.get(0)
+            })
+            .optional()?;
+        Ok(data.map(|d| serde_json::from_str(&d)).transpose()?)
+    }
+    /// Returns the mirrored maps, ordered by key
+    pub fn maps(&self) -> Result<Vec<MapDetail>, MirrorError> {
+        let mut maps = vec![];
+        self.for_each_map(|map| {
+            maps.push(map);
+            Ok(())
+        })?;
+        Ok(maps)
+    }
+    /// Returns the progress of the last backfill, `None` if no backfill was started
+    pub fn checkpoint(&self) -> Result<Option<Checkpoint>, MirrorError> {
+        let checkpoint = self
+            .conn
+            .query_row(
+                "SELECT updated_at, key FROM checkpoint WHERE id = 0",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        Ok(checkpoint.map(|(updated_at, key)| Checkpoint {
+            updated_at: from_millis(updated_at),
+            key,
+        }))
+    }
+    /// Returns the mirrored users, ordered by ID
+    pub fn users(&self) -> Result<Vec<UserDetail>, MirrorError> {
+        let mut statement = self.conn.prepare("SELECT data FROM users ORDER BY id")?;
+        let mut rows = statement.query([])?;
+        let mut users = vec![];
+        while let Some(row) = rows.next()? {
+            users.push(serde_json::from_str(&row.get::<_, String>(0)?)?);
+        }
+        Ok(users)
+    }
+    /// Writes every mirrored map to the writer in the specified format, ordered by key
+    ///
+    /// Returns the number of maps written.
+    pub fn export<W: Write>(
+        &self,
+        format: ExportFormat,
+        mut writer: W,
+    ) -> Result<usize, MirrorError> {
+        if format == ExportFormat::Csv {
+            write_csv_row(&mut writer, CSV_HEADER.iter().map(|c| c.to_string()))?;
+        }
+        let mut count = 0;
+        self.for_each_map(|map| {
+            match format {
+                ExportFormat::JsonLines => {
+                    serde_json::to_writer(&mut writer, &map)?;
+                    writer.write_all(b"\n")?;
+                }
+                ExportFormat::Csv => write_csv_row(&mut writer, csv_row(&map))?,
+            }
+            count += 1;
+            Ok(())
+        })?;
+        writer.flush()?;
+        Ok(count)
+    }
+    /// Calls `f` with every mirrored map, ordered by key, without loading them all at once
+    fn for_each_map<F>(&self, mut f: F) -> Result<(), MirrorError>
+    where
+        F: FnMut(MapDetail) -> Result<(), MirrorError>,
+    {
+        let mut statement = self.conn.prepare("SELECT data FROM maps ORDER BY key")?;
+        let mut rows = statement.query([])?;
+        while let Some(row) = rows.next()? {
+            f(serde_json::from_str(&row.get::<_, String>(0)?)?)?;
+        }
+        Ok(())
+    }
+    /// Adds a map to the mirror, replacing the previous copy of the map, along with its uploader
+    /// and curator
+    pub fn insert_map(&mut self, map: MapDetail) -> Result<Option<MapDetail>, MirrorError> {
+        let tx = self.conn.transaction()?;
+        let previous = get_map(&tx, map.id.as_str())?;
+        put_map(&tx, &map)?;
+        tx.commit()?;
+        Ok(previous)
+    }
+    /// Removes the map with the specified key from the mirror, along with its versions
+    pub fn remove_map(&mut self, key: &str) -> Result<Option<MapDetail>, MirrorError> {
+        let tx = self.conn.transaction()?;
+        let map = get_map(&tx, key)?;
+        tx.execute("DELETE FROM maps WHERE key = ?1", [key])?;
+        tx.commit()?;
+        Ok(map)
+    }
+    /// Marks the mirrored map with the specified key as deleted upstream at the provided time
+    ///
+    /// Returns `false` if the map isn't mirrored or is already marked as deleted.
+    pub fn mark_deleted(&mut self, key: &str, at: DateTime<Utc>) -> Result<bool, MirrorError> {
+        let tx = self.conn.transaction()?;
+        let mut map = match get_map(&tx, key)? {
+            Some(map) if map.deleted_at.is_none() => map,
+            _ => return Ok(false),
+        };
+        map.deleted_at = Some(at);
+        put_map(&tx, &map)?;
+        tx.commit()?;
+        Ok(true)
+    }
+    /// Applies an event received from the [websocket][crate::ws], returns `true` if the mirror
+    /// changed
+    ///
+    /// Created and updated maps are added, deleted maps are marked as deleted.
+    pub fn apply_event(&mut self, event: &MapEvent) -> Result<bool, MirrorError> {
+        match event {
+            MapEvent::Create(map) | MapEvent::Update(map) => {
+                self.apply(map.clone(), &mut SyncReport::default())
+            }
+            MapEvent::Delete(key) => self.mark_deleted(key.as_str(), Utc::now()),
+            MapEvent::Unknown(_, _) => Ok(false),
+        }
+    }
+    /// Keys of the mirrored maps that aren't marked as deleted
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn live_keys(&self) -> Result<Vec<String>, MirrorError> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT key FROM maps WHERE deleted_at IS NULL ORDER BY key")?;
+        let keys = statement.query_map([], |row| row.get(0))?;
+        Ok(keys.collect::<Result<_, _>>()?)
+    }
+    /// Applies the result of a request for a mirrored map, missing maps are marked as deleted
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn apply_refresh<T: Error>(
+        &mut self,
+        key: &str,
+        map: Result<MapDetail, BeatSaverApiError<T>>,
+        report: &mut SyncReport,
+    ) -> Result<(), BeatSaverApiError<T>> {
+        match map {
+            Ok(map) => {
+                self.apply(map, report)?;
+            }
+            Err(BeatSaverApiError::NotFound(_)) => {
+                if self.mark_deleted(key, Utc::now())? {
+                    report.deleted += 1;
+                }
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+    /// Adds a map retrieved from the API, returns `false` if the mirror already has this copy of
+    /// the map
+    fn apply(&mut self, map: MapDetail, report: &mut SyncReport) -> Result<bool, MirrorError> {
+        let tx = self.conn.transaction()?;
+        let applied = apply_map(&tx, map, report)?;
+        tx.commit()?;
+        Ok(applied)
+    }
+    /// Retrieves the maps updated since the last sync
+    ///
+    /// Maps are retrieved newest first, until a map that is already mirrored is found.
+    #[cfg(feature = "async")]
+    pub async fn sync<T, C>(&mut self, client: &C) -> Result<SyncReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiAsync<T> + Send + Sync,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut report = SyncReport::default();
+        let mut maps = client.maps_latest(latest_updated());
+        while let Some(map) = maps.next().await {
+            if !self.apply(map?, &mut report)? {
+                break;
+            }
+        }
+        Ok(report)
+    }
+    /// Retrieves the maps updated since the last sync
+    ///
+    /// Maps are retrieved newest first, until a map that is already mirrored is found.
+    #[cfg(feature = "sync")]
+    pub fn sync_blocking<T, C>(&mut self, client: &C) -> Result<SyncReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiSync<T>,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut report = SyncReport::default();
+        for map in client.maps_latest(latest_updated()) {
+            if !self.apply(map?, &mut report)? {
+                break;
+            }
+        }
+        Ok(report)
+    }
+    /// Retrieves every map updated after the [checkpoint][crate::mirror::Mirror::checkpoint],
+    /// or the whole catalog if no backfill was started
+    ///
+    /// Every page is written in a single transaction along with the checkpoint, so interrupting
+    /// the backfill loses at most the page being written. Once the backfill is complete, calling
+    /// it again retrieves the maps updated since.
+    #[cfg(feature = "async")]
+    pub async fn backfill<T, C>(&mut self, client: &C) -> Result<SyncReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiAsync<T> + Send + Sync,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut report = SyncReport::default();
+        let mut pages = client.maps_latest_pages(self.backfill_options()?);
+        while let Some(page) = pages.next().await {
+            self.apply_page(page?.docs, &mut report)?;
+        }
+        Ok(report)
+    }
+    /// Retrieves every map updated after the [checkpoint][crate::mirror::Mirror::checkpoint],
+    /// or the whole catalog if no backfill was started
+    ///
+    /// Every page is written in a single transaction along with the checkpoint, so interrupting
+    /// the backfill loses at most the page being written. Once the backfill is complete, calling
+    /// it again retrieves the maps updated since.
+    #[cfg(feature = "sync")]
+    pub fn backfill_blocking<T, C>(
+        &mut self,
+        client: &C,
+    ) -> Result<SyncReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiSync<T>,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut report = SyncReport::default();
+        for page in client.maps_latest_pages(self.backfill_options()?) {
+            self.apply_page(page?.docs, &mut report)?;
+        }
+        Ok(report)
+    }
+    /// Retrieves the maps updated since the provided time (e.g. the time of the previous sync),
+    /// along with the vote totals of mirrored maps
+    ///
+    /// Only the changes since are retrieved: maps are followed with the `after` cursor, oldest
+    /// first, and votes come from the vote endpoint.
+    #[cfg(feature = "async")]
+    pub async fn sync_since<T, C>(
+        &mut self,
+        client: &C,
+        since: DateTime<Utc>,
+    ) -> Result<SyncReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiAsync<T> + Send + Sync,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut report = SyncReport::default();
+        let mut maps = client.maps_latest(latest_updated().with_after(since));
+        while let Some(map) = maps.next().await {
+            self.apply(map?, &mut report)?;
+        }
+        let mut votes = client.votes_since(since);
+        while let Some(vote) = votes.next().await {
+            let (id, votes) = vote?;
+            self.apply_votes(&id, &votes, &mut report)?;
+        }
+        Ok(report)
+    }
+    /// Retrieves the maps updated since the provided time (e.g. the time of the previous sync),
+    /// along with the vote totals of mirrored maps
+    ///
+    /// Only the changes since are retrieved: maps are followed with the `after` cursor, oldest
+    /// first, and votes come from the vote endpoint.
+    #[cfg(feature = "sync")]
+    pub fn sync_since_blocking<T, C>(
+        &mut self,
+        client: &C,
+        since: DateTime<Utc>,
+    ) -> Result<SyncReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiSync<T>,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut report = SyncReport::default();
+        for map in client.maps_latest(latest_updated().with_after(since)) {
+            self.apply(map?, &mut report)?;
+        }
+        for (id, votes) in client.votes_since(since)? {
+            self.apply_votes(&id, &votes, &mut report)?;
+        }
+        Ok(report)
+    }
+    /// Requests every mirrored map again
+    ///
+    /// Maps that no longer exist upstream are marked as deleted. Maps already marked as deleted
+    /// are skipped.
+    #[cfg(feature = "async")]
+    pub async fn refresh<T, C>(&mut self, client: &C) -> Result<SyncReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiAsync<T> + Send + Sync,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut report = SyncReport::default();
+        for key in self.live_keys()? {
+            let map = client.map_by_key(key.as_str()).await;
+            self.apply_refresh(key.as_str(), map, &mut report)?;
+        }
+        Ok(report)
+    }
+    /// Requests every mirrored map again
+    ///
+    /// Maps that no longer exist upstream are marked as deleted. Maps already marked as deleted
+    /// are skipped.
+    #[cfg(feature = "sync")]
+    pub fn refresh_blocking<T, C>(&mut self, client: &C) -> Result<SyncReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiSync<T>,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut report = SyncReport::default();
+        for key in self.live_keys()? {
+            let map = client.map_by_key(key.as_str());
+            self.apply_refresh(key.as_str(), map, &mut report)?;
+        }
+        Ok(report)
+    }
+    /// Options retrieving the maps updated since the checkpoint, oldest first
+    ///
+    /// The `after` cursor is exclusive, so the maps updated at the same time as the checkpoint
+    /// are retrieved again: other maps may share its timestamp without having been retrieved yet.
+    /// The ones already mirrored are skipped by [apply_map].
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn backfill_options(&self) -> Result<LatestOptions, MirrorError> {
+        let after = match self.checkpoint()? {
+            Some(checkpoint) => checkpoint.updated_at - chrono::Duration::milliseconds(1),
+            None => UNIX_EPOCH.into(),
+        };
+        Ok(latest_updated().with_after(after))
+    }
+    /// Updates the vote totals of a mirrored map
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn apply_votes(
+        &mut self,
+        id: &MapId,
+        votes: &VoteSummary,
+        report: &mut SyncReport,
+    ) -> Result<(), MirrorError> {
+        let mut map = match self.map(id.to_string().as_str())? {
+            Some(map) => map,
+            None => return Ok(()),
+        };
+        let stats = &mut map.stats;
+        if (stats.upvotes, stats.downvotes) != (votes.upvotes, votes.downvotes) {
+            stats.upvotes = votes.upvotes;
+            stats.downvotes = votes.downvotes;
+            stats.score = votes.score;
+            put_map(&self.conn, &map)?;
+            report.votes += 1;
+        }
+        Ok(())
+    }
+    /// Adds a page of a backfill and moves the checkpoint to its last map, in a single
+    /// transaction
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn apply_page(
+        &mut self,
+        maps: VecDeque<MapDetail>,
+        report: &mut SyncReport,
+    ) -> Result<(), MirrorError> {
+        let checkpoint = match maps.back() {
+            Some(last) => (last.updated_at.timestamp_millis(), last.id.clone()),
+            None => return Ok(()),
+        };
+        let tx = self.conn.transaction()?;
+        for map in maps {
+            apply_map(&tx, map, report)?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO checkpoint (id, updated_at, key) VALUES (0, ?1, ?2)",
+            params![checkpoint.0, checkpoint.1],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+/// Stores a map retrieved from the API, returns `false` if the stored copy is as recent
+fn apply_map(
+    conn: &Connection,
+    map: MapDetail,
+    report: &mut SyncReport,
+) -> Result<bool, MirrorError> {
+    let updated_at: Option<i64> = conn
+        .query_row(
+            "SELECT updated_at FROM maps WHERE key = ?1",
+            [map.id.as_str()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    match updated_at {
+        Some(t) if t >= map.updated_at.timestamp_millis() => return Ok(false),
+        Some(_) => report.updated += 1,
+        None => report.added += 1,
+    }
+    put_map(conn, &map)?;
+    Ok(true)
+}
+
+/// Returns the stored copy of the map with the specified key
+fn get_map(conn: &Connection, key: &str) -> Result<Option<MapDetail>, MirrorError> {
+    let data: Option<String> = conn
+        .query_row("SELECT data FROM maps WHERE key = ?1", [key], |row| {
+            row.get(0)
+        })
+        .optional()?;
+    Ok(data.map(|d| serde_json::from_str(&d)).transpose()?)
+}
+
+/// Stores a map, replacing its previous copy and versions, along with its uploader and curator
+fn put_map(conn: &Connection, map: &MapDetail) -> Result<(), MirrorError> {
+    for user in std::iter::once(&map.uploader).chain(map.curator.as_ref()) {
+        conn.execute(
+            "INSERT INTO users (id, name, data) VALUES (?1, ?2, ?3)
+            ON CONFLICT (id) DO UPDATE SET name = excluded.name, data = excluded.data",
+            params![user.id, user.name, serde_json::to_string(user)?],
+        )?;
+    }
+    conn.execute(
+        "INSERT INTO maps (key, name, uploader_id, updated_at, deleted_at, data)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        ON CONFLICT (key) DO UPDATE SET name = excluded.name, uploader_id = excluded.uploader_id,
+            updated_at = excluded.updated_at, deleted_at = excluded.deleted_at, data = excluded.data",
+        params![
+            map.id,
+            map.name,
+            map.uploader.id,
+            map.updated_at.timestamp_millis(),
+            map.deleted_at.map(|t| t.timestamp_millis()),
+            serde_json::to_string(map)?,
+        ],
+    )?;
+    conn.execute("DELETE FROM versions WHERE map_key = ?1", [map.id.as_str()])?;
+    for version in map.versions.iter() {
+        conn.execute(
+            "INSERT OR REPLACE INTO versions (hash, map_key, created_at) VALUES (?1, ?2, ?3)",
+            params![
+                version.hash.to_lowercase(),
+                map.id,
+                version.created_at.timestamp_millis()
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Converts a time stored as milliseconds since the epoch
+fn from_millis(millis: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(millis)
+        .single()
+        .unwrap_or_default()
+}
+
+/// Values of the [CSV_HEADER] columns for a map
+fn csv_row(map: &MapDetail) -> Vec<String> {
+    let tags: Vec<&str> = map.tags.iter().map(|t| t.as_str()).collect();
+    vec![
+        map.id.clone(),
+        map.hash().unwrap_or_default().to_string(),
+        map.name.clone(),
+        map.metadata.song_name.clone(),
+        map.metadata.song_sub_name.clone(),
+        map.metadata.song_author_name.clone(),
+        map.metadata.level_author_name.clone(),
+        map.uploader.id.to_string(),
+        map.uploader.name.clone(),
+        map.metadata.bpm.to_string(),
+        map.metadata.duration.to_string(),
+        map.stats.plays.to_string(),
+        map.stats.downloads.to_string(),
+        map.stats.upvotes.to_string(),
+        map.stats.downvotes.to_string(),
+        map.stats.score.to_string(),
+        tags.join(";"),
+        map.automapper.to_string(),
+        map.ranked.to_string(),
+        map.qualified.to_string(),
+        map.uploaded.to_rfc3339(),
+        map.updated_at.to_rfc3339(),
+    ]
+}
+
+/// Writes a CSV row, quoting the fields that contain separators, quotes or line breaks
+fn write_csv_row<W: Write, I: IntoIterator<Item = String>>(
+    writer: &mut W,
+    row: I,
+) -> io::Result<()> {
+    let fields: Vec<String> = row
+        .into_iter()
+        .map(|field| match field.contains(&[',', '"', '\n', '\r'][..]) {
+            true => format!("\"{}\"", field.replace('"', "\"\"")),
+            false => field,
+        })
+        .collect();
+    writer.write_all(fields.join(",").as_bytes())?;
+    writer.write_all(b"\r\n")
+}
+
+/// Options retrieving maps by update time, most recently updated first
+#[cfg(any(feature = "async", feature = "sync"))]
+fn latest_updated() -> LatestOptions {
+    LatestOptions::new().with_sort(LatestSort::Updated)
+}
+
+/// Migrates a database to the current schema version
+fn migrate(conn: &mut Connection) -> Result<(), MirrorError> {
+    let version: usize = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    if version > SCHEMA_VERSION {
+        return Err(MirrorError::UnsupportedVersion(version));
+    }
+    let tx = conn.transaction()?;
+    for migration in MIGRATIONS.iter().skip(version) {
+        tx.execute_batch(migration)?;
+    }
+    tx.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+    tx.commit()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mirror::{
+        migrate, write_csv_row, ExportFormat, Mirror, MirrorError, SyncReport, CSV_HEADER,
+        SCHEMA_VERSION,
+    };
+    use crate::model::map::MapDetail;
+    use crate::tests::{map_hash, map_json};
+    use crate::ws::MapEvent;
+    use rusqlite::Connection;
+
+    fn fake_map(key: &str) -> MapDetail {
+        serde_json::from_str(map_json(key).as_str()).unwrap()
+    }
+
+    #[test]
+    fn test_mirror() {
+        let mut mirror = Mirror::new().unwrap();
+        assert!(mirror.insert_map(fake_map("1")).unwrap().is_none());
+        assert!(mirror.insert_map(fake_map("1")).unwrap().is_some());
+        mirror.insert_map(fake_map("2")).unwrap();
+        assert_eq!(mirror.len().unwrap(), 2);
+        let hash = map_hash("2");
+        assert_eq!(mirror.map_by_hash(&hash).unwrap().unwrap().id, "2");
+        assert_eq!(
+            mirror
+                .map_by_hash(&hash.to_uppercase())
+                .unwrap()
+                .unwrap()
+                .id,
+            "2"
+        );
+        assert_eq!(mirror.user(58338).unwrap().unwrap().name, "bennydabeast");
+        assert_eq!(mirror.remove_map("2").unwrap().unwrap().id, "2");
+        assert!(mirror.map_by_hash(&hash).unwrap().is_none());
+        assert!(mirror.path().is_none());
+    }
+    #[test]
+    fn test_export() {
+        let mut mirror = Mirror::new().unwrap();
+        mirror.insert_map(fake_map("2")).unwrap();
+        mirror.insert_map(fake_map("1")).unwrap();
+
+        let mut jsonl = vec![];
+        assert_eq!(
+            mirror.export(ExportFormat::JsonLines, &mut jsonl).unwrap(),
+            2
+        );
+        let lines: Vec<MapDetail> = String::from_utf8(jsonl)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines, vec![fake_map("1"), fake_map("2")]);
+
+        let mut csv = vec![];
+        assert_eq!(mirror.export(ExportFormat::Csv, &mut csv).unwrap(), 2);
+        let csv = String::from_utf8(csv).unwrap();
+        let rows: Vec<Vec<&str>> = csv.lines().map(|l| l.split(',').collect()).collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], CSV_HEADER);
+        assert_eq!(rows[1][..3], ["1", map_hash("1").as_str(), "Map 1"]);
+    }
+    #[test]
+    fn test_write_csv_row() {
+        let mut row = vec![];
+        let fields = vec!["a".to_string(), "b,c".into(), "say \"hi\"".into()];
+        write_csv_row(&mut row, fields).unwrap();
+        assert_eq!(row, b"a,\"b,c\",\"say \"\"hi\"\"\"\r\n");
+    }
+    #[test]
+    fn test_apply_event() {
+        let mut mirror = Mirror::new().unwrap();
+        assert!(mirror
+            .apply_event(&MapEvent::Create(fake_map("1")))
+            .unwrap());
+        assert!(!mirror
+            .apply_event(&MapEvent::Update(fake_map("1")))
+            .unwrap());
+        assert!(mirror.apply_event(&MapEvent::Delete("1".into())).unwrap());
+        assert!(mirror.map("1").unwrap().unwrap().deleted_at.is_some());
+        assert!(!mirror.apply_event(&MapEvent::Delete("1".into())).unwrap());
+        assert!(!mirror.apply_event(&MapEvent::Delete("2".into())).unwrap());
+    }
+    /// Mirror of maps 1 and 2, where map 2 was deleted upstream
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn refreshed() -> (Mirror, crate::tests::FakeClientPaged) {
+        use crate::tests::FakeClientPaged;
+        use crate::BEATSAVER_URL;
+
+        let mut pages = std::collections::HashMap::new();
+        let url = |key| BEATSAVER_URL.join(format!("maps/id/{}", key).as_str());
+        pages.insert(url("1").unwrap(), map_json("1").into());
+        let mut client = FakeClientPaged::new(pages);
+        client.deleted.insert(url("2").unwrap());
+        let mut mirror = Mirror::new().unwrap();
+        mirror.insert_map(fake_map("1")).unwrap();
+        mirror.insert_map(fake_map("2")).unwrap();
+        (mirror, client)
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_refresh_blocking() {
+        let (mut mirror, client) = refreshed();
+        assert_eq!(mirror.refresh_blocking(&client).unwrap().deleted, 1);
+        assert!(mirror.map("1").unwrap().unwrap().deleted_at.is_none());
+        assert!(mirror.map("2").unwrap().unwrap().deleted_at.is_some());
+        // Deleted maps aren't requested again
+        assert_eq!(mirror.refresh_blocking(&client).unwrap().deleted, 0);
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_refresh() {
+        use futures::executor::block_on;
+
+        let (mut mirror, client) = refreshed();
+        assert_eq!(block_on(mirror.refresh(&client)).unwrap().deleted, 1);
+        assert!(mirror.map("2").unwrap().unwrap().deleted_at.is_some());
+    }
+    #[test]
+    fn test_mirror_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mirror.db");
+        let mut mirror = Mirror::open(&path).unwrap();
+        assert!(mirror.is_empty().unwrap());
+        mirror.insert_map(fake_map("1")).unwrap();
+        drop(mirror);
+
+        let mirror = Mirror::open(&path).unwrap();
+        assert_eq!(mirror.path(), Some(path.as_path()));
+        assert_eq!(mirror.maps().unwrap()[0].id, "1");
+        let map = mirror.map_by_hash(map_hash("1").as_str()).unwrap();
+        assert_eq!(map.unwrap().id, "1");
+        assert_eq!(mirror.users().unwrap().len(), 1);
+    }
+    #[test]
+    fn test_migrate() {
+        let version = |conn: &Connection| -> usize {
+            conn.pragma_query_value(None, "user_version", |row| row.get(0))
+                .unwrap()
+        };
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate(&mut conn).unwrap();
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+        // Migrating an up to date database is a no-op
+        migrate(&mut conn).unwrap();
+        assert_eq!(version(&conn), SCHEMA_VERSION);
+
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", SCHEMA_VERSION + 1)
+            .unwrap();
+        assert!(matches!(
+            migrate(&mut conn),
+            Err(MirrorError::UnsupportedVersion(v)) if v == SCHEMA_VERSION + 1
+        ));
+    }
+    #[test]
+    fn test_apply() {
+        let mut mirror = Mirror::new().unwrap();
+        let mut report = SyncReport::default();
+        assert!(mirror.apply(fake_map("1"), &mut report).unwrap());
+        assert!(!mirror.apply(fake_map("1"), &mut report).unwrap());
+        let mut map = fake_map("1");
+        map.updated_at += chrono::Duration::seconds(1);
+        assert!(mirror.apply(map, &mut report).unwrap());
+        assert_eq!(
+            report,
+            SyncReport {
+                added: 1,
+                updated: 1,
+                votes: 0,
+                deleted: 0
+            }
+        );
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_sync_blocking() {
+        use crate::tests::{page_json, FakeClientPaged};
+        use crate::BEATSAVER_URL;
+        use std::collections::HashMap;
+
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL.join("maps/latest?sort=UPDATED").unwrap(),
+            page_json(&["1", "2"]),
+        );
+        let client = FakeClientPaged::new(pages);
+        let mut mirror = Mirror::new().unwrap();
+        mirror.insert_map(fake_map("2")).unwrap();
+        let report = mirror.sync_blocking(&client).unwrap();
+        assert_eq!(
+            report,
+            SyncReport {
+                added: 1,
+                updated: 0,
+                votes: 0,
+                deleted: 0
+            }
+        );
+        assert_eq!(mirror.len().unwrap(), 2);
+    }
+    /// Pages of a backfill retrieving maps 1 and 2
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn backfill_pages() -> std::collections::HashMap<url::Url, bytes::Bytes> {
+        use crate::tests::page_json;
+        use crate::BEATSAVER_URL;
+
+        let mut pages = std::collections::HashMap::new();
+        let latest = |query| BEATSAVER_URL.join(format!("maps/latest?{}", query).as_str());
+        pages.insert(
+            latest("after=1970-01-01T00%3A00%3A00Z&sort=UPDATED").unwrap(),
+            page_json(&["1", "2"]),
+        );
+        pages.insert(
+            latest("after=2021-06-01T12%3A00%3A00Z&sort=UPDATED").unwrap(),
+            page_json(&[]),
+        );
+        pages
+    }
+    /// Pages of a backfill resumed at map 2, where map 3 was updated at the same time as map 2
+    #[cfg(feature = "sync")]
+    fn resumed_pages() -> std::collections::HashMap<url::Url, bytes::Bytes> {
+        use crate::tests::page_json;
+        use crate::BEATSAVER_URL;
+
+        let mut pages = std::collections::HashMap::new();
+        let latest = |query| BEATSAVER_URL.join(format!("maps/latest?{}", query).as_str());
+        pages.insert(
+            latest("after=2021-06-01T11%3A59%3A59.999Z&sort=UPDATED").unwrap(),
+            page_json(&["2", "3"]),
+        );
+        pages.insert(
+            latest("after=2021-06-01T12%3A00%3A00Z&sort=UPDATED").unwrap(),
+            page_json(&[]),
+        );
+        pages
+    }
+    /// Changes since 2021-01-01: map 1 was updated, map 2 received votes
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn sync_since_pages() -> std::collections::HashMap<url::Url, bytes::Bytes> {
+        use crate::tests::page_json;
+        use crate::BEATSAVER_URL;
+
+        let mut pages = std::collections::HashMap::new();
+        let url = |path| BEATSAVER_URL.join(path).unwrap();
+        pages.insert(
+            url("maps/latest?after=2021-01-01T00%3A00%3A00Z&sort=UPDATED"),
+            page_json(&["1"]),
+        );
+        pages.insert(
+            url("maps/latest?after=2021-06-01T12%3A00%3A00Z&sort=UPDATED"),
+            page_json(&[]),
+        );
+        pages.insert(
+            url("vote?since=2021-01-01T00%3A00%3A00Z"),
+            format!(
+                r#"[{{"hash":"{}","mapId":2,"upvotes":20,"downvotes":1,"score":0.9}},{{"hash":"{}","mapId":3,"upvotes":1,"downvotes":0,"score":0.5}}]"#,
+                map_hash("2"),
+                map_hash("3")
+            )
+            .into(),
+        );
+        pages
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_sync_since_blocking() {
+        use crate::tests::FakeClientPaged;
+
+        let client = FakeClientPaged::new(sync_since_pages());
+        let mut mirror = Mirror::new().unwrap();
+        mirror.insert_map(fake_map("2")).unwrap();
+        let since = "2021-01-01T00:00:00Z".parse().unwrap();
+        let report = mirror.sync_since_blocking(&client, since).unwrap();
+        assert_eq!(
+            report,
+            SyncReport {
+                added: 1,
+                updated: 0,
+                votes: 1,
+                deleted: 0
+            }
+        );
+        assert_eq!(mirror.map("2").unwrap().unwrap().stats.upvotes, 20);
+        assert!(mirror.map("3").unwrap().is_none());
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_sync_since() {
+        use crate::tests::FakeClientPaged;
+        use futures::executor::block_on;
+
+        let client = FakeClientPaged::new(sync_since_pages());
+        let mut mirror = Mirror::new().unwrap();
+        mirror.insert_map(fake_map("2")).unwrap();
+        let since = "2021-01-01T00:00:00Z".parse().unwrap();
+        let report = block_on(mirror.sync_since(&client, since)).unwrap();
+        assert_eq!((report.added, report.votes), (1, 1));
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_backfill_blocking() {
+        use crate::mirror::Checkpoint;
+        use crate::tests::FakeClientPaged;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mirror.db");
+        let client = FakeClientPaged::new(backfill_pages());
+        let mut mirror = Mirror::open(&path).unwrap();
+        assert_eq!(mirror.backfill_blocking(&client).unwrap().added, 2);
+        let checkpoint = Checkpoint {
+            updated_at: "2021-06-01T12:00:00Z".parse().unwrap(),
+            key: "2".into(),
+        };
+        assert_eq!(mirror.checkpoint().unwrap(), Some(checkpoint.clone()));
+
+        // Resumes at the checkpoint, retrieving the map that shares its update time
+        let mut mirror = Mirror::open(&path).unwrap();
+        assert_eq!(mirror.checkpoint().unwrap(), Some(checkpoint));
+        let client = FakeClientPaged::new(resumed_pages());
+        assert_eq!(
+            mirror.backfill_blocking(&client).unwrap(),
+            SyncReport {
+                added: 1,
+                updated: 0,
+                votes: 0,
+                deleted: 0
+            }
+        );
+        assert_eq!(mirror.len().unwrap(), 3);
+        assert_eq!(mirror.checkpoint().unwrap().unwrap().key, "3");
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_backfill() {
+        use crate::tests::FakeClientPaged;
+        use futures::executor::block_on;
+
+        let client = FakeClientPaged::new(backfill_pages());
+        let mut mirror = Mirror::new().unwrap();
+        assert_eq!(block_on(mirror.backfill(&client)).unwrap().added, 2);
+        assert_eq!(mirror.checkpoint().unwrap().unwrap().key, "2");
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_sync() {
+        use crate::tests::{page_json, FakeClientPaged};
+        use crate::BEATSAVER_URL;
+        use futures::executor::block_on;
+        use std::collections::HashMap;
+
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL.join("maps/latest?sort=UPDATED").unwrap(),
+            page_json(&["1", "2"]),
+        );
+        let client = FakeClientPaged::new(pages);
+        let mut mirror = Mirror::new().unwrap();
+        mirror.insert_map(fake_map("2")).unwrap();
+        let report = block_on(mirror.sync(&client)).unwrap();
+        assert_eq!(report.added, 1);
+        assert_eq!(mirror.len().unwrap(), 2);
+    }
+}
@@ -0,0 +1,59 @@
+//! # Error
+//!
+//! This module contains structures that correspond to the error payloads of the API
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Error payload sent along with 4xx responses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiErrorBody {
+    /// Message describing the error
+    #[serde(default, alias = "message")]
+    pub error: Option<String>,
+    /// Reasons the request was rejected
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+impl ApiErrorBody {
+    /// Parses the body of a failed response, `None` if it doesn't contain an error message
+    pub(crate) fn parse(body: &[u8]) -> Option<Self> {
+        match serde_json::from_slice::<Self>(body) {
+            Ok(e) if e.error.is_some() || !e.errors.is_empty() => Some(e),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ApiErrorBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let messages: Vec<&str> = self
+            .error
+            .iter()
+            .chain(self.errors.iter())
+            .map(String::as_str)
+            .collect();
+        write!(f, "{}", messages.join("; "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::error::ApiErrorBody;
+
+    #[test]
+    fn test_api_error_body() {
+        let e = ApiErrorBody::parse(br#"{"error": "Not Found"}"#).unwrap();
+        assert_eq!(e.error, Some("Not Found".into()));
+        assert_eq!(e.to_string(), "Not Found");
+
+        let data = br#"{"success": false, "errors": ["Invalid version", "Not a tester"]}"#;
+        let e = ApiErrorBody::parse(data).unwrap();
+        assert_eq!(e.error, None);
+        assert_eq!(e.to_string(), "Invalid version; Not a tester");
+
+        let e = ApiErrorBody::parse(br#"{"message": "Unauthorized"}"#).unwrap();
+        assert_eq!(e.error, Some("Unauthorized".into()));
+
+        assert_eq!(ApiErrorBody::parse(b"{}"), None);
+        assert_eq!(ApiErrorBody::parse(b"<html>Bad Gateway</html>"), None);
+    }
+}
@@ -0,0 +1,896 @@
+//! # Map
+//!
+//! This module contains structures that correspond to the map API responses
+use crate::model::user::UserDetail;
+use crate::preview::Previewer;
+use crate::MapId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+use url::Url;
+
+/// Publishing state of a map version
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MapState {
+    /// Version was uploaded but not published
+    Uploaded,
+    /// Version is available for testplays
+    Testplay,
+    /// Version is published
+    Published,
+    /// Version is waiting for testplay feedback
+    Feedback,
+    /// Version is scheduled to be published
+    Scheduled,
+    /// State not known by this version of the library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether (and by whom) a map was declared to be AI generated
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum DeclaredAi {
+    /// Map isn't declared as AI generated
+    #[default]
+    None,
+    /// Map was flagged as AI generated by its sage score
+    SageScore,
+    /// Map was declared as AI generated by its uploader
+    Uploader,
+    /// Map was declared as AI generated by an admin
+    Admin,
+    /// Value not known by this version of the library
+    #[serde(other)]
+    Unknown,
+}
+impl DeclaredAi {
+    /// Returns `true` if the map was declared as AI generated
+    pub fn is_ai(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+}
+
+/// Summary of the parity checks run on a difficulty
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapParitySummary {
+    /// Number of parity errors
+    pub errors: usize,
+    /// Number of parity warnings
+    pub warns: usize,
+    /// Number of resets
+    pub resets: usize,
+}
+
+/// Information about a single difficulty of a map version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapDifficulty {
+    /// Note jump speed
+    pub njs: f32,
+    /// Note jump offset
+    pub offset: f32,
+    /// Number of notes
+    pub notes: usize,
+    /// Number of bombs
+    pub bombs: usize,
+    /// Number of walls
+    pub obstacles: usize,
+    /// Notes per second
+    pub nps: f32,
+    /// Length of the difficulty in beats
+    pub length: f32,
+    /// Characteristic of the difficulty (e.g. `Standard`, `OneSaber`)
+    pub characteristic: String,
+    /// Difficulty level (e.g. `Expert`, `ExpertPlus`)
+    pub difficulty: String,
+    /// Number of lighting events
+    pub events: usize,
+    /// `true` if the difficulty uses Chroma
+    pub chroma: bool,
+    /// `true` if the difficulty requires Mapping Extensions
+    pub me: bool,
+    /// `true` if the difficulty requires Noodle Extensions
+    pub ne: bool,
+    /// `true` if the difficulty uses Cinema
+    pub cinema: bool,
+    /// Length of the difficulty in seconds
+    pub seconds: f32,
+    /// Parity check results
+    #[serde(alias = "paritySummary")]
+    pub parity_summary: MapParitySummary,
+    /// Star rating, if the difficulty is ranked
+    #[serde(default)]
+    pub stars: Option<f32>,
+    /// BeatLeader star rating, if the difficulty is ranked on BeatLeader
+    #[serde(alias = "blStars", default)]
+    pub bl_stars: Option<f32>,
+    /// Maximum possible score
+    #[serde(alias = "maxScore")]
+    pub max_score: usize,
+    /// Custom difficulty label
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+/// A single uploaded version of a map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapVersion {
+    /// Hash of the version
+    ///
+    /// Note: This is one of the values used to index maps
+    pub hash: String,
+    /// Key of the map, only included for published versions
+    #[serde(default)]
+    pub key: Option<String>,
+    /// Publishing state of the version
+    pub state: MapState,
+    /// Timestamp of the upload
+    #[serde(alias = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    /// Likelihood of the version being automapped, as estimated by BeatSage detection
+    #[serde(alias = "sageScore", default)]
+    pub sage_score: Option<i32>,
+    /// Difficulties included in the version
+    pub diffs: Vec<MapDifficulty>,
+    /// CDN URL to download the version from
+    #[serde(alias = "downloadURL")]
+    pub download_url: String,
+    /// Cover art URL
+    #[serde(alias = "coverURL")]
+    pub cover_url: String,
+    /// Audio preview URL
+    #[serde(alias = "previewURL")]
+    pub preview_url: String,
+    /// Testplays of the version, only included while it is available for testplays
+    #[serde(default)]
+    pub testplays: Vec<MapTestPlay>,
+}
+
+/// Difficulty levels, from the easiest to the hardest
+const DIFFICULTIES: &[&str] = &["Easy", "Normal", "Hard", "Expert", "ExpertPlus"];
+
+impl MapDifficulty {
+    /// Returns `true` if the difficulty is played in the provided characteristic
+    pub fn is_characteristic(&self, characteristic: &MapCharacteristic) -> bool {
+        self.characteristic == characteristic.as_str()
+    }
+    /// Position of the difficulty level in [DIFFICULTIES], `None` for unknown levels
+    fn level(&self) -> Option<usize> {
+        DIFFICULTIES.iter().position(|d| *d == self.difficulty)
+    }
+}
+
+impl MapVersion {
+    /// Returns the hardest difficulty of the version, across characteristics
+    ///
+    /// Difficulties with an unknown level are only returned if no other difficulty is known.
+    pub fn highest_difficulty(&self) -> Option<&MapDifficulty> {
+        self.diffs.iter().max_by_key(|d| d.level())
+    }
+    /// Returns `true` if the version has a difficulty played in the provided characteristic
+    pub fn has_characteristic(&self, characteristic: MapCharacteristic) -> bool {
+        self.diffs
+            .iter()
+            .any(|d| d.is_characteristic(&characteristic))
+    }
+    /// Difficulties with notes per second between `lo` and `hi`, inclusive
+    pub fn diffs_between_nps(&self, lo: f32, hi: f32) -> impl Iterator<Item = &MapDifficulty> {
+        self.diffs
+            .iter()
+            .filter(move |d| lo <= d.nps && d.nps <= hi)
+    }
+    /// Returns `true` if a difficulty of the version requires Noodle Extensions
+    pub fn requires_noodle(&self) -> bool {
+        self.diffs.iter().any(|d| d.ne)
+    }
+    /// Returns `true` if a difficulty of the version requires Mapping Extensions
+    pub fn requires_mapping_extensions(&self) -> bool {
+        self.diffs.iter().any(|d| d.me)
+    }
+}
+
+/// Testplay of a map version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapTestPlay {
+    /// User who played the version
+    pub user: UserDetail,
+    /// Feedback left by the user, if any
+    #[serde(default)]
+    pub feedback: Option<String>,
+    /// Link to a video of the testplay, if any
+    #[serde(default)]
+    pub video: Option<String>,
+    /// Timestamp of the testplay
+    #[serde(alias = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of the feedback, if any was left
+    #[serde(alias = "feedbackAt", default)]
+    pub feedback_at: Option<DateTime<Utc>>,
+}
+
+/// Song metadata of a map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapDetailMetadata {
+    /// Song beats per minute
+    pub bpm: f32,
+    /// Song duration in seconds
+    pub duration: usize,
+    /// Name of the map's song
+    #[serde(alias = "songName")]
+    pub song_name: String,
+    /// Subname of the map's song
+    #[serde(alias = "songSubName")]
+    pub song_sub_name: String,
+    /// Name of the author of the song
+    #[serde(alias = "songAuthorName")]
+    pub song_author_name: String,
+    /// Name of the author of the beatmap
+    #[serde(alias = "levelAuthorName")]
+    pub level_author_name: String,
+}
+
+/// Collected BeatSaver statistics for the map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapStats {
+    /// Number of times map has been played
+    pub plays: usize,
+    /// Number of times map has been downloaded
+    pub downloads: usize,
+    /// Number of times map has been upvoted
+    pub upvotes: usize,
+    /// Number of times map has been downvoted
+    pub downvotes: usize,
+    /// Rating of the map, between 0 and 1
+    pub score: f32,
+}
+
+/// Tag describing the style or music genre of a map
+///
+/// Tags added to BeatSaver after this version of the library are kept as [Tag::Other][crate::model::map::Tag::Other].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Tag {
+    /// Accuracy focused map
+    Accuracy,
+    /// Balanced map
+    Balanced,
+    /// Challenge map
+    Challenge,
+    /// Dance style map
+    Dance,
+    /// Fitness map
+    Fitness,
+    /// Speed map
+    Speed,
+    /// Tech map
+    Tech,
+    /// Alternative music
+    Alternative,
+    /// Ambient music
+    Ambient,
+    /// Anime music
+    Anime,
+    /// Comedy or meme song
+    ComedyMeme,
+    /// Dance music
+    DanceMusic,
+    /// Drum and bass music
+    DrumAndBass,
+    /// Dubstep music
+    Dubstep,
+    /// Electronic music
+    Electronic,
+    /// Folk or acoustic music
+    FolkAcoustic,
+    /// Funk or disco music
+    FunkDisco,
+    /// Hardcore music
+    Hardcore,
+    /// Hip hop or rap music
+    HipHopRap,
+    /// Holiday music
+    Holiday,
+    /// Instrumental music
+    Instrumental,
+    /// J-core music
+    JCore,
+    /// J-rock music
+    JRock,
+    /// Jazz music
+    Jazz,
+    /// Kids or family music
+    KidsFamily,
+    /// K-pop music
+    KPop,
+    /// Metal music
+    Metal,
+    /// Nightcore music
+    Nightcore,
+    /// Pop music
+    Pop,
+    /// Punk music
+    Punk,
+    /// R&B music
+    RnB,
+    /// Rock music
+    Rock,
+    /// Soul music
+    Soul,
+    /// Speedcore music
+    Speedcore,
+    /// Swing music
+    Swing,
+    /// TV or movie soundtrack
+    TvMovieSoundtrack,
+    /// Video game soundtrack
+    VideoGameSoundtrack,
+    /// Vocaloid music
+    Vocaloid,
+    /// World music
+    WorldMusic,
+    /// Tag not known by this version of the library
+    Other(String),
+}
+impl Tag {
+    /// Value of the tag used by the API (e.g. `tech`)
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Accuracy => "accuracy",
+            Self::Balanced => "balanced",
+            Self::Challenge => "challenge",
+            Self::Dance => "dance-style",
+            Self::Fitness => "fitness",
+            Self::Speed => "speed",
+            Self::Tech => "tech",
+            Self::Alternative => "alternative",
+            Self::Ambient => "ambient",
+            Self::Anime => "anime",
+            Self::ComedyMeme => "comedy-meme",
+            Self::DanceMusic => "dance",
+            Self::DrumAndBass => "drum-and-bass",
+            Self::Dubstep => "dubstep",
+            Self::Electronic => "electronic",
+            Self::FolkAcoustic => "folk-acoustic",
+            Self::FunkDisco => "funk-disco",
+            Self::Hardcore => "hardcore",
+            Self::HipHopRap => "hip-hop-rap",
+            Self::Holiday => "holiday",
+            Self::Instrumental => "instrumental",
+            Self::JCore => "j-core",
+            Self::JRock => "j-rock",
+            Self::Jazz => "jazz",
+            Self::KidsFamily => "kids-family",
+            Self::KPop => "k-pop",
+            Self::Metal => "metal",
+            Self::Nightcore => "nightcore",
+            Self::Pop => "pop",
+            Self::Punk => "punk",
+            Self::RnB => "rb",
+            Self::Rock => "rock",
+            Self::Soul => "soul",
+            Self::Speedcore => "speedcore",
+            Self::Swing => "swing",
+            Self::TvMovieSoundtrack => "tv-movie-soundtrack",
+            Self::VideoGameSoundtrack => "video-game-soundtrack",
+            Self::Vocaloid => "vocaloid",
+            Self::WorldMusic => "world-music",
+            Self::Other(s) => s.as_str(),
+        }
+    }
+}
+impl From<&str> for Tag {
+    fn from(s: &str) -> Self {
+        match s {
+            "accuracy" => Self::Accuracy,
+            "balanced" => Self::Balanced,
+            "challenge" => Self::Challenge,
+            "dance-style" => Self::Dance,
+            "fitness" => Self::Fitness,
+            "speed" => Self::Speed,
+            "tech" => Self::Tech,
+            "alternative" => Self::Alternative,
+            "ambient" => Self::Ambient,
+            "anime" => Self::Anime,
+            "comedy-meme" => Self::ComedyMeme,
+            "dance" => Self::DanceMusic,
+            "drum-and-bass" => Self::DrumAndBass,
+            "dubstep" => Self::Dubstep,
+            "electronic" => Self::Electronic,
+            "folk-acoustic" => Self::FolkAcoustic,
+            "funk-disco" => Self::FunkDisco,
+            "hardcore" => Self::Hardcore,
+            "hip-hop-rap" => Self::HipHopRap,
+            "holiday" => Self::Holiday,
+            "instrumental" => Self::Instrumental,
+            "j-core" => Self::JCore,
+            "j-rock" => Self::JRock,
+            "jazz" => Self::Jazz,
+            "kids-family" => Self::KidsFamily,
+            "k-pop" => Self::KPop,
+            "metal" => Self::Metal,
+            "nightcore" => Self::Nightcore,
+            "pop" => Self::Pop,
+            "punk" => Self::Punk,
+            "rb" => Self::RnB,
+            "rock" => Self::Rock,
+            "soul" => Self::Soul,
+            "speedcore" => Self::Speedcore,
+            "swing" => Self::Swing,
+            "tv-movie-soundtrack" => Self::TvMovieSoundtrack,
+            "video-game-soundtrack" => Self::VideoGameSoundtrack,
+            "vocaloid" => Self::Vocaloid,
+            "world-music" => Self::WorldMusic,
+            s => Self::Other(s.into()),
+        }
+    }
+}
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl Serialize for Tag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from(s.as_str()))
+    }
+}
+
+/// Characteristic of a difficulty, i.e. the game mode it is played in
+///
+/// Characteristics not known by this version of the library are kept as
+/// [MapCharacteristic::Other][crate::model::map::MapCharacteristic::Other].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MapCharacteristic {
+    /// Two sabers
+    Standard,
+    /// Single saber
+    OneSaber,
+    /// Notes without arrows
+    NoArrows,
+    /// Notes coming from 90 degrees around the player
+    NinetyDegree,
+    /// Notes coming from all around the player
+    ThreeSixtyDegree,
+    /// Lights only, without notes
+    Lightshow,
+    /// Modded difficulty without any restriction
+    Lawless,
+    /// Difficulty kept for older game versions
+    Legacy,
+    /// Characteristic not known by this version of the library
+    Other(String),
+}
+impl MapCharacteristic {
+    /// Name of the characteristic used by the API (e.g. `OneSaber`)
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Standard => "Standard",
+            Self::OneSaber => "OneSaber",
+            Self::NoArrows => "NoArrows",
+            Self::NinetyDegree => "90Degree",
+            Self::ThreeSixtyDegree => "360Degree",
+            Self::Lightshow => "Lightshow",
+            Self::Lawless => "Lawless",
+            Self::Legacy => "Legacy",
+            Self::Other(s) => s.as_str(),
+        }
+    }
+}
+impl From<&str> for MapCharacteristic {
+    fn from(s: &str) -> Self {
+        match s {
+            "Standard" => Self::Standard,
+            "OneSaber" => Self::OneSaber,
+            "NoArrows" => Self::NoArrows,
+            "90Degree" => Self::NinetyDegree,
+            "360Degree" => Self::ThreeSixtyDegree,
+            "Lightshow" => Self::Lightshow,
+            "Lawless" => Self::Lawless,
+            "Legacy" => Self::Legacy,
+            s => Self::Other(s.into()),
+        }
+    }
+}
+impl fmt::Display for MapCharacteristic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl Serialize for MapCharacteristic {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for MapCharacteristic {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from(s.as_str()))
+    }
+}
+
+/// Information about a map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapDetail {
+    /// Key assigned to the map (e.g. `2144`)
+    ///
+    /// Note: This is one of the values used to index maps
+    pub id: String,
+    /// Name given to the map
+    pub name: String,
+    /// Description of the map
+    pub description: String,
+    /// User who uploaded the map
+    pub uploader: UserDetail,
+    /// Song metadata
+    pub metadata: MapDetailMetadata,
+    /// Map statistics
+    pub stats: MapStats,
+    /// Timestamp of map upload
+    pub uploaded: DateTime<Utc>,
+    /// `true` if the map was made by an automapper
+    pub automapper: bool,
+    /// Whether the map was declared to be AI generated
+    #[serde(alias = "declaredAi", default)]
+    pub declared_ai: DeclaredAi,
+    /// `true` if the map is ranked
+    pub ranked: bool,
+    /// `true` if the map is qualified for ranking
+    pub qualified: bool,
+    /// `true` if the map is ranked on BeatLeader
+    #[serde(alias = "blRanked", default)]
+    pub bl_ranked: bool,
+    /// `true` if the map is qualified for ranking on BeatLeader
+    #[serde(alias = "blQualified", default)]
+    pub bl_qualified: bool,
+    /// Uploaded versions of the map
+    pub versions: Vec<MapVersion>,
+    /// Tags describing the style and music genre of the map
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    /// User who curated the map, if it was curated
+    #[serde(default)]
+    pub curator: Option<UserDetail>,
+    /// Timestamp of curation, if the map was curated
+    #[serde(alias = "curatedAt", default)]
+    pub curated_at: Option<DateTime<Utc>>,
+    /// Timestamp of map creation
+    #[serde(alias = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of the last update
+    #[serde(alias = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+    /// Timestamp of the last time a version was published
+    #[serde(alias = "lastPublishedAt", default)]
+    pub last_published_at: Option<DateTime<Utc>>,
+    /// Timestamp of deletion, if the map was deleted
+    #[serde(alias = "deletedAt", default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+impl MapDetail {
+    /// Returns the most recently uploaded [MapVersion][crate::model::map::MapVersion]
+    pub fn latest_version(&self) -> Option<&MapVersion> {
+        self.versions.iter().max_by_key(|v| v.created_at)
+    }
+    /// Returns the hash of the most recently uploaded version
+    pub fn hash(&self) -> Option<&str> {
+        self.latest_version().map(|v| v.hash.as_str())
+    }
+    /// Returns the hardest difficulty of the latest version
+    ///
+    /// See [MapVersion::highest_difficulty][crate::model::map::MapVersion::highest_difficulty].
+    pub fn highest_difficulty(&self) -> Option<&MapDifficulty> {
+        self.latest_version()?.highest_difficulty()
+    }
+    /// Returns `true` if the latest version has a difficulty played in the provided characteristic
+    pub fn has_characteristic(&self, characteristic: MapCharacteristic) -> bool {
+        self.latest_version()
+            .is_some_and(|v| v.has_characteristic(characteristic))
+    }
+    /// Difficulties of the latest version with notes per second between `lo` and `hi`, inclusive
+    pub fn diffs_between_nps(&self, lo: f32, hi: f32) -> impl Iterator<Item = &MapDifficulty> {
+        self.latest_version()
+            .into_iter()
+            .flat_map(move |v| v.diffs_between_nps(lo, hi))
+    }
+    /// Returns `true` if a difficulty of the latest version requires Noodle Extensions
+    pub fn requires_noodle(&self) -> bool {
+        self.latest_version()
+            .is_some_and(MapVersion::requires_noodle)
+    }
+    /// Returns `true` if a difficulty of the latest version requires Mapping Extensions
+    pub fn requires_mapping_extensions(&self) -> bool {
+        self.latest_version()
+            .is_some_and(MapVersion::requires_mapping_extensions)
+    }
+    /// Returns the OneClick install URL of the map (e.g. `beatsaver://2144`)
+    pub fn one_click_url(&self) -> String {
+        format!("{}{}", crate::ONE_CLICK_SCHEME, self.id)
+    }
+    /// Returns the URL previewing the map, or one of its difficulties, in an in-browser
+    /// [Previewer][crate::preview::Previewer]
+    ///
+    /// Maps are opened by key, or by the hash of their latest version if the key is invalid.
+    pub fn preview_url(&self, previewer: Previewer, diff: Option<&MapDifficulty>) -> Url {
+        let id = MapId::try_from(self)
+            .unwrap_or_else(|_| MapId::Hash(self.hash().unwrap_or_default().to_string()));
+        match diff {
+            Some(d) => previewer.difficulty_url(&id, &d.characteristic, &d.difficulty),
+            None => previewer.url(&id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::map::{
+        DeclaredAi, MapCharacteristic, MapDetail, MapDifficulty, MapState, MapTestPlay, MapVersion,
+        Tag,
+    };
+    use crate::tests::map_json;
+    use chrono::DateTime;
+
+    #[test]
+    fn test_map() {
+        let data = r#"
+        {
+            "id": "2144",
+            "name": "Shut Up and Dance - WALK THE MOON",
+            "description": "Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY",
+            "uploader": {
+                "id": 58338,
+                "name": "bennydabeast",
+                "hash": "5cff0b7298cc5a672c84e98d",
+                "avatar": "https://cdn.beatsaver.com/avatar/58338.png",
+                "type": "SIMPLE",
+                "admin": false,
+                "curator": false,
+                "verifiedMapper": true,
+                "playlistUrl": "https://api.beatsaver.com/users/id/58338/playlist"
+            },
+            "metadata": {
+                "bpm": 128,
+                "duration": 195,
+                "songName": "Shut Up and Dance",
+                "songSubName": "WALK THE MOON",
+                "songAuthorName": "BennyDaBeast",
+                "levelAuthorName": "bennydabeast"
+            },
+            "stats": {
+                "plays": 558,
+                "downloads": 418854,
+                "upvotes": 10763,
+                "downvotes": 133,
+                "score": 0.9581
+            },
+            "uploaded": "2018-11-21T01:27:00Z",
+            "automapper": false,
+            "declaredAi": "None",
+            "ranked": false,
+            "qualified": false,
+            "blRanked": true,
+            "blQualified": false,
+            "tags": ["dance-style", "pop"],
+            "versions": [{
+                "hash": "89cf8bb07afb3c59ae7b5ac00337d62261c36fb4",
+                "key": "2144",
+                "state": "Published",
+                "createdAt": "2018-11-21T01:27:00Z",
+                "sageScore": 0,
+                "diffs": [{
+                    "njs": 10,
+                    "offset": 0,
+                    "notes": 301,
+                    "bombs": 4,
+                    "obstacles": 24,
+                    "nps": 1.544,
+                    "length": 417,
+                    "characteristic": "Standard",
+                    "difficulty": "Normal",
+                    "events": 1062,
+                    "chroma": false,
+                    "me": false,
+                    "ne": false,
+                    "cinema": false,
+                    "seconds": 195.469,
+                    "paritySummary": {"errors": 0, "warns": 12, "resets": 0},
+                    "maxScore": 268295
+                }, {
+                    "njs": 12,
+                    "offset": 0,
+                    "notes": 894,
+                    "bombs": 0,
+                    "obstacles": 0,
+                    "nps": 4.587,
+                    "length": 417.5,
+                    "characteristic": "Standard",
+                    "difficulty": "ExpertPlus",
+                    "events": 1062,
+                    "chroma": false,
+                    "me": false,
+                    "ne": false,
+                    "cinema": false,
+                    "seconds": 195.703,
+                    "paritySummary": {"errors": 3, "warns": 41, "resets": 1},
+                    "stars": 4.21,
+                    "blStars": 5.07,
+                    "maxScore": 814315,
+                    "label": "Expert+"
+                }],
+                "downloadURL": "https://cdn.beatsaver.com/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip",
+                "coverURL": "https://cdn.beatsaver.com/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.jpg",
+                "previewURL": "https://cdn.beatsaver.com/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.mp3"
+            }],
+            "createdAt": "2018-11-21T01:27:00Z",
+            "updatedAt": "2021-06-01T12:00:00Z",
+            "lastPublishedAt": "2018-11-21T01:27:00Z"
+        }"#;
+
+        let v: MapDetail = serde_json::from_str(data).unwrap();
+
+        assert_eq!(v.id, "2144");
+        assert_eq!(v.name, "Shut Up and Dance - WALK THE MOON");
+        assert_eq!(v.uploader.id, 58338);
+        assert_eq!(v.uploader.name, "bennydabeast");
+        assert!(v.uploader.verified_mapper);
+
+        assert_eq!(v.metadata.bpm, 128f32);
+        assert_eq!(v.metadata.duration, 195);
+        assert_eq!(v.metadata.song_name, "Shut Up and Dance");
+        assert_eq!(v.metadata.song_sub_name, "WALK THE MOON");
+        assert_eq!(v.metadata.song_author_name, "BennyDaBeast");
+        assert_eq!(v.metadata.level_author_name, "bennydabeast");
+
+        assert_eq!(v.stats.plays, 558);
+        assert_eq!(v.stats.downloads, 418854);
+        assert_eq!(v.stats.upvotes, 10763);
+        assert_eq!(v.stats.downvotes, 133);
+        assert_eq!(v.stats.score, 0.9581f32);
+
+        assert_eq!(
+            v.uploaded,
+            DateTime::parse_from_rfc3339("2018-11-21T01:27:00Z").unwrap()
+        );
+        assert!(!v.automapper);
+        assert_eq!(v.declared_ai, DeclaredAi::None);
+        assert!(!v.declared_ai.is_ai());
+        assert!(!v.ranked);
+        assert!(v.bl_ranked);
+        assert!(!v.bl_qualified);
+        assert_eq!(v.curator, None);
+        assert_eq!(v.curated_at, None);
+        assert_eq!(v.deleted_at, None);
+        assert_eq!(v.tags, vec![Tag::Dance, Tag::Pop]);
+
+        assert_eq!(v.versions.len(), 1);
+        let version = v.latest_version().unwrap();
+        assert_eq!(version.key, Some("2144".into()));
+        assert_eq!(version.state, MapState::Published);
+        assert_eq!(v.hash(), Some("89cf8bb07afb3c59ae7b5ac00337d62261c36fb4"));
+        assert_eq!(
+            version.download_url,
+            "https://cdn.beatsaver.com/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip"
+        );
+
+        assert_eq!(version.diffs.len(), 2);
+        let normal = &version.diffs[0];
+        assert_eq!(normal.difficulty, "Normal");
+        assert_eq!(normal.characteristic, "Standard");
+        assert_eq!(normal.notes, 301);
+        assert_eq!(normal.bombs, 4);
+        assert_eq!(normal.obstacles, 24);
+        assert_eq!(normal.njs, 10f32);
+        assert_eq!(normal.stars, None);
+        assert_eq!(normal.bl_stars, None);
+        assert_eq!(normal.label, None);
+        let expert_plus = &version.diffs[1];
+        assert_eq!(expert_plus.difficulty, "ExpertPlus");
+        assert_eq!(expert_plus.parity_summary.errors, 3);
+        assert_eq!(expert_plus.stars, Some(4.21f32));
+        assert_eq!(expert_plus.bl_stars, Some(5.07f32));
+        assert_eq!(expert_plus.label, Some("Expert+".into()));
+    }
+    #[test]
+    fn test_map_curated() {
+        let mut data: serde_json::Value = serde_json::from_str(map_json("1").as_str()).unwrap();
+        data["curator"] = serde_json::json!({
+            "id": 4284201,
+            "name": "Joetastic",
+            "avatar": "https://cdn.beatsaver.com/avatar/4284201.png",
+            "curator": true
+        });
+        data["curatedAt"] = "2021-06-02T12:00:00Z".into();
+
+        let v: MapDetail = serde_json::from_value(data).unwrap();
+        let curator = v.curator.unwrap();
+        assert_eq!(curator.name, "Joetastic");
+        assert!(curator.curator);
+        assert_eq!(v.curated_at, Some("2021-06-02T12:00:00Z".parse().unwrap()));
+    }
+    #[test]
+    fn test_map_testplay() {
+        let data = r#"
+        {
+            "user": {
+                "id": 4285984,
+                "name": "qwerty01",
+                "avatar": "https://cdn.beatsaver.com/avatar/4285984.png"
+            },
+            "feedback": "Great flow",
+            "createdAt": "2021-06-01T12:00:00Z",
+            "feedbackAt": "2021-06-01T13:00:00Z"
+        }"#;
+
+        let v: MapTestPlay = serde_json::from_str(data).unwrap();
+        assert_eq!(v.user.name, "qwerty01");
+        assert_eq!(v.feedback, Some("Great flow".into()));
+        assert_eq!(v.video, None);
+
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MapTestPlay>(json.as_str()).unwrap(),
+            v
+        );
+    }
+    #[test]
+    fn test_tag() {
+        let tags: Vec<Tag> = serde_json::from_str(r#"["tech", "hyperpop"]"#).unwrap();
+        assert_eq!(tags, vec![Tag::Tech, Tag::Other("hyperpop".into())]);
+        assert_eq!(
+            serde_json::to_string(&tags).unwrap(),
+            r#"["tech","hyperpop"]"#
+        );
+        assert_eq!(
+            Tag::VideoGameSoundtrack.to_string(),
+            "video-game-soundtrack"
+        );
+    }
+    #[test]
+    fn test_map_state_unknown() {
+        let state: MapState = serde_json::from_str(r#""Archived""#).unwrap();
+        assert_eq!(state, MapState::Unknown);
+    }
+    #[test]
+    fn test_characteristic() {
+        let characteristic: MapCharacteristic = serde_json::from_str(r#""360Degree""#).unwrap();
+        assert_eq!(characteristic, MapCharacteristic::ThreeSixtyDegree);
+        assert_eq!(characteristic.to_string(), "360Degree");
+        assert_eq!(
+            MapCharacteristic::from("Custom"),
+            MapCharacteristic::Other("Custom".to_string())
+        );
+    }
+    #[test]
+    fn test_difficulty_queries() {
+        let mut expert_plus = MapDifficulty::fake("OneSaber", "ExpertPlus");
+        expert_plus.nps = 7.5;
+        expert_plus.ne = true;
+        let mut custom = MapDifficulty::fake("Standard", "Impossible");
+        custom.nps = 12.0;
+        let map = MapDetail::fake("1").with_version(
+            MapVersion::fake("2")
+                .with_difficulty(expert_plus)
+                .with_difficulty(custom),
+        );
+        assert_eq!(map.highest_difficulty().unwrap().difficulty, "ExpertPlus");
+        assert!(map.has_characteristic(MapCharacteristic::OneSaber));
+        assert!(!map.has_characteristic(MapCharacteristic::Lawless));
+        let diffs: Vec<&str> = map
+            .diffs_between_nps(4.2, 7.5)
+            .map(|d| d.difficulty.as_str())
+            .collect();
+        assert_eq!(diffs, ["Expert", "ExpertPlus"]);
+        assert!(map.requires_noodle());
+        assert!(!map.requires_mapping_extensions());
+
+        // only the latest version is considered
+        let old = &map.versions[0];
+        assert_eq!(old.highest_difficulty().unwrap().difficulty, "Expert");
+        assert!(!old.has_characteristic(MapCharacteristic::OneSaber));
+        assert!(!old.requires_noodle());
+    }
+}
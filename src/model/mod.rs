@@ -0,0 +1,11 @@
+//! # Model
+//!
+//! This module contains structures that correspond to the v2 API responses
+//!
+//! [API documentation here](https://api.beatsaver.com/docs/)
+pub mod error;
+pub mod map;
+pub mod playlist;
+pub mod testplay;
+pub mod user;
+pub mod vote;
@@ -0,0 +1,174 @@
+//! # Playlist
+//!
+//! This module contains structures that correspond to the playlist API responses
+use crate::model::map::MapDetail;
+use crate::model::user::UserDetail;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Visibility of a playlist
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PlaylistType {
+    /// Playlist is only visible to its owner
+    Private,
+    /// Playlist is visible to everyone
+    Public,
+    /// Playlist is managed by BeatSaver (e.g. a user's bookmarks)
+    System,
+    /// Playlist is generated from a search query
+    Search,
+    /// Type not known by this version of the library
+    #[serde(other)]
+    Unknown,
+}
+
+/// Collected statistics of the maps in a playlist
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaylistStats {
+    /// Number of maps in the playlist
+    #[serde(alias = "totalMaps")]
+    pub total_maps: usize,
+    /// Number of distinct mappers in the playlist
+    #[serde(alias = "mapperCount")]
+    pub mapper_count: usize,
+    /// Total duration of all maps, in seconds
+    #[serde(alias = "totalDuration")]
+    pub total_duration: usize,
+    /// Lowest notes per second of any difficulty
+    #[serde(alias = "minNps")]
+    pub min_nps: f32,
+    /// Highest notes per second of any difficulty
+    #[serde(alias = "maxNps")]
+    pub max_nps: f32,
+    /// Total number of upvotes across all maps
+    #[serde(alias = "upVotes")]
+    pub upvotes: usize,
+    /// Total number of downvotes across all maps
+    #[serde(alias = "downVotes")]
+    pub downvotes: usize,
+    /// Average rating of the maps, between 0 and 1
+    #[serde(alias = "avgScore")]
+    pub avg_score: f32,
+}
+
+/// Information about a playlist
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaylistDetail {
+    /// Playlist ID (e.g. `1234`)
+    #[serde(alias = "playlistId")]
+    pub id: usize,
+    /// Name given to the playlist
+    pub name: String,
+    /// Description of the playlist
+    #[serde(default)]
+    pub description: String,
+    /// Cover image URL
+    #[serde(alias = "playlistImage")]
+    pub image: String,
+    /// Cover image URL, 512x512
+    #[serde(alias = "playlistImage512", default)]
+    pub image_512: Option<String>,
+    /// User who created the playlist
+    pub owner: UserDetail,
+    /// User who curated the playlist, if it was curated
+    #[serde(default)]
+    pub curator: Option<UserDetail>,
+    /// Playlist statistics
+    #[serde(default)]
+    pub stats: Option<PlaylistStats>,
+    /// Visibility of the playlist
+    #[serde(rename = "type")]
+    pub playlist_type: PlaylistType,
+    /// URL to download the playlist as a `.bplist`
+    #[serde(alias = "downloadURL")]
+    pub download_url: String,
+    /// Timestamp of playlist creation
+    #[serde(alias = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of the last update
+    #[serde(alias = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+    /// Timestamp of the last time maps were added or removed
+    #[serde(alias = "songsChangedAt", default)]
+    pub songs_changed_at: Option<DateTime<Utc>>,
+    /// Timestamp of curation, if the playlist was curated
+    #[serde(alias = "curatedAt", default)]
+    pub curated_at: Option<DateTime<Utc>>,
+    /// Timestamp of deletion, if the playlist was deleted
+    #[serde(alias = "deletedAt", default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Map contained in a playlist
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaylistMap {
+    /// Map information
+    pub map: MapDetail,
+    /// Position of the map in the playlist
+    pub order: f32,
+}
+
+/// Single page of a playlist, as returned by the playlist detail endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaylistPage {
+    /// Playlist information
+    pub playlist: PlaylistDetail,
+    /// Maps contained in the page
+    #[serde(default)]
+    pub maps: Vec<PlaylistMap>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::playlist::{PlaylistDetail, PlaylistPage, PlaylistType};
+    use crate::tests::playlist_maps_json;
+
+    #[test]
+    fn test_playlist() {
+        let data = r#"
+        {
+            "playlistId": 1234,
+            "name": "Tech Maps",
+            "description": "Maps with lots of tech",
+            "playlistImage": "https://cdn.beatsaver.com/playlist/1234.jpg",
+            "playlistImage512": "https://cdn.beatsaver.com/playlist/1234-512.jpg",
+            "owner": {
+                "id": 4285984,
+                "name": "qwerty01",
+                "avatar": "https://cdn.beatsaver.com/avatar/4285984.png"
+            },
+            "stats": {
+                "totalMaps": 2,
+                "mapperCount": 1,
+                "totalDuration": 390,
+                "minNps": 3.5,
+                "maxNps": 8.25,
+                "upVotes": 20,
+                "downVotes": 2,
+                "avgScore": 0.8
+            },
+            "createdAt": "2021-08-01T12:00:00Z",
+            "updatedAt": "2021-08-02T12:00:00Z",
+            "songsChangedAt": "2021-08-02T12:00:00Z",
+            "downloadURL": "https://api.beatsaver.com/playlists/id/1234/download",
+            "type": "Public"
+        }"#;
+
+        let v: PlaylistDetail = serde_json::from_str(data).unwrap();
+        assert_eq!(v.id, 1234);
+        assert_eq!(v.owner.name, "qwerty01");
+        assert_eq!(v.curator, None);
+        assert_eq!(v.playlist_type, PlaylistType::Public);
+        assert_eq!(v.stats.unwrap().total_maps, 2);
+    }
+    #[test]
+    fn test_playlist_page() {
+        let data = playlist_maps_json(1234, &["2144", "1"]);
+
+        let v: PlaylistPage = serde_json::from_slice(data.as_ref()).unwrap();
+        assert_eq!(v.playlist.id, 1234);
+        assert_eq!(v.maps.len(), 2);
+        assert_eq!(v.maps[0].map.id, "2144");
+        assert_eq!(v.maps[1].order, 1.0);
+    }
+}
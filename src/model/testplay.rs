@@ -0,0 +1,104 @@
+//! # Testplay
+//!
+//! This module contains structures that correspond to the testplay API requests and responses
+use crate::model::map::MapState;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Request changing the publishing state of a map version
+///
+/// Example:
+/// ```
+/// use beatsaver_rs::model::map::MapState;
+/// use beatsaver_rs::model::testplay::TestplayStateRequest;
+///
+/// let request = TestplayStateRequest::new("89cf8bb07afb3c59ae7b5ac00337d62261c36fb4", MapState::Testplay);
+/// assert_eq!(request.state, MapState::Testplay);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestplayStateRequest {
+    /// Hash of the version
+    pub hash: String,
+    /// State to move the version to
+    pub state: MapState,
+    /// Time at which the version is published, for [Scheduled][crate::model::map::MapState::Scheduled]
+    #[serde(
+        rename = "scheduleAt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub schedule_at: Option<DateTime<Utc>>,
+}
+impl TestplayStateRequest {
+    /// Creates a new [TestplayStateRequest][crate::model::testplay::TestplayStateRequest]
+    pub fn new<S: Into<String>>(hash: S, state: MapState) -> Self {
+        Self {
+            hash: hash.into(),
+            state,
+            schedule_at: None,
+        }
+    }
+    /// Creates a request scheduling the version to be published at the specified time
+    pub fn scheduled<S: Into<String>>(hash: S, at: DateTime<Utc>) -> Self {
+        Self {
+            schedule_at: Some(at),
+            ..Self::new(hash, MapState::Scheduled)
+        }
+    }
+}
+
+/// Feedback left on a version that is available for testplays
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestplayFeedbackRequest {
+    /// Hash of the version
+    pub hash: String,
+    /// Feedback text
+    pub feedback: String,
+}
+impl TestplayFeedbackRequest {
+    /// Creates a new [TestplayFeedbackRequest][crate::model::testplay::TestplayFeedbackRequest]
+    pub fn new<H: Into<String>, F: Into<String>>(hash: H, feedback: F) -> Self {
+        Self {
+            hash: hash.into(),
+            feedback: feedback.into(),
+        }
+    }
+}
+
+/// Outcome of a testplay action
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionResponse {
+    /// `true` if the action was applied
+    pub success: bool,
+    /// Reasons the action was rejected
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::map::MapState;
+    use crate::model::testplay::{ActionResponse, TestplayStateRequest};
+
+    #[test]
+    fn test_state_request() {
+        let request = TestplayStateRequest::new("abc", MapState::Testplay);
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"hash":"abc","state":"Testplay"}"#
+        );
+        let request =
+            TestplayStateRequest::scheduled("abc", "2021-06-01T12:00:00Z".parse().unwrap());
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"hash":"abc","state":"Scheduled","scheduleAt":"2021-06-01T12:00:00Z"}"#
+        );
+    }
+    #[test]
+    fn test_action_response() {
+        let v: ActionResponse =
+            serde_json::from_str(r#"{"success":false,"errors":["Not found"]}"#).unwrap();
+        assert!(!v.success);
+        assert_eq!(v.errors, vec!["Not found"]);
+    }
+}
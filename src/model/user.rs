@@ -0,0 +1,168 @@
+//! # User
+//!
+//! This module contains structures that correspond to the user API responses
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Aggregated statistics of the maps uploaded by a user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserStats {
+    /// Total number of upvotes across all maps
+    #[serde(alias = "totalUpvotes")]
+    pub total_upvotes: usize,
+    /// Total number of downvotes across all maps
+    #[serde(alias = "totalDownvotes")]
+    pub total_downvotes: usize,
+    /// Number of maps uploaded
+    #[serde(alias = "totalMaps")]
+    pub total_maps: usize,
+    /// Number of ranked maps
+    #[serde(alias = "rankedMaps")]
+    pub ranked_maps: usize,
+    /// Average BPM of all maps
+    #[serde(alias = "avgBpm")]
+    pub avg_bpm: f32,
+    /// Average score of all maps
+    #[serde(alias = "avgScore")]
+    pub avg_score: f32,
+    /// Average duration of all maps, in seconds
+    #[serde(alias = "avgDuration")]
+    pub avg_duration: f32,
+    /// Timestamp of the first upload
+    #[serde(alias = "firstUpload", default)]
+    pub first_upload: Option<DateTime<Utc>>,
+    /// Timestamp of the most recent upload
+    #[serde(alias = "lastUpload", default)]
+    pub last_upload: Option<DateTime<Utc>>,
+}
+
+/// Information about a user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserDetail {
+    /// User ID (e.g. `4285984`)
+    pub id: usize,
+    /// User name (e.g. `qwerty01`)
+    pub name: String,
+    /// Legacy ID from the old API (e.g. `5fbe7cd60192c700062b2a1f`)
+    ///
+    /// Note: Only set for accounts that existed before the v2 API
+    #[serde(default)]
+    pub hash: Option<String>,
+    /// Avatar URL
+    pub avatar: String,
+    /// Profile description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// `true` if the user is an admin
+    #[serde(default)]
+    pub admin: bool,
+    /// `true` if the user is a curator
+    #[serde(default)]
+    pub curator: bool,
+    /// `true` if the user is a verified mapper
+    #[serde(alias = "verifiedMapper", default)]
+    pub verified_mapper: bool,
+    /// URL of a playlist containing all of the user's maps
+    #[serde(alias = "playlistUrl", default)]
+    pub playlist_url: Option<String>,
+    /// Statistics of the user's maps
+    ///
+    /// Note: Only included when requesting a user directly
+    #[serde(default)]
+    pub stats: Option<UserStats>,
+}
+
+/// Proof of account ownership submitted to the user verification endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserVerifyRequest {
+    /// Steam ID of the user being verified
+    #[serde(rename = "steamId", skip_serializing_if = "Option::is_none", default)]
+    pub steam_id: Option<String>,
+    /// Oculus ID of the user being verified
+    #[serde(rename = "oculusId", skip_serializing_if = "Option::is_none", default)]
+    pub oculus_id: Option<String>,
+    /// Authentication proof (e.g. a Steam session ticket or Oculus nonce)
+    pub proof: String,
+}
+impl UserVerifyRequest {
+    /// Creates a verification request for a Steam account
+    pub fn steam<S: Into<String>, P: Into<String>>(steam_id: S, proof: P) -> Self {
+        Self {
+            steam_id: Some(steam_id.into()),
+            oculus_id: None,
+            proof: proof.into(),
+        }
+    }
+    /// Creates a verification request for an Oculus account
+    pub fn oculus<S: Into<String>, P: Into<String>>(oculus_id: S, proof: P) -> Self {
+        Self {
+            steam_id: None,
+            oculus_id: Some(oculus_id.into()),
+            proof: proof.into(),
+        }
+    }
+}
+
+/// Result of a user verification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserVerifyResponse {
+    /// `true` if the proof was accepted
+    pub success: bool,
+    /// BeatSaver ID of the verified user
+    #[serde(alias = "userId", default)]
+    pub user_id: Option<usize>,
+    /// Reason the proof was rejected
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::user::{UserDetail, UserVerifyRequest, UserVerifyResponse};
+
+    #[test]
+    fn test_user() {
+        let data = r#"
+        {
+            "id": 4285984,
+            "name": "qwerty01",
+            "hash": "5fbe7cd60192c700062b2a1f",
+            "avatar": "https://cdn.beatsaver.com/avatar/4285984.png",
+            "stats": {
+                "totalUpvotes": 0,
+                "totalDownvotes": 0,
+                "totalMaps": 0,
+                "rankedMaps": 0,
+                "avgBpm": 0.0,
+                "avgScore": 0.0,
+                "avgDuration": 0.0
+            },
+            "type": "SIMPLE",
+            "playlistUrl": "https://api.beatsaver.com/users/id/4285984/playlist"
+        }"#;
+
+        let v: UserDetail = serde_json::from_str(data).unwrap();
+        assert_eq!(v.id, 4285984);
+        assert_eq!(v.name, "qwerty01");
+        assert_eq!(v.hash, Some("5fbe7cd60192c700062b2a1f".into()));
+        assert!(!v.admin);
+        assert!(!v.verified_mapper);
+        let stats = v.stats.unwrap();
+        assert_eq!(stats.total_maps, 0);
+        assert_eq!(stats.first_upload, None);
+    }
+    #[test]
+    fn test_user_verify() {
+        let request = UserVerifyRequest::steam("76561198000000000", "ticket");
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"steamId":"76561198000000000","proof":"ticket"}"#
+        );
+
+        let v: UserVerifyResponse =
+            serde_json::from_str(r#"{"success":true,"userId":4285984}"#).unwrap();
+        assert!(v.success);
+        assert_eq!(v.user_id, Some(4285984));
+        assert_eq!(v.error, None);
+    }
+}
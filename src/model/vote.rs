@@ -0,0 +1,46 @@
+//! # Vote
+//!
+//! This module contains structures that correspond to the vote API responses
+use serde::{Deserialize, Serialize};
+
+/// Aggregated votes of a map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteSummary {
+    /// Hash of the map's latest version
+    pub hash: String,
+    /// Numeric map key (e.g. `0x2144`)
+    #[serde(alias = "mapId")]
+    pub map_id: usize,
+    /// Map key in its base64 form
+    #[serde(default)]
+    pub key64: Option<String>,
+    /// Number of times map has been upvoted
+    pub upvotes: usize,
+    /// Number of times map has been downvoted
+    pub downvotes: usize,
+    /// Rating of the map, between 0 and 1
+    pub score: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::vote::VoteSummary;
+
+    #[test]
+    fn test_vote_summary() {
+        let data = r#"
+        {
+            "hash": "89cf8bb07afb3c59ae7b5b6c7c3cc9c2f0dc25e0",
+            "mapId": 8516,
+            "key64": "IUQ",
+            "upvotes": 1234,
+            "downvotes": 56,
+            "score": 0.9
+        }"#;
+
+        let v: VoteSummary = serde_json::from_str(data).unwrap();
+        assert_eq!(v.map_id, 0x2144);
+        assert_eq!(v.upvotes, 1234);
+        assert_eq!(v.key64, Some("IUQ".into()));
+    }
+}
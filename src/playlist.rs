@@ -0,0 +1,718 @@
+//! # Playlist
+//!
+//! This module reads and writes `.bplist` playlists, the format used by Beat Saber playlist mods
+//! (e.g. PlaylistManager) and mod managers.
+//!
+//! Playlists are built from maps returned by the API, and validated before being written:
+//! ```no_run
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaver;
+//! use beatsaver_rs::playlist::{Playlist, PlaylistSong};
+//! use beatsaver_rs::BeatSaverApiAsync;
+//! use std::convert::TryInto;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BeatSaver::new();
+//!     let map = client.map(&"2144".try_into().unwrap()).await.unwrap();
+//!     let playlist = Playlist::new("Favorites")
+//!         .with_author("me")
+//!         .with_song(PlaylistSong::from_map(&map).unwrap());
+//!     playlist.save("favorites.bplist").unwrap();
+//! }
+//! # }
+//! ```
+//!
+//! Web pages can offer playlists for download with [to_data_url][crate::playlist::Playlist::to_data_url],
+//! or as OneClick installs with [one_click_url][crate::playlist::one_click_url] once hosted.
+//!
+//! Paged results can also be collected straight into a playlist, with
+//! [CollectPlaylist][crate::playlist::CollectPlaylist] on iterators and
+//! [CollectPlaylistAsync][crate::playlist::CollectPlaylistAsync] on streams:
+//! ```no_run
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaver;
+//! use beatsaver_rs::playlist::CollectPlaylistAsync;
+//! use beatsaver_rs::BeatSaverApiAsync;
+//! use futures::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BeatSaver::new();
+//!     let playlist = client.search("camellia").take(50).collect_playlist("My List").await.unwrap();
+//!     playlist.save("my-list.bplist").unwrap();
+//! }
+//! # }
+//! ```
+use crate::model::map::MapDetail;
+#[cfg(feature = "async")]
+use crate::BeatSaverApiAsync;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::BeatSaverApiError;
+#[cfg(feature = "sync")]
+use crate::BeatSaverApiSync;
+#[cfg(any(feature = "async", feature = "sync"))]
+use crate::MAPS_BY_HASHES_LIMIT;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+#[cfg(feature = "async")]
+use futures::future::LocalBoxFuture;
+#[cfg(feature = "async")]
+use futures::{Stream, StreamExt};
+#[cfg(any(feature = "async", feature = "sync"))]
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Borrow;
+#[cfg(any(feature = "async", feature = "sync"))]
+use std::collections::HashMap;
+use std::collections::HashSet;
+#[cfg(any(feature = "async", feature = "sync"))]
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use thiserror::Error;
+use url::Url;
+
+/// Scheme of the OneClick playlist install URLs registered by mod managers (e.g. ModAssistant)
+pub const PLAYLIST_ONE_CLICK_SCHEME: &str = "bsplaylist://playlist/";
+/// Prefix of the `data:` URLs built by [to_data_url][crate::playlist::Playlist::to_data_url]
+const DATA_URL_PREFIX: &str = "data:application/json;base64,";
+
+/// Builds the OneClick install URL of a playlist hosted at the provided URL (e.g.
+/// `bsplaylist://playlist/https://example.com/list.bplist`)
+pub fn one_click_url(url: &Url) -> String {
+    format!("{}{}", PLAYLIST_ONE_CLICK_SCHEME, url)
+}
+
+/// Difficulty names accepted in playlists, compared case-insensitively
+const DIFFICULTY_NAMES: &[&str] = &["Easy", "Normal", "Hard", "Expert", "ExpertPlus"];
+
+/// Error returned when validating a [Playlist][crate::playlist::Playlist]
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum PlaylistError {
+    /// The playlist title is empty
+    #[error("Playlist title is empty")]
+    MissingTitle,
+    /// The hash of a song isn't 40 hex characters
+    #[error("Song {0} has an invalid hash: {1}")]
+    InvalidHash(usize, String),
+    /// The key of a song isn't a valid [MapKey][crate::MapKey]
+    #[error("Song {0} has an invalid key: {1}")]
+    InvalidKey(usize, String),
+    /// A difficulty of a song has an empty characteristic or an unknown name
+    #[error("Song {0} has an invalid difficulty: {1} {2}")]
+    InvalidDifficulty(usize, String, String),
+    /// A song with the same hash is already in the playlist
+    #[error("Song {0} is a duplicate of {1}")]
+    DuplicateSong(usize, String),
+}
+
+/// Changes made to a playlist by [refresh][crate::playlist::Playlist::refresh]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefreshReport {
+    /// Songs whose hash was replaced by the one of the latest version of their map
+    pub updated: usize,
+    /// Duplicate songs removed after being updated to the same version
+    pub removed: usize,
+    /// Hashes of the songs whose map was deleted, or isn't known to BeatSaver
+    pub deleted: Vec<String>,
+}
+
+/// Difficulty of a song highlighted by a playlist
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistDifficulty {
+    /// Characteristic of the difficulty (e.g. `Standard`, `OneSaber`)
+    pub characteristic: String,
+    /// Name of the difficulty (e.g. `Expert`, `ExpertPlus`)
+    pub name: String,
+}
+
+/// Song of a [Playlist][crate::playlist::Playlist]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistSong {
+    /// Hash of the map version
+    pub hash: String,
+    /// Key of the map
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Name of the song
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub song_name: Option<String>,
+    /// Name of the mapper
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level_author_name: Option<String>,
+    /// Difficulties highlighted by the playlist, empty if the whole map is included
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub difficulties: Vec<PlaylistDifficulty>,
+}
+impl PlaylistSong {
+    /// Creates a new [PlaylistSong][crate::playlist::PlaylistSong] from a map hash
+    pub fn new(hash: &str) -> Self {
+        Self {
+            hash: hash.to_lowercase(),
+            key: None,
+            song_name: None,
+            level_author_name: None,
+            difficulties: vec![],
+        }
+    }
+    /// Creates a [PlaylistSong][crate::playlist::PlaylistSong] for the latest version of a map
+    ///
+    /// Returns `None` if the map has no versions.
+    pub fn from_map(map: &MapDetail) -> Option<Self> {
+        let version = map.latest_version()?;
+        Some(Self {
+            key: Some(map.id.clone()),
+            song_name: Some(map.metadata.song_name.clone()),
+            level_author_name: Some(map.metadata.level_author_name.clone()),
+            ..Self::new(version.hash.as_str())
+        })
+    }
+    /// Highlights a difficulty of the song
+    pub fn with_difficulty(mut self, characteristic: &str, name: &str) -> Self {
+        self.difficulties.push(PlaylistDifficulty {
+            characteristic: characteristic.to_string(),
+            name: name.to_string(),
+        });
+        self
+    }
+}
+
+/// Playlist in the `.bplist` format
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Playlist {
+    /// Title of the playlist
+    #[serde(rename = "playlistTitle")]
+    pub title: String,
+    /// Author of the playlist
+    #[serde(rename = "playlistAuthor", default)]
+    pub author: String,
+    /// Description of the playlist
+    #[serde(
+        rename = "playlistDescription",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    /// Cover image of the playlist, base64 encoded (optionally as a `data:` URL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Songs of the playlist
+    #[serde(default)]
+    pub songs: Vec<PlaylistSong>,
+    /// Data added by mods (e.g. `syncURL`), kept as is
+    #[serde(
+        rename = "customData",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub custom_data: Option<Value>,
+}
+impl Playlist {
+    /// Creates a new, empty [Playlist][crate::playlist::Playlist]
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            author: String::new(),
+            description: None,
+            image: None,
+            songs: vec![],
+            custom_data: None,
+        }
+    }
+    /// Sets the author of the playlist
+    pub fn with_author(mut self, author: &str) -> Self {
+        self.author = author.to_string();
+        self
+    }
+    /// Sets the description of the playlist
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+    /// Sets the base64 encoded cover image of the playlist
+    pub fn with_image(mut self, image: &str) -> Self {
+        self.image = Some(image.to_string());
+        self
+    }
+    /// Adds a song to the playlist
+    pub fn with_song(mut self, song: PlaylistSong) -> Self {
+        self.songs.push(song);
+        self
+    }
+    /// Creates a [Playlist][crate::playlist::Playlist] with the latest version of every map
+    ///
+    /// Maps without versions, and maps whose latest version is already in the playlist, are
+    /// skipped.
+    pub fn from_maps<M: Borrow<MapDetail>, I: IntoIterator<Item = M>>(
+        title: &str,
+        maps: I,
+    ) -> Self {
+        let mut playlist = Self::new(title);
+        for map in maps {
+            playlist.add_map(map.borrow());
+        }
+        playlist
+    }
+    /// Adds the latest version of a map, returns `false` if it has no versions or is already in
+    /// the playlist
+    pub fn add_map(&mut self, map: &MapDetail) -> bool {
+        let song = match PlaylistSong::from_map(map) {
+            Some(song) => song,
+            None => return false,
+        };
+        if self
+            .songs
+            .iter()
+            .any(|s| s.hash.eq_ignore_ascii_case(&song.hash))
+        {
+            return false;
+        }
+        self.songs.push(song);
+        true
+    }
+    /// Looks up every song by hash, and updates the songs whose map has a newer version
+    ///
+    /// Songs whose map was deleted are left in the playlist and reported, so they can be removed
+    /// or replaced. Songs updated to a version already in the playlist are removed.
+    #[cfg(feature = "async")]
+    pub async fn refresh<T, C>(&mut self, client: &C) -> Result<RefreshReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiAsync<T> + Send + Sync,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut maps = HashMap::new();
+        for hashes in self.hashes().chunks(MAPS_BY_HASHES_LIMIT) {
+            match client.maps_by_hashes(hashes).await {
+                Ok(found) => maps.extend(found),
+                // A single unknown hash is a 404 rather than a missing entry
+                Err(BeatSaverApiError::HttpError {
+                    status: StatusCode::NOT_FOUND,
+                    ..
+                }) if hashes.len() == 1 => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self.apply_refresh(&maps))
+    }
+    /// Looks up every song by hash, and updates the songs whose map has a newer version
+    ///
+    /// Songs whose map was deleted are left in the playlist and reported, so they can be removed
+    /// or replaced. Songs updated to a version already in the playlist are removed.
+    #[cfg(feature = "sync")]
+    pub fn refresh_blocking<T, C>(
+        &mut self,
+        client: &C,
+    ) -> Result<RefreshReport, BeatSaverApiError<T>>
+    where
+        C: BeatSaverApiSync<T>,
+        T: 'static + Error,
+        BeatSaverApiError<T>: From<T>,
+    {
+        let mut maps = HashMap::new();
+        for hashes in self.hashes().chunks(MAPS_BY_HASHES_LIMIT) {
+            match client.maps_by_hashes(hashes) {
+                Ok(found) => maps.extend(found),
+                // A single unknown hash is a 404 rather than a missing entry
+                Err(BeatSaverApiError::HttpError {
+                    status: StatusCode::NOT_FOUND,
+                    ..
+                }) if hashes.len() == 1 => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self.apply_refresh(&maps))
+    }
+    /// Distinct lowercase hashes of the songs
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn hashes(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.songs
+            .iter()
+            .map(|s| s.hash.to_lowercase())
+            .filter(|h| seen.insert(h.clone()))
+            .collect()
+    }
+    /// Updates the songs from the maps found for their hashes, keyed by lowercase hash
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn apply_refresh(&mut self, maps: &HashMap<String, MapDetail>) -> RefreshReport {
+        let mut report = RefreshReport::default();
+        for song in self.songs.iter_mut() {
+            let map = match maps.get(&song.hash.to_lowercase()) {
+                Some(map) if map.deleted_at.is_none() => map,
+                _ => {
+                    report.deleted.push(song.hash.clone());
+                    continue;
+                }
+            };
+            let latest = match PlaylistSong::from_map(map) {
+                Some(latest) => latest,
+                None => continue,
+            };
+            if !latest.hash.eq_ignore_ascii_case(&song.hash) {
+                report.updated += 1;
+            }
+            song.hash = latest.hash;
+            song.key = latest.key;
+            song.song_name = latest.song_name;
+            song.level_author_name = latest.level_author_name;
+        }
+        let mut seen = HashSet::new();
+        let count = self.songs.len();
+        self.songs.retain(|s| seen.insert(s.hash.to_lowercase()));
+        report.removed = count - self.songs.len();
+        report
+    }
+    /// Checks that the playlist can be loaded by the game
+    ///
+    /// Hashes must be 40 hex characters and unique, keys must be valid
+    /// [MapKeys][crate::MapKey], and difficulties must name a known difficulty.
+    pub fn validate(&self) -> Result<(), PlaylistError> {
+        if self.title.trim().is_empty() {
+            return Err(PlaylistError::MissingTitle);
+        }
+        let mut hashes = HashSet::new();
+        for (i, song) in self.songs.iter().enumerate() {
+            if song.hash.len() != 40 || hex::decode(&song.hash).is_err() {
+                return Err(PlaylistError::InvalidHash(i, song.hash.clone()));
+            }
+            if !hashes.insert(song.hash.to_lowercase()) {
+                return Err(PlaylistError::DuplicateSong(i, song.hash.clone()));
+            }
+            if let Some(key) = &song.key {
+                if crate::MapKey::new(key).is_err() {
+                    return Err(PlaylistError::InvalidKey(i, key.clone()));
+                }
+            }
+            for d in &song.difficulties {
+                if d.characteristic.is_empty()
+                    || !DIFFICULTY_NAMES
+                        .iter()
+                        .any(|n| n.eq_ignore_ascii_case(&d.name))
+                {
+                    return Err(PlaylistError::InvalidDifficulty(
+                        i,
+                        d.characteristic.clone(),
+                        d.name.clone(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Reads and validates a playlist
+    ///
+    /// Fails with [InvalidData][std::io::ErrorKind::InvalidData] if the playlist is invalid.
+    pub fn read<R: Read>(reader: R) -> io::Result<Self> {
+        let playlist: Self = serde_json::from_reader(reader)?;
+        playlist
+            .validate()
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        Ok(playlist)
+    }
+    /// Validates and writes the playlist as JSON
+    ///
+    /// Fails with [InvalidData][std::io::ErrorKind::InvalidData] if the playlist is invalid.
+    pub fn write<W: Write>(&self, writer: W) -> io::Result<()> {
+        self.validate()
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+    /// Validates and encodes the playlist as a `data:` URL, e.g. for download links on web pages
+    pub fn to_data_url(&self) -> io::Result<String> {
+        self.validate()
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        let data = serde_json::to_vec(self)?;
+        Ok(format!("{}{}", DATA_URL_PREFIX, BASE64.encode(data)))
+    }
+    /// Decodes and validates a playlist encoded as a base64 JSON `data:` URL
+    ///
+    /// Fails with [InvalidData][std::io::ErrorKind::InvalidData] if the URL isn't a base64 `data:`
+    /// URL or the playlist is invalid.
+    pub fn from_data_url(url: &str) -> io::Result<Self> {
+        let invalid = |e| io::Error::new(ErrorKind::InvalidData, e);
+        let (header, data) = url
+            .strip_prefix("data:")
+            .and_then(|url| url.split_once(','))
+            .ok_or_else(|| invalid("not a data URL".to_string()))?;
+        if !header.ends_with(";base64") {
+            return Err(invalid("data URL isn't base64 encoded".to_string()));
+        }
+        let data = BASE64.decode(data).map_err(|e| invalid(e.to_string()))?;
+        Self::read(data.as_slice())
+    }
+    /// Loads the playlist at the specified path, see [read][crate::playlist::Playlist::read]
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+    /// Writes the playlist to the specified path, see [write][crate::playlist::Playlist::write]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+}
+
+/// Collects an iterator of maps, such as a [MapIterator][crate::MapIterator], into a
+/// [Playlist][crate::playlist::Playlist]
+pub trait CollectPlaylist<E>: Iterator<Item = Result<MapDetail, E>> + Sized {
+    /// Collects the maps into a playlist, see [Playlist::from_maps][crate::playlist::Playlist::from_maps]
+    ///
+    /// Stops at the first error.
+    fn collect_playlist(self, title: &str) -> Result<Playlist, E> {
+        let mut playlist = Playlist::new(title);
+        for map in self {
+            playlist.add_map(&map?);
+        }
+        Ok(playlist)
+    }
+}
+impl<E, I: Iterator<Item = Result<MapDetail, E>>> CollectPlaylist<E> for I {}
+
+/// Collects a stream of maps, such as a [PageStream][crate::PageStream], into a
+/// [Playlist][crate::playlist::Playlist]
+#[cfg(feature = "async")]
+pub trait CollectPlaylistAsync<'a, E: 'a>:
+    Stream<Item = Result<MapDetail, E>> + Sized + 'a
+{
+    /// Collects the maps into a playlist, see [Playlist::from_maps][crate::playlist::Playlist::from_maps]
+    ///
+    /// Stops at the first error.
+    fn collect_playlist(self, title: &str) -> LocalBoxFuture<'a, Result<Playlist, E>> {
+        let mut playlist = Playlist::new(title);
+        Box::pin(async move {
+            let mut stream = Box::pin(self);
+            while let Some(map) = stream.next().await {
+                playlist.add_map(&map?);
+            }
+            Ok(playlist)
+        })
+    }
+}
+#[cfg(feature = "async")]
+impl<'a, E: 'a, S: Stream<Item = Result<MapDetail, E>> + 'a> CollectPlaylistAsync<'a, E> for S {}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::map::MapDetail;
+    use crate::playlist::{one_click_url, CollectPlaylist, Playlist, PlaylistError, PlaylistSong};
+    use crate::tests::{map_hash, map_json};
+    use std::io::ErrorKind;
+    use url::Url;
+
+    #[test]
+    fn test_playlist_roundtrip() {
+        let map: MapDetail = serde_json::from_str(map_json("2144").as_str()).unwrap();
+        let song = PlaylistSong::from_map(&map).unwrap();
+        assert_eq!(song.key.as_deref(), Some("2144"));
+        let playlist = Playlist::new("Favorites")
+            .with_author("me")
+            .with_song(song.with_difficulty("Standard", "ExpertPlus"));
+        let mut data = vec![];
+        playlist.write(&mut data).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&data).unwrap();
+        assert_eq!(value["playlistTitle"], "Favorites");
+        assert_eq!(value["songs"][0]["songName"], map.metadata.song_name);
+        assert_eq!(value["songs"][0]["difficulties"][0]["name"], "ExpertPlus");
+        assert_eq!(Playlist::read(data.as_slice()).unwrap(), playlist);
+    }
+    #[test]
+    fn test_playlist_read() {
+        let data = format!(
+            r#"{{"playlistTitle":"a","songs":[{{"hash":"{}"}}],"customData":{{"syncURL":"x"}}}}"#,
+            map_hash("1")
+        );
+        let playlist = Playlist::read(data.as_bytes()).unwrap();
+        assert_eq!(playlist.author, "");
+        assert_eq!(playlist.songs[0].key, None);
+        assert_eq!(playlist.custom_data.unwrap()["syncURL"], "x");
+
+        let err = Playlist::read(r#"{"playlistTitle":"a","songs":[{"hash":"x"}]}"#.as_bytes());
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+    #[test]
+    fn test_playlist_from_maps() {
+        let maps: Vec<MapDetail> = ["1", "2", "1"]
+            .iter()
+            .map(|k| serde_json::from_str(map_json(k).as_str()).unwrap())
+            .collect();
+        let playlist = Playlist::from_maps("a", &maps);
+        let keys: Vec<_> = playlist.songs.iter().map(|s| s.key.as_deref()).collect();
+        assert_eq!(keys, [Some("1"), Some("2")]);
+        assert_eq!(Playlist::from_maps("a", maps.clone()), playlist);
+
+        let results = maps.into_iter().map(Ok::<_, ()>);
+        assert_eq!(results.clone().collect_playlist("a"), Ok(playlist));
+        let failing = results.take(1).chain(std::iter::once(Err(())));
+        assert_eq!(failing.collect_playlist("a"), Err(()));
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_collect_playlist_async() {
+        use crate::playlist::CollectPlaylistAsync;
+        use futures::executor::block_on;
+        use futures::stream;
+
+        let maps: Vec<Result<MapDetail, ()>> = ["1", "2"]
+            .iter()
+            .map(|k| Ok(serde_json::from_str(map_json(k).as_str()).unwrap()))
+            .collect();
+        let playlist = block_on(stream::iter(maps).collect_playlist("a")).unwrap();
+        assert_eq!(playlist.title, "a");
+        assert_eq!(playlist.songs.len(), 2);
+        assert_eq!(playlist.songs[1].hash, map_hash("2"));
+    }
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_collect_search() {
+        use crate::client::BeatSaverUreq;
+        use crate::testing::TestServer;
+        use crate::BeatSaverApiSync;
+
+        let server = TestServer::start().unwrap().with_maps(&["1", "2", "3"]);
+        let client = BeatSaverUreq::new().with_base_url(server.url());
+        let playlist = client.search("").take(2).collect_playlist("a").unwrap();
+        assert_eq!(playlist.songs.len(), 2);
+        assert_eq!(playlist.validate(), Ok(()));
+    }
+    /// Playlist with an outdated version of map 2, and a deleted map
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn outdated() -> (Playlist, crate::testing::MockClient) {
+        use crate::testing::MockClient;
+        use crate::BEATSAVER_URL;
+
+        let old = "0".repeat(40);
+        let deleted = "f".repeat(40);
+        let playlist = Playlist::new("a")
+            .with_song(PlaylistSong::new(map_hash("1").as_str()))
+            .with_song(PlaylistSong::new(old.as_str()).with_difficulty("Standard", "Expert"))
+            .with_song(PlaylistSong::new(map_hash("2").as_str()))
+            .with_song(PlaylistSong::new(deleted.as_str()));
+        let hashes = [map_hash("1"), old.clone(), map_hash("2"), deleted.clone()];
+        let url = BEATSAVER_URL.join(format!("maps/hash/{}", hashes.join(",")).as_str());
+        let body = format!(
+            r#"{{"{}":{},"{}":{},"{}":{},"{}":null}}"#,
+            hashes[0],
+            map_json("1"),
+            old,
+            map_json("2"),
+            hashes[2],
+            map_json("2"),
+            deleted
+        );
+        (
+            playlist,
+            MockClient::new().with_response(url.unwrap(), body),
+        )
+    }
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn assert_refreshed(playlist: &Playlist, report: crate::playlist::RefreshReport) {
+        use crate::playlist::RefreshReport;
+
+        assert_eq!(
+            report,
+            RefreshReport {
+                updated: 1,
+                removed: 1,
+                deleted: vec!["f".repeat(40)]
+            }
+        );
+        let keys: Vec<_> = playlist.songs.iter().map(|s| s.key.as_deref()).collect();
+        assert_eq!(keys, [Some("1"), Some("2"), None]);
+        assert_eq!(playlist.songs[1].hash, map_hash("2"));
+        assert_eq!(playlist.songs[1].difficulties.len(), 1);
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_refresh_blocking() {
+        let (mut playlist, client) = outdated();
+        let report = playlist.refresh_blocking(&client).unwrap();
+        assert_refreshed(&playlist, report);
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_refresh_single_deleted() {
+        use crate::testing::MockClient;
+
+        let mut playlist = Playlist::new("a").with_song(PlaylistSong::new(map_hash("1").as_str()));
+        let report = playlist.refresh_blocking(&MockClient::new()).unwrap();
+        assert_eq!(report.deleted, [map_hash("1")]);
+        assert_eq!(playlist.songs.len(), 1);
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_refresh() {
+        use futures::executor::block_on;
+
+        let (mut playlist, client) = outdated();
+        let report = block_on(playlist.refresh(&client)).unwrap();
+        assert_refreshed(&playlist, report);
+    }
+    #[test]
+    fn test_one_click_url() {
+        let url = Url::parse("https://example.com/lists/a.bplist").unwrap();
+        assert_eq!(
+            one_click_url(&url),
+            "bsplaylist://playlist/https://example.com/lists/a.bplist"
+        );
+    }
+    #[test]
+    fn test_data_url() {
+        let playlist = Playlist::new("a").with_song(PlaylistSong::new(map_hash("1").as_str()));
+        let url = playlist.to_data_url().unwrap();
+        assert!(url.starts_with("data:application/json;base64,"));
+        assert_eq!(Playlist::from_data_url(url.as_str()).unwrap(), playlist);
+
+        assert!(Playlist::new("").to_data_url().is_err());
+        for url in &["a.bplist", "data:application/json,{}", "data:;base64,!"] {
+            let err = Playlist::from_data_url(url).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+    }
+    #[test]
+    fn test_playlist_validate() {
+        let song = PlaylistSong::new(map_hash("1").as_str());
+        assert_eq!(
+            Playlist::new(" ").validate(),
+            Err(PlaylistError::MissingTitle)
+        );
+        let playlist = Playlist::new("a").with_song(song.clone());
+        assert_eq!(playlist.validate(), Ok(()));
+        assert!(matches!(
+            playlist.clone().with_song(song.clone()).validate(),
+            Err(PlaylistError::DuplicateSong(1, _))
+        ));
+        assert!(matches!(
+            Playlist::new("a")
+                .with_song(PlaylistSong::new("1234"))
+                .validate(),
+            Err(PlaylistError::InvalidHash(0, _))
+        ));
+        let mut keyed = song.clone();
+        keyed.key = Some("21-44".to_string());
+        assert!(matches!(
+            Playlist::new("a").with_song(keyed).validate(),
+            Err(PlaylistError::InvalidKey(0, _))
+        ));
+        assert_eq!(
+            Playlist::new("a")
+                .with_song(song.clone().with_difficulty("Standard", "expertplus"))
+                .validate(),
+            Ok(())
+        );
+        assert!(matches!(
+            Playlist::new("a")
+                .with_song(song.with_difficulty("Standard", "Impossible"))
+                .validate(),
+            Err(PlaylistError::InvalidDifficulty(0, _, _))
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+//! # Preview
+//!
+//! This module builds links to in-browser map previewers, e.g. for bots replying to map requests
+//! with a clickable preview.
+//!
+//! Maps are opened by key when possible. Hashes are opened through the CDN URL of the version,
+//! since previewers look up keys on BeatSaver themselves.
+//!
+//! Example:
+//! ```
+//! use beatsaver_rs::preview::Previewer;
+//! use std::convert::TryInto;
+//!
+//! let id = "2144".try_into().unwrap();
+//! let url = Previewer::ArcViewer.difficulty_url(&id, "Standard", "ExpertPlus");
+//! assert_eq!(
+//!     url.as_str(),
+//!     "https://allpoland.github.io/ArcViewer/?id=2144&mode=Standard&difficulty=ExpertPlus"
+//! );
+//! ```
+use crate::{cdn_download_url, MapId};
+use lazy_static::lazy_static;
+use url::Url;
+
+lazy_static! {
+    /// URL of [ArcViewer](https://allpoland.github.io/ArcViewer/)
+    pub static ref ARCVIEWER_URL: Url = Url::parse("https://allpoland.github.io/ArcViewer/").unwrap();
+    /// URL of the [skystudioapps](https://skystudioapps.com/bs-viewer/) viewer
+    pub static ref SKYSTUDIO_URL: Url = Url::parse("https://skystudioapps.com/bs-viewer/").unwrap();
+}
+
+/// In-browser map previewer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Previewer {
+    /// [ArcViewer](https://allpoland.github.io/ArcViewer/)
+    ArcViewer,
+    /// [skystudioapps](https://skystudioapps.com/bs-viewer/) viewer
+    SkyStudio,
+}
+impl Previewer {
+    /// Base URL of the previewer
+    pub fn base_url(&self) -> &'static Url {
+        match self {
+            Self::ArcViewer => &ARCVIEWER_URL,
+            Self::SkyStudio => &SKYSTUDIO_URL,
+        }
+    }
+    /// Builds the URL previewing a map, opening its default difficulty
+    pub fn url(&self, id: &MapId) -> Url {
+        let mut url = self.base_url().clone();
+        match id {
+            MapId::Key(key) => url.query_pairs_mut().append_pair("id", key.as_str()),
+            MapId::Hash(hash) => url
+                .query_pairs_mut()
+                .append_pair("url", cdn_download_url(hash).as_str()),
+        };
+        url
+    }
+    /// Builds the URL previewing a difficulty of a map
+    ///
+    /// `characteristic` and `difficulty` are named like in
+    /// [MapDifficulty][crate::model::map::MapDifficulty] (e.g. `Standard` and `ExpertPlus`).
+    pub fn difficulty_url(&self, id: &MapId, characteristic: &str, difficulty: &str) -> Url {
+        let mut url = self.url(id);
+        url.query_pairs_mut()
+            .append_pair("mode", characteristic)
+            .append_pair("difficulty", difficulty);
+        url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::map::MapDetail;
+    use crate::preview::Previewer;
+    use crate::tests::{map_hash, map_json};
+    use crate::MapId;
+    use std::convert::TryInto;
+
+    #[test]
+    fn test_preview_url() {
+        let key: MapId = "2144".try_into().unwrap();
+        assert_eq!(
+            Previewer::ArcViewer.url(&key).as_str(),
+            "https://allpoland.github.io/ArcViewer/?id=2144"
+        );
+        assert_eq!(
+            Previewer::SkyStudio
+                .difficulty_url(&key, "OneSaber", "Expert")
+                .as_str(),
+            "https://skystudioapps.com/bs-viewer/?id=2144&mode=OneSaber&difficulty=Expert"
+        );
+        let hash = MapId::Hash(map_hash("1").to_uppercase());
+        assert_eq!(
+            Previewer::ArcViewer.url(&hash).as_str(),
+            format!(
+                "https://allpoland.github.io/ArcViewer/?url=https%3A%2F%2Fcdn.beatsaver.com%2F{}.zip",
+                map_hash("1")
+            )
+        );
+    }
+    #[test]
+    fn test_map_preview_url() {
+        let map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        assert_eq!(
+            map.preview_url(Previewer::ArcViewer, None).as_str(),
+            "https://allpoland.github.io/ArcViewer/?id=1"
+        );
+        let diff = &map.versions[0].diffs[0];
+        assert_eq!(
+            map.preview_url(Previewer::ArcViewer, Some(diff)).as_str(),
+            "https://allpoland.github.io/ArcViewer/?id=1&mode=Standard&difficulty=Expert"
+        );
+    }
+}
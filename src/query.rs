@@ -0,0 +1,322 @@
+//! # Query
+//!
+//! This module contains typed parameters for the API endpoints that accept query strings.
+use crate::model::map::{MapDetail, Tag};
+use chrono::{DateTime, SecondsFormat, Utc};
+use urlencoding::encode;
+
+/// Field used to order the results of the latest maps endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LatestSort {
+    /// Orders by the time the map was first published (API default)
+    #[default]
+    FirstPublished,
+    /// Orders by the time the map was last updated
+    Updated,
+    /// Orders by the time a version of the map was last published
+    LastPublished,
+    /// Orders by the time the map was created
+    Created,
+    /// Orders by the time the map was curated
+    Curated,
+}
+impl LatestSort {
+    /// Value of the `sort` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FirstPublished => "FIRST_PUBLISHED",
+            Self::Updated => "UPDATED",
+            Self::LastPublished => "LAST_PUBLISHED",
+            Self::Created => "CREATED",
+            Self::Curated => "CURATED",
+        }
+    }
+    /// Timestamp of the map that is compared against `before`/`after` for this ordering
+    pub fn cursor(&self, map: &MapDetail) -> DateTime<Utc> {
+        match self {
+            Self::Updated => map.updated_at,
+            Self::LastPublished => map.last_published_at.unwrap_or(map.uploaded),
+            Self::Created => map.created_at,
+            Self::Curated => map.curated_at.unwrap_or(map.uploaded),
+            Self::FirstPublished => map.uploaded,
+        }
+    }
+}
+
+/// Parameters of the latest maps endpoint
+///
+/// Results are returned newest first, unless `after` is set, in which case they are returned
+/// oldest first starting at `after`.
+///
+/// Example:
+/// ```
+/// use beatsaver_rs::query::{LatestOptions, LatestSort};
+///
+/// let options = LatestOptions::new()
+///     .with_after("2021-06-01T12:00:00Z".parse().unwrap())
+///     .with_sort(LatestSort::Updated)
+///     .with_automapper(false);
+/// assert_eq!(
+///     options.query_string(),
+///     "after=2021-06-01T12%3A00%3A00Z&sort=UPDATED&automapper=false"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LatestOptions {
+    /// Only return maps older than this timestamp
+    pub before: Option<DateTime<Utc>>,
+    /// Only return maps newer than this timestamp
+    pub after: Option<DateTime<Utc>>,
+    /// Field used to order (and filter) the results
+    pub sort: Option<LatestSort>,
+    /// Set to `true` to include automapped maps, `false` to exclude them
+    pub automapper: Option<bool>,
+}
+impl LatestOptions {
+    /// Creates a new [LatestOptions][crate::query::LatestOptions] using the API defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Only return maps older than the specified timestamp
+    pub fn with_before(mut self, before: DateTime<Utc>) -> Self {
+        self.before = Some(before);
+        self
+    }
+    /// Only return maps newer than the specified timestamp
+    pub fn with_after(mut self, after: DateTime<Utc>) -> Self {
+        self.after = Some(after);
+        self
+    }
+    /// Orders the results by the specified field
+    pub fn with_sort(mut self, sort: LatestSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+    /// Includes or excludes automapped maps
+    pub fn with_automapper(mut self, automapper: bool) -> Self {
+        self.automapper = Some(automapper);
+        self
+    }
+    /// Builds the query string for these options, without the leading `?`
+    ///
+    /// Note: timestamps keep their sub-second digits, so maps uploaded within the same second as
+    /// a cursor aren't skipped
+    pub fn query_string(&self) -> String {
+        let timestamp =
+            |t: &DateTime<Utc>| encode(t.to_rfc3339_opts(SecondsFormat::AutoSi, true).as_str());
+        let mut params = vec![];
+        if let Some(before) = &self.before {
+            params.push(format!("before={}", timestamp(before)));
+        }
+        if let Some(after) = &self.after {
+            params.push(format!("after={}", timestamp(after)));
+        }
+        if let Some(sort) = &self.sort {
+            params.push(format!("sort={}", sort.as_str()));
+        }
+        if let Some(automapper) = &self.automapper {
+            params.push(format!("automapper={}", automapper));
+        }
+        params.join("&")
+    }
+    /// Returns the options used to request the results following the provided map
+    ///
+    /// The cursor moves backwards in time, or forwards if `after` is set.
+    pub(crate) fn next(&self, last: &MapDetail) -> Self {
+        let cursor = self.sort.unwrap_or_default().cursor(last);
+        let mut next = self.clone();
+        match self.after {
+            Some(_) => next.after = Some(cursor),
+            None => next.before = Some(cursor),
+        }
+        next
+    }
+}
+
+/// Order of the results of the text search endpoint
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortOrder {
+    /// Newest maps first
+    Latest,
+    /// Best matches first
+    Relevance,
+    /// Highest rated maps first
+    Rating,
+    /// Most recently curated maps first
+    Curated,
+    /// Most recently published maps first
+    FirstPublished,
+}
+impl SortOrder {
+    /// Value of the `sortOrder` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Latest => "Latest",
+            Self::Relevance => "Relevance",
+            Self::Rating => "Rating",
+            Self::Curated => "Curated",
+            Self::FirstPublished => "FirstPublished",
+        }
+    }
+}
+
+/// Parameters of the text search endpoint
+///
+/// Example:
+/// ```
+/// use beatsaver_rs::model::map::Tag;
+/// use beatsaver_rs::query::{SearchOptions, SortOrder};
+///
+/// let options = SearchOptions::new("camellia")
+///     .with_sort(SortOrder::Rating)
+///     .with_tag(Tag::Tech)
+///     .without_tag(Tag::Anime);
+/// assert_eq!(
+///     options.query_string(),
+///     "q=camellia&sortOrder=Rating&tags=tech%2C%21anime"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchOptions {
+    /// Text to search for
+    pub query: String,
+    /// Order of the results, the API defaults to [Relevance][crate::query::SortOrder::Relevance]
+    pub sort: Option<SortOrder>,
+    /// Tags that every map has to have
+    pub tags: Vec<Tag>,
+    /// Tags that no map may have
+    pub excluded_tags: Vec<Tag>,
+    /// Set to `true` to include AI generated maps, `false` to exclude them
+    pub automapper: Option<bool>,
+    /// Minimum notes per second of at least one difficulty
+    pub min_nps: Option<f32>,
+    /// Maximum notes per second of at least one difficulty
+    pub max_nps: Option<f32>,
+}
+impl SearchOptions {
+    /// Creates a new [SearchOptions][crate::query::SearchOptions] searching for the provided text
+    pub fn new<S: Into<String>>(query: S) -> Self {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+    /// Orders the results using the specified [SortOrder][crate::query::SortOrder]
+    pub fn with_sort(mut self, sort: SortOrder) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+    /// Only return maps with the specified tag
+    pub fn with_tag(mut self, tag: Tag) -> Self {
+        self.tags.push(tag);
+        self
+    }
+    /// Only return maps without the specified tag
+    pub fn without_tag(mut self, tag: Tag) -> Self {
+        self.excluded_tags.push(tag);
+        self
+    }
+    /// Includes or excludes maps declared as AI generated
+    pub fn with_automapper(mut self, automapper: bool) -> Self {
+        self.automapper = Some(automapper);
+        self
+    }
+    /// Excludes maps declared as AI generated
+    pub fn without_ai(self) -> Self {
+        self.with_automapper(false)
+    }
+    /// Only return maps with a difficulty of at least the specified notes per second
+    pub fn with_min_nps(mut self, nps: f32) -> Self {
+        self.min_nps = Some(nps);
+        self
+    }
+    /// Only return maps with a difficulty of at most the specified notes per second
+    pub fn with_max_nps(mut self, nps: f32) -> Self {
+        self.max_nps = Some(nps);
+        self
+    }
+    /// Builds the query string for these options, without the leading `?`
+    ///
+    /// Note: urlencodes the query
+    pub fn query_string(&self) -> String {
+        let mut params = vec![format!("q={}", encode(self.query.as_str()))];
+        if let Some(sort) = &self.sort {
+            params.push(format!("sortOrder={}", sort.as_str()));
+        }
+        if let Some(automapper) = &self.automapper {
+            params.push(format!("automapper={}", automapper));
+        }
+        if let Some(nps) = &self.min_nps {
+            params.push(format!("minNps={}", nps));
+        }
+        if let Some(nps) = &self.max_nps {
+            params.push(format!("maxNps={}", nps));
+        }
+        let tags: Vec<String> = self
+            .tags
+            .iter()
+            .map(|t| t.to_string())
+            .chain(self.excluded_tags.iter().map(|t| format!("!{}", t)))
+            .collect();
+        if !tags.is_empty() {
+            params.push(format!("tags={}", encode(tags.join(",").as_str())));
+        }
+        params.join("&")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::map::{MapDetail, Tag};
+    use crate::query::{LatestOptions, LatestSort, SearchOptions, SortOrder};
+    use crate::tests::map_json;
+
+    #[test]
+    fn test_latest_options() {
+        assert_eq!(LatestOptions::new().query_string(), "");
+        let options = LatestOptions::new()
+            .with_before("2021-06-01T12:00:00Z".parse().unwrap())
+            .with_sort(LatestSort::Curated)
+            .with_automapper(true);
+        assert_eq!(
+            options.query_string(),
+            "before=2021-06-01T12%3A00%3A00Z&sort=CURATED&automapper=true"
+        );
+    }
+    #[test]
+    fn test_latest_options_next() {
+        let map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        let options = LatestOptions::new().with_before("2022-01-01T00:00:00Z".parse().unwrap());
+        assert_eq!(options.next(&map).before, Some(map.uploaded));
+
+        let options = LatestOptions::new()
+            .with_after("2020-01-01T00:00:00Z".parse().unwrap())
+            .with_sort(LatestSort::Updated);
+        let next = options.next(&map);
+        assert_eq!(next.after, Some(map.updated_at));
+        assert_eq!(next.before, None);
+    }
+    #[test]
+    fn test_latest_options_cursor_precision() {
+        let mut map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        map.uploaded = "2021-06-01T12:00:00.250Z".parse().unwrap();
+        assert_eq!(
+            LatestOptions::new().next(&map).query_string(),
+            "before=2021-06-01T12%3A00%3A00.250Z"
+        );
+    }
+    #[test]
+    fn test_search_options() {
+        assert_eq!(SearchOptions::new("").query_string(), "q=");
+        let options = SearchOptions::new("shut up")
+            .with_tag(Tag::Pop)
+            .with_tag(Tag::Other("hyperpop".into()));
+        assert_eq!(options.query_string(), "q=shut%20up&tags=pop%2Chyperpop");
+        let options = SearchOptions::new("").with_sort(SortOrder::FirstPublished);
+        assert_eq!(options.query_string(), "q=&sortOrder=FirstPublished");
+        let options = SearchOptions::new("x").without_ai().with_tag(Tag::Tech);
+        assert_eq!(options.query_string(), "q=x&automapper=false&tags=tech");
+        let options = SearchOptions::new("x").with_min_nps(5.0).with_max_nps(7.5);
+        assert_eq!(options.query_string(), "q=x&minNps=5&maxNps=7.5");
+    }
+}
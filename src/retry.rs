@@ -0,0 +1,291 @@
+//! # Retry
+//!
+//! This module contains the retry policy clients apply to requests that fail due to transient
+//! errors, such as 5xx responses or dropped connections.
+//!
+//! Retries are disabled by default, they can be enabled on any client:
+//! ```no_run
+//! # #[cfg(feature = "reqwest_backend")]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaverReqwest;
+//! use beatsaver_rs::retry::RetryPolicy;
+//! use std::time::Duration;
+//!
+//! # fn main() {
+//! let client = BeatSaverReqwest::new().with_retry_policy(
+//!     RetryPolicy::new()
+//!         .with_max_attempts(5)
+//!         .with_base_delay(Duration::from_secs(1)),
+//! );
+//! # }
+//! # }
+//! ```
+use crate::{BeatSaverApiError, RateLimitPolicy, RateLimitTracker};
+use http::StatusCode;
+use std::collections::hash_map::RandomState;
+use std::error::Error;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, ErrorKind};
+use std::time::Duration;
+
+/// Retry behaviour for requests that fail due to transient errors
+///
+/// Requests are retried after 5xx responses, and errors the client classifies as
+/// [transient][crate::BeatSaverApiAsync::is_transient] other than rate limits. Delays grow
+/// exponentially from `base_delay` up to `max_delay`.
+///
+/// Note: only applies to GET requests, rate limits are handled by the
+/// [RateLimitPolicy][crate::RateLimitPolicy]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first request
+    pub max_attempts: usize,
+    /// Delay before the first retry, doubled for every following retry
+    pub base_delay: Duration,
+    /// Upper bound of the delay between two attempts
+    pub max_delay: Duration,
+    /// Randomizes every delay between half and all of its value, so clients that failed at the
+    /// same time don't retry in lockstep
+    pub jitter: bool,
+}
+impl RetryPolicy {
+    /// Creates a new [RetryPolicy][crate::retry::RetryPolicy] that doesn't retry requests
+    ///
+    /// Retries are enabled with [with_max_attempts][crate::retry::RetryPolicy::with_max_attempts].
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the maximum number of attempts, including the first request
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+    /// Sets the delay before the first retry
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+    /// Sets the upper bound of the delay between two attempts
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+    /// Enables or disables the randomization of delays
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+    /// Returns `true` if another attempt can be made after `attempts` attempts
+    pub(crate) fn should_retry(&self, attempts: usize) -> bool {
+        attempts < self.max_attempts
+    }
+    /// Delay before the specified retry (starting at 1)
+    pub fn delay(&self, retry: usize) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1) as u32)
+            .unwrap_or(u32::MAX);
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |d| d.min(self.max_delay));
+        match self.jitter {
+            true => delay / 2 + delay.mul_f64(random_fraction() / 2.0),
+            false => delay,
+        }
+    }
+}
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+/// Attempts made to send a request, checked against the retry and rate limit policies of a client
+pub(crate) struct Attempts<'a> {
+    retry: RetryPolicy,
+    rate_limit: RateLimitPolicy,
+    tracker: Option<&'a RateLimitTracker>,
+    attempts: usize,
+    waits: usize,
+}
+impl<'a> Attempts<'a> {
+    pub(crate) fn new(
+        retry: RetryPolicy,
+        rate_limit: RateLimitPolicy,
+        tracker: Option<&'a RateLimitTracker>,
+    ) -> Self {
+        Self {
+            retry,
+            rate_limit,
+            tracker,
+            attempts: 1,
+            waits: 0,
+        }
+    }
+    /// Returns the delay before the next attempt, `None` once the retry policy is exhausted
+    pub(crate) fn next_retry(&mut self) -> Option<Duration> {
+        if !self.retry.should_retry(self.attempts) {
+            return None;
+        }
+        self.attempts += 1;
+        Some(self.retry.delay(self.attempts - 1))
+    }
+    /// Returns the delay before sending a request that failed with the error again, or the error if
+    /// it can't be retried
+    ///
+    /// Rate limits are waited for as allowed by the rate limit policy, which pauses the other
+    /// requests sharing the tracker too. Other errors are retried if they are `transient`.
+    pub(crate) fn retry_error<T: Error>(
+        &mut self,
+        error: BeatSaverApiError<T>,
+        transient: bool,
+    ) -> Result<Duration, BeatSaverApiError<T>> {
+        match error {
+            BeatSaverApiError::RateLimitError(limit) if self.rate_limit.should_wait(self.waits) => {
+                self.waits += 1;
+                if let Some(tracker) = self.tracker {
+                    tracker.pause(limit.reset_after);
+                }
+                Ok(limit.reset_after)
+            }
+            e @ BeatSaverApiError::RateLimitError(_) => Err(e),
+            e if transient => self.next_retry().ok_or(e),
+            e => Err(e),
+        }
+    }
+    /// Returns how long to wait for a rate limit hit by another request sharing the tracker
+    pub(crate) fn pause(&self) -> Option<Duration> {
+        self.tracker?.paused_for()
+    }
+}
+
+/// Returns a random number in `[0, 1)`, using the random keys of the standard library's hasher
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Returns `true` if the IO error is likely caused by a transient network failure
+pub(crate) fn is_transient_io(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::BrokenPipe
+            | ErrorKind::TimedOut
+            | ErrorKind::UnexpectedEof
+            | ErrorKind::Interrupted
+    )
+}
+
+/// Returns `true` if a request answered with the status can succeed when sent again
+///
+/// Note: 429 responses aren't included, as they are handled by the
+/// [RateLimitPolicy][crate::RateLimitPolicy]
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::REQUEST_TIMEOUT
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::retry::{Attempts, RetryPolicy};
+    use crate::tests::FakeError;
+    use crate::{
+        BeatSaverApiError, BeatSaverRateLimit, RateLimitPolicy, RateLimitTracker, BEATSAVER_URL,
+    };
+    use bytes::Bytes;
+    use chrono::Utc;
+    use http::StatusCode;
+    use std::io::{self, ErrorKind};
+    use std::time::Duration;
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy::new()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500))
+            .with_jitter(false);
+        let delays: Vec<u128> = (1..=5).map(|r| policy.delay(r).as_millis()).collect();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+        assert_eq!(policy.delay(usize::MAX), Duration::from_millis(500));
+
+        let policy = policy.with_jitter(true);
+        for retry in 1..=5 {
+            let delay = policy.delay(retry);
+            assert!(delay >= policy.with_jitter(false).delay(retry) / 2);
+            assert!(delay <= policy.with_jitter(false).delay(retry));
+        }
+    }
+    #[test]
+    fn test_is_retryable() {
+        let reset = io::Error::new(ErrorKind::ConnectionReset, "reset");
+        assert!(BeatSaverApiError::<FakeError>::from(reset).is_retryable());
+        let not_found = io::Error::new(ErrorKind::NotFound, "not found");
+        assert!(!BeatSaverApiError::<FakeError>::from(not_found).is_retryable());
+        assert!(!BeatSaverApiError::<FakeError>::AuthenticationRequired.is_retryable());
+        assert_eq!(
+            BeatSaverApiError::<FakeError>::AuthenticationRequired.retry_after(),
+            None
+        );
+
+        let http_error = |status| BeatSaverApiError::<FakeError>::HttpError {
+            status,
+            url: BEATSAVER_URL.clone(),
+            body: Bytes::new(),
+        };
+        assert!(http_error(StatusCode::BAD_GATEWAY).is_retryable());
+        assert!(http_error(StatusCode::REQUEST_TIMEOUT).is_retryable());
+        assert!(!http_error(StatusCode::NOT_FOUND).is_retryable());
+        assert!(!http_error(StatusCode::FORBIDDEN).is_retryable());
+
+        let limit = BeatSaverApiError::<FakeError>::RateLimitError(BeatSaverRateLimit {
+            reset: Utc::now(),
+            reset_after: Duration::from_secs(3),
+        });
+        assert!(limit.is_retryable());
+        assert_eq!(limit.retry_after(), Some(Duration::from_secs(3)));
+    }
+    #[test]
+    fn test_attempts() {
+        let limit = || {
+            BeatSaverApiError::<FakeError>::RateLimitError(BeatSaverRateLimit {
+                reset: Utc::now(),
+                reset_after: Duration::from_secs(3),
+            })
+        };
+        let tracker = RateLimitTracker::new();
+        let mut attempts = Attempts::new(RetryPolicy::new(), RateLimitPolicy::Fail, Some(&tracker));
+        assert!(attempts.retry_error(limit(), true).is_err());
+        assert_eq!(attempts.next_retry(), None);
+        assert_eq!(attempts.pause(), None);
+
+        let policy = RetryPolicy::new()
+            .with_max_attempts(2)
+            .with_base_delay(Duration::from_millis(100))
+            .with_jitter(false);
+        let mut attempts = Attempts::new(
+            policy,
+            RateLimitPolicy::WaitAndRetry { max_waits: 1 },
+            Some(&tracker),
+        );
+        assert_eq!(
+            attempts.retry_error(limit(), false).unwrap(),
+            Duration::from_secs(3)
+        );
+        assert!(attempts.pause().unwrap() > Duration::from_secs(2));
+        assert!(attempts.retry_error(limit(), false).is_err());
+
+        let reset = || io::Error::new(ErrorKind::ConnectionReset, "reset").into();
+        assert!(attempts.retry_error::<FakeError>(reset(), false).is_err());
+        assert_eq!(
+            attempts.retry_error::<FakeError>(reset(), true).unwrap(),
+            Duration::from_millis(100)
+        );
+        assert!(attempts.retry_error::<FakeError>(reset(), true).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+//! # ScoreSaber
+//!
+//! This module joins ranking information from [ScoreSaber](https://scoresaber.com/) onto maps
+//! retrieved from BeatSaver: whether each difficulty is ranked or qualified, and its star rating.
+//!
+//! Leaderboards are requested for every difficulty of the latest version of a map, without the
+//! credentials attached to the client.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(all(feature = "reqwest_backend", not(feature = "surf_backend"), not(feature = "ureq_backend"), not(feature = "attohttpc_backend")))]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaver;
+//! use beatsaver_rs::scoresaber::ranked_difficulties;
+//! use beatsaver_rs::BeatSaverApiAsync;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BeatSaver::new();
+//!     let mut map = client.map_by_key("2144").await.unwrap();
+//!     client.join_scoresaber(&mut map).await.unwrap();
+//!     for diff in ranked_difficulties(&map) {
+//!         println!("{} {}: {} stars", diff.characteristic, diff.difficulty, diff.stars.unwrap());
+//!     }
+//! }
+//! # }
+//! ```
+use crate::model::map::{MapDetail, MapDifficulty};
+use crate::{response_body, BeatSaverApiError};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::{Response, StatusCode};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use url::Url;
+
+lazy_static! {
+    /// Base URL for the ScoreSaber API
+    pub static ref SCORESABER_URL: Url = Url::parse("https://scoresaber.com/api/").unwrap();
+}
+
+/// Difficulty names, in the order of their ScoreSaber difficulty numbers (1, 3, 5, 7, 9)
+const DIFFICULTIES: &[&str] = &["Easy", "Normal", "Hard", "Expert", "ExpertPlus"];
+
+/// Difficulty a ScoreSaber leaderboard is for
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardDifficulty {
+    /// ID of the leaderboard
+    #[serde(alias = "leaderboardId")]
+    pub leaderboard_id: usize,
+    /// Difficulty number (1 for `Easy` to 9 for `ExpertPlus`)
+    pub difficulty: u8,
+    /// Game mode, the characteristic prefixed with `Solo` (e.g. `SoloStandard`)
+    #[serde(alias = "gameMode")]
+    pub game_mode: String,
+    /// Raw difficulty name (e.g. `_ExpertPlus_SoloStandard`)
+    #[serde(alias = "difficultyRaw")]
+    pub difficulty_raw: String,
+}
+
+/// ScoreSaber leaderboard of a single difficulty
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Leaderboard {
+    /// ID of the leaderboard
+    pub id: usize,
+    /// Hash of the map version, in uppercase
+    #[serde(alias = "songHash")]
+    pub song_hash: String,
+    /// Difficulty of the leaderboard
+    pub difficulty: LeaderboardDifficulty,
+    /// Maximum possible score
+    #[serde(alias = "maxScore")]
+    pub max_score: usize,
+    /// `true` if the difficulty is ranked
+    pub ranked: bool,
+    /// `true` if the difficulty is qualified for ranking
+    pub qualified: bool,
+    /// Star rating, `0` if the difficulty isn't ranked or qualified
+    pub stars: f32,
+    /// Timestamp of the ranking
+    #[serde(alias = "rankedDate", default)]
+    pub ranked_date: Option<DateTime<Utc>>,
+}
+impl Leaderboard {
+    /// Returns `true` if the leaderboard is for the provided difficulty
+    pub fn matches(&self, diff: &MapDifficulty) -> bool {
+        difficulty_number(diff.difficulty.as_str()) == Some(self.difficulty.difficulty)
+            && self.difficulty.game_mode == format!("Solo{}", diff.characteristic)
+    }
+}
+
+/// ScoreSaber difficulty number of a difficulty name
+fn difficulty_number(name: &str) -> Option<u8> {
+    let i = DIFFICULTIES
+        .iter()
+        .position(|d| d.eq_ignore_ascii_case(name))?;
+    Some(i as u8 * 2 + 1)
+}
+
+/// Builds the URL of the leaderboard of a difficulty, `None` if the difficulty name is unknown
+pub(crate) fn leaderboard_url(hash: &str, diff: &MapDifficulty) -> Option<Url> {
+    let number = difficulty_number(diff.difficulty.as_str())?;
+    let path = format!(
+        "leaderboard/by-hash/{}/info?difficulty={}&gameMode=Solo{}",
+        hash.to_uppercase(),
+        number,
+        diff.characteristic
+    );
+    SCORESABER_URL.join(path.as_str()).ok()
+}
+
+/// Parses a leaderboard response, difficulties without a leaderboard return `None`
+pub(crate) fn parse_leaderboard<T: Error>(
+    url: &Url,
+    response: Response<Bytes>,
+) -> Result<Option<Leaderboard>, BeatSaverApiError<T>> {
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    let data = response_body(url, response)?;
+    Ok(Some(serde_json::from_slice(data.as_ref())?))
+}
+
+/// Joins leaderboards onto the latest version of a map
+///
+/// Difficulties get the star rating of their leaderboard if it is ranked or qualified, and no
+/// star rating otherwise. The map is ranked (or qualified) if any of its difficulties is.
+/// Leaderboards for other versions of the map are ignored.
+pub fn join(map: &mut MapDetail, leaderboards: &[Leaderboard]) {
+    let version = match map.versions.iter_mut().max_by_key(|v| v.created_at) {
+        Some(version) => version,
+        None => return,
+    };
+    let hash = version.hash.clone();
+    let leaderboards: Vec<&Leaderboard> = leaderboards
+        .iter()
+        .filter(|l| l.song_hash.eq_ignore_ascii_case(&hash))
+        .collect();
+    for diff in version.diffs.iter_mut() {
+        diff.stars = leaderboards
+            .iter()
+            .find(|l| l.matches(diff))
+            .filter(|l| (l.ranked || l.qualified) && l.stars > 0.0)
+            .map(|l| l.stars);
+    }
+    map.ranked = leaderboards.iter().any(|l| l.ranked);
+    map.qualified = leaderboards.iter().any(|l| l.qualified);
+}
+
+/// Difficulties of the latest version of a map that have a star rating
+pub fn ranked_difficulties(map: &MapDetail) -> impl Iterator<Item = &MapDifficulty> {
+    map.latest_version()
+        .into_iter()
+        .flat_map(|v| v.diffs.iter())
+        .filter(|d| d.stars.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::map::MapDetail;
+    use crate::scoresaber::{join, leaderboard_url, ranked_difficulties, Leaderboard};
+    use crate::tests::{map_hash, map_json};
+
+    /// Fixture map 1, with an `Expert` and an `ExpertPlus` difficulty
+    fn map() -> MapDetail {
+        let mut map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        let mut expert_plus = map.versions[0].diffs[0].clone();
+        expert_plus.difficulty = "ExpertPlus".to_string();
+        map.versions[0].diffs.push(expert_plus);
+        map
+    }
+    /// Leaderboard response for a difficulty of fixture map 1
+    fn leaderboard_json(difficulty: u8, ranked: bool, stars: f32) -> String {
+        format!(
+            r#"{{"id":{difficulty},"songHash":"{hash}","songName":"Song 1","difficulty":{{"leaderboardId":{difficulty},"difficulty":{difficulty},"gameMode":"SoloStandard","difficultyRaw":"_Expert_SoloStandard"}},"maxScore":450000,"ranked":{ranked},"qualified":false,"stars":{stars},"rankedDate":null}}"#,
+            difficulty = difficulty,
+            hash = map_hash("1").to_uppercase(),
+            ranked = ranked,
+            stars = stars
+        )
+    }
+
+    #[test]
+    fn test_leaderboard_url() {
+        let map = map();
+        let url = leaderboard_url(map_hash("1").as_str(), &map.versions[0].diffs[1]).unwrap();
+        assert_eq!(
+            url.as_str(),
+            format!(
+                "https://scoresaber.com/api/leaderboard/by-hash/{}/info?difficulty=9&gameMode=SoloStandard",
+                map_hash("1")
+            )
+        );
+        let mut diff = map.versions[0].diffs[0].clone();
+        diff.difficulty = "Impossible".to_string();
+        assert_eq!(leaderboard_url(map_hash("1").as_str(), &diff), None);
+    }
+    #[test]
+    fn test_join() {
+        let mut map = map();
+        let expert: Leaderboard = serde_json::from_str(&leaderboard_json(7, true, 5.5)).unwrap();
+        let mut other = expert.clone();
+        other.song_hash = map_hash("2");
+        other.difficulty.difficulty = 9;
+        join(&mut map, &[expert, other]);
+        assert!(map.ranked);
+        assert!(!map.qualified);
+        assert_eq!(map.versions[0].diffs[0].stars, Some(5.5));
+        assert_eq!(map.versions[0].diffs[1].stars, None);
+        assert_eq!(ranked_difficulties(&map).count(), 1);
+
+        let unranked: Leaderboard = serde_json::from_str(&leaderboard_json(7, false, 0.0)).unwrap();
+        join(&mut map, &[unranked]);
+        assert!(!map.ranked);
+        assert_eq!(ranked_difficulties(&map).count(), 0);
+    }
+    /// Client answering the `Expert` leaderboard of fixture map 1 as ranked
+    #[cfg(any(feature = "async", feature = "sync"))]
+    fn client() -> crate::testing::MockClient {
+        use crate::testing::MockClient;
+
+        let map = map();
+        let url = leaderboard_url(map_hash("1").as_str(), &map.versions[0].diffs[0]);
+        MockClient::new().with_response(url.unwrap(), leaderboard_json(7, true, 5.5))
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_join_scoresaber_blocking() {
+        use crate::BeatSaverApiSync;
+
+        let client = client();
+        let mut map = map();
+        assert_eq!(client.scoresaber_leaderboards(&map).unwrap().len(), 1);
+        client.join_scoresaber(&mut map).unwrap();
+        assert!(map.ranked);
+        assert_eq!(map.versions[0].diffs[0].stars, Some(5.5));
+        assert_eq!(client.requests().len(), 4);
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_join_scoresaber() {
+        use crate::BeatSaverApiAsync;
+        use futures::executor::block_on;
+
+        let client = client();
+        let mut map = map();
+        block_on(client.join_scoresaber(&mut map)).unwrap();
+        assert!(map.ranked);
+        assert_eq!(ranked_difficulties(&map).count(), 1);
+    }
+}
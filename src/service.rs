@@ -0,0 +1,201 @@
+//! # Service
+//!
+//! This module exposes the API as a [tower Service][tower_service::Service], so existing tower
+//! middleware (timeouts, rate limits, tracing) can be composed around BeatSaver calls.
+//!
+//! Requires the `tower` feature.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(feature = "reqwest_backend")]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaverReqwest;
+//! use beatsaver_rs::service::{ApiRequest, ApiResponse, BeatSaverService};
+//! use std::convert::TryInto;
+//! use tower_service::Service;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let mut service = BeatSaverService::new(BeatSaverReqwest::new());
+//!     let request = ApiRequest::Map("2144".try_into().unwrap());
+//!     if let ApiResponse::Map(map) = service.call(request).await.unwrap() {
+//!         println!("{}", map.name);
+//!     }
+//! }
+//! # }
+//! ```
+use crate::model::map::MapDetail;
+use crate::model::user::UserDetail;
+use crate::query::LatestOptions;
+use crate::{BeatSaverApiAsync, BeatSaverApiError, MapId, Page};
+use bytes::Bytes;
+use futures::Future;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower_service::Service;
+
+/// Typed request handled by [BeatSaverService][crate::service::BeatSaverService]
+///
+/// Each variant corresponds to the [BeatSaverApiAsync][crate::BeatSaverApiAsync] method of the same name
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiRequest {
+    /// Gets a map from a given [MapId][crate::MapId]
+    Map(MapId),
+    /// Retrieves maps created by a specified beatsaver user id, specifying a page number
+    MapsByPage(usize, usize),
+    /// Retrieves all maps sorted by rating, specifying a page number
+    MapsRatingPage(usize),
+    /// Retrieves a single batch of the latest maps matching the provided options
+    MapsLatestPage(LatestOptions),
+    /// Retrieves curated maps sorted by curation time, specifying a page number
+    MapsCuratedPage(usize),
+    /// Retrieves all maps sorted by number of plays, specifying a page number
+    MapsPlaysPage(usize),
+    /// Retrieves info on a specified beatsaver user id
+    User(usize),
+    /// Retrieves maps based on a specified search query, specifying a page number
+    SearchPage(String, usize),
+    /// Retrieves maps based on an advanced search query, specifying a page number
+    SearchAdvancedPage(String, usize),
+    /// Downloads a provided map
+    Download(MapId),
+}
+
+/// Response returned by [BeatSaverService][crate::service::BeatSaverService]
+#[derive(Debug, Clone)]
+pub enum ApiResponse {
+    /// Response to [ApiRequest::Map][crate::service::ApiRequest::Map]
+    Map(Box<MapDetail>),
+    /// Response to any of the paged requests
+    Page(Page<MapDetail>),
+    /// Response to [ApiRequest::MapsLatestPage][crate::service::ApiRequest::MapsLatestPage]
+    Maps(Vec<MapDetail>),
+    /// Response to [ApiRequest::User][crate::service::ApiRequest::User]
+    User(Box<UserDetail>),
+    /// Response to [ApiRequest::Download][crate::service::ApiRequest::Download]
+    Download(Bytes),
+}
+
+/// [tower Service][tower_service::Service] wrapping an asynchronous client
+pub struct BeatSaverService<C, E> {
+    client: Arc<C>,
+    _error: PhantomData<fn() -> E>,
+}
+impl<C, E> BeatSaverService<C, E> {
+    /// Creates a new [BeatSaverService][crate::service::BeatSaverService] wrapping the provided client
+    pub fn new(client: C) -> Self {
+        Self::from(Arc::new(client))
+    }
+}
+impl<C, E> From<Arc<C>> for BeatSaverService<C, E> {
+    fn from(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _error: PhantomData,
+        }
+    }
+}
+impl<C, E> Clone for BeatSaverService<C, E> {
+    fn clone(&self) -> Self {
+        Self::from(self.client.clone())
+    }
+}
+impl<C: fmt::Debug, E> fmt::Debug for BeatSaverService<C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BeatSaverService")
+            .field("client", &self.client)
+            .finish()
+    }
+}
+impl<C, E> Service<ApiRequest> for BeatSaverService<C, E>
+where
+    C: BeatSaverApiAsync<E> + Send + Sync + 'static,
+    E: Error + Send + 'static,
+    BeatSaverApiError<E>: From<E>,
+{
+    type Response = ApiResponse;
+    type Error = BeatSaverApiError<E>;
+    type Future = Pin<Box<dyn Future<Output = Result<ApiResponse, BeatSaverApiError<E>>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+    fn call(&mut self, request: ApiRequest) -> Self::Future {
+        let client = self.client.clone();
+        Box::pin(async move {
+            let response = match request {
+                ApiRequest::Map(id) => ApiResponse::Map(Box::new(client.map(&id).await?)),
+                ApiRequest::MapsByPage(user, page) => {
+                    ApiResponse::Page(client.maps_by_page(user, page).await?)
+                }
+                ApiRequest::MapsRatingPage(page) => {
+                    ApiResponse::Page(client.maps_rating_page(page).await?)
+                }
+                ApiRequest::MapsLatestPage(options) => {
+                    ApiResponse::Maps(client.maps_latest_page(&options).await?)
+                }
+                ApiRequest::MapsCuratedPage(page) => {
+                    ApiResponse::Page(client.maps_curated_page(page).await?)
+                }
+                ApiRequest::MapsPlaysPage(page) => {
+                    ApiResponse::Page(client.maps_plays_page(page).await?)
+                }
+                ApiRequest::User(id) => ApiResponse::User(Box::new(client.user(id).await?)),
+                ApiRequest::SearchPage(query, page) => {
+                    ApiResponse::Page(client.search_page(&query, page).await?)
+                }
+                ApiRequest::SearchAdvancedPage(query, page) => {
+                    ApiResponse::Page(client.search_advanced_page(&query, page).await?)
+                }
+                ApiRequest::Download(id) => ApiResponse::Download(client.download(id).await?),
+            };
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tokio")]
+mod tests {
+    use crate::service::{ApiRequest, ApiResponse, BeatSaverService};
+    use crate::tests::FakeClient;
+    use crate::BEATSAVER_URL;
+    use std::convert::TryInto;
+    use tower_service::Service;
+
+    #[tokio::test]
+    async fn test_service_user() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("users/id/4285984").unwrap(),
+            r#"{"id":4285984,"name":"qwerty01","avatar":"https://cdn.beatsaver.com/avatar/4285984.png"}"#
+                .into(),
+        );
+        let mut service = BeatSaverService::new(client);
+
+        match service.call(ApiRequest::User(4285984)).await.unwrap() {
+            ApiResponse::User(u) => assert_eq!(u.name, "qwerty01"),
+            r => panic!("unexpected response: {:?}", r),
+        }
+    }
+    #[tokio::test]
+    async fn test_service_download() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("download/key/1").unwrap(),
+            "map #1".into(),
+        );
+        let mut service = BeatSaverService::new(client);
+
+        match service
+            .call(ApiRequest::Download("1".try_into().unwrap()))
+            .await
+            .unwrap()
+        {
+            ApiResponse::Download(d) => assert_eq!(d, "map #1"),
+            r => panic!("unexpected response: {:?}", r),
+        }
+    }
+}
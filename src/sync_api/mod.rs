@@ -1,13 +1,53 @@
 #![cfg(feature = "sync")]
-use crate::map::Map;
-use crate::{BeatSaverApiError, BeatSaverUser, MapId, Page, BEATSAVER_URL};
+#[cfg(feature = "hash")]
+use crate::archive::Archive;
+use crate::auth::{authorize, Credentials, OAuthConfig, OAuthToken, TokenResponse, TOKEN_URL};
+#[cfg(feature = "beatleader")]
+use crate::beatleader;
+#[cfg(feature = "bsaber")]
+use crate::bsaber::{curated_url, parse_curated, CuratedPage};
+use crate::cache::{update, CachedResponse, Lookup, ResponseCache};
+#[cfg(feature = "hash")]
+use crate::hash::verify;
+use crate::index::KeyIndex;
+#[cfg(feature = "hash")]
+use crate::install::{extract_map, installed_hashes, InstallOutcome, InstallTarget};
+#[cfg(feature = "hash")]
+use crate::library::{reconcile, scan, Reconciliation};
+use crate::lucene::validate;
+use crate::model::map::{MapDetail, MapVersion};
+use crate::model::playlist::{PlaylistDetail, PlaylistPage};
+use crate::model::testplay::{ActionResponse, TestplayFeedbackRequest, TestplayStateRequest};
+use crate::model::user::{UserDetail, UserVerifyRequest, UserVerifyResponse};
+use crate::model::vote::VoteSummary;
+use crate::query::{LatestOptions, SearchOptions, SortOrder};
+use crate::retry::{is_retryable_status, Attempts, RetryPolicy};
+#[cfg(feature = "scoresaber")]
+use crate::scoresaber::{join, leaderboard_url, parse_leaderboard, Leaderboard};
+#[cfg(any(feature = "hash", feature = "bsaber"))]
+use crate::MAPS_BY_HASHES_LIMIT;
+use crate::{
+    cdn_download_url, content_length, download_url, get_request, map_url, maps_by_hashes_url,
+    maps_by_ids_url, parse_map, parse_maps_by_hashes, post_request, response_body, status_error,
+    track_rate_limit, BeatSaverApiError, DownloadOutcome, DownloadRoute, IterErrorPolicy, MapId,
+    MapKey, Page, PageProgress, RateLimitPolicy, RateLimitStatus, RateLimitTracker, BEATSAVER_URL,
+};
 use bytes::Bytes;
-use hex;
+use chrono::{DateTime, SecondsFormat, Utc};
+use http::{HeaderMap, Request, Response, StatusCode};
 use serde::Serialize;
-use serde_json;
-use std::collections::VecDeque;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::convert::From;
 use std::error::Error;
+use std::io::{Cursor, ErrorKind, Read, Write};
+#[cfg(feature = "hash")]
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
 use url::Url;
 use urlencoding::encode;
 
@@ -19,6 +59,11 @@ where
 {
     curr: Page<T>,
     next_page: Box<F>,
+    policy: IterErrorPolicy,
+    /// Number of times the page being requested failed in a row
+    failures: usize,
+    current_page: Option<usize>,
+    yielded: usize,
 }
 
 impl<T: Serialize, E: Error, F> Iterator for PageIterator<T, E, F>
@@ -29,1194 +74,2843 @@ where
     type Item = Result<T, BeatSaverApiError<E>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.curr.docs.is_empty() {
-            // We're at the end of the current page
-            self.curr = match self.curr.next_page {
-                Some(n) => {
-                    let next = self.next_page.as_ref();
-                    match next(n) {
-                        Ok(s) => s,
-                        Err(e) => return Some(Err(e.into())),
-                    }
-                }
-                None => return None,
-            };
+        if let Err(e) = self.advance()? {
+            return Some(Err(e));
         }
+        self.yielded += 1;
         let item = self.curr.docs.pop_front().unwrap();
         Some(Ok(item))
     }
 }
 
-/// API trait for synchronous clients
-pub trait BeatSaverApiSync<'a, T: 'a + Error>
+impl<T: Serialize, E: Error, F> PageIterator<T, E, F>
 where
-    BeatSaverApiError<T>: From<T>,
+    BeatSaverApiError<E>: From<E>,
+    F: Fn(usize) -> Result<Page<T>, BeatSaverApiError<E>> + ?Sized,
 {
-    /// Executes a raw request to the provided [Url][url::Url]
-    ///
-    /// Make sure to handle 429 (pass the data to [rate_limit][crate::rate_limit])
-    fn request_raw(&'a self, url: Url) -> Result<Bytes, BeatSaverApiError<T>>;
-    /// Executes a request and converts the result into a [String][std::string::String]
-    fn request(&'a self, url: Url) -> Result<String, BeatSaverApiError<T>> {
-        let data = self.request_raw(url)?;
-        Ok(String::from_utf8(data.as_ref().to_vec())?)
+    /// Sets how failed page requests are handled, defaults to [IterErrorPolicy::RetryForever]
+    pub fn error_policy(mut self, policy: IterErrorPolicy) -> Self {
+        self.policy = policy;
+        self
     }
-    /// Gets a map from a given [MapId][crate::MapId]
-    fn map(&'a self, id: &'a MapId) -> Result<Map, BeatSaverApiError<T>> {
-        let data = match id {
-            MapId::Key(k) => self.request(
-                BEATSAVER_URL
-                    .join(format!("api/maps/detail/{:x}", k).as_str())
-                    .unwrap(),
-            )?,
-            MapId::Hash(h) => self.request(
-                BEATSAVER_URL
-                    .join(format!("api/maps/by-hash/{}", h).as_str())
-                    .unwrap(),
-            )?,
-        };
-
-        Ok(serde_json::from_str(data.as_str())?)
+    /// Progress through the results, as of the last page received
+    pub fn progress(&self) -> PageProgress {
+        PageProgress {
+            yielded: self.yielded,
+            total_docs: self.curr.total_docs,
+            current_page: self.current_page,
+            last_page: self.curr.last_page,
+        }
     }
-    /// Retrieves maps created by a specified beatsaver user
-    fn maps_by(
-        &'a self,
-        user: &'a BeatSaverUser,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        self.maps_by_page_iter(user, 0)
+    /// Requests pages until one has documents, following the error policy
+    fn advance(&mut self) -> Option<Result<(), BeatSaverApiError<E>>> {
+        while self.curr.docs.is_empty() {
+            // We're at the end of the current page
+            let n = self.curr.next_page?;
+            let e = match (self.next_page)(n) {
+                Ok(page) => {
+                    self.curr = page;
+                    self.failures = 0;
+                    self.current_page = Some(n);
+                    continue;
+                }
+                Err(e) => e,
+            };
+            match self.policy {
+                // The failed page is requested again on the next call
+                IterErrorPolicy::RetryForever => {}
+                IterErrorPolicy::Retry(retries) if self.failures < retries => {
+                    self.failures += 1;
+                    continue;
+                }
+                IterErrorPolicy::FailFast | IterErrorPolicy::Retry(_) => self.curr.next_page = None,
+                IterErrorPolicy::SkipPage => {
+                    let last = self.curr.last_page;
+                    self.curr.next_page = Some(n + 1).filter(|&n| last.is_none_or(|l| n <= l));
+                }
+            }
+            return Some(Err(e));
+        }
+        Some(Ok(()))
     }
-    /// Retrieves maps created by a specified beatsaver user, specifying a page number
-    fn maps_by_page(
-        &'a self,
-        user: &BeatSaverUser,
-        page: usize,
-    ) -> Result<Page<Map>, BeatSaverApiError<T>> {
-        let url = BEATSAVER_URL
-            .join(format!("api/maps/uploader/{}/", user.id).as_str())
-            .unwrap();
-        let data = self.request(url.join(page.to_string().as_str()).unwrap())?;
-        Ok(serde_json::from_str(data.as_str())?)
+    /// Converts the iterator into an iterator over whole pages, e.g. for bulk inserts
+    ///
+    /// Empty pages (such as the one marking the end of endpoints that don't report totals) are
+    /// skipped. Documents already yielded by the iterator aren't included.
+    pub fn pages(self) -> PagesIterator<T, E, F> {
+        PagesIterator(self)
     }
-    /// Retrieves maps created by a specified beatsaver user, specifying a page number, iterable
-    fn maps_by_page_iter(
-        &'a self,
-        user: &'a BeatSaverUser,
-        page: usize,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        let page = Page {
-            docs: VecDeque::<Map>::new(),
-            total_docs: 0,
-            last_page: 0,
-            prev_page: None,
-            next_page: Some(page),
-        };
+}
+
+/// Iterator over whole pages, as returned by [PageIterator::pages][crate::PageIterator::pages]
+pub struct PagesIterator<T: Serialize, E: Error, F>(PageIterator<T, E, F>)
+where
+    BeatSaverApiError<E>: From<E>,
+    F: Fn(usize) -> Result<Page<T>, BeatSaverApiError<E>> + ?Sized;
 
-        let next = move |p| self.maps_by_page(user, p);
+impl<T: Serialize, E: Error, F> Iterator for PagesIterator<T, E, F>
+where
+    BeatSaverApiError<E>: From<E>,
+    F: Fn(usize) -> Result<Page<T>, BeatSaverApiError<E>> + ?Sized,
+{
+    type Item = Result<Page<T>, BeatSaverApiError<E>>;
 
-        PageIterator {
-            curr: page,
-            next_page: Box::new(next),
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = &mut self.0;
+        if let Err(e) = iter.advance()? {
+            return Some(Err(e));
         }
+        iter.yielded += iter.curr.docs.len();
+        let curr = &mut iter.curr;
+        Some(Ok(Page {
+            docs: std::mem::take(&mut curr.docs),
+            total_docs: curr.total_docs,
+            last_page: curr.last_page,
+            prev_page: curr.prev_page,
+            next_page: curr.next_page,
+        }))
     }
-    /// Retrieves the current hot maps on beatsaver
-    fn maps_hot(
-        &'a self,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        self.maps_hot_page_iter(0)
-    }
-    /// Retrieves the current hot maps on beatsaver, specifying a page number
-    fn maps_hot_page(&'a self, page: usize) -> Result<Page<Map>, BeatSaverApiError<T>> {
-        let url = BEATSAVER_URL.join("api/maps/hot/").unwrap();
-        let data = self.request(url.join(page.to_string().as_str()).unwrap())?;
-        Ok(serde_json::from_str(data.as_str())?)
-    }
-    /// Retrieves the current hot maps on beatsaver, specifying a page number, iterable
-    fn maps_hot_page_iter(
-        &'a self,
-        page: usize,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        let page = Page {
-            docs: VecDeque::<Map>::new(),
-            total_docs: 0,
-            last_page: 0,
-            prev_page: None,
-            next_page: Some(page),
-        };
+}
 
-        let next = move |p| self.maps_hot_page(p);
+/// Size of the buffer used when streaming downloads
+const DOWNLOAD_CHUNK_SIZE: usize = 16 * 1024;
 
-        PageIterator {
-            curr: page,
-            next_page: Box::new(next),
-        }
+type PageFn<'a, T, E> = dyn Fn(usize) -> Result<Page<T>, BeatSaverApiError<E>> + 'a;
+
+/// Iterator over maps, as returned by the paged API methods
+pub type MapIterator<'a, E> = PageIterator<MapDetail, E, PageFn<'a, MapDetail, E>>;
+
+/// Iterator over playlists, as returned by the paged playlist API methods
+pub type PlaylistIterator<'a, E> = PageIterator<PlaylistDetail, E, PageFn<'a, PlaylistDetail, E>>;
+
+/// Iterator over the latest playlists, as returned by
+/// [playlists_latest][crate::BeatSaverApiSync::playlists_latest]
+pub type LatestPlaylistIterator<'a, E> =
+    Box<dyn Iterator<Item = Result<PlaylistDetail, BeatSaverApiError<E>>> + 'a>;
+
+fn iterate_page<'a, T: Serialize, E: Error>(
+    f: Box<PageFn<'a, T, E>>,
+    initial: usize,
+) -> PageIterator<T, E, PageFn<'a, T, E>>
+where
+    BeatSaverApiError<E>: From<E>,
+{
+    let page = Page {
+        docs: VecDeque::new(),
+        total_docs: None,
+        last_page: None,
+        prev_page: None,
+        next_page: Some(initial),
+    };
+    PageIterator {
+        curr: page,
+        next_page: f,
+        policy: IterErrorPolicy::default(),
+        failures: 0,
+        current_page: None,
+        yielded: 0,
     }
-    /// Retrieves all maps sorted by rating
-    fn maps_rating(
-        &'a self,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        self.maps_rating_page_iter(0)
+}
+
+/// Finds the last page with documents, `None` if the first page is empty
+///
+/// Uses the totals reported by the API when available, otherwise doubles the page number until an
+/// empty page is found and binary searches for the last page before it.
+fn find_last_page<T: Serialize, E: Error>(
+    f: &PageFn<'_, T, E>,
+) -> Result<Option<usize>, BeatSaverApiError<E>> {
+    let first = f(0)?;
+    if first.docs.is_empty() {
+        return Ok(None);
     }
-    /// Retrieves all maps sorted by rating, specifying a page number
-    fn maps_rating_page(&'a self, page: usize) -> Result<Page<Map>, BeatSaverApiError<T>> {
-        let url = BEATSAVER_URL.join("api/maps/rating/").unwrap();
-        let data = self.request(url.join(page.to_string().as_str()).unwrap())?;
-        Ok(serde_json::from_str(data.as_str())?)
+    if first.last_page.is_some() {
+        return Ok(first.last_page);
     }
-    /// Retrieves all maps sorted by rating, specifying a page number, iterable
-    fn maps_rating_page_iter(
-        &'a self,
-        page: usize,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        let page = Page {
-            docs: VecDeque::<Map>::new(),
-            total_docs: 0,
-            last_page: 0,
-            prev_page: None,
-            next_page: Some(page),
-        };
-
-        let next = move |p| self.maps_rating_page(p);
-
-        PageIterator {
-            curr: page,
-            next_page: Box::new(next),
+    let (mut low, mut high) = (0, 1);
+    loop {
+        let page = f(high)?;
+        if page.docs.is_empty() {
+            break;
         }
+        if page.last_page.is_some() {
+            return Ok(page.last_page);
+        }
+        low = high;
+        high *= 2;
     }
-    /// Retrieves all maps sorted by upload time
-    fn maps_latest(
-        &'a self,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        self.maps_latest_page_iter(0)
-    }
-    /// Retrieves all maps sorted by upload time, specifying a page number
-    fn maps_latest_page(&'a self, page: usize) -> Result<Page<Map>, BeatSaverApiError<T>> {
-        let url = BEATSAVER_URL.join("api/maps/latest/").unwrap();
-        let data = self.request(url.join(page.to_string().as_str()).unwrap())?;
-        Ok(serde_json::from_str(data.as_str())?)
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        match f(mid)?.docs.is_empty() {
+            true => high = mid,
+            false => low = mid,
+        }
     }
-    /// Retrieves all maps sorted by upload time, specifying a page number
-    fn maps_latest_page_iter(
-        &'a self,
-        page: usize,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        let page = Page {
-            docs: VecDeque::<Map>::new(),
-            total_docs: 0,
-            last_page: 0,
-            prev_page: None,
-            next_page: Some(page),
-        };
+    Ok(Some(low))
+}
 
-        let next = move |p| self.maps_latest_page(p);
+/// Finds the last page with documents and walks the pages backwards from it
+///
+/// Pages are numbered from the last page, and their documents are reversed.
+fn iterate_page_rev<'a, T: Serialize + 'a, E: Error + 'a>(
+    f: Box<PageFn<'a, T, E>>,
+) -> Result<PageIterator<T, E, PageFn<'a, T, E>>, BeatSaverApiError<E>>
+where
+    BeatSaverApiError<E>: From<E>,
+{
+    let (last, first) = match find_last_page(f.as_ref())? {
+        Some(last) => (last, Some(0)),
+        None => (0, None),
+    };
+    let mut iter = iterate_page(Box::new(move |i| Ok(f(last - i)?.reversed(i, last))), 0);
+    iter.curr.next_page = first;
+    Ok(iter)
+}
 
-        PageIterator {
-            curr: page,
-            next_page: Box::new(next),
+/// Calls `f` on every id using up to `concurrency` threads, returning results in the order of `ids`
+fn for_each_concurrent<'a, R, F>(ids: &'a [MapId], concurrency: usize, f: F) -> Vec<(MapId, R)>
+where
+    R: Send,
+    F: Fn(&'a MapId) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(ids.len()));
+    thread::scope(|s| {
+        for _ in 0..concurrency.clamp(1, ids.len().max(1)) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, atomic::Ordering::Relaxed);
+                let id = match ids.get(i) {
+                    Some(id) => id,
+                    None => break,
+                };
+                let result = f(id);
+                results.lock().unwrap().push((i, id.clone(), result));
+            });
         }
-    }
-    /// Retrieves all maps sorted by total downloads
-    fn maps_downloads(
-        &'a self,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        self.maps_downloads_page_iter(0)
-    }
-    /// Retrieves all maps sorted by total downloads, specifying a page number
-    fn maps_downloads_page(&'a self, page: usize) -> Result<Page<Map>, BeatSaverApiError<T>> {
-        let url = BEATSAVER_URL.join("api/maps/downloads/").unwrap();
-        let data = self.request(url.join(page.to_string().as_str()).unwrap())?;
-        Ok(serde_json::from_str(data.as_str())?)
-    }
-    /// Retrieves all maps sorted by total downloads, specifying a page number, iterable
-    fn maps_downloads_page_iter(
-        &'a self,
-        page: usize,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        let page = Page {
-            docs: VecDeque::<Map>::new(),
-            total_docs: 0,
-            last_page: 0,
-            prev_page: None,
-            next_page: Some(page),
-        };
+    });
 
-        let next = move |p| self.maps_downloads_page(p);
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(i, _, _)| *i);
+    results
+        .into_iter()
+        .map(|(_, id, result)| (id, result))
+        .collect()
+}
 
-        PageIterator {
-            curr: page,
-            next_page: Box::new(next),
+/// Sends a GET request to the provided [Url][url::Url] until its response doesn't need to be retried
+///
+/// Rate limits and transient failures are handled according to the policies of the client, other
+/// responses are returned as is.
+fn send_get<T, C>(
+    client: &C,
+    url: &Url,
+    headers: &HeaderMap,
+    credentials: Option<&Credentials>,
+) -> Result<Response<Bytes>, BeatSaverApiError<T>>
+where
+    T: 'static + Error,
+    BeatSaverApiError<T>: From<T>,
+    C: BeatSaverApiSync<T> + ?Sized,
+{
+    let mut attempts = Attempts::new(
+        client.retry_policy(),
+        client.rate_limit_policy(),
+        client.rate_limit_tracker(),
+    );
+    loop {
+        if let Some(pause) = attempts.pause() {
+            client.sleep(pause);
         }
-    }
-    /// Retrieves all maps sorted by number of plays
-    fn maps_plays(
-        &'a self,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        self.maps_plays_page_iter(0)
-    }
-    /// Retrieves all maps sorted by number of plays, specifying a page number
-    fn maps_plays_page(&'a self, page: usize) -> Result<Page<Map>, BeatSaverApiError<T>> {
-        let url = BEATSAVER_URL.join("api/maps/plays/").unwrap();
-        let data = self.request(url.join(page.to_string().as_str()).unwrap())?;
-        Ok(serde_json::from_str(data.as_str())?)
-    }
-    /// Retrieves all maps sorted by number of plays, specifying a page number
-    fn maps_plays_page_iter(
-        &'a self,
-        page: usize,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        let page = Page {
-            docs: VecDeque::<Map>::new(),
-            total_docs: 0,
-            last_page: 0,
-            prev_page: None,
-            next_page: Some(page),
+        let mut request = authorize(get_request(url.clone()), credentials);
+        request.headers_mut().extend(headers.clone());
+        let delay = match client.send(request) {
+            Ok(response) => {
+                track_rate_limit(client.rate_limit_tracker(), response.headers());
+                match response.status() {
+                    StatusCode::TOO_MANY_REQUESTS => {
+                        attempts.retry_error(status_error(url, response), false)?
+                    }
+                    status if is_retryable_status(status) => match attempts.next_retry() {
+                        Some(delay) => delay,
+                        None => return Ok(response),
+                    },
+                    _ => return Ok(response),
+                }
+            }
+            Err(e) => {
+                let transient = client.is_transient(&e);
+                attempts.retry_error(e, transient)?
+            }
         };
+        client.sleep(delay);
+    }
+}
+
+type LatestFn<'a, E> = dyn Fn(&LatestOptions) -> Result<Vec<MapDetail>, BeatSaverApiError<E>> + 'a;
+
+/// Iterator over the latest maps, following the `before` (or `after`) cursor past the last map received
+pub struct LatestIterator<'a, E: Error>
+where
+    BeatSaverApiError<E>: From<E>,
+{
+    curr: VecDeque<MapDetail>,
+    next: Option<LatestOptions>,
+    next_page: Box<LatestFn<'a, E>>,
+}
 
-        let next = move |p| self.maps_plays_page(p);
+impl<'a, E: Error> Iterator for LatestIterator<'a, E>
+where
+    BeatSaverApiError<E>: From<E>,
+{
+    type Item = Result<MapDetail, BeatSaverApiError<E>>;
 
-        PageIterator {
-            curr: page,
-            next_page: Box::new(next),
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.curr.is_empty() {
+            // We're at the end of the current batch
+            let options = self.next.take()?;
+            match (self.next_page)(&options) {
+                Ok(maps) => {
+                    self.next = maps.last().map(|m| options.next(m));
+                    self.curr = maps.into();
+                }
+                Err(e) => {
+                    self.next = Some(options);
+                    return Some(Err(e));
+                }
+            }
         }
+        self.curr.pop_front().map(Ok)
     }
-    /// Retrieves info on a specified beatsaber user
-    fn user(&'a self, id: String) -> Result<BeatSaverUser, BeatSaverApiError<T>> {
-        if id.len() != 24 || hex::decode(&id).is_err() {
-            return Err(BeatSaverApiError::ArgumentError("id"));
+}
+
+impl<'a, E: Error> LatestIterator<'a, E>
+where
+    BeatSaverApiError<E>: From<E>,
+{
+    /// Converts the iterator into an iterator over whole batches, e.g. for bulk inserts
+    ///
+    /// Batches are numbered from 0 in the order they are retrieved. Maps already yielded by the
+    /// iterator aren't included.
+    pub fn pages(self) -> LatestPagesIterator<'a, E> {
+        LatestPagesIterator {
+            iter: self,
+            batch: 0,
         }
-        let data = self.request(
-            BEATSAVER_URL
-                .join(format!("api/users/find/{}", id).as_str())
-                .unwrap(),
-        )?;
+    }
+}
 
-        Ok(serde_json::from_str(data.as_str())?)
+/// Iterator over whole batches of the latest maps, as returned by
+/// [maps_latest_pages][crate::BeatSaverApiSync::maps_latest_pages]
+pub struct LatestPagesIterator<'a, E: Error>
+where
+    BeatSaverApiError<E>: From<E>,
+{
+    iter: LatestIterator<'a, E>,
+    batch: usize,
+}
+
+impl<'a, E: Error> Iterator for LatestPagesIterator<'a, E>
+where
+    BeatSaverApiError<E>: From<E>,
+{
+    type Item = Result<Page<MapDetail>, BeatSaverApiError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = &mut self.iter;
+        while iter.curr.is_empty() {
+            let options = iter.next.take()?;
+            match (iter.next_page)(&options) {
+                Ok(maps) => {
+                    iter.next = maps.last().map(|m| options.next(m));
+                    iter.curr = maps.into();
+                }
+                Err(e) => {
+                    iter.next = Some(options);
+                    return Some(Err(e));
+                }
+            }
+        }
+        let page = Page::from_docs(std::mem::take(&mut iter.curr), self.batch);
+        self.batch += 1;
+        Some(Ok(page))
     }
-    /// Retrieves maps based on a specified search query
+}
+
+/// API trait for synchronous clients
+///
+/// Backends only need to implement [send][crate::BeatSaverApiSync::send], which allows any
+/// HTTP library to be adapted with a few lines. Implementing
+/// [HttpBackendSync][crate::client::HttpBackendSync] instead and wrapping it in a
+/// [BeatSaverClient][crate::client::BeatSaverClient] also provides the configuration of the
+/// built-in clients (credentials, policies, caches, ...):
+/// ```
+/// # #[cfg(feature = "sync")]
+/// # mod example {
+/// use beatsaver_rs::{BeatSaverApiError, BeatSaverApiSync};
+/// use bytes::Bytes;
+/// use http::{Request, Response};
+/// use std::fmt;
+///
+/// #[derive(Debug)]
+/// struct MyError;
+/// impl fmt::Display for MyError {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "request failed")
+///     }
+/// }
+/// impl std::error::Error for MyError {}
+/// impl From<MyError> for BeatSaverApiError<MyError> {
+///     fn from(e: MyError) -> Self {
+///         Self::RequestError(e)
+///     }
+/// }
+///
+/// struct MyClient;
+/// impl BeatSaverApiSync<MyError> for MyClient {
+///     fn send(&self, request: Request<Bytes>) -> Result<Response<Bytes>, BeatSaverApiError<MyError>> {
+///         // Hand the request to your HTTP library of choice here
+///         Err(MyError.into())
+///     }
+/// }
+/// # }
+/// ```
+pub trait BeatSaverApiSync<T: 'static + Error>
+where
+    BeatSaverApiError<T>: From<T>,
+{
+    /// Sends an HTTP request and returns the response
     ///
-    /// Note: urlencodes the query
-    fn search(
+    /// Responses should be returned regardless of their status code, status handling (e.g. rate
+    /// limits) is done by [request_raw][crate::BeatSaverApiSync::request_raw]
+    fn send(&self, request: Request<Bytes>) -> Result<Response<Bytes>, BeatSaverApiError<T>>;
+    /// Sends an HTTP request and returns the response, with a reader streaming its body
+    ///
+    /// Backends that can't stream responses return a reader over the whole body.
+    fn send_streaming<'a>(
         &'a self,
-        query: &'a String,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        self.search_page_iter(query, 0)
+        request: Request<Bytes>,
+    ) -> Result<Response<Box<dyn Read + 'a>>, BeatSaverApiError<T>> {
+        let response = self.send(request)?;
+        Ok(response.map(|b| Box::new(Cursor::new(b)) as Box<dyn Read + 'a>))
     }
-    /// Retrieves maps based on a specified search query, specifying a page number
+    /// Executes a raw GET request to the provided [Url][url::Url]
     ///
-    /// Note: urlencodes the query
-    fn search_page(
-        &'a self,
-        query: &'a String,
-        page: usize,
-    ) -> Result<Page<Map>, BeatSaverApiError<T>> {
-        let query = encode(query.as_str());
-        let url = BEATSAVER_URL
-            .join(format!("api/search/text/{}?q={}", page, query).as_str())
-            .unwrap();
-        let data = self.request(url)?;
-        Ok(serde_json::from_str(data.as_str())?)
+    /// Note: non-2xx responses are converted to [HttpError][crate::BeatSaverApiError::HttpError],
+    /// except 429 responses which are converted to
+    /// [RateLimitError][crate::BeatSaverApiError::RateLimitError] after waiting for the rate limit to expire as allowed by the
+    /// [rate limit policy][crate::BeatSaverApiSync::rate_limit_policy]. Transient failures
+    /// are retried according to the [retry policy][crate::BeatSaverApiSync::retry_policy].
+    fn request_raw(&self, url: Url) -> Result<Bytes, BeatSaverApiError<T>> {
+        let response = self.request_response(url.clone(), HeaderMap::new())?;
+        response_body(&url, response)
     }
-    /// Retrieves maps based on a specified search query, starting at the specified page
+    /// Executes a GET request to the provided [Url][url::Url] with additional headers, returning
+    /// the whole response
     ///
-    /// Note: urlencodes the query
-    fn search_page_iter(
-        &'a self,
-        query: &'a String,
-        page: usize,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        // TODO: Don't make a request! Should return PageIterator every time!
-        let page = Page {
-            docs: VecDeque::<Map>::new(),
-            total_docs: 0,
-            last_page: 0,
-            prev_page: None,
-            next_page: Some(page),
-        };
-
-        let next = move |p| self.search_page(query, p);
-
-        PageIterator {
-            curr: page,
-            next_page: Box::new(next),
-        }
+    /// Rate limits and transient failures are handled like in
+    /// [request_raw][crate::BeatSaverApiSync::request_raw], other responses are returned as is.
+    fn request_response(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+    ) -> Result<Response<Bytes>, BeatSaverApiError<T>> {
+        send_get(self, &url, &headers, self.credentials())
     }
-    /// Retrieves maps based on an advanced search query
+    /// Executes a request and converts the result into a [String][std::string::String]
     ///
-    /// Note: urlencodes the query
+    /// If a [response cache][crate::BeatSaverApiSync::response_cache] is attached, cached responses
+    /// are returned while fresh, and revalidated with the server instead of being downloaded again
+    /// afterwards.
+    fn request(&self, url: Url) -> Result<String, BeatSaverApiError<T>> {
+        let response = self.request_cached(url.clone())?;
+        let data = response_body(&url, response)?;
+        Ok(String::from_utf8(data.as_ref().to_vec())?)
+    }
+    /// Executes a request through the [response cache][crate::BeatSaverApiSync::response_cache],
+    /// returning the whole response
     ///
-    /// Advanced queries use [Apache Lucene](https://lucene.apache.org/core/2_9_4/queryparsersyntax.html) syntax
-    fn search_advanced(
-        &'a self,
-        query: &'a String,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        self.search_advanced_page_iter(query, 0)
+    /// Responses served from the cache or revalidated with the server have a `200` status and only
+    /// carry the body. Without a cache attached, this is the same as
+    /// [request_response][crate::BeatSaverApiSync::request_response] without additional headers.
+    fn request_cached(&self, url: Url) -> Result<Response<Bytes>, BeatSaverApiError<T>> {
+        Ok(match self.response_cache() {
+            Some(cache) => match Lookup::new(cache, &url) {
+                Lookup::Fresh(body) => Response::new(body),
+                Lookup::Stale(cached) => {
+                    let headers = cached.as_ref().map(CachedResponse::conditional_headers);
+                    let response =
+                        self.request_response(url.clone(), headers.unwrap_or_default())?;
+                    update(cache, &url, cached, response)
+                }
+            },
+            None => self.request_response(url, HeaderMap::new())?,
+        })
     }
-    /// Retrieves maps based on an advanced search query, specifying a page
+    /// Returns the [Credentials][crate::auth::Credentials] attached to the client, if any
     ///
-    /// Note: urlencodes the query
+    /// Attached credentials are sent with every request.
+    fn credentials(&self) -> Option<&Credentials> {
+        None
+    }
+    /// Returns `true` if the client has credentials attached
+    fn authenticated(&self) -> bool {
+        self.credentials().is_some()
+    }
+    /// Returns the [KeyIndex][crate::index::KeyIndex] attached to the client, if any
     ///
-    /// Advanced queries use [Apache Lucene](https://lucene.apache.org/core/2_9_4/queryparsersyntax.html) syntax
-    fn search_advanced_page(
-        &'a self,
-        query: &'a String,
-        page: usize,
-    ) -> Result<Page<Map>, BeatSaverApiError<T>> {
-        // TODO: Validate Lucene syntax
-        let query = encode(query.as_str());
-        let url = BEATSAVER_URL
-            .join(format!("api/search/advanced/{}?q={}", page, query).as_str())
-            .unwrap();
-        let data = self.request(url)?;
-        Ok(serde_json::from_str(data.as_str())?)
+    /// Every map retrieved through the API is recorded in this index.
+    fn key_index(&self) -> Option<&KeyIndex> {
+        None
     }
-    /// Retrieves maps based on an advanced search query, specifying a page, iterable
+    /// Returns the base URL requests are sent to, defaults to [BEATSAVER_URL][crate::BEATSAVER_URL]
+    fn base_url(&self) -> &Url {
+        &BEATSAVER_URL
+    }
+    /// Returns the [ResponseCache][crate::cache::ResponseCache] attached to the client, if any
     ///
-    /// Note: urlencodes the query
+    /// Only applies to requests made through [request][crate::BeatSaverApiSync::request].
+    fn response_cache(&self) -> Option<&dyn ResponseCache> {
+        None
+    }
+    /// Returns how requests that hit a rate limit are handled, defaults to
+    /// [RateLimitPolicy::Fail][crate::RateLimitPolicy::Fail]
     ///
-    /// Advanced queries use [Apache Lucene](https://lucene.apache.org/core/2_9_4/queryparsersyntax.html) syntax
-    fn search_advanced_page_iter(
-        &'a self,
-        query: &'a String,
-        page: usize,
-    ) -> PageIterator<Map, T, dyn Fn(usize) -> Result<Page<Map>, BeatSaverApiError<T>> + 'a> {
-        let page = Page {
-            docs: VecDeque::<Map>::new(),
-            total_docs: 0,
-            last_page: 0,
-            prev_page: None,
-            next_page: Some(page),
-        };
+    /// Applies to GET requests made through [request_raw][crate::BeatSaverApiSync::request_raw] and
+    /// [download_to][crate::BeatSaverApiSync::download_to].
+    fn rate_limit_policy(&self) -> RateLimitPolicy {
+        RateLimitPolicy::default()
+    }
+    /// Returns how requests that fail due to transient errors are retried, defaults to no retries
+    ///
+    /// Applies to GET requests made through [request_raw][crate::BeatSaverApiSync::request_raw] and
+    /// [download_to][crate::BeatSaverApiSync::download_to].
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+    /// Returns `true` if a request that failed with the error can succeed when sent again,
+    /// defaults to [is_retryable][crate::BeatSaverApiError::is_retryable]
+    ///
+    /// Clients also retry the [RequestError][crate::BeatSaverApiError::RequestError]s their HTTP
+    /// library reports as transient, such as failed connections.
+    fn is_transient(&self, error: &BeatSaverApiError<T>) -> bool {
+        error.is_retryable()
+    }
+    /// Returns the route map archives are downloaded through, defaults to
+    /// [DownloadRoute::Api][crate::DownloadRoute::Api]
+    ///
+    /// Applies to [download][crate::BeatSaverApiSync::download] and
+    /// [download_version][crate::BeatSaverApiSync::download_version].
+    fn download_route(&self) -> DownloadRoute {
+        DownloadRoute::default()
+    }
+    /// Returns the [RateLimitTracker][crate::RateLimitTracker] attached to the client, if any
+    ///
+    /// The rate limit headers of every API response are recorded in this tracker. While a request
+    /// waits for a rate limit to expire, the other requests sharing the tracker wait too.
+    fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+        None
+    }
+    /// Returns the rate limit quota reported by the last API response, e.g. to throttle requests
+    /// before hitting the rate limit
+    ///
+    /// Note: `None` if the client has no [tracker][crate::BeatSaverApiSync::rate_limit_tracker]
+    /// attached, or no response reported the quota yet
+    fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.rate_limit_tracker()?.status()
+    }
+    /// Waits for the specified duration, used to pause when a rate limit is hit
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+    /// Exchanges an OAuth2 authorization code for a token
+    fn oauth_exchange(
+        &self,
+        config: &OAuthConfig,
+        code: &str,
+    ) -> Result<OAuthToken, BeatSaverApiError<T>> {
+        let response = self.send(config.exchange_request(code))?;
+        let data = response_body(&TOKEN_URL, response)?;
 
-        let next = move |p| self.search_advanced_page(query, p);
+        let token: TokenResponse = serde_json::from_slice(data.as_ref())?;
+        Ok(token.into())
+    }
+    /// Renews an OAuth2 token using its refresh token
+    fn oauth_refresh(
+        &self,
+        config: &OAuthConfig,
+        token: &OAuthToken,
+    ) -> Result<OAuthToken, BeatSaverApiError<T>> {
+        let refresh_token =
+            token
+                .refresh_token
+                .as_ref()
+                .ok_or(BeatSaverApiError::ArgumentError(
+                    "token has no refresh token",
+                ))?;
+        let response = self.send(config.refresh_request(refresh_token))?;
+        let data = response_body(&TOKEN_URL, response)?;
 
-        PageIterator {
-            curr: page,
-            next_page: Box::new(next),
-        }
+        let token: TokenResponse = serde_json::from_slice(data.as_ref())?;
+        Ok(token.into())
     }
-    /// Downloads a provided map
+    /// Gets a map from a given [MapId][crate::MapId]
     ///
-    /// [Maps][crate::map::Map] can be converted to [MapIds][crate::MapId] using the [Into][std::convert::Into] trait.
-    fn download(&'a self, id: MapId) -> Result<Bytes, BeatSaverApiError<T>> {
-        Ok(self.request_raw(
-            BEATSAVER_URL
-                .join(
-                    match id {
-                        MapId::Key(k) => format!("api/download/key/{:x}", k),
-                        MapId::Hash(h) => format!("api/download/hash/{}", h),
-                    }
-                    .as_str(),
-                )
-                .unwrap(),
-        )?)
-    }
-}
+    /// Returns [NotFound][crate::BeatSaverApiError::NotFound] if no map exists with the id
+    fn map(&self, id: &MapId) -> Result<MapDetail, BeatSaverApiError<T>> {
+        let url = map_url(self.base_url(), id);
+        let response = self.request_cached(url.clone())?;
 
-#[cfg(test)]
-mod tests {
-    use crate::map::Map;
-    use crate::tests::{FakeClient, FakeClientPaged, FakeError};
-    use crate::BeatSaverApiSync;
-    use crate::{BeatSaverApiError, BeatSaverUser, Page, BEATSAVER_URL};
-    use bytes::Bytes;
-    use std::collections::HashMap;
-    use std::convert::TryInto;
-    use url::Url;
+        let map = parse_map(&url, response, id)?;
+        if let Some(index) = self.key_index() {
+            index.insert_map(&map);
+        }
+        Ok(map)
+    }
+    /// Gets a map from its key (e.g. `2144`)
+    ///
+    /// Returns [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the key is invalid
+    fn map_by_key(&self, key: &str) -> Result<MapDetail, BeatSaverApiError<T>> {
+        let key =
+            MapKey::new(key).map_err(|_| BeatSaverApiError::ArgumentError("invalid map key"))?;
+        let id = MapId::Key(key);
+        let url = map_url(self.base_url(), &id);
+        let response = self.request_cached(url.clone())?;
 
-    impl<'a> BeatSaverApiSync<'a, FakeError> for FakeClient {
-        fn request_raw(&'a self, url: Url) -> Result<Bytes, BeatSaverApiError<FakeError>> {
-            assert_eq!(self.url, url);
-            Ok(self.data.clone())
+        let map = parse_map(&url, response, &id)?;
+        if let Some(index) = self.key_index() {
+            index.insert_map(&map);
         }
+        Ok(map)
     }
-    impl<'a> BeatSaverApiSync<'a, FakeError> for FakeClientPaged {
-        fn request_raw(&'a self, url: Url) -> Result<Bytes, BeatSaverApiError<FakeError>> {
-            let data = match self.pages.get(&url) {
-                Some(d) => d,
-                None => panic!("Url not found: {}", url.as_str()),
-            };
-            Ok(data.clone())
+    /// Gets a map from the hash of one of its versions
+    ///
+    /// Returns [ArgumentError][crate::BeatSaverApiError::ArgumentError] if the hash isn't 40 hex
+    /// characters
+    fn map_by_hash(&self, hash: &str) -> Result<MapDetail, BeatSaverApiError<T>> {
+        if hash.len() != 40 || hex::decode(hash).is_err() {
+            return Err(BeatSaverApiError::ArgumentError("invalid map hash"));
+        }
+        let id = MapId::Hash(hash.to_lowercase());
+        let url = map_url(self.base_url(), &id);
+        let response = self.request_cached(url.clone())?;
+
+        let map = parse_map(&url, response, &id)?;
+        if let Some(index) = self.key_index() {
+            index.insert_map(&map);
         }
+        Ok(map)
     }
+    /// Gets up to [MAPS_BY_IDS_LIMIT][crate::MAPS_BY_IDS_LIMIT] maps in a single request, keyed by map id
+    ///
+    /// Note: the endpoint only accepts keys, hashes are resolved using the [KeyIndex][crate::index::KeyIndex]
+    /// if the client provides one
+    fn maps_by_ids<I>(&self, ids: I) -> Result<HashMap<String, MapDetail>, BeatSaverApiError<T>>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<MapId>,
+    {
+        let ids: Vec<MapId> = ids.into_iter().map(|id| id.borrow().clone()).collect();
+        let url = maps_by_ids_url(self.base_url(), &ids, self.key_index())?;
+        let data = self.request(url)?;
 
-    #[test]
-    fn test_map() {
-        let client = FakeClient::new(BEATSAVER_URL.join("api/maps/detail/1").unwrap(),  r#"{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":188.625,"length":141,"bombs":28,"notes":337,"obstacles":11,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"me & u","songSubName":"","songAuthorName":"succducc","levelAuthorName":"datkami","bpm":160},"stats":{"downloads":86164,"plays":8377,"downVotes":110,"upVotes":512,"heat":17.2028038,"rating":0.7765731134313741},"description":"Hard Only / ~330 notes / Event Lighting! / https://soundcloud.com/succducc/me-n-u","deletedAt":null,"_id":"5cff620c48229f7d88fc60df","key":"1","name":"succducc - me & u","uploader":{"_id":"5cff0b7298cc5a672c84e8a3","username":"datkami"},"uploaded":"2018-05-08T14:28:56.000Z","hash":"fda568fc27c20d21f8dc6f3709b49b5cc96723be","directDownload":"/cdn/1/fda568fc27c20d21f8dc6f3709b49b5cc96723be.zip","downloadURL":"/api/download/key/1","coverURL":"/cdn/1/fda568fc27c20d21f8dc6f3709b49b5cc96723be.jpg"}"#.into());
-        client.map(&"1".to_string().try_into().unwrap()).unwrap();
-        let client = FakeClient::new(BEATSAVER_URL.join("api/maps/by-hash/fda568fc27c20d21f8dc6f3709b49b5cc96723be").unwrap(),  r#"{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":188.625,"length":141,"bombs":28,"notes":337,"obstacles":11,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"me & u","songSubName":"","songAuthorName":"succducc","levelAuthorName":"datkami","bpm":160},"stats":{"downloads":86164,"plays":8377,"downVotes":110,"upVotes":512,"heat":17.2028038,"rating":0.7765731134313741},"description":"Hard Only / ~330 notes / Event Lighting! / https://soundcloud.com/succducc/me-n-u","deletedAt":null,"_id":"5cff620c48229f7d88fc60df","key":"1","name":"succducc - me & u","uploader":{"_id":"5cff0b7298cc5a672c84e8a3","username":"datkami"},"uploaded":"2018-05-08T14:28:56.000Z","hash":"fda568fc27c20d21f8dc6f3709b49b5cc96723be","directDownload":"/cdn/1/fda568fc27c20d21f8dc6f3709b49b5cc96723be.zip","downloadURL":"/api/download/key/1","coverURL":"/cdn/1/fda568fc27c20d21f8dc6f3709b49b5cc96723be.jpg"}"#.into());
-        let _: Map = client
-            .map(
-                &"fda568fc27c20d21f8dc6f3709b49b5cc96723be"
-                    .to_string()
-                    .try_into()
-                    .unwrap(),
-            )
-            .unwrap();
+        let maps: HashMap<String, MapDetail> = serde_json::from_str(data.as_str())?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(maps.values());
+        }
+        Ok(maps)
+    }
+    /// Gets up to [MAPS_BY_HASHES_LIMIT][crate::MAPS_BY_HASHES_LIMIT] maps in a single request, keyed by lowercase hash
+    ///
+    /// Hashes that BeatSaver doesn't know about are missing from the result.
+    fn maps_by_hashes<I>(
+        &self,
+        hashes: I,
+    ) -> Result<HashMap<String, MapDetail>, BeatSaverApiError<T>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let hashes: Vec<String> = hashes.into_iter().map(|h| h.as_ref().to_string()).collect();
+        let url = maps_by_hashes_url(self.base_url(), &hashes)?;
+        let data = self.request(url)?;
+
+        let maps = parse_maps_by_hashes(&hashes, data.as_str())?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(maps.values());
+        }
+        Ok(maps)
+    }
+    /// Retrieves maps created by a specified beatsaver user id
+    fn maps_by(&self, user_id: usize) -> MapIterator<'_, T> {
+        self.maps_by_page_iter(user_id, 0)
+    }
+    /// Retrieves maps created by a specified beatsaver user id, specifying a page number
+    fn maps_by_page(
+        &self,
+        user_id: usize,
+        page: usize,
+    ) -> Result<Page<MapDetail>, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("maps/uploader/{}/{}", user_id, page).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+        let page: Page<MapDetail> = Page::parse(data.as_str(), page)?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(&page.docs);
+        }
+        Ok(page)
+    }
+    /// Retrieves maps created by a specified beatsaver user id, starting at the specified page
+    fn maps_by_page_iter(&self, user_id: usize, page: usize) -> MapIterator<'_, T> {
+        iterate_page(Box::new(move |p| self.maps_by_page(user_id, p)), page)
+    }
+    /// Retrieves maps created by a specified beatsaver user id, oldest first
+    ///
+    /// The last page is found before the iterator is returned, pages are then numbered from the
+    /// last page. Maps uploaded while iterating shift the pages, so some maps may be yielded twice.
+    fn maps_by_page_iter_rev(
+        &self,
+        user_id: usize,
+    ) -> Result<MapIterator<'_, T>, BeatSaverApiError<T>> {
+        iterate_page_rev(Box::new(move |p| self.maps_by_page(user_id, p)))
+    }
+    /// Retrieves all maps sorted by rating
+    fn maps_rating(&self) -> MapIterator<'_, T> {
+        self.maps_rating_page_iter(0)
+    }
+    /// Retrieves all maps sorted by rating, specifying a page number
+    fn maps_rating_page(&self, page: usize) -> Result<Page<MapDetail>, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("search/text/{}?sortOrder=Rating", page).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+        let page: Page<MapDetail> = Page::parse(data.as_str(), page)?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(&page.docs);
+        }
+        Ok(page)
+    }
+    /// Retrieves all maps sorted by rating, starting at the specified page
+    fn maps_rating_page_iter(&self, page: usize) -> MapIterator<'_, T> {
+        iterate_page(Box::new(move |p| self.maps_rating_page(p)), page)
+    }
+    /// Retrieves the latest maps, following the `before` (or `after`) cursor past the last map received
+    fn maps_latest(&self, options: LatestOptions) -> LatestIterator<'_, T> {
+        LatestIterator {
+            curr: VecDeque::new(),
+            next: Some(options),
+            next_page: Box::new(move |o| self.maps_latest_page(o)),
+        }
+    }
+    /// Retrieves the latest maps one batch at a time, following the `before` (or `after`) cursor
+    fn maps_latest_pages(&self, options: LatestOptions) -> LatestPagesIterator<'_, T> {
+        self.maps_latest(options).pages()
+    }
+    /// Retrieves a single batch of the latest maps matching the provided options
+    fn maps_latest_page(
+        &self,
+        options: &LatestOptions,
+    ) -> Result<Vec<MapDetail>, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("maps/latest?{}", options.query_string()).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+        let page: Page<MapDetail> = Page::parse(data.as_str(), 0)?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(&page.docs);
+        }
+        Ok(page.docs.into())
+    }
+    /// Retrieves curated maps sorted by curation time
+    fn maps_curated(&self) -> MapIterator<'_, T> {
+        self.maps_curated_page_iter(0)
+    }
+    /// Retrieves curated maps sorted by curation time, specifying a page number
+    fn maps_curated_page(&self, page: usize) -> Result<Page<MapDetail>, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("search/text/{}?curated=true&sortOrder=Curated", page).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+        let page: Page<MapDetail> = Page::parse(data.as_str(), page)?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(&page.docs);
+        }
+        Ok(page)
+    }
+    /// Retrieves curated maps sorted by curation time, starting at the specified page
+    fn maps_curated_page_iter(&self, page: usize) -> MapIterator<'_, T> {
+        iterate_page(Box::new(move |p| self.maps_curated_page(p)), page)
+    }
+    /// Retrieves curated maps, least recently curated first
+    ///
+    /// The last page is found before the iterator is returned, pages are then numbered from the
+    /// last page. Maps uploaded while iterating shift the pages, so some maps may be yielded twice.
+    fn maps_curated_page_iter_rev(&self) -> Result<MapIterator<'_, T>, BeatSaverApiError<T>> {
+        iterate_page_rev(Box::new(move |p| self.maps_curated_page(p)))
+    }
+    /// Retrieves all maps sorted by number of plays
+    fn maps_plays(&self) -> MapIterator<'_, T> {
+        self.maps_plays_page_iter(0)
+    }
+    /// Retrieves all maps sorted by number of plays, specifying a page number
+    fn maps_plays_page(&self, page: usize) -> Result<Page<MapDetail>, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("maps/plays/{}", page).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+        let page: Page<MapDetail> = Page::parse(data.as_str(), page)?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(&page.docs);
+        }
+        Ok(page)
+    }
+    /// Retrieves all maps sorted by number of plays, starting at the specified page
+    fn maps_plays_page_iter(&self, page: usize) -> MapIterator<'_, T> {
+        iterate_page(Box::new(move |p| self.maps_plays_page(p)), page)
+    }
+    /// Retrieves info on a specified beatsaver user id
+    fn user(&self, id: usize) -> Result<UserDetail, BeatSaverApiError<T>> {
+        let data = self.request(
+            self.base_url()
+                .join(format!("users/id/{}", id).as_str())
+                .unwrap(),
+        )?;
+
+        Ok(serde_json::from_str(data.as_str())?)
+    }
+    /// Retrieves info on the user the client is authenticated as
+    ///
+    /// Note: requires [credentials][crate::auth::Credentials], returns
+    /// [AuthenticationRequired][crate::BeatSaverApiError::AuthenticationRequired] otherwise
+    fn identity(&self) -> Result<UserDetail, BeatSaverApiError<T>> {
+        if !self.authenticated() {
+            return Err(BeatSaverApiError::AuthenticationRequired);
+        }
+        let data = self.request(self.base_url().join("users/me").unwrap())?;
+
+        Ok(serde_json::from_str(data.as_str())?)
+    }
+    /// Retrieves info on a beatsaver user by name
+    ///
+    /// Note: urlencodes the name
+    fn user_by_name(&self, name: &str) -> Result<UserDetail, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("users/name/{}", encode(name)).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+
+        Ok(serde_json::from_str(data.as_str())?)
+    }
+    /// Verifies a user-supplied proof of account ownership, returning the verified user id
+    fn verify_user(
+        &self,
+        request: &UserVerifyRequest,
+    ) -> Result<UserVerifyResponse, BeatSaverApiError<T>> {
+        let url = self.base_url().join("users/verify").unwrap();
+        let body = serde_json::to_vec(request)?;
+        let request = authorize(post_request(url.clone(), body.into()), self.credentials());
+        let response = self.send(request)?;
+        track_rate_limit(self.rate_limit_tracker(), response.headers());
+        let data = response_body(&url, response)?;
+
+        Ok(serde_json::from_slice(data.as_ref())?)
+    }
+    /// Changes the publishing state of a map version (e.g. to make it available for testplays)
+    ///
+    /// Note: requires [credentials][crate::auth::Credentials] of the version's uploader, returns
+    /// [AuthenticationRequired][crate::BeatSaverApiError::AuthenticationRequired] otherwise
+    fn testplay_state(
+        &self,
+        request: &TestplayStateRequest,
+    ) -> Result<ActionResponse, BeatSaverApiError<T>> {
+        if !self.authenticated() {
+            return Err(BeatSaverApiError::AuthenticationRequired);
+        }
+        let url = self.base_url().join("testplay/state").unwrap();
+        let body = serde_json::to_vec(request)?;
+        let request = authorize(post_request(url.clone(), body.into()), self.credentials());
+        let response = self.send(request)?;
+        track_rate_limit(self.rate_limit_tracker(), response.headers());
+        let data = response_body(&url, response)?;
+
+        Ok(serde_json::from_slice(data.as_ref())?)
+    }
+    /// Submits feedback on a map version that is available for testplays
+    ///
+    /// Note: requires [credentials][crate::auth::Credentials], returns
+    /// [AuthenticationRequired][crate::BeatSaverApiError::AuthenticationRequired] otherwise
+    fn testplay_feedback(
+        &self,
+        request: &TestplayFeedbackRequest,
+    ) -> Result<ActionResponse, BeatSaverApiError<T>> {
+        if !self.authenticated() {
+            return Err(BeatSaverApiError::AuthenticationRequired);
+        }
+        let url = self.base_url().join("testplay/feedback").unwrap();
+        let body = serde_json::to_vec(request)?;
+        let request = authorize(post_request(url.clone(), body.into()), self.credentials());
+        let response = self.send(request)?;
+        track_rate_limit(self.rate_limit_tracker(), response.headers());
+        let data = response_body(&url, response)?;
+
+        Ok(serde_json::from_slice(data.as_ref())?)
+    }
+    /// Retrieves the vote totals of every map that received votes since the provided time
+    fn votes_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(MapId, VoteSummary)>, BeatSaverApiError<T>> {
+        let since = since.to_rfc3339_opts(SecondsFormat::Secs, true);
+        let url = self
+            .base_url()
+            .join(format!("vote?since={}", encode(since.as_str())).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+
+        let votes: Vec<VoteSummary> = serde_json::from_str(data.as_str())?;
+        Ok(votes
+            .into_iter()
+            .map(|v| (MapId::from(v.map_id), v))
+            .collect())
+    }
+    /// Retrieves maps based on a specified search query
+    ///
+    /// Note: urlencodes the query
+    fn search<'a>(&'a self, query: &'a str) -> MapIterator<'a, T> {
+        self.search_page_iter(query, 0)
+    }
+    /// Retrieves maps based on a specified search query, specifying a page number
+    ///
+    /// Note: urlencodes the query
+    fn search_page(
+        &self,
+        query: &str,
+        page: usize,
+    ) -> Result<Page<MapDetail>, BeatSaverApiError<T>> {
+        self.search_filtered_page(&SearchOptions::new(query), page)
+    }
+    /// Retrieves maps based on a specified search query, starting at the specified page
+    ///
+    /// Note: urlencodes the query
+    fn search_page_iter<'a>(&'a self, query: &'a str, page: usize) -> MapIterator<'a, T> {
+        iterate_page(Box::new(move |p| self.search_page(query, p)), page)
+    }
+    /// Retrieves maps based on the provided [SearchOptions][crate::query::SearchOptions]
+    fn search_filtered<'a>(&'a self, options: &'a SearchOptions) -> MapIterator<'a, T> {
+        self.search_filtered_page_iter(options, 0)
+    }
+    /// Retrieves maps based on the provided [SearchOptions][crate::query::SearchOptions], specifying a page number
+    fn search_filtered_page(
+        &self,
+        options: &SearchOptions,
+        page: usize,
+    ) -> Result<Page<MapDetail>, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("search/text/{}?{}", page, options.query_string()).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+
+        let page: Page<MapDetail> = Page::parse(data.as_str(), page)?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(&page.docs);
+        }
+        Ok(page)
+    }
+    /// Retrieves maps based on the provided [SearchOptions][crate::query::SearchOptions], starting at the specified page
+    fn search_filtered_page_iter<'a>(
+        &'a self,
+        options: &'a SearchOptions,
+        page: usize,
+    ) -> MapIterator<'a, T> {
+        iterate_page(
+            Box::new(move |p| self.search_filtered_page(options, p)),
+            page,
+        )
+    }
+    /// Retrieves maps based on the provided [SearchOptions][crate::query::SearchOptions], starting at
+    /// the last page
+    ///
+    /// Maps are oldest first when sorted by [Latest][crate::query::SortOrder::Latest].
+    ///
+    /// The last page is found before the iterator is returned, pages are then numbered from the
+    /// last page. Maps uploaded while iterating shift the pages, so some maps may be yielded twice.
+    fn search_filtered_page_iter_rev<'a>(
+        &'a self,
+        options: &'a SearchOptions,
+    ) -> Result<MapIterator<'a, T>, BeatSaverApiError<T>> {
+        iterate_page_rev(Box::new(move |p| self.search_filtered_page(options, p)))
+    }
+    /// Retrieves maps based on an advanced search query
+    ///
+    /// Note: urlencodes the query
+    ///
+    /// Advanced queries use [Apache Lucene](https://lucene.apache.org/core/2_9_4/queryparsersyntax.html) syntax
+    fn search_advanced<'a>(&'a self, query: &'a str) -> MapIterator<'a, T> {
+        self.search_advanced_page_iter(query, 0)
+    }
+    /// Retrieves maps based on an advanced search query, specifying a page
+    ///
+    /// Note: urlencodes the query
+    ///
+    /// Advanced queries use [Apache Lucene](https://lucene.apache.org/core/2_9_4/queryparsersyntax.html) syntax,
+    /// the v2 API serves them from the same route as [search_page][crate::BeatSaverApiSync::search_page].
+    /// Queries can be built with the [lucene][crate::lucene] module, malformed queries are rejected with an
+    /// [ArgumentError][crate::BeatSaverApiError::ArgumentError] before a request is made
+    fn search_advanced_page(
+        &self,
+        query: &str,
+        page: usize,
+    ) -> Result<Page<MapDetail>, BeatSaverApiError<T>> {
+        if validate(query).is_err() {
+            return Err(BeatSaverApiError::ArgumentError("invalid Lucene query"));
+        }
+        let options = SearchOptions::new(query).with_sort(SortOrder::Relevance);
+        self.search_filtered_page(&options, page)
+    }
+    /// Retrieves maps based on an advanced search query, specifying a page, iterable
+    ///
+    /// Note: urlencodes the query
+    ///
+    /// Advanced queries use [Apache Lucene](https://lucene.apache.org/core/2_9_4/queryparsersyntax.html) syntax
+    fn search_advanced_page_iter<'a>(&'a self, query: &'a str, page: usize) -> MapIterator<'a, T> {
+        iterate_page(Box::new(move |p| self.search_advanced_page(query, p)), page)
+    }
+    /// Retrieves all public playlists, newest first, following the `before` cursor past the last
+    /// playlist received
+    fn playlists_latest(&self) -> LatestPlaylistIterator<'_, T> {
+        let (mut curr, mut next) = (VecDeque::new(), Some(None));
+        Box::new(std::iter::from_fn(move || {
+            while curr.is_empty() {
+                // We're at the end of the current batch
+                let before = next.take()?;
+                match self.playlists_latest_page(before) {
+                    Ok(playlists) => {
+                        next = playlists.last().map(|p| Some(p.created_at));
+                        curr = VecDeque::from(playlists);
+                    }
+                    Err(e) => {
+                        next = Some(before);
+                        return Some(Err(e));
+                    }
+                }
+            }
+            curr.pop_front().map(Ok)
+        }))
+    }
+    /// Retrieves a single batch of the latest public playlists, created before the specified time
+    /// if any
+    fn playlists_latest_page(
+        &self,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<Vec<PlaylistDetail>, BeatSaverApiError<T>> {
+        let mut query = String::from("sort=CREATED");
+        if let Some(before) = before {
+            let before = before.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+            query = format!("before={}&{}", encode(before.as_str()), query);
+        }
+        let url = self
+            .base_url()
+            .join(format!("playlists/latest?{}", query).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+
+        let page: Page<PlaylistDetail> = Page::parse(data.as_str(), 0)?;
+        Ok(page.docs.into())
+    }
+    /// Retrieves playlists based on a specified search query
+    ///
+    /// Note: urlencodes the query
+    fn playlists_search<'a>(&'a self, query: &'a str) -> PlaylistIterator<'a, T> {
+        self.playlists_search_page_iter(query, 0)
+    }
+    /// Retrieves playlists based on a specified search query, specifying a page number
+    ///
+    /// Note: urlencodes the query
+    fn playlists_search_page(
+        &self,
+        query: &str,
+        page: usize,
+    ) -> Result<Page<PlaylistDetail>, BeatSaverApiError<T>> {
+        let query = encode(query);
+        let url = self
+            .base_url()
+            .join(format!("playlists/search/{}?q={}", page, query).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+
+        Ok(Page::parse(data.as_str(), page)?)
+    }
+    /// Retrieves playlists based on a specified search query, starting at the specified page
+    ///
+    /// Note: urlencodes the query
+    fn playlists_search_page_iter<'a>(
+        &'a self,
+        query: &'a str,
+        page: usize,
+    ) -> PlaylistIterator<'a, T> {
+        iterate_page(
+            Box::new(move |p| self.playlists_search_page(query, p)),
+            page,
+        )
+    }
+    /// Retrieves the playlists published by a specified beatsaver user id
+    fn playlists_by_user(
+        &self,
+        user_id: usize,
+    ) -> Result<Vec<PlaylistDetail>, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("users/id/{}/playlists", user_id).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+
+        let page: Page<PlaylistDetail> = Page::parse(data.as_str(), 0)?;
+        Ok(page.docs.into())
+    }
+    /// Retrieves a single page of a playlist, including the maps on that page
+    fn playlist_page(&self, id: usize, page: usize) -> Result<PlaylistPage, BeatSaverApiError<T>> {
+        let url = self
+            .base_url()
+            .join(format!("playlists/id/{}/{}", id, page).as_str())
+            .unwrap();
+        let data = self.request(url)?;
+
+        let page: PlaylistPage = serde_json::from_str(data.as_str())?;
+        if let Some(index) = self.key_index() {
+            index.insert_maps(page.maps.iter().map(|m| &m.map));
+        }
+        Ok(page)
+    }
+    /// Retrieves info on a specified playlist id
+    fn playlist(&self, id: usize) -> Result<PlaylistDetail, BeatSaverApiError<T>> {
+        Ok(self.playlist_page(id, 0)?.playlist)
+    }
+    /// Retrieves the maps contained in a specified playlist id
+    fn playlist_maps(&self, id: usize) -> MapIterator<'_, T> {
+        self.playlist_maps_page_iter(id, 0)
+    }
+    /// Retrieves the maps contained in a specified playlist id, specifying a page number
+    fn playlist_maps_page(
+        &self,
+        id: usize,
+        page: usize,
+    ) -> Result<Page<MapDetail>, BeatSaverApiError<T>> {
+        let mut maps = self.playlist_page(id, page)?.maps;
+        maps.sort_by(|a, b| a.order.partial_cmp(&b.order).unwrap_or(Ordering::Equal));
+        Ok(Page::from_docs(
+            maps.into_iter().map(|m| m.map).collect(),
+            page,
+        ))
+    }
+    /// Retrieves the maps contained in a specified playlist id, starting at the specified page
+    fn playlist_maps_page_iter(&self, id: usize, page: usize) -> MapIterator<'_, T> {
+        iterate_page(Box::new(move |p| self.playlist_maps_page(id, p)), page)
+    }
+    /// Downloads a provided map
+    ///
+    /// [MapDetails][crate::model::map::MapDetail] can be converted to [MapIds][crate::MapId] using the [TryInto][std::convert::TryInto] trait.
+    ///
+    /// Maps requested by hash are downloaded from the CDN first if the
+    /// [download_route][crate::BeatSaverApiSync::download_route] is [Cdn][crate::DownloadRoute::Cdn].
+    fn download(&self, id: MapId) -> Result<Bytes, BeatSaverApiError<T>> {
+        if let (DownloadRoute::Cdn, MapId::Hash(hash)) = (self.download_route(), &id) {
+            let url = cdn_download_url(hash);
+            if let Ok(response) = send_get(self, &url, &HeaderMap::new(), None) {
+                if let Ok(data) = response_body::<T>(&url, response) {
+                    return Ok(data);
+                }
+            }
+        }
+        self.request_raw(download_url(self.base_url(), &id))
+    }
+    /// Downloads a [MapVersion][crate::model::map::MapVersion]
+    ///
+    /// If the [download_route][crate::BeatSaverApiSync::download_route] is
+    /// [Cdn][crate::DownloadRoute::Cdn], the archive is downloaded from the `download_url` of the
+    /// version first, and from the API if that fails.
+    fn download_version(&self, version: &MapVersion) -> Result<Bytes, BeatSaverApiError<T>> {
+        if self.download_route() == DownloadRoute::Cdn {
+            if let Ok(url) = Url::parse(version.download_url.as_str()) {
+                if let Ok(response) = send_get(self, &url, &HeaderMap::new(), None) {
+                    if let Ok(data) = response_body::<T>(&url, response) {
+                        return Ok(data);
+                    }
+                }
+            }
+        }
+        let id = MapId::Hash(version.hash.clone());
+        self.request_raw(download_url(self.base_url(), &id))
+    }
+    /// Downloads several maps concurrently, with at most `concurrency` downloads in flight
+    ///
+    /// Results are returned in the order of `ids`, alongside the [MapId][crate::MapId] they belong
+    /// to. Rate limits are handled according to the
+    /// [rate limit policy][crate::BeatSaverApiSync::rate_limit_policy]: while a download waits for
+    /// a rate limit to expire, the other downloads sharing the client's
+    /// [tracker][crate::BeatSaverApiSync::rate_limit_tracker] are paused until it expires too.
+    #[allow(clippy::type_complexity)]
+    fn download_many(
+        &self,
+        ids: &[MapId],
+        concurrency: usize,
+    ) -> Vec<(MapId, Result<Bytes, BeatSaverApiError<T>>)>
+    where
+        Self: Sync,
+        T: Send,
+    {
+        for_each_concurrent(ids, concurrency, |id| self.download(id.clone()))
+    }
+    /// Downloads the maps whose latest version isn't installed, using up to `concurrency` threads
+    ///
+    /// `installed` contains the lowercase hashes of the installed maps (e.g. the keys returned by
+    /// `install::installed_hashes`). Maps requested by key are looked up to find the hash of their
+    /// latest version, maps that are already installed are reported as
+    /// [Skipped][crate::DownloadOutcome::Skipped] instead of being downloaded. Rate limits are
+    /// handled like [download_many][crate::BeatSaverApiSync::download_many].
+    #[allow(clippy::type_complexity)]
+    fn download_missing(
+        &self,
+        ids: &[MapId],
+        installed: &HashSet<String>,
+        concurrency: usize,
+    ) -> Vec<(MapId, Result<DownloadOutcome, BeatSaverApiError<T>>)>
+    where
+        Self: Sync,
+        T: Send,
+    {
+        for_each_concurrent(ids, concurrency, |id| {
+            let hash = match id {
+                MapId::Hash(h) => Some(h.to_lowercase()),
+                MapId::Key(_) => self.map(id)?.hash().map(str::to_lowercase),
+            };
+            if hash.is_some_and(|h| installed.contains(&h)) {
+                return Ok(DownloadOutcome::Skipped);
+            }
+            self.download(id.clone()).map(DownloadOutcome::Downloaded)
+        })
+    }
+    /// Downloads a [MapVersion][crate::model::map::MapVersion], checking that the archive matches its hash
+    ///
+    /// Returns [HashMismatch][crate::BeatSaverApiError::HashMismatch] for corrupted or tampered
+    /// archives.
+    #[cfg(feature = "hash")]
+    fn download_verified(&self, version: &MapVersion) -> Result<Bytes, BeatSaverApiError<T>> {
+        let data = self.download_version(version)?;
+        let archive = Archive::new(data.clone())?;
+        verify(&archive, version.hash.as_str())?;
+        Ok(data)
+    }
+    /// Installs the latest version of a map into a Beat Saber install
+    ///
+    /// The download is checked against the hash of the version, and maps whose latest version is
+    /// already present in the `CustomLevels` folder are skipped.
+    ///
+    /// Note: the whole `CustomLevels` folder is hashed to find the installed maps, use
+    /// [install_map_into][crate::BeatSaverApiSync::install_map_into] to install several maps
+    #[cfg(feature = "hash")]
+    fn install_map(
+        &self,
+        id: &MapId,
+        target: &InstallTarget,
+    ) -> Result<InstallOutcome, BeatSaverApiError<T>> {
+        let custom_levels = target
+            .custom_levels()
+            .ok_or(BeatSaverApiError::ArgumentError(
+                "Beat Saber install not found",
+            ))?;
+        let mut installed = installed_hashes(&custom_levels)?;
+        self.install_map_into(id, &custom_levels, &mut installed)
+    }
+    /// Installs the latest version of a map into a `CustomLevels` folder, using the installed maps
+    /// of `installed` instead of hashing the folder
+    ///
+    /// `installed` maps the hashes of the installed maps to their folder, as returned by
+    /// [installed_hashes][crate::install::installed_hashes], and is updated with the maps that get
+    /// installed, so it can be reused across calls.
+    #[cfg(feature = "hash")]
+    fn install_map_into(
+        &self,
+        id: &MapId,
+        custom_levels: &Path,
+        installed: &mut HashMap<String, PathBuf>,
+    ) -> Result<InstallOutcome, BeatSaverApiError<T>> {
+        let map = self.map(id)?;
+        let hash = match map.hash() {
+            Some(h) => h.to_lowercase(),
+            None => return Err(BeatSaverApiError::ArgumentError("map has no versions")),
+        };
+        if let Some(path) = installed.get(&hash) {
+            return Ok(InstallOutcome::AlreadyInstalled(path.clone()));
+        }
+
+        let data = self.download(MapId::Hash(hash.clone()))?;
+        verify(&Archive::new(data.clone())?, hash.as_str())?;
+        let path = extract_map(&map, data, custom_levels)?;
+        installed.insert(hash, path.clone());
+        Ok(InstallOutcome::Installed(path))
+    }
+    /// Scans the maps installed in a `CustomLevels` folder and reconciles them against BeatSaver
+    ///
+    /// Hashes are looked up in batches of [MAPS_BY_HASHES_LIMIT][crate::MAPS_BY_HASHES_LIMIT].
+    #[cfg(feature = "hash")]
+    fn reconcile_library(
+        &self,
+        custom_levels: &Path,
+    ) -> Result<Reconciliation, BeatSaverApiError<T>> {
+        let local = scan(custom_levels)?;
+        let mut remote = HashMap::new();
+        for chunk in local.chunks(MAPS_BY_HASHES_LIMIT) {
+            let hashes: Vec<String> = chunk.iter().map(|m| m.hash.clone()).collect();
+            let url = maps_by_hashes_url(self.base_url(), &hashes)?;
+            let data = self.request(url)?;
+            let maps = parse_maps_by_hashes(&hashes, data.as_str())?;
+            if let Some(index) = self.key_index() {
+                index.insert_maps(maps.values());
+            }
+            remote.extend(maps);
+        }
+        Ok(reconcile(local, &remote))
+    }
+    /// Gets the [ScoreSaber leaderboards][crate::scoresaber::Leaderboard] of the difficulties of
+    /// the latest version of a map
+    ///
+    /// Difficulties without a leaderboard are skipped. Requests are sent to
+    /// [SCORESABER_URL][crate::scoresaber::SCORESABER_URL] without the attached credentials.
+    #[cfg(feature = "scoresaber")]
+    fn scoresaber_leaderboards(
+        &self,
+        map: &MapDetail,
+    ) -> Result<Vec<Leaderboard>, BeatSaverApiError<T>> {
+        let version = match map.latest_version() {
+            Some(version) => version,
+            None => return Ok(vec![]),
+        };
+        let mut leaderboards = vec![];
+        for diff in &version.diffs {
+            let url = match leaderboard_url(version.hash.as_str(), diff) {
+                Some(url) => url,
+                None => continue,
+            };
+            let response = self.send(get_request(url.clone()))?;
+            leaderboards.extend(parse_leaderboard(&url, response)?);
+        }
+        Ok(leaderboards)
+    }
+    /// Joins the ranked status and star ratings from ScoreSaber onto a map
+    ///
+    /// See [join][crate::scoresaber::join] for how the leaderboards are applied.
+    #[cfg(feature = "scoresaber")]
+    fn join_scoresaber(&self, map: &mut MapDetail) -> Result<(), BeatSaverApiError<T>> {
+        let leaderboards = self.scoresaber_leaderboards(map)?;
+        join(map, &leaderboards);
+        Ok(())
+    }
+    /// Gets the [BeatLeader leaderboards][crate::beatleader::Leaderboard] of the difficulties of
+    /// the latest version of a map
+    ///
+    /// Versions without leaderboards return an empty list. The request is sent to
+    /// [BEATLEADER_URL][crate::beatleader::BEATLEADER_URL] without the attached credentials.
+    #[cfg(feature = "beatleader")]
+    fn beatleader_leaderboards(
+        &self,
+        map: &MapDetail,
+    ) -> Result<Vec<beatleader::Leaderboard>, BeatSaverApiError<T>> {
+        let hash = match map.hash() {
+            Some(hash) => hash,
+            None => return Ok(vec![]),
+        };
+        let url = beatleader::leaderboards_url(hash);
+        let response = self.send(get_request(url.clone()))?;
+        beatleader::parse_leaderboards(&url, response)
+    }
+    /// Joins the ranked status and star ratings from BeatLeader onto a map
+    ///
+    /// See [join][crate::beatleader::join] for how the leaderboards are applied.
+    #[cfg(feature = "beatleader")]
+    fn join_beatleader(&self, map: &mut MapDetail) -> Result<(), BeatSaverApiError<T>> {
+        let leaderboards = self.beatleader_leaderboards(map)?;
+        beatleader::join(map, &leaderboards);
+        Ok(())
+    }
+    /// Gets a page of the maps recommended by BeastSaber curators, pages start at 1
+    ///
+    /// The request is sent to [BSABER_URL][crate::bsaber::BSABER_URL] without the attached
+    /// credentials.
+    #[cfg(feature = "bsaber")]
+    fn bsaber_curated(&self, page: usize) -> Result<CuratedPage, BeatSaverApiError<T>> {
+        let url = curated_url(page);
+        let response = self.send(get_request(url.clone()))?;
+        parse_curated(&url, response)
+    }
+    /// Gets a page of the maps recommended by BeastSaber curators from BeatSaver, pages start at 1
+    ///
+    /// Maps are returned in the order of the recommendations, maps unknown to BeatSaver are
+    /// skipped.
+    #[cfg(feature = "bsaber")]
+    fn bsaber_curated_maps(&self, page: usize) -> Result<Vec<MapDetail>, BeatSaverApiError<T>> {
+        let hashes = self.bsaber_curated(page)?.hashes();
+        let mut maps = HashMap::new();
+        for chunk in hashes.chunks(MAPS_BY_HASHES_LIMIT) {
+            match self.maps_by_hashes(chunk) {
+                Ok(found) => maps.extend(found),
+                // A single unknown hash is a 404 rather than a missing entry
+                Err(BeatSaverApiError::HttpError {
+                    status: StatusCode::NOT_FOUND,
+                    ..
+                }) if chunk.len() == 1 => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(hashes.iter().filter_map(|h| maps.remove(h)).collect())
+    }
+    /// Downloads a provided map into a writer, streaming the archive chunk by chunk
+    ///
+    /// `progress` is called after every chunk with the number of bytes downloaded so far and the
+    /// total size of the archive (if the server sent a `Content-Length`). Returns the number of
+    /// bytes written.
+    fn download_to<W: Write, F: FnMut(u64, Option<u64>)>(
+        &self,
+        id: MapId,
+        writer: &mut W,
+        mut progress: F,
+    ) -> Result<u64, BeatSaverApiError<T>> {
+        let url = download_url(self.base_url(), &id);
+        let mut attempts = Attempts::new(
+            self.retry_policy(),
+            self.rate_limit_policy(),
+            self.rate_limit_tracker(),
+        );
+        let response = loop {
+            if let Some(pause) = attempts.pause() {
+                self.sleep(pause);
+            }
+            let request = authorize(get_request(url.clone()), self.credentials());
+            let delay = match self.send_streaming(request) {
+                Ok(response) => {
+                    track_rate_limit(self.rate_limit_tracker(), response.headers());
+                    if response.status().is_success() {
+                        break response;
+                    }
+                    let (parts, mut body) = response.into_parts();
+                    let mut data = vec![];
+                    body.read_to_end(&mut data)?;
+                    let response = Response::from_parts(parts, Bytes::from(data));
+                    match is_retryable_status(response.status()) {
+                        true => attempts
+                            .next_retry()
+                            .ok_or_else(|| status_error(&url, response))?,
+                        false => attempts.retry_error(status_error(&url, response), false)?,
+                    }
+                }
+                Err(e) => {
+                    let transient = self.is_transient(&e);
+                    attempts.retry_error(e, transient)?
+                }
+            };
+            self.sleep(delay);
+        };
+        let total = content_length(&response);
+        let mut body = response.into_body();
+
+        let mut buf = [0u8; DOWNLOAD_CHUNK_SIZE];
+        let mut downloaded = 0;
+        loop {
+            let n = match body.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            writer.write_all(&buf[..n])?;
+            downloaded += n as u64;
+            progress(downloaded, total);
+        }
+        writer.flush()?;
+        Ok(downloaded)
+    }
+    /// Downloads the audio preview (MP3) of a [MapVersion][crate::model::map::MapVersion]
+    ///
+    /// Note: the preview is served by the CDN, so [credentials][crate::auth::Credentials] are not sent
+    fn download_preview(&self, version: &MapVersion) -> Result<Bytes, BeatSaverApiError<T>> {
+        let url = Url::parse(version.preview_url.as_str())
+            .map_err(|_| BeatSaverApiError::ArgumentError("invalid preview URL"))?;
+        let response = self.send(get_request(url.clone()))?;
+        response_body(&url, response)
+    }
+    /// Downloads the cover image (JPEG) of a [MapVersion][crate::model::map::MapVersion]
+    ///
+    /// Note: the cover is served by the CDN, so [credentials][crate::auth::Credentials] are not sent.
+    /// Covers are stored in the [response cache][crate::BeatSaverApiSync::response_cache], if any.
+    fn download_cover(&self, version: &MapVersion) -> Result<Bytes, BeatSaverApiError<T>> {
+        let url = Url::parse(version.cover_url.as_str())
+            .map_err(|_| BeatSaverApiError::ArgumentError("invalid cover URL"))?;
+        let cache = match self.response_cache() {
+            Some(cache) => cache,
+            None => return response_body(&url, self.send(get_request(url.clone()))?),
+        };
+        match Lookup::new(cache, &url) {
+            Lookup::Fresh(body) => Ok(body),
+            Lookup::Stale(cached) => {
+                let mut request = get_request(url.clone());
+                if let Some(cached) = &cached {
+                    request.headers_mut().extend(cached.conditional_headers());
+                }
+                let response = self.send(request)?;
+                response_body(&url, update(cache, &url, cached, response))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::{Credentials, OAuthConfig, TOKEN_URL};
+    use crate::cache::{DiskCache, MemoryCache, ResponseCache};
+    use crate::index::KeyIndex;
+    #[cfg(feature = "hash")]
+    use crate::install::{InstallOutcome, InstallTarget};
+    use crate::model::map::{MapDetail, MapState, Tag};
+    use crate::model::testplay::{TestplayFeedbackRequest, TestplayStateRequest};
+    use crate::model::user::UserVerifyRequest;
+    use crate::query::{LatestOptions, LatestSort, SearchOptions, SortOrder};
+    use crate::retry::RetryPolicy;
+    use crate::sync_api::iterate_page;
+    #[cfg(feature = "hash")]
+    use crate::tests::{map_archive, zip_archive, MAP_ARCHIVE_HASH};
+    use crate::tests::{
+        map_hash, map_json, page_json, playlist_maps_json, playlist_page_json, FakeClient,
+        FakeClientPaged, FakeError,
+    };
+    use crate::BeatSaverApiSync;
+    use crate::{
+        BeatSaverApiError, DownloadOutcome, IterErrorPolicy, MapId, Page, PageProgress,
+        RateLimitPolicy, RateLimitTracker, BEATSAVER_URL,
+    };
+    use bytes::Bytes;
+    use http::{Request, Response};
+    use std::cell::RefCell;
+    use std::collections::{HashMap, HashSet};
+    use std::convert::TryInto;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+    use url::Url;
+
+    impl BeatSaverApiSync<FakeError> for FakeClient {
+        fn credentials(&self) -> Option<&Credentials> {
+            self.credentials.as_ref()
+        }
+        fn key_index(&self) -> Option<&KeyIndex> {
+            Some(&self.index)
+        }
+        fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<FakeError>> {
+            assert_eq!(self.url.as_str(), request.uri().to_string());
+            Ok(Response::new(self.data.clone()))
+        }
+    }
+    impl BeatSaverApiSync<FakeError> for FakeClientPaged {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            Some(&self.index)
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            Some(&self.rate_limit_tracker)
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.retry_policy
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.cache.as_deref()
+        }
+        fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<FakeError>> {
+            let response = self
+                .rate_limited()
+                .or_else(|| self.server_error())
+                .or_else(|| self.not_found(&request));
+            if let Some(response) = response {
+                return Ok(response);
+            }
+            let url = Url::parse(request.uri().to_string().as_str()).unwrap();
+            let data = match self.pages.get(&url) {
+                Some(d) => d,
+                None => panic!("Url not found: {}", url.as_str()),
+            };
+            Ok(self.page(&request, data))
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("maps/id/2144").unwrap(),
+            map_json("2144").into(),
+        );
+        let map = client.map(&"2144".try_into().unwrap()).unwrap();
+        assert_eq!(map.id, "2144");
+        let hash = map_hash("2144");
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join(format!("maps/hash/{}", hash).as_str())
+                .unwrap(),
+            map_json("2144").into(),
+        );
+        let map = client.map(&hash.as_str().try_into().unwrap()).unwrap();
+        assert_eq!(map.hash(), Some(hash.as_str()));
+    }
+    #[test]
+    fn test_generic_client() {
+        // Ids created inside the function can be borrowed for a single call
+        fn names<C: BeatSaverApiSync<FakeError>>(client: &C, keys: &[usize]) -> Vec<String> {
+            keys.iter()
+                .map(|&key| client.map(&MapId::from(key)).unwrap().name)
+                .collect()
+        }
+
+        let mut pages = HashMap::new();
+        for key in ["1", "2"] {
+            let url = BEATSAVER_URL.join(format!("maps/id/{}", key).as_str());
+            pages.insert(url.unwrap(), map_json(key).into());
+        }
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(names(&client, &[1, 2]).len(), 2);
+    }
+    #[test]
+    fn test_map_by_key_hash() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("maps/id/2144").unwrap(),
+            map_json("2144").into(),
+        );
+        assert_eq!(client.map_by_key("2144").unwrap().id, "2144");
+        assert!(matches!(
+            client.map_by_key("z-z"),
+            Err(BeatSaverApiError::ArgumentError(_))
+        ));
+        let hash = map_hash("2144");
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join(format!("maps/hash/{}", hash).as_str())
+                .unwrap(),
+            map_json("2144").into(),
+        );
+        let map = client.map_by_hash(hash.as_str()).unwrap();
+        assert_eq!(map.hash(), Some(hash.as_str()));
+        assert!(matches!(
+            client.map_by_hash("2144"),
+            Err(BeatSaverApiError::ArgumentError(_))
+        ));
+    }
+    #[test]
+    fn test_map_not_found() {
+        let hash = map_hash("2144");
+        let mut client = FakeClientPaged::new(HashMap::new());
+        client
+            .deleted
+            .insert(BEATSAVER_URL.join("maps/id/2144").unwrap());
+        client.deleted.insert(
+            BEATSAVER_URL
+                .join(format!("maps/hash/{}", hash).as_str())
+                .unwrap(),
+        );
+        assert!(matches!(
+            client.map_by_key("2144"),
+            Err(BeatSaverApiError::NotFound(MapId::Key(k))) if k.as_str() == "2144"
+        ));
+        assert!(matches!(
+            client.map_by_hash(hash.to_uppercase().as_str()),
+            Err(BeatSaverApiError::NotFound(MapId::Hash(h))) if h == hash
+        ));
+        let err = client.map(&MapId::Hash(hash.clone())).unwrap_err();
+        assert_eq!(err.to_string(), format!("Map {} not found", hash));
+    }
+    #[test]
+    fn test_http_error() {
+        let url = BEATSAVER_URL.join("download/key/2144").unwrap();
+        let mut client = FakeClientPaged::new(HashMap::new());
+        client.deleted.insert(url.clone());
+        let e = client.download(MapId::from(0x2144)).unwrap_err();
+        assert!(matches!(
+            &e,
+            BeatSaverApiError::HttpError { status, .. } if *status == http::StatusCode::NOT_FOUND
+        ));
+        assert_eq!(e.url(), Some(&url));
+    }
+    #[test]
+    fn test_key_index() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("maps/id/1").unwrap(),
+            map_json("1").into(),
+        );
+        assert!(client.index.is_empty());
+        client.map(&"1".try_into().unwrap()).unwrap();
+        assert_eq!(
+            client.index.key(map_hash("1").as_str()),
+            Some("1".to_string())
+        );
+    }
+    #[test]
+    fn test_maps_by_ids() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("maps/ids/1,2144").unwrap(),
+            format!(r#"{{"1":{},"2144":{}}}"#, map_json("1"), map_json("2144")).into(),
+        );
+        let ids = vec!["1".try_into().unwrap(), "2144".try_into().unwrap()];
+        let maps = client.maps_by_ids(&ids).unwrap();
+        assert_eq!(maps.len(), 2);
+        assert_eq!(maps["2144"].id, "2144");
+        assert_eq!(
+            client.index.key(map_hash("2144").as_str()),
+            Some("2144".to_string())
+        );
+
+        // Hashes are resolved through the key index
+        let ids = vec![
+            map_hash("1").as_str().try_into().unwrap(),
+            "2144".try_into().unwrap(),
+        ];
+        let maps = client.maps_by_ids(&ids).unwrap();
+        assert_eq!(maps["1"].id, "1");
+    }
+    #[test]
+    fn test_maps_by_ids_invalid() {
+        let client = FakeClient::new(BEATSAVER_URL.join("maps/ids/1").unwrap(), "{}".into());
+        assert!(client.maps_by_ids(Vec::<MapId>::new()).is_err());
+        let ids = vec![map_hash("1").as_str().try_into().unwrap()];
+        assert!(client.maps_by_ids(&ids).is_err());
+        let ids: Vec<_> = (1..=51).map(MapId::from).collect();
+        assert!(client.maps_by_ids(&ids).is_err());
+    }
+    #[test]
+    fn test_maps_by_hashes() {
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join(format!("maps/hash/{},{}", map_hash("1"), map_hash("2")).as_str())
+                .unwrap(),
+            format!(
+                r#"{{"{}":{},"{}":null}}"#,
+                map_hash("1"),
+                map_json("1"),
+                map_hash("2")
+            )
+            .into(),
+        );
+        let hashes = vec![map_hash("1"), map_hash("2")];
+        let maps = client.maps_by_hashes(&hashes).unwrap();
+        assert_eq!(maps.len(), 1);
+        assert_eq!(maps[&map_hash("1")].id, "1");
+        assert_eq!(
+            client.index.key(map_hash("1").as_str()),
+            Some("1".to_string())
+        );
+        assert!(client.maps_by_hashes(Vec::<String>::new()).is_err());
+        assert!(client.maps_by_hashes(["1"]).is_err());
     }
     #[test]
     fn test_maps_by() {
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/uploader/5cff0b7298cc5a672c84e8a3/0").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":221,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":335,"length":217,"njs":12,"njsOffset":0,"bombs":0,"notes":926,"obstacles":17},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"},{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":335,"length":217,"njs":12,"njsOffset":0,"bombs":0,"notes":946,"obstacles":17},"hard":null,"normal":null},"name":"360Degree"}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Sara Bareilles","songName":"Brave","songSubName":"","bpm":92.5},"stats":{"downloads":10551,"plays":0,"downVotes":10,"upVotes":173,"heat":1357.5463584,"rating":0.8526874836722508},"description":"Been a long time. Hope you're all safe inside. o/ \n\nMade this for someone's birthday and wanted to play with 360.\n\nAlso, sign up for the new project I've been working on, getsupernatural.com.\nIf you like what I've done with Beat Saber, you'll like what I'm doing with Supernatural.","deletedAt":null,"_id":"5e8a5055d87e580006ca6357","key":"97d3","name":"Brave - Sara Bareilles 360","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"35447f96f2d03bd274f977f01f566b029a3f7a9d","uploaded":"2020-04-05T21:40:37.685Z","directDownload":"/cdn/97d3/35447f96f2d03bd274f977f01f566b029a3f7a9d.zip","downloadURL":"/api/download/key/97d3","coverURL":"/cdn/97d3/35447f96f2d03bd274f977f01f566b029a3f7a9d.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":387.5,"length":192,"njs":12,"njsOffset":0,"bombs":0,"notes":668,"obstacles":84},"expertPlus":{"duration":387.5,"length":192,"njs":15,"njsOffset":0,"bombs":0,"notes":770,"obstacles":129},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Young Pines","songName":"Start Right Now ft. Laney Jones","songSubName":"","bpm":121},"stats":{"downloads":52756,"plays":0,"downVotes":27,"upVotes":583,"heat":987.0983173,"rating":0.8896633415336082},"description":"We're gonna start right now! ... working out, of course!\n\nHope this makes you sweat. ;)","deletedAt":null,"_id":"5d8b94d7048dff0006da90d1","key":"65cb","name":"[YUR Workout] Start Right Now - Young Pines ft. Laney Jones","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"d203ee856b5b60d058fc1ff7aabca93ed7de1753","uploaded":"2019-09-25T16:24:55.914Z","directDownload":"/cdn/65cb/d203ee856b5b60d058fc1ff7aabca93ed7de1753.zip","downloadURL":"/api/download/key/65cb","coverURL":"/cdn/65cb/d203ee856b5b60d058fc1ff7aabca93ed7de1753.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":403,"length":201,"njs":12,"njsOffset":0,"bombs":8,"notes":614,"obstacles":41},"expertPlus":null,"hard":{"duration":403,"length":201,"njs":12,"njsOffset":1,"bombs":8,"notes":609,"obstacles":41},"normal":null},"name":"Standard"}],"levelAuthorName":"BennyDabeast","songAuthorName":"5 Seconds of Summer","songName":"Youngblood","songSubName":"","bpm":120},"stats":{"downloads":31152,"plays":0,"downVotes":17,"upVotes":742,"heat":939.6463574,"rating":0.9127603275186517},"description":"Just an Expert level.","deletedAt":null,"_id":"5d6aeb6ae6a676000604751c","key":"6078","name":"Youngblood - 5 Seconds of Summer","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"182e88e0e80450dd02bdcd6ac4924e3cc00d8673","uploaded":"2019-08-31T21:49:30.872Z","directDownload":"/cdn/6078/182e88e0e80450dd02bdcd6ac4924e3cc00d8673.zip","downloadURL":"/api/download/key/6078","coverURL":"/cdn/6078/182e88e0e80450dd02bdcd6ac4924e3cc00d8673.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":649,"length":223,"njs":12,"njsOffset":0,"bombs":12,"notes":371,"obstacles":8},"expert":{"duration":649,"length":223,"njs":15,"njsOffset":1,"bombs":12,"notes":686,"obstacles":8},"expertPlus":null,"hard":{"duration":649,"length":223,"njs":12,"njsOffset":0,"bombs":12,"notes":461,"obstacles":8},"normal":null},"name":"Standard"}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Ellie Goulding","songName":"Burn","songSubName":"","bpm":174},"stats":{"downloads":34715,"plays":0,"downVotes":22,"upVotes":671,"heat":920.402318,"rating":0.9029187964493368},"description":"Literally not an easter egg. Just a fix.","deletedAt":null,"_id":"5d5dbd21085fff00062e947f","key":"5e5b","name":"Burn - Remastered","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"b4df429f5da907afb2d11e03439f0e4610316e8e","uploaded":"2019-08-21T21:52:33.300Z","directDownload":"/cdn/5e5b/b4df429f5da907afb2d11e03439f0e4610316e8e.zip","downloadURL":"/api/download/key/5e5b","coverURL":"/cdn/5e5b/b4df429f5da907afb2d11e03439f0e4610316e8e.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":381.5,"length":197,"njs":12,"njsOffset":0,"bombs":0,"notes":811,"obstacles":10},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Spencer Ludwig","songName":"Got Me Like","songSubName":"","bpm":116},"stats":{"downloads":11956,"plays":0,"downVotes":12,"upVotes":249,"heat":918.451418,"rating":0.8690860475200204},"description":"Yummy! I don't have a lot of time these days for mapping Beat Saber songs, but I found a moment to drop an Ex+ track together for a song that's been stuck in my ears for a hot minute. Made it really quick though, so it's a bit rough. :P Enjoy!\n\nHad a YouTube preview, but didn't realize CameraPlus was off for smoothing. XD","deletedAt":null,"_id":"5d5cb31a2c316f00068795ec","key":"5e2d","name":"Got Me Like - Spencer Ludwig","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"f3e3f1215ba6160539c79ca06f41718260c53e8a","uploaded":"2019-08-21T02:57:30.133Z","directDownload":"/cdn/5e2d/f3e3f1215ba6160539c79ca06f41718260c53e8a.zip","downloadURL":"/api/download/key/5e2d","coverURL":"/cdn/5e2d/f3e3f1215ba6160539c79ca06f41718260c53e8a.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":386.33087158203125,"length":246,"bombs":0,"notes":1240,"obstacles":3,"njs":13,"njsOffset":0}}}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Weezer","songName":"Perfect Situation","songSubName":"","bpm":94},"stats":{"downloads":13059,"plays":0,"downVotes":34,"upVotes":57,"heat":831.2705988,"rating":0.5939771775658828},"description":"Releasing my first 6 lane EX+ map. Enjoy the workout!","deletedAt":null,"_id":"5d21886b36e5930006fc36fb","key":"55d6","name":"(6 Lane) Perfect Situation - Weezer","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"18fc2b140f04041bf67c6cde01137634f814d841","uploaded":"2019-07-07T05:51:39.192Z","directDownload":"/cdn/55d6/18fc2b140f04041bf67c6cde01137634f814d841.zip","downloadURL":"/api/download/key/55d6","coverURL":"/cdn/55d6/18fc2b140f04041bf67c6cde01137634f814d841.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":532,"length":187,"bombs":28,"notes":840,"obstacles":17,"njs":12,"njsOffset":0}}}],"levelAuthorName":"BennyDaBeast","songAuthorName":"K/DA (ft Madison Beer, (G)I-DLE, Jaira Burns)","songName":"POP/STARS","songSubName":"","bpm":170},"stats":{"downloads":50190,"plays":0,"downVotes":70,"upVotes":261,"heat":798.5388126,"rating":0.7382579354059302},"description":"Re-upload of an earlier Beatmap that was lost to the update.","deletedAt":null,"_id":"5d0a6d30e66d5000063fc546","key":"538a","name":"KDA/POPSTARS - League of Legends","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"6b7c5baf85b9e4402b3461eb137908d4522a9a9c","uploaded":"2019-06-19T17:13:20.065Z","directDownload":"/cdn/538a/6b7c5baf85b9e4402b3461eb137908d4522a9a9c.zip","downloadURL":"/api/download/key/538a","coverURL":"/cdn/538a/6b7c5baf85b9e4402b3461eb137908d4522a9a9c.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":318,"length":190,"bombs":0,"notes":269,"obstacles":18,"njs":10,"njsOffset":0},"hard":{"duration":318.5,"length":191,"bombs":0,"notes":374,"obstacles":18,"njs":10,"njsOffset":0},"expert":{"duration":318.5,"length":191,"bombs":0,"notes":551,"obstacles":27,"njs":10,"njsOffset":0},"expertPlus":{"duration":318.5,"length":191,"bombs":4,"notes":720,"obstacles":27,"njs":10,"njsOffset":0}}}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Imagine Dragons","songName":"On Top of the World","songSubName":"","bpm":100},"stats":{"downloads":137082,"plays":0,"downVotes":72,"upVotes":2387,"heat":799.6083973,"rating":0.925846372391154},"description":"Re-upload of an earlier Beatmap that was lost to the update.","deletedAt":null,"_id":"5d0a6abddee262000650b000","key":"5389","name":"On Top of the World - Imagine Dragons","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"88314981432a8002f62e464562c0c41f06393ab5","uploaded":"2019-06-19T17:02:53.084Z","directDownload":"/cdn/5389/88314981432a8002f62e464562c0c41f06393ab5.zip","downloadURL":"/api/download/key/5389","coverURL":"/cdn/5389/88314981432a8002f62e464562c0c41f06393ab5.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":427,"length":184,"bombs":0,"notes":246,"obstacles":16,"njs":10,"njsOffset":0},"hard":{"duration":427,"length":184,"bombs":0,"notes":400,"obstacles":16,"njs":10,"njsOffset":0},"expert":{"duration":427,"length":184,"bombs":0,"notes":537,"obstacles":16,"njs":10,"njsOffset":0},"expertPlus":{"duration":427,"length":184,"bombs":0,"notes":715,"obstacles":16,"njs":10,"njsOffset":0}}}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Two Door Cinema Club","songName":"What You Know","songSubName":"","bpm":139},"stats":{"downloads":44035,"plays":0,"downVotes":112,"upVotes":788,"heat":799.071015,"rating":0.8271145221130625},"description":"Re-upload of an earlier Beatmap that was lost to the update.","deletedAt":null,"_id":"5d0a6a40c87a6a000653a546","key":"5388","name":"What You Know - Two Door Cinema Club","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"b8830915b5023c4c8030b2b0077688eb8508dc4c","uploaded":"2019-06-19T17:00:48.072Z","directDownload":"/cdn/5388/b8830915b5023c4c8030b2b0077688eb8508dc4c.zip","downloadURL":"/api/download/key/5388","coverURL":"/cdn/5388/b8830915b5023c4c8030b2b0077688eb8508dc4c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":514,"length":268,"bombs":0,"notes":639,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":514,"length":268,"bombs":0,"notes":776,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Uptown Funk","songSubName":"Mark Ronson","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":115},"stats":{"downloads":257325,"plays":0,"downVotes":164,"upVotes":6894,"heat":763.6662151,"rating":0.94367246970076},"description":"5/31: This version now works for the Oculus Quest.\r\n\r\nDifficulties: Expert and Hard\r\nYouTube Link: https://youtu.be/6TYji_Klr9I","deletedAt":null,"_id":"5cff621748229f7d88fc9549","key":"5038","name":"Uptown Funk - Mark Ronson","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-05-31T17:58:39.000Z","hash":"d110e413fb7fb462b692f1f17b835cf8b7280884","directDownload":"/cdn/5038/d110e413fb7fb462b692f1f17b835cf8b7280884.zip","downloadURL":"/api/download/key/5038","coverURL":"/cdn/5038/d110e413fb7fb462b692f1f17b835cf8b7280884.png"}],"totalDocs":46,"lastPage":2,"prevPage":null,"nextPage":1}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/uploader/5cff0b7298cc5a672c84e8a3/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":608,"length":190,"bombs":16,"notes":822,"obstacles":20,"njs":12,"njsOffset":0}}}],"songName":"The Greatest (ft. Kendrick Lamar)","songSubName":"Sia","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":192},"stats":{"downloads":109163,"plays":0,"downVotes":53,"upVotes":2039,"heat":653.9647126,"rating":0.927160709241388},"description":"YouTube Preview: https://youtu.be/huUMotlFpig","deletedAt":null,"_id":"5cff621648229f7d88fc8cf7","key":"4377","name":"The Greatest - Sia","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T21:20:03.000Z","hash":"58cd8ddf99600d967bca61285e9e0c429138009d","directDownload":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.zip","downloadURL":"/api/download/key/4377","coverURL":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":420,"length":201,"bombs":132,"notes":693,"obstacles":13,"njs":12,"njsOffset":0}}}],"songName":"Came Here for Love","songSubName":"Sigala & Ella Eyre","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":125},"stats":{"downloads":56604,"plays":0,"downVotes":29,"upVotes":877,"heat":653.490707,"rating":0.9077478149713},"description":"I haven't had this much fun playing a map in a long time to a freakin' amazing song! I hope you enjoy it as much as I do! :D\r\nYouTube Preview: Coming Soon","deletedAt":null,"_id":"5cff621648229f7d88fc8cf4","key":"4373","name":"Came Here for Love - Sigala & Ella Eyre","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T20:01:44.000Z","hash":"19a00f2fbe514aa821cf8ad68962d53bfa28b731","directDownload":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.zip","downloadURL":"/api/download/key/4373","coverURL":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":501,"length":193,"bombs":0,"notes":231,"obstacles":8,"njs":10,"njsOffset":0},"normal":{"duration":501,"length":193,"bombs":0,"notes":259,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":501,"length":193,"bombs":0,"notes":442,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":501,"length":193,"bombs":0,"notes":487,"obstacles":8,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Kamikaze","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":155},"stats":{"downloads":65622,"plays":0,"downVotes":23,"upVotes":878,"heat":647.9081883,"rating":0.9132933652430438},"description":"An older map that I have no idea why I never published. So here it is!","deletedAt":null,"_id":"5cff621648229f7d88fc8c9d","key":"42de","name":"Kamikaze - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-01T22:12:10.000Z","hash":"63d2d5920e6676f63bf997ae606b6d1590cd8c8d","directDownload":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.zip","downloadURL":"/api/download/key/42de","coverURL":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":392.1283264160156,"length":235,"bombs":0,"notes":880,"obstacles":43,"njs":14,"njsOffset":0}}}],"songName":"Heart Afire (feat. Strix)","songSubName":"Defqwop","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":100},"stats":{"downloads":16313,"plays":0,"downVotes":8,"upVotes":438,"heat":645.1888462,"rating":0.9052768696521943},"description":"YouTube Preview: https://youtu.be/dY-jMl3RKpw\r\nEDIT: Fixed Borked Lighting","deletedAt":null,"_id":"5cff621648229f7d88fc8c55","key":"425c","name":"Heart Afire (feat. Strix) - Defqwop","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-31T15:56:32.000Z","hash":"74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0","directDownload":"/cdn/425c/74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0.zip","downloadURL":"/api/download/key/425c","coverURL":"/cdn/425c/74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":554.9882202148438,"length":188,"bombs":0,"notes":1015,"obstacles":17,"njs":12,"njsOffset":0}}}],"songName":"RISE (ft. The Glitch Mob, Mako, The Word Alive)","songSubName":"League of Legends","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":177},"stats":{"downloads":20985,"plays":0,"downVotes":70,"upVotes":386,"heat":643.0575537,"rating":0.7916663490366552},"description":"YouTube Preview: https://youtu.be/Zf_wiprotdw","deletedAt":null,"_id":"5cff621648229f7d88fc8c15","key":"4200","name":"RISE feat The Glitch Mob, Mako, The Word Alive - League of Legends","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-30T14:58:24.000Z","hash":"ca715e7b3ab2b5514919893f150190717e2efbbe","directDownload":"/cdn/4200/ca715e7b3ab2b5514919893f150190717e2efbbe.zip","downloadURL":"/api/download/key/4200","coverURL":"/cdn/4200/ca715e7b3ab2b5514919893f150190717e2efbbe.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24170,"plays":0,"downVotes":24,"upVotes":346,"heat":641.4545003,"rating":0.8618247442569846},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":584,"length":213,"bombs":0,"notes":1050,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Girlfriend","songSubName":"Avril Lavigne","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":164},"stats":{"downloads":65101,"plays":0,"downVotes":78,"upVotes":641,"heat":638.124064,"rating":0.8374895085870108},"description":"YouTube Preview: Coming Soon","deletedAt":null,"_id":"5cff621548229f7d88fc8b9a","key":"413b","name":"Girlfriend - Avril Lavigne","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-27T22:10:10.000Z","hash":"164414d063e1728486f7996582a10250652940ec","directDownload":"/cdn/413b/164414d063e1728486f7996582a10250652940ec.zip","downloadURL":"/api/download/key/413b","coverURL":"/cdn/413b/164414d063e1728486f7996582a10250652940ec.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":387,"obstacles":54,"njs":10,"njsOffset":0},"expert":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":616,"obstacles":54,"njs":12,"njsOffset":0},"expertPlus":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":868,"obstacles":57,"njs":14,"njsOffset":0}}}],"songName":"Nevada (feat. Cozi Zuehlsdorff)","songSubName":"Vicetone","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":124},"stats":{"downloads":30032,"plays":0,"downVotes":32,"upVotes":1066,"heat":638.1105428,"rating":0.9136481130972827},"description":"YouTube Preview: https://youtu.be/3gb2gH8eueI","deletedAt":null,"_id":"5cff621548229f7d88fc8b94","key":"4131","name":"Nevada feat. Cozi Zuehlsdorff - Vicetone","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-27T18:42:01.000Z","hash":"5e847d92de6e01dfd5ce3491969b3939b4374699","directDownload":"/cdn/4131/5e847d92de6e01dfd5ce3491969b3939b4374699.zip","downloadURL":"/api/download/key/4131","coverURL":"/cdn/4131/5e847d92de6e01dfd5ce3491969b3939b4374699.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":395.75,"length":221,"bombs":0,"notes":937,"obstacles":6,"njs":14,"njsOffset":0}}}],"songName":"Alone feat. Kyle Reynolds","songSubName":"Asketa & Natan Chaim","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":107},"stats":{"downloads":53318,"plays":0,"downVotes":26,"upVotes":707,"heat":634.3503027,"rating":0.9007980474001192},"description":"You ever just find a map gathering dust but pretty much finished? Yeah... let's go ahead and release that.\r\nDifficulties: Expert+ Only\r\nYouTube Preview: https://youtu.be/cg1wBYBCqX0","deletedAt":null,"_id":"5cff621548229f7d88fc8b42","key":"40b2","name":"Alone feat. Kyle Reynolds - Asketa & Natan Chaim","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-25T21:57:52.000Z","hash":"84ac2667162920902490fb1a572ed4cf5ad50a1f","directDownload":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.zip","downloadURL":"/api/download/key/40b2","coverURL":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":432,"obstacles":284,"njs":10,"njsOffset":0},"expert":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":616,"obstacles":293,"njs":10,"njsOffset":0},"expertPlus":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":932,"obstacles":307,"njs":14,"njsOffset":0}}}],"songName":"High Enough ft. Rosie Darling","songSubName":"Justin Caruso","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":54616,"plays":0,"downVotes":133,"upVotes":615,"heat":626.3101804,"rating":0.7782575573900176},"description":"Difficulties: Expert+, Expert, Hard\r\nYouTube Preview: https://youtu.be/pGiaa-PJOps","deletedAt":null,"_id":"5cff621548229f7d88fc8a9d","key":"3f8b","name":"High Enough ft. Rosie Darling (Baaku Remix) - Justin Caruso","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-21T19:20:21.000Z","hash":"b5483e3f38df32d233700b49a0bdbf72ba1650cc","directDownload":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.zip","downloadURL":"/api/download/key/3f8b","coverURL":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.jpg"}],"totalDocs":46,"lastPage":2,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/uploader/5cff0b7298cc5a672c84e8a3/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61431,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":357,"length":177,"bombs":0,"notes":373,"obstacles":268,"njs":10,"njsOffset":0},"expert":{"duration":357,"length":177,"bombs":0,"notes":661,"obstacles":291,"njs":10,"njsOffset":0},"expertPlus":{"duration":357,"length":177,"bombs":0,"notes":875,"obstacles":291,"njs":14,"njsOffset":0}}}],"songName":"Go Bang","songSubName":"PNAU","songAuthorName":"BennyDaBeast & TheRealZeroz","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":55668,"plays":0,"downVotes":63,"upVotes":979,"heat":514.3598733,"rating":0.8852888597073685},"description":"YouTube Preview: Coming Soon\r\nLighting By: TheRealZeroz\r\nMany many thanks to TheRealZeros for the grade A lighting, especially with the cool strobes! :D","deletedAt":null,"_id":"5cff621348229f7d88fc80e7","key":"300a","name":"Go Bang - PNAU (Lighting by TheRealZeroz)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-22T08:28:29.000Z","hash":"7573b8504b186f217787fb8fb7c5218012c6299d","directDownload":"/cdn/300a/7573b8504b186f217787fb8fb7c5218012c6299d.zip","downloadURL":"/api/download/key/300a","coverURL":"/cdn/300a/7573b8504b186f217787fb8fb7c5218012c6299d.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23796,"plays":4,"downVotes":17,"upVotes":98,"heat":435.3491072,"rating":0.7679775361870059},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":545.281494140625,"length":212,"bombs":0,"notes":1007,"obstacles":17,"njs":11,"njsOffset":0}}}],"songName":"The Other Side (The Greatest Showman)","songSubName":"Hugh Jackman & Zac Efron","songAuthorName":"BennyDaBeast, Lit by Willums & Assistant","levelAuthorName":"bennydabeast","bpm":154},"stats":{"downloads":76441,"plays":11,"downVotes":42,"upVotes":995,"heat":430.8640707,"rating":0.9027024852773321},"description":"Expert+ Only!","deletedAt":null,"_id":"5cff621248229f7d88fc79e5","key":"24e3","name":"The Other Side (The Greatest Showman)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-09T20:33:44.000Z","hash":"d1a01de5b779523c390b594bd0f61ed57b0e41f8","directDownload":"/cdn/24e3/d1a01de5b779523c390b594bd0f61ed57b0e41f8.zip","downloadURL":"/api/download/key/24e3","coverURL":"/cdn/24e3/d1a01de5b779523c390b594bd0f61ed57b0e41f8.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":417,"length":195,"bombs":4,"notes":301,"obstacles":24,"njs":10,"njsOffset":0},"hard":{"duration":417,"length":195,"bombs":4,"notes":486,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":417.5,"length":195,"bombs":4,"notes":620,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":{"duration":417.5,"length":195,"bombs":0,"notes":894,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Shut Up and Dance","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":433479,"plays":558,"downVotes":140,"upVotes":11089,"heat":395.8353745,"rating":0.9581072075276635},"description":"Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY","deletedAt":null,"_id":"5cff621148229f7d88fc77c9","key":"2144","name":"Shut Up and Dance - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-21T01:27:00.000Z","hash":"89cf8bb07afb3c59ae7b5ac00337d62261c36fb4","directDownload":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip","downloadURL":"/api/download/key/2144","coverURL":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.png"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":528,"length":198,"bombs":0,"notes":280,"obstacles":26,"njs":10,"njsOffset":0},"normal":{"duration":528,"length":198,"bombs":0,"notes":360,"obstacles":26,"njs":10,"njsOffset":0},"hard":{"duration":530,"length":198,"bombs":0,"notes":521,"obstacles":154,"njs":12,"njsOffset":0},"expert":{"duration":530,"length":198,"bombs":0,"notes":746,"obstacles":155,"njs":12,"njsOffset":0},"expertPlus":{"duration":530,"length":198,"bombs":0,"notes":1225,"obstacles":207,"njs":14,"njsOffset":0}}}],"songName":"Don't Let Me Down (Illenium Remix)","songSubName":"The Chainsmokers feat. Daya","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":258504,"plays":1391,"downVotes":136,"upVotes":3843,"heat":379.4741337,"rating":0.9274015748142236},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nDifficulties: Expert+, Expert, Hard, Normal","deletedAt":null,"_id":"5cff621148229f7d88fc76f2","key":"1ff6","name":"Don't Let Me Down (Illenium Remix) - The Chainsmokers feat. Daya (100k Contest)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-12T18:48:50.000Z","hash":"5639a12cccf90238055116ec53e5297aa004627e","directDownload":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.zip","downloadURL":"/api/download/key/1ff6","coverURL":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33337,"plays":366,"downVotes":20,"upVotes":785,"heat":339.1379059,"rating":0.9117768411304571},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":434.5,"length":186,"bombs":0,"notes":256,"obstacles":5,"njs":10,"njsOffset":0},"hard":{"duration":434.5,"length":186,"bombs":0,"notes":334,"obstacles":5,"njs":10,"njsOffset":0},"expert":{"duration":434.5,"length":186,"bombs":0,"notes":492,"obstacles":5,"njs":10,"njsOffset":0},"expertPlus":{"duration":434.5,"length":186,"bombs":0,"notes":671,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"Monster Mash","songSubName":"Boris Pickett","songAuthorName":"(HallowBeats) BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":54354,"plays":1403,"downVotes":45,"upVotes":387,"heat":332.5239372,"rating":0.8321757094972239},"description":"'tis the season to be spooky, fall and fall and fall leaves to the ground!\r\nDifficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/MIbpQlOdGw8","deletedAt":null,"_id":"5cff621048229f7d88fc742b","key":"1b0c","name":"[HallowBeats] Monster Mash - Boris Pickett","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-19T20:52:26.000Z","hash":"96dd957c33ed3d7094217737cedbe8700140f437","directDownload":"/cdn/1b0c/96dd957c33ed3d7094217737cedbe8700140f437.zip","downloadURL":"/api/download/key/1b0c","coverURL":"/cdn/1b0c/96dd957c33ed3d7094217737cedbe8700140f437.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":634,"length":190,"bombs":0,"notes":277,"obstacles":9,"njs":10,"njsOffset":0},"hard":{"duration":635,"length":190,"bombs":0,"notes":317,"obstacles":9,"njs":10,"njsOffset":0},"expert":{"duration":635,"length":190,"bombs":0,"notes":541,"obstacles":9,"njs":10,"njsOffset":0},"expertPlus":{"duration":635,"length":190,"bombs":0,"notes":848,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"Under The Sea","songSubName":"Samuel E. Wright","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":200},"stats":{"downloads":250016,"plays":3317,"downVotes":119,"upVotes":5076,"heat":312.7946411,"rating":0.9407792405748187},"description":"\"What do they got? A lot of sand. We got a hot crustacean band! Each little clam here know how to jam here, under the sea!\"\r\nDifficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/h6eMPlGUZsw","deletedAt":null,"_id":"5cff621048229f7d88fc7327","key":"195f","name":"Under The Sea (Little Mermaid) - Samuel E. Wright","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T23:44:34.000Z","hash":"f745bac45c618fa2c49a0ee0c383f2a6cb356a78","directDownload":"/cdn/195f/f745bac45c618fa2c49a0ee0c383f2a6cb356a78.zip","downloadURL":"/api/download/key/195f","coverURL":"/cdn/195f/f745bac45c618fa2c49a0ee0c383f2a6cb356a78.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":413.125,"length":130,"bombs":179,"notes":664,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"THE HEISENBERG SONG","songSubName":"Say My Name","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":191},"stats":{"downloads":30245,"plays":129,"downVotes":39,"upVotes":41,"heat":308.6084522,"rating":0.5091703485317295},"description":"https://www.twitch.tv/heisenberg_irl\r\n\r\nA tribute to the mapping style of the one and only, Heisenberg in real life. The real Heisenberg. Not the fake one.","deletedAt":null,"_id":"5cff621048229f7d88fc731b","key":"194e","name":"THE HEISENBERG SONG (Tribute to Heisenbergirl)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T13:50:34.000Z","hash":"c3b7b8a99a8f07961122b37547794f03adb27f96","directDownload":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.zip","downloadURL":"/api/download/key/194e","coverURL":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.png"}],"totalDocs":46,"lastPage":2,"prevPage":1,"nextPage":null}"#.into());
+        pages.insert(
+            BEATSAVER_URL.join("maps/uploader/58338/0").unwrap(),
+            page_json(&["97d3", "65cb", "6078"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("maps/uploader/58338/1").unwrap(),
+            page_json(&["5e5b", "2144"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("maps/uploader/58338/2").unwrap(),
+            page_json(&[]),
+        );
         let client = FakeClientPaged::new(pages);
         assert_eq!(
             client
-                .maps_by(&BeatSaverUser {
-                    id: "5cff0b7298cc5a672c84e8a3".into(),
-                    username: "bennydabeast".into()
-                })
-                .map(|m| m.unwrap().key)
+                .maps_by(58338)
+                .map(|m| m.unwrap().id)
                 .collect::<Vec<String>>(),
             vec![
                 "97d3".to_string(),
                 "65cb".to_string(),
                 "6078".to_string(),
                 "5e5b".to_string(),
-                "5e2d".to_string(),
-                "55d6".to_string(),
-                "538a".to_string(),
-                "5389".to_string(),
-                "5388".to_string(),
-                "5038".to_string(),
-                "4377".to_string(),
-                "4373".to_string(),
-                "42de".to_string(),
-                "425c".to_string(),
-                "4200".to_string(),
-                "41cc".to_string(),
-                "413b".to_string(),
-                "4131".to_string(),
-                "40b2".to_string(),
-                "3f8b".to_string(),
-                "31f8".to_string(),
-                "300a".to_string(),
-                "2556".to_string(),
-                "24e3".to_string(),
+                "2144".to_string(),
+            ]
+        );
+    }
+    #[test]
+    fn test_maps_by_page_iter_rev() {
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL.join("maps/uploader/58338/0").unwrap(),
+            page_json(&["97d3", "65cb", "6078"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("maps/uploader/58338/1").unwrap(),
+            page_json(&["5e5b", "2144"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("maps/uploader/58338/2").unwrap(),
+            page_json(&[]),
+        );
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(
+            client
+                .maps_by_page_iter_rev(58338)
+                .unwrap()
+                .map(|m| m.unwrap().id)
+                .collect::<Vec<String>>(),
+            vec![
+                "2144".to_string(),
+                "5e5b".to_string(),
+                "6078".to_string(),
+                "65cb".to_string(),
+                "97d3".to_string(),
+            ]
+        );
+    }
+    #[test]
+    fn test_maps_by_page() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("maps/uploader/58338/2").unwrap(),
+            page_json(&["5e5b", "2144"]),
+        );
+        let page = client.maps_by_page(58338, 2).unwrap();
+        assert_eq!(page.docs.len(), 2);
+        assert_eq!(page.prev_page, Some(1));
+        assert_eq!(page.next_page, Some(3));
+    }
+    #[test]
+    fn test_maps_by_page_iter() {
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL.join("maps/uploader/58338/1").unwrap(),
+            page_json(&["5e5b", "2144"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("maps/uploader/58338/2").unwrap(),
+            page_json(&[]),
+        );
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(
+            client
+                .maps_by_page_iter(58338, 1)
+                .map(|m| m.unwrap().id)
+                .collect::<Vec<String>>(),
+            vec!["5e5b".to_string(), "2144".to_string(),]
+        );
+    }
+    #[test]
+    fn test_maps_rating() {
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/0?sortOrder=Rating")
+                .unwrap(),
+            page_json(&["2144", "570", "3cf5"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/1?sortOrder=Rating")
+                .unwrap(),
+            page_json(&["121f", "1fef"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/2?sortOrder=Rating")
+                .unwrap(),
+            page_json(&[]),
+        );
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(
+            client
+                .maps_rating()
+                .map(|m| m.unwrap().id)
+                .collect::<Vec<String>>(),
+            vec![
+                "2144".to_string(),
+                "570".to_string(),
+                "3cf5".to_string(),
+                "121f".to_string(),
+                "1fef".to_string(),
+            ]
+        );
+    }
+    #[test]
+    fn test_page_iterator_progress() {
+        let mut iter = iterate_page(
+            Box::new(|n| {
+                let data = format!(
+                    r#"{{"docs":[{},{}],"info":{{"total":6,"pages":3}}}}"#,
+                    n * 2,
+                    n * 2 + 1
+                );
+                Ok::<_, BeatSaverApiError<FakeError>>(Page::<usize>::parse(&data, n).unwrap())
+            }),
+            0,
+        );
+        assert_eq!(iter.progress(), PageProgress::default());
+        for _ in 0..3 {
+            iter.next().unwrap().unwrap();
+        }
+        assert_eq!(
+            iter.progress(),
+            PageProgress {
+                yielded: 3,
+                total_docs: Some(6),
+                current_page: Some(1),
+                last_page: Some(2),
+            }
+        );
+        assert_eq!(iter.by_ref().count(), 3);
+        assert_eq!(iter.progress().yielded, 6);
+        assert_eq!(iter.progress().current_page, Some(2));
+    }
+    /// Collects pages of two numbers each until the empty page 3, page 1 failing its first
+    /// `failures` requests
+    ///
+    /// Errors are collected as `None`, along with the pages requested.
+    fn flaky_pages(failures: usize, policy: IterErrorPolicy) -> (Vec<Option<usize>>, Vec<usize>) {
+        let requested = RefCell::new(vec![]);
+        let iter = iterate_page(
+            Box::new(|n| {
+                let mut requested = requested.borrow_mut();
+                requested.push(n);
+                if n == 1 && requested.iter().filter(|&&r| r == 1).count() <= failures {
+                    return Err(BeatSaverApiError::<FakeError>::ArgumentError("flaky"));
+                }
+                let docs = match n < 3 {
+                    true => vec![n * 2, n * 2 + 1],
+                    false => vec![],
+                };
+                Ok(Page::from_docs(docs.into(), n))
+            }),
+            0,
+        )
+        .error_policy(policy);
+        let docs = iter.map(|d| d.ok()).collect();
+        (docs, requested.into_inner())
+    }
+    #[test]
+    fn test_page_iterator_error_policy() {
+        assert_eq!(
+            flaky_pages(1, IterErrorPolicy::RetryForever),
+            (
+                vec![Some(0), Some(1), None, Some(2), Some(3), Some(4), Some(5)],
+                vec![0, 1, 1, 2, 3]
+            )
+        );
+        assert_eq!(
+            flaky_pages(1, IterErrorPolicy::FailFast),
+            (vec![Some(0), Some(1), None], vec![0, 1])
+        );
+        assert_eq!(
+            flaky_pages(2, IterErrorPolicy::Retry(2)),
+            (
+                vec![Some(0), Some(1), Some(2), Some(3), Some(4), Some(5)],
+                vec![0, 1, 1, 1, 2, 3]
+            )
+        );
+        assert_eq!(
+            flaky_pages(2, IterErrorPolicy::Retry(1)),
+            (vec![Some(0), Some(1), None], vec![0, 1, 1])
+        );
+        assert_eq!(
+            flaky_pages(1, IterErrorPolicy::SkipPage),
+            (
+                vec![Some(0), Some(1), None, Some(4), Some(5)],
+                vec![0, 1, 2, 3]
+            )
+        );
+    }
+    #[test]
+    fn test_maps_rating_pages() {
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/0?sortOrder=Rating")
+                .unwrap(),
+            page_json(&["2144", "570", "3cf5"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/1?sortOrder=Rating")
+                .unwrap(),
+            page_json(&["121f", "1fef"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/2?sortOrder=Rating")
+                .unwrap(),
+            page_json(&[]),
+        );
+        let client = FakeClientPaged::new(pages);
+        let pages = client
+            .maps_rating()
+            .pages()
+            .map(|p| {
+                let p = p.unwrap();
+                (p.next_page, p.docs.into_iter().map(|m| m.id).collect())
+            })
+            .collect::<Vec<(Option<usize>, Vec<String>)>>();
+        assert_eq!(
+            pages,
+            vec![
+                (Some(1), vec!["2144".into(), "570".into(), "3cf5".into()]),
+                (Some(2), vec!["121f".into(), "1fef".into()]),
+            ]
+        );
+    }
+    #[test]
+    fn test_maps_rating_page() {
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join("search/text/2?sortOrder=Rating")
+                .unwrap(),
+            page_json(&["121f", "1fef"]),
+        );
+        let page = client.maps_rating_page(2).unwrap();
+        assert_eq!(page.docs.len(), 2);
+        assert_eq!(page.prev_page, Some(1));
+        assert_eq!(page.next_page, Some(3));
+    }
+    #[test]
+    fn test_maps_rating_page_iter() {
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/1?sortOrder=Rating")
+                .unwrap(),
+            page_json(&["121f", "1fef"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/2?sortOrder=Rating")
+                .unwrap(),
+            page_json(&[]),
+        );
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(
+            client
+                .maps_rating_page_iter(1)
+                .map(|m| m.unwrap().id)
+                .collect::<Vec<String>>(),
+            vec!["121f".to_string(), "1fef".to_string(),]
+        );
+    }
+    #[test]
+    fn test_maps_plays() {
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL.join("maps/plays/0").unwrap(),
+            page_json(&["570", "2144", "4c6"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("maps/plays/1").unwrap(),
+            page_json(&["66e6", "4e6f"]),
+        );
+        pages.insert(BEATSAVER_URL.join("maps/plays/2").unwrap(), page_json(&[]));
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(
+            client
+                .maps_plays()
+                .map(|m| m.unwrap().id)
+                .collect::<Vec<String>>(),
+            vec![
+                "570".to_string(),
+                "2144".to_string(),
+                "4c6".to_string(),
+                "66e6".to_string(),
+                "4e6f".to_string(),
+            ]
+        );
+    }
+    #[test]
+    fn test_maps_plays_page() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("maps/plays/2").unwrap(),
+            page_json(&["66e6", "4e6f"]),
+        );
+        let page = client.maps_plays_page(2).unwrap();
+        assert_eq!(page.docs.len(), 2);
+        assert_eq!(page.prev_page, Some(1));
+        assert_eq!(page.next_page, Some(3));
+    }
+    #[test]
+    fn test_maps_plays_page_iter() {
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL.join("maps/plays/1").unwrap(),
+            page_json(&["66e6", "4e6f"]),
+        );
+        pages.insert(BEATSAVER_URL.join("maps/plays/2").unwrap(), page_json(&[]));
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(
+            client
+                .maps_plays_page_iter(1)
+                .map(|m| m.unwrap().id)
+                .collect::<Vec<String>>(),
+            vec!["66e6".to_string(), "4e6f".to_string(),]
+        );
+    }
+    #[test]
+    fn test_search() {
+        let query = "bennydabeast".to_string();
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL.join("search/text/0?q=bennydabeast").unwrap(),
+            page_json(&["2144", "1ff6", "1bc4"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("search/text/1?q=bennydabeast").unwrap(),
+            page_json(&["1b0c", "195f"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("search/text/2?q=bennydabeast").unwrap(),
+            page_json(&[]),
+        );
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(
+            client
+                .search(&query)
+                .map(|m| m.unwrap().id)
+                .collect::<Vec<String>>(),
+            vec![
                 "2144".to_string(),
                 "1ff6".to_string(),
                 "1bc4".to_string(),
                 "1b0c".to_string(),
                 "195f".to_string(),
-                "194e".to_string()
             ]
         );
     }
     #[test]
-    fn test_maps_by_page() {
-        let client = FakeClient::new(BEATSAVER_URL.join("api/maps/uploader/5cff0b7298cc5a672c84e98d/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61091,"plays":0,"downVotes":75,"upVotes":850,"heat":526.9025684,"rating":0.8653280150881011},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":357,"length":177,"bombs":0,"notes":373,"obstacles":268,"njs":10,"njsOffset":0},"expert":{"duration":357,"length":177,"bombs":0,"notes":661,"obstacles":291,"njs":10,"njsOffset":0},"expertPlus":{"duration":357,"length":177,"bombs":0,"notes":875,"obstacles":291,"njs":14,"njsOffset":0}}}],"songName":"Go Bang","songSubName":"PNAU","songAuthorName":"BennyDaBeast & TheRealZeroz","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":55371,"plays":0,"downVotes":63,"upVotes":973,"heat":514.3570192,"rating":0.8848876955933839},"description":"YouTube Preview: Coming Soon\r\nLighting By: TheRealZeroz\r\nMany many thanks to TheRealZeros for the grade A lighting, especially with the cool strobes! :D","deletedAt":null,"_id":"5cff621348229f7d88fc80e7","key":"300a","name":"Go Bang - PNAU (Lighting by TheRealZeroz)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-22T08:28:29.000Z","hash":"7573b8504b186f217787fb8fb7c5218012c6299d","directDownload":"/cdn/300a/7573b8504b186f217787fb8fb7c5218012c6299d.zip","downloadURL":"/api/download/key/300a","coverURL":"/cdn/300a/7573b8504b186f217787fb8fb7c5218012c6299d.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23574,"plays":4,"downVotes":16,"upVotes":97,"heat":435.3491072,"rating":0.7722707184352429},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":545.281494140625,"length":212,"bombs":0,"notes":1007,"obstacles":17,"njs":11,"njsOffset":0}}}],"songName":"The Other Side (The Greatest Showman)","songSubName":"Hugh Jackman & Zac Efron","songAuthorName":"BennyDaBeast, Lit by Willums & Assistant","levelAuthorName":"bennydabeast","bpm":154},"stats":{"downloads":75997,"plays":11,"downVotes":42,"upVotes":990,"heat":430.8617861,"rating":0.9024479312105432},"description":"Expert+ Only!","deletedAt":null,"_id":"5cff621248229f7d88fc79e5","key":"24e3","name":"The Other Side (The Greatest Showman)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-09T20:33:44.000Z","hash":"d1a01de5b779523c390b594bd0f61ed57b0e41f8","directDownload":"/cdn/24e3/d1a01de5b779523c390b594bd0f61ed57b0e41f8.zip","downloadURL":"/api/download/key/24e3","coverURL":"/cdn/24e3/d1a01de5b779523c390b594bd0f61ed57b0e41f8.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":417,"length":195,"bombs":4,"notes":301,"obstacles":24,"njs":10,"njsOffset":0},"hard":{"duration":417,"length":195,"bombs":4,"notes":486,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":417.5,"length":195,"bombs":4,"notes":620,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":{"duration":417.5,"length":195,"bombs":0,"notes":894,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Shut Up and Dance","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":427710,"plays":558,"downVotes":138,"upVotes":10940,"heat":395.8295042,"rating":0.9579970045720925},"description":"Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY","deletedAt":null,"_id":"5cff621148229f7d88fc77c9","key":"2144","name":"Shut Up and Dance - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-21T01:27:00.000Z","hash":"89cf8bb07afb3c59ae7b5ac00337d62261c36fb4","directDownload":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip","downloadURL":"/api/download/key/2144","coverURL":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.png"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":528,"length":198,"bombs":0,"notes":280,"obstacles":26,"njs":10,"njsOffset":0},"normal":{"duration":528,"length":198,"bombs":0,"notes":360,"obstacles":26,"njs":10,"njsOffset":0},"hard":{"duration":530,"length":198,"bombs":0,"notes":521,"obstacles":154,"njs":12,"njsOffset":0},"expert":{"duration":530,"length":198,"bombs":0,"notes":746,"obstacles":155,"njs":12,"njsOffset":0},"expertPlus":{"duration":530,"length":198,"bombs":0,"notes":1225,"obstacles":207,"njs":14,"njsOffset":0}}}],"songName":"Don't Let Me Down (Illenium Remix)","songSubName":"The Chainsmokers feat. Daya","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":256993,"plays":1391,"downVotes":135,"upVotes":3811,"heat":379.4704866,"rating":0.927275423748384},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nDifficulties: Expert+, Expert, Hard, Normal","deletedAt":null,"_id":"5cff621148229f7d88fc76f2","key":"1ff6","name":"Don't Let Me Down (Illenium Remix) - The Chainsmokers feat. Daya (100k Contest)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-12T18:48:50.000Z","hash":"5639a12cccf90238055116ec53e5297aa004627e","directDownload":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.zip","downloadURL":"/api/download/key/1ff6","coverURL":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33210,"plays":366,"downVotes":20,"upVotes":780,"heat":339.135058,"rating":0.9115234775631975},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":434.5,"length":186,"bombs":0,"notes":256,"obstacles":5,"njs":10,"njsOffset":0},"hard":{"duration":434.5,"length":186,"bombs":0,"notes":334,"obstacles":5,"njs":10,"njsOffset":0},"expert":{"duration":434.5,"length":186,"bombs":0,"notes":492,"obstacles":5,"njs":10,"njsOffset":0},"expertPlus":{"duration":434.5,"length":186,"bombs":0,"notes":671,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"Monster Mash","songSubName":"Boris Pickett","songAuthorName":"(HallowBeats) BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":54194,"plays":1403,"downVotes":45,"upVotes":386,"heat":332.5226655,"rating":0.831928595701797},"description":"'tis the season to be spooky, fall and fall and fall leaves to the ground!\r\nDifficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/MIbpQlOdGw8","deletedAt":null,"_id":"5cff621048229f7d88fc742b","key":"1b0c","name":"[HallowBeats] Monster Mash - Boris Pickett","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-19T20:52:26.000Z","hash":"96dd957c33ed3d7094217737cedbe8700140f437","directDownload":"/cdn/1b0c/96dd957c33ed3d7094217737cedbe8700140f437.zip","downloadURL":"/api/download/key/1b0c","coverURL":"/cdn/1b0c/96dd957c33ed3d7094217737cedbe8700140f437.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":634,"length":190,"bombs":0,"notes":277,"obstacles":9,"njs":10,"njsOffset":0},"hard":{"duration":635,"length":190,"bombs":0,"notes":317,"obstacles":9,"njs":10,"njsOffset":0},"expert":{"duration":635,"length":190,"bombs":0,"notes":541,"obstacles":9,"njs":10,"njsOffset":0},"expertPlus":{"duration":635,"length":190,"bombs":0,"notes":848,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"Under The Sea","songSubName":"Samuel E. Wright","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":200},"stats":{"downloads":248025,"plays":3317,"downVotes":118,"upVotes":5040,"heat":312.7915638,"rating":0.9407283375990468},"description":"\"What do they got? A lot of sand. We got a hot crustacean band! Each little clam here know how to jam here, under the sea!\"\r\nDifficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/h6eMPlGUZsw","deletedAt":null,"_id":"5cff621048229f7d88fc7327","key":"195f","name":"Under The Sea (Little Mermaid) - Samuel E. Wright","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T23:44:34.000Z","hash":"f745bac45c618fa2c49a0ee0c383f2a6cb356a78","directDownload":"/cdn/195f/f745bac45c618fa2c49a0ee0c383f2a6cb356a78.zip","downloadURL":"/api/download/key/195f","coverURL":"/cdn/195f/f745bac45c618fa2c49a0ee0c383f2a6cb356a78.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":413.125,"length":130,"bombs":179,"notes":664,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"THE HEISENBERG SONG","songSubName":"Say My Name","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":191},"stats":{"downloads":30210,"plays":129,"downVotes":39,"upVotes":41,"heat":308.6084522,"rating":0.5091703485317295},"description":"https://www.twitch.tv/heisenberg_irl\r\n\r\nA tribute to the mapping style of the one and only, Heisenberg in real life. The real Heisenberg. Not the fake one.","deletedAt":null,"_id":"5cff621048229f7d88fc731b","key":"194e","name":"THE HEISENBERG SONG (Tribute to Heisenbergirl)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T13:50:34.000Z","hash":"c3b7b8a99a8f07961122b37547794f03adb27f96","directDownload":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.zip","downloadURL":"/api/download/key/194e","coverURL":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.png"}],"totalDocs":46,"lastPage":4,"prevPage":1,"nextPage":3}"#.into());
-        let _: Page<Map> = client
-            .maps_by_page(
-                &BeatSaverUser {
-                    id: "5cff0b7298cc5a672c84e98d".into(),
-                    username: "bennydabeast".into(),
-                },
-                2,
-            )
-            .unwrap();
+    fn test_search_page() {
+        let query = "bennydabeast".to_string();
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("search/text/2?q=bennydabeast").unwrap(),
+            page_json(&["1b0c", "195f"]),
+        );
+        let page = client.search_page(&query, 2).unwrap();
+        assert_eq!(page.docs.len(), 2);
+        assert_eq!(page.prev_page, Some(1));
+        assert_eq!(page.next_page, Some(3));
     }
     #[test]
-    fn test_maps_by_page_iter() {
+    fn test_search_page_iter() {
+        let query = "bennydabeast".to_string();
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/uploader/5cff0b7298cc5a672c84e8a3/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":608,"length":190,"bombs":16,"notes":822,"obstacles":20,"njs":12,"njsOffset":0}}}],"songName":"The Greatest (ft. Kendrick Lamar)","songSubName":"Sia","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":192},"stats":{"downloads":109163,"plays":0,"downVotes":53,"upVotes":2039,"heat":653.9647126,"rating":0.927160709241388},"description":"YouTube Preview: https://youtu.be/huUMotlFpig","deletedAt":null,"_id":"5cff621648229f7d88fc8cf7","key":"4377","name":"The Greatest - Sia","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T21:20:03.000Z","hash":"58cd8ddf99600d967bca61285e9e0c429138009d","directDownload":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.zip","downloadURL":"/api/download/key/4377","coverURL":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":420,"length":201,"bombs":132,"notes":693,"obstacles":13,"njs":12,"njsOffset":0}}}],"songName":"Came Here for Love","songSubName":"Sigala & Ella Eyre","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":125},"stats":{"downloads":56604,"plays":0,"downVotes":29,"upVotes":877,"heat":653.490707,"rating":0.9077478149713},"description":"I haven't had this much fun playing a map in a long time to a freakin' amazing song! I hope you enjoy it as much as I do! :D\r\nYouTube Preview: Coming Soon","deletedAt":null,"_id":"5cff621648229f7d88fc8cf4","key":"4373","name":"Came Here for Love - Sigala & Ella Eyre","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T20:01:44.000Z","hash":"19a00f2fbe514aa821cf8ad68962d53bfa28b731","directDownload":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.zip","downloadURL":"/api/download/key/4373","coverURL":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":501,"length":193,"bombs":0,"notes":231,"obstacles":8,"njs":10,"njsOffset":0},"normal":{"duration":501,"length":193,"bombs":0,"notes":259,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":501,"length":193,"bombs":0,"notes":442,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":501,"length":193,"bombs":0,"notes":487,"obstacles":8,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Kamikaze","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":155},"stats":{"downloads":65622,"plays":0,"downVotes":23,"upVotes":878,"heat":647.9081883,"rating":0.9132933652430438},"description":"An older map that I have no idea why I never published. So here it is!","deletedAt":null,"_id":"5cff621648229f7d88fc8c9d","key":"42de","name":"Kamikaze - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-01T22:12:10.000Z","hash":"63d2d5920e6676f63bf997ae606b6d1590cd8c8d","directDownload":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.zip","downloadURL":"/api/download/key/42de","coverURL":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":392.1283264160156,"length":235,"bombs":0,"notes":880,"obstacles":43,"njs":14,"njsOffset":0}}}],"songName":"Heart Afire (feat. Strix)","songSubName":"Defqwop","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":100},"stats":{"downloads":16313,"plays":0,"downVotes":8,"upVotes":438,"heat":645.1888462,"rating":0.9052768696521943},"description":"YouTube Preview: https://youtu.be/dY-jMl3RKpw\r\nEDIT: Fixed Borked Lighting","deletedAt":null,"_id":"5cff621648229f7d88fc8c55","key":"425c","name":"Heart Afire (feat. Strix) - Defqwop","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-31T15:56:32.000Z","hash":"74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0","directDownload":"/cdn/425c/74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0.zip","downloadURL":"/api/download/key/425c","coverURL":"/cdn/425c/74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":554.9882202148438,"length":188,"bombs":0,"notes":1015,"obstacles":17,"njs":12,"njsOffset":0}}}],"songName":"RISE (ft. The Glitch Mob, Mako, The Word Alive)","songSubName":"League of Legends","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":177},"stats":{"downloads":20985,"plays":0,"downVotes":70,"upVotes":386,"heat":643.0575537,"rating":0.7916663490366552},"description":"YouTube Preview: https://youtu.be/Zf_wiprotdw","deletedAt":null,"_id":"5cff621648229f7d88fc8c15","key":"4200","name":"RISE feat The Glitch Mob, Mako, The Word Alive - League of Legends","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-30T14:58:24.000Z","hash":"ca715e7b3ab2b5514919893f150190717e2efbbe","directDownload":"/cdn/4200/ca715e7b3ab2b5514919893f150190717e2efbbe.zip","downloadURL":"/api/download/key/4200","coverURL":"/cdn/4200/ca715e7b3ab2b5514919893f150190717e2efbbe.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24170,"plays":0,"downVotes":24,"upVotes":346,"heat":641.4545003,"rating":0.8618247442569846},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":584,"length":213,"bombs":0,"notes":1050,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Girlfriend","songSubName":"Avril Lavigne","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":164},"stats":{"downloads":65101,"plays":0,"downVotes":78,"upVotes":641,"heat":638.124064,"rating":0.8374895085870108},"description":"YouTube Preview: Coming Soon","deletedAt":null,"_id":"5cff621548229f7d88fc8b9a","key":"413b","name":"Girlfriend - Avril Lavigne","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-27T22:10:10.000Z","hash":"164414d063e1728486f7996582a10250652940ec","directDownload":"/cdn/413b/164414d063e1728486f7996582a10250652940ec.zip","downloadURL":"/api/download/key/413b","coverURL":"/cdn/413b/164414d063e1728486f7996582a10250652940ec.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":387,"obstacles":54,"njs":10,"njsOffset":0},"expert":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":616,"obstacles":54,"njs":12,"njsOffset":0},"expertPlus":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":868,"obstacles":57,"njs":14,"njsOffset":0}}}],"songName":"Nevada (feat. Cozi Zuehlsdorff)","songSubName":"Vicetone","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":124},"stats":{"downloads":30032,"plays":0,"downVotes":32,"upVotes":1066,"heat":638.1105428,"rating":0.9136481130972827},"description":"YouTube Preview: https://youtu.be/3gb2gH8eueI","deletedAt":null,"_id":"5cff621548229f7d88fc8b94","key":"4131","name":"Nevada feat. Cozi Zuehlsdorff - Vicetone","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-27T18:42:01.000Z","hash":"5e847d92de6e01dfd5ce3491969b3939b4374699","directDownload":"/cdn/4131/5e847d92de6e01dfd5ce3491969b3939b4374699.zip","downloadURL":"/api/download/key/4131","coverURL":"/cdn/4131/5e847d92de6e01dfd5ce3491969b3939b4374699.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":395.75,"length":221,"bombs":0,"notes":937,"obstacles":6,"njs":14,"njsOffset":0}}}],"songName":"Alone feat. Kyle Reynolds","songSubName":"Asketa & Natan Chaim","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":107},"stats":{"downloads":53318,"plays":0,"downVotes":26,"upVotes":707,"heat":634.3503027,"rating":0.9007980474001192},"description":"You ever just find a map gathering dust but pretty much finished? Yeah... let's go ahead and release that.\r\nDifficulties: Expert+ Only\r\nYouTube Preview: https://youtu.be/cg1wBYBCqX0","deletedAt":null,"_id":"5cff621548229f7d88fc8b42","key":"40b2","name":"Alone feat. Kyle Reynolds - Asketa & Natan Chaim","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-25T21:57:52.000Z","hash":"84ac2667162920902490fb1a572ed4cf5ad50a1f","directDownload":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.zip","downloadURL":"/api/download/key/40b2","coverURL":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":432,"obstacles":284,"njs":10,"njsOffset":0},"expert":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":616,"obstacles":293,"njs":10,"njsOffset":0},"expertPlus":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":932,"obstacles":307,"njs":14,"njsOffset":0}}}],"songName":"High Enough ft. Rosie Darling","songSubName":"Justin Caruso","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":54616,"plays":0,"downVotes":133,"upVotes":615,"heat":626.3101804,"rating":0.7782575573900176},"description":"Difficulties: Expert+, Expert, Hard\r\nYouTube Preview: https://youtu.be/pGiaa-PJOps","deletedAt":null,"_id":"5cff621548229f7d88fc8a9d","key":"3f8b","name":"High Enough ft. Rosie Darling (Baaku Remix) - Justin Caruso","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-21T19:20:21.000Z","hash":"b5483e3f38df32d233700b49a0bdbf72ba1650cc","directDownload":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.zip","downloadURL":"/api/download/key/3f8b","coverURL":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.jpg"}],"totalDocs":46,"lastPage":2,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/uploader/5cff0b7298cc5a672c84e8a3/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61431,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":357,"length":177,"bombs":0,"notes":373,"obstacles":268,"njs":10,"njsOffset":0},"expert":{"duration":357,"length":177,"bombs":0,"notes":661,"obstacles":291,"njs":10,"njsOffset":0},"expertPlus":{"duration":357,"length":177,"bombs":0,"notes":875,"obstacles":291,"njs":14,"njsOffset":0}}}],"songName":"Go Bang","songSubName":"PNAU","songAuthorName":"BennyDaBeast & TheRealZeroz","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":55668,"plays":0,"downVotes":63,"upVotes":979,"heat":514.3598733,"rating":0.8852888597073685},"description":"YouTube Preview: Coming Soon\r\nLighting By: TheRealZeroz\r\nMany many thanks to TheRealZeros for the grade A lighting, especially with the cool strobes! :D","deletedAt":null,"_id":"5cff621348229f7d88fc80e7","key":"300a","name":"Go Bang - PNAU (Lighting by TheRealZeroz)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-22T08:28:29.000Z","hash":"7573b8504b186f217787fb8fb7c5218012c6299d","directDownload":"/cdn/300a/7573b8504b186f217787fb8fb7c5218012c6299d.zip","downloadURL":"/api/download/key/300a","coverURL":"/cdn/300a/7573b8504b186f217787fb8fb7c5218012c6299d.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23796,"plays":4,"downVotes":17,"upVotes":98,"heat":435.3491072,"rating":0.7679775361870059},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":545.281494140625,"length":212,"bombs":0,"notes":1007,"obstacles":17,"njs":11,"njsOffset":0}}}],"songName":"The Other Side (The Greatest Showman)","songSubName":"Hugh Jackman & Zac Efron","songAuthorName":"BennyDaBeast, Lit by Willums & Assistant","levelAuthorName":"bennydabeast","bpm":154},"stats":{"downloads":76441,"plays":11,"downVotes":42,"upVotes":995,"heat":430.8640707,"rating":0.9027024852773321},"description":"Expert+ Only!","deletedAt":null,"_id":"5cff621248229f7d88fc79e5","key":"24e3","name":"The Other Side (The Greatest Showman)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-09T20:33:44.000Z","hash":"d1a01de5b779523c390b594bd0f61ed57b0e41f8","directDownload":"/cdn/24e3/d1a01de5b779523c390b594bd0f61ed57b0e41f8.zip","downloadURL":"/api/download/key/24e3","coverURL":"/cdn/24e3/d1a01de5b779523c390b594bd0f61ed57b0e41f8.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":417,"length":195,"bombs":4,"notes":301,"obstacles":24,"njs":10,"njsOffset":0},"hard":{"duration":417,"length":195,"bombs":4,"notes":486,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":417.5,"length":195,"bombs":4,"notes":620,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":{"duration":417.5,"length":195,"bombs":0,"notes":894,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Shut Up and Dance","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":433479,"plays":558,"downVotes":140,"upVotes":11089,"heat":395.8353745,"rating":0.9581072075276635},"description":"Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY","deletedAt":null,"_id":"5cff621148229f7d88fc77c9","key":"2144","name":"Shut Up and Dance - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-21T01:27:00.000Z","hash":"89cf8bb07afb3c59ae7b5ac00337d62261c36fb4","directDownload":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip","downloadURL":"/api/download/key/2144","coverURL":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.png"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":528,"length":198,"bombs":0,"notes":280,"obstacles":26,"njs":10,"njsOffset":0},"normal":{"duration":528,"length":198,"bombs":0,"notes":360,"obstacles":26,"njs":10,"njsOffset":0},"hard":{"duration":530,"length":198,"bombs":0,"notes":521,"obstacles":154,"njs":12,"njsOffset":0},"expert":{"duration":530,"length":198,"bombs":0,"notes":746,"obstacles":155,"njs":12,"njsOffset":0},"expertPlus":{"duration":530,"length":198,"bombs":0,"notes":1225,"obstacles":207,"njs":14,"njsOffset":0}}}],"songName":"Don't Let Me Down (Illenium Remix)","songSubName":"The Chainsmokers feat. Daya","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":258504,"plays":1391,"downVotes":136,"upVotes":3843,"heat":379.4741337,"rating":0.9274015748142236},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nDifficulties: Expert+, Expert, Hard, Normal","deletedAt":null,"_id":"5cff621148229f7d88fc76f2","key":"1ff6","name":"Don't Let Me Down (Illenium Remix) - The Chainsmokers feat. Daya (100k Contest)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-12T18:48:50.000Z","hash":"5639a12cccf90238055116ec53e5297aa004627e","directDownload":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.zip","downloadURL":"/api/download/key/1ff6","coverURL":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33337,"plays":366,"downVotes":20,"upVotes":785,"heat":339.1379059,"rating":0.9117768411304571},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":434.5,"length":186,"bombs":0,"notes":256,"obstacles":5,"njs":10,"njsOffset":0},"hard":{"duration":434.5,"length":186,"bombs":0,"notes":334,"obstacles":5,"njs":10,"njsOffset":0},"expert":{"duration":434.5,"length":186,"bombs":0,"notes":492,"obstacles":5,"njs":10,"njsOffset":0},"expertPlus":{"duration":434.5,"length":186,"bombs":0,"notes":671,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"Monster Mash","songSubName":"Boris Pickett","songAuthorName":"(HallowBeats) BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":54354,"plays":1403,"downVotes":45,"upVotes":387,"heat":332.5239372,"rating":0.8321757094972239},"description":"'tis the season to be spooky, fall and fall and fall leaves to the ground!\r\nDifficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/MIbpQlOdGw8","deletedAt":null,"_id":"5cff621048229f7d88fc742b","key":"1b0c","name":"[HallowBeats] Monster Mash - Boris Pickett","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-19T20:52:26.000Z","hash":"96dd957c33ed3d7094217737cedbe8700140f437","directDownload":"/cdn/1b0c/96dd957c33ed3d7094217737cedbe8700140f437.zip","downloadURL":"/api/download/key/1b0c","coverURL":"/cdn/1b0c/96dd957c33ed3d7094217737cedbe8700140f437.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":634,"length":190,"bombs":0,"notes":277,"obstacles":9,"njs":10,"njsOffset":0},"hard":{"duration":635,"length":190,"bombs":0,"notes":317,"obstacles":9,"njs":10,"njsOffset":0},"expert":{"duration":635,"length":190,"bombs":0,"notes":541,"obstacles":9,"njs":10,"njsOffset":0},"expertPlus":{"duration":635,"length":190,"bombs":0,"notes":848,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"Under The Sea","songSubName":"Samuel E. Wright","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":200},"stats":{"downloads":250016,"plays":3317,"downVotes":119,"upVotes":5076,"heat":312.7946411,"rating":0.9407792405748187},"description":"\"What do they got? A lot of sand. We got a hot crustacean band! Each little clam here know how to jam here, under the sea!\"\r\nDifficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/h6eMPlGUZsw","deletedAt":null,"_id":"5cff621048229f7d88fc7327","key":"195f","name":"Under The Sea (Little Mermaid) - Samuel E. Wright","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T23:44:34.000Z","hash":"f745bac45c618fa2c49a0ee0c383f2a6cb356a78","directDownload":"/cdn/195f/f745bac45c618fa2c49a0ee0c383f2a6cb356a78.zip","downloadURL":"/api/download/key/195f","coverURL":"/cdn/195f/f745bac45c618fa2c49a0ee0c383f2a6cb356a78.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":413.125,"length":130,"bombs":179,"notes":664,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"THE HEISENBERG SONG","songSubName":"Say My Name","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":191},"stats":{"downloads":30245,"plays":129,"downVotes":39,"upVotes":41,"heat":308.6084522,"rating":0.5091703485317295},"description":"https://www.twitch.tv/heisenberg_irl\r\n\r\nA tribute to the mapping style of the one and only, Heisenberg in real life. The real Heisenberg. Not the fake one.","deletedAt":null,"_id":"5cff621048229f7d88fc731b","key":"194e","name":"THE HEISENBERG SONG (Tribute to Heisenbergirl)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T13:50:34.000Z","hash":"c3b7b8a99a8f07961122b37547794f03adb27f96","directDownload":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.zip","downloadURL":"/api/download/key/194e","coverURL":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.png"}],"totalDocs":46,"lastPage":2,"prevPage":1,"nextPage":null}"#.into());
+        pages.insert(
+            BEATSAVER_URL.join("search/text/1?q=bennydabeast").unwrap(),
+            page_json(&["1b0c", "195f"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("search/text/2?q=bennydabeast").unwrap(),
+            page_json(&[]),
+        );
         let client = FakeClientPaged::new(pages);
         assert_eq!(
             client
-                .maps_by_page_iter(
-                    &BeatSaverUser {
-                        id: "5cff0b7298cc5a672c84e8a3".into(),
-                        username: "datkami".into()
-                    },
-                    1
-                )
-                .map(|m| m.unwrap().key)
+                .search_page_iter(&query, 1)
+                .map(|m| m.unwrap().id)
+                .collect::<Vec<String>>(),
+            vec!["1b0c".to_string(), "195f".to_string(),]
+        );
+    }
+    #[test]
+    fn test_search_advanced() {
+        let query = "uploader.name:bennydabeast".to_string();
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/0?q=uploader.name%3Abennydabeast&sortOrder=Relevance")
+                .unwrap(),
+            page_json(&["97d3", "2144", "195f"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/1?q=uploader.name%3Abennydabeast&sortOrder=Relevance")
+                .unwrap(),
+            page_json(&["194e", "24e3"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/2?q=uploader.name%3Abennydabeast&sortOrder=Relevance")
+                .unwrap(),
+            page_json(&[]),
+        );
+        let client = FakeClientPaged::new(pages);
+        assert_eq!(
+            client
+                .search_advanced(&query)
+                .map(|m| m.unwrap().id)
                 .collect::<Vec<String>>(),
             vec![
-                "4377".to_string(),
-                "4373".to_string(),
-                "42de".to_string(),
-                "425c".to_string(),
-                "4200".to_string(),
-                "41cc".to_string(),
-                "413b".to_string(),
-                "4131".to_string(),
-                "40b2".to_string(),
-                "3f8b".to_string(),
-                "31f8".to_string(),
-                "300a".to_string(),
-                "2556".to_string(),
-                "24e3".to_string(),
+                "97d3".to_string(),
                 "2144".to_string(),
-                "1ff6".to_string(),
-                "1bc4".to_string(),
-                "1b0c".to_string(),
                 "195f".to_string(),
-                "194e".to_string()
+                "194e".to_string(),
+                "24e3".to_string(),
             ]
         );
     }
     #[test]
-    fn test_maps_hot() {
+    fn test_search_advanced_page() {
+        let query = "uploader.name:bennydabeast".to_string();
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join("search/text/2?q=uploader.name%3Abennydabeast&sortOrder=Relevance")
+                .unwrap(),
+            page_json(&["194e", "24e3"]),
+        );
+        let page = client.search_advanced_page(&query, 2).unwrap();
+        assert_eq!(page.docs.len(), 2);
+        assert_eq!(page.prev_page, Some(1));
+        assert_eq!(page.next_page, Some(3));
+    }
+    #[test]
+    fn test_search_advanced_page_iter() {
+        let query = "uploader.name:bennydabeast".to_string();
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/hot/0").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":176,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":347.47916699999996,"length":173,"njs":12,"njsOffset":0,"bombs":0,"notes":490,"obstacles":13},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"},{"difficulties":{"easy":{"duration":0,"length":0,"njs":16,"njsOffset":0,"bombs":0,"notes":0,"obstacles":0},"expert":null,"expertPlus":null,"hard":null,"normal":null},"name":"Lightshow"}],"levelAuthorName":"Jokidum","songAuthorName":"Jonathan Coulton","songName":"Still Alive","songSubName":"- Portal","bpm":120},"stats":{"downloads":437,"plays":0,"downVotes":0,"upVotes":12,"heat":1822.880162,"rating":0.7689852862300238},"description":"I've wanted to map this song for a while now. And since this one's probably the last Portal song I'll map, I gave it my best.   \r\n\r\nP.S. - I'm so glad to be back mapping this month. I had to take a break due to overwhelming stress, but now I'm feeling ready again.\r\nI hope you enjoy.\r\n\r\nPortal on Steam:\r\nhttps://store.steampowered.com/app/400/Portal/","deletedAt":null,"_id":"5fca9c74029e550006a0e4fa","key":"11b7a","name":"Still Alive - Portal","uploader":{"_id":"5e15518d47f1600006e912cc","username":"jokidum"},"hash":"8c03b43484b0dd1fc7d8a3104a82f0992a001c6c","uploaded":"2020-12-04T20:30:44.132Z","directDownload":"/cdn/11b7a/8c03b43484b0dd1fc7d8a3104a82f0992a001c6c.zip","downloadURL":"/api/download/key/11b7a","coverURL":"/cdn/11b7a/8c03b43484b0dd1fc7d8a3104a82f0992a001c6c.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":257,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":590.5,"length":249,"njs":19,"njsOffset":-0.625,"bombs":0,"notes":1284,"obstacles":18},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"qqrz997","songAuthorName":"FELT","songName":"white","songSubName":"","bpm":142},"stats":{"downloads":353,"plays":0,"downVotes":0,"upVotes":5,"heat":1822.4148589,"rating":0.7084432256108616},"description":"vocals 舞花","deletedAt":null,"_id":"5fca8d7f9370580009f57436","key":"11b77","name":"FELT - white","uploader":{"_id":"5e06183effb3c40006a3d4d9","username":"qqrz997"},"hash":"52df6c0fa50406739652692293a3d3d2a068a24e","uploaded":"2020-12-04T19:26:55.002Z","directDownload":"/cdn/11b77/52df6c0fa50406739652692293a3d3d2a068a24e.zip","downloadURL":"/api/download/key/11b77","coverURL":"/cdn/11b77/52df6c0fa50406739652692293a3d3d2a068a24e.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":199,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":303,"length":196,"njs":20,"njsOffset":0,"bombs":0,"notes":757,"obstacles":0},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"cookie","songAuthorName":"Hollywood Undead","songName":"Bullet","songSubName":"","bpm":92.5},"stats":{"downloads":74,"plays":0,"downVotes":0,"upVotes":2,"heat":1822.4104543,"rating":0.6407951473548253},"description":"An upbeat song from Hollywood Undead that's about suicide.\r\nExpert only with custom lighting!","deletedAt":null,"_id":"5fcad2aca3f6d20006e06575","key":"11b9d","name":"Hollywood Undead - Bullet","uploader":{"_id":"5d1356c9f8b4040007dcb9fd","username":"cookiesaber"},"hash":"590ffb06d96eda325df735667baf0553d88cb8e0","uploaded":"2020-12-05T00:22:04.095Z","directDownload":"/cdn/11b9d/590ffb06d96eda325df735667baf0553d88cb8e0.zip","downloadURL":"/api/download/key/11b9d","coverURL":"/cdn/11b9d/590ffb06d96eda325df735667baf0553d88cb8e0.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":317,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":818.1199951171875,"length":306,"njs":20,"njsOffset":-0.30000001192092896,"bombs":25,"notes":1859,"obstacles":27},"expertPlus":{"duration":818.1199951171875,"length":306,"njs":22,"njsOffset":-0.550000011920929,"bombs":25,"notes":2453,"obstacles":21},"hard":{"duration":818.1199951171875,"length":306,"njs":18,"njsOffset":-0.10000000149011612,"bombs":25,"notes":1617,"obstacles":27},"normal":null},"name":"Standard"}],"levelAuthorName":"AaltopahWi","songAuthorName":"Nuruhachi","songName":"Ruler License","songSubName":"V2","bpm":160},"stats":{"downloads":553,"plays":0,"downVotes":0,"upVotes":8,"heat":1822.404368,"rating":0.7419437476723167},"description":"3 diffs. Took way too many hours.\nThanks for testing everyone.\n\nAlso wash your hands and wear a mask. No-one can rule when they are dead.","deletedAt":null,"_id":"5fca67c59370580009f54646","key":"11b63","name":"Nuruhachi - Ruler License (V2)","uploader":{"_id":"5cff0b7598cc5a672c853187","username":"aaltopahwi"},"hash":"d24c9f090b8a45132cb7c6c47d01ccca0071a950","uploaded":"2020-12-04T16:45:57.510Z","directDownload":"/cdn/11b63/d24c9f090b8a45132cb7c6c47d01ccca0071a950.zip","downloadURL":"/api/download/key/11b63","coverURL":"/cdn/11b63/d24c9f090b8a45132cb7c6c47d01ccca0071a950.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":195,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":373,"length":189,"njs":10,"njsOffset":0,"bombs":0,"notes":195,"obstacles":0},"expert":{"duration":373.5,"length":189,"njs":17,"njsOffset":-0.5,"bombs":40,"notes":749,"obstacles":91},"expertPlus":{"duration":373.5,"length":189,"njs":18,"njsOffset":0.5,"bombs":40,"notes":823,"obstacles":92},"hard":{"duration":373.5,"length":189,"njs":14,"njsOffset":0,"bombs":22,"notes":550,"obstacles":68},"normal":{"duration":373.5,"length":189,"njs":12,"njsOffset":0,"bombs":0,"notes":376,"obstacles":10}},"name":"Standard"}],"levelAuthorName":"S1MMZE","songAuthorName":"Owl City","songName":"Unbelievable","songSubName":"ft. Hanson","bpm":118},"stats":{"downloads":723,"plays":0,"downVotes":0,"upVotes":9,"heat":1822.3381796,"rating":0.75},"description":"NPS: (E=1) (N=1.92) (H=2.82) (X=3.83) (X+=4.21)\nThank you to Jafdy for test playing ","deletedAt":null,"_id":"5fca5325029e550006a093d2","key":"11b5e","name":"Owl City - Unbelievable","uploader":{"_id":"5fa48a5d3da7010006fd01e2","username":"s1mmze"},"hash":"3ebeef8732aa6e3881ab625e29eddb0587f3dccd","uploaded":"2020-12-04T15:17:57.170Z","directDownload":"/cdn/11b5e/3ebeef8732aa6e3881ab625e29eddb0587f3dccd.zip","downloadURL":"/api/download/key/11b5e","coverURL":"/cdn/11b5e/3ebeef8732aa6e3881ab625e29eddb0587f3dccd.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":262,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":684.2666625976562,"length":256,"njs":12,"njsOffset":-1.5,"bombs":112,"notes":354,"obstacles":253},"expert":{"duration":684.2666625976562,"length":256,"njs":20,"njsOffset":-0.550000011920929,"bombs":100,"notes":1399,"obstacles":219},"expertPlus":{"duration":684.2670288085938,"length":256,"njs":21,"njsOffset":-0.75,"bombs":332,"notes":1613,"obstacles":198},"hard":{"duration":684.2666625976562,"length":256,"njs":17,"njsOffset":-0.3499999940395355,"bombs":124,"notes":985,"obstacles":212},"normal":{"duration":684.2662963867188,"length":256,"njs":14,"njsOffset":0.10000000149011612,"bombs":112,"notes":614,"obstacles":211}},"name":"Standard"},{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":0,"length":0,"njs":18,"njsOffset":0,"bombs":0,"notes":0,"obstacles":0},"hard":null,"normal":null},"name":"Lightshow"}],"levelAuthorName":"FatBeanzoop & ExUnReal","songAuthorName":"Pegboard Nerds","songName":"Purple People Eater","songSubName":"","bpm":160},"stats":{"downloads":742,"plays":0,"downVotes":0,"upVotes":14,"heat":1822.2642383,"rating":0.7787255562245505},"description":"sure looks strange to me","deletedAt":null,"_id":"5fca246aa3f6d20006dfb9da","key":"11b49","name":"Pegboard Nerds - Purple People Eater","uploader":{"_id":"5cff0b7398cc5a672c84fbc5","username":"fatbeanzoop"},"hash":"09f8bee6908e3a9cd724b3db3162a5c381ecb156","uploaded":"2020-12-04T11:58:34.962Z","directDownload":"/cdn/11b49/09f8bee6908e3a9cd724b3db3162a5c381ecb156.zip","downloadURL":"/api/download/key/11b49","coverURL":"/cdn/11b49/09f8bee6908e3a9cd724b3db3162a5c381ecb156.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":260,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":827.4833374023438,"length":248,"njs":21,"njsOffset":0,"bombs":16,"notes":2764,"obstacles":258},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ordinary09","songAuthorName":"xi","songName":"Blue Zenith","songSubName":"","bpm":200},"stats":{"downloads":176,"plays":0,"downVotes":0,"upVotes":2,"heat":1822.2381191,"rating":0.6407951473548253},"description":"i know there are already existing maps for this song, but i tried put my own twist on it where it doesnt only consist of stream patterns. some obscure patterns though. beware.\n\nreupload due to mapping error :(","deletedAt":null,"_id":"5fcab461d2a4c2000634e72c","key":"11b8a","name":"xi - Blue Zenith","uploader":{"_id":"5eafee9f7abb000006c2417c","username":"ordinary09"},"hash":"838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a","uploaded":"2020-12-04T22:12:49.008Z","directDownload":"/cdn/11b8a/838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a.zip","downloadURL":"/api/download/key/11b8a","coverURL":"/cdn/11b8a/838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":384,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":1438.3209228515625,"length":367,"njs":21,"njsOffset":0,"bombs":38,"notes":6774,"obstacles":11},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"WDG_Doctor","songAuthorName":"Camellia","songName":"Z:iRNiTRA","songSubName":"","bpm":235},"stats":{"downloads":12,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.22707,"rating":0},"description":"vibro maps of camellia songs are really fun to make\n\nmapped in 4 hours","deletedAt":null,"_id":"5fcae75ad2a4c20006351988","key":"11bad","name":"Z:iRNiTRA","uploader":{"_id":"5ed46197ed49a2000774fa15","username":"devonix"},"hash":"9a7d149c4a4a0f0219fae20daa5ec550ee908286","uploaded":"2020-12-05T01:50:18.148Z","directDownload":"/cdn/11bad/9a7d149c4a4a0f0219fae20daa5ec550ee908286.zip","downloadURL":"/api/download/key/11bad","coverURL":"/cdn/11bad/9a7d149c4a4a0f0219fae20daa5ec550ee908286.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":false,"hard":true,"normal":false},"duration":164,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":null,"hard":{"duration":385.2607727050781,"length":160,"njs":15,"njsOffset":0,"bombs":52,"notes":358,"obstacles":15},"normal":null},"name":"Standard"}],"levelAuthorName":"nate","songAuthorName":"Yoko Shimomura","songName":"Nachtflugel","songSubName":"(Yozora)","bpm":143.89},"stats":{"downloads":15,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.2221184,"rating":0},"description":"moremoremore\n\nAutolights as requested\n\nVariable BPM but sticks to somewhere around 143.89\nHard\n\nThis map was a special request Trixks","deletedAt":null,"_id":"5fcae67bd2a4c200063518d3","key":"11bac","name":"Nachtflügel (Yozora) [Kingdom Hearts III Re:Mind OST]","uploader":{"_id":"5d761e29048dff0006ca82d4","username":"nate"},"hash":"7ca62d9094694b81502c5ef78656849f15caa3dc","uploaded":"2020-12-05T01:46:35.328Z","directDownload":"/cdn/11bac/7ca62d9094694b81502c5ef78656849f15caa3dc.zip","downloadURL":"/api/download/key/11bac","coverURL":"/cdn/11bac/7ca62d9094694b81502c5ef78656849f15caa3dc.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":207,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":404.5,"length":202,"njs":18,"njsOffset":-0.30000001192092896,"bombs":0,"notes":870,"obstacles":10},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"llekel","songAuthorName":"Wonder Girls","songName":"I Feel You","songSubName":"","bpm":120},"stats":{"downloads":19,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.209791,"rating":0},"description":"JYP - Big thanks to Joey, Todai and TinkerVR85 for the testing/feedback!\n\nNice 80's inspired Kpop!","deletedAt":null,"_id":"5fcae4509370580009f5d2f1","key":"11baa","name":"I Feel You - Wonder Girls","uploader":{"_id":"5e712e44d87e580006b27597","username":"llekel"},"hash":"9bb7ba570827f8de8cb445d5cb410c289e09d314","uploaded":"2020-12-05T01:37:20.595Z","directDownload":"/cdn/11baa/9bb7ba570827f8de8cb445d5cb410c289e09d314.zip","downloadURL":"/api/download/key/11baa","coverURL":"/cdn/11baa/9bb7ba570827f8de8cb445d5cb410c289e09d314.jpg"}],"totalDocs":36011,"lastPage":3601,"prevPage":null,"nextPage":1}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/hot/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":160,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":291,"length":157,"njs":16,"njsOffset":0,"bombs":0,"notes":745,"obstacles":12},"expertPlus":{"duration":291.0625,"length":157,"njs":18,"njsOffset":0.20000000298023224,"bombs":0,"notes":804,"obstacles":11},"hard":{"duration":291,"length":157,"njs":14,"njsOffset":0,"bombs":0,"notes":677,"obstacles":11},"normal":null},"name":"Standard"}],"levelAuthorName":"Alekcake","songAuthorName":"Blackpink ft. Cardi B","songName":"Bet You Wanna","songSubName":"","bpm":111},"stats":{"downloads":212,"plays":0,"downVotes":0,"upVotes":2,"heat":1822.1947366,"rating":0.6407951473548253},"description":"Gameplay here!: https://youtu.be/umSrHBLZxeg\r\n\r\nSpecial thanks to noxn and todai for helping us with the map!\r\nAlso thank you to our playtesters, pezzax and TinkerVr85!\r\n\r\nNPS \r\nExpert+: 4.99\r\nExpert: 4.63\r\nHard: 4.2\r\n\r\nCustom Map GIVEAWAY each month on our discord server!\r\nHow to enter: Subscribe to our YouTube channel through the gameplay link, join our discord server and pick the \"Giveaway\" role and you'll be entered!\r\nAlekcake's Kpop & VR Arcade - https://discord.gg/P4eGh26","deletedAt":null,"_id":"5fcaacc0029e550006a0f9f8","key":"11b85","name":"Blackpink ft Cardi B - Bet You Wanna","uploader":{"_id":"5ee6cc499ae7380006da7aef","username":"alekcake"},"hash":"9bdf6e59503b5081ca726c9c0c1d71808f6580ab","uploaded":"2020-12-04T21:40:16.795Z","directDownload":"/cdn/11b85/9bdf6e59503b5081ca726c9c0c1d71808f6580ab.zip","downloadURL":"/api/download/key/11b85","coverURL":"/cdn/11b85/9bdf6e59503b5081ca726c9c0c1d71808f6580ab.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":false,"expertPlus":false,"hard":true,"normal":true},"duration":102,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":92,"notes":126,"obstacles":12},"expert":null,"expertPlus":null,"hard":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":327,"notes":315,"obstacles":18},"normal":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":92,"notes":223,"obstacles":18}},"name":"Standard"}],"levelAuthorName":"Josh","songAuthorName":"Laura Shigihara","songName":"Brainiac Maniac - Plants VS Zombies","songSubName":"","bpm":120},"stats":{"downloads":43,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1609861,"rating":0},"description":"","deletedAt":null,"_id":"5fcadbbcd2a4c200063510e8","key":"11ba5","name":"Brainiac Maniac - Plants VS Zombies","uploader":{"_id":"5fc8273aa3f6d20006de32e3","username":"electrojosh12@gmail.com"},"hash":"220f9e5dabdd505f8fa7e995ce1e46110b6cfda1","uploaded":"2020-12-05T01:00:44.375Z","directDownload":"/cdn/11ba5/220f9e5dabdd505f8fa7e995ce1e46110b6cfda1.zip","downloadURL":"/api/download/key/11ba5","coverURL":"/cdn/11ba5/220f9e5dabdd505f8fa7e995ce1e46110b6cfda1.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":259,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":667.5,"length":258,"njs":19,"njsOffset":-0.4000000059604645,"bombs":15,"notes":1758,"obstacles":74},"expertPlus":{"duration":667.5,"length":258,"njs":21,"njsOffset":-0.550000011920929,"bombs":8,"notes":1812,"obstacles":75},"hard":{"duration":667.5,"length":258,"njs":17,"njsOffset":0,"bombs":50,"notes":1340,"obstacles":77},"normal":null},"name":"Standard"}],"levelAuthorName":"mux","songAuthorName":"Takaaki Natsushiro","songName":"Endroll -covered by Isekaijoucho-","songSubName":"","bpm":155},"stats":{"downloads":45,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1591567,"rating":0},"description":"Lyric MV\r\nhttps://www.youtube.com/watch?v=X0BykQ-ljKE\r\n\r\nOriginal\r\nhttps://www.youtube.com/watch?v=a7jPH3vbMys\r\n","deletedAt":null,"_id":"5fcadb6aa3f6d20006e0702f","key":"11ba4","name":"Endroll -covered by Isekaijoucho-","uploader":{"_id":"5e0a5f2046a07100065909fe","username":"mux"},"hash":"1c861c8438a7012422be6acdc78854663c509853","uploaded":"2020-12-05T00:59:22.053Z","directDownload":"/cdn/11ba4/1c861c8438a7012422be6acdc78854663c509853.zip","downloadURL":"/api/download/key/11ba4","coverURL":"/cdn/11ba4/1c861c8438a7012422be6acdc78854663c509853.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":215,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":383,"length":214,"njs":12,"njsOffset":0,"bombs":0,"notes":1011,"obstacles":7},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"darthGandalf95","songAuthorName":"Jan Hegenberg","songName":"Die Garde der Hoffnung","songSubName":"","bpm":107.30000305175781},"stats":{"downloads":38,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1591484,"rating":0},"description":"Für alle Hegis.\nFirst try ... so be rude","deletedAt":null,"_id":"5fcadb69029e550006a12b6d","key":"11ba3","name":"Jan Hegenberg - Die Garde der Hoffnung","uploader":{"_id":"5fcacf72b05fe7000691e5af","username":"darthgandalf95"},"hash":"a41ea9833039435c1216a9cdfb1f0e35024d2b4e","uploaded":"2020-12-05T00:59:21.677Z","directDownload":"/cdn/11ba3/a41ea9833039435c1216a9cdfb1f0e35024d2b4e.zip","downloadURL":"/api/download/key/11ba3","coverURL":"/cdn/11ba3/a41ea9833039435c1216a9cdfb1f0e35024d2b4e.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":400,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":615.2254638671875,"length":398,"njs":18,"njsOffset":0.30000001192092896,"bombs":132,"notes":1328,"obstacles":77},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Dalmane","songAuthorName":"Focus","songName":"Hocus Pocus","songSubName":"","bpm":92.69999694824219},"stats":{"downloads":351,"plays":0,"downVotes":1,"upVotes":4,"heat":1822.130901,"rating":0.625065935366517},"description":"Swing your arms and move your hips to this 1971 rock classic!  This song was the reason I started mapping.  Patterns are designed for full arm swings.  Expert only.  Run time 6:40.  Warning: some short strobing sequences.","deletedAt":null,"_id":"5fca8294607cfe0006936dbc","key":"11b73","name":"Hocus Pocus - Focus (Full Version)","uploader":{"_id":"5e7fb3ba16c96800060e43b5","username":"dalmane"},"hash":"c324b03dbeea8cc6f9fce6a7829594796d0f117b","uploaded":"2020-12-04T18:40:20.089Z","directDownload":"/cdn/11b73/c324b03dbeea8cc6f9fce6a7829594796d0f117b.zip","downloadURL":"/api/download/key/11b73","coverURL":"/cdn/11b73/c324b03dbeea8cc6f9fce6a7829594796d0f117b.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":426,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":842.671875,"length":421,"njs":16,"njsOffset":0,"bombs":0,"notes":1817,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ASharpBlade","songAuthorName":"Avenged Sevenfold","songName":"Not Ready to Die (From \"Call of the Dead\")","songSubName":"","bpm":120},"stats":{"downloads":406,"plays":0,"downVotes":0,"upVotes":4,"heat":1822.1289253,"rating":0.6919940778277485},"description":"This songs is from Avenged Sevenfold. It was a easter egg song from Cod Zombies map \" Call of the Dead\".","deletedAt":null,"_id":"5fca6c44b05fe700069172fa","key":"11b64","name":"Not Ready To Die","uploader":{"_id":"5fc67ef1b05fe700068e46ab","username":"asharpblade"},"hash":"15f9a896a271d4063c87c0d79b1871bf02fa9070","uploaded":"2020-12-04T17:05:08.940Z","directDownload":"/cdn/11b64/15f9a896a271d4063c87c0d79b1871bf02fa9070.zip","downloadURL":"/api/download/key/11b64","coverURL":"/cdn/11b64/15f9a896a271d4063c87c0d79b1871bf02fa9070.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":136,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":378.35833740234375,"length":133,"njs":14,"njsOffset":0.800000011920929,"bombs":11,"notes":469,"obstacles":10},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Dack","songAuthorName":"THE iDOLM@STER MILLION LIVE!","songName":"Super Lover","songSubName":"","bpm":170},"stats":{"downloads":60,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1238145,"rating":0},"description":"Patreon: patreon.com/Dack","deletedAt":null,"_id":"5fcad533d2a4c200063509a7","key":"11b9e","name":"THE IDOLM@STER MILLION - Super Lover","uploader":{"_id":"5cff0b7598cc5a672c852c6f","username":"dack"},"hash":"9303890819a081a59bb0a7c0c4718d367330ca34","uploaded":"2020-12-05T00:32:51.654Z","directDownload":"/cdn/11b9e/9303890819a081a59bb0a7c0c4718d367330ca34.zip","downloadURL":"/api/download/key/11b9e","coverURL":"/cdn/11b9e/9303890819a081a59bb0a7c0c4718d367330ca34.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":471,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":2672.249267578125,"length":471,"njs":23,"njsOffset":-1.399999976158142,"bombs":1,"notes":5080,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Lolighter","songAuthorName":"Sewerslvt","songName":"Mr. Kill Myself","songSubName":"","bpm":340},"stats":{"downloads":77,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1002815,"rating":0},"description":"funny lolighter map","deletedAt":null,"_id":"5fcad110d2a4c2000635047d","key":"11b9b","name":"M.K.MS","uploader":{"_id":"5cff0b7698cc5a672c854a7f","username":"wh4t"},"hash":"2fea40d139432aaa7b4bb1c5e46309c92dda2179","uploaded":"2020-12-05T00:15:12.667Z","directDownload":"/cdn/11b9b/2fea40d139432aaa7b4bb1c5e46309c92dda2179.zip","downloadURL":"/api/download/key/11b9b","coverURL":"/cdn/11b9b/2fea40d139432aaa7b4bb1c5e46309c92dda2179.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":192,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":403.25,"length":192,"njs":12,"njsOffset":0,"bombs":22,"notes":434,"obstacles":9},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Xpoiled","songAuthorName":"Distrion","songName":"Distrion Alibi (ft. heelen","songSubName":"Distrion","bpm":126},"stats":{"downloads":94,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0699933,"rating":0},"description":"My first beatmap enjoy follow for more. make sure to have decent play space as ther is 1 spot wher ther is a three wide wall","deletedAt":null,"_id":"5fcacbbda3f6d20006e05db1","key":"11b98","name":"Distrion - Alibi (Ft. Heleen)","uploader":{"_id":"5fc97909b05fe7000690be4e","username":"xpoled"},"hash":"b011aa10021f1aaaca714dc4ae0b176c71558247","uploaded":"2020-12-04T23:52:29.700Z","directDownload":"/cdn/11b98/b011aa10021f1aaaca714dc4ae0b176c71558247.zip","downloadURL":"/api/download/key/11b98","coverURL":"/cdn/11b98/b011aa10021f1aaaca714dc4ae0b176c71558247.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":195,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":449.2485046386719,"length":189,"njs":18,"njsOffset":-0.20000000298023224,"bombs":54,"notes":756,"obstacles":186},"expertPlus":{"duration":456.2485046386719,"length":192,"njs":20,"njsOffset":-0.4000000059604645,"bombs":0,"notes":1153,"obstacles":21},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Matt","songAuthorName":"Daniel Ingram, Caleb Chan, Michael Kovach","songName":"I've Had Enough of You","songSubName":"Fantoccio boss theme","bpm":142},"stats":{"downloads":113,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.0268422,"rating":0.5941636475090435},"description":"Woah, a map with two difficulties? Wac. Anyway descriptions of both:\r\nDance Time - The lighter of the difficulties, uses some less standard patterns, I tried to emulate a couple of the dances that happen during the fight.\r\nEx+ - A more standard style, though I tried to use some more variety and I think it turned out fairly well.","deletedAt":null,"_id":"5fcac427607cfe000693b71e","key":"11b91","name":"I've Had Enough of You","uploader":{"_id":"5e0158a0d89bd20006d5fb30","username":"matt"},"hash":"3900830997a659e0e0ac91e17a428a9a1625fc5f","uploaded":"2020-12-04T23:20:07.897Z","directDownload":"/cdn/11b91/3900830997a659e0e0ac91e17a428a9a1625fc5f.zip","downloadURL":"/api/download/key/11b91","coverURL":"/cdn/11b91/3900830997a659e0e0ac91e17a428a9a1625fc5f.jpg"}],"totalDocs":36011,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/hot/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":206,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":403.125,"length":201,"njs":16,"njsOffset":0,"bombs":0,"notes":841,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ASharpBlade","songAuthorName":"NateWantsToBattle","songName":"Manipulate","songSubName":"","bpm":120},"stats":{"downloads":118,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0126179,"rating":0},"description":"This song is By NatewantstoBattle. This is the 2nd song I did from him.","deletedAt":null,"_id":"5fcac1a7029e550006a10ecf","key":"11b90","name":"Manipulate","uploader":{"_id":"5fc67ef1b05fe700068e46ab","username":"asharpblade"},"hash":"f9cf3a19853b803d78cd327e12dc5c335b5acbfc","uploaded":"2020-12-04T23:09:27.806Z","directDownload":"/cdn/11b90/f9cf3a19853b803d78cd327e12dc5c335b5acbfc.zip","downloadURL":"/api/download/key/11b90","coverURL":"/cdn/11b90/f9cf3a19853b803d78cd327e12dc5c335b5acbfc.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":270,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":555.5,"length":260,"njs":12,"njsOffset":0,"bombs":0,"notes":1345,"obstacles":3},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"MegaHUstar","songAuthorName":"Rocco Vanwell","songName":"Big Bad Santa - Rocco Vanwell","songSubName":"Big Bad Santa","bpm":128},"stats":{"downloads":115,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0031818,"rating":0},"description":"First map gave a heart to it, but lightning part is too strobe effect so who has it please help yourself","deletedAt":null,"_id":"5fcabfff607cfe000693b373","key":"11b8f","name":"Rocco Vanwell - Big Bad Santa ","uploader":{"_id":"5fcabeb2d2a4c2000634f115","username":"petruska"},"hash":"fcf44786a3de8efc9e7411f26e447c37c4da7574","uploaded":"2020-12-04T23:02:23.180Z","directDownload":"/cdn/11b8f/fcf44786a3de8efc9e7411f26e447c37c4da7574.zip","downloadURL":"/api/download/key/11b8f","coverURL":"/cdn/11b8f/fcf44786a3de8efc9e7411f26e447c37c4da7574.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":111,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":175.5,"length":108,"njs":18,"njsOffset":0,"bombs":0,"notes":624,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Kpop Discord","songAuthorName":"Agust D, BTS, Dawin","songName":"What Do You Think?","songSubName":"(About That Dopessert)","bpm":97},"stats":{"downloads":111,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0030919,"rating":0},"description":"I just love being here honestly","deletedAt":null,"_id":"5fcabffb9370580009f5adeb","key":"11b8e","name":"What Do You Think? (About That Dopessert)","uploader":{"_id":"5f0419ec0ea71e0006780728","username":"smalldongstudios"},"hash":"4162744d4bd20e16335304dd2de548f01a7f6fca","uploaded":"2020-12-04T23:02:19.136Z","directDownload":"/cdn/11b8e/4162744d4bd20e16335304dd2de548f01a7f6fca.zip","downloadURL":"/api/download/key/11b8e","coverURL":"/cdn/11b8e/4162744d4bd20e16335304dd2de548f01a7f6fca.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":14,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":31.25,"length":13,"njs":22,"njsOffset":0,"bombs":0,"notes":47,"obstacles":0},"expertPlus":{"duration":32,"length":14,"njs":22,"njsOffset":0,"bombs":110,"notes":70,"obstacles":4},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BungiCraft","songAuthorName":"Imagine Dragons","songName":"\"Eggman\"","songSubName":"","bpm":136},"stats":{"downloads":120,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.0029829,"rating":0.5941636475090435},"description":"This is a shitpost. It is really bad. Play it to suffer :)","deletedAt":null,"_id":"5fcabff69370580009f5ade7","key":"11b8d","name":"\"Eggman\"","uploader":{"_id":"5fca7071029e550006a0b1a8","username":"bungicraft"},"hash":"4e26756e379841dd6e4897d8bfe80e2a1623e2e1","uploaded":"2020-12-04T23:02:14.229Z","directDownload":"/cdn/11b8d/4e26756e379841dd6e4897d8bfe80e2a1623e2e1.zip","downloadURL":"/api/download/key/11b8d","coverURL":"/cdn/11b8d/4e26756e379841dd6e4897d8bfe80e2a1623e2e1.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":230,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":435,"length":227,"njs":18,"njsOffset":0.25,"bombs":104,"notes":1327,"obstacles":188},"expertPlus":{"duration":437.875,"length":229,"njs":18,"njsOffset":0.25,"bombs":216,"notes":1331,"obstacles":104},"hard":{"duration":437.875,"length":229,"njs":16,"njsOffset":-0.5,"bombs":0,"notes":815,"obstacles":167},"normal":null},"name":"Standard"}],"levelAuthorName":"CyanSnow & Teuflum","songAuthorName":"Neil Cicierega","songName":"Bustin","songSubName":"","bpm":114.56},"stats":{"downloads":477,"plays":0,"downVotes":1,"upVotes":4,"heat":1821.9599204,"rating":0.625065935366517},"description":"BUSTIN BUSTIN\r\n\r\n\r\nTeuflum maps: https://beatsaver.com/uploader/5cff0b7498cc5a672c851a6b","deletedAt":null,"_id":"5fca6485d2a4c200063487f8","key":"11b62","name":"Neil Cicierega - Bustin","uploader":{"_id":"5cff0b7698cc5a672c8543ac","username":"cyansnow"},"hash":"e54f82ba5be9c5f773ab2ddf90fd20b69887d90f","uploaded":"2020-12-04T16:32:05.960Z","directDownload":"/cdn/11b62/e54f82ba5be9c5f773ab2ddf90fd20b69887d90f.zip","downloadURL":"/api/download/key/11b62","coverURL":"/cdn/11b62/e54f82ba5be9c5f773ab2ddf90fd20b69887d90f.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":277,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":202.5576629638672,"length":89,"njs":19,"njsOffset":-0.20000000298023224,"bombs":0,"notes":511,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Cratonugget","songAuthorName":"Camilia","songName":"Arcology on Permafrost","songSubName":"","bpm":136},"stats":{"downloads":151,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9524506,"rating":0},"description":"","deletedAt":null,"_id":"5fcab714b05fe7000691cd7c","key":"11b8c","name":"Camilia - Arcology on Permafrost ver 4","uploader":{"_id":"5f8e0d20e19f180007e17c6a","username":"cratornugg"},"hash":"1339a367a666dfcf86e9b3796ad1385f8fb47f8a","uploaded":"2020-12-04T22:24:20.275Z","directDownload":"/cdn/11b8c/1339a367a666dfcf86e9b3796ad1385f8fb47f8a.zip","downloadURL":"/api/download/key/11b8c","coverURL":"/cdn/11b8c/1339a367a666dfcf86e9b3796ad1385f8fb47f8a.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":32,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":128.57183837890625,"length":32,"njs":25,"njsOffset":0.6000000238418579,"bombs":17,"notes":2998,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"fuck","songAuthorName":"engineer gaming","songName":"HEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAHHH","songSubName":"Engineer!!!!!!","bpm":240},"stats":{"downloads":147,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9513778,"rating":0},"description":"HEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHHHH","deletedAt":null,"_id":"5fcab6e3b05fe7000691cd37","key":"11b8b","name":"Engineer Hey Ya","uploader":{"_id":"5f8a6a7f9f3df7000863cac3","username":"joen"},"hash":"93daccae82f9dd85881f8b309210ce432cd602aa","uploaded":"2020-12-04T22:23:31.999Z","directDownload":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.zip","downloadURL":"/api/download/key/11b8b","coverURL":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":200,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":823.6616821289062,"length":190,"njs":24,"njsOffset":-0.10000000149011612,"bombs":23,"notes":2777,"obstacles":332},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Water","songAuthorName":"DJ Kurara","songName":"Be My Extratone","songSubName":"","bpm":260},"stats":{"downloads":152,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9352852,"rating":0},"description":"520bpm jump map that requires a bit of stamina\nundermapped in some parts (things that should be notes mapped as walls) for stamina reasons\nty saint for playtest\nminion toy","deletedAt":null,"_id":"5fcab40fb05fe7000691ca64","key":"11b89","name":"DJ Kurara - Be My Extratone","uploader":{"_id":"5cff0b7698cc5a672c85513e","username":"waterbottle7"},"hash":"48afdca736b315555b25a2dfcd41be76675ec63f","uploaded":"2020-12-04T22:11:27.833Z","directDownload":"/cdn/11b89/48afdca736b315555b25a2dfcd41be76675ec63f.zip","downloadURL":"/api/download/key/11b89","coverURL":"/cdn/11b89/48afdca736b315555b25a2dfcd41be76675ec63f.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":22,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":87.75,"length":21,"njs":19,"njsOffset":-0.30000001192092896,"bombs":0,"notes":564,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BearplayzTheBear","songAuthorName":"Drumset Fundamentals","songName":"6 Lane Vibro Challenge","songSubName":"6 Lane Vibro Challenge","bpm":250},"stats":{"downloads":137,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9338203,"rating":0},"description":"i hate myself","deletedAt":null,"_id":"5fcab3cda3f6d20006e04664","key":"11b88","name":"6 Lane Vibro Challenge (shit)","uploader":{"_id":"5e0258f79921260006595932","username":"bearplayzthebear"},"hash":"c027c02cdd36657d2b1e102995e13a753b67d26c","uploaded":"2020-12-04T22:10:21.914Z","directDownload":"/cdn/11b88/c027c02cdd36657d2b1e102995e13a753b67d26c.zip","downloadURL":"/api/download/key/11b88","coverURL":"/cdn/11b88/c027c02cdd36657d2b1e102995e13a753b67d26c.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":140,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":385.5028076171875,"length":136,"njs":22,"njsOffset":-0.3400000035762787,"bombs":336,"notes":677,"obstacles":4},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BungiCraft","songAuthorName":"Jake Hill & Josh A","songName":"RUN UP!","songSubName":"","bpm":169},"stats":{"downloads":350,"plays":0,"downVotes":1,"upVotes":3,"heat":1821.9166942,"rating":0.5959970389138742},"description":"First ever map made by me. I tried to make it as good as I could, but don't expect greatness.","deletedAt":null,"_id":"5fca7be09370580009f55f31","key":"11b6c","name":"Jake Hill & Josh A - RUN UP!","uploader":{"_id":"5fca7071029e550006a0b1a8","username":"bungicraft"},"hash":"b0af3060ca4d21358848491d3269e78ecd6cb4df","uploaded":"2020-12-04T18:11:44.891Z","directDownload":"/cdn/11b6c/b0af3060ca4d21358848491d3269e78ecd6cb4df.zip","downloadURL":"/api/download/key/11b6c","coverURL":"/cdn/11b6c/b0af3060ca4d21358848491d3269e78ecd6cb4df.jpg"}],"totalDocs":36011,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/1?q=uploader.name%3Abennydabeast&sortOrder=Relevance")
+                .unwrap(),
+            page_json(&["194e", "24e3"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/2?q=uploader.name%3Abennydabeast&sortOrder=Relevance")
+                .unwrap(),
+            page_json(&[]),
+        );
         let client = FakeClientPaged::new(pages);
         assert_eq!(
             client
-                .maps_hot()
-                .map(|m| m.unwrap().key)
+                .search_advanced_page_iter(&query, 1)
+                .map(|m| m.unwrap().id)
                 .collect::<Vec<String>>(),
-            vec![
-                "11b7a".to_string(),
-                "11b77".to_string(),
-                "11b9d".to_string(),
-                "11b63".to_string(),
-                "11b5e".to_string(),
-                "11b49".to_string(),
-                "11b8a".to_string(),
-                "11bad".to_string(),
-                "11bac".to_string(),
-                "11baa".to_string(),
-                "11b85".to_string(),
-                "11ba5".to_string(),
-                "11ba4".to_string(),
-                "11ba3".to_string(),
-                "11b73".to_string(),
-                "11b64".to_string(),
-                "11b9e".to_string(),
-                "11b9b".to_string(),
-                "11b98".to_string(),
-                "11b91".to_string(),
-                "11b90".to_string(),
-                "11b8f".to_string(),
-                "11b8e".to_string(),
-                "11b8d".to_string(),
-                "11b62".to_string(),
-                "11b8c".to_string(),
-                "11b8b".to_string(),
-                "11b89".to_string(),
-                "11b88".to_string(),
-                "11b6c".to_string()
-            ]
+            vec!["194e".to_string(), "24e3".to_string(),]
         );
     }
     #[test]
-    fn test_maps_hot_page() {
-        let client = FakeClient::new(BEATSAVER_URL.join("api/maps/hot/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":235,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":488.125,"length":232,"njs":19,"njsOffset":0.30000001192092896,"bombs":120,"notes":1643,"obstacles":94},"expertPlus":{"duration":487.625,"length":232,"njs":24,"njsOffset":0.20000000298023224,"bombs":124,"notes":1843,"obstacles":13},"hard":{"duration":488,"length":232,"njs":15,"njsOffset":0,"bombs":8,"notes":966,"obstacles":194},"normal":null},"name":"Standard"}],"levelAuthorName":"Mr.Mrow","songAuthorName":"Juggernaut.","songName":"Abyss","songSubName":"Counterfate","bpm":126},"stats":{"downloads":807,"plays":0,"downVotes":0,"upVotes":4,"heat":1811.014434,"rating":0.6919940778277485},"description":"Yo ItsMagma yo how do you be, I guess you'll mod this version too\nImplemented some feedback, simple up down map\n\nMr.Mrow#8039 for feedback","deletedAt":null,"_id":"5fc2ca8cf9ebbf000692dffe","key":"1170d","name":"Juggernaut - Abyss","uploader":{"_id":"5f1228528f03870006b5d00c","username":"mr.mrow"},"hash":"0859aa5c4c5985ecd1201d14b1bb3d3e80ce7fe1","uploaded":"2020-11-28T22:09:16.831Z","directDownload":"/cdn/1170d/0859aa5c4c5985ecd1201d14b1bb3d3e80ce7fe1.zip","downloadURL":"/api/download/key/1170d","coverURL":"/cdn/1170d/0859aa5c4c5985ecd1201d14b1bb3d3e80ce7fe1.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":169,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":420.25,"length":168,"njs":21,"njsOffset":-0.800000011920929,"bombs":32,"notes":1035,"obstacles":458},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Fvrwvrd","songAuthorName":"Xilent","songName":"Run","songSubName":"","bpm":150},"stats":{"downloads":558,"plays":0,"downVotes":0,"upVotes":2,"heat":1811.0060785,"rating":0.6407951473548253},"description":"gamer streams.\n\nI think i'm getting better at this mappeing thing\n\nfvrwvrd#2472 on discord for feedback. pls give","deletedAt":null,"_id":"5fc2fdff62bd1c0007a277ef","key":"1176c","name":"Xilent - Run","uploader":{"_id":"5f7bd3c84bf28e00062a6286","username":"fvrwvrd"},"hash":"31a2090b68a28617d2060a97f66bc28d14e66952","uploaded":"2020-11-29T01:48:47.182Z","directDownload":"/cdn/1176c/31a2090b68a28617d2060a97f66bc28d14e66952.zip","downloadURL":"/api/download/key/1176c","coverURL":"/cdn/1176c/31a2090b68a28617d2060a97f66bc28d14e66952.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":236,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":413.75,"length":236,"njs":15,"njsOffset":0,"bombs":73,"notes":576,"obstacles":12},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"mspchill","songAuthorName":"Scott Gairdner","songName":"S*x Offender Shuffle ","songSubName":"","bpm":105},"stats":{"downloads":443,"plays":0,"downVotes":0,"upVotes":0,"heat":1810.9783342,"rating":0},"description":"The state of Florida has asked us to, make a Beat Saber map for you.","deletedAt":null,"_id":"5fc32e09ec1c5f00073c3393","key":"117aa","name":"S*x Offender Shuffle (Redone)","uploader":{"_id":"5e993d26d87e580006d85faf","username":"mspchill"},"hash":"39f5231f354b09fd5cd8c67b1794b73da5934bb8","uploaded":"2020-11-29T05:13:45.039Z","directDownload":"/cdn/117aa/39f5231f354b09fd5cd8c67b1794b73da5934bb8.zip","downloadURL":"/api/download/key/117aa","coverURL":"/cdn/117aa/39f5231f354b09fd5cd8c67b1794b73da5934bb8.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":274,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":638,"length":273,"njs":21,"njsOffset":0.400000005960464,"bombs":0,"notes":1674,"obstacles":0},"expertPlus":{"duration":638,"length":273,"njs":21,"njsOffset":0.400000005960464,"bombs":0,"notes":1674,"obstacles":1427},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Verlexin","songAuthorName":"Code: Pandorum","songName":"Overseers","songSubName":"feat. Bratkilla","bpm":140},"stats":{"downloads":547,"plays":0,"downVotes":0,"upVotes":2,"heat":1810.9743956,"rating":0.6407951473548253},"description":"my proudest map so far, DARK (ex) has no walls and VOID (ex+) has the walls. :)","deletedAt":null,"_id":"5fc2f86d9f95e80006d2f51a","key":"11760","name":"Code: Pandorum & Bratkilla - Overseers","uploader":{"_id":"5e894f912a340900068db88c","username":"verlexin"},"hash":"0d628b3a8b694096b241fa13d2aa690ff1eb38f0","uploaded":"2020-11-29T01:25:01.453Z","directDownload":"/cdn/11760/0d628b3a8b694096b241fa13d2aa690ff1eb38f0.zip","downloadURL":"/api/download/key/11760","coverURL":"/cdn/11760/0d628b3a8b694096b241fa13d2aa690ff1eb38f0.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":23,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":27.578125,"length":23,"njs":15,"njsOffset":0,"bombs":10,"notes":152,"obstacles":0},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"mspchill","songAuthorName":"saturdaynightbigsalaryman","songName":"New Anime Plot","songSubName":"","bpm":70},"stats":{"downloads":430,"plays":0,"downVotes":0,"upVotes":0,"heat":1810.8794728,"rating":0},"description":"i got this new anime plot. basically there’s this high school girl except she’s got huge bunkers. i mean some serious honkers. a real set of badonkers. packin some dobonhonkeros. massive dohoonkabhankoloos. big ol’ tonhongerekoogers. what happens next?! transfer student shows up with even bigger bonkhonagahoogs. humongous hungolomghononoloughongous","deletedAt":null,"_id":"5fc31ca862bd1c0007a29993","key":"1179a","name":"New Anime Plot (Redone)","uploader":{"_id":"5e993d26d87e580006d85faf","username":"mspchill"},"hash":"7e3cdc22855ee91272b71e885c3f74335b3a6208","uploaded":"2020-11-29T03:59:36.274Z","directDownload":"/cdn/1179a/7e3cdc22855ee91272b71e885c3f74335b3a6208.zip","downloadURL":"/api/download/key/1179a","coverURL":"/cdn/1179a/7e3cdc22855ee91272b71e885c3f74335b3a6208.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":467,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":950.5875244140625,"length":456,"njs":12,"njsOffset":0,"bombs":16,"notes":1585,"obstacles":394},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"phunkyspelunky","songAuthorName":"Tame Impala","songName":"Let It Happen","songSubName":"","bpm":125},"stats":{"downloads":804,"plays":0,"downVotes":0,"upVotes":3,"heat":1810.8530357,"rating":0.6705937099936721},"description":"May add more difficulties in the near future. Enjoy :)","deletedAt":null,"_id":"5fc2c4249f95e80006d2af52","key":"11706","name":"Tame Impala - Let It Happen (Expert) ","uploader":{"_id":"5fa85d017925e2000613610e","username":"phunkyspelunky"},"hash":"6674b0161cda51ce3d1cb2c208cd4faaa12bdb70","uploaded":"2020-11-28T21:41:56.148Z","directDownload":"/cdn/11706/6674b0161cda51ce3d1cb2c208cd4faaa12bdb70.zip","downloadURL":"/api/download/key/11706","coverURL":"/cdn/11706/6674b0161cda51ce3d1cb2c208cd4faaa12bdb70.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":false,"hard":true,"normal":false},"duration":158,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":null,"hard":{"duration":231.69166564941406,"length":139,"njs":12,"njsOffset":0,"bombs":102,"notes":426,"obstacles":4},"normal":null},"name":"Standard"}],"levelAuthorName":"unknow","songAuthorName":"unknow","songName":"unknow","songSubName":"","bpm":100},"stats":{"downloads":459,"plays":0,"downVotes":1,"upVotes":0,"heat":1810.8503299,"rating":0.40583635249095656},"description":"A hip hop remix of Amelia Watson's ASMR Stream by Holo Bass","deletedAt":null,"_id":"5fc31788c09f4c0006f243b4","key":"11799","name":"Amelia Watson - Pop on Rocks A Dr. Seuss Rap","uploader":{"_id":"5f1a4db6a51f7f0006f1ac69","username":"budderbroham"},"hash":"2a8ab6a3439f365e834fa5d6d0446838eda8fe6e","uploaded":"2020-11-29T03:37:44.846Z","directDownload":"/cdn/11799/2a8ab6a3439f365e834fa5d6d0446838eda8fe6e.zip","downloadURL":"/api/download/key/11799","coverURL":"/cdn/11799/2a8ab6a3439f365e834fa5d6d0446838eda8fe6e.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":267,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":893.75,"length":255,"njs":23,"njsOffset":-0.5,"bombs":12,"notes":2669,"obstacles":95},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Retrx","songAuthorName":"Camellia","songName":"The King Of Lions","songSubName":"","bpm":210},"stats":{"downloads":420,"plays":0,"downVotes":0,"upVotes":1,"heat":1810.8466323,"rating":0.5941636475090435},"description":"","deletedAt":null,"_id":"5fc316e2c09f4c0006f24324","key":"11798","name":"King of Lions","uploader":{"_id":"5ef3d186f82f170006c0aad8","username":"retrx"},"hash":"49b27568c702b6ceb9eb2d99555aa24893231390","uploaded":"2020-11-29T03:34:58.452Z","directDownload":"/cdn/11798/49b27568c702b6ceb9eb2d99555aa24893231390.zip","downloadURL":"/api/download/key/11798","coverURL":"/cdn/11798/49b27568c702b6ceb9eb2d99555aa24893231390.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":199,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":395.125,"length":189,"njs":20,"njsOffset":0.25,"bombs":0,"notes":560,"obstacles":43},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Ryger","songAuthorName":"Rone","songName":"Lou","songSubName":"","bpm":125},"stats":{"downloads":424,"plays":0,"downVotes":0,"upVotes":1,"heat":1810.8344219,"rating":0.5941636475090435},"description":"","deletedAt":null,"_id":"5fc314bcec1c5f00073c171b","key":"11795","name":"Rone - Lou","uploader":{"_id":"5cff0b7798cc5a672c855a42","username":"ryger"},"hash":"6cacaae1760882e0b75bc6e6d97b8d0f9af2f218","uploaded":"2020-11-29T03:25:48.986Z","directDownload":"/cdn/11795/6cacaae1760882e0b75bc6e6d97b8d0f9af2f218.zip","downloadURL":"/api/download/key/11795","coverURL":"/cdn/11795/6cacaae1760882e0b75bc6e6d97b8d0f9af2f218.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":227,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":476.75,"length":228,"njs":20,"njsOffset":0,"bombs":27,"notes":2241,"obstacles":11},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ImAbstr4kt","songAuthorName":"LHUGUENY","songName":"Undertale the Musical","songSubName":"","bpm":125},"stats":{"downloads":451,"plays":0,"downVotes":1,"upVotes":1,"heat":1810.7784936,"rating":0.5},"description":"A beautiful shitmap made in 2 hours, if you manage to pass this PLEASE send me a video. I would love to see it.\n\nFeedback: Abstr4kt#2416","deletedAt":null,"_id":"5fc30ae8f9ebbf000693349f","key":"11780","name":"LHUGUENY - Undertale the Musical","uploader":{"_id":"5f03a0cdc51ba90006d82f45","username":"imabstr4kt"},"hash":"48d4519cd15d323b4545845c7ce62a9899eec64e","uploaded":"2020-11-29T02:43:52.211Z","directDownload":"/cdn/11780/48d4519cd15d323b4545845c7ce62a9899eec64e.zip","downloadURL":"/api/download/key/11780","coverURL":"/cdn/11780/48d4519cd15d323b4545845c7ce62a9899eec64e.jpg"}],"totalDocs":35737,"lastPage":3573,"prevPage":1,"nextPage":3}"#.into());
-        let _: Page<Map> = client.maps_hot_page(2).unwrap();
+    fn test_user() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("users/id/4285984").unwrap(),
+            r#"{"id":4285984,"name":"qwerty01","avatar":"https://cdn.beatsaver.com/avatar/4285984.png"}"#.into(),
+        );
+        let user = client.user(4285984).unwrap();
+        assert_eq!(user.name, "qwerty01");
     }
     #[test]
-    fn test_maps_hot_page_iter() {
-        let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/hot/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":160,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":291,"length":157,"njs":16,"njsOffset":0,"bombs":0,"notes":745,"obstacles":12},"expertPlus":{"duration":291.0625,"length":157,"njs":18,"njsOffset":0.20000000298023224,"bombs":0,"notes":804,"obstacles":11},"hard":{"duration":291,"length":157,"njs":14,"njsOffset":0,"bombs":0,"notes":677,"obstacles":11},"normal":null},"name":"Standard"}],"levelAuthorName":"Alekcake","songAuthorName":"Blackpink ft. Cardi B","songName":"Bet You Wanna","songSubName":"","bpm":111},"stats":{"downloads":212,"plays":0,"downVotes":0,"upVotes":2,"heat":1822.1947366,"rating":0.6407951473548253},"description":"Gameplay here!: https://youtu.be/umSrHBLZxeg\r\n\r\nSpecial thanks to noxn and todai for helping us with the map!\r\nAlso thank you to our playtesters, pezzax and TinkerVr85!\r\n\r\nNPS \r\nExpert+: 4.99\r\nExpert: 4.63\r\nHard: 4.2\r\n\r\nCustom Map GIVEAWAY each month on our discord server!\r\nHow to enter: Subscribe to our YouTube channel through the gameplay link, join our discord server and pick the \"Giveaway\" role and you'll be entered!\r\nAlekcake's Kpop & VR Arcade - https://discord.gg/P4eGh26","deletedAt":null,"_id":"5fcaacc0029e550006a0f9f8","key":"11b85","name":"Blackpink ft Cardi B - Bet You Wanna","uploader":{"_id":"5ee6cc499ae7380006da7aef","username":"alekcake"},"hash":"9bdf6e59503b5081ca726c9c0c1d71808f6580ab","uploaded":"2020-12-04T21:40:16.795Z","directDownload":"/cdn/11b85/9bdf6e59503b5081ca726c9c0c1d71808f6580ab.zip","downloadURL":"/api/download/key/11b85","coverURL":"/cdn/11b85/9bdf6e59503b5081ca726c9c0c1d71808f6580ab.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":false,"expertPlus":false,"hard":true,"normal":true},"duration":102,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":92,"notes":126,"obstacles":12},"expert":null,"expertPlus":null,"hard":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":327,"notes":315,"obstacles":18},"normal":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":92,"notes":223,"obstacles":18}},"name":"Standard"}],"levelAuthorName":"Josh","songAuthorName":"Laura Shigihara","songName":"Brainiac Maniac - Plants VS Zombies","songSubName":"","bpm":120},"stats":{"downloads":43,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1609861,"rating":0},"description":"","deletedAt":null,"_id":"5fcadbbcd2a4c200063510e8","key":"11ba5","name":"Brainiac Maniac - Plants VS Zombies","uploader":{"_id":"5fc8273aa3f6d20006de32e3","username":"electrojosh12@gmail.com"},"hash":"220f9e5dabdd505f8fa7e995ce1e46110b6cfda1","uploaded":"2020-12-05T01:00:44.375Z","directDownload":"/cdn/11ba5/220f9e5dabdd505f8fa7e995ce1e46110b6cfda1.zip","downloadURL":"/api/download/key/11ba5","coverURL":"/cdn/11ba5/220f9e5dabdd505f8fa7e995ce1e46110b6cfda1.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":259,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":667.5,"length":258,"njs":19,"njsOffset":-0.4000000059604645,"bombs":15,"notes":1758,"obstacles":74},"expertPlus":{"duration":667.5,"length":258,"njs":21,"njsOffset":-0.550000011920929,"bombs":8,"notes":1812,"obstacles":75},"hard":{"duration":667.5,"length":258,"njs":17,"njsOffset":0,"bombs":50,"notes":1340,"obstacles":77},"normal":null},"name":"Standard"}],"levelAuthorName":"mux","songAuthorName":"Takaaki Natsushiro","songName":"Endroll -covered by Isekaijoucho-","songSubName":"","bpm":155},"stats":{"downloads":45,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1591567,"rating":0},"description":"Lyric MV\r\nhttps://www.youtube.com/watch?v=X0BykQ-ljKE\r\n\r\nOriginal\r\nhttps://www.youtube.com/watch?v=a7jPH3vbMys\r\n","deletedAt":null,"_id":"5fcadb6aa3f6d20006e0702f","key":"11ba4","name":"Endroll -covered by Isekaijoucho-","uploader":{"_id":"5e0a5f2046a07100065909fe","username":"mux"},"hash":"1c861c8438a7012422be6acdc78854663c509853","uploaded":"2020-12-05T00:59:22.053Z","directDownload":"/cdn/11ba4/1c861c8438a7012422be6acdc78854663c509853.zip","downloadURL":"/api/download/key/11ba4","coverURL":"/cdn/11ba4/1c861c8438a7012422be6acdc78854663c509853.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":215,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":383,"length":214,"njs":12,"njsOffset":0,"bombs":0,"notes":1011,"obstacles":7},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"darthGandalf95","songAuthorName":"Jan Hegenberg","songName":"Die Garde der Hoffnung","songSubName":"","bpm":107.30000305175781},"stats":{"downloads":38,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1591484,"rating":0},"description":"Für alle Hegis.\nFirst try ... so be rude","deletedAt":null,"_id":"5fcadb69029e550006a12b6d","key":"11ba3","name":"Jan Hegenberg - Die Garde der Hoffnung","uploader":{"_id":"5fcacf72b05fe7000691e5af","username":"darthgandalf95"},"hash":"a41ea9833039435c1216a9cdfb1f0e35024d2b4e","uploaded":"2020-12-05T00:59:21.677Z","directDownload":"/cdn/11ba3/a41ea9833039435c1216a9cdfb1f0e35024d2b4e.zip","downloadURL":"/api/download/key/11ba3","coverURL":"/cdn/11ba3/a41ea9833039435c1216a9cdfb1f0e35024d2b4e.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":400,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":615.2254638671875,"length":398,"njs":18,"njsOffset":0.30000001192092896,"bombs":132,"notes":1328,"obstacles":77},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Dalmane","songAuthorName":"Focus","songName":"Hocus Pocus","songSubName":"","bpm":92.69999694824219},"stats":{"downloads":351,"plays":0,"downVotes":1,"upVotes":4,"heat":1822.130901,"rating":0.625065935366517},"description":"Swing your arms and move your hips to this 1971 rock classic!  This song was the reason I started mapping.  Patterns are designed for full arm swings.  Expert only.  Run time 6:40.  Warning: some short strobing sequences.","deletedAt":null,"_id":"5fca8294607cfe0006936dbc","key":"11b73","name":"Hocus Pocus - Focus (Full Version)","uploader":{"_id":"5e7fb3ba16c96800060e43b5","username":"dalmane"},"hash":"c324b03dbeea8cc6f9fce6a7829594796d0f117b","uploaded":"2020-12-04T18:40:20.089Z","directDownload":"/cdn/11b73/c324b03dbeea8cc6f9fce6a7829594796d0f117b.zip","downloadURL":"/api/download/key/11b73","coverURL":"/cdn/11b73/c324b03dbeea8cc6f9fce6a7829594796d0f117b.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":426,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":842.671875,"length":421,"njs":16,"njsOffset":0,"bombs":0,"notes":1817,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ASharpBlade","songAuthorName":"Avenged Sevenfold","songName":"Not Ready to Die (From \"Call of the Dead\")","songSubName":"","bpm":120},"stats":{"downloads":406,"plays":0,"downVotes":0,"upVotes":4,"heat":1822.1289253,"rating":0.6919940778277485},"description":"This songs is from Avenged Sevenfold. It was a easter egg song from Cod Zombies map \" Call of the Dead\".","deletedAt":null,"_id":"5fca6c44b05fe700069172fa","key":"11b64","name":"Not Ready To Die","uploader":{"_id":"5fc67ef1b05fe700068e46ab","username":"asharpblade"},"hash":"15f9a896a271d4063c87c0d79b1871bf02fa9070","uploaded":"2020-12-04T17:05:08.940Z","directDownload":"/cdn/11b64/15f9a896a271d4063c87c0d79b1871bf02fa9070.zip","downloadURL":"/api/download/key/11b64","coverURL":"/cdn/11b64/15f9a896a271d4063c87c0d79b1871bf02fa9070.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":136,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":378.35833740234375,"length":133,"njs":14,"njsOffset":0.800000011920929,"bombs":11,"notes":469,"obstacles":10},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Dack","songAuthorName":"THE iDOLM@STER MILLION LIVE!","songName":"Super Lover","songSubName":"","bpm":170},"stats":{"downloads":60,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1238145,"rating":0},"description":"Patreon: patreon.com/Dack","deletedAt":null,"_id":"5fcad533d2a4c200063509a7","key":"11b9e","name":"THE IDOLM@STER MILLION - Super Lover","uploader":{"_id":"5cff0b7598cc5a672c852c6f","username":"dack"},"hash":"9303890819a081a59bb0a7c0c4718d367330ca34","uploaded":"2020-12-05T00:32:51.654Z","directDownload":"/cdn/11b9e/9303890819a081a59bb0a7c0c4718d367330ca34.zip","downloadURL":"/api/download/key/11b9e","coverURL":"/cdn/11b9e/9303890819a081a59bb0a7c0c4718d367330ca34.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":471,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":2672.249267578125,"length":471,"njs":23,"njsOffset":-1.399999976158142,"bombs":1,"notes":5080,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Lolighter","songAuthorName":"Sewerslvt","songName":"Mr. Kill Myself","songSubName":"","bpm":340},"stats":{"downloads":77,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1002815,"rating":0},"description":"funny lolighter map","deletedAt":null,"_id":"5fcad110d2a4c2000635047d","key":"11b9b","name":"M.K.MS","uploader":{"_id":"5cff0b7698cc5a672c854a7f","username":"wh4t"},"hash":"2fea40d139432aaa7b4bb1c5e46309c92dda2179","uploaded":"2020-12-05T00:15:12.667Z","directDownload":"/cdn/11b9b/2fea40d139432aaa7b4bb1c5e46309c92dda2179.zip","downloadURL":"/api/download/key/11b9b","coverURL":"/cdn/11b9b/2fea40d139432aaa7b4bb1c5e46309c92dda2179.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":192,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":403.25,"length":192,"njs":12,"njsOffset":0,"bombs":22,"notes":434,"obstacles":9},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Xpoiled","songAuthorName":"Distrion","songName":"Distrion Alibi (ft. heelen","songSubName":"Distrion","bpm":126},"stats":{"downloads":94,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0699933,"rating":0},"description":"My first beatmap enjoy follow for more. make sure to have decent play space as ther is 1 spot wher ther is a three wide wall","deletedAt":null,"_id":"5fcacbbda3f6d20006e05db1","key":"11b98","name":"Distrion - Alibi (Ft. Heleen)","uploader":{"_id":"5fc97909b05fe7000690be4e","username":"xpoled"},"hash":"b011aa10021f1aaaca714dc4ae0b176c71558247","uploaded":"2020-12-04T23:52:29.700Z","directDownload":"/cdn/11b98/b011aa10021f1aaaca714dc4ae0b176c71558247.zip","downloadURL":"/api/download/key/11b98","coverURL":"/cdn/11b98/b011aa10021f1aaaca714dc4ae0b176c71558247.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":195,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":449.2485046386719,"length":189,"njs":18,"njsOffset":-0.20000000298023224,"bombs":54,"notes":756,"obstacles":186},"expertPlus":{"duration":456.2485046386719,"length":192,"njs":20,"njsOffset":-0.4000000059604645,"bombs":0,"notes":1153,"obstacles":21},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Matt","songAuthorName":"Daniel Ingram, Caleb Chan, Michael Kovach","songName":"I've Had Enough of You","songSubName":"Fantoccio boss theme","bpm":142},"stats":{"downloads":113,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.0268422,"rating":0.5941636475090435},"description":"Woah, a map with two difficulties? Wac. Anyway descriptions of both:\r\nDance Time - The lighter of the difficulties, uses some less standard patterns, I tried to emulate a couple of the dances that happen during the fight.\r\nEx+ - A more standard style, though I tried to use some more variety and I think it turned out fairly well.","deletedAt":null,"_id":"5fcac427607cfe000693b71e","key":"11b91","name":"I've Had Enough of You","uploader":{"_id":"5e0158a0d89bd20006d5fb30","username":"matt"},"hash":"3900830997a659e0e0ac91e17a428a9a1625fc5f","uploaded":"2020-12-04T23:20:07.897Z","directDownload":"/cdn/11b91/3900830997a659e0e0ac91e17a428a9a1625fc5f.zip","downloadURL":"/api/download/key/11b91","coverURL":"/cdn/11b91/3900830997a659e0e0ac91e17a428a9a1625fc5f.jpg"}],"totalDocs":36011,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/hot/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":206,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":403.125,"length":201,"njs":16,"njsOffset":0,"bombs":0,"notes":841,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ASharpBlade","songAuthorName":"NateWantsToBattle","songName":"Manipulate","songSubName":"","bpm":120},"stats":{"downloads":118,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0126179,"rating":0},"description":"This song is By NatewantstoBattle. This is the 2nd song I did from him.","deletedAt":null,"_id":"5fcac1a7029e550006a10ecf","key":"11b90","name":"Manipulate","uploader":{"_id":"5fc67ef1b05fe700068e46ab","username":"asharpblade"},"hash":"f9cf3a19853b803d78cd327e12dc5c335b5acbfc","uploaded":"2020-12-04T23:09:27.806Z","directDownload":"/cdn/11b90/f9cf3a19853b803d78cd327e12dc5c335b5acbfc.zip","downloadURL":"/api/download/key/11b90","coverURL":"/cdn/11b90/f9cf3a19853b803d78cd327e12dc5c335b5acbfc.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":270,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":555.5,"length":260,"njs":12,"njsOffset":0,"bombs":0,"notes":1345,"obstacles":3},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"MegaHUstar","songAuthorName":"Rocco Vanwell","songName":"Big Bad Santa - Rocco Vanwell","songSubName":"Big Bad Santa","bpm":128},"stats":{"downloads":115,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0031818,"rating":0},"description":"First map gave a heart to it, but lightning part is too strobe effect so who has it please help yourself","deletedAt":null,"_id":"5fcabfff607cfe000693b373","key":"11b8f","name":"Rocco Vanwell - Big Bad Santa ","uploader":{"_id":"5fcabeb2d2a4c2000634f115","username":"petruska"},"hash":"fcf44786a3de8efc9e7411f26e447c37c4da7574","uploaded":"2020-12-04T23:02:23.180Z","directDownload":"/cdn/11b8f/fcf44786a3de8efc9e7411f26e447c37c4da7574.zip","downloadURL":"/api/download/key/11b8f","coverURL":"/cdn/11b8f/fcf44786a3de8efc9e7411f26e447c37c4da7574.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":111,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":175.5,"length":108,"njs":18,"njsOffset":0,"bombs":0,"notes":624,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Kpop Discord","songAuthorName":"Agust D, BTS, Dawin","songName":"What Do You Think?","songSubName":"(About That Dopessert)","bpm":97},"stats":{"downloads":111,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0030919,"rating":0},"description":"I just love being here honestly","deletedAt":null,"_id":"5fcabffb9370580009f5adeb","key":"11b8e","name":"What Do You Think? (About That Dopessert)","uploader":{"_id":"5f0419ec0ea71e0006780728","username":"smalldongstudios"},"hash":"4162744d4bd20e16335304dd2de548f01a7f6fca","uploaded":"2020-12-04T23:02:19.136Z","directDownload":"/cdn/11b8e/4162744d4bd20e16335304dd2de548f01a7f6fca.zip","downloadURL":"/api/download/key/11b8e","coverURL":"/cdn/11b8e/4162744d4bd20e16335304dd2de548f01a7f6fca.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":14,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":31.25,"length":13,"njs":22,"njsOffset":0,"bombs":0,"notes":47,"obstacles":0},"expertPlus":{"duration":32,"length":14,"njs":22,"njsOffset":0,"bombs":110,"notes":70,"obstacles":4},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BungiCraft","songAuthorName":"Imagine Dragons","songName":"\"Eggman\"","songSubName":"","bpm":136},"stats":{"downloads":120,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.0029829,"rating":0.5941636475090435},"description":"This is a shitpost. It is really bad. Play it to suffer :)","deletedAt":null,"_id":"5fcabff69370580009f5ade7","key":"11b8d","name":"\"Eggman\"","uploader":{"_id":"5fca7071029e550006a0b1a8","username":"bungicraft"},"hash":"4e26756e379841dd6e4897d8bfe80e2a1623e2e1","uploaded":"2020-12-04T23:02:14.229Z","directDownload":"/cdn/11b8d/4e26756e379841dd6e4897d8bfe80e2a1623e2e1.zip","downloadURL":"/api/download/key/11b8d","coverURL":"/cdn/11b8d/4e26756e379841dd6e4897d8bfe80e2a1623e2e1.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":230,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":435,"length":227,"njs":18,"njsOffset":0.25,"bombs":104,"notes":1327,"obstacles":188},"expertPlus":{"duration":437.875,"length":229,"njs":18,"njsOffset":0.25,"bombs":216,"notes":1331,"obstacles":104},"hard":{"duration":437.875,"length":229,"njs":16,"njsOffset":-0.5,"bombs":0,"notes":815,"obstacles":167},"normal":null},"name":"Standard"}],"levelAuthorName":"CyanSnow & Teuflum","songAuthorName":"Neil Cicierega","songName":"Bustin","songSubName":"","bpm":114.56},"stats":{"downloads":477,"plays":0,"downVotes":1,"upVotes":4,"heat":1821.9599204,"rating":0.625065935366517},"description":"BUSTIN BUSTIN\r\n\r\n\r\nTeuflum maps: https://beatsaver.com/uploader/5cff0b7498cc5a672c851a6b","deletedAt":null,"_id":"5fca6485d2a4c200063487f8","key":"11b62","name":"Neil Cicierega - Bustin","uploader":{"_id":"5cff0b7698cc5a672c8543ac","username":"cyansnow"},"hash":"e54f82ba5be9c5f773ab2ddf90fd20b69887d90f","uploaded":"2020-12-04T16:32:05.960Z","directDownload":"/cdn/11b62/e54f82ba5be9c5f773ab2ddf90fd20b69887d90f.zip","downloadURL":"/api/download/key/11b62","coverURL":"/cdn/11b62/e54f82ba5be9c5f773ab2ddf90fd20b69887d90f.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":277,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":202.5576629638672,"length":89,"njs":19,"njsOffset":-0.20000000298023224,"bombs":0,"notes":511,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Cratonugget","songAuthorName":"Camilia","songName":"Arcology on Permafrost","songSubName":"","bpm":136},"stats":{"downloads":151,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9524506,"rating":0},"description":"","deletedAt":null,"_id":"5fcab714b05fe7000691cd7c","key":"11b8c","name":"Camilia - Arcology on Permafrost ver 4","uploader":{"_id":"5f8e0d20e19f180007e17c6a","username":"cratornugg"},"hash":"1339a367a666dfcf86e9b3796ad1385f8fb47f8a","uploaded":"2020-12-04T22:24:20.275Z","directDownload":"/cdn/11b8c/1339a367a666dfcf86e9b3796ad1385f8fb47f8a.zip","downloadURL":"/api/download/key/11b8c","coverURL":"/cdn/11b8c/1339a367a666dfcf86e9b3796ad1385f8fb47f8a.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":32,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":128.57183837890625,"length":32,"njs":25,"njsOffset":0.6000000238418579,"bombs":17,"notes":2998,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"fuck","songAuthorName":"engineer gaming","songName":"HEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAHHH","songSubName":"Engineer!!!!!!","bpm":240},"stats":{"downloads":147,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9513778,"rating":0},"description":"HEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHHHH","deletedAt":null,"_id":"5fcab6e3b05fe7000691cd37","key":"11b8b","name":"Engineer Hey Ya","uploader":{"_id":"5f8a6a7f9f3df7000863cac3","username":"joen"},"hash":"93daccae82f9dd85881f8b309210ce432cd602aa","uploaded":"2020-12-04T22:23:31.999Z","directDownload":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.zip","downloadURL":"/api/download/key/11b8b","coverURL":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":200,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":823.6616821289062,"length":190,"njs":24,"njsOffset":-0.10000000149011612,"bombs":23,"notes":2777,"obstacles":332},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Water","songAuthorName":"DJ Kurara","songName":"Be My Extratone","songSubName":"","bpm":260},"stats":{"downloads":152,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9352852,"rating":0},"description":"520bpm jump map that requires a bit of stamina\nundermapped in some parts (things that should be notes mapped as walls) for stamina reasons\nty saint for playtest\nminion toy","deletedAt":null,"_id":"5fcab40fb05fe7000691ca64","key":"11b89","name":"DJ Kurara - Be My Extratone","uploader":{"_id":"5cff0b7698cc5a672c85513e","username":"waterbottle7"},"hash":"48afdca736b315555b25a2dfcd41be76675ec63f","uploaded":"2020-12-04T22:11:27.833Z","directDownload":"/cdn/11b89/48afdca736b315555b25a2dfcd41be76675ec63f.zip","downloadURL":"/api/download/key/11b89","coverURL":"/cdn/11b89/48afdca736b315555b25a2dfcd41be76675ec63f.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":22,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":87.75,"length":21,"njs":19,"njsOffset":-0.30000001192092896,"bombs":0,"notes":564,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BearplayzTheBear","songAuthorName":"Drumset Fundamentals","songName":"6 Lane Vibro Challenge","songSubName":"6 Lane Vibro Challenge","bpm":250},"stats":{"downloads":137,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9338203,"rating":0},"description":"i hate myself","deletedAt":null,"_id":"5fcab3cda3f6d20006e04664","key":"11b88","name":"6 Lane Vibro Challenge (shit)","uploader":{"_id":"5e0258f79921260006595932","username":"bearplayzthebear"},"hash":"c027c02cdd36657d2b1e102995e13a753b67d26c","uploaded":"2020-12-04T22:10:21.914Z","directDownload":"/cdn/11b88/c027c02cdd36657d2b1e102995e13a753b67d26c.zip","downloadURL":"/api/download/key/11b88","coverURL":"/cdn/11b88/c027c02cdd36657d2b1e102995e13a753b67d26c.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":140,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":385.5028076171875,"length":136,"njs":22,"njsOffset":-0.3400000035762787,"bombs":336,"notes":677,"obstacles":4},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BungiCraft","songAuthorName":"Jake Hill & Josh A","songName":"RUN UP!","songSubName":"","bpm":169},"stats":{"downloads":350,"plays":0,"downVotes":1,"upVotes":3,"heat":1821.9166942,"rating":0.5959970389138742},"description":"First ever map made by me. I tried to make it as good as I could, but don't expect greatness.","deletedAt":null,"_id":"5fca7be09370580009f55f31","key":"11b6c","name":"Jake Hill & Josh A - RUN UP!","uploader":{"_id":"5fca7071029e550006a0b1a8","username":"bungicraft"},"hash":"b0af3060ca4d21358848491d3269e78ecd6cb4df","uploaded":"2020-12-04T18:11:44.891Z","directDownload":"/cdn/11b6c/b0af3060ca4d21358848491d3269e78ecd6cb4df.zip","downloadURL":"/api/download/key/11b6c","coverURL":"/cdn/11b6c/b0af3060ca4d21358848491d3269e78ecd6cb4df.jpg"}],"totalDocs":36011,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
-        assert_eq!(
-            client
-                .maps_hot_page_iter(1)
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "11b85".to_string(),
-                "11ba5".to_string(),
-                "11ba4".to_string(),
-                "11ba3".to_string(),
-                "11b73".to_string(),
-                "11b64".to_string(),
-                "11b9e".to_string(),
-                "11b9b".to_string(),
-                "11b98".to_string(),
-                "11b91".to_string(),
-                "11b90".to_string(),
-                "11b8f".to_string(),
-                "11b8e".to_string(),
-                "11b8d".to_string(),
-                "11b62".to_string(),
-                "11b8c".to_string(),
-                "11b8b".to_string(),
-                "11b89".to_string(),
-                "11b88".to_string(),
-                "11b6c".to_string()
-            ]
+    fn test_user_by_name() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("users/name/qwerty%2001").unwrap(),
+            r#"{"id":4285984,"name":"qwerty 01","avatar":"https://cdn.beatsaver.com/avatar/4285984.png"}"#.into(),
         );
+        let user = client.user_by_name("qwerty 01").unwrap();
+        assert_eq!(user.id, 4285984);
     }
     #[test]
-    fn test_maps_rating() {
+    fn test_verify_user() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("users/verify").unwrap(),
+            r#"{"success":true,"userId":4285984}"#.into(),
+        );
+        let request = UserVerifyRequest::oculus("1234", "nonce");
+        let response = client.verify_user(&request).unwrap();
+        assert!(response.success);
+        assert_eq!(response.user_id, Some(4285984));
+    }
+    #[test]
+    fn test_testplay() {
+        let mut client = FakeClient::new(
+            BEATSAVER_URL.join("testplay/state").unwrap(),
+            r#"{"success":true}"#.into(),
+        );
+        let request = TestplayStateRequest::new(map_hash("1"), MapState::Testplay);
+        assert!(matches!(
+            client.testplay_state(&request),
+            Err(BeatSaverApiError::AuthenticationRequired)
+        ));
+        client.credentials = Some(Credentials::Bearer("abc".into()));
+        assert!(client.testplay_state(&request).unwrap().success);
+
+        client.url = BEATSAVER_URL.join("testplay/feedback").unwrap();
+        client.data = r#"{"success":false,"errors":["Already submitted"]}"#.into();
+        let request = TestplayFeedbackRequest::new(map_hash("1"), "Great flow");
+        let response = client.testplay_feedback(&request).unwrap();
+        assert!(!response.success);
+        assert_eq!(response.errors, vec!["Already submitted"]);
+    }
+    #[test]
+    fn test_playlists_latest() {
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/rating/0").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":417,"length":195,"bombs":4,"notes":301,"obstacles":24,"njs":10,"njsOffset":0},"hard":{"duration":417,"length":195,"bombs":4,"notes":486,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":417.5,"length":195,"bombs":4,"notes":620,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":{"duration":417.5,"length":195,"bombs":0,"notes":894,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Shut Up and Dance","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":433527,"plays":558,"downVotes":140,"upVotes":11092,"heat":395.8354934,"rating":0.9581127024944741},"description":"Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY","deletedAt":null,"_id":"5cff621148229f7d88fc77c9","key":"2144","name":"Shut Up and Dance - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-21T01:27:00.000Z","hash":"89cf8bb07afb3c59ae7b5ac00337d62261c36fb4","directDownload":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip","downloadURL":"/api/download/key/2144","coverURL":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":623.3125,"length":214,"bombs":0,"notes":462,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":623.3125,"length":214,"bombs":0,"notes":639,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":623.3125,"length":214,"bombs":0,"notes":825,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Mr. Blue Sky","songSubName":"Electric Light Orchestra","songAuthorName":"GreatYazer","levelAuthorName":"greatyazer","bpm":174},"stats":{"downloads":945198,"plays":39426,"downVotes":498,"upVotes":23079,"heat":94.0251654,"rating":0.9557587286273417},"description":"Channel your inner Baby Groot.  Normal, Hard, Expert\r\nSpecial thanks to BennydaBeast for his help on this track!","deletedAt":null,"_id":"5cff620d48229f7d88fc65f7","key":"570","name":"Mr. Blue Sky | Electric Light Orchestra","uploader":{"_id":"5cff0b7298cc5a672c84ea71","username":"greatyazer"},"uploaded":"2018-06-16T16:53:34.000Z","hash":"236173d5ba7dc379d480b9cb5fb6b4fa5abe77da","directDownload":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.zip","downloadURL":"/api/download/key/570","coverURL":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":475,"length":226,"bombs":0,"notes":620,"obstacles":10,"njs":10,"njsOffset":0},"expert":{"duration":475,"length":226,"bombs":0,"notes":738,"obstacles":11,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Feel Invincible","songSubName":"Skillet","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":126},"stats":{"downloads":270807,"plays":3479,"downVotes":91,"upVotes":7264,"heat":231.7584786,"rating":0.9541992623197538},"description":"Expert / Hard\r\nhttps://www.youtube.com/watch?v=nq-Qul4XxbE","deletedAt":null,"_id":"5cff620f48229f7d88fc6eba","key":"121f","name":"Skillet - Feel Invincible","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-08-27T16:47:05.000Z","hash":"2e9ab6e1fb8055649e241cade98b018926cc93a8","directDownload":"/cdn/121f/2e9ab6e1fb8055649e241cade98b018926cc93a8.zip","downloadURL":"/api/download/key/121f","coverURL":"/cdn/121f/2e9ab6e1fb8055649e241cade98b018926cc93a8.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":501.19000244140625,"length":227,"bombs":0,"notes":671,"obstacles":16,"njs":10,"njsOffset":0},"expert":{"duration":501.19000244140625,"length":227,"bombs":0,"notes":831,"obstacles":16,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Daddy","songSubName":"PSY","songAuthorName":"Fafurion","levelAuthorName":"fafurion","bpm":132},"stats":{"downloads":256190,"plays":2330,"downVotes":125,"upVotes":8536,"heat":243.903181,"rating":0.9538784951359949},"description":"Insanely fun dance map! Enjoy!\r\nSee the map in action (Expert): https://www.youtube.com/watch?v=cWz6flYGs20\r\n\r\nThank you to my playtesters:\r\nQTPop (https://www.twitch.tv/qtpop)\r\nDuoVR (https://www.twitch.tv/duovr)\r\nSourgurl (https://www.twitch.tv/sourgurl)\r\nRexxxzi (https://www.twitch.tv/rexxxzi)\r\nAshleyriott (https://www.twitch.tv/ashleyriott)\r\n\r\nDiscord: @Fufu#5452","deletedAt":null,"_id":"5cff620f48229f7d88fc6f6c","key":"133b","name":"Daddy - PSY","uploader":{"_id":"5cff0b7398cc5a672c84f945","username":"fafurion"},"uploaded":"2018-09-02T23:43:45.000Z","hash":"dc489921185f92dfecb9cb07b84fc556123bd134","directDownload":"/cdn/133b/dc489921185f92dfecb9cb07b84fc556123bd134.zip","downloadURL":"/api/download/key/133b","coverURL":"/cdn/133b/dc489921185f92dfecb9cb07b84fc556123bd134.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":418,"length":200,"bombs":0,"notes":216,"obstacles":0,"njs":10,"njsOffset":0},"normal":{"duration":418,"length":200,"bombs":0,"notes":388,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":418,"length":200,"bombs":0,"notes":514,"obstacles":10,"njs":11,"njsOffset":0},"expert":{"duration":418,"length":200,"bombs":0,"notes":560,"obstacles":12,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Believer (100k ver.)","songSubName":"Imagine Dragons","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":125},"stats":{"downloads":525285,"plays":9381,"downVotes":225,"upVotes":12195,"heat":379.9100497,"rating":0.9536691507278133},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!","deletedAt":null,"_id":"5cff621148229f7d88fc76ec","key":"1fef","name":"Imagine Dragons - Believer (100k ver.) | 100k Contest","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-11-12T17:53:58.000Z","hash":"9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390","directDownload":"/cdn/1fef/9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390.zip","downloadURL":"/api/download/key/1fef","coverURL":"/cdn/1fef/9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":476.7637634277344,"length":173,"bombs":52,"notes":722,"obstacles":28,"njs":14,"njsOffset":0},"expertPlus":null}}],"songName":"Caramelldansen (Speedcake Remix)","songSubName":"Caramell","songAuthorName":"Dack","levelAuthorName":"Dack","bpm":165},"stats":{"downloads":264023,"plays":0,"downVotes":267,"upVotes":13510,"heat":604.9951864,"rating":0.9533437578099047},"description":"Preview: https://youtu.be/V5p0HOzunY0\n\n\nPatreon: https://www.patreon.com/Dack","deletedAt":null,"_id":"5cff621548229f7d88fc8904","key":"3cf5","name":"Caramelldansen","uploader":{"_id":"5cff0b7598cc5a672c852c6f","username":"dack"},"uploaded":"2019-03-09T22:54:54.000Z","hash":"cf5e32d6b7f30095f7198da5894139c92336cad7","directDownload":"/cdn/3cf5/cf5e32d6b7f30095f7198da5894139c92336cad7.zip","downloadURL":"/api/download/key/3cf5","coverURL":"/cdn/3cf5/cf5e32d6b7f30095f7198da5894139c92336cad7.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":523.625,"length":245,"bombs":0,"notes":633,"obstacles":13,"njs":10,"njsOffset":0},"expert":{"duration":523.5650024414062,"length":245,"bombs":0,"notes":880,"obstacles":19,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Uprising","songSubName":"Muse","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":128},"stats":{"downloads":467954,"plays":11598,"downVotes":168,"upVotes":9389,"heat":84.4283558,"rating":0.9518568128491359},"description":"Expert / Hard\r\nhttps://youtu.be/WpFUt3UNp7c","deletedAt":null,"_id":"5cff620d48229f7d88fc655e","key":"4c6","name":"Muse - Uprising","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-06-11T21:47:41.000Z","hash":"00e5671e594a6fe621c3605fcc5a0e4466ba6478","directDownload":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.zip","downloadURL":"/api/download/key/4c6","coverURL":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":547,"length":234,"njs":12,"njsOffset":0,"bombs":0,"notes":705,"obstacles":10},"expertPlus":{"duration":547,"length":234,"njs":16,"njsOffset":0,"bombs":0,"notes":876,"obstacles":10},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"KikaeAeon","songAuthorName":"League of Legends & Against The Current","songName":"Legends Never Die","songSubName":"","bpm":140},"stats":{"downloads":147256,"plays":0,"downVotes":90,"upVotes":6408,"heat":997.2669264,"rating":0.9515565434224502},"description":"A special request from Prima1URGE","deletedAt":null,"_id":"5d91d6c1871b1a0006f9b3e7","key":"66e6","name":"League of Legends - Legends never die (ft. Against The Current)","uploader":{"_id":"5cff0b7498cc5a672c85109b","username":"kikaeaeon"},"hash":"732bd4072b89d4b3bf0e63db812a7ffc3096e837","uploaded":"2019-09-30T10:19:45.606Z","directDownload":"/cdn/66e6/732bd4072b89d4b3bf0e63db812a7ffc3096e837.zip","downloadURL":"/api/download/key/66e6","coverURL":"/cdn/66e6/732bd4072b89d4b3bf0e63db812a7ffc3096e837.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":578,"length":194,"bombs":8,"notes":519,"obstacles":86,"njs":13,"njsOffset":0},"expert":{"duration":578,"length":194,"bombs":10,"notes":679,"obstacles":86,"njs":16,"njsOffset":0},"expertPlus":null}}],"songName":"Flamingo","songSubName":"Kero Kero Bonito","songAuthorName":"ETAN","levelAuthorName":"ETAN","bpm":178},"stats":{"downloads":146378,"plays":0,"downVotes":98,"upVotes":6547,"heat":744.9918702,"rating":0.9509546000585934},"description":"edited: NJS was a tad slow my b\r\nfuramingo \r\noh oh ooh oh\r\nthis needed to be remapped\r\n \r\nPlease check out Kero Kero Bonito and the rest of their songs\r\nI'll be mappin more of em soon\r\n\r\nBPM 178\r\nFlamingo (Expert)\r\nShrimp (Hard)\r\n\r\nhave fun \r\n\r\ngimme feedback on Discord\r\nETAN#8341","deletedAt":null,"_id":"5cff621748229f7d88fc93fc","key":"4e6f","name":"Kero Kero Bonito - Flamingo","uploader":{"_id":"5cff0b7798cc5a672c855775","username":"etan"},"uploaded":"2019-05-22T00:46:47.000Z","hash":"585ee25e654ebf5db5aa0ec02c3bcecbaccf3e0b","directDownload":"/cdn/4e6f/585ee25e654ebf5db5aa0ec02c3bcecbaccf3e0b.zip","downloadURL":"/api/download/key/4e6f","coverURL":"/cdn/4e6f/585ee25e654ebf5db5aa0ec02c3bcecbaccf3e0b.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":218.6858367919922,"length":170,"bombs":0,"notes":252,"obstacles":6,"njs":10,"njsOffset":0},"normal":{"duration":218.6858367919922,"length":170,"bombs":0,"notes":342,"obstacles":5,"njs":10,"njsOffset":0},"hard":{"duration":218.6858367919922,"length":170,"bombs":0,"notes":472,"obstacles":5,"njs":11,"njsOffset":0},"expert":{"duration":218.6858367919922,"length":170,"bombs":0,"notes":565,"obstacles":5,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"The greatest show reimagined","songSubName":"Panic at the disco","songAuthorName":"Stumi","levelAuthorName":"stumi","bpm":77},"stats":{"downloads":366135,"plays":4092,"downVotes":111,"upVotes":6750,"heat":387.3393249,"rating":0.949952901540827},"description":"Here is a bonus map for the 100K contest! Playing any difficulty will give you 4x the normal chance of winning up to $12000 in prizes!!\r\nBig thank you to the beat saber mapping group for helping me out with a few things, you guys are great!\r\n\r\nTo enter, you need to go to the following website and download the mod installer: https://bsaber.com/100k-contest/\r\n\r\nThe competition is now over, but the maps remain!\r\nVideo Preview: https://www.youtube.com/watch?v=E929e6puEQA","deletedAt":null,"_id":"5cff621148229f7d88fc774e","key":"2087","name":"Panic at the disco - The Greatest Show reimagined | (100K Contest)","uploader":{"_id":"5cff0b7398cc5a672c84f720","username":"stumi"},"uploaded":"2018-11-16T17:57:55.000Z","hash":"afc2a905079d1fcdc9d82fa50e83c842d4d42118","directDownload":"/cdn/2087/afc2a905079d1fcdc9d82fa50e83c842d4d42118.zip","downloadURL":"/api/download/key/2087","coverURL":"/cdn/2087/afc2a905079d1fcdc9d82fa50e83c842d4d42118.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":null,"nextPage":1}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/rating/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":689.8004760742188,"length":242,"bombs":16,"notes":1093,"obstacles":16,"njs":14,"njsOffset":0},"expertPlus":null}}],"songName":"IGNITE","songSubName":"Aoi Eir","songAuthorName":"Joetastic","levelAuthorName":"Joetastic","bpm":171},"stats":{"downloads":271817,"plays":0,"downVotes":150,"upVotes":8091,"heat":451.3689196,"rating":0.9498813296392387},"description":"Trying to get this version ranked! New version with changes according to the ranking criteria: Double directional notes fixed, flow improvements, removed fast dodge walls on bridge section.","deletedAt":null,"_id":"5cff621248229f7d88fc7b21","key":"26f6","name":"IGNITE (Ranked Version) [Sword Art Online Season 2 Opening] - Aoi Eir","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"uploaded":"2018-12-20T01:21:47.000Z","hash":"125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c","directDownload":"/cdn/26f6/125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c.zip","downloadURL":"/api/download/key/26f6","coverURL":"/cdn/26f6/125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":571,"obstacles":78,"njs":10,"njsOffset":0},"normal":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":747,"obstacles":78,"njs":10,"njsOffset":0},"hard":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":788,"obstacles":78,"njs":11,"njsOffset":0},"expert":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":850,"obstacles":78,"njs":12,"njsOffset":0},"expertPlus":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":872,"obstacles":78,"njs":14,"njsOffset":0}}}],"songName":"Without Me - ILLENIUM Remix","songSubName":"Halsey, ILLENIUM","songAuthorName":"StyngMe","levelAuthorName":"styngme","bpm":136},"stats":{"downloads":222041,"plays":23,"downVotes":103,"upVotes":6415,"heat":421.7027003,"rating":0.9497753170153899},"description":"All 5 Difficulties\r\nBPM: 136\r\nNote Jumps Speed from E+to Easy: 14, 12, 11, 10, 10\r\n\r\nLemme know what you think!","deletedAt":null,"_id":"5cff621248229f7d88fc7949","key":"23c3","name":"Without Me (ILLENIUM Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e9cd","username":"styngme"},"uploaded":"2018-12-04T15:46:54.000Z","hash":"a684cee0d634b9bb59eef234665fd28ef27876ac","directDownload":"/cdn/23c3/a684cee0d634b9bb59eef234665fd28ef27876ac.zip","downloadURL":"/api/download/key/23c3","coverURL":"/cdn/23c3/a684cee0d634b9bb59eef234665fd28ef27876ac.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":355.7663269042969,"length":167,"bombs":334,"notes":375,"obstacles":9,"njs":10,"njsOffset":0},"hard":{"duration":355.7450866699219,"length":167,"bombs":306,"notes":480,"obstacles":3,"njs":10,"njsOffset":0},"expert":{"duration":355.7450866699219,"length":167,"bombs":138,"notes":662,"obstacles":3,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Technologic","songSubName":"Daft Punk","songAuthorName":"Awfulnaut","levelAuthorName":"awfulnaut","bpm":127},"stats":{"downloads":316765,"plays":6632,"downVotes":146,"upVotes":7815,"heat":120.5655832,"rating":0.9494190069382702},"description":"Expert / Hard / Normal","deletedAt":null,"_id":"5cff620e48229f7d88fc67a8","key":"747","name":"Technologic - Daft Punk (Update)","uploader":{"_id":"5cff0b7398cc5a672c84edac","username":"awfulnaut"},"uploaded":"2018-06-30T18:30:38.000Z","hash":"831247d7d02e948e5d03622748bb130b5057023d","directDownload":"/cdn/747/831247d7d02e948e5d03622748bb130b5057023d.zip","downloadURL":"/api/download/key/747","coverURL":"/cdn/747/831247d7d02e948e5d03622748bb130b5057023d.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":199,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":558,"length":195,"njs":10,"njsOffset":0,"bombs":0,"notes":271,"obstacles":15},"expert":{"duration":558,"length":195,"njs":17,"njsOffset":0.5,"bombs":44,"notes":594,"obstacles":33},"expertPlus":null,"hard":{"duration":558,"length":195,"njs":13,"njsOffset":1,"bombs":16,"notes":457,"obstacles":25},"normal":null},"name":"Standard"}],"levelAuthorName":"NovaShaft","songAuthorName":"The Weeknd","songName":"Blinding Lights","songSubName":"","bpm":171},"stats":{"downloads":168891,"plays":0,"downVotes":106,"upVotes":6428,"heat":1249.3560434,"rating":0.9494101356195022},"description":"This map was supposed to release last year and have a 90 degree difficulty but it got cut because life ¯\\_(ツ)_/¯ Fantastic song though.\n\nExpert Playthrough : https://youtu.be/GonnTYPmDVY","deletedAt":null,"_id":"5e3eef3f521acf0006790d9e","key":"8553","name":"Blinding Lights - The Weeknd","uploader":{"_id":"5cff0b7398cc5a672c84ee55","username":"novashaft"},"hash":"17d298f2bf78b453cd66474ce268ae9dac9fab46","uploaded":"2020-02-08T17:26:23.499Z","directDownload":"/cdn/8553/17d298f2bf78b453cd66474ce268ae9dac9fab46.zip","downloadURL":"/api/download/key/8553","coverURL":"/cdn/8553/17d298f2bf78b453cd66474ce268ae9dac9fab46.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":308.9583435058594,"length":168,"bombs":0,"notes":512,"obstacles":52,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"I Wanna Be Like You (Sim Gretina Remix)","songSubName":"Jungle Book","songAuthorName":"ConnorJC","levelAuthorName":"ConnorJC","bpm":110},"stats":{"downloads":190120,"plays":1594,"downVotes":82,"upVotes":5496,"heat":210.2683405,"rating":0.9491430971728251},"description":"Who doesn't love The Jungle Book? \r\nPreview: https://youtu.be/DXdo1-FlxSk","deletedAt":null,"_id":"5cff620f48229f7d88fc6d90","key":"1023","name":"I Wanna Be Like You (Sim Gretina Remix)","uploader":{"_id":"5cff0b7398cc5a672c84fb89","username":"connorjc"},"uploaded":"2018-08-16T13:41:07.000Z","hash":"638cf20187a122490b4662555e9072cd8073146b","directDownload":"/cdn/1023/638cf20187a122490b4662555e9072cd8073146b.zip","downloadURL":"/api/download/key/1023","coverURL":"/cdn/1023/638cf20187a122490b4662555e9072cd8073146b.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":387,"obstacles":18,"njs":11,"njsOffset":0},"normal":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":503,"obstacles":6,"njs":12,"njsOffset":0},"hard":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":617,"obstacles":0,"njs":12,"njsOffset":0},"expert":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":1006,"obstacles":0,"njs":14,"njsOffset":0},"expertPlus":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":1207,"obstacles":0,"njs":16,"njsOffset":0}}}],"songName":"Love The Way You Lie (ft. Rihanna)","songSubName":"Eminem, Rihanna","songAuthorName":"StyngMe","levelAuthorName":"styngme","bpm":87},"stats":{"downloads":256571,"plays":0,"downVotes":93,"upVotes":5808,"heat":521.5015051,"rating":0.9487686883502519},"description":"I hope you enjoy!\r\nBPM: 87\r\nDifficulties: ALL\r\nNote Jump Speed for E+ through Easy: 16, 14, 12, 12, 11","deletedAt":null,"_id":"5cff621348229f7d88fc8181","key":"30fd","name":"Love The Way You Lie (ft. Rihanna) - Eminem, Rihanna | Charted by StyngMe","uploader":{"_id":"5cff0b7298cc5a672c84e9cd","username":"styngme"},"uploaded":"2019-01-25T15:48:22.000Z","hash":"8161daf97cab8c95aea0556549b4b8cfb29a13d3","directDownload":"/cdn/30fd/8161daf97cab8c95aea0556549b4b8cfb29a13d3.zip","downloadURL":"/api/download/key/30fd","coverURL":"/cdn/30fd/8161daf97cab8c95aea0556549b4b8cfb29a13d3.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":289,"length":145,"bombs":0,"notes":734,"obstacles":16,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Ievan Polkka","songSubName":"Hatsune Miku","songAuthorName":"Fraktar","levelAuthorName":"Fraktar","bpm":119},"stats":{"downloads":137156,"plays":0,"downVotes":99,"upVotes":5957,"heat":577.2052605,"rating":0.9484997273179496},"description":"","deletedAt":null,"_id":"5cff621448229f7d88fc866f","key":"38ff","name":"Ievan Polkka","uploader":{"_id":"5cff0b7698cc5a672c8551b0","username":"fraktar"},"uploaded":"2019-02-23T15:58:08.000Z","hash":"72bf749f5df632cd103fccd734c9fd5cc7596caa","directDownload":"/cdn/38ff/72bf749f5df632cd103fccd734c9fd5cc7596caa.zip","downloadURL":"/api/download/key/38ff","coverURL":"/cdn/38ff/72bf749f5df632cd103fccd734c9fd5cc7596caa.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":627.203125,"length":250,"bombs":0,"notes":332,"obstacles":12,"njs":9,"njsOffset":0},"normal":{"duration":627.203125,"length":250,"bombs":0,"notes":471,"obstacles":17,"njs":10,"njsOffset":0},"hard":{"duration":627.203125,"length":250,"bombs":0,"notes":679,"obstacles":17,"njs":11,"njsOffset":0},"expert":{"duration":627.203125,"length":250,"bombs":0,"notes":954,"obstacles":17,"njs":12.5,"njsOffset":0},"expertPlus":{"duration":627.203125,"length":250,"bombs":0,"notes":1086,"obstacles":1,"njs":12.5,"njsOffset":0}}}],"songName":"YoiYoi Kokon","songSubName":"REOL","songAuthorName":"Kolezan","levelAuthorName":"Kolezan","bpm":150},"stats":{"downloads":119113,"plays":0,"downVotes":83,"upVotes":5299,"heat":507.5073376,"rating":0.9480848814064379},"description":"Put on your yukata; lets travel back in time and enjoy ourselves! Here's REOL's probably most popular song mapped by me, YoiYoi Kokon (宵々古今)!\r\n\r\nThe audio version of the song is that of the music video (slightly edited by me) as I love the addition of the fireworks in the last part of the song. Together with the crowd in the intro this makes it feel like you're attending a festival.\r\n\r\nExpert+ is basically Expert but with no pauses and with the melody followed instead of the shouts.\r\n\r\nPreview:\r\nhttps://youtu.be/G61m6Au2foM\r\n\r\nBPM: 150\r\nDuration: 4:16\r\nEasy, Normal, Hard, Expert and Expert+ difficulties.\r\n\r\nEasy: 332, 12 obstacles, 0 mines\r\nNormal: 471 notes, 17 obstacles, 0 mines\r\nHard: 679 notes, 17 obstacles, 0 mines\r\nExpert: 954 notes, 17 obstacles, 0 mines\r\nExpert+: 1086 notes, 1 obstacles, 0 mines\r\n\r\nThanks to Redmagi, Aithke, aggrogahu, Don and Emperor of the Internet for playtesting!\r\n\r\nEnjoy!","deletedAt":null,"_id":"5cff621348229f7d88fc8002","key":"2e9e","name":"YoiYoi Kokon - REOL","uploader":{"_id":"5cff0b7398cc5a672c84fcbb","username":"kolezan"},"uploaded":"2019-01-18T09:22:30.000Z","hash":"766a222b102b6522cd5d31e82ec7adc7c5ff011e","directDownload":"/cdn/2e9e/766a222b102b6522cd5d31e82ec7adc7c5ff011e.zip","downloadURL":"/api/download/key/2e9e","coverURL":"/cdn/2e9e/766a222b102b6522cd5d31e82ec7adc7c5ff011e.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":364,"length":174,"bombs":0,"notes":251,"obstacles":0,"njs":10,"njsOffset":0},"normal":{"duration":364,"length":174,"bombs":0,"notes":444,"obstacles":0,"njs":11,"njsOffset":0},"hard":{"duration":364,"length":174,"bombs":0,"notes":581,"obstacles":0,"njs":12,"njsOffset":0},"expert":{"duration":364,"length":174,"bombs":0,"notes":723,"obstacles":0,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Du Hast","songSubName":"Rammstein","songAuthorName":"Level by Kolezan","levelAuthorName":"Kolezan","bpm":125},"stats":{"downloads":258691,"plays":4760,"downVotes":151,"upVotes":7442,"heat":305.805676,"rating":0.9475141587030342},"description":"Many has been asking, many have been waiting patiently, many have been confused as to why this hasn't been done before, but now it's here and now you can have it too; here's Du Hast by Rammstein now in Beat Saber!\r\n\r\nSong has been edited (shortened) to be tighter and not too repetitive.\r\n\r\nPreview:\r\nhttps://youtu.be/3i1mf2EGSjc\r\n\r\nBPM: 125.09\r\nDuration: 2:57\r\nEasy, Normal, Hard and Expert difficulties.\r\n\r\nEasy: 251, 0 obstacles, 0 mines\r\nNormal: 444 notes, 0 obstacles, 0 mines\r\nHard: 581 notes, 0 obstacles, 0 mines\r\nExpert: 437 notes, 0 obstacles, 0 mines\r\n\r\nThanks to VenclaireVR, Redmagi, Horseman, Saut and elliotttate for playtesting!\r\n\r\nDownload the One Saber version of this map here:\r\nhttps://beatsaver.com/browse/detail/6784-6436\r\n\r\nEnjoy!","deletedAt":null,"_id":"5cff621048229f7d88fc72cb","key":"18cc","name":"Du Hast by Rammstein (Kole Edit)","uploader":{"_id":"5cff0b7398cc5a672c84fcbb","username":"kolezan"},"uploaded":"2018-10-05T06:17:10.000Z","hash":"6df961680ead49e2d92de1a95b789e0ce4a026db","directDownload":"/cdn/18cc/6df961680ead49e2d92de1a95b789e0ce4a026db.zip","downloadURL":"/api/download/key/18cc","coverURL":"/cdn/18cc/6df961680ead49e2d92de1a95b789e0ce4a026db.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":740,"length":258,"njs":20,"njsOffset":0,"bombs":0,"notes":606,"obstacles":9589},"expertPlus":null,"hard":{"duration":740,"length":258,"njs":20,"njsOffset":0,"bombs":0,"notes":449,"obstacles":9581},"normal":null},"name":"Standard"}],"levelAuthorName":"Rustic","songAuthorName":"ABIS & Signal & Tasha Baxter","songName":"The Wall","songSubName":"(Buunshin Remix)","bpm":172},"stats":{"downloads":85004,"plays":0,"downVotes":49,"upVotes":3792,"heat":1037.6142733,"rating":0.9466279260659083},"description":"https://youtu.be/xEowRTlHvuI","deletedAt":null,"_id":"5dadb307ac36cc00063e2242","key":"6b55","name":"ABIS & Signal & Tasha Baxter - The Wall (Buunshin Remix)","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"hash":"e855d1a7b17904322aa3ebaf3bb002421bce568a","uploaded":"2019-10-21T13:30:47.405Z","directDownload":"/cdn/6b55/e855d1a7b17904322aa3ebaf3bb002421bce568a.zip","downloadURL":"/api/download/key/6b55","coverURL":"/cdn/6b55/e855d1a7b17904322aa3ebaf3bb002421bce568a.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/rating/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":423,"obstacles":7,"njs":10,"njsOffset":0},"hard":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":507,"obstacles":32,"njs":10,"njsOffset":0},"expert":{"duration":401.9254150390625,"length":207,"bombs":0,"notes":771,"obstacles":32,"njs":10,"njsOffset":0},"expertPlus":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":873,"obstacles":32,"njs":10,"njsOffset":0}}}],"songName":"New Rules","songSubName":"Dua Lipa","songAuthorName":"BennyDaBeast (Lighting: Heisenberg)","levelAuthorName":"bennydabeast","bpm":116},"stats":{"downloads":302337,"plays":7319,"downVotes":115,"upVotes":6052,"heat":168.6453893,"rating":0.9465574296574264},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/Ohq6cA_1NAE\r\nLighting by Heisenberg: https://beatsaver.com/index.php/browse/byuser/1089","deletedAt":null,"_id":"5cff620e48229f7d88fc6afc","key":"bd3","name":"New Rules - Dua Lipa","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-25T20:53:52.000Z","hash":"674b1b540ac5851c92c8cde7dd456698f5a733c4","directDownload":"/cdn/bd3/674b1b540ac5851c92c8cde7dd456698f5a733c4.zip","downloadURL":"/api/download/key/bd3","coverURL":"/cdn/bd3/674b1b540ac5851c92c8cde7dd456698f5a733c4.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":569.0850219726562,"length":200,"njs":14,"njsOffset":0,"bombs":0,"notes":651,"obstacles":42},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Joetastic","songAuthorName":"Twenty One Pilots","songName":"Stressed Out","songSubName":"","bpm":170},"stats":{"downloads":133914,"plays":0,"downVotes":61,"upVotes":4049,"heat":922.5082825,"rating":0.945532469866561},"description":"\"Stressed Out\" is a song by American musical duo Twenty One Pilots, for their fourth studio album, Blurryface. Written by Tyler Joseph and produced by Mike Elizondo, the lyrics discuss the harsh end of adolescence and the transition to adulthood.","deletedAt":null,"_id":"5d5ea4b62c316f000688d7b7","key":"5e81","name":"Stressed Out - Twenty One Pilots","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"hash":"068a80d89857a6a2221f54e8a5d374584b2db77e","uploaded":"2019-08-22T14:20:38.731Z","directDownload":"/cdn/5e81/068a80d89857a6a2221f54e8a5d374584b2db77e.zip","downloadURL":"/api/download/key/5e81","coverURL":"/cdn/5e81/068a80d89857a6a2221f54e8a5d374584b2db77e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":454,"length":252,"bombs":0,"notes":321,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":454,"length":252,"bombs":0,"notes":432,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":454,"length":252,"bombs":0,"notes":680,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":{"duration":454,"length":252,"bombs":0,"notes":810,"obstacles":14,"njs":10,"njsOffset":0}}}],"songName":"Some Nights","songSubName":"Fun.","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":108},"stats":{"downloads":296647,"plays":6779,"downVotes":134,"upVotes":6290,"heat":199.3548319,"rating":0.944928676014249},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/X4R7VIZPffk","deletedAt":null,"_id":"5cff620f48229f7d88fc6cee","key":"efe","name":"Some Nights - Fun.","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-10T20:34:09.000Z","hash":"e64d6b7c5527d36005ae1801268798dd21ccf605","directDownload":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.zip","downloadURL":"/api/download/key/efe","coverURL":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":389.8768310546875,"length":182,"bombs":0,"notes":721,"obstacles":8,"njs":14,"njsOffset":0},"expertPlus":null}}],"levelAuthorName":"Joetastic","songAuthorName":"Run DMC","songName":"It's Tricky","songSubName":"","bpm":128},"stats":{"downloads":106060,"plays":0,"downVotes":36,"upVotes":2995,"heat":824.7020516,"rating":0.944428499995616},"description":"\"It's Tricky\" is the fourth and final single released from Run-DMC's third album, Raising Hell which was released early in 1987. \n\nThis is one of favorite workout songs, can't believe I haven't mapped it yet :P","deletedAt":null,"_id":"5d1b92fe1aea5f0006478bd9","key":"5552","name":"It's Tricky - Run DMC","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"hash":"9027d4274febf8722078a5f70b51da58cf433322","uploaded":"2019-07-02T17:23:10.798Z","directDownload":"/cdn/5552/9027d4274febf8722078a5f70b51da58cf433322.zip","downloadURL":"/api/download/key/5552","coverURL":"/cdn/5552/9027d4274febf8722078a5f70b51da58cf433322.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":429.5,"length":201,"bombs":0,"notes":297,"obstacles":88,"njs":10,"njsOffset":0},"normal":{"duration":429.54266357421875,"length":201,"bombs":0,"notes":343,"obstacles":88,"njs":10,"njsOffset":0},"hard":{"duration":429.54266357421875,"length":201,"bombs":0,"notes":524,"obstacles":125,"njs":12,"njsOffset":0},"expert":{"duration":429.5,"length":201,"bombs":0,"notes":695,"obstacles":100,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Moves Like Jagger","songSubName":"Maroon 5 ft. Christina Aguilera","songAuthorName":"Fafurion","levelAuthorName":"fafurion","bpm":128},"stats":{"downloads":204299,"plays":0,"downVotes":94,"upVotes":4925,"heat":516.3258593,"rating":0.9442570841924475},"description":"See it in Action: https://www.youtube.com/watch?v=J4_8scnZPjk\r\n\r\nThank you to my playtesters:\r\n\r\nQTpop (https://www.twitch.tv/qtpop)\r\n\r\nI stream playing and making maps for beat saber: https://www.twitch.tv/fafurion","deletedAt":null,"_id":"5cff621348229f7d88fc8108","key":"3038","name":"Moves Like Jagger - Maroon 5 ft. Christina Aguilera","uploader":{"_id":"5cff0b7398cc5a672c84f945","username":"fafurion"},"uploaded":"2019-01-23T00:01:22.000Z","hash":"6f5e229b8ec73c9e448c00a680548b0ce25918ee","directDownload":"/cdn/3038/6f5e229b8ec73c9e448c00a680548b0ce25918ee.zip","downloadURL":"/api/download/key/3038","coverURL":"/cdn/3038/6f5e229b8ec73c9e448c00a680548b0ce25918ee.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":127,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":264,"length":123,"njs":18,"njsOffset":0,"bombs":0,"notes":748,"obstacles":4878},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Reaxt","songAuthorName":"Dirty Androids","songName":"Midnight Lady","songSubName":"","bpm":128},"stats":{"downloads":52917,"plays":0,"downVotes":41,"upVotes":3128,"heat":1621.474019,"rating":0.944043142002959},"description":"Enjoy the ride.\n\nMassive thanks to Nyri0 and Checkthepan for helping with scripts and some math!!\n\n\nThis map was released as a part of the \"Extra Sensory\" event.\n\nVideo showcase thingy: https://youtu.be/pE_s9bvntA0 (watch tougeVR's instead tho)","deletedAt":null,"_id":"5f3ea9559824150006b6ebce","key":"da60","name":"[Extra Sensory] Dirty Androids - Midnight Lady","uploader":{"_id":"5cff0b7298cc5a672c84e8ab","username":"reaxt"},"hash":"ee501d7348223470c16ca88eac4a946e41cd11a9","uploaded":"2020-08-20T16:48:21.705Z","directDownload":"/cdn/da60/ee501d7348223470c16ca88eac4a946e41cd11a9.zip","downloadURL":"/api/download/key/da60","coverURL":"/cdn/da60/ee501d7348223470c16ca88eac4a946e41cd11a9.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":514,"length":268,"bombs":0,"notes":639,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":514,"length":268,"bombs":0,"notes":776,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Uptown Funk","songSubName":"Mark Ronson","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":115},"stats":{"downloads":257348,"plays":0,"downVotes":164,"upVotes":6894,"heat":763.6662151,"rating":0.94367246970076},"description":"5/31: This version now works for the Oculus Quest.\r\n\r\nDifficulties: Expert and Hard\r\nYouTube Link: https://youtu.be/6TYji_Klr9I","deletedAt":null,"_id":"5cff621748229f7d88fc9549","key":"5038","name":"Uptown Funk - Mark Ronson","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-05-31T17:58:39.000Z","hash":"d110e413fb7fb462b692f1f17b835cf8b7280884","directDownload":"/cdn/5038/d110e413fb7fb462b692f1f17b835cf8b7280884.zip","downloadURL":"/api/download/key/5038","coverURL":"/cdn/5038/d110e413fb7fb462b692f1f17b835cf8b7280884.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":707.3033447265625,"length":326,"njs":16,"njsOffset":0,"bombs":0,"notes":1218,"obstacles":6631},"expertPlus":null,"hard":{"duration":707.3033447265625,"length":326,"njs":16,"njsOffset":0,"bombs":0,"notes":1218,"obstacles":43},"normal":null},"name":"Standard"}],"levelAuthorName":"ejiejidayo","songAuthorName":".","songName":"Star Sky Two Steps From Hell","songSubName":"","bpm":130},"stats":{"downloads":97307,"plays":0,"downVotes":29,"upVotes":2642,"heat":966.9368189,"rating":0.9436590294134172},"description":"68\nNormal wall --- 43 walls\nHyper wall --- 6631 walls\n\ndoodle :)","deletedAt":null,"_id":"5d7d46b1fffdb800068e0b37","key":"6366","name":"Star Sky - Two Steps From Hell","uploader":{"_id":"5cff0b7398cc5a672c84fc58","username":"ejiejidayo"},"hash":"323975ef9eaec337c8208dc864e766289e3d5ef1","uploaded":"2019-09-14T19:59:45.575Z","directDownload":"/cdn/6366/323975ef9eaec337c8208dc864e766289e3d5ef1.zip","downloadURL":"/api/download/key/6366","coverURL":"/cdn/6366/323975ef9eaec337c8208dc864e766289e3d5ef1.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":180,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":307.98065185546875,"length":175,"njs":10,"njsOffset":0,"bombs":0,"notes":175,"obstacles":1},"expert":{"duration":307.98065185546875,"length":175,"njs":14,"njsOffset":0,"bombs":0,"notes":578,"obstacles":15},"expertPlus":{"duration":307.98065185546875,"length":175,"njs":14,"njsOffset":0,"bombs":0,"notes":580,"obstacles":14},"hard":{"duration":307.98065185546875,"length":175,"njs":12,"njsOffset":0,"bombs":0,"notes":459,"obstacles":10},"normal":{"duration":307.98065185546875,"length":175,"njs":10,"njsOffset":0,"bombs":0,"notes":322,"obstacles":2}},"name":"Standard"}],"levelAuthorName":"Holair","songAuthorName":"Joey Batey","songName":"Toss A Coin To Your Witcher","songSubName":"Netflix's The Witcher","bpm":105},"stats":{"downloads":201348,"plays":0,"downVotes":189,"upVotes":7568,"heat":1169.003459,"rating":0.9435469313661412},"description":"Expert+ Preview https://www.youtube.com/watch?v=AjfABhvswrI\n\nToss a Coin to Beat Saber!\n\nNetflix's The Witcher OST.\nOriginal Music by Sonya Belousova & Giona Ostinelli.\nLyrics by Jenny Klein, Sonya Belousova & Giona Ostinelli.\nPerformed by Joey Batey.\n\nThanks Sorukun for playtesting.","deletedAt":null,"_id":"5e07b6f7932ef2000772d492","key":"7a64","name":"Joey Batey - Toss A Coin To Your Witcher","uploader":{"_id":"5cff0b7698cc5a672c853af4","username":"holair"},"hash":"19a733cf9202ef25725966d78f465bf87b26254d","uploaded":"2019-12-28T20:11:35.767Z","directDownload":"/cdn/7a64/19a733cf9202ef25725966d78f465bf87b26254d.zip","downloadURL":"/api/download/key/7a64","coverURL":"/cdn/7a64/19a733cf9202ef25725966d78f465bf87b26254d.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":353,"obstacles":78,"njs":13,"njsOffset":0},"normal":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":589,"obstacles":18,"njs":13,"njsOffset":0},"hard":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":893,"obstacles":13,"njs":13,"njsOffset":0},"expert":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":1167,"obstacles":25,"njs":13,"njsOffset":0},"expertPlus":null}}],"songName":"New Divide","songSubName":"Linkin Park","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":118},"stats":{"downloads":259017,"plays":5363,"downVotes":65,"upVotes":3789,"heat":379.9690541,"rating":0.9429028282900022},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nhttps://www.youtube.com/watch?v=deWMNAw03QI&t=28s","deletedAt":null,"_id":"5cff621148229f7d88fc7700","key":"2005","name":"New Divide - Linkin Park - 100k Contest","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-11-13T00:58:32.000Z","hash":"be4a33ad1b1caef9f1f42b680ca1a9087db19ffe","directDownload":"/cdn/2005/be4a33ad1b1caef9f1f42b680ca1a9087db19ffe.zip","downloadURL":"/api/download/key/2005","coverURL":"/cdn/2005/be4a33ad1b1caef9f1f42b680ca1a9087db19ffe.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
+        pages.insert(
+            BEATSAVER_URL.join("playlists/latest?sort=CREATED").unwrap(),
+            playlist_page_json(&[3, 2]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("playlists/latest?before=2021-08-01T12%3A00%3A00Z&sort=CREATED")
+                .unwrap(),
+            playlist_page_json(&[]),
+        );
         let client = FakeClientPaged::new(pages);
-        assert_eq!(
-            client
-                .maps_rating()
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "2144".to_string(),
-                "570".to_string(),
-                "121f".to_string(),
-                "133b".to_string(),
-                "1fef".to_string(),
-                "3cf5".to_string(),
-                "4c6".to_string(),
-                "66e6".to_string(),
-                "4e6f".to_string(),
-                "2087".to_string(),
-                "26f6".to_string(),
-                "23c3".to_string(),
-                "747".to_string(),
-                "8553".to_string(),
-                "1023".to_string(),
-                "30fd".to_string(),
-                "38ff".to_string(),
-                "2e9e".to_string(),
-                "18cc".to_string(),
-                "6b55".to_string(),
-                "bd3".to_string(),
-                "5e81".to_string(),
-                "efe".to_string(),
-                "5552".to_string(),
-                "3038".to_string(),
-                "da60".to_string(),
-                "5038".to_string(),
-                "6366".to_string(),
-                "7a64".to_string(),
-                "2005".to_string()
-            ]
+        let ids: Vec<usize> = client
+            .playlists_latest()
+            .map(|p| p.unwrap().id)
+            .collect::<Vec<usize>>();
+        assert_eq!(ids, vec![3, 2]);
+    }
+    #[test]
+    fn test_playlists_search_page() {
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join("playlists/search/1?q=tech%20maps")
+                .unwrap(),
+            playlist_page_json(&[1234]),
         );
+        let page = client.playlists_search_page("tech maps", 1).unwrap();
+        assert_eq!(page.docs[0].name, "Playlist 1234");
+        assert_eq!(page.prev_page, Some(0));
+        assert_eq!(page.next_page, Some(2));
     }
     #[test]
-    fn test_maps_rating_page() {
-        let client = FakeClient::new(BEATSAVER_URL.join("api/maps/rating/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":423,"obstacles":7,"njs":10,"njsOffset":0},"hard":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":507,"obstacles":32,"njs":10,"njsOffset":0},"expert":{"duration":401.9254150390625,"length":207,"bombs":0,"notes":771,"obstacles":32,"njs":10,"njsOffset":0},"expertPlus":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":873,"obstacles":32,"njs":10,"njsOffset":0}}}],"songName":"New Rules","songSubName":"Dua Lipa","songAuthorName":"BennyDaBeast (Lighting: Heisenberg)","levelAuthorName":"bennydabeast","bpm":116},"stats":{"downloads":300360,"plays":7319,"downVotes":115,"upVotes":6006,"heat":168.6420112,"rating":0.9463489464557748},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/Ohq6cA_1NAE\r\nLighting by Heisenberg: https://beatsaver.com/index.php/browse/byuser/1089","deletedAt":null,"_id":"5cff620e48229f7d88fc6afc","key":"bd3","name":"New Rules - Dua Lipa","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-25T20:53:52.000Z","hash":"674b1b540ac5851c92c8cde7dd456698f5a733c4","directDownload":"/cdn/bd3/674b1b540ac5851c92c8cde7dd456698f5a733c4.zip","downloadURL":"/api/download/key/bd3","coverURL":"/cdn/bd3/674b1b540ac5851c92c8cde7dd456698f5a733c4.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":569.0850219726562,"length":200,"njs":14,"njsOffset":0,"bombs":0,"notes":651,"obstacles":42},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Joetastic","songAuthorName":"Twenty One Pilots","songName":"Stressed Out","songSubName":"","bpm":170},"stats":{"downloads":132241,"plays":0,"downVotes":61,"upVotes":4008,"heat":922.5037945,"rating":0.9452754283183059},"description":"\"Stressed Out\" is a song by American musical duo Twenty One Pilots, for their fourth studio album, Blurryface. Written by Tyler Joseph and produced by Mike Elizondo, the lyrics discuss the harsh end of adolescence and the transition to adulthood.","deletedAt":null,"_id":"5d5ea4b62c316f000688d7b7","key":"5e81","name":"Stressed Out - Twenty One Pilots","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"hash":"068a80d89857a6a2221f54e8a5d374584b2db77e","uploaded":"2019-08-22T14:20:38.731Z","directDownload":"/cdn/5e81/068a80d89857a6a2221f54e8a5d374584b2db77e.zip","downloadURL":"/api/download/key/5e81","coverURL":"/cdn/5e81/068a80d89857a6a2221f54e8a5d374584b2db77e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":454,"length":252,"bombs":0,"notes":321,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":454,"length":252,"bombs":0,"notes":432,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":454,"length":252,"bombs":0,"notes":680,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":{"duration":454,"length":252,"bombs":0,"notes":810,"obstacles":14,"njs":10,"njsOffset":0}}}],"songName":"Some Nights","songSubName":"Fun.","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":108},"stats":{"downloads":294845,"plays":6779,"downVotes":133,"upVotes":6250,"heat":199.3520718,"rating":0.9448837394753198},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/X4R7VIZPffk","deletedAt":null,"_id":"5cff620f48229f7d88fc6cee","key":"efe","name":"Some Nights - Fun.","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-10T20:34:09.000Z","hash":"e64d6b7c5527d36005ae1801268798dd21ccf605","directDownload":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.zip","downloadURL":"/api/download/key/efe","coverURL":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":389.8768310546875,"length":182,"bombs":0,"notes":721,"obstacles":8,"njs":14,"njsOffset":0},"expertPlus":null}}],"levelAuthorName":"Joetastic","songAuthorName":"Run DMC","songName":"It's Tricky","songSubName":"","bpm":128},"stats":{"downloads":104893,"plays":0,"downVotes":34,"upVotes":2955,"heat":824.6964382,"rating":0.9447017186713489},"description":"\"It's Tricky\" is the fourth and final single released from Run-DMC's third album, Raising Hell which was released early in 1987. \n\nThis is one of favorite workout songs, can't believe I haven't mapped it yet :P","deletedAt":null,"_id":"5d1b92fe1aea5f0006478bd9","key":"5552","name":"It's Tricky - Run DMC","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"hash":"9027d4274febf8722078a5f70b51da58cf433322","uploaded":"2019-07-02T17:23:10.798Z","directDownload":"/cdn/5552/9027d4274febf8722078a5f70b51da58cf433322.zip","downloadURL":"/api/download/key/5552","coverURL":"/cdn/5552/9027d4274febf8722078a5f70b51da58cf433322.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":429.5,"length":201,"bombs":0,"notes":297,"obstacles":88,"njs":10,"njsOffset":0},"normal":{"duration":429.54266357421875,"length":201,"bombs":0,"notes":343,"obstacles":88,"njs":10,"njsOffset":0},"hard":{"duration":429.54266357421875,"length":201,"bombs":0,"notes":524,"obstacles":125,"njs":12,"njsOffset":0},"expert":{"duration":429.5,"length":201,"bombs":0,"notes":695,"obstacles":100,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Moves Like Jagger","songSubName":"Maroon 5 ft. Christina Aguilera","songAuthorName":"Fafurion","levelAuthorName":"fafurion","bpm":128},"stats":{"downloads":202165,"plays":0,"downVotes":93,"upVotes":4859,"heat":516.3199763,"rating":0.9440595804974747},"description":"See it in Action: https://www.youtube.com/watch?v=J4_8scnZPjk\r\n\r\nThank you to my playtesters:\r\n\r\nQTpop (https://www.twitch.tv/qtpop)\r\n\r\nI stream playing and making maps for beat saber: https://www.twitch.tv/fafurion","deletedAt":null,"_id":"5cff621348229f7d88fc8108","key":"3038","name":"Moves Like Jagger - Maroon 5 ft. Christina Aguilera","uploader":{"_id":"5cff0b7398cc5a672c84f945","username":"fafurion"},"uploaded":"2019-01-23T00:01:22.000Z","hash":"6f5e229b8ec73c9e448c00a680548b0ce25918ee","directDownload":"/cdn/3038/6f5e229b8ec73c9e448c00a680548b0ce25918ee.zip","downloadURL":"/api/download/key/3038","coverURL":"/cdn/3038/6f5e229b8ec73c9e448c00a680548b0ce25918ee.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":127,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":264,"length":123,"njs":18,"njsOffset":0,"bombs":0,"notes":748,"obstacles":4878},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Reaxt","songAuthorName":"Dirty Androids","songName":"Midnight Lady","songSubName":"","bpm":128},"stats":{"downloads":51835,"plays":0,"downVotes":39,"upVotes":3047,"heat":1621.4627602,"rating":0.9439715751108106},"description":"Enjoy the ride.\n\nMassive thanks to Nyri0 and Checkthepan for helping with scripts and some math!!\n\n\nThis map was released as a part of the \"Extra Sensory\" event.\n\nVideo showcase thingy: https://youtu.be/pE_s9bvntA0 (watch tougeVR's instead tho)","deletedAt":null,"_id":"5f3ea9559824150006b6ebce","key":"da60","name":"[Extra Sensory] Dirty Androids - Midnight Lady","uploader":{"_id":"5cff0b7298cc5a672c84e8ab","username":"reaxt"},"hash":"ee501d7348223470c16ca88eac4a946e41cd11a9","uploaded":"2020-08-20T16:48:21.705Z","directDownload":"/cdn/da60/ee501d7348223470c16ca88eac4a946e41cd11a9.zip","downloadURL":"/api/download/key/da60","coverURL":"/cdn/da60/ee501d7348223470c16ca88eac4a946e41cd11a9.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":514,"length":268,"bombs":0,"notes":639,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":514,"length":268,"bombs":0,"notes":776,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Uptown Funk","songSubName":"Mark Ronson","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":115},"stats":{"downloads":254754,"plays":0,"downVotes":162,"upVotes":6815,"heat":763.6612175,"rating":0.9435730219852141},"description":"5/31: This version now works for the Oculus Quest.\r\n\r\nDifficulties: Expert and Hard\r\nYouTube Link: https://youtu.be/6TYji_Klr9I","deletedAt":null,"_id":"5cff621748229f7d88fc9549","key":"5038","name":"Uptown Funk - Mark Ronson","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-05-31T17:58:39.000Z","hash":"d110e413fb7fb462b692f1f17b835cf8b7280884","directDownload":"/cdn/5038/d110e413fb7fb462b692f1f17b835cf8b7280884.zip","downloadURL":"/api/download/key/5038","coverURL":"/cdn/5038/d110e413fb7fb462b692f1f17b835cf8b7280884.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":707.3033447265625,"length":326,"njs":16,"njsOffset":0,"bombs":0,"notes":1218,"obstacles":6631},"expertPlus":null,"hard":{"duration":707.3033447265625,"length":326,"njs":16,"njsOffset":0,"bombs":0,"notes":1218,"obstacles":43},"normal":null},"name":"Standard"}],"levelAuthorName":"ejiejidayo","songAuthorName":".","songName":"Star Sky Two Steps From Hell","songSubName":"","bpm":130},"stats":{"downloads":96518,"plays":0,"downVotes":29,"upVotes":2632,"heat":966.9351536,"rating":0.9435706585457941},"description":"68\nNormal wall --- 43 walls\nHyper wall --- 6631 walls\n\ndoodle :)","deletedAt":null,"_id":"5d7d46b1fffdb800068e0b37","key":"6366","name":"Star Sky - Two Steps From Hell","uploader":{"_id":"5cff0b7398cc5a672c84fc58","username":"ejiejidayo"},"hash":"323975ef9eaec337c8208dc864e766289e3d5ef1","uploaded":"2019-09-14T19:59:45.575Z","directDownload":"/cdn/6366/323975ef9eaec337c8208dc864e766289e3d5ef1.zip","downloadURL":"/api/download/key/6366","coverURL":"/cdn/6366/323975ef9eaec337c8208dc864e766289e3d5ef1.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":180,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":307.98065185546875,"length":175,"njs":10,"njsOffset":0,"bombs":0,"notes":175,"obstacles":1},"expert":{"duration":307.98065185546875,"length":175,"njs":14,"njsOffset":0,"bombs":0,"notes":578,"obstacles":15},"expertPlus":{"duration":307.98065185546875,"length":175,"njs":14,"njsOffset":0,"bombs":0,"notes":580,"obstacles":14},"hard":{"duration":307.98065185546875,"length":175,"njs":12,"njsOffset":0,"bombs":0,"notes":459,"obstacles":10},"normal":{"duration":307.98065185546875,"length":175,"njs":10,"njsOffset":0,"bombs":0,"notes":322,"obstacles":2}},"name":"Standard"}],"levelAuthorName":"Holair","songAuthorName":"Joey Batey","songName":"Toss A Coin To Your Witcher","songSubName":"Netflix's The Witcher","bpm":105},"stats":{"downloads":199534,"plays":0,"downVotes":188,"upVotes":7500,"heat":1168.9994977,"rating":0.9433779085510436},"description":"Expert+ Preview https://www.youtube.com/watch?v=AjfABhvswrI\n\nToss a Coin to Beat Saber!\n\nNetflix's The Witcher OST.\nOriginal Music by Sonya Belousova & Giona Ostinelli.\nLyrics by Jenny Klein, Sonya Belousova & Giona Ostinelli.\nPerformed by Joey Batey.\n\nThanks Sorukun for playtesting.","deletedAt":null,"_id":"5e07b6f7932ef2000772d492","key":"7a64","name":"Joey Batey - Toss A Coin To Your Witcher","uploader":{"_id":"5cff0b7698cc5a672c853af4","username":"holair"},"hash":"19a733cf9202ef25725966d78f465bf87b26254d","uploaded":"2019-12-28T20:11:35.767Z","directDownload":"/cdn/7a64/19a733cf9202ef25725966d78f465bf87b26254d.zip","downloadURL":"/api/download/key/7a64","coverURL":"/cdn/7a64/19a733cf9202ef25725966d78f465bf87b26254d.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":353,"obstacles":78,"njs":13,"njsOffset":0},"normal":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":589,"obstacles":18,"njs":13,"njsOffset":0},"hard":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":893,"obstacles":13,"njs":13,"njsOffset":0},"expert":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":1167,"obstacles":25,"njs":13,"njsOffset":0},"expertPlus":null}}],"songName":"New Divide","songSubName":"Linkin Park","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":118},"stats":{"downloads":257520,"plays":5363,"downVotes":65,"upVotes":3770,"heat":379.9668327,"rating":0.9427663556455308},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nhttps://www.youtube.com/watch?v=deWMNAw03QI&t=28s","deletedAt":null,"_id":"5cff621148229f7d88fc7700","key":"2005","name":"New Divide - Linkin Park - 100k Contest","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-11-13T00:58:32.000Z","hash":"be4a33ad1b1caef9f1f42b680ca1a9087db19ffe","directDownload":"/cdn/2005/be4a33ad1b1caef9f1f42b680ca1a9087db19ffe.zip","downloadURL":"/api/download/key/2005","coverURL":"/cdn/2005/be4a33ad1b1caef9f1f42b680ca1a9087db19ffe.jpg"}],"totalDocs":35794,"lastPage":3579,"prevPage":1,"nextPage":3}"#.into());
-        let _: Page<Map> = client.maps_rating_page(2).unwrap();
+    fn test_playlist() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("playlists/id/1234/0").unwrap(),
+            playlist_maps_json(1234, &["2144"]),
+        );
+        let playlist = client.playlist(1234).unwrap();
+        assert_eq!(playlist.name, "Playlist 1234");
     }
     #[test]
-    fn test_maps_rating_page_iter() {
+    fn test_playlist_maps() {
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/rating/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":689.8004760742188,"length":242,"bombs":16,"notes":1093,"obstacles":16,"njs":14,"njsOffset":0},"expertPlus":null}}],"songName":"IGNITE","songSubName":"Aoi Eir","songAuthorName":"Joetastic","levelAuthorName":"Joetastic","bpm":171},"stats":{"downloads":271817,"plays":0,"downVotes":150,"upVotes":8091,"heat":451.3689196,"rating":0.9498813296392387},"description":"Trying to get this version ranked! New version with changes according to the ranking criteria: Double directional notes fixed, flow improvements, removed fast dodge walls on bridge section.","deletedAt":null,"_id":"5cff621248229f7d88fc7b21","key":"26f6","name":"IGNITE (Ranked Version) [Sword Art Online Season 2 Opening] - Aoi Eir","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"uploaded":"2018-12-20T01:21:47.000Z","hash":"125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c","directDownload":"/cdn/26f6/125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c.zip","downloadURL":"/api/download/key/26f6","coverURL":"/cdn/26f6/125b07ebcc06fe9667e83fc2d6b9ae5ecbc72e8c.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":571,"obstacles":78,"njs":10,"njsOffset":0},"normal":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":747,"obstacles":78,"njs":10,"njsOffset":0},"hard":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":788,"obstacles":78,"njs":11,"njsOffset":0},"expert":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":850,"obstacles":78,"njs":12,"njsOffset":0},"expertPlus":{"duration":521.4559936523438,"length":230,"bombs":0,"notes":872,"obstacles":78,"njs":14,"njsOffset":0}}}],"songName":"Without Me - ILLENIUM Remix","songSubName":"Halsey, ILLENIUM","songAuthorName":"StyngMe","levelAuthorName":"styngme","bpm":136},"stats":{"downloads":222041,"plays":23,"downVotes":103,"upVotes":6415,"heat":421.7027003,"rating":0.9497753170153899},"description":"All 5 Difficulties\r\nBPM: 136\r\nNote Jumps Speed from E+to Easy: 14, 12, 11, 10, 10\r\n\r\nLemme know what you think!","deletedAt":null,"_id":"5cff621248229f7d88fc7949","key":"23c3","name":"Without Me (ILLENIUM Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e9cd","username":"styngme"},"uploaded":"2018-12-04T15:46:54.000Z","hash":"a684cee0d634b9bb59eef234665fd28ef27876ac","directDownload":"/cdn/23c3/a684cee0d634b9bb59eef234665fd28ef27876ac.zip","downloadURL":"/api/download/key/23c3","coverURL":"/cdn/23c3/a684cee0d634b9bb59eef234665fd28ef27876ac.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":355.7663269042969,"length":167,"bombs":334,"notes":375,"obstacles":9,"njs":10,"njsOffset":0},"hard":{"duration":355.7450866699219,"length":167,"bombs":306,"notes":480,"obstacles":3,"njs":10,"njsOffset":0},"expert":{"duration":355.7450866699219,"length":167,"bombs":138,"notes":662,"obstacles":3,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Technologic","songSubName":"Daft Punk","songAuthorName":"Awfulnaut","levelAuthorName":"awfulnaut","bpm":127},"stats":{"downloads":316765,"plays":6632,"downVotes":146,"upVotes":7815,"heat":120.5655832,"rating":0.9494190069382702},"description":"Expert / Hard / Normal","deletedAt":null,"_id":"5cff620e48229f7d88fc67a8","key":"747","name":"Technologic - Daft Punk (Update)","uploader":{"_id":"5cff0b7398cc5a672c84edac","username":"awfulnaut"},"uploaded":"2018-06-30T18:30:38.000Z","hash":"831247d7d02e948e5d03622748bb130b5057023d","directDownload":"/cdn/747/831247d7d02e948e5d03622748bb130b5057023d.zip","downloadURL":"/api/download/key/747","coverURL":"/cdn/747/831247d7d02e948e5d03622748bb130b5057023d.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":199,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":558,"length":195,"njs":10,"njsOffset":0,"bombs":0,"notes":271,"obstacles":15},"expert":{"duration":558,"length":195,"njs":17,"njsOffset":0.5,"bombs":44,"notes":594,"obstacles":33},"expertPlus":null,"hard":{"duration":558,"length":195,"njs":13,"njsOffset":1,"bombs":16,"notes":457,"obstacles":25},"normal":null},"name":"Standard"}],"levelAuthorName":"NovaShaft","songAuthorName":"The Weeknd","songName":"Blinding Lights","songSubName":"","bpm":171},"stats":{"downloads":168891,"plays":0,"downVotes":106,"upVotes":6428,"heat":1249.3560434,"rating":0.9494101356195022},"description":"This map was supposed to release last year and have a 90 degree difficulty but it got cut because life ¯\\_(ツ)_/¯ Fantastic song though.\n\nExpert Playthrough : https://youtu.be/GonnTYPmDVY","deletedAt":null,"_id":"5e3eef3f521acf0006790d9e","key":"8553","name":"Blinding Lights - The Weeknd","uploader":{"_id":"5cff0b7398cc5a672c84ee55","username":"novashaft"},"hash":"17d298f2bf78b453cd66474ce268ae9dac9fab46","uploaded":"2020-02-08T17:26:23.499Z","directDownload":"/cdn/8553/17d298f2bf78b453cd66474ce268ae9dac9fab46.zip","downloadURL":"/api/download/key/8553","coverURL":"/cdn/8553/17d298f2bf78b453cd66474ce268ae9dac9fab46.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":308.9583435058594,"length":168,"bombs":0,"notes":512,"obstacles":52,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"I Wanna Be Like You (Sim Gretina Remix)","songSubName":"Jungle Book","songAuthorName":"ConnorJC","levelAuthorName":"ConnorJC","bpm":110},"stats":{"downloads":190120,"plays":1594,"downVotes":82,"upVotes":5496,"heat":210.2683405,"rating":0.9491430971728251},"description":"Who doesn't love The Jungle Book? \r\nPreview: https://youtu.be/DXdo1-FlxSk","deletedAt":null,"_id":"5cff620f48229f7d88fc6d90","key":"1023","name":"I Wanna Be Like You (Sim Gretina Remix)","uploader":{"_id":"5cff0b7398cc5a672c84fb89","username":"connorjc"},"uploaded":"2018-08-16T13:41:07.000Z","hash":"638cf20187a122490b4662555e9072cd8073146b","directDownload":"/cdn/1023/638cf20187a122490b4662555e9072cd8073146b.zip","downloadURL":"/api/download/key/1023","coverURL":"/cdn/1023/638cf20187a122490b4662555e9072cd8073146b.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":387,"obstacles":18,"njs":11,"njsOffset":0},"normal":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":503,"obstacles":6,"njs":12,"njsOffset":0},"hard":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":617,"obstacles":0,"njs":12,"njsOffset":0},"expert":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":1006,"obstacles":0,"njs":14,"njsOffset":0},"expertPlus":{"duration":380.4599914550781,"length":262,"bombs":0,"notes":1207,"obstacles":0,"njs":16,"njsOffset":0}}}],"songName":"Love The Way You Lie (ft. Rihanna)","songSubName":"Eminem, Rihanna","songAuthorName":"StyngMe","levelAuthorName":"styngme","bpm":87},"stats":{"downloads":256571,"plays":0,"downVotes":93,"upVotes":5808,"heat":521.5015051,"rating":0.9487686883502519},"description":"I hope you enjoy!\r\nBPM: 87\r\nDifficulties: ALL\r\nNote Jump Speed for E+ through Easy: 16, 14, 12, 12, 11","deletedAt":null,"_id":"5cff621348229f7d88fc8181","key":"30fd","name":"Love The Way You Lie (ft. Rihanna) - Eminem, Rihanna | Charted by StyngMe","uploader":{"_id":"5cff0b7298cc5a672c84e9cd","username":"styngme"},"uploaded":"2019-01-25T15:48:22.000Z","hash":"8161daf97cab8c95aea0556549b4b8cfb29a13d3","directDownload":"/cdn/30fd/8161daf97cab8c95aea0556549b4b8cfb29a13d3.zip","downloadURL":"/api/download/key/30fd","coverURL":"/cdn/30fd/8161daf97cab8c95aea0556549b4b8cfb29a13d3.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":289,"length":145,"bombs":0,"notes":734,"obstacles":16,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Ievan Polkka","songSubName":"Hatsune Miku","songAuthorName":"Fraktar","levelAuthorName":"Fraktar","bpm":119},"stats":{"downloads":137156,"plays":0,"downVotes":99,"upVotes":5957,"heat":577.2052605,"rating":0.9484997273179496},"description":"","deletedAt":null,"_id":"5cff621448229f7d88fc866f","key":"38ff","name":"Ievan Polkka","uploader":{"_id":"5cff0b7698cc5a672c8551b0","username":"fraktar"},"uploaded":"2019-02-23T15:58:08.000Z","hash":"72bf749f5df632cd103fccd734c9fd5cc7596caa","directDownload":"/cdn/38ff/72bf749f5df632cd103fccd734c9fd5cc7596caa.zip","downloadURL":"/api/download/key/38ff","coverURL":"/cdn/38ff/72bf749f5df632cd103fccd734c9fd5cc7596caa.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":627.203125,"length":250,"bombs":0,"notes":332,"obstacles":12,"njs":9,"njsOffset":0},"normal":{"duration":627.203125,"length":250,"bombs":0,"notes":471,"obstacles":17,"njs":10,"njsOffset":0},"hard":{"duration":627.203125,"length":250,"bombs":0,"notes":679,"obstacles":17,"njs":11,"njsOffset":0},"expert":{"duration":627.203125,"length":250,"bombs":0,"notes":954,"obstacles":17,"njs":12.5,"njsOffset":0},"expertPlus":{"duration":627.203125,"length":250,"bombs":0,"notes":1086,"obstacles":1,"njs":12.5,"njsOffset":0}}}],"songName":"YoiYoi Kokon","songSubName":"REOL","songAuthorName":"Kolezan","levelAuthorName":"Kolezan","bpm":150},"stats":{"downloads":119113,"plays":0,"downVotes":83,"upVotes":5299,"heat":507.5073376,"rating":0.9480848814064379},"description":"Put on your yukata; lets travel back in time and enjoy ourselves! Here's REOL's probably most popular song mapped by me, YoiYoi Kokon (宵々古今)!\r\n\r\nThe audio version of the song is that of the music video (slightly edited by me) as I love the addition of the fireworks in the last part of the song. Together with the crowd in the intro this makes it feel like you're attending a festival.\r\n\r\nExpert+ is basically Expert but with no pauses and with the melody followed instead of the shouts.\r\n\r\nPreview:\r\nhttps://youtu.be/G61m6Au2foM\r\n\r\nBPM: 150\r\nDuration: 4:16\r\nEasy, Normal, Hard, Expert and Expert+ difficulties.\r\n\r\nEasy: 332, 12 obstacles, 0 mines\r\nNormal: 471 notes, 17 obstacles, 0 mines\r\nHard: 679 notes, 17 obstacles, 0 mines\r\nExpert: 954 notes, 17 obstacles, 0 mines\r\nExpert+: 1086 notes, 1 obstacles, 0 mines\r\n\r\nThanks to Redmagi, Aithke, aggrogahu, Don and Emperor of the Internet for playtesting!\r\n\r\nEnjoy!","deletedAt":null,"_id":"5cff621348229f7d88fc8002","key":"2e9e","name":"YoiYoi Kokon - REOL","uploader":{"_id":"5cff0b7398cc5a672c84fcbb","username":"kolezan"},"uploaded":"2019-01-18T09:22:30.000Z","hash":"766a222b102b6522cd5d31e82ec7adc7c5ff011e","directDownload":"/cdn/2e9e/766a222b102b6522cd5d31e82ec7adc7c5ff011e.zip","downloadURL":"/api/download/key/2e9e","coverURL":"/cdn/2e9e/766a222b102b6522cd5d31e82ec7adc7c5ff011e.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":364,"length":174,"bombs":0,"notes":251,"obstacles":0,"njs":10,"njsOffset":0},"normal":{"duration":364,"length":174,"bombs":0,"notes":444,"obstacles":0,"njs":11,"njsOffset":0},"hard":{"duration":364,"length":174,"bombs":0,"notes":581,"obstacles":0,"njs":12,"njsOffset":0},"expert":{"duration":364,"length":174,"bombs":0,"notes":723,"obstacles":0,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Du Hast","songSubName":"Rammstein","songAuthorName":"Level by Kolezan","levelAuthorName":"Kolezan","bpm":125},"stats":{"downloads":258691,"plays":4760,"downVotes":151,"upVotes":7442,"heat":305.805676,"rating":0.9475141587030342},"description":"Many has been asking, many have been waiting patiently, many have been confused as to why this hasn't been done before, but now it's here and now you can have it too; here's Du Hast by Rammstein now in Beat Saber!\r\n\r\nSong has been edited (shortened) to be tighter and not too repetitive.\r\n\r\nPreview:\r\nhttps://youtu.be/3i1mf2EGSjc\r\n\r\nBPM: 125.09\r\nDuration: 2:57\r\nEasy, Normal, Hard and Expert difficulties.\r\n\r\nEasy: 251, 0 obstacles, 0 mines\r\nNormal: 444 notes, 0 obstacles, 0 mines\r\nHard: 581 notes, 0 obstacles, 0 mines\r\nExpert: 437 notes, 0 obstacles, 0 mines\r\n\r\nThanks to VenclaireVR, Redmagi, Horseman, Saut and elliotttate for playtesting!\r\n\r\nDownload the One Saber version of this map here:\r\nhttps://beatsaver.com/browse/detail/6784-6436\r\n\r\nEnjoy!","deletedAt":null,"_id":"5cff621048229f7d88fc72cb","key":"18cc","name":"Du Hast by Rammstein (Kole Edit)","uploader":{"_id":"5cff0b7398cc5a672c84fcbb","username":"kolezan"},"uploaded":"2018-10-05T06:17:10.000Z","hash":"6df961680ead49e2d92de1a95b789e0ce4a026db","directDownload":"/cdn/18cc/6df961680ead49e2d92de1a95b789e0ce4a026db.zip","downloadURL":"/api/download/key/18cc","coverURL":"/cdn/18cc/6df961680ead49e2d92de1a95b789e0ce4a026db.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":740,"length":258,"njs":20,"njsOffset":0,"bombs":0,"notes":606,"obstacles":9589},"expertPlus":null,"hard":{"duration":740,"length":258,"njs":20,"njsOffset":0,"bombs":0,"notes":449,"obstacles":9581},"normal":null},"name":"Standard"}],"levelAuthorName":"Rustic","songAuthorName":"ABIS & Signal & Tasha Baxter","songName":"The Wall","songSubName":"(Buunshin Remix)","bpm":172},"stats":{"downloads":85004,"plays":0,"downVotes":49,"upVotes":3792,"heat":1037.6142733,"rating":0.9466279260659083},"description":"https://youtu.be/xEowRTlHvuI","deletedAt":null,"_id":"5dadb307ac36cc00063e2242","key":"6b55","name":"ABIS & Signal & Tasha Baxter - The Wall (Buunshin Remix)","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"hash":"e855d1a7b17904322aa3ebaf3bb002421bce568a","uploaded":"2019-10-21T13:30:47.405Z","directDownload":"/cdn/6b55/e855d1a7b17904322aa3ebaf3bb002421bce568a.zip","downloadURL":"/api/download/key/6b55","coverURL":"/cdn/6b55/e855d1a7b17904322aa3ebaf3bb002421bce568a.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/rating/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":423,"obstacles":7,"njs":10,"njsOffset":0},"hard":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":507,"obstacles":32,"njs":10,"njsOffset":0},"expert":{"duration":401.9254150390625,"length":207,"bombs":0,"notes":771,"obstacles":32,"njs":10,"njsOffset":0},"expertPlus":{"duration":401.92266845703125,"length":207,"bombs":0,"notes":873,"obstacles":32,"njs":10,"njsOffset":0}}}],"songName":"New Rules","songSubName":"Dua Lipa","songAuthorName":"BennyDaBeast (Lighting: Heisenberg)","levelAuthorName":"bennydabeast","bpm":116},"stats":{"downloads":302337,"plays":7319,"downVotes":115,"upVotes":6052,"heat":168.6453893,"rating":0.9465574296574264},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/Ohq6cA_1NAE\r\nLighting by Heisenberg: https://beatsaver.com/index.php/browse/byuser/1089","deletedAt":null,"_id":"5cff620e48229f7d88fc6afc","key":"bd3","name":"New Rules - Dua Lipa","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-25T20:53:52.000Z","hash":"674b1b540ac5851c92c8cde7dd456698f5a733c4","directDownload":"/cdn/bd3/674b1b540ac5851c92c8cde7dd456698f5a733c4.zip","downloadURL":"/api/download/key/bd3","coverURL":"/cdn/bd3/674b1b540ac5851c92c8cde7dd456698f5a733c4.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":569.0850219726562,"length":200,"njs":14,"njsOffset":0,"bombs":0,"notes":651,"obstacles":42},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Joetastic","songAuthorName":"Twenty One Pilots","songName":"Stressed Out","songSubName":"","bpm":170},"stats":{"downloads":133914,"plays":0,"downVotes":61,"upVotes":4049,"heat":922.5082825,"rating":0.945532469866561},"description":"\"Stressed Out\" is a song by American musical duo Twenty One Pilots, for their fourth studio album, Blurryface. Written by Tyler Joseph and produced by Mike Elizondo, the lyrics discuss the harsh end of adolescence and the transition to adulthood.","deletedAt":null,"_id":"5d5ea4b62c316f000688d7b7","key":"5e81","name":"Stressed Out - Twenty One Pilots","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"hash":"068a80d89857a6a2221f54e8a5d374584b2db77e","uploaded":"2019-08-22T14:20:38.731Z","directDownload":"/cdn/5e81/068a80d89857a6a2221f54e8a5d374584b2db77e.zip","downloadURL":"/api/download/key/5e81","coverURL":"/cdn/5e81/068a80d89857a6a2221f54e8a5d374584b2db77e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":454,"length":252,"bombs":0,"notes":321,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":454,"length":252,"bombs":0,"notes":432,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":454,"length":252,"bombs":0,"notes":680,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":{"duration":454,"length":252,"bombs":0,"notes":810,"obstacles":14,"njs":10,"njsOffset":0}}}],"songName":"Some Nights","songSubName":"Fun.","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":108},"stats":{"downloads":296647,"plays":6779,"downVotes":134,"upVotes":6290,"heat":199.3548319,"rating":0.944928676014249},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/X4R7VIZPffk","deletedAt":null,"_id":"5cff620f48229f7d88fc6cee","key":"efe","name":"Some Nights - Fun.","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-10T20:34:09.000Z","hash":"e64d6b7c5527d36005ae1801268798dd21ccf605","directDownload":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.zip","downloadURL":"/api/download/key/efe","coverURL":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":389.8768310546875,"length":182,"bombs":0,"notes":721,"obstacles":8,"njs":14,"njsOffset":0},"expertPlus":null}}],"levelAuthorName":"Joetastic","songAuthorName":"Run DMC","songName":"It's Tricky","songSubName":"","bpm":128},"stats":{"downloads":106060,"plays":0,"downVotes":36,"upVotes":2995,"heat":824.7020516,"rating":0.944428499995616},"description":"\"It's Tricky\" is the fourth and final single released from Run-DMC's third album, Raising Hell which was released early in 1987. \n\nThis is one of favorite workout songs, can't believe I haven't mapped it yet :P","deletedAt":null,"_id":"5d1b92fe1aea5f0006478bd9","key":"5552","name":"It's Tricky - Run DMC","uploader":{"_id":"5cff0b7498cc5a672c85050e","username":"joetastic"},"hash":"9027d4274febf8722078a5f70b51da58cf433322","uploaded":"2019-07-02T17:23:10.798Z","directDownload":"/cdn/5552/9027d4274febf8722078a5f70b51da58cf433322.zip","downloadURL":"/api/download/key/5552","coverURL":"/cdn/5552/9027d4274febf8722078a5f70b51da58cf433322.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":429.5,"length":201,"bombs":0,"notes":297,"obstacles":88,"njs":10,"njsOffset":0},"normal":{"duration":429.54266357421875,"length":201,"bombs":0,"notes":343,"obstacles":88,"njs":10,"njsOffset":0},"hard":{"duration":429.54266357421875,"length":201,"bombs":0,"notes":524,"obstacles":125,"njs":12,"njsOffset":0},"expert":{"duration":429.5,"length":201,"bombs":0,"notes":695,"obstacles":100,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Moves Like Jagger","songSubName":"Maroon 5 ft. Christina Aguilera","songAuthorName":"Fafurion","levelAuthorName":"fafurion","bpm":128},"stats":{"downloads":204299,"plays":0,"downVotes":94,"upVotes":4925,"heat":516.3258593,"rating":0.9442570841924475},"description":"See it in Action: https://www.youtube.com/watch?v=J4_8scnZPjk\r\n\r\nThank you to my playtesters:\r\n\r\nQTpop (https://www.twitch.tv/qtpop)\r\n\r\nI stream playing and making maps for beat saber: https://www.twitch.tv/fafurion","deletedAt":null,"_id":"5cff621348229f7d88fc8108","key":"3038","name":"Moves Like Jagger - Maroon 5 ft. Christina Aguilera","uploader":{"_id":"5cff0b7398cc5a672c84f945","username":"fafurion"},"uploaded":"2019-01-23T00:01:22.000Z","hash":"6f5e229b8ec73c9e448c00a680548b0ce25918ee","directDownload":"/cdn/3038/6f5e229b8ec73c9e448c00a680548b0ce25918ee.zip","downloadURL":"/api/download/key/3038","coverURL":"/cdn/3038/6f5e229b8ec73c9e448c00a680548b0ce25918ee.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":127,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":264,"length":123,"njs":18,"njsOffset":0,"bombs":0,"notes":748,"obstacles":4878},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Reaxt","songAuthorName":"Dirty Androids","songName":"Midnight Lady","songSubName":"","bpm":128},"stats":{"downloads":52917,"plays":0,"downVotes":41,"upVotes":3128,"heat":1621.474019,"rating":0.944043142002959},"description":"Enjoy the ride.\n\nMassive thanks to Nyri0 and Checkthepan for helping with scripts and some math!!\n\n\nThis map was released as a part of the \"Extra Sensory\" event.\n\nVideo showcase thingy: https://youtu.be/pE_s9bvntA0 (watch tougeVR's instead tho)","deletedAt":null,"_id":"5f3ea9559824150006b6ebce","key":"da60","name":"[Extra Sensory] Dirty Androids - Midnight Lady","uploader":{"_id":"5cff0b7298cc5a672c84e8ab","username":"reaxt"},"hash":"ee501d7348223470c16ca88eac4a946e41cd11a9","uploaded":"2020-08-20T16:48:21.705Z","directDownload":"/cdn/da60/ee501d7348223470c16ca88eac4a946e41cd11a9.zip","downloadURL":"/api/download/key/da60","coverURL":"/cdn/da60/ee501d7348223470c16ca88eac4a946e41cd11a9.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":514,"length":268,"bombs":0,"notes":639,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":514,"length":268,"bombs":0,"notes":776,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Uptown Funk","songSubName":"Mark Ronson","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":115},"stats":{"downloads":257348,"plays":0,"downVotes":164,"upVotes":6894,"heat":763.6662151,"rating":0.94367246970076},"description":"5/31: This version now works for the Oculus Quest.\r\n\r\nDifficulties: Expert and Hard\r\nYouTube Link: https://youtu.be/6TYji_Klr9I","deletedAt":null,"_id":"5cff621748229f7d88fc9549","key":"5038","name":"Uptown Funk - Mark Ronson","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-05-31T17:58:39.000Z","hash":"d110e413fb7fb462b692f1f17b835cf8b7280884","directDownload":"/cdn/5038/d110e413fb7fb462b692f1f17b835cf8b7280884.zip","downloadURL":"/api/download/key/5038","coverURL":"/cdn/5038/d110e413fb7fb462b692f1f17b835cf8b7280884.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":707.3033447265625,"length":326,"njs":16,"njsOffset":0,"bombs":0,"notes":1218,"obstacles":6631},"expertPlus":null,"hard":{"duration":707.3033447265625,"length":326,"njs":16,"njsOffset":0,"bombs":0,"notes":1218,"obstacles":43},"normal":null},"name":"Standard"}],"levelAuthorName":"ejiejidayo","songAuthorName":".","songName":"Star Sky Two Steps From Hell","songSubName":"","bpm":130},"stats":{"downloads":97307,"plays":0,"downVotes":29,"upVotes":2642,"heat":966.9368189,"rating":0.9436590294134172},"description":"68\nNormal wall --- 43 walls\nHyper wall --- 6631 walls\n\ndoodle :)","deletedAt":null,"_id":"5d7d46b1fffdb800068e0b37","key":"6366","name":"Star Sky - Two Steps From Hell","uploader":{"_id":"5cff0b7398cc5a672c84fc58","username":"ejiejidayo"},"hash":"323975ef9eaec337c8208dc864e766289e3d5ef1","uploaded":"2019-09-14T19:59:45.575Z","directDownload":"/cdn/6366/323975ef9eaec337c8208dc864e766289e3d5ef1.zip","downloadURL":"/api/download/key/6366","coverURL":"/cdn/6366/323975ef9eaec337c8208dc864e766289e3d5ef1.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":180,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":307.98065185546875,"length":175,"njs":10,"njsOffset":0,"bombs":0,"notes":175,"obstacles":1},"expert":{"duration":307.98065185546875,"length":175,"njs":14,"njsOffset":0,"bombs":0,"notes":578,"obstacles":15},"expertPlus":{"duration":307.98065185546875,"length":175,"njs":14,"njsOffset":0,"bombs":0,"notes":580,"obstacles":14},"hard":{"duration":307.98065185546875,"length":175,"njs":12,"njsOffset":0,"bombs":0,"notes":459,"obstacles":10},"normal":{"duration":307.98065185546875,"length":175,"njs":10,"njsOffset":0,"bombs":0,"notes":322,"obstacles":2}},"name":"Standard"}],"levelAuthorName":"Holair","songAuthorName":"Joey Batey","songName":"Toss A Coin To Your Witcher","songSubName":"Netflix's The Witcher","bpm":105},"stats":{"downloads":201348,"plays":0,"downVotes":189,"upVotes":7568,"heat":1169.003459,"rating":0.9435469313661412},"description":"Expert+ Preview https://www.youtube.com/watch?v=AjfABhvswrI\n\nToss a Coin to Beat Saber!\n\nNetflix's The Witcher OST.\nOriginal Music by Sonya Belousova & Giona Ostinelli.\nLyrics by Jenny Klein, Sonya Belousova & Giona Ostinelli.\nPerformed by Joey Batey.\n\nThanks Sorukun for playtesting.","deletedAt":null,"_id":"5e07b6f7932ef2000772d492","key":"7a64","name":"Joey Batey - Toss A Coin To Your Witcher","uploader":{"_id":"5cff0b7698cc5a672c853af4","username":"holair"},"hash":"19a733cf9202ef25725966d78f465bf87b26254d","uploaded":"2019-12-28T20:11:35.767Z","directDownload":"/cdn/7a64/19a733cf9202ef25725966d78f465bf87b26254d.zip","downloadURL":"/api/download/key/7a64","coverURL":"/cdn/7a64/19a733cf9202ef25725966d78f465bf87b26254d.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":353,"obstacles":78,"njs":13,"njsOffset":0},"normal":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":589,"obstacles":18,"njs":13,"njsOffset":0},"hard":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":893,"obstacles":13,"njs":13,"njsOffset":0},"expert":{"duration":517.3426513671875,"length":263,"bombs":0,"notes":1167,"obstacles":25,"njs":13,"njsOffset":0},"expertPlus":null}}],"songName":"New Divide","songSubName":"Linkin Park","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":118},"stats":{"downloads":259017,"plays":5363,"downVotes":65,"upVotes":3789,"heat":379.9690541,"rating":0.9429028282900022},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nhttps://www.youtube.com/watch?v=deWMNAw03QI&t=28s","deletedAt":null,"_id":"5cff621148229f7d88fc7700","key":"2005","name":"New Divide - Linkin Park - 100k Contest","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-11-13T00:58:32.000Z","hash":"be4a33ad1b1caef9f1f42b680ca1a9087db19ffe","directDownload":"/cdn/2005/be4a33ad1b1caef9f1f42b680ca1a9087db19ffe.zip","downloadURL":"/api/download/key/2005","coverURL":"/cdn/2005/be4a33ad1b1caef9f1f42b680ca1a9087db19ffe.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
+        pages.insert(
+            BEATSAVER_URL.join("playlists/id/1234/0").unwrap(),
+            playlist_maps_json(1234, &["97d3", "65cb"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("playlists/id/1234/1").unwrap(),
+            playlist_maps_json(1234, &["2144"]),
+        );
+        pages.insert(
+            BEATSAVER_URL.join("playlists/id/1234/2").unwrap(),
+            playlist_maps_json(1234, &[]),
+        );
         let client = FakeClientPaged::new(pages);
         assert_eq!(
             client
-                .maps_rating_page_iter(1)
-                .map(|m| m.unwrap().key)
+                .playlist_maps(1234)
+                .map(|m| m.unwrap().id)
                 .collect::<Vec<String>>(),
-            vec![
-                "26f6".to_string(),
-                "23c3".to_string(),
-                "747".to_string(),
-                "8553".to_string(),
-                "1023".to_string(),
-                "30fd".to_string(),
-                "38ff".to_string(),
-                "2e9e".to_string(),
-                "18cc".to_string(),
-                "6b55".to_string(),
-                "bd3".to_string(),
-                "5e81".to_string(),
-                "efe".to_string(),
-                "5552".to_string(),
-                "3038".to_string(),
-                "da60".to_string(),
-                "5038".to_string(),
-                "6366".to_string(),
-                "7a64".to_string(),
-                "2005".to_string()
-            ]
+            vec!["97d3".to_string(), "65cb".to_string(), "2144".to_string()]
+        );
+        assert_eq!(
+            client.index.key(map_hash("2144").as_str()),
+            Some("2144".to_string())
         );
     }
     #[test]
-    fn test_maps_latest() {
-        let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/latest/0").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":178,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":580.25,"length":174,"njs":14,"njsOffset":-0.800000011920929,"bombs":0,"notes":764,"obstacles":36},"hard":null,"normal":null},"name":"360Degree"},{"difficulties":{"easy":null,"expert":{"duration":580,"length":174,"njs":14,"njsOffset":-0.800000011920929,"bombs":0,"notes":526,"obstacles":39},"expertPlus":null,"hard":null,"normal":null},"name":"90Degree"},{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":580.25,"length":174,"njs":16,"njsOffset":0.800000011920929,"bombs":0,"notes":764,"obstacles":36},"hard":{"duration":580,"length":174,"njs":13,"njsOffset":-0.699999988079071,"bombs":0,"notes":526,"obstacles":39},"normal":{"duration":739,"length":221,"njs":12,"njsOffset":-0.5,"bombs":0,"notes":369,"obstacles":45}},"name":"Standard"}],"levelAuthorName":"TechButterfly","songAuthorName":"Shaggy feat. Ding Dong and Ne-Yo","songName":"Holiday in Jamaica","songSubName":"","bpm":200},"stats":{"downloads":1,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.3250661,"rating":0},"description":"Unless you already live in a tropical paradise, you probably won't be visiting one this holiday season. But things will get better soon, and maybe next year we'll get to go to Jamaica or the Bahamas or some other warm fun place. In the meantime, you can enjoy this - Shaggy's new-for-2020 Christmas hit, Holiday in Jamaica!\n\nThis and the other maps put out by MajorPickle and myself this holiday season will eventually be collected into the PickleTech Christmas Pack we released last year. Check out that pack and our other new songs for some holiday slicing and dicing fun!\n\nNormal, Hard, and ExpertPlus Standard levels, as well as an Expert 90 and ExpertPlus 360.\n\nBig thanks to Maybeemae, MajorPickle, and Brentastic for playtesting!","deletedAt":null,"_id":"5fcaf893607cfe000693e7c5","key":"11bb1","name":"Holiday in Jamaica - Shaggy ft. Ding Dong and Ne-Yo","uploader":{"_id":"5cff0b7798cc5a672c8566f7","username":"techbutterfly"},"hash":"3fbb092b03ddb592402e3ba5e3edf04f460c55df","uploaded":"2020-12-05T03:03:47.974Z","directDownload":"/cdn/11bb1/3fbb092b03ddb592402e3ba5e3edf04f460c55df.zip","downloadURL":"/api/download/key/11bb1","coverURL":"/cdn/11bb1/3fbb092b03ddb592402e3ba5e3edf04f460c55df.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":277,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":230.5576629638672,"length":101,"njs":19,"njsOffset":-0.20000000298023224,"bombs":0,"notes":605,"obstacles":3},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Cratonugget","songAuthorName":"Camilia","songName":"Arcology on Permafrost","songSubName":"","bpm":136},"stats":{"downloads":32,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.2853899,"rating":0},"description":"","deletedAt":null,"_id":"5fcaf19ab05fe7000692051d","key":"11bb0","name":"Camilia - Arcology on Permafrost ver 5","uploader":{"_id":"5f8e0d20e19f180007e17c6a","username":"cratornugg"},"hash":"2ec4912de0e1a44cbc2d96f00f15f959b65b4220","uploaded":"2020-12-05T02:34:02.545Z","directDownload":"/cdn/11bb0/2ec4912de0e1a44cbc2d96f00f15f959b65b4220.zip","downloadURL":"/api/download/key/11bb0","coverURL":"/cdn/11bb0/2ec4912de0e1a44cbc2d96f00f15f959b65b4220.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":329,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":660,"length":314,"njs":20,"njsOffset":0.30000001192092896,"bombs":116,"notes":1757,"obstacles":706},"hard":null,"normal":null},"name":"Standard"},{"difficulties":{"easy":{"duration":0,"length":0,"njs":20,"njsOffset":0,"bombs":0,"notes":0,"obstacles":0},"expert":null,"expertPlus":null,"hard":null,"normal":null},"name":"Lightshow"}],"levelAuthorName":"A Jhintleman","songAuthorName":"Lektrique & MIDNIGHT CVLT","songName":"Full Throttle","songSubName":"","bpm":126},"stats":{"downloads":51,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.2686339,"rating":0},"description":"Reupload - Now with 80% less potential vision block walls!\n\nBeatmap number 9 (released)!\n\nHave fun!\n\nPretty pog vanilla lights (6,596 Events). If you use static lights I will be sad.","deletedAt":null,"_id":"5fcaeea89370580009f5dae2","key":"11baf","name":"Full Throttle","uploader":{"_id":"5f33e6ec530c8e00061a375a","username":"a jhintleman"},"hash":"28f224c2c79ce1f0697589d3294e600489d5ab8c","uploaded":"2020-12-05T02:21:28.525Z","directDownload":"/cdn/11baf/28f224c2c79ce1f0697589d3294e600489d5ab8c.zip","downloadURL":"/api/download/key/11baf","coverURL":"/cdn/11baf/28f224c2c79ce1f0697589d3294e600489d5ab8c.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":384,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":1438.3209228515625,"length":367,"njs":21,"njsOffset":0,"bombs":38,"notes":6774,"obstacles":11},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"WDG_Doctor","songAuthorName":"Camellia","songName":"Z:iRNiTRA","songSubName":"","bpm":235},"stats":{"downloads":74,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.22707,"rating":0},"description":"vibro maps of camellia songs are really fun to make\n\nmapped in 4 hours","deletedAt":null,"_id":"5fcae75ad2a4c20006351988","key":"11bad","name":"Z:iRNiTRA","uploader":{"_id":"5ed46197ed49a2000774fa15","username":"devonix"},"hash":"9a7d149c4a4a0f0219fae20daa5ec550ee908286","uploaded":"2020-12-05T01:50:18.148Z","directDownload":"/cdn/11bad/9a7d149c4a4a0f0219fae20daa5ec550ee908286.zip","downloadURL":"/api/download/key/11bad","coverURL":"/cdn/11bad/9a7d149c4a4a0f0219fae20daa5ec550ee908286.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":false,"hard":true,"normal":false},"duration":164,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":null,"hard":{"duration":385.2607727050781,"length":160,"njs":15,"njsOffset":0,"bombs":52,"notes":358,"obstacles":15},"normal":null},"name":"Standard"}],"levelAuthorName":"nate","songAuthorName":"Yoko Shimomura","songName":"Nachtflugel","songSubName":"(Yozora)","bpm":143.89},"stats":{"downloads":65,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.2221184,"rating":0},"description":"moremoremore\n\nAutolights as requested\n\nVariable BPM but sticks to somewhere around 143.89\nHard\n\nThis map was a special request Trixks","deletedAt":null,"_id":"5fcae67bd2a4c200063518d3","key":"11bac","name":"Nachtflügel (Yozora) [Kingdom Hearts III Re:Mind OST]","uploader":{"_id":"5d761e29048dff0006ca82d4","username":"nate"},"hash":"7ca62d9094694b81502c5ef78656849f15caa3dc","uploaded":"2020-12-05T01:46:35.328Z","directDownload":"/cdn/11bac/7ca62d9094694b81502c5ef78656849f15caa3dc.zip","downloadURL":"/api/download/key/11bac","coverURL":"/cdn/11bac/7ca62d9094694b81502c5ef78656849f15caa3dc.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":207,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":404.5,"length":202,"njs":18,"njsOffset":-0.30000001192092896,"bombs":0,"notes":870,"obstacles":10},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"llekel","songAuthorName":"Wonder Girls","songName":"I Feel You","songSubName":"","bpm":120},"stats":{"downloads":68,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.209791,"rating":0},"description":"JYP - Big thanks to Joey, Todai and TinkerVR85 for the testing/feedback!\n\nNice 80's inspired Kpop!","deletedAt":null,"_id":"5fcae4509370580009f5d2f1","key":"11baa","name":"I Feel You - Wonder Girls","uploader":{"_id":"5e712e44d87e580006b27597","username":"llekel"},"hash":"9bb7ba570827f8de8cb445d5cb410c289e09d314","uploaded":"2020-12-05T01:37:20.595Z","directDownload":"/cdn/11baa/9bb7ba570827f8de8cb445d5cb410c289e09d314.zip","downloadURL":"/api/download/key/11baa","coverURL":"/cdn/11baa/9bb7ba570827f8de8cb445d5cb410c289e09d314.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":false,"expertPlus":false,"hard":true,"normal":true},"duration":102,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":92,"notes":126,"obstacles":12},"expert":null,"expertPlus":null,"hard":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":327,"notes":315,"obstacles":18},"normal":{"duration":201,"length":100,"njs":12,"njsOffset":0,"bombs":92,"notes":223,"obstacles":18}},"name":"Standard"}],"levelAuthorName":"Josh","songAuthorName":"Laura Shigihara","songName":"Brainiac Maniac - Plants VS Zombies","songSubName":"","bpm":120},"stats":{"downloads":95,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1609861,"rating":0},"description":"","deletedAt":null,"_id":"5fcadbbcd2a4c200063510e8","key":"11ba5","name":"Brainiac Maniac - Plants VS Zombies","uploader":{"_id":"5fc8273aa3f6d20006de32e3","username":"electrojosh12@gmail.com"},"hash":"220f9e5dabdd505f8fa7e995ce1e46110b6cfda1","uploaded":"2020-12-05T01:00:44.375Z","directDownload":"/cdn/11ba5/220f9e5dabdd505f8fa7e995ce1e46110b6cfda1.zip","downloadURL":"/api/download/key/11ba5","coverURL":"/cdn/11ba5/220f9e5dabdd505f8fa7e995ce1e46110b6cfda1.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":259,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":667.5,"length":258,"njs":19,"njsOffset":-0.4000000059604645,"bombs":15,"notes":1758,"obstacles":74},"expertPlus":{"duration":667.5,"length":258,"njs":21,"njsOffset":-0.550000011920929,"bombs":8,"notes":1812,"obstacles":75},"hard":{"duration":667.5,"length":258,"njs":17,"njsOffset":0,"bombs":50,"notes":1340,"obstacles":77},"normal":null},"name":"Standard"}],"levelAuthorName":"mux","songAuthorName":"Takaaki Natsushiro","songName":"Endroll -covered by Isekaijoucho-","songSubName":"","bpm":155},"stats":{"downloads":97,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1591567,"rating":0},"description":"Lyric MV\r\nhttps://www.youtube.com/watch?v=X0BykQ-ljKE\r\n\r\nOriginal\r\nhttps://www.youtube.com/watch?v=a7jPH3vbMys\r\n","deletedAt":null,"_id":"5fcadb6aa3f6d20006e0702f","key":"11ba4","name":"Endroll -covered by Isekaijoucho-","uploader":{"_id":"5e0a5f2046a07100065909fe","username":"mux"},"hash":"1c861c8438a7012422be6acdc78854663c509853","uploaded":"2020-12-05T00:59:22.053Z","directDownload":"/cdn/11ba4/1c861c8438a7012422be6acdc78854663c509853.zip","downloadURL":"/api/download/key/11ba4","coverURL":"/cdn/11ba4/1c861c8438a7012422be6acdc78854663c509853.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":215,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":383,"length":214,"njs":12,"njsOffset":0,"bombs":0,"notes":1011,"obstacles":7},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"darthGandalf95","songAuthorName":"Jan Hegenberg","songName":"Die Garde der Hoffnung","songSubName":"","bpm":107.30000305175781},"stats":{"downloads":83,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.1591484,"rating":0.5941636475090435},"description":"Für alle Hegis.\nFirst try ... so be rude","deletedAt":null,"_id":"5fcadb69029e550006a12b6d","key":"11ba3","name":"Jan Hegenberg - Die Garde der Hoffnung","uploader":{"_id":"5fcacf72b05fe7000691e5af","username":"darthgandalf95"},"hash":"a41ea9833039435c1216a9cdfb1f0e35024d2b4e","uploaded":"2020-12-05T00:59:21.677Z","directDownload":"/cdn/11ba3/a41ea9833039435c1216a9cdfb1f0e35024d2b4e.zip","downloadURL":"/api/download/key/11ba3","coverURL":"/cdn/11ba3/a41ea9833039435c1216a9cdfb1f0e35024d2b4e.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":136,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":378.35833740234375,"length":133,"njs":14,"njsOffset":0.800000011920929,"bombs":11,"notes":469,"obstacles":10},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Dack","songAuthorName":"THE iDOLM@STER MILLION LIVE!","songName":"Super Lover","songSubName":"","bpm":170},"stats":{"downloads":109,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1238145,"rating":0},"description":"Patreon: patreon.com/Dack","deletedAt":null,"_id":"5fcad533d2a4c200063509a7","key":"11b9e","name":"THE IDOLM@STER MILLION - Super Lover","uploader":{"_id":"5cff0b7598cc5a672c852c6f","username":"dack"},"hash":"9303890819a081a59bb0a7c0c4718d367330ca34","uploaded":"2020-12-05T00:32:51.654Z","directDownload":"/cdn/11b9e/9303890819a081a59bb0a7c0c4718d367330ca34.zip","downloadURL":"/api/download/key/11b9e","coverURL":"/cdn/11b9e/9303890819a081a59bb0a7c0c4718d367330ca34.png"}],"totalDocs":36014,"lastPage":3601,"prevPage":null,"nextPage":1}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/latest/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":199,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":303,"length":196,"njs":20,"njsOffset":0,"bombs":0,"notes":757,"obstacles":0},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"cookie","songAuthorName":"Hollywood Undead","songName":"Bullet","songSubName":"","bpm":92.5},"stats":{"downloads":139,"plays":0,"downVotes":0,"upVotes":3,"heat":1822.5865456,"rating":0.6705937099936721},"description":"An upbeat song from Hollywood Undead that's about suicide.\r\nExpert only with custom lighting!","deletedAt":null,"_id":"5fcad2aca3f6d20006e06575","key":"11b9d","name":"Hollywood Undead - Bullet","uploader":{"_id":"5d1356c9f8b4040007dcb9fd","username":"cookiesaber"},"hash":"590ffb06d96eda325df735667baf0553d88cb8e0","uploaded":"2020-12-05T00:22:04.095Z","directDownload":"/cdn/11b9d/590ffb06d96eda325df735667baf0553d88cb8e0.zip","downloadURL":"/api/download/key/11b9d","coverURL":"/cdn/11b9d/590ffb06d96eda325df735667baf0553d88cb8e0.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":471,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":2672.249267578125,"length":471,"njs":23,"njsOffset":-1.399999976158142,"bombs":1,"notes":5080,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Lolighter","songAuthorName":"Sewerslvt","songName":"Mr. Kill Myself","songSubName":"","bpm":340},"stats":{"downloads":123,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1002815,"rating":0},"description":"funny lolighter map","deletedAt":null,"_id":"5fcad110d2a4c2000635047d","key":"11b9b","name":"M.K.MS","uploader":{"_id":"5cff0b7698cc5a672c854a7f","username":"wh4t"},"hash":"2fea40d139432aaa7b4bb1c5e46309c92dda2179","uploaded":"2020-12-05T00:15:12.667Z","directDownload":"/cdn/11b9b/2fea40d139432aaa7b4bb1c5e46309c92dda2179.zip","downloadURL":"/api/download/key/11b9b","coverURL":"/cdn/11b9b/2fea40d139432aaa7b4bb1c5e46309c92dda2179.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":192,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":403.25,"length":192,"njs":12,"njsOffset":0,"bombs":22,"notes":434,"obstacles":9},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Xpoiled","songAuthorName":"Distrion","songName":"Distrion Alibi (ft. heelen","songSubName":"Distrion","bpm":126},"stats":{"downloads":137,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0699933,"rating":0},"description":"My first beatmap enjoy follow for more. make sure to have decent play space as ther is 1 spot wher ther is a three wide wall","deletedAt":null,"_id":"5fcacbbda3f6d20006e05db1","key":"11b98","name":"Distrion - Alibi (Ft. Heleen)","uploader":{"_id":"5fc97909b05fe7000690be4e","username":"xpoled"},"hash":"b011aa10021f1aaaca714dc4ae0b176c71558247","uploaded":"2020-12-04T23:52:29.700Z","directDownload":"/cdn/11b98/b011aa10021f1aaaca714dc4ae0b176c71558247.zip","downloadURL":"/api/download/key/11b98","coverURL":"/cdn/11b98/b011aa10021f1aaaca714dc4ae0b176c71558247.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":195,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":449.2485046386719,"length":189,"njs":18,"njsOffset":-0.20000000298023224,"bombs":54,"notes":756,"obstacles":186},"expertPlus":{"duration":456.2485046386719,"length":192,"njs":20,"njsOffset":-0.4000000059604645,"bombs":0,"notes":1153,"obstacles":21},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Matt","songAuthorName":"Daniel Ingram, Caleb Chan, Michael Kovach","songName":"I've Had Enough of You","songSubName":"Fantoccio boss theme","bpm":142},"stats":{"downloads":155,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.0268422,"rating":0.5941636475090435},"description":"Woah, a map with two difficulties? Wac. Anyway descriptions of both:\r\nDance Time - The lighter of the difficulties, uses some less standard patterns, I tried to emulate a couple of the dances that happen during the fight.\r\nEx+ - A more standard style, though I tried to use some more variety and I think it turned out fairly well.","deletedAt":null,"_id":"5fcac427607cfe000693b71e","key":"11b91","name":"I've Had Enough of You","uploader":{"_id":"5e0158a0d89bd20006d5fb30","username":"matt"},"hash":"3900830997a659e0e0ac91e17a428a9a1625fc5f","uploaded":"2020-12-04T23:20:07.897Z","directDownload":"/cdn/11b91/3900830997a659e0e0ac91e17a428a9a1625fc5f.zip","downloadURL":"/api/download/key/11b91","coverURL":"/cdn/11b91/3900830997a659e0e0ac91e17a428a9a1625fc5f.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":206,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":403.125,"length":201,"njs":16,"njsOffset":0,"bombs":0,"notes":841,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ASharpBlade","songAuthorName":"NateWantsToBattle","songName":"Manipulate","songSubName":"","bpm":120},"stats":{"downloads":170,"plays":0,"downVotes":0,"upVotes":2,"heat":1822.3136479,"rating":0.6407951473548253},"description":"This song is By NatewantstoBattle. This is the 2nd song I did from him.","deletedAt":null,"_id":"5fcac1a7029e550006a10ecf","key":"11b90","name":"Manipulate","uploader":{"_id":"5fc67ef1b05fe700068e46ab","username":"asharpblade"},"hash":"f9cf3a19853b803d78cd327e12dc5c335b5acbfc","uploaded":"2020-12-04T23:09:27.806Z","directDownload":"/cdn/11b90/f9cf3a19853b803d78cd327e12dc5c335b5acbfc.zip","downloadURL":"/api/download/key/11b90","coverURL":"/cdn/11b90/f9cf3a19853b803d78cd327e12dc5c335b5acbfc.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":270,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":555.5,"length":260,"njs":12,"njsOffset":0,"bombs":0,"notes":1345,"obstacles":3},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"MegaHUstar","songAuthorName":"Rocco Vanwell","songName":"Big Bad Santa - Rocco Vanwell","songSubName":"Big Bad Santa","bpm":128},"stats":{"downloads":157,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0031818,"rating":0},"description":"First map gave a heart to it, but lightning part is too strobe effect so who has it please help yourself","deletedAt":null,"_id":"5fcabfff607cfe000693b373","key":"11b8f","name":"Rocco Vanwell - Big Bad Santa ","uploader":{"_id":"5fcabeb2d2a4c2000634f115","username":"petruska"},"hash":"fcf44786a3de8efc9e7411f26e447c37c4da7574","uploaded":"2020-12-04T23:02:23.180Z","directDownload":"/cdn/11b8f/fcf44786a3de8efc9e7411f26e447c37c4da7574.zip","downloadURL":"/api/download/key/11b8f","coverURL":"/cdn/11b8f/fcf44786a3de8efc9e7411f26e447c37c4da7574.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":111,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":175.5,"length":108,"njs":18,"njsOffset":0,"bombs":0,"notes":624,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Kpop Discord","songAuthorName":"Agust D, BTS, Dawin","songName":"What Do You Think?","songSubName":"(About That Dopessert)","bpm":97},"stats":{"downloads":153,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0030919,"rating":0},"description":"I just love being here honestly","deletedAt":null,"_id":"5fcabffb9370580009f5adeb","key":"11b8e","name":"What Do You Think? (About That Dopessert)","uploader":{"_id":"5f0419ec0ea71e0006780728","username":"smalldongstudios"},"hash":"4162744d4bd20e16335304dd2de548f01a7f6fca","uploaded":"2020-12-04T23:02:19.136Z","directDownload":"/cdn/11b8e/4162744d4bd20e16335304dd2de548f01a7f6fca.zip","downloadURL":"/api/download/key/11b8e","coverURL":"/cdn/11b8e/4162744d4bd20e16335304dd2de548f01a7f6fca.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":14,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":31.25,"length":13,"njs":22,"njsOffset":0,"bombs":0,"notes":47,"obstacles":0},"expertPlus":{"duration":32,"length":14,"njs":22,"njsOffset":0,"bombs":110,"notes":70,"obstacles":4},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BungiCraft","songAuthorName":"Imagine Dragons","songName":"\"Eggman\"","songSubName":"","bpm":136},"stats":{"downloads":165,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.0029829,"rating":0.5941636475090435},"description":"This is a shitpost. It is really bad. Play it to suffer :)","deletedAt":null,"_id":"5fcabff69370580009f5ade7","key":"11b8d","name":"\"Eggman\"","uploader":{"_id":"5fca7071029e550006a0b1a8","username":"bungicraft"},"hash":"4e26756e379841dd6e4897d8bfe80e2a1623e2e1","uploaded":"2020-12-04T23:02:14.229Z","directDownload":"/cdn/11b8d/4e26756e379841dd6e4897d8bfe80e2a1623e2e1.zip","downloadURL":"/api/download/key/11b8d","coverURL":"/cdn/11b8d/4e26756e379841dd6e4897d8bfe80e2a1623e2e1.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":277,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":202.5576629638672,"length":89,"njs":19,"njsOffset":-0.20000000298023224,"bombs":0,"notes":511,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Cratonugget","songAuthorName":"Camilia","songName":"Arcology on Permafrost","songSubName":"","bpm":136},"stats":{"downloads":200,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9524506,"rating":0},"description":"","deletedAt":null,"_id":"5fcab714b05fe7000691cd7c","key":"11b8c","name":"Camilia - Arcology on Permafrost ver 4","uploader":{"_id":"5f8e0d20e19f180007e17c6a","username":"cratornugg"},"hash":"1339a367a666dfcf86e9b3796ad1385f8fb47f8a","uploaded":"2020-12-04T22:24:20.275Z","directDownload":"/cdn/11b8c/1339a367a666dfcf86e9b3796ad1385f8fb47f8a.zip","downloadURL":"/api/download/key/11b8c","coverURL":"/cdn/11b8c/1339a367a666dfcf86e9b3796ad1385f8fb47f8a.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":32,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":128.57183837890625,"length":32,"njs":25,"njsOffset":0.6000000238418579,"bombs":17,"notes":2998,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"fuck","songAuthorName":"engineer gaming","songName":"HEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAHHH","songSubName":"Engineer!!!!!!","bpm":240},"stats":{"downloads":189,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9513778,"rating":0},"description":"HEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHHHH","deletedAt":null,"_id":"5fcab6e3b05fe7000691cd37","key":"11b8b","name":"Engineer Hey Ya","uploader":{"_id":"5f8a6a7f9f3df7000863cac3","username":"joen"},"hash":"93daccae82f9dd85881f8b309210ce432cd602aa","uploaded":"2020-12-04T22:23:31.999Z","directDownload":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.zip","downloadURL":"/api/download/key/11b8b","coverURL":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/latest/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":32,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":128.57183837890625,"length":32,"njs":25,"njsOffset":0.6000000238418579,"bombs":17,"notes":2998,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"fuck","songAuthorName":"engineer gaming","songName":"HEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAHHH","songSubName":"Engineer!!!!!!","bpm":240},"stats":{"downloads":189,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9513778,"rating":0},"description":"HEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHHHH","deletedAt":null,"_id":"5fcab6e3b05fe7000691cd37","key":"11b8b","name":"Engineer Hey Ya","uploader":{"_id":"5f8a6a7f9f3df7000863cac3","username":"joen"},"hash":"93daccae82f9dd85881f8b309210ce432cd602aa","uploaded":"2020-12-04T22:23:31.999Z","directDownload":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.zip","downloadURL":"/api/download/key/11b8b","coverURL":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":260,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":827.4833374023438,"length":248,"njs":21,"njsOffset":0,"bombs":16,"notes":2764,"obstacles":258},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ordinary09","songAuthorName":"xi","songName":"Blue Zenith","songSubName":"","bpm":200},"stats":{"downloads":231,"plays":0,"downVotes":0,"upVotes":2,"heat":1822.2381191,"rating":0.6407951473548253},"description":"i know there are already existing maps for this song, but i tried put my own twist on it where it doesnt only consist of stream patterns. some obscure patterns though. beware.\n\nreupload due to mapping error :(","deletedAt":null,"_id":"5fcab461d2a4c2000634e72c","key":"11b8a","name":"xi - Blue Zenith","uploader":{"_id":"5eafee9f7abb000006c2417c","username":"ordinary09"},"hash":"838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a","uploaded":"2020-12-04T22:12:49.008Z","directDownload":"/cdn/11b8a/838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a.zip","downloadURL":"/api/download/key/11b8a","coverURL":"/cdn/11b8a/838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":200,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":823.6616821289062,"length":190,"njs":24,"njsOffset":-0.10000000149011612,"bombs":23,"notes":2777,"obstacles":332},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Water","songAuthorName":"DJ Kurara","songName":"Be My Extratone","songSubName":"","bpm":260},"stats":{"downloads":190,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9352852,"rating":0},"description":"520bpm jump map that requires a bit of stamina\nundermapped in some parts (things that should be notes mapped as walls) for stamina reasons\nty saint for playtest\nminion toy","deletedAt":null,"_id":"5fcab40fb05fe7000691ca64","key":"11b89","name":"DJ Kurara - Be My Extratone","uploader":{"_id":"5cff0b7698cc5a672c85513e","username":"waterbottle7"},"hash":"48afdca736b315555b25a2dfcd41be76675ec63f","uploaded":"2020-12-04T22:11:27.833Z","directDownload":"/cdn/11b89/48afdca736b315555b25a2dfcd41be76675ec63f.zip","downloadURL":"/api/download/key/11b89","coverURL":"/cdn/11b89/48afdca736b315555b25a2dfcd41be76675ec63f.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":22,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":87.75,"length":21,"njs":19,"njsOffset":-0.30000001192092896,"bombs":0,"notes":564,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BearplayzTheBear","songAuthorName":"Drumset Fundamentals","songName":"6 Lane Vibro Challenge","songSubName":"6 Lane Vibro Challenge","bpm":250},"stats":{"downloads":171,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9338203,"rating":0},"description":"i hate myself","deletedAt":null,"_id":"5fcab3cda3f6d20006e04664","key":"11b88","name":"6 Lane Vibro Challenge (shit)","uploader":{"_id":"5e0258f79921260006595932","username":"bearplayzthebear"},"hash":"c027c02cdd36657d2b1e102995e13a753b67d26c","uploaded":"2020-12-04T22:10:21.914Z","directDownload":"/cdn/11b88/c027c02cdd36657d2b1e102995e13a753b67d26c.zip","downloadURL":"/api/download/key/11b88","coverURL":"/cdn/11b88/c027c02cdd36657d2b1e102995e13a753b67d26c.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":120,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":444.3733215332031,"length":119,"njs":23,"njsOffset":-0.20000000298023224,"bombs":0,"notes":2432,"obstacles":3},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Saint","songAuthorName":"Sound Source","songName":"Sayonara Planet Wars","songSubName":"","bpm":224},"stats":{"downloads":184,"plays":0,"downVotes":1,"upVotes":0,"heat":1821.9042648,"rating":0.40583635249095656},"description":"attempt at an almost impossible spiral map\n\nNJS: 23\nOffset: -0.2","deletedAt":null,"_id":"5fcaae9b029e550006a0fc4e","key":"11b86","name":"Sayonara Planet Wars","uploader":{"_id":"5e51fe01c0d5b30006b976ec","username":"wdg_saint"},"hash":"eb889c31aa4ce0cd5f0072d2569cf0ea30c258db","uploaded":"2020-12-04T21:48:11.914Z","directDownload":"/cdn/11b86/eb889c31aa4ce0cd5f0072d2569cf0ea30c258db.zip","downloadURL":"/api/download/key/11b86","coverURL":"/cdn/11b86/eb889c31aa4ce0cd5f0072d2569cf0ea30c258db.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":160,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":291,"length":157,"njs":16,"njsOffset":0,"bombs":0,"notes":745,"obstacles":12},"expertPlus":{"duration":291.0625,"length":157,"njs":18,"njsOffset":0.20000000298023224,"bombs":0,"notes":804,"obstacles":11},"hard":{"duration":291,"length":157,"njs":14,"njsOffset":0,"bombs":0,"notes":677,"obstacles":11},"normal":null},"name":"Standard"}],"levelAuthorName":"Alekcake","songAuthorName":"Blackpink ft. Cardi B","songName":"Bet You Wanna","songSubName":"","bpm":111},"stats":{"downloads":283,"plays":0,"downVotes":1,"upVotes":3,"heat":1822.1947366,"rating":0.5959970389138742},"description":"Gameplay here!: https://youtu.be/umSrHBLZxeg\r\n\r\nSpecial thanks to noxn and todai for helping us with the map!\r\nAlso thank you to our playtesters, pezzax and TinkerVr85!\r\n\r\nNPS \r\nExpert+: 4.99\r\nExpert: 4.63\r\nHard: 4.2\r\n\r\nCustom Map GIVEAWAY each month on our discord server!\r\nHow to enter: Subscribe to our YouTube channel through the gameplay link, join our discord server and pick the \"Giveaway\" role and you'll be entered!\r\nAlekcake's Kpop & VR Arcade - https://discord.gg/P4eGh26","deletedAt":null,"_id":"5fcaacc0029e550006a0f9f8","key":"11b85","name":"Blackpink ft Cardi B - Bet You Wanna","uploader":{"_id":"5ee6cc499ae7380006da7aef","username":"alekcake"},"hash":"9bdf6e59503b5081ca726c9c0c1d71808f6580ab","uploaded":"2020-12-04T21:40:16.795Z","directDownload":"/cdn/11b85/9bdf6e59503b5081ca726c9c0c1d71808f6580ab.zip","downloadURL":"/api/download/key/11b85","coverURL":"/cdn/11b85/9bdf6e59503b5081ca726c9c0c1d71808f6580ab.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":223,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":20.29413414001465,"length":8,"njs":18,"njsOffset":0,"bombs":0,"notes":30,"obstacles":0},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"MappersOfFun","songAuthorName":"Renault Clio","songName":"Toujours Debout","songSubName":"","bpm":139},"stats":{"downloads":170,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.8899085,"rating":0},"description":"Alors","deletedAt":null,"_id":"5fcaac15029e550006a0f925","key":"11b84","name":"Toujours Debout","uploader":{"_id":"5cff0b7898cc5a672c856c61","username":"mappersoffun"},"hash":"a4a921dbe8004cac5cc7529104324c765cca836f","uploaded":"2020-12-04T21:37:25.882Z","directDownload":"/cdn/11b84/a4a921dbe8004cac5cc7529104324c765cca836f.zip","downloadURL":"/api/download/key/11b84","coverURL":"/cdn/11b84/a4a921dbe8004cac5cc7529104324c765cca836f.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":240,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":428,"length":237,"njs":10,"njsOffset":0,"bombs":0,"notes":307,"obstacles":0},"expert":{"duration":428,"length":237,"njs":16,"njsOffset":0,"bombs":324,"notes":928,"obstacles":58},"expertPlus":{"duration":428,"length":237,"njs":18,"njsOffset":0,"bombs":0,"notes":1276,"obstacles":0},"hard":{"duration":428,"length":237,"njs":14,"njsOffset":0,"bombs":0,"notes":829,"obstacles":34},"normal":{"duration":428,"length":237,"njs":12,"njsOffset":0,"bombs":0,"notes":639,"obstacles":14}},"name":"Standard"}],"levelAuthorName":"Zardoz Xerxes","songAuthorName":"Mr. Scruff","songName":"Fish","songSubName":"","bpm":107.91200256347656},"stats":{"downloads":187,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.8610691,"rating":0},"description":"Length: 4:00\r\nBPM: 107.912\r\nDifficulty: Easy, Normal, Hard, Expert, Expert Plus.\r\nNPS: 1.28, 2.66, 3.45, 3.88, 5.31\r\n\r\nKudos if you beat Expert Plus!\r\n\r\nThis is an update, I fixed some irregularities in Expert mode & tweaked it a little.\r\nThis version only affects Expert mode, I uploaded it as a second version so you can keep the original if you like. \r\n\r\nIf you don't use Expert mode or don't care about the irregularities (they were fairly pedantic) then you don't need to bother with this version.","deletedAt":null,"_id":"5fcaa704029e550006a0f2f8","key":"11b83","name":"Fish V2","uploader":{"_id":"5fc9480e607cfe0006926f59","username":"zardoz_xerxes"},"hash":"89b09614896ac57bc8cbd7b8098b884ca03f9672","uploaded":"2020-12-04T21:15:48.110Z","directDownload":"/cdn/11b83/89b09614896ac57bc8cbd7b8098b884ca03f9672.zip","downloadURL":"/api/download/key/11b83","coverURL":"/cdn/11b83/89b09614896ac57bc8cbd7b8098b884ca03f9672.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":true},"duration":225,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":608,"length":214,"njs":24,"njsOffset":3,"bombs":4,"notes":1103,"obstacles":13},"hard":null,"normal":{"duration":552,"length":194,"njs":12,"njsOffset":2,"bombs":0,"notes":386,"obstacles":0}},"name":"Standard"}],"levelAuthorName":"Phoenix 5","songAuthorName":"Darren Styles","songName":"Sky Is Falling","songSubName":"(Monstercat Release)","bpm":170},"stats":{"downloads":229,"plays":0,"downVotes":1,"upVotes":0,"heat":1821.8414997,"rating":0.40583635249095656},"description":"BPM: 170\nDifficulties: Normal, Expert+\n\nNormal:\nNotes: 386\nBombs: 0\nWalls: 0\nSBO: 2\nNJS: 12\n\nExpert+ :\nNotes: 1103\nBombs: 4\nWalls: 13\nSBO: 3\nNJS: 24\n\nThis level has been an amazing journey to work on, and I hope everyone enjoys it!\n\n-Phoenix","deletedAt":null,"_id":"5fcaa3939370580009f58f7b","key":"11b82","name":"Sky is Falling","uploader":{"_id":"5fc950cdd2a4c2000633b23b","username":"phoenix 5"},"hash":"c0f554c3e45d4a94433cde5307ec206dcc98231e","uploaded":"2020-12-04T21:01:07.487Z","directDownload":"/cdn/11b82/c0f554c3e45d4a94433cde5307ec206dcc98231e.zip","downloadURL":"/api/download/key/11b82","coverURL":"/cdn/11b82/c0f554c3e45d4a94433cde5307ec206dcc98231e.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":309,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":927.25,"length":299,"njs":27,"njsOffset":3,"bombs":4,"notes":1582,"obstacles":4},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Phoenix 5","songAuthorName":"ILLENIUM","songName":"Crawl Outta Love","songSubName":"(The Glitch Mob Remix)","bpm":186},"stats":{"downloads":220,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.8355849,"rating":0},"description":"BPM: 186\nDifficulties: Expert+\n\nExpert+ :\nNotes: 1582\nBombs: 4\nWalls: 4\nSBO: 3\nNJS: 27\n\nI designed this level to be both Techy and Speedy (To the best of my ability) with an over 5 minute run time, this level is an ultimate gauntlet.\n\n-Phoenix","deletedAt":null,"_id":"5fcaa289029e550006a0ed59","key":"11b81","name":"Crawl Outta Love (The Glitch Mob Remix)","uploader":{"_id":"5fc950cdd2a4c2000633b23b","username":"phoenix 5"},"hash":"abba4e22bf3eb1f2ce14814d8c79a875bf4c17cc","uploaded":"2020-12-04T20:56:41.321Z","directDownload":"/cdn/11b81/abba4e22bf3eb1f2ce14814d8c79a875bf4c17cc.zip","downloadURL":"/api/download/key/11b81","coverURL":"/cdn/11b81/abba4e22bf3eb1f2ce14814d8c79a875bf4c17cc.jpg"}],"totalDocs":36015,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
+    fn test_playlists_by_user() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("users/id/4285984/playlists").unwrap(),
+            playlist_page_json(&[1, 1234]),
+        );
+        let playlists = client.playlists_by_user(4285984).unwrap();
         assert_eq!(
-            client
-                .maps_latest()
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "11bb1".to_string(),
-                "11bb0".to_string(),
-                "11baf".to_string(),
-                "11bad".to_string(),
-                "11bac".to_string(),
-                "11baa".to_string(),
-                "11ba5".to_string(),
-                "11ba4".to_string(),
-                "11ba3".to_string(),
-                "11b9e".to_string(),
-                "11b9d".to_string(),
-                "11b9b".to_string(),
-                "11b98".to_string(),
-                "11b91".to_string(),
-                "11b90".to_string(),
-                "11b8f".to_string(),
-                "11b8e".to_string(),
-                "11b8d".to_string(),
-                "11b8c".to_string(),
-                "11b8b".to_string(),
-                "11b8b".to_string(),
-                "11b8a".to_string(),
-                "11b89".to_string(),
-                "11b88".to_string(),
-                "11b86".to_string(),
-                "11b85".to_string(),
-                "11b84".to_string(),
-                "11b83".to_string(),
-                "11b82".to_string(),
-                "11b81".to_string()
-            ]
+            playlists.iter().map(|p| p.id).collect::<Vec<usize>>(),
+            vec![1, 1234]
         );
     }
     #[test]
-    fn test_maps_latest_page() {
-        let client = FakeClient::new(BEATSAVER_URL.join("api/maps/latest/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":false,"hard":true,"normal":false},"duration":145,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":null,"hard":{"duration":242.9824981689453,"length":138,"njs":14,"njsOffset":0,"bombs":0,"notes":394,"obstacles":70},"normal":null},"name":"Standard"}],"levelAuthorName":"LoveLessDark","songAuthorName":"Lady Gaga","songName":"Summerboy","songSubName":"(Short Ver.)","bpm":105},"stats":{"downloads":164,"plays":0,"downVotes":0,"upVotes":0,"heat":1813.1443116,"rating":0},"description":"Lady Gaga - Summerboy (Short Ver.)\nPreview : https://www.bilibili.com/video/BV1az4y1k7xb/","deletedAt":null,"_id":"5fc4aac69370580009f0ccd9","key":"11875","name":"Lady Gaga - Summerboy (Short Ver.)","uploader":{"_id":"5f26d1f3103cbc0006643d54","username":"lovelessdark"},"hash":"30c3d2ab8918386133cc0373076e7c4211679adb","uploaded":"2020-11-30T08:18:14.022Z","directDownload":"/cdn/11875/30c3d2ab8918386133cc0373076e7c4211679adb.zip","downloadURL":"/api/download/key/11875","coverURL":"/cdn/11875/30c3d2ab8918386133cc0373076e7c4211679adb.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":176,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":275.7170104980469,"length":167,"njs":14,"njsOffset":0,"bombs":0,"notes":512,"obstacles":19},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"LoveLessDark","songAuthorName":"Lady Gaga","songName":"Paper Gangsta","songSubName":"(Short Ver.)","bpm":99},"stats":{"downloads":154,"plays":0,"downVotes":0,"upVotes":0,"heat":1813.1440152,"rating":0},"description":"Lady Gaga - Paper Gangsta (Short Ver.)\nPreview : https://www.bilibili.com/video/BV1jr4y1c7zx/","deletedAt":null,"_id":"5fc4aab8029e5500069c27f4","key":"11874","name":"Lady Gaga - Paper Gangsta (Short Ver.)","uploader":{"_id":"5f26d1f3103cbc0006643d54","username":"lovelessdark"},"hash":"18a4a22da3350fd5a6640630e8ef79b80d8b2148","uploaded":"2020-11-30T08:18:00.686Z","directDownload":"/cdn/11874/18a4a22da3350fd5a6640630e8ef79b80d8b2148.zip","downloadURL":"/api/download/key/11874","coverURL":"/cdn/11874/18a4a22da3350fd5a6640630e8ef79b80d8b2148.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":false,"hard":false,"normal":true},"duration":213,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":448,"length":210,"njs":12,"njsOffset":0,"bombs":14,"notes":544,"obstacles":16},"expertPlus":null,"hard":null,"normal":{"duration":448,"length":210,"njs":12,"njsOffset":0,"bombs":4,"notes":267,"obstacles":9}},"name":"Standard"},{"difficulties":{"easy":{"duration":448,"length":210,"njs":12,"njsOffset":0,"bombs":4,"notes":215,"obstacles":9},"expert":null,"expertPlus":null,"hard":null,"normal":null},"name":"NoArrows"}],"levelAuthorName":"salmon","songAuthorName":"T-ara","songName":"Number 9","songSubName":"","bpm":128},"stats":{"downloads":202,"plays":0,"downVotes":2,"upVotes":0,"heat":1812.6622793,"rating":0.35920485264517465},"description":"My first map! Please let me know what I can do better, which aspects you liked, etc. I tried to do multiple difficulties, but am not sure I'm gauging them appropriately.","deletedAt":null,"_id":"5fc48af4d2a4c200062ffd96","key":"11873","name":"T-ara [티아라] - Number 9","uploader":{"_id":"5fc489bdb05fe700068cdd71","username":"salmon2"},"hash":"8c312e523e6d5498e60111fbad86c47b41fecdb8","uploaded":"2020-11-30T06:02:28.919Z","directDownload":"/cdn/11873/8c312e523e6d5498e60111fbad86c47b41fecdb8.zip","downloadURL":"/api/download/key/11873","coverURL":"/cdn/11873/8c312e523e6d5498e60111fbad86c47b41fecdb8.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":271,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":1149.875,"length":269,"njs":22,"njsOffset":0.100000001490116,"bombs":474,"notes":2977,"obstacles":124},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Atomosophere","songAuthorName":"Camellia","songName":"Blackmagik Blazing","songSubName":"","bpm":256},"stats":{"downloads":196,"plays":0,"downVotes":2,"upVotes":0,"heat":1812.6597046,"rating":0.35920485264517465},"description":"so I reupped this due to concern about the bombs\n\nI swear, the way they are now it's just like playing a normal double stream\nswing the same\nyou will be fine\nI promise\n\nanyway jammer going for ranked SmileW","deletedAt":null,"_id":"5fc48a81029e5500069c1754","key":"11872","name":"Blackmagik Blazing - かめりあ","uploader":{"_id":"5ddeae138d832b000680e4ec","username":"atomosophere"},"hash":"38dbb1c74daac4086636169b55b791f85018bd1e","uploaded":"2020-11-30T06:00:33.055Z","directDownload":"/cdn/11872/38dbb1c74daac4086636169b55b791f85018bd1e.zip","downloadURL":"/api/download/key/11872","coverURL":"/cdn/11872/38dbb1c74daac4086636169b55b791f85018bd1e.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":219,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":349.5,"length":209,"njs":22,"njsOffset":0.20000000298023224,"bombs":0,"notes":956,"obstacles":75},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"8-bit bulldog","songAuthorName":"Porter Robinson & Madeon","songName":"Shelter","songSubName":"","bpm":100},"stats":{"downloads":247,"plays":0,"downVotes":0,"upVotes":2,"heat":1813.2591647,"rating":0.6407951473548253},"description":"Just a fun map to vibe with, nothing too crazy or difficult. I think its a nice benchmark on my progress as a mapper in general\n\nNotes: 956\nBombs: 0\nWalls: 75\nNotes Per Second: 4.35\nNote Jump Speed: 22\nSpawn Offset: 0.2","deletedAt":null,"_id":"5fc48a0c607cfe00068ecd41","key":"11871","name":"Shelter","uploader":{"_id":"5ec8794f3f476a000685621e","username":"8-bit bulldog"},"hash":"2d212c53f7354f02e788531a6271e109c0056417","uploaded":"2020-11-30T05:58:36.061Z","directDownload":"/cdn/11871/2d212c53f7354f02e788531a6271e109c0056417.zip","downloadURL":"/api/download/key/11871","coverURL":"/cdn/11871/2d212c53f7354f02e788531a6271e109c0056417.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":289,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":1568.75,"length":280,"njs":24,"njsOffset":-1.5,"bombs":33,"notes":3291,"obstacles":719},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"landify","songAuthorName":"Camellia as \"menaXe inXonnu\"","songName":"Xronial Xero","songSubName":"(Laur Remix)","bpm":335},"stats":{"downloads":232,"plays":0,"downVotes":0,"upVotes":0,"heat":1812.9580276,"rating":0},"description":"Heavily inspired by altrewins Xronial Xero\n\nTook some walls from altrewins Xronial Xero\n\nFeel free to leave me feedback on discord:\n@landify#1818","deletedAt":null,"_id":"5fc48a07029e5500069c16ef","key":"11870","name":"Xronial Xero (Laur Remix)","uploader":{"_id":"5f614224530c8e000639ee58","username":"landify"},"hash":"c87ea9470547fb1aa5676d9c1095b1933e914f3c","uploaded":"2020-11-30T05:58:31.244Z","directDownload":"/cdn/11870/c87ea9470547fb1aa5676d9c1095b1933e914f3c.zip","downloadURL":"/api/download/key/11870","coverURL":"/cdn/11870/c87ea9470547fb1aa5676d9c1095b1933e914f3c.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":161,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":618,"length":161,"njs":23,"njsOffset":-0.30000001192092896,"bombs":45,"notes":1811,"obstacles":39},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"cerret","songAuthorName":"Kobaryo","songName":"Speedcore Psytama","songSubName":"","bpm":230},"stats":{"downloads":332,"plays":0,"downVotes":0,"upVotes":5,"heat":1813.4831551,"rating":0.7084432256108616},"description":"mapped in 2 and a half hours lol\nfunny ending\n","deletedAt":null,"_id":"5fc46b78d2a4c200062fe4b7","key":"1186c","name":"Kobaryo - Speedcore Psytama","uploader":{"_id":"5cff0b7698cc5a672c8541e1","username":"cerret"},"hash":"d352dcc8c2f0c0ce7cbef9f1df691ef05603c47c","uploaded":"2020-11-30T03:48:08.331Z","directDownload":"/cdn/1186c/d352dcc8c2f0c0ce7cbef9f1df691ef05603c47c.zip","downloadURL":"/api/download/key/1186c","coverURL":"/cdn/1186c/d352dcc8c2f0c0ce7cbef9f1df691ef05603c47c.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":false,"expertPlus":false,"hard":false,"normal":false},"duration":5,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":11.015625,"length":5,"njs":20,"njsOffset":0,"bombs":0,"notes":19,"obstacles":0},"expert":null,"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"zbolt","songAuthorName":"zboltlightning","songName":"facts","songSubName":"","bpm":120},"stats":{"downloads":259,"plays":0,"downVotes":0,"upVotes":0,"heat":1812.715026,"rating":0},"description":"lol","deletedAt":null,"_id":"5fc45f509370580009f0985e","key":"11850","name":"its true","uploader":{"_id":"5f655ad24bf28e00061ce4f0","username":"zboltlightning"},"hash":"d1188ff5d4046c75950fd513cd4582d3376317fb","uploaded":"2020-11-30T02:56:16.172Z","directDownload":"/cdn/11850/d1188ff5d4046c75950fd513cd4582d3376317fb.zip","downloadURL":"/api/download/key/11850","coverURL":"/cdn/11850/d1188ff5d4046c75950fd513cd4582d3376317fb.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":85,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":270.1250305175781,"length":81,"njs":22,"njsOffset":-0.20000000298023224,"bombs":95,"notes":414,"obstacles":34},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"thelitlewiseowl","songAuthorName":"Hatsuki Yura","songName":"Dragon Slayer -Koku Ryuu no Kishi hen-","songSubName":"","bpm":198},"stats":{"downloads":278,"plays":0,"downVotes":1,"upVotes":3,"heat":1813.0054291,"rating":0.5959970389138742},"description":"Somewhat short, hope you enjoy","deletedAt":null,"_id":"5fc45d71a3f6d20006db43df","key":"1184a","name":"Dragon Slayer Koku Ryuu no Kishi hen | Hatsuki Yura","uploader":{"_id":"5cff0b7698cc5a672c854bca","username":"thelitlewiseowl"},"hash":"ff1ce0efaecf5b3ba8b7409038fa0088df16110c","uploaded":"2020-11-30T02:48:17.960Z","directDownload":"/cdn/1184a/ff1ce0efaecf5b3ba8b7409038fa0088df16110c.zip","downloadURL":"/api/download/key/1184a","coverURL":"/cdn/1184a/ff1ce0efaecf5b3ba8b7409038fa0088df16110c.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":288,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":618.5,"length":285,"njs":20,"njsOffset":0.5,"bombs":8,"notes":1115,"obstacles":20},"expertPlus":null,"hard":{"duration":618.5,"length":285,"njs":18,"njsOffset":-0.4000000059604645,"bombs":8,"notes":1091,"obstacles":20},"normal":null},"name":"Standard"}],"levelAuthorName":"thelitlewiseowl","songAuthorName":"DECO 27","songName":"Once Upon a Me","songSubName":"","bpm":130},"stats":{"downloads":358,"plays":0,"downVotes":0,"upVotes":5,"heat":1813.4004346,"rating":0.7084432256108616},"description":"むかしむかしのきょうのぼく (Mukashi Mukashi no Kyou no Boku)\nby DECO*27\n\n","deletedAt":null,"_id":"5fc45ced9370580009f09592","key":"11849","name":"Once upon a me | DECO*27","uploader":{"_id":"5cff0b7698cc5a672c854bca","username":"thelitlewiseowl"},"hash":"d7369741299c77c6859aa5c4058f4a696f482f75","uploaded":"2020-11-30T02:46:05.909Z","directDownload":"/cdn/11849/d7369741299c77c6859aa5c4058f4a696f482f75.zip","downloadURL":"/api/download/key/11849","coverURL":"/cdn/11849/d7369741299c77c6859aa5c4058f4a696f482f75.jpg"}],"totalDocs":35794,"lastPage":3579,"prevPage":1,"nextPage":3}"#.into());
-        let _: Page<Map> = client.maps_latest_page(2).unwrap();
+    fn test_votes_since() {
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join("vote?since=2021-06-01T12%3A00%3A00Z")
+                .unwrap(),
+            r#"[{"hash":"0000000000000000000000000000000000002144","mapId":8516,"upvotes":10,"downvotes":1,"score":0.8}]"#.into(),
+        );
+        let since = "2021-06-01T12:00:00Z".parse().unwrap();
+        let votes = client.votes_since(since).unwrap();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].0, "2144".try_into().unwrap());
+        assert_eq!(votes[0].1.upvotes, 10);
+    }
+    #[test]
+    fn test_oauth() {
+        let config = OAuthConfig::new("id", "secret", "http://localhost/callback");
+        let client = FakeClient::new(
+            TOKEN_URL.clone(),
+            r#"{"access_token":"abc","token_type":"Bearer","expires_in":3600,"refresh_token":"def","scope":"identity"}"#.into(),
+        );
+        let token = client.oauth_exchange(&config, "code").unwrap();
+        assert_eq!(token.access_token, "abc");
+        let token = client.oauth_refresh(&config, &token).unwrap();
+        assert_eq!(token.refresh_token, Some("def".into()));
     }
     #[test]
-    fn test_maps_latest_page_iter() {
+    fn test_identity() {
+        let mut client = FakeClient::new(
+            BEATSAVER_URL.join("users/me").unwrap(),
+            r#"{"id":4285984,"name":"qwerty01","avatar":"https://cdn.beatsaver.com/avatar/4285984.png"}"#.into(),
+        );
+        assert!(!client.authenticated());
+        assert!(matches!(
+            client.identity(),
+            Err(BeatSaverApiError::AuthenticationRequired)
+        ));
+        client.credentials = Some(Credentials::Bearer("abc".into()));
+        assert_eq!(client.identity().unwrap().id, 4285984);
+    }
+    #[test]
+    fn test_maps_latest() {
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/latest/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":199,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":303,"length":196,"njs":20,"njsOffset":0,"bombs":0,"notes":757,"obstacles":0},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"cookie","songAuthorName":"Hollywood Undead","songName":"Bullet","songSubName":"","bpm":92.5},"stats":{"downloads":139,"plays":0,"downVotes":0,"upVotes":3,"heat":1822.5865456,"rating":0.6705937099936721},"description":"An upbeat song from Hollywood Undead that's about suicide.\r\nExpert only with custom lighting!","deletedAt":null,"_id":"5fcad2aca3f6d20006e06575","key":"11b9d","name":"Hollywood Undead - Bullet","uploader":{"_id":"5d1356c9f8b4040007dcb9fd","username":"cookiesaber"},"hash":"590ffb06d96eda325df735667baf0553d88cb8e0","uploaded":"2020-12-05T00:22:04.095Z","directDownload":"/cdn/11b9d/590ffb06d96eda325df735667baf0553d88cb8e0.zip","downloadURL":"/api/download/key/11b9d","coverURL":"/cdn/11b9d/590ffb06d96eda325df735667baf0553d88cb8e0.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":471,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":2672.249267578125,"length":471,"njs":23,"njsOffset":-1.399999976158142,"bombs":1,"notes":5080,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Lolighter","songAuthorName":"Sewerslvt","songName":"Mr. Kill Myself","songSubName":"","bpm":340},"stats":{"downloads":123,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.1002815,"rating":0},"description":"funny lolighter map","deletedAt":null,"_id":"5fcad110d2a4c2000635047d","key":"11b9b","name":"M.K.MS","uploader":{"_id":"5cff0b7698cc5a672c854a7f","username":"wh4t"},"hash":"2fea40d139432aaa7b4bb1c5e46309c92dda2179","uploaded":"2020-12-05T00:15:12.667Z","directDownload":"/cdn/11b9b/2fea40d139432aaa7b4bb1c5e46309c92dda2179.zip","downloadURL":"/api/download/key/11b9b","coverURL":"/cdn/11b9b/2fea40d139432aaa7b4bb1c5e46309c92dda2179.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":192,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":403.25,"length":192,"njs":12,"njsOffset":0,"bombs":22,"notes":434,"obstacles":9},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Xpoiled","songAuthorName":"Distrion","songName":"Distrion Alibi (ft. heelen","songSubName":"Distrion","bpm":126},"stats":{"downloads":137,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0699933,"rating":0},"description":"My first beatmap enjoy follow for more. make sure to have decent play space as ther is 1 spot wher ther is a three wide wall","deletedAt":null,"_id":"5fcacbbda3f6d20006e05db1","key":"11b98","name":"Distrion - Alibi (Ft. Heleen)","uploader":{"_id":"5fc97909b05fe7000690be4e","username":"xpoled"},"hash":"b011aa10021f1aaaca714dc4ae0b176c71558247","uploaded":"2020-12-04T23:52:29.700Z","directDownload":"/cdn/11b98/b011aa10021f1aaaca714dc4ae0b176c71558247.zip","downloadURL":"/api/download/key/11b98","coverURL":"/cdn/11b98/b011aa10021f1aaaca714dc4ae0b176c71558247.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":195,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":449.2485046386719,"length":189,"njs":18,"njsOffset":-0.20000000298023224,"bombs":54,"notes":756,"obstacles":186},"expertPlus":{"duration":456.2485046386719,"length":192,"njs":20,"njsOffset":-0.4000000059604645,"bombs":0,"notes":1153,"obstacles":21},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Matt","songAuthorName":"Daniel Ingram, Caleb Chan, Michael Kovach","songName":"I've Had Enough of You","songSubName":"Fantoccio boss theme","bpm":142},"stats":{"downloads":155,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.0268422,"rating":0.5941636475090435},"description":"Woah, a map with two difficulties? Wac. Anyway descriptions of both:\r\nDance Time - The lighter of the difficulties, uses some less standard patterns, I tried to emulate a couple of the dances that happen during the fight.\r\nEx+ - A more standard style, though I tried to use some more variety and I think it turned out fairly well.","deletedAt":null,"_id":"5fcac427607cfe000693b71e","key":"11b91","name":"I've Had Enough of You","uploader":{"_id":"5e0158a0d89bd20006d5fb30","username":"matt"},"hash":"3900830997a659e0e0ac91e17a428a9a1625fc5f","uploaded":"2020-12-04T23:20:07.897Z","directDownload":"/cdn/11b91/3900830997a659e0e0ac91e17a428a9a1625fc5f.zip","downloadURL":"/api/download/key/11b91","coverURL":"/cdn/11b91/3900830997a659e0e0ac91e17a428a9a1625fc5f.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":206,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":403.125,"length":201,"njs":16,"njsOffset":0,"bombs":0,"notes":841,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ASharpBlade","songAuthorName":"NateWantsToBattle","songName":"Manipulate","songSubName":"","bpm":120},"stats":{"downloads":170,"plays":0,"downVotes":0,"upVotes":2,"heat":1822.3136479,"rating":0.6407951473548253},"description":"This song is By NatewantstoBattle. This is the 2nd song I did from him.","deletedAt":null,"_id":"5fcac1a7029e550006a10ecf","key":"11b90","name":"Manipulate","uploader":{"_id":"5fc67ef1b05fe700068e46ab","username":"asharpblade"},"hash":"f9cf3a19853b803d78cd327e12dc5c335b5acbfc","uploaded":"2020-12-04T23:09:27.806Z","directDownload":"/cdn/11b90/f9cf3a19853b803d78cd327e12dc5c335b5acbfc.zip","downloadURL":"/api/download/key/11b90","coverURL":"/cdn/11b90/f9cf3a19853b803d78cd327e12dc5c335b5acbfc.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":270,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":555.5,"length":260,"njs":12,"njsOffset":0,"bombs":0,"notes":1345,"obstacles":3},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"MegaHUstar","songAuthorName":"Rocco Vanwell","songName":"Big Bad Santa - Rocco Vanwell","songSubName":"Big Bad Santa","bpm":128},"stats":{"downloads":157,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0031818,"rating":0},"description":"First map gave a heart to it, but lightning part is too strobe effect so who has it please help yourself","deletedAt":null,"_id":"5fcabfff607cfe000693b373","key":"11b8f","name":"Rocco Vanwell - Big Bad Santa ","uploader":{"_id":"5fcabeb2d2a4c2000634f115","username":"petruska"},"hash":"fcf44786a3de8efc9e7411f26e447c37c4da7574","uploaded":"2020-12-04T23:02:23.180Z","directDownload":"/cdn/11b8f/fcf44786a3de8efc9e7411f26e447c37c4da7574.zip","downloadURL":"/api/download/key/11b8f","coverURL":"/cdn/11b8f/fcf44786a3de8efc9e7411f26e447c37c4da7574.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":111,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":175.5,"length":108,"njs":18,"njsOffset":0,"bombs":0,"notes":624,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Kpop Discord","songAuthorName":"Agust D, BTS, Dawin","songName":"What Do You Think?","songSubName":"(About That Dopessert)","bpm":97},"stats":{"downloads":153,"plays":0,"downVotes":0,"upVotes":0,"heat":1822.0030919,"rating":0},"description":"I just love being here honestly","deletedAt":null,"_id":"5fcabffb9370580009f5adeb","key":"11b8e","name":"What Do You Think? (About That Dopessert)","uploader":{"_id":"5f0419ec0ea71e0006780728","username":"smalldongstudios"},"hash":"4162744d4bd20e16335304dd2de548f01a7f6fca","uploaded":"2020-12-04T23:02:19.136Z","directDownload":"/cdn/11b8e/4162744d4bd20e16335304dd2de548f01a7f6fca.zip","downloadURL":"/api/download/key/11b8e","coverURL":"/cdn/11b8e/4162744d4bd20e16335304dd2de548f01a7f6fca.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":14,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":31.25,"length":13,"njs":22,"njsOffset":0,"bombs":0,"notes":47,"obstacles":0},"expertPlus":{"duration":32,"length":14,"njs":22,"njsOffset":0,"bombs":110,"notes":70,"obstacles":4},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BungiCraft","songAuthorName":"Imagine Dragons","songName":"\"Eggman\"","songSubName":"","bpm":136},"stats":{"downloads":165,"plays":0,"downVotes":0,"upVotes":1,"heat":1822.0029829,"rating":0.5941636475090435},"description":"This is a shitpost. It is really bad. Play it to suffer :)","deletedAt":null,"_id":"5fcabff69370580009f5ade7","key":"11b8d","name":"\"Eggman\"","uploader":{"_id":"5fca7071029e550006a0b1a8","username":"bungicraft"},"hash":"4e26756e379841dd6e4897d8bfe80e2a1623e2e1","uploaded":"2020-12-04T23:02:14.229Z","directDownload":"/cdn/11b8d/4e26756e379841dd6e4897d8bfe80e2a1623e2e1.zip","downloadURL":"/api/download/key/11b8d","coverURL":"/cdn/11b8d/4e26756e379841dd6e4897d8bfe80e2a1623e2e1.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":277,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":202.5576629638672,"length":89,"njs":19,"njsOffset":-0.20000000298023224,"bombs":0,"notes":511,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Cratonugget","songAuthorName":"Camilia","songName":"Arcology on Permafrost","songSubName":"","bpm":136},"stats":{"downloads":200,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9524506,"rating":0},"description":"","deletedAt":null,"_id":"5fcab714b05fe7000691cd7c","key":"11b8c","name":"Camilia - Arcology on Permafrost ver 4","uploader":{"_id":"5f8e0d20e19f180007e17c6a","username":"cratornugg"},"hash":"1339a367a666dfcf86e9b3796ad1385f8fb47f8a","uploaded":"2020-12-04T22:24:20.275Z","directDownload":"/cdn/11b8c/1339a367a666dfcf86e9b3796ad1385f8fb47f8a.zip","downloadURL":"/api/download/key/11b8c","coverURL":"/cdn/11b8c/1339a367a666dfcf86e9b3796ad1385f8fb47f8a.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":32,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":128.57183837890625,"length":32,"njs":25,"njsOffset":0.6000000238418579,"bombs":17,"notes":2998,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"fuck","songAuthorName":"engineer gaming","songName":"HEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAHHH","songSubName":"Engineer!!!!!!","bpm":240},"stats":{"downloads":189,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9513778,"rating":0},"description":"HEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHHHH","deletedAt":null,"_id":"5fcab6e3b05fe7000691cd37","key":"11b8b","name":"Engineer Hey Ya","uploader":{"_id":"5f8a6a7f9f3df7000863cac3","username":"joen"},"hash":"93daccae82f9dd85881f8b309210ce432cd602aa","uploaded":"2020-12-04T22:23:31.999Z","directDownload":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.zip","downloadURL":"/api/download/key/11b8b","coverURL":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/latest/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":32,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":128.57183837890625,"length":32,"njs":25,"njsOffset":0.6000000238418579,"bombs":17,"notes":2998,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"fuck","songAuthorName":"engineer gaming","songName":"HEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAHHH","songSubName":"Engineer!!!!!!","bpm":240},"stats":{"downloads":189,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9513778,"rating":0},"description":"HEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHHHH","deletedAt":null,"_id":"5fcab6e3b05fe7000691cd37","key":"11b8b","name":"Engineer Hey Ya","uploader":{"_id":"5f8a6a7f9f3df7000863cac3","username":"joen"},"hash":"93daccae82f9dd85881f8b309210ce432cd602aa","uploaded":"2020-12-04T22:23:31.999Z","directDownload":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.zip","downloadURL":"/api/download/key/11b8b","coverURL":"/cdn/11b8b/93daccae82f9dd85881f8b309210ce432cd602aa.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":260,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":827.4833374023438,"length":248,"njs":21,"njsOffset":0,"bombs":16,"notes":2764,"obstacles":258},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"ordinary09","songAuthorName":"xi","songName":"Blue Zenith","songSubName":"","bpm":200},"stats":{"downloads":231,"plays":0,"downVotes":0,"upVotes":2,"heat":1822.2381191,"rating":0.6407951473548253},"description":"i know there are already existing maps for this song, but i tried put my own twist on it where it doesnt only consist of stream patterns. some obscure patterns though. beware.\n\nreupload due to mapping error :(","deletedAt":null,"_id":"5fcab461d2a4c2000634e72c","key":"11b8a","name":"xi - Blue Zenith","uploader":{"_id":"5eafee9f7abb000006c2417c","username":"ordinary09"},"hash":"838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a","uploaded":"2020-12-04T22:12:49.008Z","directDownload":"/cdn/11b8a/838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a.zip","downloadURL":"/api/download/key/11b8a","coverURL":"/cdn/11b8a/838d3d5e3c5eff66c825b1098f8e1e2d93a19a4a.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":200,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":823.6616821289062,"length":190,"njs":24,"njsOffset":-0.10000000149011612,"bombs":23,"notes":2777,"obstacles":332},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Water","songAuthorName":"DJ Kurara","songName":"Be My Extratone","songSubName":"","bpm":260},"stats":{"downloads":190,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9352852,"rating":0},"description":"520bpm jump map that requires a bit of stamina\nundermapped in some parts (things that should be notes mapped as walls) for stamina reasons\nty saint for playtest\nminion toy","deletedAt":null,"_id":"5fcab40fb05fe7000691ca64","key":"11b89","name":"DJ Kurara - Be My Extratone","uploader":{"_id":"5cff0b7698cc5a672c85513e","username":"waterbottle7"},"hash":"48afdca736b315555b25a2dfcd41be76675ec63f","uploaded":"2020-12-04T22:11:27.833Z","directDownload":"/cdn/11b89/48afdca736b315555b25a2dfcd41be76675ec63f.zip","downloadURL":"/api/download/key/11b89","coverURL":"/cdn/11b89/48afdca736b315555b25a2dfcd41be76675ec63f.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":22,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":87.75,"length":21,"njs":19,"njsOffset":-0.30000001192092896,"bombs":0,"notes":564,"obstacles":0},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BearplayzTheBear","songAuthorName":"Drumset Fundamentals","songName":"6 Lane Vibro Challenge","songSubName":"6 Lane Vibro Challenge","bpm":250},"stats":{"downloads":171,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.9338203,"rating":0},"description":"i hate myself","deletedAt":null,"_id":"5fcab3cda3f6d20006e04664","key":"11b88","name":"6 Lane Vibro Challenge (shit)","uploader":{"_id":"5e0258f79921260006595932","username":"bearplayzthebear"},"hash":"c027c02cdd36657d2b1e102995e13a753b67d26c","uploaded":"2020-12-04T22:10:21.914Z","directDownload":"/cdn/11b88/c027c02cdd36657d2b1e102995e13a753b67d26c.zip","downloadURL":"/api/download/key/11b88","coverURL":"/cdn/11b88/c027c02cdd36657d2b1e102995e13a753b67d26c.png"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":120,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":444.3733215332031,"length":119,"njs":23,"njsOffset":-0.20000000298023224,"bombs":0,"notes":2432,"obstacles":3},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Saint","songAuthorName":"Sound Source","songName":"Sayonara Planet Wars","songSubName":"","bpm":224},"stats":{"downloads":184,"plays":0,"downVotes":1,"upVotes":0,"heat":1821.9042648,"rating":0.40583635249095656},"description":"attempt at an almost impossible spiral map\n\nNJS: 23\nOffset: -0.2","deletedAt":null,"_id":"5fcaae9b029e550006a0fc4e","key":"11b86","name":"Sayonara Planet Wars","uploader":{"_id":"5e51fe01c0d5b30006b976ec","username":"wdg_saint"},"hash":"eb889c31aa4ce0cd5f0072d2569cf0ea30c258db","uploaded":"2020-12-04T21:48:11.914Z","directDownload":"/cdn/11b86/eb889c31aa4ce0cd5f0072d2569cf0ea30c258db.zip","downloadURL":"/api/download/key/11b86","coverURL":"/cdn/11b86/eb889c31aa4ce0cd5f0072d2569cf0ea30c258db.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":false},"duration":160,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":291,"length":157,"njs":16,"njsOffset":0,"bombs":0,"notes":745,"obstacles":12},"expertPlus":{"duration":291.0625,"length":157,"njs":18,"njsOffset":0.20000000298023224,"bombs":0,"notes":804,"obstacles":11},"hard":{"duration":291,"length":157,"njs":14,"njsOffset":0,"bombs":0,"notes":677,"obstacles":11},"normal":null},"name":"Standard"}],"levelAuthorName":"Alekcake","songAuthorName":"Blackpink ft. Cardi B","songName":"Bet You Wanna","songSubName":"","bpm":111},"stats":{"downloads":283,"plays":0,"downVotes":1,"upVotes":3,"heat":1822.1947366,"rating":0.5959970389138742},"description":"Gameplay here!: https://youtu.be/umSrHBLZxeg\r\n\r\nSpecial thanks to noxn and todai for helping us with the map!\r\nAlso thank you to our playtesters, pezzax and TinkerVr85!\r\n\r\nNPS \r\nExpert+: 4.99\r\nExpert: 4.63\r\nHard: 4.2\r\n\r\nCustom Map GIVEAWAY each month on our discord server!\r\nHow to enter: Subscribe to our YouTube channel through the gameplay link, join our discord server and pick the \"Giveaway\" role and you'll be entered!\r\nAlekcake's Kpop & VR Arcade - https://discord.gg/P4eGh26","deletedAt":null,"_id":"5fcaacc0029e550006a0f9f8","key":"11b85","name":"Blackpink ft Cardi B - Bet You Wanna","uploader":{"_id":"5ee6cc499ae7380006da7aef","username":"alekcake"},"hash":"9bdf6e59503b5081ca726c9c0c1d71808f6580ab","uploaded":"2020-12-04T21:40:16.795Z","directDownload":"/cdn/11b85/9bdf6e59503b5081ca726c9c0c1d71808f6580ab.zip","downloadURL":"/api/download/key/11b85","coverURL":"/cdn/11b85/9bdf6e59503b5081ca726c9c0c1d71808f6580ab.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":false,"normal":false},"duration":223,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":20.29413414001465,"length":8,"njs":18,"njsOffset":0,"bombs":0,"notes":30,"obstacles":0},"expertPlus":null,"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"MappersOfFun","songAuthorName":"Renault Clio","songName":"Toujours Debout","songSubName":"","bpm":139},"stats":{"downloads":170,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.8899085,"rating":0},"description":"Alors","deletedAt":null,"_id":"5fcaac15029e550006a0f925","key":"11b84","name":"Toujours Debout","uploader":{"_id":"5cff0b7898cc5a672c856c61","username":"mappersoffun"},"hash":"a4a921dbe8004cac5cc7529104324c765cca836f","uploaded":"2020-12-04T21:37:25.882Z","directDownload":"/cdn/11b84/a4a921dbe8004cac5cc7529104324c765cca836f.zip","downloadURL":"/api/download/key/11b84","coverURL":"/cdn/11b84/a4a921dbe8004cac5cc7529104324c765cca836f.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":240,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":428,"length":237,"njs":10,"njsOffset":0,"bombs":0,"notes":307,"obstacles":0},"expert":{"duration":428,"length":237,"njs":16,"njsOffset":0,"bombs":324,"notes":928,"obstacles":58},"expertPlus":{"duration":428,"length":237,"njs":18,"njsOffset":0,"bombs":0,"notes":1276,"obstacles":0},"hard":{"duration":428,"length":237,"njs":14,"njsOffset":0,"bombs":0,"notes":829,"obstacles":34},"normal":{"duration":428,"length":237,"njs":12,"njsOffset":0,"bombs":0,"notes":639,"obstacles":14}},"name":"Standard"}],"levelAuthorName":"Zardoz Xerxes","songAuthorName":"Mr. Scruff","songName":"Fish","songSubName":"","bpm":107.91200256347656},"stats":{"downloads":187,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.8610691,"rating":0},"description":"Length: 4:00\r\nBPM: 107.912\r\nDifficulty: Easy, Normal, Hard, Expert, Expert Plus.\r\nNPS: 1.28, 2.66, 3.45, 3.88, 5.31\r\n\r\nKudos if you beat Expert Plus!\r\n\r\nThis is an update, I fixed some irregularities in Expert mode & tweaked it a little.\r\nThis version only affects Expert mode, I uploaded it as a second version so you can keep the original if you like. \r\n\r\nIf you don't use Expert mode or don't care about the irregularities (they were fairly pedantic) then you don't need to bother with this version.","deletedAt":null,"_id":"5fcaa704029e550006a0f2f8","key":"11b83","name":"Fish V2","uploader":{"_id":"5fc9480e607cfe0006926f59","username":"zardoz_xerxes"},"hash":"89b09614896ac57bc8cbd7b8098b884ca03f9672","uploaded":"2020-12-04T21:15:48.110Z","directDownload":"/cdn/11b83/89b09614896ac57bc8cbd7b8098b884ca03f9672.zip","downloadURL":"/api/download/key/11b83","coverURL":"/cdn/11b83/89b09614896ac57bc8cbd7b8098b884ca03f9672.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":true},"duration":225,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":608,"length":214,"njs":24,"njsOffset":3,"bombs":4,"notes":1103,"obstacles":13},"hard":null,"normal":{"duration":552,"length":194,"njs":12,"njsOffset":2,"bombs":0,"notes":386,"obstacles":0}},"name":"Standard"}],"levelAuthorName":"Phoenix 5","songAuthorName":"Darren Styles","songName":"Sky Is Falling","songSubName":"(Monstercat Release)","bpm":170},"stats":{"downloads":229,"plays":0,"downVotes":1,"upVotes":0,"heat":1821.8414997,"rating":0.40583635249095656},"description":"BPM: 170\nDifficulties: Normal, Expert+\n\nNormal:\nNotes: 386\nBombs: 0\nWalls: 0\nSBO: 2\nNJS: 12\n\nExpert+ :\nNotes: 1103\nBombs: 4\nWalls: 13\nSBO: 3\nNJS: 24\n\nThis level has been an amazing journey to work on, and I hope everyone enjoys it!\n\n-Phoenix","deletedAt":null,"_id":"5fcaa3939370580009f58f7b","key":"11b82","name":"Sky is Falling","uploader":{"_id":"5fc950cdd2a4c2000633b23b","username":"phoenix 5"},"hash":"c0f554c3e45d4a94433cde5307ec206dcc98231e","uploaded":"2020-12-04T21:01:07.487Z","directDownload":"/cdn/11b82/c0f554c3e45d4a94433cde5307ec206dcc98231e.zip","downloadURL":"/api/download/key/11b82","coverURL":"/cdn/11b82/c0f554c3e45d4a94433cde5307ec206dcc98231e.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":309,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":null,"expertPlus":{"duration":927.25,"length":299,"njs":27,"njsOffset":3,"bombs":4,"notes":1582,"obstacles":4},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"Phoenix 5","songAuthorName":"ILLENIUM","songName":"Crawl Outta Love","songSubName":"(The Glitch Mob Remix)","bpm":186},"stats":{"downloads":220,"plays":0,"downVotes":0,"upVotes":0,"heat":1821.8355849,"rating":0},"description":"BPM: 186\nDifficulties: Expert+\n\nExpert+ :\nNotes: 1582\nBombs: 4\nWalls: 4\nSBO: 3\nNJS: 27\n\nI designed this level to be both Techy and Speedy (To the best of my ability) with an over 5 minute run time, this level is an ultimate gauntlet.\n\n-Phoenix","deletedAt":null,"_id":"5fcaa289029e550006a0ed59","key":"11b81","name":"Crawl Outta Love (The Glitch Mob Remix)","uploader":{"_id":"5fc950cdd2a4c2000633b23b","username":"phoenix 5"},"hash":"abba4e22bf3eb1f2ce14814d8c79a875bf4c17cc","uploaded":"2020-12-04T20:56:41.321Z","directDownload":"/cdn/11b81/abba4e22bf3eb1f2ce14814d8c79a875bf4c17cc.zip","downloadURL":"/api/download/key/11b81","coverURL":"/cdn/11b81/abba4e22bf3eb1f2ce14814d8c79a875bf4c17cc.jpg"}],"totalDocs":36015,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
+        pages.insert(
+            BEATSAVER_URL.join("maps/latest?automapper=false").unwrap(),
+            page_json(&["97d3", "65cb"]),
+        );
+        // The cursor moves to the upload time of the last map of the previous batch
+        pages.insert(
+            BEATSAVER_URL
+                .join("maps/latest?before=2021-06-01T12%3A00%3A00Z&automapper=false")
+                .unwrap(),
+            page_json(&[]),
+        );
         let client = FakeClientPaged::new(pages);
         assert_eq!(
             client
-                .maps_latest_page_iter(1)
-                .map(|m| m.unwrap().key)
+                .maps_latest(LatestOptions::new().with_automapper(false))
+                .map(|m| m.unwrap().id)
                 .collect::<Vec<String>>(),
-            vec![
-                "11b9d".to_string(),
-                "11b9b".to_string(),
-                "11b98".to_string(),
-                "11b91".to_string(),
-                "11b90".to_string(),
-                "11b8f".to_string(),
-                "11b8e".to_string(),
-                "11b8d".to_string(),
-                "11b8c".to_string(),
-                "11b8b".to_string(),
-                "11b8b".to_string(),
-                "11b8a".to_string(),
-                "11b89".to_string(),
-                "11b88".to_string(),
-                "11b86".to_string(),
-                "11b85".to_string(),
-                "11b84".to_string(),
-                "11b83".to_string(),
-                "11b82".to_string(),
-                "11b81".to_string()
-            ]
+            vec!["97d3".to_string(), "65cb".to_string()]
         );
     }
     #[test]
-    fn test_maps_downloads() {
+    fn test_maps_latest_pages() {
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/downloads/0").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":468,"length":212,"bombs":4,"notes":415,"obstacles":42,"njs":10,"njsOffset":0},"hard":{"duration":468,"length":212,"bombs":40,"notes":695,"obstacles":94,"njs":10,"njsOffset":0},"expert":{"duration":468,"length":212,"bombs":50,"notes":932,"obstacles":103,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Gangnam Style","songSubName":"PSY","songAuthorName":"GreatYazer","levelAuthorName":"greatyazer","bpm":132},"stats":{"downloads":1085834,"plays":82700,"downVotes":628,"upVotes":17751,"heat":41.512291,"rating":0.9415905977324845},"description":"Expert, Hard, and Normal tracks.  I tried my best to setup the chorus charts to allow you to mimic the classic dance moves.  I think it matches up quite nicely.  I hope you have as much fun playing as I did making this!  Enjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc620d","key":"141","name":"GANGNAM STYLE","uploader":{"_id":"5cff0b7298cc5a672c84ea71","username":"greatyazer"},"uploaded":"2018-05-20T09:59:02.000Z","hash":"8e7e553099436af31564adf1977a5ec42a61cfff","directDownload":"/cdn/141/8e7e553099436af31564adf1977a5ec42a61cfff.zip","downloadURL":"/api/download/key/141","coverURL":"/cdn/141/8e7e553099436af31564adf1977a5ec42a61cfff.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":418,"length":200,"bombs":0,"notes":546,"obstacles":10,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Believer","songSubName":"Imagine Dragons","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":125},"stats":{"downloads":1058605,"plays":70725,"downVotes":360,"upVotes":9543,"heat":18.9184513,"rating":0.9345851484445983},"description":"Currently expert only. Events included.","deletedAt":null,"_id":"5cff620c48229f7d88fc60e9","key":"b","name":"Imagine Dragons - Believer","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-05-08T18:56:36.000Z","hash":"19f2879d11a91b51a5c090d63471c3e8d9b7aee3","directDownload":"/cdn/b/19f2879d11a91b51a5c090d63471c3e8d9b7aee3.zip","downloadURL":"/api/download/key/b","coverURL":"/cdn/b/19f2879d11a91b51a5c090d63471c3e8d9b7aee3.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":328.556396484375,"length":142,"bombs":0,"notes":188,"obstacles":84,"njs":10,"njsOffset":0},"normal":{"duration":328.681396484375,"length":142,"bombs":40,"notes":219,"obstacles":70,"njs":10,"njsOffset":0},"hard":{"duration":328.681396484375,"length":142,"bombs":42,"notes":386,"obstacles":72,"njs":10,"njsOffset":0},"expert":{"duration":328.681396484375,"length":142,"bombs":46,"notes":623,"obstacles":69,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Beat it","songSubName":"Michael Jackson","songAuthorName":"Freeek","levelAuthorName":"freeek","bpm":139},"stats":{"downloads":954121,"plays":117624,"downVotes":788,"upVotes":12810,"heat":51.3070656,"rating":0.916864133003606},"description":"Easy/Normal/Hard/Expert - Obstacles and mines purely for dance moves! 100% Expert Playthrough: https://bit.ly/2IKzCp3\r\n\r\n- Freeek =)","deletedAt":null,"_id":"5cff620c48229f7d88fc62d6","key":"217","name":"Beat it - Michael Jackson","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-25T14:20:19.000Z","hash":"4b2da842b687ec4cfbc948c583c21c79d4120de0","directDownload":"/cdn/217/4b2da842b687ec4cfbc948c583c21c79d4120de0.zip","downloadURL":"/api/download/key/217","coverURL":"/cdn/217/4b2da842b687ec4cfbc948c583c21c79d4120de0.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":640.7428588867188,"length":311,"bombs":57,"notes":423,"obstacles":33,"njs":10,"njsOffset":0},"expert":{"duration":640.7428588867188,"length":311,"bombs":68,"notes":616,"obstacles":33,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Harder Better Faster Stronger","songSubName":"Daft Punk","songAuthorName":"RunRockGame","levelAuthorName":"runrockgame","bpm":123},"stats":{"downloads":950414,"plays":74223,"downVotes":768,"upVotes":13329,"heat":65.0613575,"rating":0.9204103268622943},"description":"Expert & Hard | 600+ Blocks | Full Song 3:44 | Includes Lighting. Request to: @themakertales","deletedAt":null,"_id":"5cff620c48229f7d88fc63dd","key":"32e","name":"Daft Punk - Harder Better Faster Stronger","uploader":{"_id":"5cff0b7398cc5a672c84f04e","username":"runrockgame"},"uploaded":"2018-06-01T18:01:45.000Z","hash":"7c7f38d467bb43fe11a142581e63e324622ecc71","directDownload":"/cdn/32e/7c7f38d467bb43fe11a142581e63e324622ecc71.zip","downloadURL":"/api/download/key/32e","coverURL":"/cdn/32e/7c7f38d467bb43fe11a142581e63e324622ecc71.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":623.3125,"length":214,"bombs":0,"notes":462,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":623.3125,"length":214,"bombs":0,"notes":639,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":623.3125,"length":214,"bombs":0,"notes":825,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Mr. Blue Sky","songSubName":"Electric Light Orchestra","songAuthorName":"GreatYazer","levelAuthorName":"greatyazer","bpm":174},"stats":{"downloads":946972,"plays":39426,"downVotes":501,"upVotes":23115,"heat":94.0257996,"rating":0.9556825237540296},"description":"Channel your inner Baby Groot.  Normal, Hard, Expert\r\nSpecial thanks to BennydaBeast for his help on this track!","deletedAt":null,"_id":"5cff620d48229f7d88fc65f7","key":"570","name":"Mr. Blue Sky | Electric Light Orchestra","uploader":{"_id":"5cff0b7298cc5a672c84ea71","username":"greatyazer"},"uploaded":"2018-06-16T16:53:34.000Z","hash":"236173d5ba7dc379d480b9cb5fb6b4fa5abe77da","directDownload":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.zip","downloadURL":"/api/download/key/570","coverURL":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":590.65625,"length":232,"bombs":0,"notes":768,"obstacles":154,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"Rasputin (Funk Overload)","songSubName":"","songAuthorName":"Jobas","levelAuthorName":"jobas","bpm":149},"stats":{"downloads":808228,"plays":29624,"downVotes":788,"upVotes":17698,"heat":40.1168992,"rating":0.9336148190735135},"description":"Hard\r\nBpm 147\r\nspeed up version of rasputin Original","deletedAt":null,"_id":"5cff620c48229f7d88fc61f0","key":"124","name":"Rasputin (Funk Overload)","uploader":{"_id":"5cff0b7298cc5a672c84e9a2","username":"jobas"},"uploaded":"2018-05-19T16:36:34.000Z","hash":"9a1d001995cc0a2014352aa7148cbcbf2e489d89","directDownload":"/cdn/124/9a1d001995cc0a2014352aa7148cbcbf2e489d89.zip","downloadURL":"/api/download/key/124","coverURL":"/cdn/124/9a1d001995cc0a2014352aa7148cbcbf2e489d89.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":473.1875,"length":228,"bombs":0,"notes":399,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":473.1875,"length":228,"bombs":0,"notes":496,"obstacles":0,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"Seven Nation Army","songSubName":"The White Stripes","songAuthorName":"BlueASIS","levelAuthorName":"blueasis","bpm":124},"stats":{"downloads":787899,"plays":56470,"downVotes":450,"upVotes":11817,"heat":74.6837125,"rating":0.9360870726827836},"description":"UPDATED! @BlueASIS#4095 on Discord let me know what you think","deletedAt":null,"_id":"5cff620d48229f7d88fc64a0","key":"3fc","name":"The White Stripes - Seven Nation Army","uploader":{"_id":"5cff0b7298cc5a672c84eb5d","username":"blueasis"},"uploaded":"2018-06-06T18:51:03.000Z","hash":"0b0ad0f34b2d0687a9794bcf5019100fda06971e","directDownload":"/cdn/3fc/0b0ad0f34b2d0687a9794bcf5019100fda06971e.zip","downloadURL":"/api/download/key/3fc","coverURL":"/cdn/3fc/0b0ad0f34b2d0687a9794bcf5019100fda06971e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":384,"length":136,"bombs":0,"notes":505,"obstacles":52,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Take On Me","songSubName":"","songAuthorName":"a-ha","levelAuthorName":"jackscape","bpm":168},"stats":{"downloads":779598,"plays":36801,"downVotes":559,"upVotes":6013,"heat":18.5957596,"rating":0.8855165188788794},"description":"Expert only","deletedAt":null,"_id":"5cff620c48229f7d88fc60e7","key":"9","name":"a-ha - Take On Me","uploader":{"_id":"5cff0b7298cc5a672c84e8bb","username":"jackscape"},"uploaded":"2018-05-08T17:44:17.000Z","hash":"2aa1f5192828e075c30dd015b1e132bba912eb86","directDownload":"/cdn/9/2aa1f5192828e075c30dd015b1e132bba912eb86.zip","downloadURL":"/api/download/key/9","coverURL":"/cdn/9/2aa1f5192828e075c30dd015b1e132bba912eb86.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":342.8125,"length":165,"bombs":0,"notes":313,"obstacles":27,"njs":10,"njsOffset":0},"normal":{"duration":343.8125,"length":166,"bombs":0,"notes":480,"obstacles":27,"njs":10,"njsOffset":0},"hard":{"duration":343.8125,"length":166,"bombs":0,"notes":730,"obstacles":27,"njs":10,"njsOffset":0},"expert":{"duration":341.75,"length":165,"bombs":11,"notes":735,"obstacles":2,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Lone Digger","songSubName":"","songAuthorName":"Caravan Palace","levelAuthorName":"calijor","bpm":124},"stats":{"downloads":687656,"plays":57999,"downVotes":840,"upVotes":14446,"heat":46.3941527,"rating":0.9205683936914438},"description":"Caravan Palace - Lone Digger\r\nEasy | Normal | Hard | Expert\r\nThis is a re-upload of my previous map, with improvements for hard, and a new, harder expert difficulty mapped by Squeaksies, as well as lower difficulties as iterations on my original map.\r\n\r\nBPM: 124\r\nDuration: 2:49\r\nNotes (Hard): 730\r\nNotes (Expert): 735\r\nPreview (Hard): https://youtu.be/NExvLUyeBUU\r\nPreview (Expert): https://youtu.be/NYmExXlpB0k","deletedAt":null,"_id":"5cff620c48229f7d88fc6282","key":"1bf","name":"Caravan Palace - Lone Digger","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-05-23T00:15:19.000Z","hash":"906160fd1f808e2f34f33c2ca5920118855c065d","directDownload":"/cdn/1bf/906160fd1f808e2f34f33c2ca5920118855c065d.zip","downloadURL":"/api/download/key/1bf","coverURL":"/cdn/1bf/906160fd1f808e2f34f33c2ca5920118855c065d.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":382.4375,"length":246,"bombs":0,"notes":728,"obstacles":16,"njs":10,"njsOffset":0},"hard":{"duration":387.4375,"length":249,"bombs":0,"notes":836,"obstacles":19,"njs":10,"njsOffset":0},"expert":{"duration":387.4375,"length":249,"bombs":0,"notes":883,"obstacles":31,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Africa","songSubName":"","songAuthorName":"Toto","levelAuthorName":"calijor","bpm":92},"stats":{"downloads":675851,"plays":27043,"downVotes":1119,"upVotes":5149,"heat":40.3137495,"rating":0.7983494876242424},"description":"Bless the rains!\r\n\r\nExpert, hard, and normal difficulty available. I do apologize, but I think they're a bit harder than vanilla.\r\n\r\nBPM: 92\r\nNotes: 883 (expert)\r\nDuration: 4:20\r\n\r\nPreview: https://youtu.be/SLpUwKson80","deletedAt":null,"_id":"5cff620c48229f7d88fc6203","key":"137","name":"Toto - Africa","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-05-20T02:51:20.000Z","hash":"12fee98db7d2080724435d0a1442545f21a9134d","directDownload":"/cdn/137/12fee98db7d2080724435d0a1442545f21a9134d.zip","downloadURL":"/api/download/key/137","coverURL":"/cdn/137/12fee98db7d2080724435d0a1442545f21a9134d.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":null,"nextPage":1}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/downloads/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":820.7191772460938,"length":346,"bombs":58,"notes":486,"obstacles":12,"njs":10,"njsOffset":0},"normal":null,"hard":{"duration":820.7191772460938,"length":346,"bombs":132,"notes":975,"obstacles":20,"njs":10,"njsOffset":0},"expert":{"duration":820.7191772460938,"length":346,"bombs":154,"notes":991,"obstacles":20,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Bohemian Rhapsody","songSubName":"Queen","songAuthorName":"KingPeuche YouTube","levelAuthorName":"kingpeuche","bpm":120},"stats":{"downloads":639142,"plays":21844,"downVotes":863,"upVotes":6090,"heat":82.2467858,"rating":0.8496736084434313},"description":"Gentlemen, I present to you Bohemian Rhapsody by Queen.\r\n\r\nDuration: 5:56\r\nNotes: 991\r\nVery Very Frightening Lightning: Included\r\n\r\n-Expert\r\n-Hard\r\n-Easy\r\n\r\nNow I know what you're thinking, \"Why hasn't anybody done this yet!?\". \r\nWell there are two primary reasons, the first being the Loudness war (Google that) has caused all original Bohemian Rhapsody files online to be absurdly quiet. The second being that the Editor does not function ideally with songs that have multiple BPMs.\r\n\r\nDid I edit the audio to make it possible to be heard through the saber slashes? Yes.\r\nDid I have to go through this entire process and put the notes in by ear rather than with Beat Snapping? Also yes.\r\n\r\nDid I do it for you, the player, to be able to enjoy Beat Saber to perhaps the greatest song of all time? Hell yes I did, and I did it because the world deserves to finally see this damned masterpiece in the game.\r\n\r\n\r\nFeel free to leave your experiences and feedback below. Any comment, good or bad, means the world to me.\r\n\r\nAlso, find me on YouTube if you'd like to be notified of when I make a new song.\r\nhttps://www.youtube.com/user/1peewerking1\r\n\r\nEnjoy yourself.","deletedAt":null,"_id":"5cff620d48229f7d88fc6541","key":"4a6","name":"Bohemian Rhapsody - Queen","uploader":{"_id":"5cff0b7398cc5a672c84ef92","username":"kingpeuche"},"uploaded":"2018-06-10T21:36:24.000Z","hash":"9a8b9b0ecc22de615cd99454579b6aac685f4b1c","directDownload":"/cdn/4a6/9a8b9b0ecc22de615cd99454579b6aac685f4b1c.zip","downloadURL":"/api/download/key/4a6","coverURL":"/cdn/4a6/9a8b9b0ecc22de615cd99454579b6aac685f4b1c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":509.5,"length":220,"bombs":18,"notes":598,"obstacles":236,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"Feel Good Inc.","songSubName":"","songAuthorName":"Gorillaz","levelAuthorName":"gavinmac","bpm":139},"stats":{"downloads":613819,"plays":31080,"downVotes":1278,"upVotes":3398,"heat":47.1753803,"rating":0.7088795937552769},"description":"Hard mode only, More coming in the future.","deletedAt":null,"_id":"5cff620c48229f7d88fc62a0","key":"1de","name":"Feel Good Inc. - The Gorillaz","uploader":{"_id":"5cff0b7398cc5a672c84ed67","username":"gavinmac"},"uploaded":"2018-05-23T20:06:47.000Z","hash":"834bb9533ea0ba5dec806461230e103b85e1a5e3","directDownload":"/cdn/1de/834bb9533ea0ba5dec806461230e103b85e1a5e3.zip","downloadURL":"/api/download/key/1de","coverURL":"/cdn/1de/834bb9533ea0ba5dec806461230e103b85e1a5e3.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":459.91717529296875,"length":194,"bombs":0,"notes":564,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Every Time We Touch","songSubName":"","songAuthorName":"Cascada","levelAuthorName":"purphoros","bpm":142},"stats":{"downloads":588967,"plays":42754,"downVotes":508,"upVotes":9301,"heat":36.8922037,"rating":0.9200354171491193},"description":"Expert Only\r\nTime - 3:19\r\nBPM - 142\r\nNotes- 564","deletedAt":null,"_id":"5cff620c48229f7d88fc61b5","key":"e4","name":"Every Time We Touch - Cascada","uploader":{"_id":"5cff0b7298cc5a672c84ea98","username":"purphoros"},"uploaded":"2018-05-18T03:51:03.000Z","hash":"bc6c7ef1385db4c11c59736d2b32eacf48c95bd9","directDownload":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.zip","downloadURL":"/api/download/key/e4","coverURL":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":189,"length":90,"bombs":0,"notes":330,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Super Mario Bros. Theme (Overworld Theme)","songSubName":"Nintendo","songAuthorName":"red knight","levelAuthorName":"redknight","bpm":125},"stats":{"downloads":561072,"plays":49329,"downVotes":1106,"upVotes":4730,"heat":22.1647882,"rating":0.78766709691541},"description":"Expert only","deletedAt":null,"_id":"5cff620c48229f7d88fc6106","key":"29","name":"Super Mario Bros Theme","uploader":{"_id":"5cff0b7298cc5a672c84e917","username":"redknight"},"uploaded":"2018-05-10T16:34:12.000Z","hash":"c1c8e2b9394050afad435608137941da0b64b8f3","directDownload":"/cdn/29/c1c8e2b9394050afad435608137941da0b64b8f3.zip","downloadURL":"/api/download/key/29","coverURL":"/cdn/29/c1c8e2b9394050afad435608137941da0b64b8f3.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":291.375,"length":138,"bombs":0,"notes":291,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":291.375,"length":138,"bombs":0,"notes":367,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":291.375,"length":138,"bombs":0,"notes":409,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"September","songSubName":"","songAuthorName":"Earth, Wind & Fire","levelAuthorName":"calijor","bpm":126},"stats":{"downloads":540111,"plays":43006,"downVotes":338,"upVotes":8828,"heat":80.2861966,"rating":0.9334114477208286},"description":"Expert | Hard | Normal\r\n\r\nBPM - 126\r\nDuration - 2:21\r\n\r\nPreview: https://youtu.be/FOob1xit17Y","deletedAt":null,"_id":"5cff620d48229f7d88fc651e","key":"480","name":"Earth, Wind & Fire - September","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-06-09T18:27:58.000Z","hash":"aa2f7bf0df25cd57dddac159fa7c159f732e0553","directDownload":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.zip","downloadURL":"/api/download/key/480","coverURL":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":418,"length":200,"bombs":0,"notes":216,"obstacles":0,"njs":10,"njsOffset":0},"normal":{"duration":418,"length":200,"bombs":0,"notes":388,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":418,"length":200,"bombs":0,"notes":514,"obstacles":10,"njs":11,"njsOffset":0},"expert":{"duration":418,"length":200,"bombs":0,"notes":560,"obstacles":12,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Believer (100k ver.)","songSubName":"Imagine Dragons","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":125},"stats":{"downloads":526518,"plays":9381,"downVotes":228,"upVotes":12218,"heat":379.9107747,"rating":0.9534955987479463},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!","deletedAt":null,"_id":"5cff621148229f7d88fc76ec","key":"1fef","name":"Imagine Dragons - Believer (100k ver.) | 100k Contest","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-11-12T17:53:58.000Z","hash":"9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390","directDownload":"/cdn/1fef/9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390.zip","downloadURL":"/api/download/key/1fef","coverURL":"/cdn/1fef/9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":354.4934997558594,"length":168,"bombs":4,"notes":200,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":354.4934997558594,"length":168,"bombs":6,"notes":402,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":354.4934997558594,"length":168,"bombs":10,"notes":702,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"The Nights","songSubName":"","songAuthorName":"Avicii","levelAuthorName":"rocket","bpm":126},"stats":{"downloads":516724,"plays":19914,"downVotes":310,"upVotes":9430,"heat":78.7877726,"rating":0.9386796457088015},"description":"The Nights By Avicii.\r\nThis is my first beatmap, feedback would be greatly appreciated :)","deletedAt":null,"_id":"5cff620d48229f7d88fc6501","key":"462","name":"The Nights - Avicii","uploader":{"_id":"5cff0b7398cc5a672c84f1c0","username":"rocket"},"uploaded":"2018-06-08T23:20:50.000Z","hash":"2c002d2874e029db43f3c7cf9bb271ae0d769b74","directDownload":"/cdn/462/2c002d2874e029db43f3c7cf9bb271ae0d769b74.zip","downloadURL":"/api/download/key/462","coverURL":"/cdn/462/2c002d2874e029db43f3c7cf9bb271ae0d769b74.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":317.85003662109375,"length":158,"bombs":0,"notes":697,"obstacles":45,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Megalovania","songSubName":"Toby Fox","songAuthorName":"Sinlord","levelAuthorName":"sinlord","bpm":120},"stats":{"downloads":513102,"plays":15195,"downVotes":913,"upVotes":6517,"heat":35.0479203,"rating":0.8513460129795872},"description":"First song created. Any suggestions are welcome. Have fun.","deletedAt":null,"_id":"5cff620c48229f7d88fc619b","key":"c5","name":"Megalovania","uploader":{"_id":"5cff0b7298cc5a672c84eaa0","username":"sinlord"},"uploaded":"2018-05-17T07:14:34.000Z","hash":"dc5b8a8f9178f2978521a942a7caf391712c63f9","directDownload":"/cdn/c5/dc5b8a8f9178f2978521a942a7caf391712c63f9.zip","downloadURL":"/api/download/key/c5","coverURL":"/cdn/c5/dc5b8a8f9178f2978521a942a7caf391712c63f9.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":472.5,"length":232,"bombs":0,"notes":373,"obstacles":11,"njs":10,"njsOffset":0},"hard":{"duration":472.5,"length":232,"bombs":0,"notes":503,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":472.5,"length":232,"bombs":0,"notes":682,"obstacles":30,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Livin' On A Prayer","songSubName":"Bon Jovi","songAuthorName":"Bon Jovi","levelAuthorName":"jnua12345","bpm":122},"stats":{"downloads":478709,"plays":47593,"downVotes":851,"upVotes":2655,"heat":34.0723032,"rating":0.735230655617696},"description":"Expert, Hard, Normal 122BPM","deletedAt":null,"_id":"5cff620c48229f7d88fc6194","key":"bd","name":"Bon Jovi - Livin' On A Prayer","uploader":{"_id":"5cff0b7298cc5a672c84e9da","username":"jnua12345"},"uploaded":"2018-05-17T01:12:03.000Z","hash":"4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88","directDownload":"/cdn/bd/4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88.zip","downloadURL":"/api/download/key/bd","coverURL":"/cdn/bd/4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":265.510009765625,"length":189,"bombs":0,"notes":297,"obstacles":57,"njs":10,"njsOffset":0},"normal":{"duration":264.510009765625,"length":188,"bombs":0,"notes":358,"obstacles":62,"njs":10,"njsOffset":0},"hard":{"duration":266.010009765625,"length":190,"bombs":0,"notes":514,"obstacles":67,"njs":10,"njsOffset":0},"expert":{"duration":276.010009765625,"length":197,"bombs":0,"notes":681,"obstacles":67,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Clint Eastwood","songSubName":"Gorillaz","songAuthorName":"unknow","levelAuthorName":"freeek","bpm":84},"stats":{"downloads":478043,"plays":51819,"downVotes":376,"upVotes":5868,"heat":51.4978639,"rating":0.9081106988394465},"description":"Easy/Normal/Hard/Expert - Audio is as loud without clipping I swear! 100% Expert Playthrough: https://bit.ly/2LuFcxq\r\n\r\nHave fun! =D\r\n\r\n- Freeek =)","deletedAt":null,"_id":"5cff620c48229f7d88fc62e4","key":"225","name":"Clint Eastwood - Gorillaz","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-25T20:58:36.000Z","hash":"507f0e09326d37e09dca08e3c2597f027dbe1940","directDownload":"/cdn/225/507f0e09326d37e09dca08e3c2597f027dbe1940.zip","downloadURL":"/api/download/key/225","coverURL":"/cdn/225/507f0e09326d37e09dca08e3c2597f027dbe1940.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/downloads/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":1467.25,"length":440,"bombs":18,"notes":1356,"obstacles":22,"njs":10,"njsOffset":0},"expert":{"duration":1467.25,"length":440,"bombs":26,"notes":2806,"obstacles":36,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"TTFAF","songSubName":"DragonForce","songAuthorName":"Freeek","levelAuthorName":"freeek","bpm":200},"stats":{"downloads":476355,"plays":18661,"downVotes":1435,"upVotes":5082,"heat":23.7707358,"rating":0.7599138914656974},"description":"Expert and a Hard difficulty. 2800 notes and Even more event light triggers... Enjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc6117","key":"3a","name":"Through the Fire and Flames - Dragonforce","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-11T12:36:36.000Z","hash":"9b0881baab8b72f33424ecf0d9ad247ea10afbb2","directDownload":"/cdn/3a/9b0881baab8b72f33424ecf0d9ad247ea10afbb2.zip","downloadURL":"/api/download/key/3a","coverURL":"/cdn/3a/9b0881baab8b72f33424ecf0d9ad247ea10afbb2.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":523.625,"length":245,"bombs":0,"notes":633,"obstacles":13,"njs":10,"njsOffset":0},"expert":{"duration":523.5650024414062,"length":245,"bombs":0,"notes":880,"obstacles":19,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Uprising","songSubName":"Muse","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":128},"stats":{"downloads":469054,"plays":11598,"downVotes":168,"upVotes":9404,"heat":84.4290617,"rating":0.951897040235784},"description":"Expert / Hard\r\nhttps://youtu.be/WpFUt3UNp7c","deletedAt":null,"_id":"5cff620d48229f7d88fc655e","key":"4c6","name":"Muse - Uprising","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-06-11T21:47:41.000Z","hash":"00e5671e594a6fe621c3605fcc5a0e4466ba6478","directDownload":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.zip","downloadURL":"/api/download/key/4c6","coverURL":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":567.893310546875,"length":212,"bombs":24,"notes":604,"obstacles":27,"njs":10,"njsOffset":0},"hard":{"duration":567.893310546875,"length":212,"bombs":24,"notes":762,"obstacles":31,"njs":10,"njsOffset":0},"expert":{"duration":567.893310546875,"length":212,"bombs":24,"notes":776,"obstacles":31,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Shake It Off","songSubName":"Taylor Swift","songAuthorName":"Jovian","levelAuthorName":"jovian","bpm":160},"stats":{"downloads":464876,"plays":27795,"downVotes":354,"upVotes":5499,"heat":65.7906965,"rating":0.9072436169435235},"description":"Supports - Normal, Hard, Expert difficulties. \r\n\r\nGameplay: https://youtu.be/7pbtO1B7tK0\r\n\r\nExpert: As chartered (gameplay shown in video) \r\nHard: Cuts down drastically on arm cross overs.\r\nNormal: As above, additionally cuts down on double/triples and compresses strike range.","deletedAt":null,"_id":"5cff620c48229f7d88fc63f5","key":"348","name":"Taylor Swift - Shake It Off","uploader":{"_id":"5cff0b7298cc5a672c84ebab","username":"jovian"},"uploaded":"2018-06-02T07:59:29.000Z","hash":"483c7bc03133c6e215f3018e5033b0913821126f","directDownload":"/cdn/348/483c7bc03133c6e215f3018e5033b0913821126f.zip","downloadURL":"/api/download/key/348","coverURL":"/cdn/348/483c7bc03133c6e215f3018e5033b0913821126f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":461647,"plays":44907,"downVotes":583,"upVotes":7779,"heat":41.85238,"rating":0.9019004934599628},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":183.5,"length":81,"bombs":0,"notes":174,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":183.5,"length":81,"bombs":0,"notes":262,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Unravel","songSubName":"(TV Size)","songAuthorName":"TK","levelAuthorName":"winepic","bpm":135},"stats":{"downloads":451714,"plays":52247,"downVotes":377,"upVotes":4225,"heat":18.3387906,"rating":0.8850745894514591},"description":"Map made by me. Includes Hard and Expert difficulties.","deletedAt":null,"_id":"5cff620c48229f7d88fc60e5","key":"7","name":"Unravel (Tokyo Ghoul OP) TV Size","uploader":{"_id":"5cff0b7298cc5a672c84e8b6","username":"winepic"},"uploaded":"2018-05-08T16:25:10.000Z","hash":"b9867cdccf8b27d7a174c861adc69215c86cdab8","directDownload":"/cdn/7/b9867cdccf8b27d7a174c861adc69215c86cdab8.zip","downloadURL":"/api/download/key/7","coverURL":"/cdn/7/b9867cdccf8b27d7a174c861adc69215c86cdab8.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":439,"length":233,"bombs":0,"notes":579,"obstacles":38,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"CAN'T STOP THE FEELING!","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":113},"stats":{"downloads":450438,"plays":9160,"downVotes":171,"upVotes":6118,"heat":78.4738535,"rating":0.9388331738034267},"description":"Difficulties: Expert Only     Lighting by GreatYazer\r\nWatch Me on Twitch: http://www.twitch.tv/bennydabeastlive\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc64fd","key":"45e","name":"CAN'T STOP THE FEELING! - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-08T21:44:40.000Z","hash":"91ba25c089d50e93154e2c7920c46d4730f22569","directDownload":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.zip","downloadURL":"/api/download/key/45e","coverURL":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":368,"length":200,"bombs":9,"notes":603,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Bangarang","songSubName":"Skrillex","songAuthorName":"tmoss94","levelAuthorName":"tmoss94","bpm":110},"stats":{"downloads":436413,"plays":30721,"downVotes":322,"upVotes":2892,"heat":35.6625553,"rating":0.8646499394205489},"description":"Expert only, got a little lighting but nothing super fancy.","deletedAt":null,"_id":"5cff620c48229f7d88fc61a6","key":"d3","name":"Bangarang - Skrillex","uploader":{"_id":"5cff0b7298cc5a672c84eab3","username":"tmoss94"},"uploaded":"2018-05-17T19:09:28.000Z","hash":"4ffb4b82a7443e184aeace69b9d12f63d39b4386","directDownload":"/cdn/d3/4ffb4b82a7443e184aeace69b9d12f63d39b4386.zip","downloadURL":"/api/download/key/d3","coverURL":"/cdn/d3/4ffb4b82a7443e184aeace69b9d12f63d39b4386.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":417,"length":195,"bombs":4,"notes":301,"obstacles":24,"njs":10,"njsOffset":0},"hard":{"duration":417,"length":195,"bombs":4,"notes":486,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":417.5,"length":195,"bombs":4,"notes":620,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":{"duration":417.5,"length":195,"bombs":0,"notes":894,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Shut Up and Dance","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":434886,"plays":558,"downVotes":140,"upVotes":11118,"heat":395.8365232,"rating":0.9581602226568194},"description":"Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY","deletedAt":null,"_id":"5cff621148229f7d88fc77c9","key":"2144","name":"Shut Up and Dance - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-21T01:27:00.000Z","hash":"89cf8bb07afb3c59ae7b5ac00337d62261c36fb4","directDownload":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip","downloadURL":"/api/download/key/2144","coverURL":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":486,"length":173,"bombs":0,"notes":368,"obstacles":285,"njs":10,"njsOffset":0},"expert":{"duration":486,"length":173,"bombs":4,"notes":523,"obstacles":329,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Thunder","songSubName":"(Maikyy Beatmap)","songAuthorName":"Imagine Dragons","levelAuthorName":"maikyy","bpm":168},"stats":{"downloads":434003,"plays":26685,"downVotes":718,"upVotes":1191,"heat":18.2184611,"rating":0.6111419715887695},"description":"Reupload with an added 'easier' Hard difficulty, really hard to balance so please let me know what I could change to make it better","deletedAt":null,"_id":"5cff620c48229f7d88fc60f2","key":"14","name":"Thunder - Imagine Dragons","uploader":{"_id":"5cff0b7298cc5a672c84e8e3","username":"maikyy"},"uploaded":"2018-05-09T02:17:42.000Z","hash":"f2c56d4ea62d31d04982db9a9e46199716a0dcfc","directDownload":"/cdn/14/f2c56d4ea62d31d04982db9a9e46199716a0dcfc.zip","downloadURL":"/api/download/key/14","coverURL":"/cdn/14/f2c56d4ea62d31d04982db9a9e46199716a0dcfc.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":290,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":374,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Portal - Still Alive (Uppermost Remix)","songSubName":"Uppermost","songAuthorName":"Kryptikos","levelAuthorName":"kryptikos","bpm":120},"stats":{"downloads":423606,"plays":32883,"downVotes":339,"upVotes":4970,"heat":47.4705859,"rating":0.9031649350412563},"description":"Second track mapped, includes Hard and Expert difficulties.\r\n\r\nI know that this isn't the hardest track, but I find it to be fun, which I see as priority number one. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc629f","key":"1dd","name":"Portal - Still Alive (Uppermost Remix)","uploader":{"_id":"5cff0b7298cc5a672c84eab4","username":"kryptikos"},"uploaded":"2018-05-23T19:33:41.000Z","hash":"01308128a87b6b561799359ee5aa213168c3b49f","directDownload":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.zip","downloadURL":"/api/download/key/1dd","coverURL":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
-        assert_eq!(
-            client
-                .maps_downloads()
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "141".to_string(),
-                "b".to_string(),
-                "217".to_string(),
-                "32e".to_string(),
-                "570".to_string(),
-                "124".to_string(),
-                "3fc".to_string(),
-                "9".to_string(),
-                "1bf".to_string(),
-                "137".to_string(),
-                "4a6".to_string(),
-                "1de".to_string(),
-                "e4".to_string(),
-                "29".to_string(),
-                "480".to_string(),
-                "1fef".to_string(),
-                "462".to_string(),
-                "c5".to_string(),
-                "bd".to_string(),
-                "225".to_string(),
-                "3a".to_string(),
-                "4c6".to_string(),
-                "348".to_string(),
-                "155".to_string(),
-                "7".to_string(),
-                "45e".to_string(),
-                "d3".to_string(),
-                "2144".to_string(),
-                "14".to_string(),
-                "1dd".to_string()
-            ]
+        pages.insert(
+            BEATSAVER_URL.join("maps/latest?automapper=false").unwrap(),
+            page_json(&["97d3", "65cb"]),
         );
+        pages.insert(
+            BEATSAVER_URL
+                .join("maps/latest?before=2021-06-01T12%3A00%3A00Z&automapper=false")
+                .unwrap(),
+            page_json(&[]),
+        );
+        let client = FakeClientPaged::new(pages);
+        let pages = client
+            .maps_latest_pages(LatestOptions::new().with_automapper(false))
+            .map(|p| p.unwrap().docs.into_iter().map(|m| m.id).collect())
+            .collect::<Vec<Vec<String>>>();
+        assert_eq!(pages, vec![vec!["97d3".to_string(), "65cb".to_string()]]);
     }
     #[test]
-    fn test_maps_downloads_page() {
-        let client = FakeClient::new(BEATSAVER_URL.join("api/maps/downloads/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":1467.25,"length":440,"bombs":18,"notes":1356,"obstacles":22,"njs":10,"njsOffset":0},"expert":{"duration":1467.25,"length":440,"bombs":26,"notes":2806,"obstacles":36,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"TTFAF","songSubName":"DragonForce","songAuthorName":"Freeek","levelAuthorName":"freeek","bpm":200},"stats":{"downloads":473727,"plays":18661,"downVotes":1420,"upVotes":5050,"heat":23.7687066,"rating":0.7605381326555614},"description":"Expert and a Hard difficulty. 2800 notes and Even more event light triggers... Enjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc6117","key":"3a","name":"Through the Fire and Flames - Dragonforce","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-11T12:36:36.000Z","hash":"9b0881baab8b72f33424ecf0d9ad247ea10afbb2","directDownload":"/cdn/3a/9b0881baab8b72f33424ecf0d9ad247ea10afbb2.zip","downloadURL":"/api/download/key/3a","coverURL":"/cdn/3a/9b0881baab8b72f33424ecf0d9ad247ea10afbb2.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":523.625,"length":245,"bombs":0,"notes":633,"obstacles":13,"njs":10,"njsOffset":0},"expert":{"duration":523.5650024414062,"length":245,"bombs":0,"notes":880,"obstacles":19,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Uprising","songSubName":"Muse","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":128},"stats":{"downloads":463841,"plays":11598,"downVotes":167,"upVotes":9303,"heat":84.4243339,"rating":0.951720218307175},"description":"Expert / Hard\r\nhttps://youtu.be/WpFUt3UNp7c","deletedAt":null,"_id":"5cff620d48229f7d88fc655e","key":"4c6","name":"Muse - Uprising","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-06-11T21:47:41.000Z","hash":"00e5671e594a6fe621c3605fcc5a0e4466ba6478","directDownload":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.zip","downloadURL":"/api/download/key/4c6","coverURL":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":567.893310546875,"length":212,"bombs":24,"notes":604,"obstacles":27,"njs":10,"njsOffset":0},"hard":{"duration":567.893310546875,"length":212,"bombs":24,"notes":762,"obstacles":31,"njs":10,"njsOffset":0},"expert":{"duration":567.893310546875,"length":212,"bombs":24,"notes":776,"obstacles":31,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Shake It Off","songSubName":"Taylor Swift","songAuthorName":"Jovian","levelAuthorName":"jovian","bpm":160},"stats":{"downloads":461671,"plays":27795,"downVotes":349,"upVotes":5459,"heat":65.787732,"rating":0.9075319571041482},"description":"Supports - Normal, Hard, Expert difficulties. \r\n\r\nGameplay: https://youtu.be/7pbtO1B7tK0\r\n\r\nExpert: As chartered (gameplay shown in video) \r\nHard: Cuts down drastically on arm cross overs.\r\nNormal: As above, additionally cuts down on double/triples and compresses strike range.","deletedAt":null,"_id":"5cff620c48229f7d88fc63f5","key":"348","name":"Taylor Swift - Shake It Off","uploader":{"_id":"5cff0b7298cc5a672c84ebab","username":"jovian"},"uploaded":"2018-06-02T07:59:29.000Z","hash":"483c7bc03133c6e215f3018e5033b0913821126f","directDownload":"/cdn/348/483c7bc03133c6e215f3018e5033b0913821126f.zip","downloadURL":"/api/download/key/348","coverURL":"/cdn/348/483c7bc03133c6e215f3018e5033b0913821126f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":458321,"plays":44907,"downVotes":577,"upVotes":7711,"heat":41.848622,"rating":0.9019191908663293},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":183.5,"length":81,"bombs":0,"notes":174,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":183.5,"length":81,"bombs":0,"notes":262,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Unravel","songSubName":"(TV Size)","songAuthorName":"TK","levelAuthorName":"winepic","bpm":135},"stats":{"downloads":448107,"plays":52247,"downVotes":375,"upVotes":4185,"heat":18.3344805,"rating":0.8846924645925284},"description":"Map made by me. Includes Hard and Expert difficulties.","deletedAt":null,"_id":"5cff620c48229f7d88fc60e5","key":"7","name":"Unravel (Tokyo Ghoul OP) TV Size","uploader":{"_id":"5cff0b7298cc5a672c84e8b6","username":"winepic"},"uploaded":"2018-05-08T16:25:10.000Z","hash":"b9867cdccf8b27d7a174c861adc69215c86cdab8","directDownload":"/cdn/7/b9867cdccf8b27d7a174c861adc69215c86cdab8.zip","downloadURL":"/api/download/key/7","coverURL":"/cdn/7/b9867cdccf8b27d7a174c861adc69215c86cdab8.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":439,"length":233,"bombs":0,"notes":579,"obstacles":38,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"CAN'T STOP THE FEELING!","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":113},"stats":{"downloads":445793,"plays":9160,"downVotes":170,"upVotes":6076,"heat":78.470849,"rating":0.9387378041429791},"description":"Difficulties: Expert Only     Lighting by GreatYazer\r\nWatch Me on Twitch: http://www.twitch.tv/bennydabeastlive\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc64fd","key":"45e","name":"CAN'T STOP THE FEELING! - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-08T21:44:40.000Z","hash":"91ba25c089d50e93154e2c7920c46d4730f22569","directDownload":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.zip","downloadURL":"/api/download/key/45e","coverURL":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":368,"length":200,"bombs":9,"notes":603,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Bangarang","songSubName":"Skrillex","songAuthorName":"tmoss94","levelAuthorName":"tmoss94","bpm":110},"stats":{"downloads":433015,"plays":30721,"downVotes":321,"upVotes":2872,"heat":35.6593327,"rating":0.8642652382205075},"description":"Expert only, got a little lighting but nothing super fancy.","deletedAt":null,"_id":"5cff620c48229f7d88fc61a6","key":"d3","name":"Bangarang - Skrillex","uploader":{"_id":"5cff0b7298cc5a672c84eab3","username":"tmoss94"},"uploaded":"2018-05-17T19:09:28.000Z","hash":"4ffb4b82a7443e184aeace69b9d12f63d39b4386","directDownload":"/cdn/d3/4ffb4b82a7443e184aeace69b9d12f63d39b4386.zip","downloadURL":"/api/download/key/d3","coverURL":"/cdn/d3/4ffb4b82a7443e184aeace69b9d12f63d39b4386.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":486,"length":173,"bombs":0,"notes":368,"obstacles":285,"njs":10,"njsOffset":0},"expert":{"duration":486,"length":173,"bombs":4,"notes":523,"obstacles":329,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Thunder","songSubName":"(Maikyy Beatmap)","songAuthorName":"Imagine Dragons","levelAuthorName":"maikyy","bpm":168},"stats":{"downloads":431893,"plays":26685,"downVotes":713,"upVotes":1186,"heat":18.2184611,"rating":0.6117069756773167},"description":"Reupload with an added 'easier' Hard difficulty, really hard to balance so please let me know what I could change to make it better","deletedAt":null,"_id":"5cff620c48229f7d88fc60f2","key":"14","name":"Thunder - Imagine Dragons","uploader":{"_id":"5cff0b7298cc5a672c84e8e3","username":"maikyy"},"uploaded":"2018-05-09T02:17:42.000Z","hash":"f2c56d4ea62d31d04982db9a9e46199716a0dcfc","directDownload":"/cdn/14/f2c56d4ea62d31d04982db9a9e46199716a0dcfc.zip","downloadURL":"/api/download/key/14","coverURL":"/cdn/14/f2c56d4ea62d31d04982db9a9e46199716a0dcfc.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":417,"length":195,"bombs":4,"notes":301,"obstacles":24,"njs":10,"njsOffset":0},"hard":{"duration":417,"length":195,"bombs":4,"notes":486,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":417.5,"length":195,"bombs":4,"notes":620,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":{"duration":417.5,"length":195,"bombs":0,"notes":894,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Shut Up and Dance","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":428629,"plays":558,"downVotes":138,"upVotes":10964,"heat":395.830468,"rating":0.9580415431678604},"description":"Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY","deletedAt":null,"_id":"5cff621148229f7d88fc77c9","key":"2144","name":"Shut Up and Dance - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-21T01:27:00.000Z","hash":"89cf8bb07afb3c59ae7b5ac00337d62261c36fb4","directDownload":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip","downloadURL":"/api/download/key/2144","coverURL":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":290,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":374,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Portal - Still Alive (Uppermost Remix)","songSubName":"Uppermost","songAuthorName":"Kryptikos","levelAuthorName":"kryptikos","bpm":120},"stats":{"downloads":420093,"plays":32883,"downVotes":339,"upVotes":4931,"heat":47.466913,"rating":0.9026549349629458},"description":"Second track mapped, includes Hard and Expert difficulties.\r\n\r\nI know that this isn't the hardest track, but I find it to be fun, which I see as priority number one. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc629f","key":"1dd","name":"Portal - Still Alive (Uppermost Remix)","uploader":{"_id":"5cff0b7298cc5a672c84eab4","username":"kryptikos"},"uploaded":"2018-05-23T19:33:41.000Z","hash":"01308128a87b6b561799359ee5aa213168c3b49f","directDownload":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.zip","downloadURL":"/api/download/key/1dd","coverURL":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.jpg"}],"totalDocs":35794,"lastPage":3579,"prevPage":1,"nextPage":3}"#.into());
-        let _: Page<Map> = client.maps_downloads_page(2).unwrap();
+    fn test_maps_latest_page() {
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join("maps/latest?after=2021-06-01T12%3A00%3A00Z&sort=UPDATED")
+                .unwrap(),
+            page_json(&["5e5b", "2144"]),
+        );
+        let options = LatestOptions::new()
+            .with_after("2021-06-01T12:00:00Z".parse().unwrap())
+            .with_sort(LatestSort::Updated);
+        let maps = client.maps_latest_page(&options).unwrap();
+        assert_eq!(maps.len(), 2);
+        assert_eq!(maps[1].id, "2144");
     }
     #[test]
-    fn test_maps_downloads_page_iter() {
+    fn test_maps_curated() {
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/downloads/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":820.7191772460938,"length":346,"bombs":58,"notes":486,"obstacles":12,"njs":10,"njsOffset":0},"normal":null,"hard":{"duration":820.7191772460938,"length":346,"bombs":132,"notes":975,"obstacles":20,"njs":10,"njsOffset":0},"expert":{"duration":820.7191772460938,"length":346,"bombs":154,"notes":991,"obstacles":20,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Bohemian Rhapsody","songSubName":"Queen","songAuthorName":"KingPeuche YouTube","levelAuthorName":"kingpeuche","bpm":120},"stats":{"downloads":639142,"plays":21844,"downVotes":863,"upVotes":6090,"heat":82.2467858,"rating":0.8496736084434313},"description":"Gentlemen, I present to you Bohemian Rhapsody by Queen.\r\n\r\nDuration: 5:56\r\nNotes: 991\r\nVery Very Frightening Lightning: Included\r\n\r\n-Expert\r\n-Hard\r\n-Easy\r\n\r\nNow I know what you're thinking, \"Why hasn't anybody done this yet!?\". \r\nWell there are two primary reasons, the first being the Loudness war (Google that) has caused all original Bohemian Rhapsody files online to be absurdly quiet. The second being that the Editor does not function ideally with songs that have multiple BPMs.\r\n\r\nDid I edit the audio to make it possible to be heard through the saber slashes? Yes.\r\nDid I have to go through this entire process and put the notes in by ear rather than with Beat Snapping? Also yes.\r\n\r\nDid I do it for you, the player, to be able to enjoy Beat Saber to perhaps the greatest song of all time? Hell yes I did, and I did it because the world deserves to finally see this damned masterpiece in the game.\r\n\r\n\r\nFeel free to leave your experiences and feedback below. Any comment, good or bad, means the world to me.\r\n\r\nAlso, find me on YouTube if you'd like to be notified of when I make a new song.\r\nhttps://www.youtube.com/user/1peewerking1\r\n\r\nEnjoy yourself.","deletedAt":null,"_id":"5cff620d48229f7d88fc6541","key":"4a6","name":"Bohemian Rhapsody - Queen","uploader":{"_id":"5cff0b7398cc5a672c84ef92","username":"kingpeuche"},"uploaded":"2018-06-10T21:36:24.000Z","hash":"9a8b9b0ecc22de615cd99454579b6aac685f4b1c","directDownload":"/cdn/4a6/9a8b9b0ecc22de615cd99454579b6aac685f4b1c.zip","downloadURL":"/api/download/key/4a6","coverURL":"/cdn/4a6/9a8b9b0ecc22de615cd99454579b6aac685f4b1c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":509.5,"length":220,"bombs":18,"notes":598,"obstacles":236,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"Feel Good Inc.","songSubName":"","songAuthorName":"Gorillaz","levelAuthorName":"gavinmac","bpm":139},"stats":{"downloads":613819,"plays":31080,"downVotes":1278,"upVotes":3398,"heat":47.1753803,"rating":0.7088795937552769},"description":"Hard mode only, More coming in the future.","deletedAt":null,"_id":"5cff620c48229f7d88fc62a0","key":"1de","name":"Feel Good Inc. - The Gorillaz","uploader":{"_id":"5cff0b7398cc5a672c84ed67","username":"gavinmac"},"uploaded":"2018-05-23T20:06:47.000Z","hash":"834bb9533ea0ba5dec806461230e103b85e1a5e3","directDownload":"/cdn/1de/834bb9533ea0ba5dec806461230e103b85e1a5e3.zip","downloadURL":"/api/download/key/1de","coverURL":"/cdn/1de/834bb9533ea0ba5dec806461230e103b85e1a5e3.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":459.91717529296875,"length":194,"bombs":0,"notes":564,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Every Time We Touch","songSubName":"","songAuthorName":"Cascada","levelAuthorName":"purphoros","bpm":142},"stats":{"downloads":588967,"plays":42754,"downVotes":508,"upVotes":9301,"heat":36.8922037,"rating":0.9200354171491193},"description":"Expert Only\r\nTime - 3:19\r\nBPM - 142\r\nNotes- 564","deletedAt":null,"_id":"5cff620c48229f7d88fc61b5","key":"e4","name":"Every Time We Touch - Cascada","uploader":{"_id":"5cff0b7298cc5a672c84ea98","username":"purphoros"},"uploaded":"2018-05-18T03:51:03.000Z","hash":"bc6c7ef1385db4c11c59736d2b32eacf48c95bd9","directDownload":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.zip","downloadURL":"/api/download/key/e4","coverURL":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":189,"length":90,"bombs":0,"notes":330,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Super Mario Bros. Theme (Overworld Theme)","songSubName":"Nintendo","songAuthorName":"red knight","levelAuthorName":"redknight","bpm":125},"stats":{"downloads":561072,"plays":49329,"downVotes":1106,"upVotes":4730,"heat":22.1647882,"rating":0.78766709691541},"description":"Expert only","deletedAt":null,"_id":"5cff620c48229f7d88fc6106","key":"29","name":"Super Mario Bros Theme","uploader":{"_id":"5cff0b7298cc5a672c84e917","username":"redknight"},"uploaded":"2018-05-10T16:34:12.000Z","hash":"c1c8e2b9394050afad435608137941da0b64b8f3","directDownload":"/cdn/29/c1c8e2b9394050afad435608137941da0b64b8f3.zip","downloadURL":"/api/download/key/29","coverURL":"/cdn/29/c1c8e2b9394050afad435608137941da0b64b8f3.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":291.375,"length":138,"bombs":0,"notes":291,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":291.375,"length":138,"bombs":0,"notes":367,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":291.375,"length":138,"bombs":0,"notes":409,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"September","songSubName":"","songAuthorName":"Earth, Wind & Fire","levelAuthorName":"calijor","bpm":126},"stats":{"downloads":540111,"plays":43006,"downVotes":338,"upVotes":8828,"heat":80.2861966,"rating":0.9334114477208286},"description":"Expert | Hard | Normal\r\n\r\nBPM - 126\r\nDuration - 2:21\r\n\r\nPreview: https://youtu.be/FOob1xit17Y","deletedAt":null,"_id":"5cff620d48229f7d88fc651e","key":"480","name":"Earth, Wind & Fire - September","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-06-09T18:27:58.000Z","hash":"aa2f7bf0df25cd57dddac159fa7c159f732e0553","directDownload":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.zip","downloadURL":"/api/download/key/480","coverURL":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":418,"length":200,"bombs":0,"notes":216,"obstacles":0,"njs":10,"njsOffset":0},"normal":{"duration":418,"length":200,"bombs":0,"notes":388,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":418,"length":200,"bombs":0,"notes":514,"obstacles":10,"njs":11,"njsOffset":0},"expert":{"duration":418,"length":200,"bombs":0,"notes":560,"obstacles":12,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Believer (100k ver.)","songSubName":"Imagine Dragons","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":125},"stats":{"downloads":526518,"plays":9381,"downVotes":228,"upVotes":12218,"heat":379.9107747,"rating":0.9534955987479463},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!","deletedAt":null,"_id":"5cff621148229f7d88fc76ec","key":"1fef","name":"Imagine Dragons - Believer (100k ver.) | 100k Contest","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-11-12T17:53:58.000Z","hash":"9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390","directDownload":"/cdn/1fef/9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390.zip","downloadURL":"/api/download/key/1fef","coverURL":"/cdn/1fef/9a7a5beadfdd1c7c0f137ecba6e5f6ff377eb390.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":354.4934997558594,"length":168,"bombs":4,"notes":200,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":354.4934997558594,"length":168,"bombs":6,"notes":402,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":354.4934997558594,"length":168,"bombs":10,"notes":702,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"The Nights","songSubName":"","songAuthorName":"Avicii","levelAuthorName":"rocket","bpm":126},"stats":{"downloads":516724,"plays":19914,"downVotes":310,"upVotes":9430,"heat":78.7877726,"rating":0.9386796457088015},"description":"The Nights By Avicii.\r\nThis is my first beatmap, feedback would be greatly appreciated :)","deletedAt":null,"_id":"5cff620d48229f7d88fc6501","key":"462","name":"The Nights - Avicii","uploader":{"_id":"5cff0b7398cc5a672c84f1c0","username":"rocket"},"uploaded":"2018-06-08T23:20:50.000Z","hash":"2c002d2874e029db43f3c7cf9bb271ae0d769b74","directDownload":"/cdn/462/2c002d2874e029db43f3c7cf9bb271ae0d769b74.zip","downloadURL":"/api/download/key/462","coverURL":"/cdn/462/2c002d2874e029db43f3c7cf9bb271ae0d769b74.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":317.85003662109375,"length":158,"bombs":0,"notes":697,"obstacles":45,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Megalovania","songSubName":"Toby Fox","songAuthorName":"Sinlord","levelAuthorName":"sinlord","bpm":120},"stats":{"downloads":513102,"plays":15195,"downVotes":913,"upVotes":6517,"heat":35.0479203,"rating":0.8513460129795872},"description":"First song created. Any suggestions are welcome. Have fun.","deletedAt":null,"_id":"5cff620c48229f7d88fc619b","key":"c5","name":"Megalovania","uploader":{"_id":"5cff0b7298cc5a672c84eaa0","username":"sinlord"},"uploaded":"2018-05-17T07:14:34.000Z","hash":"dc5b8a8f9178f2978521a942a7caf391712c63f9","directDownload":"/cdn/c5/dc5b8a8f9178f2978521a942a7caf391712c63f9.zip","downloadURL":"/api/download/key/c5","coverURL":"/cdn/c5/dc5b8a8f9178f2978521a942a7caf391712c63f9.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":472.5,"length":232,"bombs":0,"notes":373,"obstacles":11,"njs":10,"njsOffset":0},"hard":{"duration":472.5,"length":232,"bombs":0,"notes":503,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":472.5,"length":232,"bombs":0,"notes":682,"obstacles":30,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Livin' On A Prayer","songSubName":"Bon Jovi","songAuthorName":"Bon Jovi","levelAuthorName":"jnua12345","bpm":122},"stats":{"downloads":478709,"plays":47593,"downVotes":851,"upVotes":2655,"heat":34.0723032,"rating":0.735230655617696},"description":"Expert, Hard, Normal 122BPM","deletedAt":null,"_id":"5cff620c48229f7d88fc6194","key":"bd","name":"Bon Jovi - Livin' On A Prayer","uploader":{"_id":"5cff0b7298cc5a672c84e9da","username":"jnua12345"},"uploaded":"2018-05-17T01:12:03.000Z","hash":"4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88","directDownload":"/cdn/bd/4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88.zip","downloadURL":"/api/download/key/bd","coverURL":"/cdn/bd/4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":265.510009765625,"length":189,"bombs":0,"notes":297,"obstacles":57,"njs":10,"njsOffset":0},"normal":{"duration":264.510009765625,"length":188,"bombs":0,"notes":358,"obstacles":62,"njs":10,"njsOffset":0},"hard":{"duration":266.010009765625,"length":190,"bombs":0,"notes":514,"obstacles":67,"njs":10,"njsOffset":0},"expert":{"duration":276.010009765625,"length":197,"bombs":0,"notes":681,"obstacles":67,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Clint Eastwood","songSubName":"Gorillaz","songAuthorName":"unknow","levelAuthorName":"freeek","bpm":84},"stats":{"downloads":478043,"plays":51819,"downVotes":376,"upVotes":5868,"heat":51.4978639,"rating":0.9081106988394465},"description":"Easy/Normal/Hard/Expert - Audio is as loud without clipping I swear! 100% Expert Playthrough: https://bit.ly/2LuFcxq\r\n\r\nHave fun! =D\r\n\r\n- Freeek =)","deletedAt":null,"_id":"5cff620c48229f7d88fc62e4","key":"225","name":"Clint Eastwood - Gorillaz","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-25T20:58:36.000Z","hash":"507f0e09326d37e09dca08e3c2597f027dbe1940","directDownload":"/cdn/225/507f0e09326d37e09dca08e3c2597f027dbe1940.zip","downloadURL":"/api/download/key/225","coverURL":"/cdn/225/507f0e09326d37e09dca08e3c2597f027dbe1940.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/downloads/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":1467.25,"length":440,"bombs":18,"notes":1356,"obstacles":22,"njs":10,"njsOffset":0},"expert":{"duration":1467.25,"length":440,"bombs":26,"notes":2806,"obstacles":36,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"TTFAF","songSubName":"DragonForce","songAuthorName":"Freeek","levelAuthorName":"freeek","bpm":200},"stats":{"downloads":476355,"plays":18661,"downVotes":1435,"upVotes":5082,"heat":23.7707358,"rating":0.7599138914656974},"description":"Expert and a Hard difficulty. 2800 notes and Even more event light triggers... Enjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc6117","key":"3a","name":"Through the Fire and Flames - Dragonforce","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-11T12:36:36.000Z","hash":"9b0881baab8b72f33424ecf0d9ad247ea10afbb2","directDownload":"/cdn/3a/9b0881baab8b72f33424ecf0d9ad247ea10afbb2.zip","downloadURL":"/api/download/key/3a","coverURL":"/cdn/3a/9b0881baab8b72f33424ecf0d9ad247ea10afbb2.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":523.625,"length":245,"bombs":0,"notes":633,"obstacles":13,"njs":10,"njsOffset":0},"expert":{"duration":523.5650024414062,"length":245,"bombs":0,"notes":880,"obstacles":19,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Uprising","songSubName":"Muse","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":128},"stats":{"downloads":469054,"plays":11598,"downVotes":168,"upVotes":9404,"heat":84.4290617,"rating":0.951897040235784},"description":"Expert / Hard\r\nhttps://youtu.be/WpFUt3UNp7c","deletedAt":null,"_id":"5cff620d48229f7d88fc655e","key":"4c6","name":"Muse - Uprising","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-06-11T21:47:41.000Z","hash":"00e5671e594a6fe621c3605fcc5a0e4466ba6478","directDownload":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.zip","downloadURL":"/api/download/key/4c6","coverURL":"/cdn/4c6/00e5671e594a6fe621c3605fcc5a0e4466ba6478.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":567.893310546875,"length":212,"bombs":24,"notes":604,"obstacles":27,"njs":10,"njsOffset":0},"hard":{"duration":567.893310546875,"length":212,"bombs":24,"notes":762,"obstacles":31,"njs":10,"njsOffset":0},"expert":{"duration":567.893310546875,"length":212,"bombs":24,"notes":776,"obstacles":31,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Shake It Off","songSubName":"Taylor Swift","songAuthorName":"Jovian","levelAuthorName":"jovian","bpm":160},"stats":{"downloads":464876,"plays":27795,"downVotes":354,"upVotes":5499,"heat":65.7906965,"rating":0.9072436169435235},"description":"Supports - Normal, Hard, Expert difficulties. \r\n\r\nGameplay: https://youtu.be/7pbtO1B7tK0\r\n\r\nExpert: As chartered (gameplay shown in video) \r\nHard: Cuts down drastically on arm cross overs.\r\nNormal: As above, additionally cuts down on double/triples and compresses strike range.","deletedAt":null,"_id":"5cff620c48229f7d88fc63f5","key":"348","name":"Taylor Swift - Shake It Off","uploader":{"_id":"5cff0b7298cc5a672c84ebab","username":"jovian"},"uploaded":"2018-06-02T07:59:29.000Z","hash":"483c7bc03133c6e215f3018e5033b0913821126f","directDownload":"/cdn/348/483c7bc03133c6e215f3018e5033b0913821126f.zip","downloadURL":"/api/download/key/348","coverURL":"/cdn/348/483c7bc03133c6e215f3018e5033b0913821126f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":461647,"plays":44907,"downVotes":583,"upVotes":7779,"heat":41.85238,"rating":0.9019004934599628},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":183.5,"length":81,"bombs":0,"notes":174,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":183.5,"length":81,"bombs":0,"notes":262,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Unravel","songSubName":"(TV Size)","songAuthorName":"TK","levelAuthorName":"winepic","bpm":135},"stats":{"downloads":451714,"plays":52247,"downVotes":377,"upVotes":4225,"heat":18.3387906,"rating":0.8850745894514591},"description":"Map made by me. Includes Hard and Expert difficulties.","deletedAt":null,"_id":"5cff620c48229f7d88fc60e5","key":"7","name":"Unravel (Tokyo Ghoul OP) TV Size","uploader":{"_id":"5cff0b7298cc5a672c84e8b6","username":"winepic"},"uploaded":"2018-05-08T16:25:10.000Z","hash":"b9867cdccf8b27d7a174c861adc69215c86cdab8","directDownload":"/cdn/7/b9867cdccf8b27d7a174c861adc69215c86cdab8.zip","downloadURL":"/api/download/key/7","coverURL":"/cdn/7/b9867cdccf8b27d7a174c861adc69215c86cdab8.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":439,"length":233,"bombs":0,"notes":579,"obstacles":38,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"CAN'T STOP THE FEELING!","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":113},"stats":{"downloads":450438,"plays":9160,"downVotes":171,"upVotes":6118,"heat":78.4738535,"rating":0.9388331738034267},"description":"Difficulties: Expert Only     Lighting by GreatYazer\r\nWatch Me on Twitch: http://www.twitch.tv/bennydabeastlive\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc64fd","key":"45e","name":"CAN'T STOP THE FEELING! - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-08T21:44:40.000Z","hash":"91ba25c089d50e93154e2c7920c46d4730f22569","directDownload":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.zip","downloadURL":"/api/download/key/45e","coverURL":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":368,"length":200,"bombs":9,"notes":603,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Bangarang","songSubName":"Skrillex","songAuthorName":"tmoss94","levelAuthorName":"tmoss94","bpm":110},"stats":{"downloads":436413,"plays":30721,"downVotes":322,"upVotes":2892,"heat":35.6625553,"rating":0.8646499394205489},"description":"Expert only, got a little lighting but nothing super fancy.","deletedAt":null,"_id":"5cff620c48229f7d88fc61a6","key":"d3","name":"Bangarang - Skrillex","uploader":{"_id":"5cff0b7298cc5a672c84eab3","username":"tmoss94"},"uploaded":"2018-05-17T19:09:28.000Z","hash":"4ffb4b82a7443e184aeace69b9d12f63d39b4386","directDownload":"/cdn/d3/4ffb4b82a7443e184aeace69b9d12f63d39b4386.zip","downloadURL":"/api/download/key/d3","coverURL":"/cdn/d3/4ffb4b82a7443e184aeace69b9d12f63d39b4386.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":417,"length":195,"bombs":4,"notes":301,"obstacles":24,"njs":10,"njsOffset":0},"hard":{"duration":417,"length":195,"bombs":4,"notes":486,"obstacles":24,"njs":10,"njsOffset":0},"expert":{"duration":417.5,"length":195,"bombs":4,"notes":620,"obstacles":24,"njs":10,"njsOffset":0},"expertPlus":{"duration":417.5,"length":195,"bombs":0,"notes":894,"obstacles":0,"njs":12,"njsOffset":0}}}],"songName":"Shut Up and Dance","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":434886,"plays":558,"downVotes":140,"upVotes":11118,"heat":395.8365232,"rating":0.9581602226568194},"description":"Difficulties: Expert+ (Added 11/15), Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/x9hJbTlPQUY","deletedAt":null,"_id":"5cff621148229f7d88fc77c9","key":"2144","name":"Shut Up and Dance - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-21T01:27:00.000Z","hash":"89cf8bb07afb3c59ae7b5ac00337d62261c36fb4","directDownload":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.zip","downloadURL":"/api/download/key/2144","coverURL":"/cdn/2144/89cf8bb07afb3c59ae7b5ac00337d62261c36fb4.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":486,"length":173,"bombs":0,"notes":368,"obstacles":285,"njs":10,"njsOffset":0},"expert":{"duration":486,"length":173,"bombs":4,"notes":523,"obstacles":329,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Thunder","songSubName":"(Maikyy Beatmap)","songAuthorName":"Imagine Dragons","levelAuthorName":"maikyy","bpm":168},"stats":{"downloads":434003,"plays":26685,"downVotes":718,"upVotes":1191,"heat":18.2184611,"rating":0.6111419715887695},"description":"Reupload with an added 'easier' Hard difficulty, really hard to balance so please let me know what I could change to make it better","deletedAt":null,"_id":"5cff620c48229f7d88fc60f2","key":"14","name":"Thunder - Imagine Dragons","uploader":{"_id":"5cff0b7298cc5a672c84e8e3","username":"maikyy"},"uploaded":"2018-05-09T02:17:42.000Z","hash":"f2c56d4ea62d31d04982db9a9e46199716a0dcfc","directDownload":"/cdn/14/f2c56d4ea62d31d04982db9a9e46199716a0dcfc.zip","downloadURL":"/api/download/key/14","coverURL":"/cdn/14/f2c56d4ea62d31d04982db9a9e46199716a0dcfc.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":290,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":374,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Portal - Still Alive (Uppermost Remix)","songSubName":"Uppermost","songAuthorName":"Kryptikos","levelAuthorName":"kryptikos","bpm":120},"stats":{"downloads":423606,"plays":32883,"downVotes":339,"upVotes":4970,"heat":47.4705859,"rating":0.9031649350412563},"description":"Second track mapped, includes Hard and Expert difficulties.\r\n\r\nI know that this isn't the hardest track, but I find it to be fun, which I see as priority number one. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc629f","key":"1dd","name":"Portal - Still Alive (Uppermost Remix)","uploader":{"_id":"5cff0b7298cc5a672c84eab4","username":"kryptikos"},"uploaded":"2018-05-23T19:33:41.000Z","hash":"01308128a87b6b561799359ee5aa213168c3b49f","directDownload":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.zip","downloadURL":"/api/download/key/1dd","coverURL":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.jpg"}],"totalDocs":36013,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/0?curated=true&sortOrder=Curated")
+                .unwrap(),
+            page_json(&["97d3", "65cb"]),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join("search/text/1?curated=true&sortOrder=Curated")
+                .unwrap(),
+            page_json(&[]),
+        );
         let client = FakeClientPaged::new(pages);
         assert_eq!(
             client
-                .maps_downloads_page_iter(1)
-                .map(|m| m.unwrap().key)
+                .maps_curated()
+                .map(|m| m.unwrap().id)
                 .collect::<Vec<String>>(),
-            vec![
-                "4a6".to_string(),
-                "1de".to_string(),
-                "e4".to_string(),
-                "29".to_string(),
-                "480".to_string(),
-                "1fef".to_string(),
-                "462".to_string(),
-                "c5".to_string(),
-                "bd".to_string(),
-                "225".to_string(),
-                "3a".to_string(),
-                "4c6".to_string(),
-                "348".to_string(),
-                "155".to_string(),
-                "7".to_string(),
-                "45e".to_string(),
-                "d3".to_string(),
-                "2144".to_string(),
-                "14".to_string(),
-                "1dd".to_string()
-            ]
+            vec!["97d3".to_string(), "65cb".to_string()]
         );
     }
     #[test]
-    fn test_maps_plays() {
-        let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/plays/0").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":328.556396484375,"length":142,"bombs":0,"notes":188,"obstacles":84,"njs":10,"njsOffset":0},"normal":{"duration":328.681396484375,"length":142,"bombs":40,"notes":219,"obstacles":70,"njs":10,"njsOffset":0},"hard":{"duration":328.681396484375,"length":142,"bombs":42,"notes":386,"obstacles":72,"njs":10,"njsOffset":0},"expert":{"duration":328.681396484375,"length":142,"bombs":46,"notes":623,"obstacles":69,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Beat it","songSubName":"Michael Jackson","songAuthorName":"Freeek","levelAuthorName":"freeek","bpm":139},"stats":{"downloads":952810,"plays":117624,"downVotes":785,"upVotes":12794,"heat":51.3065957,"rating":0.9169854042752824},"description":"Easy/Normal/Hard/Expert - Obstacles and mines purely for dance moves! 100% Expert Playthrough: https://bit.ly/2IKzCp3\r\n\r\n- Freeek =)","deletedAt":null,"_id":"5cff620c48229f7d88fc62d6","key":"217","name":"Beat it - Michael Jackson","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-25T14:20:19.000Z","hash":"4b2da842b687ec4cfbc948c583c21c79d4120de0","directDownload":"/cdn/217/4b2da842b687ec4cfbc948c583c21c79d4120de0.zip","downloadURL":"/api/download/key/217","coverURL":"/cdn/217/4b2da842b687ec4cfbc948c583c21c79d4120de0.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":468,"length":212,"bombs":4,"notes":415,"obstacles":42,"njs":10,"njsOffset":0},"hard":{"duration":468,"length":212,"bombs":40,"notes":695,"obstacles":94,"njs":10,"njsOffset":0},"expert":{"duration":468,"length":212,"bombs":50,"notes":932,"obstacles":103,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Gangnam Style","songSubName":"PSY","songAuthorName":"GreatYazer","levelAuthorName":"greatyazer","bpm":132},"stats":{"downloads":1084053,"plays":82700,"downVotes":627,"upVotes":17722,"heat":41.5115802,"rating":0.9415773790845633},"description":"Expert, Hard, and Normal tracks.  I tried my best to setup the chorus charts to allow you to mimic the classic dance moves.  I think it matches up quite nicely.  I hope you have as much fun playing as I did making this!  Enjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc620d","key":"141","name":"GANGNAM STYLE","uploader":{"_id":"5cff0b7298cc5a672c84ea71","username":"greatyazer"},"uploaded":"2018-05-20T09:59:02.000Z","hash":"8e7e553099436af31564adf1977a5ec42a61cfff","directDownload":"/cdn/141/8e7e553099436af31564adf1977a5ec42a61cfff.zip","downloadURL":"/api/download/key/141","coverURL":"/cdn/141/8e7e553099436af31564adf1977a5ec42a61cfff.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":640.7428588867188,"length":311,"bombs":57,"notes":423,"obstacles":33,"njs":10,"njsOffset":0},"expert":{"duration":640.7428588867188,"length":311,"bombs":68,"notes":616,"obstacles":33,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Harder Better Faster Stronger","songSubName":"Daft Punk","songAuthorName":"RunRockGame","levelAuthorName":"runrockgame","bpm":123},"stats":{"downloads":949302,"plays":74223,"downVotes":767,"upVotes":13305,"heat":65.0605616,"rating":0.9203726335924455},"description":"Expert & Hard | 600+ Blocks | Full Song 3:44 | Includes Lighting. Request to: @themakertales","deletedAt":null,"_id":"5cff620c48229f7d88fc63dd","key":"32e","name":"Daft Punk - Harder Better Faster Stronger","uploader":{"_id":"5cff0b7398cc5a672c84f04e","username":"runrockgame"},"uploaded":"2018-06-01T18:01:45.000Z","hash":"7c7f38d467bb43fe11a142581e63e324622ecc71","directDownload":"/cdn/32e/7c7f38d467bb43fe11a142581e63e324622ecc71.zip","downloadURL":"/api/download/key/32e","coverURL":"/cdn/32e/7c7f38d467bb43fe11a142581e63e324622ecc71.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":418,"length":200,"bombs":0,"notes":546,"obstacles":10,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Believer","songSubName":"Imagine Dragons","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":125},"stats":{"downloads":1057332,"plays":70725,"downVotes":360,"upVotes":9530,"heat":18.917836,"rating":0.9345288675447209},"description":"Currently expert only. Events included.","deletedAt":null,"_id":"5cff620c48229f7d88fc60e9","key":"b","name":"Imagine Dragons - Believer","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-05-08T18:56:36.000Z","hash":"19f2879d11a91b51a5c090d63471c3e8d9b7aee3","directDownload":"/cdn/b/19f2879d11a91b51a5c090d63471c3e8d9b7aee3.zip","downloadURL":"/api/download/key/b","coverURL":"/cdn/b/19f2879d11a91b51a5c090d63471c3e8d9b7aee3.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":342.8125,"length":165,"bombs":0,"notes":313,"obstacles":27,"njs":10,"njsOffset":0},"normal":{"duration":343.8125,"length":166,"bombs":0,"notes":480,"obstacles":27,"njs":10,"njsOffset":0},"hard":{"duration":343.8125,"length":166,"bombs":0,"notes":730,"obstacles":27,"njs":10,"njsOffset":0},"expert":{"duration":341.75,"length":165,"bombs":11,"notes":735,"obstacles":2,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Lone Digger","songSubName":"","songAuthorName":"Caravan Palace","levelAuthorName":"calijor","bpm":124},"stats":{"downloads":686632,"plays":57999,"downVotes":840,"upVotes":14419,"heat":46.39329,"rating":0.9204634795462161},"description":"Caravan Palace - Lone Digger\r\nEasy | Normal | Hard | Expert\r\nThis is a re-upload of my previous map, with improvements for hard, and a new, harder expert difficulty mapped by Squeaksies, as well as lower difficulties as iterations on my original map.\r\n\r\nBPM: 124\r\nDuration: 2:49\r\nNotes (Hard): 730\r\nNotes (Expert): 735\r\nPreview (Hard): https://youtu.be/NExvLUyeBUU\r\nPreview (Expert): https://youtu.be/NYmExXlpB0k","deletedAt":null,"_id":"5cff620c48229f7d88fc6282","key":"1bf","name":"Caravan Palace - Lone Digger","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-05-23T00:15:19.000Z","hash":"906160fd1f808e2f34f33c2ca5920118855c065d","directDownload":"/cdn/1bf/906160fd1f808e2f34f33c2ca5920118855c065d.zip","downloadURL":"/api/download/key/1bf","coverURL":"/cdn/1bf/906160fd1f808e2f34f33c2ca5920118855c065d.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":473.1875,"length":228,"bombs":0,"notes":399,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":473.1875,"length":228,"bombs":0,"notes":496,"obstacles":0,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"Seven Nation Army","songSubName":"The White Stripes","songAuthorName":"BlueASIS","levelAuthorName":"blueasis","bpm":124},"stats":{"downloads":786765,"plays":56470,"downVotes":447,"upVotes":11790,"heat":74.6827946,"rating":0.9362130919612548},"description":"UPDATED! @BlueASIS#4095 on Discord let me know what you think","deletedAt":null,"_id":"5cff620d48229f7d88fc64a0","key":"3fc","name":"The White Stripes - Seven Nation Army","uploader":{"_id":"5cff0b7298cc5a672c84eb5d","username":"blueasis"},"uploaded":"2018-06-06T18:51:03.000Z","hash":"0b0ad0f34b2d0687a9794bcf5019100fda06971e","directDownload":"/cdn/3fc/0b0ad0f34b2d0687a9794bcf5019100fda06971e.zip","downloadURL":"/api/download/key/3fc","coverURL":"/cdn/3fc/0b0ad0f34b2d0687a9794bcf5019100fda06971e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":183.5,"length":81,"bombs":0,"notes":174,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":183.5,"length":81,"bombs":0,"notes":262,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Unravel","songSubName":"(TV Size)","songAuthorName":"TK","levelAuthorName":"winepic","bpm":135},"stats":{"downloads":450948,"plays":52247,"downVotes":377,"upVotes":4214,"heat":18.3375474,"rating":0.8848700339609514},"description":"Map made by me. Includes Hard and Expert difficulties.","deletedAt":null,"_id":"5cff620c48229f7d88fc60e5","key":"7","name":"Unravel (Tokyo Ghoul OP) TV Size","uploader":{"_id":"5cff0b7298cc5a672c84e8b6","username":"winepic"},"uploaded":"2018-05-08T16:25:10.000Z","hash":"b9867cdccf8b27d7a174c861adc69215c86cdab8","directDownload":"/cdn/7/b9867cdccf8b27d7a174c861adc69215c86cdab8.zip","downloadURL":"/api/download/key/7","coverURL":"/cdn/7/b9867cdccf8b27d7a174c861adc69215c86cdab8.png"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":265.510009765625,"length":189,"bombs":0,"notes":297,"obstacles":57,"njs":10,"njsOffset":0},"normal":{"duration":264.510009765625,"length":188,"bombs":0,"notes":358,"obstacles":62,"njs":10,"njsOffset":0},"hard":{"duration":266.010009765625,"length":190,"bombs":0,"notes":514,"obstacles":67,"njs":10,"njsOffset":0},"expert":{"duration":276.010009765625,"length":197,"bombs":0,"notes":681,"obstacles":67,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Clint Eastwood","songSubName":"Gorillaz","songAuthorName":"unknow","levelAuthorName":"freeek","bpm":84},"stats":{"downloads":477413,"plays":51819,"downVotes":376,"upVotes":5856,"heat":51.4969139,"rating":0.9079847589829955},"description":"Easy/Normal/Hard/Expert - Audio is as loud without clipping I swear! 100% Expert Playthrough: https://bit.ly/2LuFcxq\r\n\r\nHave fun! =D\r\n\r\n- Freeek =)","deletedAt":null,"_id":"5cff620c48229f7d88fc62e4","key":"225","name":"Clint Eastwood - Gorillaz","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-25T20:58:36.000Z","hash":"507f0e09326d37e09dca08e3c2597f027dbe1940","directDownload":"/cdn/225/507f0e09326d37e09dca08e3c2597f027dbe1940.zip","downloadURL":"/api/download/key/225","coverURL":"/cdn/225/507f0e09326d37e09dca08e3c2597f027dbe1940.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":189,"length":90,"bombs":0,"notes":330,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Super Mario Bros. Theme (Overworld Theme)","songSubName":"Nintendo","songAuthorName":"red knight","levelAuthorName":"redknight","bpm":125},"stats":{"downloads":560209,"plays":49329,"downVotes":1105,"upVotes":4723,"heat":22.1640686,"rating":0.78757562838332},"description":"Expert only","deletedAt":null,"_id":"5cff620c48229f7d88fc6106","key":"29","name":"Super Mario Bros Theme","uploader":{"_id":"5cff0b7298cc5a672c84e917","username":"redknight"},"uploaded":"2018-05-10T16:34:12.000Z","hash":"c1c8e2b9394050afad435608137941da0b64b8f3","directDownload":"/cdn/29/c1c8e2b9394050afad435608137941da0b64b8f3.zip","downloadURL":"/api/download/key/29","coverURL":"/cdn/29/c1c8e2b9394050afad435608137941da0b64b8f3.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":472.5,"length":232,"bombs":0,"notes":373,"obstacles":11,"njs":10,"njsOffset":0},"hard":{"duration":472.5,"length":232,"bombs":0,"notes":503,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":472.5,"length":232,"bombs":0,"notes":682,"obstacles":30,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Livin' On A Prayer","songSubName":"Bon Jovi","songAuthorName":"Bon Jovi","levelAuthorName":"jnua12345","bpm":122},"stats":{"downloads":478160,"plays":47593,"downVotes":851,"upVotes":2653,"heat":34.0718215,"rating":0.7351001994714781},"description":"Expert, Hard, Normal 122BPM","deletedAt":null,"_id":"5cff620c48229f7d88fc6194","key":"bd","name":"Bon Jovi - Livin' On A Prayer","uploader":{"_id":"5cff0b7298cc5a672c84e9da","username":"jnua12345"},"uploaded":"2018-05-17T01:12:03.000Z","hash":"4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88","directDownload":"/cdn/bd/4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88.zip","downloadURL":"/api/download/key/bd","coverURL":"/cdn/bd/4b47cccc819825f10ffbbf0d52ce2a00cc7a5f88.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":null,"nextPage":1}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/plays/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":227.08416748046875,"length":194,"bombs":0,"notes":313,"obstacles":4,"njs":10,"njsOffset":0},"hard":{"duration":227.08416748046875,"length":194,"bombs":0,"notes":514,"obstacles":4,"njs":10,"njsOffset":0},"expert":{"duration":227.13458251953125,"length":194,"bombs":0,"notes":774,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Blue (KNY Factory Remix)","songSubName":"Effeil 65","songAuthorName":"Freeek","levelAuthorName":"freeek","bpm":70},"stats":{"downloads":334401,"plays":45173,"downVotes":676,"upVotes":2360,"heat":25.891831,"rating":0.752524991619825},"description":"Expert/Hard/Normal | Fun one handed chorus' ! | Very detailed event lighting | Enjoy!\r\n\r\n- Freeek","deletedAt":null,"_id":"5cff620c48229f7d88fc612b","key":"4e","name":"Blue - Eiffel 65 (KZY Factory Remix)","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-12T19:19:07.000Z","hash":"7de95858ce1d8d38296b3dfc524502f393153c33","directDownload":"/cdn/4e/7de95858ce1d8d38296b3dfc524502f393153c33.zip","downloadURL":"/api/download/key/4e","coverURL":"/cdn/4e/7de95858ce1d8d38296b3dfc524502f393153c33.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":460997,"plays":44907,"downVotes":583,"upVotes":7767,"heat":41.8516552,"rating":0.9017946384171858},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":291.375,"length":138,"bombs":0,"notes":291,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":291.375,"length":138,"bombs":0,"notes":367,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":291.375,"length":138,"bombs":0,"notes":409,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"September","songSubName":"","songAuthorName":"Earth, Wind & Fire","levelAuthorName":"calijor","bpm":126},"stats":{"downloads":539133,"plays":43006,"downVotes":338,"upVotes":8817,"heat":80.2856335,"rating":0.9333592430550526},"description":"Expert | Hard | Normal\r\n\r\nBPM - 126\r\nDuration - 2:21\r\n\r\nPreview: https://youtu.be/FOob1xit17Y","deletedAt":null,"_id":"5cff620d48229f7d88fc651e","key":"480","name":"Earth, Wind & Fire - September","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-06-09T18:27:58.000Z","hash":"aa2f7bf0df25cd57dddac159fa7c159f732e0553","directDownload":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.zip","downloadURL":"/api/download/key/480","coverURL":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":459.91717529296875,"length":194,"bombs":0,"notes":564,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Every Time We Touch","songSubName":"","songAuthorName":"Cascada","levelAuthorName":"purphoros","bpm":142},"stats":{"downloads":588020,"plays":42754,"downVotes":507,"upVotes":9279,"heat":36.8911653,"rating":0.9199971968096474},"description":"Expert Only\r\nTime - 3:19\r\nBPM - 142\r\nNotes- 564","deletedAt":null,"_id":"5cff620c48229f7d88fc61b5","key":"e4","name":"Every Time We Touch - Cascada","uploader":{"_id":"5cff0b7298cc5a672c84ea98","username":"purphoros"},"uploaded":"2018-05-18T03:51:03.000Z","hash":"bc6c7ef1385db4c11c59736d2b32eacf48c95bd9","directDownload":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.zip","downloadURL":"/api/download/key/e4","coverURL":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":474,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":747,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":1049,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Boulevard of Broken Dreams","songSubName":"Green Day","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":167},"stats":{"downloads":348312,"plays":39543,"downVotes":284,"upVotes":5594,"heat":69.6861834,"rating":0.9185588152087345},"description":"Expert - Hard - Normal\r\n1000+ Notes on Expert\r\nLighting Events","deletedAt":null,"_id":"5cff620d48229f7d88fc644c","key":"3a4","name":"Boulevard of Broken Dreams - Green Day","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-06-04T08:30:49.000Z","hash":"fa36428f6eed2648dade2fe320156adfaabe07b5","directDownload":"/cdn/3a4/fa36428f6eed2648dade2fe320156adfaabe07b5.zip","downloadURL":"/api/download/key/3a4","coverURL":"/cdn/3a4/fa36428f6eed2648dade2fe320156adfaabe07b5.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":623.3125,"length":214,"bombs":0,"notes":462,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":623.3125,"length":214,"bombs":0,"notes":639,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":623.3125,"length":214,"bombs":0,"notes":825,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Mr. Blue Sky","songSubName":"Electric Light Orchestra","songAuthorName":"GreatYazer","levelAuthorName":"greatyazer","bpm":174},"stats":{"downloads":945232,"plays":39426,"downVotes":498,"upVotes":23081,"heat":94.0252039,"rating":0.9557610240595098},"description":"Channel your inner Baby Groot.  Normal, Hard, Expert\r\nSpecial thanks to BennydaBeast for his help on this track!","deletedAt":null,"_id":"5cff620d48229f7d88fc65f7","key":"570","name":"Mr. Blue Sky | Electric Light Orchestra","uploader":{"_id":"5cff0b7298cc5a672c84ea71","username":"greatyazer"},"uploaded":"2018-06-16T16:53:34.000Z","hash":"236173d5ba7dc379d480b9cb5fb6b4fa5abe77da","directDownload":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.zip","downloadURL":"/api/download/key/570","coverURL":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":306,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":337,"obstacles":25,"njs":10,"njsOffset":0},"expert":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":448,"obstacles":25,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"The Fox (What Does The Fox Say?)","songSubName":"Ylvis","songAuthorName":"kyuz","levelAuthorName":"kyuz","bpm":128},"stats":{"downloads":251569,"plays":38274,"downVotes":179,"upVotes":3699,"heat":43.4754982,"rating":0.9161203732088851},"description":"Three difficulty levels. Even on expert this track is somewhat casual oriented and not ultra-difficult. Just a fun track with some goofy/jokey twists.","deletedAt":null,"_id":"5cff620c48229f7d88fc6252","key":"18b","name":"Ylvis - The Fox (What Does The Fox Say?)","uploader":{"_id":"5cff0b7298cc5a672c84eaab","username":"kyuz"},"uploaded":"2018-05-21T19:06:43.000Z","hash":"5ee3b92eb40778dee6469a517b43c8829fc8c53f","directDownload":"/cdn/18b/5ee3b92eb40778dee6469a517b43c8829fc8c53f.zip","downloadURL":"/api/download/key/18b","coverURL":"/cdn/18b/5ee3b92eb40778dee6469a517b43c8829fc8c53f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":450,"length":226,"bombs":0,"notes":714,"obstacles":32,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Sail (V2)","songSubName":"","songAuthorName":"AWOLNATION","levelAuthorName":"rellimjoe4","bpm":121},"stats":{"downloads":367076,"plays":37463,"downVotes":178,"upVotes":2098,"heat":24.1029679,"rating":0.8806367287255594},"description":"This is an outdated version. Please download the newer and much improved version - Sail (V3)\r\n\r\nDownload Link: https://beatsaver.com/browse/detail/631-405","deletedAt":null,"_id":"5cff620c48229f7d88fc611a","key":"3d","name":"Sail V2- AWOLNATION (outdated version)","uploader":{"_id":"5cff0b7298cc5a672c84e939","username":"rellimjoe4"},"uploaded":"2018-05-11T20:14:45.000Z","hash":"2b9c36605b9f4f8f780563da074cc439f0dd824e","directDownload":"/cdn/3d/2b9c36605b9f4f8f780563da074cc439f0dd824e.zip","downloadURL":"/api/download/key/3d","coverURL":"/cdn/3d/2b9c36605b9f4f8f780563da074cc439f0dd824e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":384,"length":136,"bombs":0,"notes":505,"obstacles":52,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Take On Me","songSubName":"","songAuthorName":"a-ha","levelAuthorName":"jackscape","bpm":168},"stats":{"downloads":778633,"plays":36801,"downVotes":559,"upVotes":6009,"heat":18.5954409,"rating":0.8854629990209468},"description":"Expert only","deletedAt":null,"_id":"5cff620c48229f7d88fc60e7","key":"9","name":"a-ha - Take On Me","uploader":{"_id":"5cff0b7298cc5a672c84e8bb","username":"jackscape"},"uploaded":"2018-05-08T17:44:17.000Z","hash":"2aa1f5192828e075c30dd015b1e132bba912eb86","directDownload":"/cdn/9/2aa1f5192828e075c30dd015b1e132bba912eb86.zip","downloadURL":"/api/download/key/9","coverURL":"/cdn/9/2aa1f5192828e075c30dd015b1e132bba912eb86.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":608.0845947265625,"length":251,"bombs":0,"notes":434,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":608.0845947265625,"length":251,"bombs":0,"notes":561,"obstacles":17,"njs":10,"njsOffset":0},"expert":{"duration":608.0845947265625,"length":251,"bombs":32,"notes":862,"obstacles":34,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"First Of The Year (Equinox)","songSubName":"","songAuthorName":"Skrillex","levelAuthorName":"fossilgenera","bpm":145},"stats":{"downloads":253516,"plays":36701,"downVotes":366,"upVotes":1998,"heat":41.954209,"rating":0.8118796525077013},"description":"Normal / Hard / Expert\r\n862 Notes || 34 Obstacles || 145 BPM\r\nVideo: https://youtu.be/hRSMbE0exFI\r\n\r\nNot responsible for seizures.\r\nEnjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc6235","key":"16c","name":"First Of The Year (Equinox) - Skrillex","uploader":{"_id":"5cff0b7298cc5a672c84ec3b","username":"fossilgenera"},"uploaded":"2018-05-21T04:16:07.000Z","hash":"8f2842e6043a3ec51df6641cb3d888337452aee1","directDownload":"/cdn/16c/8f2842e6043a3ec51df6641cb3d888337452aee1.zip","downloadURL":"/api/download/key/16c","coverURL":"/cdn/16c/8f2842e6043a3ec51df6641cb3d888337452aee1.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/plays/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":168.25,"length":71,"bombs":0,"notes":183,"obstacles":61,"njs":10,"njsOffset":0},"hard":{"duration":168.25,"length":71,"bombs":0,"notes":254,"obstacles":61,"njs":10,"njsOffset":0},"expert":{"duration":168.25,"length":71,"bombs":0,"notes":377,"obstacles":61,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"He's a pirate","songSubName":"Hans Zimmer & Klaus Badelt","songAuthorName":"Hans Zimmer & Klaus Badelt","levelAuthorName":"jnua12345","bpm":140},"stats":{"downloads":409971,"plays":36415,"downVotes":3884,"upVotes":1204,"heat":22.9578874,"rating":0.2568072751953776},"description":"Expert, Hard, and Normal 140 BPM","deletedAt":null,"_id":"5cff620c48229f7d88fc6159","key":"80","name":"He's a pirate - Hans Zimmer & Klaus Badelt","uploader":{"_id":"5cff0b7298cc5a672c84e9da","username":"jnua12345"},"uploaded":"2018-05-14T17:49:31.000Z","hash":"f1ec6967a2a3940c2e65fcb207fc418f2813403d","directDownload":"/cdn/80/f1ec6967a2a3940c2e65fcb207fc418f2813403d.zip","downloadURL":"/api/download/key/80","coverURL":"/cdn/80/f1ec6967a2a3940c2e65fcb207fc418f2813403d.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":574,"obstacles":2,"njs":10,"njsOffset":0},"hard":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":739,"obstacles":2,"njs":10,"njsOffset":0},"expert":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":849,"obstacles":2,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"I Gotta Feeling","songSubName":"The Black Eyed Peas","songAuthorName":"RunRockGame","levelAuthorName":"runrockgame","bpm":128},"stats":{"downloads":343109,"plays":36096,"downVotes":524,"upVotes":3120,"heat":65.3748158,"rating":0.8260359199604895},"description":"Expert, Hard & Normal | 800+ Blocks | Full Song 4:56 | Includes Lighting. Request to: @themakertales","deletedAt":null,"_id":"5cff620c48229f7d88fc63f1","key":"344","name":"The Black Eyed Peas - I Gotta Feeling","uploader":{"_id":"5cff0b7398cc5a672c84f04e","username":"runrockgame"},"uploaded":"2018-06-02T06:30:23.000Z","hash":"0e440ed89a72fbe2b9835fcdc57688423d0b7d02","directDownload":"/cdn/344/0e440ed89a72fbe2b9835fcdc57688423d0b7d02.zip","downloadURL":"/api/download/key/344","coverURL":"/cdn/344/0e440ed89a72fbe2b9835fcdc57688423d0b7d02.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":324,"length":162,"bombs":0,"notes":335,"obstacles":22,"njs":10,"njsOffset":0},"expert":{"duration":324,"length":162,"bombs":0,"notes":511,"obstacles":11,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"New Dawn","songSubName":"Prototyperaptor","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":120},"stats":{"downloads":155431,"plays":35038,"downVotes":208,"upVotes":569,"heat":17.9584628,"rating":0.7009864107208541},"description":"Hard/Expert + Lights","deletedAt":null,"_id":"5cff620c48229f7d88fc60ef","key":"11","name":"Prototyperaptor - New Dawn","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-05-09T00:30:43.000Z","hash":"b677fb72f916f74d69e532b279ce90b28e4fa14f","directDownload":"/cdn/11/b677fb72f916f74d69e532b279ce90b28e4fa14f.zip","downloadURL":"/api/download/key/11","coverURL":"/cdn/11/b677fb72f916f74d69e532b279ce90b28e4fa14f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":344,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":553,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":835,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"American Idiot","songSubName":"Green Day","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":186},"stats":{"downloads":312681,"plays":34648,"downVotes":417,"upVotes":5288,"heat":91.1286181,"rating":0.895315180713646},"description":"Expert - Hard - Normal Charts\r\nLighting Events","deletedAt":null,"_id":"5cff620d48229f7d88fc65ce","key":"541","name":"American Idiot - Green Day","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-06-15T13:00:45.000Z","hash":"4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569","directDownload":"/cdn/541/4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569.zip","downloadURL":"/api/download/key/541","coverURL":"/cdn/541/4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":388.90625,"length":191,"bombs":0,"notes":402,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":389,"length":191,"bombs":0,"notes":618,"obstacles":15,"njs":10,"njsOffset":0},"expert":{"duration":389,"length":191,"bombs":0,"notes":914,"obstacles":15,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Black Betty","songSubName":"Caravan Palace Cover","songAuthorName":"Caravan Palace","levelAuthorName":"calijor","bpm":122},"stats":{"downloads":278929,"plays":34223,"downVotes":418,"upVotes":3856,"heat":49.5446392,"rating":0.8697339511120226},"description":"Caravan Palace - Black Betty (cover)\r\nThis is not the classic Black Betty but instead an electro-swing cover by Caravan Palace, and it is a banger.\r\n\r\nNormal | Hard | Expert\r\n\r\nBPM: 122\r\nNotes (Expert): 914\r\nDuration: 3:11\r\n\r\nPreview: https://youtu.be/5SgT9hUO7rU","deletedAt":null,"_id":"5cff620c48229f7d88fc62c8","key":"208","name":"Caravan Palace - Black Betty (cover)","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-05-24T23:06:15.000Z","hash":"32d2e0072615066f6958ea33519f62eca7d8f59e","directDownload":"/cdn/208/32d2e0072615066f6958ea33519f62eca7d8f59e.zip","downloadURL":"/api/download/key/208","coverURL":"/cdn/208/32d2e0072615066f6958ea33519f62eca7d8f59e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":365.49951171875,"length":162,"bombs":0,"notes":275,"obstacles":70,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"You're Welcome","songSubName":"Moana","songAuthorName":"Prime","levelAuthorName":"prime","bpm":135},"stats":{"downloads":367495,"plays":34012,"downVotes":278,"upVotes":3981,"heat":53.6019093,"rating":0.8995983405791548},"description":"Difficulties: Hard\r\nBPM: 135\r\nLights: Done\r\nListen: https://www.youtube.com/watch?v=79DijItQXMM\r\n\r\nNot meant to be challenging, just a feel-good song to enjoy :)","deletedAt":null,"_id":"5cff620c48229f7d88fc631d","key":"261","name":"Moana - You're Welcome","uploader":{"_id":"5cff0b7298cc5a672c84eb1e","username":"prime"},"uploaded":"2018-05-27T01:25:01.000Z","hash":"0e4b5e325760bdabb66caea4506c5463daf4b51f","directDownload":"/cdn/261/0e4b5e325760bdabb66caea4506c5463daf4b51f.zip","downloadURL":"/api/download/key/261","coverURL":"/cdn/261/0e4b5e325760bdabb66caea4506c5463daf4b51f.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":596.3594360351562,"length":176,"bombs":0,"notes":349,"obstacles":54,"njs":10,"njsOffset":0},"normal":{"duration":591.7109985351562,"length":174,"bombs":0,"notes":399,"obstacles":78,"njs":10,"njsOffset":0},"hard":{"duration":591.7109985351562,"length":174,"bombs":0,"notes":491,"obstacles":81,"njs":10,"njsOffset":0},"expert":{"duration":591.6094360351562,"length":174,"bombs":28,"notes":498,"obstacles":90,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Kung Fu Fighting","songSubName":"Carl Douglas","songAuthorName":"Kleid","levelAuthorName":"kleid","bpm":103},"stats":{"downloads":284190,"plays":32946,"downVotes":239,"upVotes":2353,"heat":44.8903939,"rating":0.8695298560920729},"description":"Kung Fu Fighting (Carl Douglas)\r\nFinished lighting\r\nDifficulties: Easy, Normal, Hard, Expert","deletedAt":null,"_id":"5cff620c48229f7d88fc626b","key":"1a8","name":"Kung Fu Fighting (Carl Douglas)","uploader":{"_id":"5cff0b7398cc5a672c84ecd9","username":"kleid"},"uploaded":"2018-05-22T15:33:58.000Z","hash":"dc3525c5a21d3dee732966e7b46ecc06120f7b84","directDownload":"/cdn/1a8/dc3525c5a21d3dee732966e7b46ecc06120f7b84.zip","downloadURL":"/api/download/key/1a8","coverURL":"/cdn/1a8/dc3525c5a21d3dee732966e7b46ecc06120f7b84.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":290,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":374,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Portal - Still Alive (Uppermost Remix)","songSubName":"Uppermost","songAuthorName":"Kryptikos","levelAuthorName":"kryptikos","bpm":120},"stats":{"downloads":422910,"plays":32883,"downVotes":339,"upVotes":4964,"heat":47.4700228,"rating":0.9030869269745219},"description":"Second track mapped, includes Hard and Expert difficulties.\r\n\r\nI know that this isn't the hardest track, but I find it to be fun, which I see as priority number one. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc629f","key":"1dd","name":"Portal - Still Alive (Uppermost Remix)","uploader":{"_id":"5cff0b7298cc5a672c84eab4","username":"kryptikos"},"uploaded":"2018-05-23T19:33:41.000Z","hash":"01308128a87b6b561799359ee5aa213168c3b49f","directDownload":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.zip","downloadURL":"/api/download/key/1dd","coverURL":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":458,"length":196,"bombs":0,"notes":412,"obstacles":21,"njs":10,"njsOffset":0},"hard":{"duration":458,"length":196,"bombs":0,"notes":500,"obstacles":23,"njs":10,"njsOffset":0},"expert":{"duration":458,"length":196,"bombs":16,"notes":877,"obstacles":23,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"DotA","songSubName":"","songAuthorName":"Basshunter","levelAuthorName":"fossilgenera","bpm":140},"stats":{"downloads":317287,"plays":32014,"downVotes":605,"upVotes":7613,"heat":48.2240608,"rating":0.8981114914293818},"description":"Normal / Hard / Expert\r\n877 Notes || 23 Obstacles || Events || 140 BPM\r\nVideo: https://youtu.be/QBkYyVkIdm0\r\n\r\nNot responsible for seizures.\r\nEnjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc62b0","key":"1ef","name":"DotA - Basshunter","uploader":{"_id":"5cff0b7298cc5a672c84ec3b","username":"fossilgenera"},"uploaded":"2018-05-24T02:43:51.000Z","hash":"dc4906a7f76965cdd2b75c72cf470344b698e352","directDownload":"/cdn/1ef/dc4906a7f76965cdd2b75c72cf470344b698e352.zip","downloadURL":"/api/download/key/1ef","coverURL":"/cdn/1ef/dc4906a7f76965cdd2b75c72cf470344b698e352.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":291,"length":136,"bombs":4,"notes":353,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":291,"length":136,"bombs":4,"notes":455,"obstacles":15,"njs":10,"njsOffset":0},"expert":{"duration":291,"length":136,"bombs":10,"notes":526,"obstacles":15,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"LUVORATORRRRRY!","songSubName":"feat.nqrse","songAuthorName":"Reol","levelAuthorName":"datkami","bpm":128},"stats":{"downloads":275679,"plays":31684,"downVotes":181,"upVotes":4276,"heat":21.0850539,"rating":0.9227729012046024},"description":"Hard (353 notes) / Hard+ (455 notes) / Expert (526 notes) / 15 Obstacles / Video Demonstration: https://streamable.com/23ayv / Part 1 of the J-EDM Graduation series! Use this song pack to level up your game!","deletedAt":null,"_id":"5cff620c48229f7d88fc60fe","key":"21","name":"REOL feat. nqrse - LUVORATORRRRRY!","uploader":{"_id":"5cff0b7298cc5a672c84e8a3","username":"datkami"},"uploaded":"2018-05-10T02:24:36.000Z","hash":"c807689fefdae82aa79ba9c7f861118fb426b4cc","directDownload":"/cdn/21/c807689fefdae82aa79ba9c7f861118fb426b4cc.zip","downloadURL":"/api/download/key/21","coverURL":"/cdn/21/c807689fefdae82aa79ba9c7f861118fb426b4cc.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
+    fn test_search_filtered_page() {
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join("search/text/0?q=camellia&tags=tech%2C%21anime")
+                .unwrap(),
+            page_json(&["2144"]),
+        );
+        let options = SearchOptions::new("camellia")
+            .with_tag(Tag::Tech)
+            .without_tag(Tag::Anime);
+        let page = client.search_filtered_page(&options, 0).unwrap();
+        assert_eq!(page.docs[0].id, "2144");
+    }
+    #[test]
+    fn test_search_sorted() {
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join("search/text/2?q=camellia&sortOrder=Curated")
+                .unwrap(),
+            page_json(&["2144"]),
+        );
+        let options = SearchOptions::new("camellia").with_sort(SortOrder::Curated);
+        let page = client.search_filtered_page(&options, 2).unwrap();
+        assert_eq!(page.docs[0].id, "2144");
+    }
+    #[test]
+    fn test_search_advanced_invalid() {
+        let client = FakeClient::new(BEATSAVER_URL.join("search/text/0").unwrap(), "".into());
+        assert!(matches!(
+            client.search_advanced_page("songName:(test", 0),
+            Err(BeatSaverApiError::ArgumentError(_))
+        ));
+    }
+    #[test]
+    fn test_download_preview() {
+        let map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        let version = map.latest_version().unwrap();
+        let mut client = FakeClient::new(
+            Url::parse(version.preview_url.as_str()).unwrap(),
+            Bytes::from_static(b"ID3"),
+        );
+        client.credentials = Some(Credentials::Bearer("abc".into()));
         assert_eq!(
-            client
-                .maps_plays()
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "217".to_string(),
-                "141".to_string(),
-                "32e".to_string(),
-                "b".to_string(),
-                "1bf".to_string(),
-                "3fc".to_string(),
-                "7".to_string(),
-                "225".to_string(),
-                "29".to_string(),
-                "bd".to_string(),
-                "4e".to_string(),
-                "155".to_string(),
-                "480".to_string(),
-                "e4".to_string(),
-                "3a4".to_string(),
-                "570".to_string(),
-                "18b".to_string(),
-                "3d".to_string(),
-                "9".to_string(),
-                "16c".to_string(),
-                "80".to_string(),
-                "344".to_string(),
-                "11".to_string(),
-                "541".to_string(),
-                "208".to_string(),
-                "261".to_string(),
-                "1a8".to_string(),
-                "1dd".to_string(),
-                "1ef".to_string(),
-                "21".to_string()
-            ]
+            client.download_preview(version).unwrap(),
+            Bytes::from_static(b"ID3")
         );
     }
     #[test]
-    fn test_maps_plays_page() {
+    fn test_download_cover() {
+        let map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        let version = map.latest_version().unwrap();
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/plays/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":227.08416748046875,"length":194,"bombs":0,"notes":313,"obstacles":4,"njs":10,"njsOffset":0},"hard":{"duration":227.08416748046875,"length":194,"bombs":0,"notes":514,"obstacles":4,"njs":10,"njsOffset":0},"expert":{"duration":227.13458251953125,"length":194,"bombs":0,"notes":774,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Blue (KNY Factory Remix)","songSubName":"Effeil 65","songAuthorName":"Freeek","levelAuthorName":"freeek","bpm":70},"stats":{"downloads":334401,"plays":45173,"downVotes":676,"upVotes":2360,"heat":25.891831,"rating":0.752524991619825},"description":"Expert/Hard/Normal | Fun one handed chorus' ! | Very detailed event lighting | Enjoy!\r\n\r\n- Freeek","deletedAt":null,"_id":"5cff620c48229f7d88fc612b","key":"4e","name":"Blue - Eiffel 65 (KZY Factory Remix)","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-12T19:19:07.000Z","hash":"7de95858ce1d8d38296b3dfc524502f393153c33","directDownload":"/cdn/4e/7de95858ce1d8d38296b3dfc524502f393153c33.zip","downloadURL":"/api/download/key/4e","coverURL":"/cdn/4e/7de95858ce1d8d38296b3dfc524502f393153c33.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":460997,"plays":44907,"downVotes":583,"upVotes":7767,"heat":41.8516552,"rating":0.9017946384171858},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":291.375,"length":138,"bombs":0,"notes":291,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":291.375,"length":138,"bombs":0,"notes":367,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":291.375,"length":138,"bombs":0,"notes":409,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"September","songSubName":"","songAuthorName":"Earth, Wind & Fire","levelAuthorName":"calijor","bpm":126},"stats":{"downloads":539133,"plays":43006,"downVotes":338,"upVotes":8817,"heat":80.2856335,"rating":0.9333592430550526},"description":"Expert | Hard | Normal\r\n\r\nBPM - 126\r\nDuration - 2:21\r\n\r\nPreview: https://youtu.be/FOob1xit17Y","deletedAt":null,"_id":"5cff620d48229f7d88fc651e","key":"480","name":"Earth, Wind & Fire - September","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-06-09T18:27:58.000Z","hash":"aa2f7bf0df25cd57dddac159fa7c159f732e0553","directDownload":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.zip","downloadURL":"/api/download/key/480","coverURL":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":459.91717529296875,"length":194,"bombs":0,"notes":564,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Every Time We Touch","songSubName":"","songAuthorName":"Cascada","levelAuthorName":"purphoros","bpm":142},"stats":{"downloads":588020,"plays":42754,"downVotes":507,"upVotes":9279,"heat":36.8911653,"rating":0.9199971968096474},"description":"Expert Only\r\nTime - 3:19\r\nBPM - 142\r\nNotes- 564","deletedAt":null,"_id":"5cff620c48229f7d88fc61b5","key":"e4","name":"Every Time We Touch - Cascada","uploader":{"_id":"5cff0b7298cc5a672c84ea98","username":"purphoros"},"uploaded":"2018-05-18T03:51:03.000Z","hash":"bc6c7ef1385db4c11c59736d2b32eacf48c95bd9","directDownload":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.zip","downloadURL":"/api/download/key/e4","coverURL":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":474,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":747,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":1049,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Boulevard of Broken Dreams","songSubName":"Green Day","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":167},"stats":{"downloads":348312,"plays":39543,"downVotes":284,"upVotes":5594,"heat":69.6861834,"rating":0.9185588152087345},"description":"Expert - Hard - Normal\r\n1000+ Notes on Expert\r\nLighting Events","deletedAt":null,"_id":"5cff620d48229f7d88fc644c","key":"3a4","name":"Boulevard of Broken Dreams - Green Day","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-06-04T08:30:49.000Z","hash":"fa36428f6eed2648dade2fe320156adfaabe07b5","directDownload":"/cdn/3a4/fa36428f6eed2648dade2fe320156adfaabe07b5.zip","downloadURL":"/api/download/key/3a4","coverURL":"/cdn/3a4/fa36428f6eed2648dade2fe320156adfaabe07b5.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":623.3125,"length":214,"bombs":0,"notes":462,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":623.3125,"length":214,"bombs":0,"notes":639,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":623.3125,"length":214,"bombs":0,"notes":825,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Mr. Blue Sky","songSubName":"Electric Light Orchestra","songAuthorName":"GreatYazer","levelAuthorName":"greatyazer","bpm":174},"stats":{"downloads":945232,"plays":39426,"downVotes":498,"upVotes":23081,"heat":94.0252039,"rating":0.9557610240595098},"description":"Channel your inner Baby Groot.  Normal, Hard, Expert\r\nSpecial thanks to BennydaBeast for his help on this track!","deletedAt":null,"_id":"5cff620d48229f7d88fc65f7","key":"570","name":"Mr. Blue Sky | Electric Light Orchestra","uploader":{"_id":"5cff0b7298cc5a672c84ea71","username":"greatyazer"},"uploaded":"2018-06-16T16:53:34.000Z","hash":"236173d5ba7dc379d480b9cb5fb6b4fa5abe77da","directDownload":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.zip","downloadURL":"/api/download/key/570","coverURL":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":306,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":337,"obstacles":25,"njs":10,"njsOffset":0},"expert":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":448,"obstacles":25,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"The Fox (What Does The Fox Say?)","songSubName":"Ylvis","songAuthorName":"kyuz","levelAuthorName":"kyuz","bpm":128},"stats":{"downloads":251569,"plays":38274,"downVotes":179,"upVotes":3699,"heat":43.4754982,"rating":0.9161203732088851},"description":"Three difficulty levels. Even on expert this track is somewhat casual oriented and not ultra-difficult. Just a fun track with some goofy/jokey twists.","deletedAt":null,"_id":"5cff620c48229f7d88fc6252","key":"18b","name":"Ylvis - The Fox (What Does The Fox Say?)","uploader":{"_id":"5cff0b7298cc5a672c84eaab","username":"kyuz"},"uploaded":"2018-05-21T19:06:43.000Z","hash":"5ee3b92eb40778dee6469a517b43c8829fc8c53f","directDownload":"/cdn/18b/5ee3b92eb40778dee6469a517b43c8829fc8c53f.zip","downloadURL":"/api/download/key/18b","coverURL":"/cdn/18b/5ee3b92eb40778dee6469a517b43c8829fc8c53f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":450,"length":226,"bombs":0,"notes":714,"obstacles":32,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Sail (V2)","songSubName":"","songAuthorName":"AWOLNATION","levelAuthorName":"rellimjoe4","bpm":121},"stats":{"downloads":367076,"plays":37463,"downVotes":178,"upVotes":2098,"heat":24.1029679,"rating":0.8806367287255594},"description":"This is an outdated version. Please download the newer and much improved version - Sail (V3)\r\n\r\nDownload Link: https://beatsaver.com/browse/detail/631-405","deletedAt":null,"_id":"5cff620c48229f7d88fc611a","key":"3d","name":"Sail V2- AWOLNATION (outdated version)","uploader":{"_id":"5cff0b7298cc5a672c84e939","username":"rellimjoe4"},"uploaded":"2018-05-11T20:14:45.000Z","hash":"2b9c36605b9f4f8f780563da074cc439f0dd824e","directDownload":"/cdn/3d/2b9c36605b9f4f8f780563da074cc439f0dd824e.zip","downloadURL":"/api/download/key/3d","coverURL":"/cdn/3d/2b9c36605b9f4f8f780563da074cc439f0dd824e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":384,"length":136,"bombs":0,"notes":505,"obstacles":52,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Take On Me","songSubName":"","songAuthorName":"a-ha","levelAuthorName":"jackscape","bpm":168},"stats":{"downloads":778633,"plays":36801,"downVotes":559,"upVotes":6009,"heat":18.5954409,"rating":0.8854629990209468},"description":"Expert only","deletedAt":null,"_id":"5cff620c48229f7d88fc60e7","key":"9","name":"a-ha - Take On Me","uploader":{"_id":"5cff0b7298cc5a672c84e8bb","username":"jackscape"},"uploaded":"2018-05-08T17:44:17.000Z","hash":"2aa1f5192828e075c30dd015b1e132bba912eb86","directDownload":"/cdn/9/2aa1f5192828e075c30dd015b1e132bba912eb86.zip","downloadURL":"/api/download/key/9","coverURL":"/cdn/9/2aa1f5192828e075c30dd015b1e132bba912eb86.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":608.0845947265625,"length":251,"bombs":0,"notes":434,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":608.0845947265625,"length":251,"bombs":0,"notes":561,"obstacles":17,"njs":10,"njsOffset":0},"expert":{"duration":608.0845947265625,"length":251,"bombs":32,"notes":862,"obstacles":34,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"First Of The Year (Equinox)","songSubName":"","songAuthorName":"Skrillex","levelAuthorName":"fossilgenera","bpm":145},"stats":{"downloads":253516,"plays":36701,"downVotes":366,"upVotes":1998,"heat":41.954209,"rating":0.8118796525077013},"description":"Normal / Hard / Expert\r\n862 Notes || 34 Obstacles || 145 BPM\r\nVideo: https://youtu.be/hRSMbE0exFI\r\n\r\nNot responsible for seizures.\r\nEnjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc6235","key":"16c","name":"First Of The Year (Equinox) - Skrillex","uploader":{"_id":"5cff0b7298cc5a672c84ec3b","username":"fossilgenera"},"uploaded":"2018-05-21T04:16:07.000Z","hash":"8f2842e6043a3ec51df6641cb3d888337452aee1","directDownload":"/cdn/16c/8f2842e6043a3ec51df6641cb3d888337452aee1.zip","downloadURL":"/api/download/key/16c","coverURL":"/cdn/16c/8f2842e6043a3ec51df6641cb3d888337452aee1.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/plays/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":168.25,"length":71,"bombs":0,"notes":183,"obstacles":61,"njs":10,"njsOffset":0},"hard":{"duration":168.25,"length":71,"bombs":0,"notes":254,"obstacles":61,"njs":10,"njsOffset":0},"expert":{"duration":168.25,"length":71,"bombs":0,"notes":377,"obstacles":61,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"He's a pirate","songSubName":"Hans Zimmer & Klaus Badelt","songAuthorName":"Hans Zimmer & Klaus Badelt","levelAuthorName":"jnua12345","bpm":140},"stats":{"downloads":409971,"plays":36415,"downVotes":3884,"upVotes":1204,"heat":22.9578874,"rating":0.2568072751953776},"description":"Expert, Hard, and Normal 140 BPM","deletedAt":null,"_id":"5cff620c48229f7d88fc6159","key":"80","name":"He's a pirate - Hans Zimmer & Klaus Badelt","uploader":{"_id":"5cff0b7298cc5a672c84e9da","username":"jnua12345"},"uploaded":"2018-05-14T17:49:31.000Z","hash":"f1ec6967a2a3940c2e65fcb207fc418f2813403d","directDownload":"/cdn/80/f1ec6967a2a3940c2e65fcb207fc418f2813403d.zip","downloadURL":"/api/download/key/80","coverURL":"/cdn/80/f1ec6967a2a3940c2e65fcb207fc418f2813403d.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":574,"obstacles":2,"njs":10,"njsOffset":0},"hard":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":739,"obstacles":2,"njs":10,"njsOffset":0},"expert":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":849,"obstacles":2,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"I Gotta Feeling","songSubName":"The Black Eyed Peas","songAuthorName":"RunRockGame","levelAuthorName":"runrockgame","bpm":128},"stats":{"downloads":343109,"plays":36096,"downVotes":524,"upVotes":3120,"heat":65.3748158,"rating":0.8260359199604895},"description":"Expert, Hard & Normal | 800+ Blocks | Full Song 4:56 | Includes Lighting. Request to: @themakertales","deletedAt":null,"_id":"5cff620c48229f7d88fc63f1","key":"344","name":"The Black Eyed Peas - I Gotta Feeling","uploader":{"_id":"5cff0b7398cc5a672c84f04e","username":"runrockgame"},"uploaded":"2018-06-02T06:30:23.000Z","hash":"0e440ed89a72fbe2b9835fcdc57688423d0b7d02","directDownload":"/cdn/344/0e440ed89a72fbe2b9835fcdc57688423d0b7d02.zip","downloadURL":"/api/download/key/344","coverURL":"/cdn/344/0e440ed89a72fbe2b9835fcdc57688423d0b7d02.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":324,"length":162,"bombs":0,"notes":335,"obstacles":22,"njs":10,"njsOffset":0},"expert":{"duration":324,"length":162,"bombs":0,"notes":511,"obstacles":11,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"New Dawn","songSubName":"Prototyperaptor","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":120},"stats":{"downloads":155431,"plays":35038,"downVotes":208,"upVotes":569,"heat":17.9584628,"rating":0.7009864107208541},"description":"Hard/Expert + Lights","deletedAt":null,"_id":"5cff620c48229f7d88fc60ef","key":"11","name":"Prototyperaptor - New Dawn","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-05-09T00:30:43.000Z","hash":"b677fb72f916f74d69e532b279ce90b28e4fa14f","directDownload":"/cdn/11/b677fb72f916f74d69e532b279ce90b28e4fa14f.zip","downloadURL":"/api/download/key/11","coverURL":"/cdn/11/b677fb72f916f74d69e532b279ce90b28e4fa14f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":344,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":553,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":835,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"American Idiot","songSubName":"Green Day","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":186},"stats":{"downloads":312681,"plays":34648,"downVotes":417,"upVotes":5288,"heat":91.1286181,"rating":0.895315180713646},"description":"Expert - Hard - Normal Charts\r\nLighting Events","deletedAt":null,"_id":"5cff620d48229f7d88fc65ce","key":"541","name":"American Idiot - Green Day","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-06-15T13:00:45.000Z","hash":"4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569","directDownload":"/cdn/541/4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569.zip","downloadURL":"/api/download/key/541","coverURL":"/cdn/541/4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":388.90625,"length":191,"bombs":0,"notes":402,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":389,"length":191,"bombs":0,"notes":618,"obstacles":15,"njs":10,"njsOffset":0},"expert":{"duration":389,"length":191,"bombs":0,"notes":914,"obstacles":15,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Black Betty","songSubName":"Caravan Palace Cover","songAuthorName":"Caravan Palace","levelAuthorName":"calijor","bpm":122},"stats":{"downloads":278929,"plays":34223,"downVotes":418,"upVotes":3856,"heat":49.5446392,"rating":0.8697339511120226},"description":"Caravan Palace - Black Betty (cover)\r\nThis is not the classic Black Betty but instead an electro-swing cover by Caravan Palace, and it is a banger.\r\n\r\nNormal | Hard | Expert\r\n\r\nBPM: 122\r\nNotes (Expert): 914\r\nDuration: 3:11\r\n\r\nPreview: https://youtu.be/5SgT9hUO7rU","deletedAt":null,"_id":"5cff620c48229f7d88fc62c8","key":"208","name":"Caravan Palace - Black Betty (cover)","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-05-24T23:06:15.000Z","hash":"32d2e0072615066f6958ea33519f62eca7d8f59e","directDownload":"/cdn/208/32d2e0072615066f6958ea33519f62eca7d8f59e.zip","downloadURL":"/api/download/key/208","coverURL":"/cdn/208/32d2e0072615066f6958ea33519f62eca7d8f59e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":365.49951171875,"length":162,"bombs":0,"notes":275,"obstacles":70,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"You're Welcome","songSubName":"Moana","songAuthorName":"Prime","levelAuthorName":"prime","bpm":135},"stats":{"downloads":367495,"plays":34012,"downVotes":278,"upVotes":3981,"heat":53.6019093,"rating":0.8995983405791548},"description":"Difficulties: Hard\r\nBPM: 135\r\nLights: Done\r\nListen: https://www.youtube.com/watch?v=79DijItQXMM\r\n\r\nNot meant to be challenging, just a feel-good song to enjoy :)","deletedAt":null,"_id":"5cff620c48229f7d88fc631d","key":"261","name":"Moana - You're Welcome","uploader":{"_id":"5cff0b7298cc5a672c84eb1e","username":"prime"},"uploaded":"2018-05-27T01:25:01.000Z","hash":"0e4b5e325760bdabb66caea4506c5463daf4b51f","directDownload":"/cdn/261/0e4b5e325760bdabb66caea4506c5463daf4b51f.zip","downloadURL":"/api/download/key/261","coverURL":"/cdn/261/0e4b5e325760bdabb66caea4506c5463daf4b51f.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":596.3594360351562,"length":176,"bombs":0,"notes":349,"obstacles":54,"njs":10,"njsOffset":0},"normal":{"duration":591.7109985351562,"length":174,"bombs":0,"notes":399,"obstacles":78,"njs":10,"njsOffset":0},"hard":{"duration":591.7109985351562,"length":174,"bombs":0,"notes":491,"obstacles":81,"njs":10,"njsOffset":0},"expert":{"duration":591.6094360351562,"length":174,"bombs":28,"notes":498,"obstacles":90,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Kung Fu Fighting","songSubName":"Carl Douglas","songAuthorName":"Kleid","levelAuthorName":"kleid","bpm":103},"stats":{"downloads":284190,"plays":32946,"downVotes":239,"upVotes":2353,"heat":44.8903939,"rating":0.8695298560920729},"description":"Kung Fu Fighting (Carl Douglas)\r\nFinished lighting\r\nDifficulties: Easy, Normal, Hard, Expert","deletedAt":null,"_id":"5cff620c48229f7d88fc626b","key":"1a8","name":"Kung Fu Fighting (Carl Douglas)","uploader":{"_id":"5cff0b7398cc5a672c84ecd9","username":"kleid"},"uploaded":"2018-05-22T15:33:58.000Z","hash":"dc3525c5a21d3dee732966e7b46ecc06120f7b84","directDownload":"/cdn/1a8/dc3525c5a21d3dee732966e7b46ecc06120f7b84.zip","downloadURL":"/api/download/key/1a8","coverURL":"/cdn/1a8/dc3525c5a21d3dee732966e7b46ecc06120f7b84.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":290,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":374,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Portal - Still Alive (Uppermost Remix)","songSubName":"Uppermost","songAuthorName":"Kryptikos","levelAuthorName":"kryptikos","bpm":120},"stats":{"downloads":422910,"plays":32883,"downVotes":339,"upVotes":4964,"heat":47.4700228,"rating":0.9030869269745219},"description":"Second track mapped, includes Hard and Expert difficulties.\r\n\r\nI know that this isn't the hardest track, but I find it to be fun, which I see as priority number one. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc629f","key":"1dd","name":"Portal - Still Alive (Uppermost Remix)","uploader":{"_id":"5cff0b7298cc5a672c84eab4","username":"kryptikos"},"uploaded":"2018-05-23T19:33:41.000Z","hash":"01308128a87b6b561799359ee5aa213168c3b49f","directDownload":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.zip","downloadURL":"/api/download/key/1dd","coverURL":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":458,"length":196,"bombs":0,"notes":412,"obstacles":21,"njs":10,"njsOffset":0},"hard":{"duration":458,"length":196,"bombs":0,"notes":500,"obstacles":23,"njs":10,"njsOffset":0},"expert":{"duration":458,"length":196,"bombs":16,"notes":877,"obstacles":23,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"DotA","songSubName":"","songAuthorName":"Basshunter","levelAuthorName":"fossilgenera","bpm":140},"stats":{"downloads":317287,"plays":32014,"downVotes":605,"upVotes":7613,"heat":48.2240608,"rating":0.8981114914293818},"description":"Normal / Hard / Expert\r\n877 Notes || 23 Obstacles || Events || 140 BPM\r\nVideo: https://youtu.be/QBkYyVkIdm0\r\n\r\nNot responsible for seizures.\r\nEnjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc62b0","key":"1ef","name":"DotA - Basshunter","uploader":{"_id":"5cff0b7298cc5a672c84ec3b","username":"fossilgenera"},"uploaded":"2018-05-24T02:43:51.000Z","hash":"dc4906a7f76965cdd2b75c72cf470344b698e352","directDownload":"/cdn/1ef/dc4906a7f76965cdd2b75c72cf470344b698e352.zip","downloadURL":"/api/download/key/1ef","coverURL":"/cdn/1ef/dc4906a7f76965cdd2b75c72cf470344b698e352.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":291,"length":136,"bombs":4,"notes":353,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":291,"length":136,"bombs":4,"notes":455,"obstacles":15,"njs":10,"njsOffset":0},"expert":{"duration":291,"length":136,"bombs":10,"notes":526,"obstacles":15,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"LUVORATORRRRRY!","songSubName":"feat.nqrse","songAuthorName":"Reol","levelAuthorName":"datkami","bpm":128},"stats":{"downloads":275679,"plays":31684,"downVotes":181,"upVotes":4276,"heat":21.0850539,"rating":0.9227729012046024},"description":"Hard (353 notes) / Hard+ (455 notes) / Expert (526 notes) / 15 Obstacles / Video Demonstration: https://streamable.com/23ayv / Part 1 of the J-EDM Graduation series! Use this song pack to level up your game!","deletedAt":null,"_id":"5cff620c48229f7d88fc60fe","key":"21","name":"REOL feat. nqrse - LUVORATORRRRRY!","uploader":{"_id":"5cff0b7298cc5a672c84e8a3","username":"datkami"},"uploaded":"2018-05-10T02:24:36.000Z","hash":"c807689fefdae82aa79ba9c7f861118fb426b4cc","directDownload":"/cdn/21/c807689fefdae82aa79ba9c7f861118fb426b4cc.zip","downloadURL":"/api/download/key/21","coverURL":"/cdn/21/c807689fefdae82aa79ba9c7f861118fb426b4cc.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
-        assert_eq!(
-            client
-                .maps_plays_page_iter(1)
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "4e".to_string(),
-                "155".to_string(),
-                "480".to_string(),
-                "e4".to_string(),
-                "3a4".to_string(),
-                "570".to_string(),
-                "18b".to_string(),
-                "3d".to_string(),
-                "9".to_string(),
-                "16c".to_string(),
-                "80".to_string(),
-                "344".to_string(),
-                "11".to_string(),
-                "541".to_string(),
-                "208".to_string(),
-                "261".to_string(),
-                "1a8".to_string(),
-                "1dd".to_string(),
-                "1ef".to_string(),
-                "21".to_string()
-            ]
+        pages.insert(
+            Url::parse(version.cover_url.as_str()).unwrap(),
+            Bytes::from_static(b"JFIF"),
         );
+        let dir = tempfile::tempdir().unwrap();
+        let mut client = FakeClientPaged::new(pages);
+        client.cache = Some(Box::new(DiskCache::new(dir.path())));
+        for _ in 0..2 {
+            assert_eq!(
+                client.download_cover(version).unwrap(),
+                Bytes::from_static(b"JFIF")
+            );
+        }
+        assert_eq!(client.not_modified.load(Ordering::SeqCst), 1);
     }
     #[test]
-    fn test_maps_plays_page_iter() {
+    fn test_download_to() {
+        let client = FakeClient::new(
+            BEATSAVER_URL.join("download/key/1").unwrap(),
+            Bytes::from_static(b"PK\x03\x04"),
+        );
+        let mut archive = vec![];
+        let mut updates = vec![];
+        let written = client
+            .download_to("1".try_into().unwrap(), &mut archive, |n, total| {
+                updates.push((n, total))
+            })
+            .unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(archive, b"PK\x03\x04");
+        assert_eq!(updates, vec![(4, None)]);
+
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/maps/plays/1").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":227.08416748046875,"length":194,"bombs":0,"notes":313,"obstacles":4,"njs":10,"njsOffset":0},"hard":{"duration":227.08416748046875,"length":194,"bombs":0,"notes":514,"obstacles":4,"njs":10,"njsOffset":0},"expert":{"duration":227.13458251953125,"length":194,"bombs":0,"notes":774,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Blue (KNY Factory Remix)","songSubName":"Effeil 65","songAuthorName":"Freeek","levelAuthorName":"freeek","bpm":70},"stats":{"downloads":334401,"plays":45173,"downVotes":676,"upVotes":2360,"heat":25.891831,"rating":0.752524991619825},"description":"Expert/Hard/Normal | Fun one handed chorus' ! | Very detailed event lighting | Enjoy!\r\n\r\n- Freeek","deletedAt":null,"_id":"5cff620c48229f7d88fc612b","key":"4e","name":"Blue - Eiffel 65 (KZY Factory Remix)","uploader":{"_id":"5cff0b7298cc5a672c84e8ad","username":"freeek"},"uploaded":"2018-05-12T19:19:07.000Z","hash":"7de95858ce1d8d38296b3dfc524502f393153c33","directDownload":"/cdn/4e/7de95858ce1d8d38296b3dfc524502f393153c33.zip","downloadURL":"/api/download/key/4e","coverURL":"/cdn/4e/7de95858ce1d8d38296b3dfc524502f393153c33.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":460997,"plays":44907,"downVotes":583,"upVotes":7767,"heat":41.8516552,"rating":0.9017946384171858},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":291.375,"length":138,"bombs":0,"notes":291,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":291.375,"length":138,"bombs":0,"notes":367,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":291.375,"length":138,"bombs":0,"notes":409,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"September","songSubName":"","songAuthorName":"Earth, Wind & Fire","levelAuthorName":"calijor","bpm":126},"stats":{"downloads":539133,"plays":43006,"downVotes":338,"upVotes":8817,"heat":80.2856335,"rating":0.9333592430550526},"description":"Expert | Hard | Normal\r\n\r\nBPM - 126\r\nDuration - 2:21\r\n\r\nPreview: https://youtu.be/FOob1xit17Y","deletedAt":null,"_id":"5cff620d48229f7d88fc651e","key":"480","name":"Earth, Wind & Fire - September","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-06-09T18:27:58.000Z","hash":"aa2f7bf0df25cd57dddac159fa7c159f732e0553","directDownload":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.zip","downloadURL":"/api/download/key/480","coverURL":"/cdn/480/aa2f7bf0df25cd57dddac159fa7c159f732e0553.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":459.91717529296875,"length":194,"bombs":0,"notes":564,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Every Time We Touch","songSubName":"","songAuthorName":"Cascada","levelAuthorName":"purphoros","bpm":142},"stats":{"downloads":588020,"plays":42754,"downVotes":507,"upVotes":9279,"heat":36.8911653,"rating":0.9199971968096474},"description":"Expert Only\r\nTime - 3:19\r\nBPM - 142\r\nNotes- 564","deletedAt":null,"_id":"5cff620c48229f7d88fc61b5","key":"e4","name":"Every Time We Touch - Cascada","uploader":{"_id":"5cff0b7298cc5a672c84ea98","username":"purphoros"},"uploaded":"2018-05-18T03:51:03.000Z","hash":"bc6c7ef1385db4c11c59736d2b32eacf48c95bd9","directDownload":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.zip","downloadURL":"/api/download/key/e4","coverURL":"/cdn/e4/bc6c7ef1385db4c11c59736d2b32eacf48c95bd9.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":474,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":747,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":715.4612426757812,"length":257,"bombs":0,"notes":1049,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Boulevard of Broken Dreams","songSubName":"Green Day","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":167},"stats":{"downloads":348312,"plays":39543,"downVotes":284,"upVotes":5594,"heat":69.6861834,"rating":0.9185588152087345},"description":"Expert - Hard - Normal\r\n1000+ Notes on Expert\r\nLighting Events","deletedAt":null,"_id":"5cff620d48229f7d88fc644c","key":"3a4","name":"Boulevard of Broken Dreams - Green Day","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-06-04T08:30:49.000Z","hash":"fa36428f6eed2648dade2fe320156adfaabe07b5","directDownload":"/cdn/3a4/fa36428f6eed2648dade2fe320156adfaabe07b5.zip","downloadURL":"/api/download/key/3a4","coverURL":"/cdn/3a4/fa36428f6eed2648dade2fe320156adfaabe07b5.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":623.3125,"length":214,"bombs":0,"notes":462,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":623.3125,"length":214,"bombs":0,"notes":639,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":623.3125,"length":214,"bombs":0,"notes":825,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Mr. Blue Sky","songSubName":"Electric Light Orchestra","songAuthorName":"GreatYazer","levelAuthorName":"greatyazer","bpm":174},"stats":{"downloads":945232,"plays":39426,"downVotes":498,"upVotes":23081,"heat":94.0252039,"rating":0.9557610240595098},"description":"Channel your inner Baby Groot.  Normal, Hard, Expert\r\nSpecial thanks to BennydaBeast for his help on this track!","deletedAt":null,"_id":"5cff620d48229f7d88fc65f7","key":"570","name":"Mr. Blue Sky | Electric Light Orchestra","uploader":{"_id":"5cff0b7298cc5a672c84ea71","username":"greatyazer"},"uploaded":"2018-06-16T16:53:34.000Z","hash":"236173d5ba7dc379d480b9cb5fb6b4fa5abe77da","directDownload":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.zip","downloadURL":"/api/download/key/570","coverURL":"/cdn/570/236173d5ba7dc379d480b9cb5fb6b4fa5abe77da.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":306,"obstacles":25,"njs":10,"njsOffset":0},"hard":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":337,"obstacles":25,"njs":10,"njsOffset":0},"expert":{"duration":312.49066162109375,"length":146,"bombs":3,"notes":448,"obstacles":25,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"The Fox (What Does The Fox Say?)","songSubName":"Ylvis","songAuthorName":"kyuz","levelAuthorName":"kyuz","bpm":128},"stats":{"downloads":251569,"plays":38274,"downVotes":179,"upVotes":3699,"heat":43.4754982,"rating":0.9161203732088851},"description":"Three difficulty levels. Even on expert this track is somewhat casual oriented and not ultra-difficult. Just a fun track with some goofy/jokey twists.","deletedAt":null,"_id":"5cff620c48229f7d88fc6252","key":"18b","name":"Ylvis - The Fox (What Does The Fox Say?)","uploader":{"_id":"5cff0b7298cc5a672c84eaab","username":"kyuz"},"uploaded":"2018-05-21T19:06:43.000Z","hash":"5ee3b92eb40778dee6469a517b43c8829fc8c53f","directDownload":"/cdn/18b/5ee3b92eb40778dee6469a517b43c8829fc8c53f.zip","downloadURL":"/api/download/key/18b","coverURL":"/cdn/18b/5ee3b92eb40778dee6469a517b43c8829fc8c53f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":450,"length":226,"bombs":0,"notes":714,"obstacles":32,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Sail (V2)","songSubName":"","songAuthorName":"AWOLNATION","levelAuthorName":"rellimjoe4","bpm":121},"stats":{"downloads":367076,"plays":37463,"downVotes":178,"upVotes":2098,"heat":24.1029679,"rating":0.8806367287255594},"description":"This is an outdated version. Please download the newer and much improved version - Sail (V3)\r\n\r\nDownload Link: https://beatsaver.com/browse/detail/631-405","deletedAt":null,"_id":"5cff620c48229f7d88fc611a","key":"3d","name":"Sail V2- AWOLNATION (outdated version)","uploader":{"_id":"5cff0b7298cc5a672c84e939","username":"rellimjoe4"},"uploaded":"2018-05-11T20:14:45.000Z","hash":"2b9c36605b9f4f8f780563da074cc439f0dd824e","directDownload":"/cdn/3d/2b9c36605b9f4f8f780563da074cc439f0dd824e.zip","downloadURL":"/api/download/key/3d","coverURL":"/cdn/3d/2b9c36605b9f4f8f780563da074cc439f0dd824e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":384,"length":136,"bombs":0,"notes":505,"obstacles":52,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Take On Me","songSubName":"","songAuthorName":"a-ha","levelAuthorName":"jackscape","bpm":168},"stats":{"downloads":778633,"plays":36801,"downVotes":559,"upVotes":6009,"heat":18.5954409,"rating":0.8854629990209468},"description":"Expert only","deletedAt":null,"_id":"5cff620c48229f7d88fc60e7","key":"9","name":"a-ha - Take On Me","uploader":{"_id":"5cff0b7298cc5a672c84e8bb","username":"jackscape"},"uploaded":"2018-05-08T17:44:17.000Z","hash":"2aa1f5192828e075c30dd015b1e132bba912eb86","directDownload":"/cdn/9/2aa1f5192828e075c30dd015b1e132bba912eb86.zip","downloadURL":"/api/download/key/9","coverURL":"/cdn/9/2aa1f5192828e075c30dd015b1e132bba912eb86.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":608.0845947265625,"length":251,"bombs":0,"notes":434,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":608.0845947265625,"length":251,"bombs":0,"notes":561,"obstacles":17,"njs":10,"njsOffset":0},"expert":{"duration":608.0845947265625,"length":251,"bombs":32,"notes":862,"obstacles":34,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"First Of The Year (Equinox)","songSubName":"","songAuthorName":"Skrillex","levelAuthorName":"fossilgenera","bpm":145},"stats":{"downloads":253516,"plays":36701,"downVotes":366,"upVotes":1998,"heat":41.954209,"rating":0.8118796525077013},"description":"Normal / Hard / Expert\r\n862 Notes || 34 Obstacles || 145 BPM\r\nVideo: https://youtu.be/hRSMbE0exFI\r\n\r\nNot responsible for seizures.\r\nEnjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc6235","key":"16c","name":"First Of The Year (Equinox) - Skrillex","uploader":{"_id":"5cff0b7298cc5a672c84ec3b","username":"fossilgenera"},"uploaded":"2018-05-21T04:16:07.000Z","hash":"8f2842e6043a3ec51df6641cb3d888337452aee1","directDownload":"/cdn/16c/8f2842e6043a3ec51df6641cb3d888337452aee1.zip","downloadURL":"/api/download/key/16c","coverURL":"/cdn/16c/8f2842e6043a3ec51df6641cb3d888337452aee1.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/maps/plays/2").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":168.25,"length":71,"bombs":0,"notes":183,"obstacles":61,"njs":10,"njsOffset":0},"hard":{"duration":168.25,"length":71,"bombs":0,"notes":254,"obstacles":61,"njs":10,"njsOffset":0},"expert":{"duration":168.25,"length":71,"bombs":0,"notes":377,"obstacles":61,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"He's a pirate","songSubName":"Hans Zimmer & Klaus Badelt","songAuthorName":"Hans Zimmer & Klaus Badelt","levelAuthorName":"jnua12345","bpm":140},"stats":{"downloads":409971,"plays":36415,"downVotes":3884,"upVotes":1204,"heat":22.9578874,"rating":0.2568072751953776},"description":"Expert, Hard, and Normal 140 BPM","deletedAt":null,"_id":"5cff620c48229f7d88fc6159","key":"80","name":"He's a pirate - Hans Zimmer & Klaus Badelt","uploader":{"_id":"5cff0b7298cc5a672c84e9da","username":"jnua12345"},"uploaded":"2018-05-14T17:49:31.000Z","hash":"f1ec6967a2a3940c2e65fcb207fc418f2813403d","directDownload":"/cdn/80/f1ec6967a2a3940c2e65fcb207fc418f2813403d.zip","downloadURL":"/api/download/key/80","coverURL":"/cdn/80/f1ec6967a2a3940c2e65fcb207fc418f2813403d.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":574,"obstacles":2,"njs":10,"njsOffset":0},"hard":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":739,"obstacles":2,"njs":10,"njsOffset":0},"expert":{"duration":619.0533447265625,"length":290,"bombs":0,"notes":849,"obstacles":2,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"I Gotta Feeling","songSubName":"The Black Eyed Peas","songAuthorName":"RunRockGame","levelAuthorName":"runrockgame","bpm":128},"stats":{"downloads":343109,"plays":36096,"downVotes":524,"upVotes":3120,"heat":65.3748158,"rating":0.8260359199604895},"description":"Expert, Hard & Normal | 800+ Blocks | Full Song 4:56 | Includes Lighting. Request to: @themakertales","deletedAt":null,"_id":"5cff620c48229f7d88fc63f1","key":"344","name":"The Black Eyed Peas - I Gotta Feeling","uploader":{"_id":"5cff0b7398cc5a672c84f04e","username":"runrockgame"},"uploaded":"2018-06-02T06:30:23.000Z","hash":"0e440ed89a72fbe2b9835fcdc57688423d0b7d02","directDownload":"/cdn/344/0e440ed89a72fbe2b9835fcdc57688423d0b7d02.zip","downloadURL":"/api/download/key/344","coverURL":"/cdn/344/0e440ed89a72fbe2b9835fcdc57688423d0b7d02.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":324,"length":162,"bombs":0,"notes":335,"obstacles":22,"njs":10,"njsOffset":0},"expert":{"duration":324,"length":162,"bombs":0,"notes":511,"obstacles":11,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"New Dawn","songSubName":"Prototyperaptor","songAuthorName":"Rustic","levelAuthorName":"rustic","bpm":120},"stats":{"downloads":155431,"plays":35038,"downVotes":208,"upVotes":569,"heat":17.9584628,"rating":0.7009864107208541},"description":"Hard/Expert + Lights","deletedAt":null,"_id":"5cff620c48229f7d88fc60ef","key":"11","name":"Prototyperaptor - New Dawn","uploader":{"_id":"5cff0b7298cc5a672c84e8c4","username":"rustic"},"uploaded":"2018-05-09T00:30:43.000Z","hash":"b677fb72f916f74d69e532b279ce90b28e4fa14f","directDownload":"/cdn/11/b677fb72f916f74d69e532b279ce90b28e4fa14f.zip","downloadURL":"/api/download/key/11","coverURL":"/cdn/11/b677fb72f916f74d69e532b279ce90b28e4fa14f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":344,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":553,"obstacles":0,"njs":10,"njsOffset":0},"expert":{"duration":535.5889892578125,"length":172,"bombs":0,"notes":835,"obstacles":0,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"American Idiot","songSubName":"Green Day","songAuthorName":"DownyCat","levelAuthorName":"downycat","bpm":186},"stats":{"downloads":312681,"plays":34648,"downVotes":417,"upVotes":5288,"heat":91.1286181,"rating":0.895315180713646},"description":"Expert - Hard - Normal Charts\r\nLighting Events","deletedAt":null,"_id":"5cff620d48229f7d88fc65ce","key":"541","name":"American Idiot - Green Day","uploader":{"_id":"5cff0b7398cc5a672c84ede5","username":"downycat"},"uploaded":"2018-06-15T13:00:45.000Z","hash":"4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569","directDownload":"/cdn/541/4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569.zip","downloadURL":"/api/download/key/541","coverURL":"/cdn/541/4b932c34c8402d4b1d1cbc11ec0eebf9d1ce9569.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":388.90625,"length":191,"bombs":0,"notes":402,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":389,"length":191,"bombs":0,"notes":618,"obstacles":15,"njs":10,"njsOffset":0},"expert":{"duration":389,"length":191,"bombs":0,"notes":914,"obstacles":15,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Black Betty","songSubName":"Caravan Palace Cover","songAuthorName":"Caravan Palace","levelAuthorName":"calijor","bpm":122},"stats":{"downloads":278929,"plays":34223,"downVotes":418,"upVotes":3856,"heat":49.5446392,"rating":0.8697339511120226},"description":"Caravan Palace - Black Betty (cover)\r\nThis is not the classic Black Betty but instead an electro-swing cover by Caravan Palace, and it is a banger.\r\n\r\nNormal | Hard | Expert\r\n\r\nBPM: 122\r\nNotes (Expert): 914\r\nDuration: 3:11\r\n\r\nPreview: https://youtu.be/5SgT9hUO7rU","deletedAt":null,"_id":"5cff620c48229f7d88fc62c8","key":"208","name":"Caravan Palace - Black Betty (cover)","uploader":{"_id":"5cff0b7298cc5a672c84ebb1","username":"calijor"},"uploaded":"2018-05-24T23:06:15.000Z","hash":"32d2e0072615066f6958ea33519f62eca7d8f59e","directDownload":"/cdn/208/32d2e0072615066f6958ea33519f62eca7d8f59e.zip","downloadURL":"/api/download/key/208","coverURL":"/cdn/208/32d2e0072615066f6958ea33519f62eca7d8f59e.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":365.49951171875,"length":162,"bombs":0,"notes":275,"obstacles":70,"njs":10,"njsOffset":0},"expert":null,"expertPlus":null}}],"songName":"You're Welcome","songSubName":"Moana","songAuthorName":"Prime","levelAuthorName":"prime","bpm":135},"stats":{"downloads":367495,"plays":34012,"downVotes":278,"upVotes":3981,"heat":53.6019093,"rating":0.8995983405791548},"description":"Difficulties: Hard\r\nBPM: 135\r\nLights: Done\r\nListen: https://www.youtube.com/watch?v=79DijItQXMM\r\n\r\nNot meant to be challenging, just a feel-good song to enjoy :)","deletedAt":null,"_id":"5cff620c48229f7d88fc631d","key":"261","name":"Moana - You're Welcome","uploader":{"_id":"5cff0b7298cc5a672c84eb1e","username":"prime"},"uploaded":"2018-05-27T01:25:01.000Z","hash":"0e4b5e325760bdabb66caea4506c5463daf4b51f","directDownload":"/cdn/261/0e4b5e325760bdabb66caea4506c5463daf4b51f.zip","downloadURL":"/api/download/key/261","coverURL":"/cdn/261/0e4b5e325760bdabb66caea4506c5463daf4b51f.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":596.3594360351562,"length":176,"bombs":0,"notes":349,"obstacles":54,"njs":10,"njsOffset":0},"normal":{"duration":591.7109985351562,"length":174,"bombs":0,"notes":399,"obstacles":78,"njs":10,"njsOffset":0},"hard":{"duration":591.7109985351562,"length":174,"bombs":0,"notes":491,"obstacles":81,"njs":10,"njsOffset":0},"expert":{"duration":591.6094360351562,"length":174,"bombs":28,"notes":498,"obstacles":90,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Kung Fu Fighting","songSubName":"Carl Douglas","songAuthorName":"Kleid","levelAuthorName":"kleid","bpm":103},"stats":{"downloads":284190,"plays":32946,"downVotes":239,"upVotes":2353,"heat":44.8903939,"rating":0.8695298560920729},"description":"Kung Fu Fighting (Carl Douglas)\r\nFinished lighting\r\nDifficulties: Easy, Normal, Hard, Expert","deletedAt":null,"_id":"5cff620c48229f7d88fc626b","key":"1a8","name":"Kung Fu Fighting (Carl Douglas)","uploader":{"_id":"5cff0b7398cc5a672c84ecd9","username":"kleid"},"uploaded":"2018-05-22T15:33:58.000Z","hash":"dc3525c5a21d3dee732966e7b46ecc06120f7b84","directDownload":"/cdn/1a8/dc3525c5a21d3dee732966e7b46ecc06120f7b84.zip","downloadURL":"/api/download/key/1a8","coverURL":"/cdn/1a8/dc3525c5a21d3dee732966e7b46ecc06120f7b84.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":290,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":272.0799865722656,"length":136,"bombs":28,"notes":374,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Portal - Still Alive (Uppermost Remix)","songSubName":"Uppermost","songAuthorName":"Kryptikos","levelAuthorName":"kryptikos","bpm":120},"stats":{"downloads":422910,"plays":32883,"downVotes":339,"upVotes":4964,"heat":47.4700228,"rating":0.9030869269745219},"description":"Second track mapped, includes Hard and Expert difficulties.\r\n\r\nI know that this isn't the hardest track, but I find it to be fun, which I see as priority number one. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc629f","key":"1dd","name":"Portal - Still Alive (Uppermost Remix)","uploader":{"_id":"5cff0b7298cc5a672c84eab4","username":"kryptikos"},"uploaded":"2018-05-23T19:33:41.000Z","hash":"01308128a87b6b561799359ee5aa213168c3b49f","directDownload":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.zip","downloadURL":"/api/download/key/1dd","coverURL":"/cdn/1dd/01308128a87b6b561799359ee5aa213168c3b49f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":458,"length":196,"bombs":0,"notes":412,"obstacles":21,"njs":10,"njsOffset":0},"hard":{"duration":458,"length":196,"bombs":0,"notes":500,"obstacles":23,"njs":10,"njsOffset":0},"expert":{"duration":458,"length":196,"bombs":16,"notes":877,"obstacles":23,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"DotA","songSubName":"","songAuthorName":"Basshunter","levelAuthorName":"fossilgenera","bpm":140},"stats":{"downloads":317287,"plays":32014,"downVotes":605,"upVotes":7613,"heat":48.2240608,"rating":0.8981114914293818},"description":"Normal / Hard / Expert\r\n877 Notes || 23 Obstacles || Events || 140 BPM\r\nVideo: https://youtu.be/QBkYyVkIdm0\r\n\r\nNot responsible for seizures.\r\nEnjoy!","deletedAt":null,"_id":"5cff620c48229f7d88fc62b0","key":"1ef","name":"DotA - Basshunter","uploader":{"_id":"5cff0b7298cc5a672c84ec3b","username":"fossilgenera"},"uploaded":"2018-05-24T02:43:51.000Z","hash":"dc4906a7f76965cdd2b75c72cf470344b698e352","directDownload":"/cdn/1ef/dc4906a7f76965cdd2b75c72cf470344b698e352.zip","downloadURL":"/api/download/key/1ef","coverURL":"/cdn/1ef/dc4906a7f76965cdd2b75c72cf470344b698e352.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":291,"length":136,"bombs":4,"notes":353,"obstacles":15,"njs":10,"njsOffset":0},"hard":{"duration":291,"length":136,"bombs":4,"notes":455,"obstacles":15,"njs":10,"njsOffset":0},"expert":{"duration":291,"length":136,"bombs":10,"notes":526,"obstacles":15,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"LUVORATORRRRRY!","songSubName":"feat.nqrse","songAuthorName":"Reol","levelAuthorName":"datkami","bpm":128},"stats":{"downloads":275679,"plays":31684,"downVotes":181,"upVotes":4276,"heat":21.0850539,"rating":0.9227729012046024},"description":"Hard (353 notes) / Hard+ (455 notes) / Expert (526 notes) / 15 Obstacles / Video Demonstration: https://streamable.com/23ayv / Part 1 of the J-EDM Graduation series! Use this song pack to level up your game!","deletedAt":null,"_id":"5cff620c48229f7d88fc60fe","key":"21","name":"REOL feat. nqrse - LUVORATORRRRRY!","uploader":{"_id":"5cff0b7298cc5a672c84e8a3","username":"datkami"},"uploaded":"2018-05-10T02:24:36.000Z","hash":"c807689fefdae82aa79ba9c7f861118fb426b4cc","directDownload":"/cdn/21/c807689fefdae82aa79ba9c7f861118fb426b4cc.zip","downloadURL":"/api/download/key/21","coverURL":"/cdn/21/c807689fefdae82aa79ba9c7f861118fb426b4cc.jpg"}],"totalDocs":36014,"lastPage":3601,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
-        assert_eq!(
-            client
-                .maps_plays_page_iter(1)
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "4e".to_string(),
-                "155".to_string(),
-                "480".to_string(),
-                "e4".to_string(),
-                "3a4".to_string(),
-                "570".to_string(),
-                "18b".to_string(),
-                "3d".to_string(),
-                "9".to_string(),
-                "16c".to_string(),
-                "80".to_string(),
-                "344".to_string(),
-                "11".to_string(),
-                "541".to_string(),
-                "208".to_string(),
-                "261".to_string(),
-                "1a8".to_string(),
-                "1dd".to_string(),
-                "1ef".to_string(),
-                "21".to_string()
-            ]
+        pages.insert(
+            BEATSAVER_URL.join("download/key/1").unwrap(),
+            Bytes::from_static(b"PK\x03\x04"),
         );
+        let mut client = FakeClientPaged::new(pages);
+        client.rate_limits.store(1, Ordering::SeqCst);
+        let mut archive = vec![];
+        assert!(matches!(
+            client.download_to(MapId::from(1), &mut archive, |_, _| {}),
+            Err(BeatSaverApiError::RateLimitError(_))
+        ));
+        client.rate_limit_policy = RateLimitPolicy::WaitAndRetry { max_waits: 1 };
+        client.retry_policy = RetryPolicy::new()
+            .with_max_attempts(2)
+            .with_base_delay(Duration::from_millis(1));
+        client.rate_limits.store(1, Ordering::SeqCst);
+        client.server_errors.store(1, Ordering::SeqCst);
+        client
+            .download_to(MapId::from(1), &mut archive, |_, _| {})
+            .unwrap();
+        assert_eq!(archive, b"PK\x03\x04");
     }
+    #[cfg(feature = "hash")]
     #[test]
-    fn test_user() {
+    fn test_download_verified() {
+        let map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        let mut version = map.latest_version().unwrap().clone();
+        version.hash = MAP_ARCHIVE_HASH.into();
         let client = FakeClient::new(
             BEATSAVER_URL
-                .join("api/users/find/5fbe7cd60192c700062b2a1f")
+                .join(format!("download/hash/{}", MAP_ARCHIVE_HASH).as_str())
                 .unwrap(),
-            r#"{"_id":"5fbe7cd60192c700062b2a1f","username":"qw3rty01"}"#.into(),
+            map_archive(),
         );
-        let _: BeatSaverUser = client.user("5fbe7cd60192c700062b2a1f".into()).unwrap();
+        assert_eq!(client.download_verified(&version).unwrap(), map_archive());
+
+        let mut client = client;
+        client.data = zip_archive(&[("Info.dat", br#"{"_difficultyBeatmapSets":[]}"#.as_ref())]);
+        assert!(matches!(
+            client.download_verified(&version),
+            Err(BeatSaverApiError::HashMismatch { .. })
+        ));
     }
+    #[cfg(feature = "hash")]
     #[test]
-    fn test_search() {
+    fn test_install_map() {
+        let mut map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        map.versions[0].hash = MAP_ARCHIVE_HASH.into();
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/search/text/0?q=bennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":642.25,"length":240,"bombs":0,"notes":805,"obstacles":41,"njs":12,"njsOffset":0},"expert":{"duration":642.25,"length":240,"bombs":0,"notes":1048,"obstacles":41,"njs":12,"njsOffset":0},"expertPlus":{"duration":642.25,"length":240,"bombs":0,"notes":1060,"obstacles":41,"njs":15,"njsOffset":0}}}],"songName":"Fractures (ft. Nevve)","songSubName":"Illenium","songAuthorName":"BennyDaBeast","levelAuthorName":"AnniversaryTeam","bpm":160},"stats":{"downloads":99011,"plays":0,"downVotes":54,"upVotes":2048,"heat":709.0725474,"rating":0.926909109741899},"description":"Part of the Anniversary Song Pack","deletedAt":null,"_id":"5cff621748229f7d88fc9156","key":"4a3d","name":"[Anniversary] Fractures (ft. Nevve) - Illenium [BennyDaBeast]","uploader":{"_id":"5cff0b7798cc5a672c8565a3","username":"anniversaryteam"},"uploaded":"2019-05-03T14:09:37.000Z","hash":"440945a4fe86b5e3ee7f03b98c636c881930072f","directDownload":"/cdn/4a3d/440945a4fe86b5e3ee7f03b98c636c881930072f.zip","downloadURL":"/api/download/key/4a3d","coverURL":"/cdn/4a3d/440945a4fe86b5e3ee7f03b98c636c881930072f.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":492,"length":241,"bombs":0,"notes":322,"obstacles":14,"njs":10,"njsOffset":1},"normal":{"duration":492,"length":241,"bombs":0,"notes":522,"obstacles":15,"njs":10,"njsOffset":1},"hard":{"duration":492,"length":241,"bombs":0,"notes":638,"obstacles":66,"njs":10,"njsOffset":1},"expert":{"duration":492,"length":241,"bombs":0,"notes":1018,"obstacles":8,"njs":14,"njsOffset":0},"expertPlus":{"duration":492,"length":241,"bombs":0,"notes":1211,"obstacles":2,"njs":14,"njsOffset":0}}}],"songName":"Honolulu","songSubName":"Last Dinosaurs","songAuthorName":"Skyler Wallace & BennyDaBeast","levelAuthorName":"skylerwallace","bpm":122},"stats":{"downloads":14721,"plays":0,"downVotes":17,"upVotes":355,"heat":588.04205,"rating":0.877885433253045},"description":"122 BPM / 4:07 Runtime\r\nEasy / 322 Notes\r\nNormal / 522 Notes\r\nHard / 638 Notes\r\nExpert / 1018 Notes\r\nExpert+ / 1211 Notes\r\n\r\nThe sequel to Apollo :)\r\nEasy, Normal, and Expert are by me.\r\nHard by Benny.\r\nExpert+ is a collab between me and Benny.\r\n\r\nGameplay:   https://youtu.be/BvIX7v53MrE","deletedAt":null,"_id":"5cff621448229f7d88fc878f","key":"3aa4","name":"Honolulu - Last Dinosaurs (collab with BennyDaBeast)","uploader":{"_id":"5cff0b7298cc5a672c84ea67","username":"skylerwallace"},"uploaded":"2019-03-01T22:54:51.000Z","hash":"22282e0fe150880d67d570c5a70a0cc96929b251","directDownload":"/cdn/3aa4/22282e0fe150880d67d570c5a70a0cc96929b251.zip","downloadURL":"/api/download/key/3aa4","coverURL":"/cdn/3aa4/22282e0fe150880d67d570c5a70a0cc96929b251.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":548,"length":205,"njs":10,"njsOffset":0,"bombs":0,"notes":390,"obstacles":0},"expert":{"duration":548,"length":205,"njs":12,"njsOffset":-1,"bombs":0,"notes":776,"obstacles":0},"expertPlus":{"duration":548,"length":205,"njs":15,"njsOffset":0.4,"bombs":0,"notes":921,"obstacles":0},"hard":{"duration":548,"length":205,"njs":12,"njsOffset":-1,"bombs":0,"notes":567,"obstacles":0},"normal":{"duration":548,"length":205,"njs":10,"njsOffset":0,"bombs":0,"notes":455,"obstacles":0}},"name":"Standard"}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Hikaru Utada & Skrillex","songName":"Face My Fears","songSubName":"","bpm":160},"stats":{"downloads":80500,"plays":0,"downVotes":38,"upVotes":1557,"heat":909.5656655,"rating":0.9244675039910542},"description":"Part of the Anniversary Song Pack","deletedAt":null,"_id":"5d560b54b8ed570006cdcf53","key":"5d24","name":"[Anniversary] Face My Fears - Hikaru Utadu & Skrillex [BennyDaBeast]","uploader":{"_id":"5cff0b7798cc5a672c8565a3","username":"anniversaryteam"},"hash":"460fcbe6916bd64eb31ff65a03e4d46d4773e31c","uploaded":"2019-08-16T01:48:04.848Z","directDownload":"/cdn/5d24/460fcbe6916bd64eb31ff65a03e4d46d4773e31c.zip","downloadURL":"/api/download/key/5d24","coverURL":"/cdn/5d24/460fcbe6916bd64eb31ff65a03e4d46d4773e31c.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":552.0846557617188,"length":260,"bombs":104,"notes":403,"obstacles":22,"njs":10,"njsOffset":0},"normal":{"duration":552.0846557617188,"length":260,"bombs":104,"notes":481,"obstacles":22,"njs":10,"njsOffset":0},"hard":{"duration":552.0846557617188,"length":260,"bombs":104,"notes":647,"obstacles":22,"njs":12,"njsOffset":0},"expert":{"duration":456.08465576171875,"length":215,"bombs":104,"notes":693,"obstacles":22,"njs":12,"njsOffset":0},"expertPlus":{"duration":456.08465576171875,"length":215,"bombs":96,"notes":914,"obstacles":34,"njs":15,"njsOffset":0}}}],"songName":"Nothing Stopping Me (ft. Kat Nestel)","songSubName":"Vicetone","songAuthorName":"BennyDaBeast","levelAuthorName":"AnniversaryTeam","bpm":127},"stats":{"downloads":115241,"plays":0,"downVotes":74,"upVotes":2207,"heat":709.1032131,"rating":0.9219668036751855},"description":"Part of the Anniversary Song Pack","deletedAt":null,"_id":"5cff621748229f7d88fc915d","key":"4a45","name":"[Anniversary] Nothing Stopping Me (ft. Kat Nestel) - Vicetone [BennyDaBeast]","uploader":{"_id":"5cff0b7798cc5a672c8565a3","username":"anniversaryteam"},"uploaded":"2019-05-03T14:10:40.000Z","hash":"01e9554069556f1756cbfcf9ac233b68d98b19c8","directDownload":"/cdn/4a45/01e9554069556f1756cbfcf9ac233b68d98b19c8.zip","downloadURL":"/api/download/key/4a45","coverURL":"/cdn/4a45/01e9554069556f1756cbfcf9ac233b68d98b19c8.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":false,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":0,"length":0,"bombs":0,"notes":0,"obstacles":0,"njs":10,"njsOffset":0},"normal":null,"hard":null,"expert":null,"expertPlus":null}}],"songName":"Infinite","songSubName":"Magic Sword","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":116},"stats":{"downloads":16313,"plays":0,"downVotes":7,"upVotes":45,"heat":176.6093392,"rating":0.7548008178765884},"description":"I took a new direction with this custom beat saber map. I wanted to improve my lighting skills, and so I initially began playing around with them before getting to notes... but as I continued to work on it, I realized it wasn't meant to have notes. It's purely meant to be an experience with lighting, so please sit back and enjoy. :D\r\n\r\nHuge thanks to Rolo for teaching me how to mod the game to remove the overlay!","deletedAt":null,"_id":"5cff620e48229f7d88fc6ba3","key":"cef","name":"Infinite - Magic Sword (Light Show)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-31T03:52:10.000Z","hash":"75484d416df87022a5f4c46aabe48b360db3744f","directDownload":"/cdn/cef/75484d416df87022a5f4c46aabe48b360db3744f.zip","downloadURL":"/api/download/key/cef","coverURL":"/cdn/cef/75484d416df87022a5f4c46aabe48b360db3744f.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":428,"length":136,"bombs":61,"notes":239,"obstacles":31,"njs":10,"njsOffset":0},"hard":{"duration":428,"length":136,"bombs":61,"notes":364,"obstacles":31,"njs":10,"njsOffset":0},"expert":{"duration":428,"length":136,"bombs":61,"notes":491,"obstacles":31,"njs":10,"njsOffset":0},"expertPlus":{"duration":428,"length":136,"bombs":61,"notes":541,"obstacles":31,"njs":10,"njsOffset":0}}}],"songName":"Bad to the Bone (Steve Urkel Remix)","songSubName":"Melodysheep","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":188},"stats":{"downloads":22944,"plays":1177,"downVotes":43,"upVotes":267,"heat":232.8685369,"rating":0.7971013122538653},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/ioIhxMuqlws","deletedAt":null,"_id":"5cff620f48229f7d88fc6ede","key":"1254","name":"Bad to the Bone (Steve Urkel Remix)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-29T01:28:43.000Z","hash":"b988ec86eac113d7e3a400ebe9f14682db746f25","directDownload":"/cdn/1254/b988ec86eac113d7e3a400ebe9f14682db746f25.zip","downloadURL":"/api/download/key/1254","coverURL":"/cdn/1254/b988ec86eac113d7e3a400ebe9f14682db746f25.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":371.5,"length":176,"bombs":18,"notes":636,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"So Funky [NCS Release]","songSubName":"NIVIRO","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":126},"stats":{"downloads":22923,"plays":963,"downVotes":10,"upVotes":559,"heat":310.9975946,"rating":0.9110037879052106},"description":"This map is one of the two that were created specifically for the 2018 Elements Tournament Qualifier.\r\nDifficulties: Expert+ Only","deletedAt":null,"_id":"5cff621048229f7d88fc731a","key":"194d","name":"So Funky - NVIRIO [Elements Qualifier]","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T13:13:31.000Z","hash":"a5bf0f0bd05e12fc10fd1dda65132531f00123a1","directDownload":"/cdn/194d/a5bf0f0bd05e12fc10fd1dda65132531f00123a1.zip","downloadURL":"/api/download/key/194d","coverURL":"/cdn/194d/a5bf0f0bd05e12fc10fd1dda65132531f00123a1.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":413.125,"length":130,"bombs":179,"notes":664,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"THE HEISENBERG SONG","songSubName":"Say My Name","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":191},"stats":{"downloads":30244,"plays":129,"downVotes":39,"upVotes":41,"heat":308.6084522,"rating":0.5091703485317295},"description":"https://www.twitch.tv/heisenberg_irl\r\n\r\nA tribute to the mapping style of the one and only, Heisenberg in real life. The real Heisenberg. Not the fake one.","deletedAt":null,"_id":"5cff621048229f7d88fc731b","key":"194e","name":"THE HEISENBERG SONG (Tribute to Heisenbergirl)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T13:50:34.000Z","hash":"c3b7b8a99a8f07961122b37547794f03adb27f96","directDownload":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.zip","downloadURL":"/api/download/key/194e","coverURL":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":554.9882202148438,"length":188,"bombs":0,"notes":1015,"obstacles":17,"njs":12,"njsOffset":0}}}],"songName":"RISE (ft. The Glitch Mob, Mako, The Word Alive)","songSubName":"League of Legends","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":177},"stats":{"downloads":20983,"plays":0,"downVotes":70,"upVotes":386,"heat":643.0575537,"rating":0.7916663490366552},"description":"YouTube Preview: https://youtu.be/Zf_wiprotdw","deletedAt":null,"_id":"5cff621648229f7d88fc8c15","key":"4200","name":"RISE feat The Glitch Mob, Mako, The Word Alive - League of Legends","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-30T14:58:24.000Z","hash":"ca715e7b3ab2b5514919893f150190717e2efbbe","directDownload":"/cdn/4200/ca715e7b3ab2b5514919893f150190717e2efbbe.zip","downloadURL":"/api/download/key/4200","coverURL":"/cdn/4200/ca715e7b3ab2b5514919893f150190717e2efbbe.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":392.1283264160156,"length":235,"bombs":0,"notes":880,"obstacles":43,"njs":14,"njsOffset":0}}}],"songName":"Heart Afire (feat. Strix)","songSubName":"Defqwop","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":100},"stats":{"downloads":16306,"plays":0,"downVotes":8,"upVotes":436,"heat":645.1868215,"rating":0.9051052345375648},"description":"YouTube Preview: https://youtu.be/dY-jMl3RKpw\r\nEDIT: Fixed Borked Lighting","deletedAt":null,"_id":"5cff621648229f7d88fc8c55","key":"425c","name":"Heart Afire (feat. Strix) - Defqwop","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-31T15:56:32.000Z","hash":"74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0","directDownload":"/cdn/425c/74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0.zip","downloadURL":"/api/download/key/425c","coverURL":"/cdn/425c/74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0.jpg"}],"totalDocs":58,"lastPage":2,"prevPage":null,"nextPage":1}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/search/text/1?q=bennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":483.5,"length":259,"bombs":0,"notes":633,"obstacles":75,"njs":10,"njsOffset":0},"expert":{"duration":483.5,"length":259,"bombs":0,"notes":749,"obstacles":75,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Polish Girl","songSubName":"Neon Indian","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":112},"stats":{"downloads":22758,"plays":1858,"downVotes":46,"upVotes":321,"heat":44.8969327,"rating":0.8113833336977261},"description":"Difficulties: Expert, Hard\r\nWatch on YouTube: https://youtu.be/hqP3dSkbgzo\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=276\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\n\r\nSupport me on Patreon: https://www.patreon.com/bennydabeast\r\n\r\nEnjoy! :)","deletedAt":null,"_id":"5cff620c48229f7d88fc628b","key":"1c9","name":"Polish Girl - Neon Indian","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-23T02:43:12.000Z","hash":"b785a1f0651a7bcdf6acf6f1212d892622ec7c3b","directDownload":"/cdn/1c9/b785a1f0651a7bcdf6acf6f1212d892622ec7c3b.zip","downloadURL":"/api/download/key/1c9","coverURL":"/cdn/1c9/b785a1f0651a7bcdf6acf6f1212d892622ec7c3b.png"},{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":841,"length":290,"bombs":12,"notes":438,"obstacles":8,"njs":10,"njsOffset":0},"normal":null,"hard":{"duration":841,"length":290,"bombs":12,"notes":519,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":649,"length":223,"bombs":12,"notes":686,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Burn","songSubName":"Ellie Goulding","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":174},"stats":{"downloads":365536,"plays":14209,"downVotes":243,"upVotes":6282,"heat":105.2630539,"rating":0.9298710853963835},"description":"Difficulties: Expert, Hard, Normal\r\nCome Hang Out on Twitch! http://www.twitch.tv/bennydabeastlive\r\nYouTube Link: https://youtu.be/KOdvSdrnaeE\r\n\r\nIf you like this, check out my other beat maps:\r\nUptown Funk: https://beatsaver.com/details.php?id=1962\r\nCAN'T STOP THE FEELING by Justin Timberlake: https://beatsaver.com/details.php?id=1587\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc66ae","key":"636","name":"Burn - Ellie Goulding","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-22T20:31:34.000Z","hash":"9d31d3aab3d58ab540df63caed06d62ff1cfefdd","directDownload":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.zip","downloadURL":"/api/download/key/636","coverURL":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33323,"plays":366,"downVotes":20,"upVotes":784,"heat":339.1373378,"rating":0.9117263729459533},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23783,"plays":4,"downVotes":17,"upVotes":98,"heat":435.3491072,"rating":0.7679775361870059},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61401,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":432,"obstacles":284,"njs":10,"njsOffset":0},"expert":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":616,"obstacles":293,"njs":10,"njsOffset":0},"expertPlus":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":932,"obstacles":307,"njs":14,"njsOffset":0}}}],"songName":"High Enough ft. Rosie Darling","songSubName":"Justin Caruso","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":54589,"plays":0,"downVotes":133,"upVotes":615,"heat":626.3101804,"rating":0.7782575573900176},"description":"Difficulties: Expert+, Expert, Hard\r\nYouTube Preview: https://youtu.be/pGiaa-PJOps","deletedAt":null,"_id":"5cff621548229f7d88fc8a9d","key":"3f8b","name":"High Enough ft. Rosie Darling (Baaku Remix) - Justin Caruso","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-21T19:20:21.000Z","hash":"b5483e3f38df32d233700b49a0bdbf72ba1650cc","directDownload":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.zip","downloadURL":"/api/download/key/3f8b","coverURL":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":395.75,"length":221,"bombs":0,"notes":937,"obstacles":6,"njs":14,"njsOffset":0}}}],"songName":"Alone feat. Kyle Reynolds","songSubName":"Asketa & Natan Chaim","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":107},"stats":{"downloads":53298,"plays":0,"downVotes":26,"upVotes":707,"heat":634.3503027,"rating":0.9007980474001192},"description":"You ever just find a map gathering dust but pretty much finished? Yeah... let's go ahead and release that.\r\nDifficulties: Expert+ Only\r\nYouTube Preview: https://youtu.be/cg1wBYBCqX0","deletedAt":null,"_id":"5cff621548229f7d88fc8b42","key":"40b2","name":"Alone feat. Kyle Reynolds - Asketa & Natan Chaim","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-25T21:57:52.000Z","hash":"84ac2667162920902490fb1a572ed4cf5ad50a1f","directDownload":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.zip","downloadURL":"/api/download/key/40b2","coverURL":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24160,"plays":0,"downVotes":24,"upVotes":345,"heat":641.4531495,"rating":0.8616190099755381},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":420,"length":201,"bombs":132,"notes":693,"obstacles":13,"njs":12,"njsOffset":0}}}],"songName":"Came Here for Love","songSubName":"Sigala & Ella Eyre","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":125},"stats":{"downloads":56576,"plays":0,"downVotes":29,"upVotes":877,"heat":653.490707,"rating":0.9077478149713},"description":"I haven't had this much fun playing a map in a long time to a freakin' amazing song! I hope you enjoy it as much as I do! :D\r\nYouTube Preview: Coming Soon","deletedAt":null,"_id":"5cff621648229f7d88fc8cf4","key":"4373","name":"Came Here for Love - Sigala & Ella Eyre","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T20:01:44.000Z","hash":"19a00f2fbe514aa821cf8ad68962d53bfa28b731","directDownload":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.zip","downloadURL":"/api/download/key/4373","coverURL":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":608,"length":190,"bombs":16,"notes":822,"obstacles":20,"njs":12,"njsOffset":0}}}],"songName":"The Greatest (ft. Kendrick Lamar)","songSubName":"Sia","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":192},"stats":{"downloads":109095,"plays":0,"downVotes":52,"upVotes":2038,"heat":653.9647126,"rating":0.9275557889693888},"description":"YouTube Preview: https://youtu.be/huUMotlFpig","deletedAt":null,"_id":"5cff621648229f7d88fc8cf7","key":"4377","name":"The Greatest - Sia","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T21:20:03.000Z","hash":"58cd8ddf99600d967bca61285e9e0c429138009d","directDownload":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.zip","downloadURL":"/api/download/key/4377","coverURL":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.png"}],"totalDocs":58,"lastPage":2,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/search/text/2?q=bennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":542,"length":264,"bombs":0,"notes":729,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":542,"length":264,"bombs":0,"notes":866,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Kids","songSubName":"MGMT","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":123},"stats":{"downloads":254408,"plays":20243,"downVotes":280,"upVotes":3799,"heat":37.9789082,"rating":0.8960440304227397},"description":"Need a Workout? This one will make you sweat!\r\nWatch on YouTube: https://youtu.be/R4fBH03WCqo\r\nDifficulties: Expert & Hard\r\nSabers: Made by Ikeiwa! Visit the Modding Discord to snag some: https://discord.gg/rpGSDFZ\r\n\r\nIf you like this, check out my other songs: Midnight City, What You Know\r\n\r\nTrain you shall, padawan. Enjoy you will, I hope. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc61ca","key":"fb","name":"Kids - MGMT","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-18T22:24:22.000Z","hash":"35d58f383a4b13c385c37b4ed6ff6460b765c29a","directDownload":"/cdn/fb/35d58f383a4b13c385c37b4ed6ff6460b765c29a.zip","downloadURL":"/api/download/key/fb","coverURL":"/cdn/fb/35d58f383a4b13c385c37b4ed6ff6460b765c29a.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":460694,"plays":44907,"downVotes":581,"upVotes":7760,"heat":41.8513528,"rating":0.9019390117645993},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":439,"length":233,"bombs":0,"notes":579,"obstacles":38,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"CAN'T STOP THE FEELING!","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":113},"stats":{"downloads":449121,"plays":9160,"downVotes":171,"upVotes":6106,"heat":78.4729763,"rating":0.9387653934376509},"description":"Difficulties: Expert Only     Lighting by GreatYazer\r\nWatch Me on Twitch: http://www.twitch.tv/bennydabeastlive\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc64fd","key":"45e","name":"CAN'T STOP THE FEELING! - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-08T21:44:40.000Z","hash":"91ba25c089d50e93154e2c7920c46d4730f22569","directDownload":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.zip","downloadURL":"/api/download/key/45e","coverURL":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":306,"length":236,"bombs":4,"notes":580,"obstacles":29,"njs":10,"njsOffset":0},"expert":{"duration":306,"length":236,"bombs":4,"notes":904,"obstacles":29,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Fake Love","songSubName":"BTS","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":78},"stats":{"downloads":206558,"plays":5793,"downVotes":126,"upVotes":2557,"heat":149.5784516,"rating":0.9109680906151835},"description":"Difficulties: Expert, Hard\r\nYouTube Preview: https://youtu.be/1LtE0PZnNZY\r\nMore Benny Originals™: https://beatsaver.com/browse/byuser/351","deletedAt":null,"_id":"5cff620e48229f7d88fc69b9","key":"9d0","name":"Fake Love - BTS","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-16T03:24:30.000Z","hash":"865989367d940d9964cc158cac54768676176ea4","directDownload":"/cdn/9d0/865989367d940d9964cc158cac54768676176ea4.zip","downloadURL":"/api/download/key/9d0","coverURL":"/cdn/9d0/865989367d940d9964cc158cac54768676176ea4.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":454,"length":252,"bombs":0,"notes":321,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":454,"length":252,"bombs":0,"notes":432,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":454,"length":252,"bombs":0,"notes":680,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":{"duration":454,"length":252,"bombs":0,"notes":810,"obstacles":14,"njs":10,"njsOffset":0}}}],"songName":"Some Nights","songSubName":"Fun.","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":108},"stats":{"downloads":296475,"plays":6779,"downVotes":134,"upVotes":6282,"heat":199.3542672,"rating":0.9448916908901297},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/X4R7VIZPffk","deletedAt":null,"_id":"5cff620f48229f7d88fc6cee","key":"efe","name":"Some Nights - Fun.","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-10T20:34:09.000Z","hash":"e64d6b7c5527d36005ae1801268798dd21ccf605","directDownload":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.zip","downloadURL":"/api/download/key/efe","coverURL":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":476.510009765625,"length":210,"bombs":1,"notes":301,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":476.510009765625,"length":210,"bombs":1,"notes":473,"obstacles":3,"njs":10,"njsOffset":0},"expert":{"duration":476.5141906738281,"length":210,"bombs":1,"notes":818,"obstacles":5,"njs":10,"njsOffset":0},"expertPlus":{"duration":476.510009765625,"length":210,"bombs":1,"notes":875,"obstacles":5,"njs":10,"njsOffset":0}}}],"songName":"Last Dance (Custic Remix)","songSubName":"Dua Lipa","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":136},"stats":{"downloads":123811,"plays":2780,"downVotes":95,"upVotes":1133,"heat":217.8287974,"rating":0.8729882137450646},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/R82tIOsEzGU","deletedAt":null,"_id":"5cff620f48229f7d88fc6e02","key":"10f3","name":"Last Dance (Custic Remix) - Dua Lipa","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-20T21:09:27.000Z","hash":"f1655fa1992bc9e841c915d0d32475506922a670","directDownload":"/cdn/10f3/f1655fa1992bc9e841c915d0d32475506922a670.zip","downloadURL":"/api/download/key/10f3","coverURL":"/cdn/10f3/f1655fa1992bc9e841c915d0d32475506922a670.png"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":528,"length":198,"bombs":0,"notes":280,"obstacles":26,"njs":10,"njsOffset":0},"normal":{"duration":528,"length":198,"bombs":0,"notes":360,"obstacles":26,"njs":10,"njsOffset":0},"hard":{"duration":530,"length":198,"bombs":0,"notes":521,"obstacles":154,"njs":12,"njsOffset":0},"expert":{"duration":530,"length":198,"bombs":0,"notes":746,"obstacles":155,"njs":12,"njsOffset":0},"expertPlus":{"duration":530,"length":198,"bombs":0,"notes":1225,"obstacles":207,"njs":14,"njsOffset":0}}}],"songName":"Don't Let Me Down (Illenium Remix)","songSubName":"The Chainsmokers feat. Daya","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":258392,"plays":1391,"downVotes":136,"upVotes":3842,"heat":379.4740165,"rating":0.9273907850726617},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nDifficulties: Expert+, Expert, Hard, Normal","deletedAt":null,"_id":"5cff621148229f7d88fc76f2","key":"1ff6","name":"Don't Let Me Down (Illenium Remix) - The Chainsmokers feat. Daya (100k Contest)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-12T18:48:50.000Z","hash":"5639a12cccf90238055116ec53e5297aa004627e","directDownload":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.zip","downloadURL":"/api/download/key/1ff6","coverURL":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61402,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24161,"plays":0,"downVotes":24,"upVotes":345,"heat":641.4531495,"rating":0.8616190099755381},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":501,"length":193,"bombs":0,"notes":231,"obstacles":8,"njs":10,"njsOffset":0},"normal":{"duration":501,"length":193,"bombs":0,"notes":259,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":501,"length":193,"bombs":0,"notes":442,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":501,"length":193,"bombs":0,"notes":487,"obstacles":8,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Kamikaze","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":155},"stats":{"downloads":65596,"plays":0,"downVotes":23,"upVotes":878,"heat":647.9081883,"rating":0.9132933652430438},"description":"An older map that I have no idea why I never published. So here it is!","deletedAt":null,"_id":"5cff621648229f7d88fc8c9d","key":"42de","name":"Kamikaze - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-01T22:12:10.000Z","hash":"63d2d5920e6676f63bf997ae606b6d1590cd8c8d","directDownload":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.zip","downloadURL":"/api/download/key/42de","coverURL":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.png"}],"totalDocs":58,"lastPage":2,"prevPage":1,"nextPage":null}"#.into());
+        pages.insert(
+            BEATSAVER_URL.join("maps/id/1").unwrap(),
+            serde_json::to_vec(&map).unwrap().into(),
+        );
+        pages.insert(
+            BEATSAVER_URL
+                .join(format!("download/hash/{}", MAP_ARCHIVE_HASH).as_str())
+                .unwrap(),
+            map_archive(),
+        );
         let client = FakeClientPaged::new(pages);
+        let dir = tempfile::tempdir().unwrap();
+        let target = InstallTarget::CustomLevels(dir.path().to_path_buf());
+        let folder = dir.path().join("1 (Song 1 - bennydabeast)");
 
-        assert_eq!(
-            client
-                .search(&"bennydabeast".into())
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "4a3d".to_string(),
-                "3aa4".to_string(),
-                "5d24".to_string(),
-                "4a45".to_string(),
-                "cef".to_string(),
-                "1254".to_string(),
-                "194d".to_string(),
-                "194e".to_string(),
-                "4200".to_string(),
-                "425c".to_string(),
-                "1c9".to_string(),
-                "636".to_string(),
-                "1bc4".to_string(),
-                "2556".to_string(),
-                "31f8".to_string(),
-                "3f8b".to_string(),
-                "40b2".to_string(),
-                "41cc".to_string(),
-                "4373".to_string(),
-                "4377".to_string(),
-                "fb".to_string(),
-                "155".to_string(),
-                "45e".to_string(),
-                "9d0".to_string(),
-                "efe".to_string(),
-                "10f3".to_string(),
-                "1ff6".to_string(),
-                "31f8".to_string(),
-                "41cc".to_string(),
-                "42de".to_string()
-            ]
-        );
+        let outcome = client.install_map(&MapId::from(1), &target).unwrap();
+        assert_eq!(outcome, InstallOutcome::Installed(folder.clone()));
+        assert!(folder.join("Info.dat").is_file());
+        let outcome = client.install_map(&MapId::from(1), &target).unwrap();
+        assert_eq!(outcome, InstallOutcome::AlreadyInstalled(folder));
+
+        let dir = tempfile::tempdir().unwrap();
+        let folder = dir.path().join("1 (Song 1 - bennydabeast)");
+        let mut installed = HashMap::new();
+        let outcome = client
+            .install_map_into(&MapId::from(1), dir.path(), &mut installed)
+            .unwrap();
+        assert_eq!(outcome, InstallOutcome::Installed(folder.clone()));
+        assert_eq!(installed.get(MAP_ARCHIVE_HASH), Some(&folder));
+        let outcome = client
+            .install_map_into(&MapId::from(1), dir.path(), &mut installed)
+            .unwrap();
+        assert_eq!(outcome, InstallOutcome::AlreadyInstalled(folder));
     }
+    #[cfg(feature = "hash")]
     #[test]
-    fn test_search_page() {
-        let client = FakeClient::new(BEATSAVER_URL.join("api/search/text/2?q=bennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":841,"length":290,"bombs":12,"notes":438,"obstacles":8,"njs":10,"njsOffset":0},"normal":null,"hard":{"duration":841,"length":290,"bombs":12,"notes":519,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":649,"length":223,"bombs":12,"notes":686,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Burn","songSubName":"Ellie Goulding","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":174},"stats":{"downloads":363792,"plays":14209,"downVotes":241,"upVotes":6251,"heat":105.2609634,"rating":0.9299311715015002},"description":"Difficulties: Expert, Hard, Normal\r\nCome Hang Out on Twitch! http://www.twitch.tv/bennydabeastlive\r\nYouTube Link: https://youtu.be/KOdvSdrnaeE\r\n\r\nIf you like this, check out my other beat maps:\r\nUptown Funk: https://beatsaver.com/details.php?id=1962\r\nCAN'T STOP THE FEELING by Justin Timberlake: https://beatsaver.com/details.php?id=1587\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc66ae","key":"636","name":"Burn - Ellie Goulding","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-22T20:31:34.000Z","hash":"9d31d3aab3d58ab540df63caed06d62ff1cfefdd","directDownload":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.zip","downloadURL":"/api/download/key/636","coverURL":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":259,"length":167,"bombs":2,"notes":706,"obstacles":36,"njs":10,"njsOffset":0}}}],"songName":"Otter Pop (feat. Hollis)","songSubName":"Shawn Wasabi","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":93},"stats":{"downloads":138178,"plays":3100,"downVotes":77,"upVotes":2274,"heat":131.8017412,"rating":0.9220994437102321},"description":"Difficulties: Expert+ Only\r\nMore Benny Originals: https://beatsaver.com/searchhtml.php?q=bennydabeast","deletedAt":null,"_id":"5cff620e48229f7d88fc687c","key":"83b","name":"Otter Pop (feat. Hollis) - Shawn Wasabi","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-06T21:44:56.000Z","hash":"c7e15a9eaeaae0fe9dd8e4ad62cfd849e3cc6cc7","directDownload":"/cdn/83b/c7e15a9eaeaae0fe9dd8e4ad62cfd849e3cc6cc7.zip","downloadURL":"/api/download/key/83b","coverURL":"/cdn/83b/c7e15a9eaeaae0fe9dd8e4ad62cfd849e3cc6cc7.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":454,"length":252,"bombs":0,"notes":321,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":454,"length":252,"bombs":0,"notes":432,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":454,"length":252,"bombs":0,"notes":680,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":{"duration":454,"length":252,"bombs":0,"notes":810,"obstacles":14,"njs":10,"njsOffset":0}}}],"songName":"Some Nights","songSubName":"Fun.","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":108},"stats":{"downloads":294847,"plays":6779,"downVotes":133,"upVotes":6250,"heat":199.3520718,"rating":0.9448837394753198},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/X4R7VIZPffk","deletedAt":null,"_id":"5cff620f48229f7d88fc6cee","key":"efe","name":"Some Nights - Fun.","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-10T20:34:09.000Z","hash":"e64d6b7c5527d36005ae1801268798dd21ccf605","directDownload":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.zip","downloadURL":"/api/download/key/efe","coverURL":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":372,"length":174,"bombs":0,"notes":271,"obstacles":16,"njs":10,"njsOffset":0},"hard":{"duration":372,"length":174,"bombs":0,"notes":474,"obstacles":16,"njs":10,"njsOffset":0},"expert":{"duration":371.5,"length":174,"bombs":0,"notes":643,"obstacles":16,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Better When I'm Dancin'","songSubName":"Meghan Trainor","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":84800,"plays":1362,"downVotes":25,"upVotes":1531,"heat":311.3518027,"rating":0.9309898926008106},"description":"\"We can do this together... I bet you feel better when you're dancing, yeah, yeah...\"\r\nDifficulties: Expert, Hard, Normal","deletedAt":null,"_id":"5cff621048229f7d88fc7319","key":"194c","name":"Better When I'm Dancin' - Meghan Trainor","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T12:10:29.000Z","hash":"9bd5e0efe697a38fe0da28f252a8bfb870da630a","directDownload":"/cdn/194c/9bd5e0efe697a38fe0da28f252a8bfb870da630a.zip","downloadURL":"/api/download/key/194c","coverURL":"/cdn/194c/9bd5e0efe697a38fe0da28f252a8bfb870da630a.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":371.5,"length":176,"bombs":18,"notes":636,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"So Funky [NCS Release]","songSubName":"NIVIRO","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":126},"stats":{"downloads":22878,"plays":963,"downVotes":10,"upVotes":557,"heat":310.9960095,"rating":0.9108753721370022},"description":"This map is one of the two that were created specifically for the 2018 Elements Tournament Qualifier.\r\nDifficulties: Expert+ Only","deletedAt":null,"_id":"5cff621048229f7d88fc731a","key":"194d","name":"So Funky - NVIRIO [Elements Qualifier]","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T13:13:31.000Z","hash":"a5bf0f0bd05e12fc10fd1dda65132531f00123a1","directDownload":"/cdn/194d/a5bf0f0bd05e12fc10fd1dda65132531f00123a1.zip","downloadURL":"/api/download/key/194d","coverURL":"/cdn/194d/a5bf0f0bd05e12fc10fd1dda65132531f00123a1.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33233,"plays":366,"downVotes":20,"upVotes":780,"heat":339.135058,"rating":0.9115234775631975},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23604,"plays":4,"downVotes":16,"upVotes":97,"heat":435.3491072,"rating":0.7722707184352429},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":395.75,"length":221,"bombs":0,"notes":937,"obstacles":6,"njs":14,"njsOffset":0}}}],"songName":"Alone feat. Kyle Reynolds","songSubName":"Asketa & Natan Chaim","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":107},"stats":{"downloads":53081,"plays":0,"downVotes":26,"upVotes":704,"heat":634.3483852,"rating":0.9005936790957055},"description":"You ever just find a map gathering dust but pretty much finished? Yeah... let's go ahead and release that.\r\nDifficulties: Expert+ Only\r\nYouTube Preview: https://youtu.be/cg1wBYBCqX0","deletedAt":null,"_id":"5cff621548229f7d88fc8b42","key":"40b2","name":"Alone feat. Kyle Reynolds - Asketa & Natan Chaim","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-25T21:57:52.000Z","hash":"84ac2667162920902490fb1a572ed4cf5ad50a1f","directDownload":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.zip","downloadURL":"/api/download/key/40b2","coverURL":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":387,"obstacles":54,"njs":10,"njsOffset":0},"expert":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":616,"obstacles":54,"njs":12,"njsOffset":0},"expertPlus":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":868,"obstacles":57,"njs":14,"njsOffset":0}}}],"songName":"Nevada (feat. Cozi Zuehlsdorff)","songSubName":"Vicetone","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":124},"stats":{"downloads":29853,"plays":0,"downVotes":32,"upVotes":1062,"heat":638.1088594,"rating":0.9134916861785893},"description":"YouTube Preview: https://youtu.be/3gb2gH8eueI","deletedAt":null,"_id":"5cff621548229f7d88fc8b94","key":"4131","name":"Nevada feat. Cozi Zuehlsdorff - Vicetone","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-27T18:42:01.000Z","hash":"5e847d92de6e01dfd5ce3491969b3939b4374699","directDownload":"/cdn/4131/5e847d92de6e01dfd5ce3491969b3939b4374699.zip","downloadURL":"/api/download/key/4131","coverURL":"/cdn/4131/5e847d92de6e01dfd5ce3491969b3939b4374699.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":501,"length":193,"bombs":0,"notes":231,"obstacles":8,"njs":10,"njsOffset":0},"normal":{"duration":501,"length":193,"bombs":0,"notes":259,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":501,"length":193,"bombs":0,"notes":442,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":501,"length":193,"bombs":0,"notes":487,"obstacles":8,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Kamikaze","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":155},"stats":{"downloads":65284,"plays":0,"downVotes":23,"upVotes":876,"heat":647.9071713,"rating":0.9132030079990229},"description":"An older map that I have no idea why I never published. So here it is!","deletedAt":null,"_id":"5cff621648229f7d88fc8c9d","key":"42de","name":"Kamikaze - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-01T22:12:10.000Z","hash":"63d2d5920e6676f63bf997ae606b6d1590cd8c8d","directDownload":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.zip","downloadURL":"/api/download/key/42de","coverURL":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.png"}],"totalDocs":58,"lastPage":5,"prevPage":1,"nextPage":3}"#.into());
-        let _: Page<Map> = client.search_page(&"bennydabeast".into(), 2).unwrap();
+    fn test_reconcile_library() {
+        let mut map: MapDetail = serde_json::from_str(map_json("1").as_str()).unwrap();
+        map.versions[0].hash = MAP_ARCHIVE_HASH.into();
+        let client = FakeClient::new(
+            BEATSAVER_URL
+                .join(format!("maps/hash/{}", MAP_ARCHIVE_HASH).as_str())
+                .unwrap(),
+            serde_json::to_vec(&map).unwrap().into(),
+        );
+        let dir = tempfile::tempdir().unwrap();
+        let archive = crate::archive::Archive::new(map_archive()).unwrap();
+        crate::install::extract(&archive, dir.path().join("1 (Song 1)").as_path()).unwrap();
+        std::fs::create_dir(dir.path().join("empty")).unwrap();
+
+        let library = client.reconcile_library(dir.path()).unwrap();
+        assert_eq!(library.up_to_date.len(), 1);
+        assert_eq!(library.up_to_date[0].0.path, dir.path().join("1 (Song 1)"));
+        assert!(library.outdated.is_empty() && library.unknown.is_empty());
     }
     #[test]
-    fn test_search_page_iter() {
+    fn test_rate_limit_policy() {
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/search/text/1?q=bennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":483.5,"length":259,"bombs":0,"notes":633,"obstacles":75,"njs":10,"njsOffset":0},"expert":{"duration":483.5,"length":259,"bombs":0,"notes":749,"obstacles":75,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Polish Girl","songSubName":"Neon Indian","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":112},"stats":{"downloads":22758,"plays":1858,"downVotes":46,"upVotes":321,"heat":44.8969327,"rating":0.8113833336977261},"description":"Difficulties: Expert, Hard\r\nWatch on YouTube: https://youtu.be/hqP3dSkbgzo\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=276\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\n\r\nSupport me on Patreon: https://www.patreon.com/bennydabeast\r\n\r\nEnjoy! :)","deletedAt":null,"_id":"5cff620c48229f7d88fc628b","key":"1c9","name":"Polish Girl - Neon Indian","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-23T02:43:12.000Z","hash":"b785a1f0651a7bcdf6acf6f1212d892622ec7c3b","directDownload":"/cdn/1c9/b785a1f0651a7bcdf6acf6f1212d892622ec7c3b.zip","downloadURL":"/api/download/key/1c9","coverURL":"/cdn/1c9/b785a1f0651a7bcdf6acf6f1212d892622ec7c3b.png"},{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":841,"length":290,"bombs":12,"notes":438,"obstacles":8,"njs":10,"njsOffset":0},"normal":null,"hard":{"duration":841,"length":290,"bombs":12,"notes":519,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":649,"length":223,"bombs":12,"notes":686,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Burn","songSubName":"Ellie Goulding","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":174},"stats":{"downloads":365536,"plays":14209,"downVotes":243,"upVotes":6282,"heat":105.2630539,"rating":0.9298710853963835},"description":"Difficulties: Expert, Hard, Normal\r\nCome Hang Out on Twitch! http://www.twitch.tv/bennydabeastlive\r\nYouTube Link: https://youtu.be/KOdvSdrnaeE\r\n\r\nIf you like this, check out my other beat maps:\r\nUptown Funk: https://beatsaver.com/details.php?id=1962\r\nCAN'T STOP THE FEELING by Justin Timberlake: https://beatsaver.com/details.php?id=1587\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc66ae","key":"636","name":"Burn - Ellie Goulding","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-22T20:31:34.000Z","hash":"9d31d3aab3d58ab540df63caed06d62ff1cfefdd","directDownload":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.zip","downloadURL":"/api/download/key/636","coverURL":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33323,"plays":366,"downVotes":20,"upVotes":784,"heat":339.1373378,"rating":0.9117263729459533},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23783,"plays":4,"downVotes":17,"upVotes":98,"heat":435.3491072,"rating":0.7679775361870059},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61401,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":432,"obstacles":284,"njs":10,"njsOffset":0},"expert":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":616,"obstacles":293,"njs":10,"njsOffset":0},"expertPlus":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":932,"obstacles":307,"njs":14,"njsOffset":0}}}],"songName":"High Enough ft. Rosie Darling","songSubName":"Justin Caruso","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":54589,"plays":0,"downVotes":133,"upVotes":615,"heat":626.3101804,"rating":0.7782575573900176},"description":"Difficulties: Expert+, Expert, Hard\r\nYouTube Preview: https://youtu.be/pGiaa-PJOps","deletedAt":null,"_id":"5cff621548229f7d88fc8a9d","key":"3f8b","name":"High Enough ft. Rosie Darling (Baaku Remix) - Justin Caruso","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-21T19:20:21.000Z","hash":"b5483e3f38df32d233700b49a0bdbf72ba1650cc","directDownload":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.zip","downloadURL":"/api/download/key/3f8b","coverURL":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":395.75,"length":221,"bombs":0,"notes":937,"obstacles":6,"njs":14,"njsOffset":0}}}],"songName":"Alone feat. Kyle Reynolds","songSubName":"Asketa & Natan Chaim","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":107},"stats":{"downloads":53298,"plays":0,"downVotes":26,"upVotes":707,"heat":634.3503027,"rating":0.9007980474001192},"description":"You ever just find a map gathering dust but pretty much finished? Yeah... let's go ahead and release that.\r\nDifficulties: Expert+ Only\r\nYouTube Preview: https://youtu.be/cg1wBYBCqX0","deletedAt":null,"_id":"5cff621548229f7d88fc8b42","key":"40b2","name":"Alone feat. Kyle Reynolds - Asketa & Natan Chaim","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-25T21:57:52.000Z","hash":"84ac2667162920902490fb1a572ed4cf5ad50a1f","directDownload":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.zip","downloadURL":"/api/download/key/40b2","coverURL":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24160,"plays":0,"downVotes":24,"upVotes":345,"heat":641.4531495,"rating":0.8616190099755381},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":420,"length":201,"bombs":132,"notes":693,"obstacles":13,"njs":12,"njsOffset":0}}}],"songName":"Came Here for Love","songSubName":"Sigala & Ella Eyre","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":125},"stats":{"downloads":56576,"plays":0,"downVotes":29,"upVotes":877,"heat":653.490707,"rating":0.9077478149713},"description":"I haven't had this much fun playing a map in a long time to a freakin' amazing song! I hope you enjoy it as much as I do! :D\r\nYouTube Preview: Coming Soon","deletedAt":null,"_id":"5cff621648229f7d88fc8cf4","key":"4373","name":"Came Here for Love - Sigala & Ella Eyre","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T20:01:44.000Z","hash":"19a00f2fbe514aa821cf8ad68962d53bfa28b731","directDownload":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.zip","downloadURL":"/api/download/key/4373","coverURL":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":608,"length":190,"bombs":16,"notes":822,"obstacles":20,"njs":12,"njsOffset":0}}}],"songName":"The Greatest (ft. Kendrick Lamar)","songSubName":"Sia","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":192},"stats":{"downloads":109095,"plays":0,"downVotes":52,"upVotes":2038,"heat":653.9647126,"rating":0.9275557889693888},"description":"YouTube Preview: https://youtu.be/huUMotlFpig","deletedAt":null,"_id":"5cff621648229f7d88fc8cf7","key":"4377","name":"The Greatest - Sia","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T21:20:03.000Z","hash":"58cd8ddf99600d967bca61285e9e0c429138009d","directDownload":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.zip","downloadURL":"/api/download/key/4377","coverURL":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.png"}],"totalDocs":58,"lastPage":2,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/search/text/2?q=bennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":542,"length":264,"bombs":0,"notes":729,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":542,"length":264,"bombs":0,"notes":866,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Kids","songSubName":"MGMT","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":123},"stats":{"downloads":254408,"plays":20243,"downVotes":280,"upVotes":3799,"heat":37.9789082,"rating":0.8960440304227397},"description":"Need a Workout? This one will make you sweat!\r\nWatch on YouTube: https://youtu.be/R4fBH03WCqo\r\nDifficulties: Expert & Hard\r\nSabers: Made by Ikeiwa! Visit the Modding Discord to snag some: https://discord.gg/rpGSDFZ\r\n\r\nIf you like this, check out my other songs: Midnight City, What You Know\r\n\r\nTrain you shall, padawan. Enjoy you will, I hope. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc61ca","key":"fb","name":"Kids - MGMT","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-18T22:24:22.000Z","hash":"35d58f383a4b13c385c37b4ed6ff6460b765c29a","directDownload":"/cdn/fb/35d58f383a4b13c385c37b4ed6ff6460b765c29a.zip","downloadURL":"/api/download/key/fb","coverURL":"/cdn/fb/35d58f383a4b13c385c37b4ed6ff6460b765c29a.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":460694,"plays":44907,"downVotes":581,"upVotes":7760,"heat":41.8513528,"rating":0.9019390117645993},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":439,"length":233,"bombs":0,"notes":579,"obstacles":38,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"CAN'T STOP THE FEELING!","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":113},"stats":{"downloads":449121,"plays":9160,"downVotes":171,"upVotes":6106,"heat":78.4729763,"rating":0.9387653934376509},"description":"Difficulties: Expert Only     Lighting by GreatYazer\r\nWatch Me on Twitch: http://www.twitch.tv/bennydabeastlive\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc64fd","key":"45e","name":"CAN'T STOP THE FEELING! - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-08T21:44:40.000Z","hash":"91ba25c089d50e93154e2c7920c46d4730f22569","directDownload":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.zip","downloadURL":"/api/download/key/45e","coverURL":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":306,"length":236,"bombs":4,"notes":580,"obstacles":29,"njs":10,"njsOffset":0},"expert":{"duration":306,"length":236,"bombs":4,"notes":904,"obstacles":29,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Fake Love","songSubName":"BTS","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":78},"stats":{"downloads":206558,"plays":5793,"downVotes":126,"upVotes":2557,"heat":149.5784516,"rating":0.9109680906151835},"description":"Difficulties: Expert, Hard\r\nYouTube Preview: https://youtu.be/1LtE0PZnNZY\r\nMore Benny Originals™: https://beatsaver.com/browse/byuser/351","deletedAt":null,"_id":"5cff620e48229f7d88fc69b9","key":"9d0","name":"Fake Love - BTS","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-16T03:24:30.000Z","hash":"865989367d940d9964cc158cac54768676176ea4","directDownload":"/cdn/9d0/865989367d940d9964cc158cac54768676176ea4.zip","downloadURL":"/api/download/key/9d0","coverURL":"/cdn/9d0/865989367d940d9964cc158cac54768676176ea4.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":454,"length":252,"bombs":0,"notes":321,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":454,"length":252,"bombs":0,"notes":432,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":454,"length":252,"bombs":0,"notes":680,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":{"duration":454,"length":252,"bombs":0,"notes":810,"obstacles":14,"njs":10,"njsOffset":0}}}],"songName":"Some Nights","songSubName":"Fun.","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":108},"stats":{"downloads":296475,"plays":6779,"downVotes":134,"upVotes":6282,"heat":199.3542672,"rating":0.9448916908901297},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/X4R7VIZPffk","deletedAt":null,"_id":"5cff620f48229f7d88fc6cee","key":"efe","name":"Some Nights - Fun.","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-10T20:34:09.000Z","hash":"e64d6b7c5527d36005ae1801268798dd21ccf605","directDownload":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.zip","downloadURL":"/api/download/key/efe","coverURL":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":476.510009765625,"length":210,"bombs":1,"notes":301,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":476.510009765625,"length":210,"bombs":1,"notes":473,"obstacles":3,"njs":10,"njsOffset":0},"expert":{"duration":476.5141906738281,"length":210,"bombs":1,"notes":818,"obstacles":5,"njs":10,"njsOffset":0},"expertPlus":{"duration":476.510009765625,"length":210,"bombs":1,"notes":875,"obstacles":5,"njs":10,"njsOffset":0}}}],"songName":"Last Dance (Custic Remix)","songSubName":"Dua Lipa","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":136},"stats":{"downloads":123811,"plays":2780,"downVotes":95,"upVotes":1133,"heat":217.8287974,"rating":0.8729882137450646},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/R82tIOsEzGU","deletedAt":null,"_id":"5cff620f48229f7d88fc6e02","key":"10f3","name":"Last Dance (Custic Remix) - Dua Lipa","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-20T21:09:27.000Z","hash":"f1655fa1992bc9e841c915d0d32475506922a670","directDownload":"/cdn/10f3/f1655fa1992bc9e841c915d0d32475506922a670.zip","downloadURL":"/api/download/key/10f3","coverURL":"/cdn/10f3/f1655fa1992bc9e841c915d0d32475506922a670.png"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":528,"length":198,"bombs":0,"notes":280,"obstacles":26,"njs":10,"njsOffset":0},"normal":{"duration":528,"length":198,"bombs":0,"notes":360,"obstacles":26,"njs":10,"njsOffset":0},"hard":{"duration":530,"length":198,"bombs":0,"notes":521,"obstacles":154,"njs":12,"njsOffset":0},"expert":{"duration":530,"length":198,"bombs":0,"notes":746,"obstacles":155,"njs":12,"njsOffset":0},"expertPlus":{"duration":530,"length":198,"bombs":0,"notes":1225,"obstacles":207,"njs":14,"njsOffset":0}}}],"songName":"Don't Let Me Down (Illenium Remix)","songSubName":"The Chainsmokers feat. Daya","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":258392,"plays":1391,"downVotes":136,"upVotes":3842,"heat":379.4740165,"rating":0.9273907850726617},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nDifficulties: Expert+, Expert, Hard, Normal","deletedAt":null,"_id":"5cff621148229f7d88fc76f2","key":"1ff6","name":"Don't Let Me Down (Illenium Remix) - The Chainsmokers feat. Daya (100k Contest)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-12T18:48:50.000Z","hash":"5639a12cccf90238055116ec53e5297aa004627e","directDownload":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.zip","downloadURL":"/api/download/key/1ff6","coverURL":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61402,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24161,"plays":0,"downVotes":24,"upVotes":345,"heat":641.4531495,"rating":0.8616190099755381},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":501,"length":193,"bombs":0,"notes":231,"obstacles":8,"njs":10,"njsOffset":0},"normal":{"duration":501,"length":193,"bombs":0,"notes":259,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":501,"length":193,"bombs":0,"notes":442,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":501,"length":193,"bombs":0,"notes":487,"obstacles":8,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Kamikaze","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":155},"stats":{"downloads":65596,"plays":0,"downVotes":23,"upVotes":878,"heat":647.9081883,"rating":0.9132933652430438},"description":"An older map that I have no idea why I never published. So here it is!","deletedAt":null,"_id":"5cff621648229f7d88fc8c9d","key":"42de","name":"Kamikaze - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-01T22:12:10.000Z","hash":"63d2d5920e6676f63bf997ae606b6d1590cd8c8d","directDownload":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.zip","downloadURL":"/api/download/key/42de","coverURL":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.png"}],"totalDocs":58,"lastPage":2,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
+        pages.insert(
+            BEATSAVER_URL.join("maps/id/1").unwrap(),
+            map_json("1").into(),
+        );
+        let mut client = FakeClientPaged::new(pages);
+        client.rate_limits.store(1, Ordering::SeqCst);
+        assert!(matches!(
+            client.map(&MapId::from(1)),
+            Err(BeatSaverApiError::RateLimitError(_))
+        ));
 
-        assert_eq!(
-            client
-                .search_page_iter(&"bennydabeast".into(), 1)
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
-            vec![
-                "1c9".to_string(),
-                "636".to_string(),
-                "1bc4".to_string(),
-                "2556".to_string(),
-                "31f8".to_string(),
-                "3f8b".to_string(),
-                "40b2".to_string(),
-                "41cc".to_string(),
-                "4373".to_string(),
-                "4377".to_string(),
-                "fb".to_string(),
-                "155".to_string(),
-                "45e".to_string(),
-                "9d0".to_string(),
-                "efe".to_string(),
-                "10f3".to_string(),
-                "1ff6".to_string(),
-                "31f8".to_string(),
-                "41cc".to_string(),
-                "42de".to_string()
-            ]
+        let status = client.rate_limit_status().unwrap();
+        assert_eq!(status.remaining, Some(0));
+        assert_eq!(status.reset.unwrap().timestamp(), 1622548800);
+
+        client.rate_limit_policy = RateLimitPolicy::WaitAndRetry { max_waits: 2 };
+        client.rate_limits.store(2, Ordering::SeqCst);
+        assert_eq!(client.map(&MapId::from(1)).unwrap().id, "1");
+        assert_eq!(client.rate_limits.load(Ordering::SeqCst), 0);
+
+        client.rate_limits.store(3, Ordering::SeqCst);
+        assert!(matches!(
+            client.map(&MapId::from(1)),
+            Err(BeatSaverApiError::RateLimitError(_))
+        ));
+    }
+    #[test]
+    fn test_retry_policy() {
+        let mut pages = HashMap::new();
+        pages.insert(
+            BEATSAVER_URL.join("maps/id/1").unwrap(),
+            map_json("1").into(),
         );
+        let mut client = FakeClientPaged::new(pages);
+        client.server_errors.store(1, Ordering::SeqCst);
+        assert!(matches!(
+            client.map(&MapId::from(1)),
+            Err(BeatSaverApiError::HttpError { status, .. }) if status.is_server_error()
+        ));
+
+        client.retry_policy = RetryPolicy::new()
+            .with_max_attempts(3)
+            .with_base_delay(Duration::from_millis(1));
+        client.rate_limit_tracker = Default::default();
+        client.server_errors.store(2, Ordering::SeqCst);
+        assert_eq!(client.map(&MapId::from(1)).unwrap().id, "1");
+        assert_eq!(client.server_errors.load(Ordering::SeqCst), 0);
+        assert_eq!(client.rate_limit_status().unwrap().remaining, Some(7));
+
+        client.server_errors.store(3, Ordering::SeqCst);
+        assert!(client.map(&MapId::from(1)).is_err());
     }
     #[test]
-    fn test_search_advanced() {
+    fn test_response_cache() {
+        let map_url = BEATSAVER_URL.join("maps/id/1").unwrap();
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/search/advanced/0?q=uploader.username%3Abennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":642.25,"length":240,"bombs":0,"notes":805,"obstacles":41,"njs":12,"njsOffset":0},"expert":{"duration":642.25,"length":240,"bombs":0,"notes":1048,"obstacles":41,"njs":12,"njsOffset":0},"expertPlus":{"duration":642.25,"length":240,"bombs":0,"notes":1060,"obstacles":41,"njs":15,"njsOffset":0}}}],"songName":"Fractures (ft. Nevve)","songSubName":"Illenium","songAuthorName":"BennyDaBeast","levelAuthorName":"AnniversaryTeam","bpm":160},"stats":{"downloads":99011,"plays":0,"downVotes":54,"upVotes":2048,"heat":709.0725474,"rating":0.926909109741899},"description":"Part of the Anniversary Song Pack","deletedAt":null,"_id":"5cff621748229f7d88fc9156","key":"4a3d","name":"[Anniversary] Fractures (ft. Nevve) - Illenium [BennyDaBeast]","uploader":{"_id":"5cff0b7798cc5a672c8565a3","username":"anniversaryteam"},"uploaded":"2019-05-03T14:09:37.000Z","hash":"440945a4fe86b5e3ee7f03b98c636c881930072f","directDownload":"/cdn/4a3d/440945a4fe86b5e3ee7f03b98c636c881930072f.zip","downloadURL":"/api/download/key/4a3d","coverURL":"/cdn/4a3d/440945a4fe86b5e3ee7f03b98c636c881930072f.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":492,"length":241,"bombs":0,"notes":322,"obstacles":14,"njs":10,"njsOffset":1},"normal":{"duration":492,"length":241,"bombs":0,"notes":522,"obstacles":15,"njs":10,"njsOffset":1},"hard":{"duration":492,"length":241,"bombs":0,"notes":638,"obstacles":66,"njs":10,"njsOffset":1},"expert":{"duration":492,"length":241,"bombs":0,"notes":1018,"obstacles":8,"njs":14,"njsOffset":0},"expertPlus":{"duration":492,"length":241,"bombs":0,"notes":1211,"obstacles":2,"njs":14,"njsOffset":0}}}],"songName":"Honolulu","songSubName":"Last Dinosaurs","songAuthorName":"Skyler Wallace & BennyDaBeast","levelAuthorName":"skylerwallace","bpm":122},"stats":{"downloads":14721,"plays":0,"downVotes":17,"upVotes":355,"heat":588.04205,"rating":0.877885433253045},"description":"122 BPM / 4:07 Runtime\r\nEasy / 322 Notes\r\nNormal / 522 Notes\r\nHard / 638 Notes\r\nExpert / 1018 Notes\r\nExpert+ / 1211 Notes\r\n\r\nThe sequel to Apollo :)\r\nEasy, Normal, and Expert are by me.\r\nHard by Benny.\r\nExpert+ is a collab between me and Benny.\r\n\r\nGameplay:   https://youtu.be/BvIX7v53MrE","deletedAt":null,"_id":"5cff621448229f7d88fc878f","key":"3aa4","name":"Honolulu - Last Dinosaurs (collab with BennyDaBeast)","uploader":{"_id":"5cff0b7298cc5a672c84ea67","username":"skylerwallace"},"uploaded":"2019-03-01T22:54:51.000Z","hash":"22282e0fe150880d67d570c5a70a0cc96929b251","directDownload":"/cdn/3aa4/22282e0fe150880d67d570c5a70a0cc96929b251.zip","downloadURL":"/api/download/key/3aa4","coverURL":"/cdn/3aa4/22282e0fe150880d67d570c5a70a0cc96929b251.jpg"},{"metadata":{"difficulties":{"easy":true,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":{"duration":548,"length":205,"njs":10,"njsOffset":0,"bombs":0,"notes":390,"obstacles":0},"expert":{"duration":548,"length":205,"njs":12,"njsOffset":-1,"bombs":0,"notes":776,"obstacles":0},"expertPlus":{"duration":548,"length":205,"njs":15,"njsOffset":0.4,"bombs":0,"notes":921,"obstacles":0},"hard":{"duration":548,"length":205,"njs":12,"njsOffset":-1,"bombs":0,"notes":567,"obstacles":0},"normal":{"duration":548,"length":205,"njs":10,"njsOffset":0,"bombs":0,"notes":455,"obstacles":0}},"name":"Standard"}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Hikaru Utada & Skrillex","songName":"Face My Fears","songSubName":"","bpm":160},"stats":{"downloads":80500,"plays":0,"downVotes":38,"upVotes":1557,"heat":909.5656655,"rating":0.9244675039910542},"description":"Part of the Anniversary Song Pack","deletedAt":null,"_id":"5d560b54b8ed570006cdcf53","key":"5d24","name":"[Anniversary] Face My Fears - Hikaru Utadu & Skrillex [BennyDaBeast]","uploader":{"_id":"5cff0b7798cc5a672c8565a3","username":"anniversaryteam"},"hash":"460fcbe6916bd64eb31ff65a03e4d46d4773e31c","uploaded":"2019-08-16T01:48:04.848Z","directDownload":"/cdn/5d24/460fcbe6916bd64eb31ff65a03e4d46d4773e31c.zip","downloadURL":"/api/download/key/5d24","coverURL":"/cdn/5d24/460fcbe6916bd64eb31ff65a03e4d46d4773e31c.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":552.0846557617188,"length":260,"bombs":104,"notes":403,"obstacles":22,"njs":10,"njsOffset":0},"normal":{"duration":552.0846557617188,"length":260,"bombs":104,"notes":481,"obstacles":22,"njs":10,"njsOffset":0},"hard":{"duration":552.0846557617188,"length":260,"bombs":104,"notes":647,"obstacles":22,"njs":12,"njsOffset":0},"expert":{"duration":456.08465576171875,"length":215,"bombs":104,"notes":693,"obstacles":22,"njs":12,"njsOffset":0},"expertPlus":{"duration":456.08465576171875,"length":215,"bombs":96,"notes":914,"obstacles":34,"njs":15,"njsOffset":0}}}],"songName":"Nothing Stopping Me (ft. Kat Nestel)","songSubName":"Vicetone","songAuthorName":"BennyDaBeast","levelAuthorName":"AnniversaryTeam","bpm":127},"stats":{"downloads":115241,"plays":0,"downVotes":74,"upVotes":2207,"heat":709.1032131,"rating":0.9219668036751855},"description":"Part of the Anniversary Song Pack","deletedAt":null,"_id":"5cff621748229f7d88fc915d","key":"4a45","name":"[Anniversary] Nothing Stopping Me (ft. Kat Nestel) - Vicetone [BennyDaBeast]","uploader":{"_id":"5cff0b7798cc5a672c8565a3","username":"anniversaryteam"},"uploaded":"2019-05-03T14:10:40.000Z","hash":"01e9554069556f1756cbfcf9ac233b68d98b19c8","directDownload":"/cdn/4a45/01e9554069556f1756cbfcf9ac233b68d98b19c8.zip","downloadURL":"/api/download/key/4a45","coverURL":"/cdn/4a45/01e9554069556f1756cbfcf9ac233b68d98b19c8.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":false,"expert":false,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":0,"length":0,"bombs":0,"notes":0,"obstacles":0,"njs":10,"njsOffset":0},"normal":null,"hard":null,"expert":null,"expertPlus":null}}],"songName":"Infinite","songSubName":"Magic Sword","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":116},"stats":{"downloads":16313,"plays":0,"downVotes":7,"upVotes":45,"heat":176.6093392,"rating":0.7548008178765884},"description":"I took a new direction with this custom beat saber map. I wanted to improve my lighting skills, and so I initially began playing around with them before getting to notes... but as I continued to work on it, I realized it wasn't meant to have notes. It's purely meant to be an experience with lighting, so please sit back and enjoy. :D\r\n\r\nHuge thanks to Rolo for teaching me how to mod the game to remove the overlay!","deletedAt":null,"_id":"5cff620e48229f7d88fc6ba3","key":"cef","name":"Infinite - Magic Sword (Light Show)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-31T03:52:10.000Z","hash":"75484d416df87022a5f4c46aabe48b360db3744f","directDownload":"/cdn/cef/75484d416df87022a5f4c46aabe48b360db3744f.zip","downloadURL":"/api/download/key/cef","coverURL":"/cdn/cef/75484d416df87022a5f4c46aabe48b360db3744f.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":428,"length":136,"bombs":61,"notes":239,"obstacles":31,"njs":10,"njsOffset":0},"hard":{"duration":428,"length":136,"bombs":61,"notes":364,"obstacles":31,"njs":10,"njsOffset":0},"expert":{"duration":428,"length":136,"bombs":61,"notes":491,"obstacles":31,"njs":10,"njsOffset":0},"expertPlus":{"duration":428,"length":136,"bombs":61,"notes":541,"obstacles":31,"njs":10,"njsOffset":0}}}],"songName":"Bad to the Bone (Steve Urkel Remix)","songSubName":"Melodysheep","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":188},"stats":{"downloads":22944,"plays":1177,"downVotes":43,"upVotes":267,"heat":232.8685369,"rating":0.7971013122538653},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/ioIhxMuqlws","deletedAt":null,"_id":"5cff620f48229f7d88fc6ede","key":"1254","name":"Bad to the Bone (Steve Urkel Remix)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-29T01:28:43.000Z","hash":"b988ec86eac113d7e3a400ebe9f14682db746f25","directDownload":"/cdn/1254/b988ec86eac113d7e3a400ebe9f14682db746f25.zip","downloadURL":"/api/download/key/1254","coverURL":"/cdn/1254/b988ec86eac113d7e3a400ebe9f14682db746f25.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":371.5,"length":176,"bombs":18,"notes":636,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"So Funky [NCS Release]","songSubName":"NIVIRO","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":126},"stats":{"downloads":22923,"plays":963,"downVotes":10,"upVotes":559,"heat":310.9975946,"rating":0.9110037879052106},"description":"This map is one of the two that were created specifically for the 2018 Elements Tournament Qualifier.\r\nDifficulties: Expert+ Only","deletedAt":null,"_id":"5cff621048229f7d88fc731a","key":"194d","name":"So Funky - NVIRIO [Elements Qualifier]","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T13:13:31.000Z","hash":"a5bf0f0bd05e12fc10fd1dda65132531f00123a1","directDownload":"/cdn/194d/a5bf0f0bd05e12fc10fd1dda65132531f00123a1.zip","downloadURL":"/api/download/key/194d","coverURL":"/cdn/194d/a5bf0f0bd05e12fc10fd1dda65132531f00123a1.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":413.125,"length":130,"bombs":179,"notes":664,"obstacles":9,"njs":10,"njsOffset":0}}}],"songName":"THE HEISENBERG SONG","songSubName":"Say My Name","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":191},"stats":{"downloads":30244,"plays":129,"downVotes":39,"upVotes":41,"heat":308.6084522,"rating":0.5091703485317295},"description":"https://www.twitch.tv/heisenberg_irl\r\n\r\nA tribute to the mapping style of the one and only, Heisenberg in real life. The real Heisenberg. Not the fake one.","deletedAt":null,"_id":"5cff621048229f7d88fc731b","key":"194e","name":"THE HEISENBERG SONG (Tribute to Heisenbergirl)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T13:50:34.000Z","hash":"c3b7b8a99a8f07961122b37547794f03adb27f96","directDownload":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.zip","downloadURL":"/api/download/key/194e","coverURL":"/cdn/194e/c3b7b8a99a8f07961122b37547794f03adb27f96.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":554.9882202148438,"length":188,"bombs":0,"notes":1015,"obstacles":17,"njs":12,"njsOffset":0}}}],"songName":"RISE (ft. The Glitch Mob, Mako, The Word Alive)","songSubName":"League of Legends","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":177},"stats":{"downloads":20983,"plays":0,"downVotes":70,"upVotes":386,"heat":643.0575537,"rating":0.7916663490366552},"description":"YouTube Preview: https://youtu.be/Zf_wiprotdw","deletedAt":null,"_id":"5cff621648229f7d88fc8c15","key":"4200","name":"RISE feat The Glitch Mob, Mako, The Word Alive - League of Legends","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-30T14:58:24.000Z","hash":"ca715e7b3ab2b5514919893f150190717e2efbbe","directDownload":"/cdn/4200/ca715e7b3ab2b5514919893f150190717e2efbbe.zip","downloadURL":"/api/download/key/4200","coverURL":"/cdn/4200/ca715e7b3ab2b5514919893f150190717e2efbbe.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":392.1283264160156,"length":235,"bombs":0,"notes":880,"obstacles":43,"njs":14,"njsOffset":0}}}],"songName":"Heart Afire (feat. Strix)","songSubName":"Defqwop","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":100},"stats":{"downloads":16306,"plays":0,"downVotes":8,"upVotes":436,"heat":645.1868215,"rating":0.9051052345375648},"description":"YouTube Preview: https://youtu.be/dY-jMl3RKpw\r\nEDIT: Fixed Borked Lighting","deletedAt":null,"_id":"5cff621648229f7d88fc8c55","key":"425c","name":"Heart Afire (feat. Strix) - Defqwop","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-31T15:56:32.000Z","hash":"74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0","directDownload":"/cdn/425c/74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0.zip","downloadURL":"/api/download/key/425c","coverURL":"/cdn/425c/74d074a7eb088c70c86d3d0eb3b5d63666d2c8b0.jpg"}],"totalDocs":58,"lastPage":2,"prevPage":null,"nextPage":1}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/search/advanced/1?q=uploader.username%3Abennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":483.5,"length":259,"bombs":0,"notes":633,"obstacles":75,"njs":10,"njsOffset":0},"expert":{"duration":483.5,"length":259,"bombs":0,"notes":749,"obstacles":75,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Polish Girl","songSubName":"Neon Indian","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":112},"stats":{"downloads":22758,"plays":1858,"downVotes":46,"upVotes":321,"heat":44.8969327,"rating":0.8113833336977261},"description":"Difficulties: Expert, Hard\r\nWatch on YouTube: https://youtu.be/hqP3dSkbgzo\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=276\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\n\r\nSupport me on Patreon: https://www.patreon.com/bennydabeast\r\n\r\nEnjoy! :)","deletedAt":null,"_id":"5cff620c48229f7d88fc628b","key":"1c9","name":"Polish Girl - Neon Indian","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-23T02:43:12.000Z","hash":"b785a1f0651a7bcdf6acf6f1212d892622ec7c3b","directDownload":"/cdn/1c9/b785a1f0651a7bcdf6acf6f1212d892622ec7c3b.zip","downloadURL":"/api/download/key/1c9","coverURL":"/cdn/1c9/b785a1f0651a7bcdf6acf6f1212d892622ec7c3b.png"},{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":841,"length":290,"bombs":12,"notes":438,"obstacles":8,"njs":10,"njsOffset":0},"normal":null,"hard":{"duration":841,"length":290,"bombs":12,"notes":519,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":649,"length":223,"bombs":12,"notes":686,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Burn","songSubName":"Ellie Goulding","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":174},"stats":{"downloads":365536,"plays":14209,"downVotes":243,"upVotes":6282,"heat":105.2630539,"rating":0.9298710853963835},"description":"Difficulties: Expert, Hard, Normal\r\nCome Hang Out on Twitch! http://www.twitch.tv/bennydabeastlive\r\nYouTube Link: https://youtu.be/KOdvSdrnaeE\r\n\r\nIf you like this, check out my other beat maps:\r\nUptown Funk: https://beatsaver.com/details.php?id=1962\r\nCAN'T STOP THE FEELING by Justin Timberlake: https://beatsaver.com/details.php?id=1587\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc66ae","key":"636","name":"Burn - Ellie Goulding","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-22T20:31:34.000Z","hash":"9d31d3aab3d58ab540df63caed06d62ff1cfefdd","directDownload":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.zip","downloadURL":"/api/download/key/636","coverURL":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33323,"plays":366,"downVotes":20,"upVotes":784,"heat":339.1373378,"rating":0.9117263729459533},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23783,"plays":4,"downVotes":17,"upVotes":98,"heat":435.3491072,"rating":0.7679775361870059},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61401,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":432,"obstacles":284,"njs":10,"njsOffset":0},"expert":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":616,"obstacles":293,"njs":10,"njsOffset":0},"expertPlus":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":932,"obstacles":307,"njs":14,"njsOffset":0}}}],"songName":"High Enough ft. Rosie Darling","songSubName":"Justin Caruso","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":54589,"plays":0,"downVotes":133,"upVotes":615,"heat":626.3101804,"rating":0.7782575573900176},"description":"Difficulties: Expert+, Expert, Hard\r\nYouTube Preview: https://youtu.be/pGiaa-PJOps","deletedAt":null,"_id":"5cff621548229f7d88fc8a9d","key":"3f8b","name":"High Enough ft. Rosie Darling (Baaku Remix) - Justin Caruso","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-21T19:20:21.000Z","hash":"b5483e3f38df32d233700b49a0bdbf72ba1650cc","directDownload":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.zip","downloadURL":"/api/download/key/3f8b","coverURL":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":395.75,"length":221,"bombs":0,"notes":937,"obstacles":6,"njs":14,"njsOffset":0}}}],"songName":"Alone feat. Kyle Reynolds","songSubName":"Asketa & Natan Chaim","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":107},"stats":{"downloads":53298,"plays":0,"downVotes":26,"upVotes":707,"heat":634.3503027,"rating":0.9007980474001192},"description":"You ever just find a map gathering dust but pretty much finished? Yeah... let's go ahead and release that.\r\nDifficulties: Expert+ Only\r\nYouTube Preview: https://youtu.be/cg1wBYBCqX0","deletedAt":null,"_id":"5cff621548229f7d88fc8b42","key":"40b2","name":"Alone feat. Kyle Reynolds - Asketa & Natan Chaim","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-25T21:57:52.000Z","hash":"84ac2667162920902490fb1a572ed4cf5ad50a1f","directDownload":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.zip","downloadURL":"/api/download/key/40b2","coverURL":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24160,"plays":0,"downVotes":24,"upVotes":345,"heat":641.4531495,"rating":0.8616190099755381},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":420,"length":201,"bombs":132,"notes":693,"obstacles":13,"njs":12,"njsOffset":0}}}],"songName":"Came Here for Love","songSubName":"Sigala & Ella Eyre","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":125},"stats":{"downloads":56576,"plays":0,"downVotes":29,"upVotes":877,"heat":653.490707,"rating":0.9077478149713},"description":"I haven't had this much fun playing a map in a long time to a freakin' amazing song! I hope you enjoy it as much as I do! :D\r\nYouTube Preview: Coming Soon","deletedAt":null,"_id":"5cff621648229f7d88fc8cf4","key":"4373","name":"Came Here for Love - Sigala & Ella Eyre","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T20:01:44.000Z","hash":"19a00f2fbe514aa821cf8ad68962d53bfa28b731","directDownload":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.zip","downloadURL":"/api/download/key/4373","coverURL":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":608,"length":190,"bombs":16,"notes":822,"obstacles":20,"njs":12,"njsOffset":0}}}],"songName":"The Greatest (ft. Kendrick Lamar)","songSubName":"Sia","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":192},"stats":{"downloads":109095,"plays":0,"downVotes":52,"upVotes":2038,"heat":653.9647126,"rating":0.9275557889693888},"description":"YouTube Preview: https://youtu.be/huUMotlFpig","deletedAt":null,"_id":"5cff621648229f7d88fc8cf7","key":"4377","name":"The Greatest - Sia","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T21:20:03.000Z","hash":"58cd8ddf99600d967bca61285e9e0c429138009d","directDownload":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.zip","downloadURL":"/api/download/key/4377","coverURL":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.png"}],"totalDocs":58,"lastPage":2,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/search/advanced/2?q=uploader.username%3Abennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":542,"length":264,"bombs":0,"notes":729,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":542,"length":264,"bombs":0,"notes":866,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Kids","songSubName":"MGMT","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":123},"stats":{"downloads":254408,"plays":20243,"downVotes":280,"upVotes":3799,"heat":37.9789082,"rating":0.8960440304227397},"description":"Need a Workout? This one will make you sweat!\r\nWatch on YouTube: https://youtu.be/R4fBH03WCqo\r\nDifficulties: Expert & Hard\r\nSabers: Made by Ikeiwa! Visit the Modding Discord to snag some: https://discord.gg/rpGSDFZ\r\n\r\nIf you like this, check out my other songs: Midnight City, What You Know\r\n\r\nTrain you shall, padawan. Enjoy you will, I hope. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc61ca","key":"fb","name":"Kids - MGMT","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-18T22:24:22.000Z","hash":"35d58f383a4b13c385c37b4ed6ff6460b765c29a","directDownload":"/cdn/fb/35d58f383a4b13c385c37b4ed6ff6460b765c29a.zip","downloadURL":"/api/download/key/fb","coverURL":"/cdn/fb/35d58f383a4b13c385c37b4ed6ff6460b765c29a.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":460694,"plays":44907,"downVotes":581,"upVotes":7760,"heat":41.8513528,"rating":0.9019390117645993},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":439,"length":233,"bombs":0,"notes":579,"obstacles":38,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"CAN'T STOP THE FEELING!","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":113},"stats":{"downloads":449121,"plays":9160,"downVotes":171,"upVotes":6106,"heat":78.4729763,"rating":0.9387653934376509},"description":"Difficulties: Expert Only     Lighting by GreatYazer\r\nWatch Me on Twitch: http://www.twitch.tv/bennydabeastlive\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc64fd","key":"45e","name":"CAN'T STOP THE FEELING! - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-08T21:44:40.000Z","hash":"91ba25c089d50e93154e2c7920c46d4730f22569","directDownload":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.zip","downloadURL":"/api/download/key/45e","coverURL":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":306,"length":236,"bombs":4,"notes":580,"obstacles":29,"njs":10,"njsOffset":0},"expert":{"duration":306,"length":236,"bombs":4,"notes":904,"obstacles":29,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Fake Love","songSubName":"BTS","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":78},"stats":{"downloads":206558,"plays":5793,"downVotes":126,"upVotes":2557,"heat":149.5784516,"rating":0.9109680906151835},"description":"Difficulties: Expert, Hard\r\nYouTube Preview: https://youtu.be/1LtE0PZnNZY\r\nMore Benny Originals™: https://beatsaver.com/browse/byuser/351","deletedAt":null,"_id":"5cff620e48229f7d88fc69b9","key":"9d0","name":"Fake Love - BTS","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-16T03:24:30.000Z","hash":"865989367d940d9964cc158cac54768676176ea4","directDownload":"/cdn/9d0/865989367d940d9964cc158cac54768676176ea4.zip","downloadURL":"/api/download/key/9d0","coverURL":"/cdn/9d0/865989367d940d9964cc158cac54768676176ea4.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":454,"length":252,"bombs":0,"notes":321,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":454,"length":252,"bombs":0,"notes":432,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":454,"length":252,"bombs":0,"notes":680,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":{"duration":454,"length":252,"bombs":0,"notes":810,"obstacles":14,"njs":10,"njsOffset":0}}}],"songName":"Some Nights","songSubName":"Fun.","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":108},"stats":{"downloads":296475,"plays":6779,"downVotes":134,"upVotes":6282,"heat":199.3542672,"rating":0.9448916908901297},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/X4R7VIZPffk","deletedAt":null,"_id":"5cff620f48229f7d88fc6cee","key":"efe","name":"Some Nights - Fun.","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-10T20:34:09.000Z","hash":"e64d6b7c5527d36005ae1801268798dd21ccf605","directDownload":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.zip","downloadURL":"/api/download/key/efe","coverURL":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":476.510009765625,"length":210,"bombs":1,"notes":301,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":476.510009765625,"length":210,"bombs":1,"notes":473,"obstacles":3,"njs":10,"njsOffset":0},"expert":{"duration":476.5141906738281,"length":210,"bombs":1,"notes":818,"obstacles":5,"njs":10,"njsOffset":0},"expertPlus":{"duration":476.510009765625,"length":210,"bombs":1,"notes":875,"obstacles":5,"njs":10,"njsOffset":0}}}],"songName":"Last Dance (Custic Remix)","songSubName":"Dua Lipa","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":136},"stats":{"downloads":123811,"plays":2780,"downVotes":95,"upVotes":1133,"heat":217.8287974,"rating":0.8729882137450646},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/R82tIOsEzGU","deletedAt":null,"_id":"5cff620f48229f7d88fc6e02","key":"10f3","name":"Last Dance (Custic Remix) - Dua Lipa","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-20T21:09:27.000Z","hash":"f1655fa1992bc9e841c915d0d32475506922a670","directDownload":"/cdn/10f3/f1655fa1992bc9e841c915d0d32475506922a670.zip","downloadURL":"/api/download/key/10f3","coverURL":"/cdn/10f3/f1655fa1992bc9e841c915d0d32475506922a670.png"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":528,"length":198,"bombs":0,"notes":280,"obstacles":26,"njs":10,"njsOffset":0},"normal":{"duration":528,"length":198,"bombs":0,"notes":360,"obstacles":26,"njs":10,"njsOffset":0},"hard":{"duration":530,"length":198,"bombs":0,"notes":521,"obstacles":154,"njs":12,"njsOffset":0},"expert":{"duration":530,"length":198,"bombs":0,"notes":746,"obstacles":155,"njs":12,"njsOffset":0},"expertPlus":{"duration":530,"length":198,"bombs":0,"notes":1225,"obstacles":207,"njs":14,"njsOffset":0}}}],"songName":"Don't Let Me Down (Illenium Remix)","songSubName":"The Chainsmokers feat. Daya","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":258392,"plays":1391,"downVotes":136,"upVotes":3842,"heat":379.4740165,"rating":0.9273907850726617},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nDifficulties: Expert+, Expert, Hard, Normal","deletedAt":null,"_id":"5cff621148229f7d88fc76f2","key":"1ff6","name":"Don't Let Me Down (Illenium Remix) - The Chainsmokers feat. Daya (100k Contest)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-12T18:48:50.000Z","hash":"5639a12cccf90238055116ec53e5297aa004627e","directDownload":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.zip","downloadURL":"/api/download/key/1ff6","coverURL":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61402,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24161,"plays":0,"downVotes":24,"upVotes":345,"heat":641.4531495,"rating":0.8616190099755381},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":501,"length":193,"bombs":0,"notes":231,"obstacles":8,"njs":10,"njsOffset":0},"normal":{"duration":501,"length":193,"bombs":0,"notes":259,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":501,"length":193,"bombs":0,"notes":442,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":501,"length":193,"bombs":0,"notes":487,"obstacles":8,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Kamikaze","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":155},"stats":{"downloads":65596,"plays":0,"downVotes":23,"upVotes":878,"heat":647.9081883,"rating":0.9132933652430438},"description":"An older map that I have no idea why I never published. So here it is!","deletedAt":null,"_id":"5cff621648229f7d88fc8c9d","key":"42de","name":"Kamikaze - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-01T22:12:10.000Z","hash":"63d2d5920e6676f63bf997ae606b6d1590cd8c8d","directDownload":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.zip","downloadURL":"/api/download/key/42de","coverURL":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.png"}],"totalDocs":58,"lastPage":2,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
+        pages.insert(map_url.clone(), map_json("1").into());
+        let mut client = FakeClientPaged::new(pages);
+        assert_eq!(client.map(&MapId::from(1)).unwrap().id, "1");
+        assert_eq!(client.map(&MapId::from(1)).unwrap().id, "1");
+        assert_eq!(client.not_modified.load(Ordering::SeqCst), 0);
+
+        client.cache = Some(Box::new(MemoryCache::new()));
+        assert_eq!(client.map(&MapId::from(1)).unwrap().id, "1");
+        assert!(client.cache.as_ref().unwrap().get(&map_url).is_some());
+        assert_eq!(client.map(&MapId::from(1)).unwrap().id, "1");
+        assert_eq!(client.not_modified.load(Ordering::SeqCst), 1);
+
+        client.cache = Some(Box::new(
+            MemoryCache::new().with_ttl(Duration::from_secs(60)),
+        ));
+        assert_eq!(client.map(&MapId::from(1)).unwrap().id, "1");
+        client.pages.clear();
+        assert_eq!(client.map(&MapId::from(1)).unwrap().id, "1");
+        assert_eq!(client.not_modified.load(Ordering::SeqCst), 1);
+    }
+    #[test]
+    fn test_download_many() {
+        let mut pages = HashMap::new();
+        for key in 1..=3 {
+            pages.insert(
+                BEATSAVER_URL
+                    .join(format!("download/key/{}", key).as_str())
+                    .unwrap(),
+                Bytes::from(format!("map #{}", key)),
+            );
+        }
+        let mut client = FakeClientPaged::new(pages);
+        client.rate_limits.store(1, Ordering::SeqCst);
+        let ids: Vec<MapId> = (1..=3).map(MapId::from).collect();
+        let results = client.download_many(&ids, 1);
+        assert!(matches!(
+            results[0],
+            (_, Err(BeatSaverApiError::RateLimitError(_)))
+        ));
+        assert!(results[1..].iter().all(|(_, data)| data.is_ok()));
 
+        client.rate_limit_policy = RateLimitPolicy::WaitAndRetry { max_waits: 2 };
+        client.rate_limits.store(2, Ordering::SeqCst);
+        let results: Vec<_> = client
+            .download_many(&ids, 2)
+            .into_iter()
+            .map(|(id, data)| (id, data.unwrap()))
+            .collect();
         assert_eq!(
-            client
-                .search_advanced(&"uploader.username:bennydabeast".into())
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
+            results,
             vec![
-                "4a3d".to_string(),
-                "3aa4".to_string(),
-                "5d24".to_string(),
-                "4a45".to_string(),
-                "cef".to_string(),
-                "1254".to_string(),
-                "194d".to_string(),
-                "194e".to_string(),
-                "4200".to_string(),
-                "425c".to_string(),
-                "1c9".to_string(),
-                "636".to_string(),
-                "1bc4".to_string(),
-                "2556".to_string(),
-                "31f8".to_string(),
-                "3f8b".to_string(),
-                "40b2".to_string(),
-                "41cc".to_string(),
-                "4373".to_string(),
-                "4377".to_string(),
-                "fb".to_string(),
-                "155".to_string(),
-                "45e".to_string(),
-                "9d0".to_string(),
-                "efe".to_string(),
-                "10f3".to_string(),
-                "1ff6".to_string(),
-                "31f8".to_string(),
-                "41cc".to_string(),
-                "42de".to_string()
+                (MapId::from(1), Bytes::from("map #1")),
+                (MapId::from(2), Bytes::from("map #2")),
+                (MapId::from(3), Bytes::from("map #3")),
             ]
         );
+        assert_eq!(client.rate_limits.load(Ordering::SeqCst), 0);
     }
     #[test]
-    fn test_search_advanced_page() {
-        let client = FakeClient::new(BEATSAVER_URL.join("api/search/advanced/2?q=uploader.username%3Abennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":259,"length":167,"bombs":2,"notes":706,"obstacles":36,"njs":10,"njsOffset":0}}}],"songName":"Otter Pop (feat. Hollis)","songSubName":"Shawn Wasabi","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":93},"stats":{"downloads":138178,"plays":3100,"downVotes":77,"upVotes":2274,"heat":131.8017412,"rating":0.9220994437102321},"description":"Difficulties: Expert+ Only\r\nMore Benny Originals: https://beatsaver.com/searchhtml.php?q=bennydabeast","deletedAt":null,"_id":"5cff620e48229f7d88fc687c","key":"83b","name":"Otter Pop (feat. Hollis) - Shawn Wasabi","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-06T21:44:56.000Z","hash":"c7e15a9eaeaae0fe9dd8e4ad62cfd849e3cc6cc7","directDownload":"/cdn/83b/c7e15a9eaeaae0fe9dd8e4ad62cfd849e3cc6cc7.zip","downloadURL":"/api/download/key/83b","coverURL":"/cdn/83b/c7e15a9eaeaae0fe9dd8e4ad62cfd849e3cc6cc7.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":372,"length":174,"bombs":0,"notes":271,"obstacles":16,"njs":10,"njsOffset":0},"hard":{"duration":372,"length":174,"bombs":0,"notes":474,"obstacles":16,"njs":10,"njsOffset":0},"expert":{"duration":371.5,"length":174,"bombs":0,"notes":643,"obstacles":16,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Better When I'm Dancin'","songSubName":"Meghan Trainor","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":84800,"plays":1362,"downVotes":25,"upVotes":1531,"heat":311.3518027,"rating":0.9309898926008106},"description":"\"We can do this together... I bet you feel better when you're dancing, yeah, yeah...\"\r\nDifficulties: Expert, Hard, Normal","deletedAt":null,"_id":"5cff621048229f7d88fc7319","key":"194c","name":"Better When I'm Dancin' - Meghan Trainor","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-08T12:10:29.000Z","hash":"9bd5e0efe697a38fe0da28f252a8bfb870da630a","directDownload":"/cdn/194c/9bd5e0efe697a38fe0da28f252a8bfb870da630a.zip","downloadURL":"/api/download/key/194c","coverURL":"/cdn/194c/9bd5e0efe697a38fe0da28f252a8bfb870da630a.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33233,"plays":366,"downVotes":20,"upVotes":780,"heat":339.135058,"rating":0.9115234775631975},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23604,"plays":4,"downVotes":16,"upVotes":97,"heat":435.3491072,"rating":0.7722707184352429},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":387,"obstacles":54,"njs":10,"njsOffset":0},"expert":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":616,"obstacles":54,"njs":12,"njsOffset":0},"expertPlus":{"duration":418.0929870605469,"length":202,"bombs":0,"notes":868,"obstacles":57,"njs":14,"njsOffset":0}}}],"songName":"Nevada (feat. Cozi Zuehlsdorff)","songSubName":"Vicetone","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":124},"stats":{"downloads":29853,"plays":0,"downVotes":32,"upVotes":1062,"heat":638.1088594,"rating":0.9134916861785893},"description":"YouTube Preview: https://youtu.be/3gb2gH8eueI","deletedAt":null,"_id":"5cff621548229f7d88fc8b94","key":"4131","name":"Nevada feat. Cozi Zuehlsdorff - Vicetone","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-27T18:42:01.000Z","hash":"5e847d92de6e01dfd5ce3491969b3939b4374699","directDownload":"/cdn/4131/5e847d92de6e01dfd5ce3491969b3939b4374699.zip","downloadURL":"/api/download/key/4131","coverURL":"/cdn/4131/5e847d92de6e01dfd5ce3491969b3939b4374699.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":501,"length":193,"bombs":0,"notes":231,"obstacles":8,"njs":10,"njsOffset":0},"normal":{"duration":501,"length":193,"bombs":0,"notes":259,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":501,"length":193,"bombs":0,"notes":442,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":501,"length":193,"bombs":0,"notes":487,"obstacles":8,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Kamikaze","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":155},"stats":{"downloads":65284,"plays":0,"downVotes":23,"upVotes":876,"heat":647.9071713,"rating":0.9132030079990229},"description":"An older map that I have no idea why I never published. So here it is!","deletedAt":null,"_id":"5cff621648229f7d88fc8c9d","key":"42de","name":"Kamikaze - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-01T22:12:10.000Z","hash":"63d2d5920e6676f63bf997ae606b6d1590cd8c8d","directDownload":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.zip","downloadURL":"/api/download/key/42de","coverURL":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":true,"normal":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":427,"length":184,"bombs":0,"notes":246,"obstacles":16,"njs":10,"njsOffset":0},"hard":{"duration":427,"length":184,"bombs":0,"notes":400,"obstacles":16,"njs":10,"njsOffset":0},"expert":{"duration":427,"length":184,"bombs":0,"notes":537,"obstacles":16,"njs":10,"njsOffset":0},"expertPlus":{"duration":427,"length":184,"bombs":0,"notes":715,"obstacles":16,"njs":10,"njsOffset":0}}}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Two Door Cinema Club","songName":"What You Know","songSubName":"","bpm":139},"stats":{"downloads":43703,"plays":0,"downVotes":111,"upVotes":783,"heat":799.0684375,"rating":0.8272637389628217},"description":"Re-upload of an earlier Beatmap that was lost to the update.","deletedAt":null,"_id":"5d0a6a40c87a6a000653a546","key":"5388","name":"What You Know - Two Door Cinema Club","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"b8830915b5023c4c8030b2b0077688eb8508dc4c","uploaded":"2019-06-19T17:00:48.072Z","directDownload":"/cdn/5388/b8830915b5023c4c8030b2b0077688eb8508dc4c.zip","downloadURL":"/api/download/key/5388","coverURL":"/cdn/5388/b8830915b5023c4c8030b2b0077688eb8508dc4c.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":false,"expertPlus":true,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":532,"length":187,"bombs":28,"notes":840,"obstacles":17,"njs":12,"njsOffset":0}}}],"levelAuthorName":"BennyDaBeast","songAuthorName":"K/DA (ft Madison Beer, (G)I-DLE, Jaira Burns)","songName":"POP/STARS","songSubName":"","bpm":170},"stats":{"downloads":49887,"plays":0,"downVotes":70,"upVotes":257,"heat":798.5296208,"rating":0.7359395873642176},"description":"Re-upload of an earlier Beatmap that was lost to the update.","deletedAt":null,"_id":"5d0a6d30e66d5000063fc546","key":"538a","name":"KDA/POPSTARS - League of Legends","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"6b7c5baf85b9e4402b3461eb137908d4522a9a9c","uploaded":"2019-06-19T17:13:20.065Z","directDownload":"/cdn/538a/6b7c5baf85b9e4402b3461eb137908d4522a9a9c.zip","downloadURL":"/api/download/key/538a","coverURL":"/cdn/538a/6b7c5baf85b9e4402b3461eb137908d4522a9a9c.png"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":false,"hard":true,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":403,"length":201,"njs":12,"njsOffset":0,"bombs":8,"notes":614,"obstacles":41},"expertPlus":null,"hard":{"duration":403,"length":201,"njs":12,"njsOffset":1,"bombs":8,"notes":609,"obstacles":41},"normal":null},"name":"Standard"}],"levelAuthorName":"BennyDabeast","songAuthorName":"5 Seconds of Summer","songName":"Youngblood","songSubName":"","bpm":120},"stats":{"downloads":30853,"plays":0,"downVotes":16,"upVotes":738,"heat":939.6445566,"rating":0.9136488781274229},"description":"Just an Expert level.","deletedAt":null,"_id":"5d6aeb6ae6a676000604751c","key":"6078","name":"Youngblood - 5 Seconds of Summer","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"182e88e0e80450dd02bdcd6ac4924e3cc00d8673","uploaded":"2019-08-31T21:49:30.872Z","directDownload":"/cdn/6078/182e88e0e80450dd02bdcd6ac4924e3cc00d8673.zip","downloadURL":"/api/download/key/6078","coverURL":"/cdn/6078/182e88e0e80450dd02bdcd6ac4924e3cc00d8673.jpg"},{"metadata":{"difficulties":{"easy":false,"expert":true,"expertPlus":true,"hard":false,"normal":false},"duration":0,"automapper":null,"characteristics":[{"difficulties":{"easy":null,"expert":{"duration":387.5,"length":192,"njs":12,"njsOffset":0,"bombs":0,"notes":668,"obstacles":84},"expertPlus":{"duration":387.5,"length":192,"njs":15,"njsOffset":0,"bombs":0,"notes":770,"obstacles":129},"hard":null,"normal":null},"name":"Standard"}],"levelAuthorName":"BennyDaBeast","songAuthorName":"Young Pines","songName":"Start Right Now ft. Laney Jones","songSubName":"","bpm":121},"stats":{"downloads":52464,"plays":0,"downVotes":27,"upVotes":581,"heat":987.0967523,"rating":0.8894736256801323},"description":"We're gonna start right now! ... working out, of course!\n\nHope this makes you sweat. ;)","deletedAt":null,"_id":"5d8b94d7048dff0006da90d1","key":"65cb","name":"[YUR Workout] Start Right Now - Young Pines ft. Laney Jones","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"hash":"d203ee856b5b60d058fc1ff7aabca93ed7de1753","uploaded":"2019-09-25T16:24:55.914Z","directDownload":"/cdn/65cb/d203ee856b5b60d058fc1ff7aabca93ed7de1753.zip","downloadURL":"/api/download/key/65cb","coverURL":"/cdn/65cb/d203ee856b5b60d058fc1ff7aabca93ed7de1753.jpg"}],"totalDocs":46,"lastPage":4,"prevPage":1,"nextPage":3}"#.into());
-        let _: Page<Map> = client
-            .search_advanced_page(&"uploader.username:bennydabeast".into(), 2)
-            .unwrap();
-    }
-    #[test]
-    fn test_search_advanced_page_iter() {
+    fn test_download_missing() {
         let mut pages = HashMap::new();
-        pages.insert(BEATSAVER_URL.join("api/search/advanced/1?q=uploader.username%3Abennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":483.5,"length":259,"bombs":0,"notes":633,"obstacles":75,"njs":10,"njsOffset":0},"expert":{"duration":483.5,"length":259,"bombs":0,"notes":749,"obstacles":75,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Polish Girl","songSubName":"Neon Indian","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":112},"stats":{"downloads":22758,"plays":1858,"downVotes":46,"upVotes":321,"heat":44.8969327,"rating":0.8113833336977261},"description":"Difficulties: Expert, Hard\r\nWatch on YouTube: https://youtu.be/hqP3dSkbgzo\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=276\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\n\r\nSupport me on Patreon: https://www.patreon.com/bennydabeast\r\n\r\nEnjoy! :)","deletedAt":null,"_id":"5cff620c48229f7d88fc628b","key":"1c9","name":"Polish Girl - Neon Indian","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-23T02:43:12.000Z","hash":"b785a1f0651a7bcdf6acf6f1212d892622ec7c3b","directDownload":"/cdn/1c9/b785a1f0651a7bcdf6acf6f1212d892622ec7c3b.zip","downloadURL":"/api/download/key/1c9","coverURL":"/cdn/1c9/b785a1f0651a7bcdf6acf6f1212d892622ec7c3b.png"},{"metadata":{"difficulties":{"easy":true,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":841,"length":290,"bombs":12,"notes":438,"obstacles":8,"njs":10,"njsOffset":0},"normal":null,"hard":{"duration":841,"length":290,"bombs":12,"notes":519,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":649,"length":223,"bombs":12,"notes":686,"obstacles":8,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Burn","songSubName":"Ellie Goulding","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":174},"stats":{"downloads":365536,"plays":14209,"downVotes":243,"upVotes":6282,"heat":105.2630539,"rating":0.9298710853963835},"description":"Difficulties: Expert, Hard, Normal\r\nCome Hang Out on Twitch! http://www.twitch.tv/bennydabeastlive\r\nYouTube Link: https://youtu.be/KOdvSdrnaeE\r\n\r\nIf you like this, check out my other beat maps:\r\nUptown Funk: https://beatsaver.com/details.php?id=1962\r\nCAN'T STOP THE FEELING by Justin Timberlake: https://beatsaver.com/details.php?id=1587\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc66ae","key":"636","name":"Burn - Ellie Goulding","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-22T20:31:34.000Z","hash":"9d31d3aab3d58ab540df63caed06d62ff1cfefdd","directDownload":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.zip","downloadURL":"/api/download/key/636","coverURL":"/cdn/636/9d31d3aab3d58ab540df63caed06d62ff1cfefdd.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":580,"length":248,"bombs":0,"notes":1206,"obstacles":1,"njs":15,"njsOffset":0}}}],"songName":"Without Me (Nurko & Miles Away Remix)","songSubName":"Halsey","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":140},"stats":{"downloads":33323,"plays":366,"downVotes":20,"upVotes":784,"heat":339.1373378,"rating":0.9117263729459533},"description":"Difficulties: Expert+ Only","deletedAt":null,"_id":"5cff621148229f7d88fc7491","key":"1bc4","name":"Without Me (Nurko & Miles Away Remix) - Halsey","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-10-23T03:10:41.000Z","hash":"e447ac77708869ac151546110aecda97acac2cab","directDownload":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.zip","downloadURL":"/api/download/key/1bc4","coverURL":"/cdn/1bc4/e447ac77708869ac151546110aecda97acac2cab.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":387.6815185546875,"length":145,"bombs":0,"notes":586,"obstacles":7,"njs":10,"njsOffset":0}}}],"songName":"What Christmas Means to Me","songSubName":"Stevie Wonder","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":23783,"plays":4,"downVotes":17,"upVotes":98,"heat":435.3491072,"rating":0.7679775361870059},"description":"","deletedAt":null,"_id":"5cff621248229f7d88fc7a2f","key":"2556","name":"What Christmas Means to Me - Stevie Wonder","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-12-12T18:00:28.000Z","hash":"34a51a17715446e103b1ae57709fa595f77dc0d5","directDownload":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.zip","downloadURL":"/api/download/key/2556","coverURL":"/cdn/2556/34a51a17715446e103b1ae57709fa595f77dc0d5.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61401,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":432,"obstacles":284,"njs":10,"njsOffset":0},"expert":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":616,"obstacles":293,"njs":10,"njsOffset":0},"expertPlus":{"duration":374.0373229980469,"length":175,"bombs":0,"notes":932,"obstacles":307,"njs":14,"njsOffset":0}}}],"songName":"High Enough ft. Rosie Darling","songSubName":"Justin Caruso","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":128},"stats":{"downloads":54589,"plays":0,"downVotes":133,"upVotes":615,"heat":626.3101804,"rating":0.7782575573900176},"description":"Difficulties: Expert+, Expert, Hard\r\nYouTube Preview: https://youtu.be/pGiaa-PJOps","deletedAt":null,"_id":"5cff621548229f7d88fc8a9d","key":"3f8b","name":"High Enough ft. Rosie Darling (Baaku Remix) - Justin Caruso","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-21T19:20:21.000Z","hash":"b5483e3f38df32d233700b49a0bdbf72ba1650cc","directDownload":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.zip","downloadURL":"/api/download/key/3f8b","coverURL":"/cdn/3f8b/b5483e3f38df32d233700b49a0bdbf72ba1650cc.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":395.75,"length":221,"bombs":0,"notes":937,"obstacles":6,"njs":14,"njsOffset":0}}}],"songName":"Alone feat. Kyle Reynolds","songSubName":"Asketa & Natan Chaim","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":107},"stats":{"downloads":53298,"plays":0,"downVotes":26,"upVotes":707,"heat":634.3503027,"rating":0.9007980474001192},"description":"You ever just find a map gathering dust but pretty much finished? Yeah... let's go ahead and release that.\r\nDifficulties: Expert+ Only\r\nYouTube Preview: https://youtu.be/cg1wBYBCqX0","deletedAt":null,"_id":"5cff621548229f7d88fc8b42","key":"40b2","name":"Alone feat. Kyle Reynolds - Asketa & Natan Chaim","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-25T21:57:52.000Z","hash":"84ac2667162920902490fb1a572ed4cf5ad50a1f","directDownload":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.zip","downloadURL":"/api/download/key/40b2","coverURL":"/cdn/40b2/84ac2667162920902490fb1a572ed4cf5ad50a1f.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24160,"plays":0,"downVotes":24,"upVotes":345,"heat":641.4531495,"rating":0.8616190099755381},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":420,"length":201,"bombs":132,"notes":693,"obstacles":13,"njs":12,"njsOffset":0}}}],"songName":"Came Here for Love","songSubName":"Sigala & Ella Eyre","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":125},"stats":{"downloads":56576,"plays":0,"downVotes":29,"upVotes":877,"heat":653.490707,"rating":0.9077478149713},"description":"I haven't had this much fun playing a map in a long time to a freakin' amazing song! I hope you enjoy it as much as I do! :D\r\nYouTube Preview: Coming Soon","deletedAt":null,"_id":"5cff621648229f7d88fc8cf4","key":"4373","name":"Came Here for Love - Sigala & Ella Eyre","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T20:01:44.000Z","hash":"19a00f2fbe514aa821cf8ad68962d53bfa28b731","directDownload":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.zip","downloadURL":"/api/download/key/4373","coverURL":"/cdn/4373/19a00f2fbe514aa821cf8ad68962d53bfa28b731.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":false,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":null,"expertPlus":{"duration":608,"length":190,"bombs":16,"notes":822,"obstacles":20,"njs":12,"njsOffset":0}}}],"songName":"The Greatest (ft. Kendrick Lamar)","songSubName":"Sia","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":192},"stats":{"downloads":109095,"plays":0,"downVotes":52,"upVotes":2038,"heat":653.9647126,"rating":0.9275557889693888},"description":"YouTube Preview: https://youtu.be/huUMotlFpig","deletedAt":null,"_id":"5cff621648229f7d88fc8cf7","key":"4377","name":"The Greatest - Sia","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-04T21:20:03.000Z","hash":"58cd8ddf99600d967bca61285e9e0c429138009d","directDownload":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.zip","downloadURL":"/api/download/key/4377","coverURL":"/cdn/4377/58cd8ddf99600d967bca61285e9e0c429138009d.png"}],"totalDocs":58,"lastPage":2,"prevPage":0,"nextPage":2}"#.into());
-        pages.insert(BEATSAVER_URL.join("api/search/advanced/2?q=uploader.username%3Abennydabeast").unwrap(), r#"{"docs":[{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":542,"length":264,"bombs":0,"notes":729,"obstacles":40,"njs":10,"njsOffset":0},"expert":{"duration":542,"length":264,"bombs":0,"notes":866,"obstacles":40,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Kids","songSubName":"MGMT","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":123},"stats":{"downloads":254408,"plays":20243,"downVotes":280,"upVotes":3799,"heat":37.9789082,"rating":0.8960440304227397},"description":"Need a Workout? This one will make you sweat!\r\nWatch on YouTube: https://youtu.be/R4fBH03WCqo\r\nDifficulties: Expert & Hard\r\nSabers: Made by Ikeiwa! Visit the Modding Discord to snag some: https://discord.gg/rpGSDFZ\r\n\r\nIf you like this, check out my other songs: Midnight City, What You Know\r\n\r\nTrain you shall, padawan. Enjoy you will, I hope. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc61ca","key":"fb","name":"Kids - MGMT","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-18T22:24:22.000Z","hash":"35d58f383a4b13c385c37b4ed6ff6460b765c29a","directDownload":"/cdn/fb/35d58f383a4b13c385c37b4ed6ff6460b765c29a.zip","downloadURL":"/api/download/key/fb","coverURL":"/cdn/fb/35d58f383a4b13c385c37b4ed6ff6460b765c29a.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":399.6875,"length":228,"bombs":0,"notes":332,"obstacles":58,"njs":10,"njsOffset":0},"hard":{"duration":399.6875,"length":228,"bombs":0,"notes":367,"obstacles":76,"njs":10,"njsOffset":0},"expert":{"duration":399.6875,"length":228,"bombs":0,"notes":634,"obstacles":76,"njs":10,"njsOffset":0},"expertPlus":{"duration":399.6875,"length":228,"bombs":0,"notes":931,"obstacles":76,"njs":10,"njsOffset":0}}}],"songName":"Midnight City","songSubName":"M83","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":105},"stats":{"downloads":460694,"plays":44907,"downVotes":581,"upVotes":7760,"heat":41.8513528,"rating":0.9019390117645993},"description":"Improved beat mapping & added difficulties.\r\nWatch on YouTube: https://youtu.be/UeNn5RQ51is\r\nDifficulties: Expert+, Expert, Hard, Normal\r\n\r\nIf you like this, check out my other beat maps:\r\nWhat You Know by Two Door Cinema Club\r\nKids by MGMT\r\n\r\nTrain you must. Dance you shall. :)","deletedAt":null,"_id":"5cff620c48229f7d88fc6220","key":"155","name":"Midnight City - M83","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-05-20T18:56:28.000Z","hash":"fbd8b9338bffb98555a10c69887234fac959d83d","directDownload":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.zip","downloadURL":"/api/download/key/155","coverURL":"/cdn/155/fbd8b9338bffb98555a10c69887234fac959d83d.jpeg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":439,"length":233,"bombs":0,"notes":579,"obstacles":38,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"CAN'T STOP THE FEELING!","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":113},"stats":{"downloads":449121,"plays":9160,"downVotes":171,"upVotes":6106,"heat":78.4729763,"rating":0.9387653934376509},"description":"Difficulties: Expert Only     Lighting by GreatYazer\r\nWatch Me on Twitch: http://www.twitch.tv/bennydabeastlive\r\n\r\nIf you like this, check out my other beat maps:\r\nMidnight City by M83: https://beatsaver.com/details.php?id=542\r\nKids by MGMT: https://beatsaver.com/details.php?id=421\r\nWhat You Know by Two Door Cinema Club: https://beatsaver.com/details.php?id=1107\r\nPolish Girl by Neon Indian: https://beatsaver.com/details.php?id=694","deletedAt":null,"_id":"5cff620d48229f7d88fc64fd","key":"45e","name":"CAN'T STOP THE FEELING! - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-06-08T21:44:40.000Z","hash":"91ba25c089d50e93154e2c7920c46d4730f22569","directDownload":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.zip","downloadURL":"/api/download/key/45e","coverURL":"/cdn/45e/91ba25c089d50e93154e2c7920c46d4730f22569.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":306,"length":236,"bombs":4,"notes":580,"obstacles":29,"njs":10,"njsOffset":0},"expert":{"duration":306,"length":236,"bombs":4,"notes":904,"obstacles":29,"njs":10,"njsOffset":0},"expertPlus":null}}],"songName":"Fake Love","songSubName":"BTS","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":78},"stats":{"downloads":206558,"plays":5793,"downVotes":126,"upVotes":2557,"heat":149.5784516,"rating":0.9109680906151835},"description":"Difficulties: Expert, Hard\r\nYouTube Preview: https://youtu.be/1LtE0PZnNZY\r\nMore Benny Originals™: https://beatsaver.com/browse/byuser/351","deletedAt":null,"_id":"5cff620e48229f7d88fc69b9","key":"9d0","name":"Fake Love - BTS","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-07-16T03:24:30.000Z","hash":"865989367d940d9964cc158cac54768676176ea4","directDownload":"/cdn/9d0/865989367d940d9964cc158cac54768676176ea4.zip","downloadURL":"/api/download/key/9d0","coverURL":"/cdn/9d0/865989367d940d9964cc158cac54768676176ea4.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":454,"length":252,"bombs":0,"notes":321,"obstacles":14,"njs":10,"njsOffset":0},"hard":{"duration":454,"length":252,"bombs":0,"notes":432,"obstacles":14,"njs":10,"njsOffset":0},"expert":{"duration":454,"length":252,"bombs":0,"notes":680,"obstacles":14,"njs":10,"njsOffset":0},"expertPlus":{"duration":454,"length":252,"bombs":0,"notes":810,"obstacles":14,"njs":10,"njsOffset":0}}}],"songName":"Some Nights","songSubName":"Fun.","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":108},"stats":{"downloads":296475,"plays":6779,"downVotes":134,"upVotes":6282,"heat":199.3542672,"rating":0.9448916908901297},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/X4R7VIZPffk","deletedAt":null,"_id":"5cff620f48229f7d88fc6cee","key":"efe","name":"Some Nights - Fun.","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-10T20:34:09.000Z","hash":"e64d6b7c5527d36005ae1801268798dd21ccf605","directDownload":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.zip","downloadURL":"/api/download/key/efe","coverURL":"/cdn/efe/e64d6b7c5527d36005ae1801268798dd21ccf605.png"},{"metadata":{"difficulties":{"easy":false,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":{"duration":476.510009765625,"length":210,"bombs":1,"notes":301,"obstacles":0,"njs":10,"njsOffset":0},"hard":{"duration":476.510009765625,"length":210,"bombs":1,"notes":473,"obstacles":3,"njs":10,"njsOffset":0},"expert":{"duration":476.5141906738281,"length":210,"bombs":1,"notes":818,"obstacles":5,"njs":10,"njsOffset":0},"expertPlus":{"duration":476.510009765625,"length":210,"bombs":1,"notes":875,"obstacles":5,"njs":10,"njsOffset":0}}}],"songName":"Last Dance (Custic Remix)","songSubName":"Dua Lipa","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":136},"stats":{"downloads":123811,"plays":2780,"downVotes":95,"upVotes":1133,"heat":217.8287974,"rating":0.8729882137450646},"description":"Difficulties: Expert+, Expert, Hard, Normal\r\nYouTube Preview: https://youtu.be/R82tIOsEzGU","deletedAt":null,"_id":"5cff620f48229f7d88fc6e02","key":"10f3","name":"Last Dance (Custic Remix) - Dua Lipa","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-08-20T21:09:27.000Z","hash":"f1655fa1992bc9e841c915d0d32475506922a670","directDownload":"/cdn/10f3/f1655fa1992bc9e841c915d0d32475506922a670.zip","downloadURL":"/api/download/key/10f3","coverURL":"/cdn/10f3/f1655fa1992bc9e841c915d0d32475506922a670.png"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":528,"length":198,"bombs":0,"notes":280,"obstacles":26,"njs":10,"njsOffset":0},"normal":{"duration":528,"length":198,"bombs":0,"notes":360,"obstacles":26,"njs":10,"njsOffset":0},"hard":{"duration":530,"length":198,"bombs":0,"notes":521,"obstacles":154,"njs":12,"njsOffset":0},"expert":{"duration":530,"length":198,"bombs":0,"notes":746,"obstacles":155,"njs":12,"njsOffset":0},"expertPlus":{"duration":530,"length":198,"bombs":0,"notes":1225,"obstacles":207,"njs":14,"njsOffset":0}}}],"songName":"Don't Let Me Down (Illenium Remix)","songSubName":"The Chainsmokers feat. Daya","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":160},"stats":{"downloads":258392,"plays":1391,"downVotes":136,"upVotes":3842,"heat":379.4740165,"rating":0.9273907850726617},"description":"This is one of the 22 maps that were mapped for the 100k Contest where you can win over $7,000 in prizes. Go to https://bsaber.com/100k-contest/ to register!\r\nDifficulties: Expert+, Expert, Hard, Normal","deletedAt":null,"_id":"5cff621148229f7d88fc76f2","key":"1ff6","name":"Don't Let Me Down (Illenium Remix) - The Chainsmokers feat. Daya (100k Contest)","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2018-11-12T18:48:50.000Z","hash":"5639a12cccf90238055116ec53e5297aa004627e","directDownload":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.zip","downloadURL":"/api/download/key/1ff6","coverURL":"/cdn/1ff6/5639a12cccf90238055116ec53e5297aa004627e.png"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":true,"expert":true,"expertPlus":true},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":{"duration":386,"length":191,"bombs":32,"notes":354,"obstacles":107,"njs":10,"njsOffset":0},"expert":{"duration":388,"length":192,"bombs":68,"notes":616,"obstacles":123,"njs":10,"njsOffset":0},"expertPlus":{"duration":388,"length":192,"bombs":68,"notes":720,"obstacles":123,"njs":14,"njsOffset":0}}}],"songName":"Pretty Girl (Cheat Codes X Cade Remix)","songSubName":"Maggie Lindemann","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":121},"stats":{"downloads":61402,"plays":0,"downVotes":75,"upVotes":855,"heat":526.9053613,"rating":0.8657950630967391},"description":"Difficulties: Expert+, Expert, Hard","deletedAt":null,"_id":"5cff621348229f7d88fc8216","key":"31f8","name":"Pretty Girl (Cheat Codes X Cade Remix) - Maggie Lindemann","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-01-28T22:09:57.000Z","hash":"782d39ee1e15246ca16a9b00faf0188c4e1de63c","directDownload":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.zip","downloadURL":"/api/download/key/31f8","coverURL":"/cdn/31f8/782d39ee1e15246ca16a9b00faf0188c4e1de63c.jpg"},{"metadata":{"difficulties":{"easy":false,"normal":false,"hard":false,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":null,"normal":null,"hard":null,"expert":{"duration":448.0859069824219,"length":263,"bombs":0,"notes":715,"obstacles":47,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Suit & Tie ft. JAY Z","songSubName":"Justin Timberlake","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":102},"stats":{"downloads":24161,"plays":0,"downVotes":24,"upVotes":345,"heat":641.4531495,"rating":0.8616190099755381},"description":"YouTube Preview: https://youtu.be/62xhM4tYMhM","deletedAt":null,"_id":"5cff621648229f7d88fc8bee","key":"41cc","name":"Suit & Tie feat. JAY Z - Justin Timberlake","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-03-29T18:49:59.000Z","hash":"1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c","directDownload":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.zip","downloadURL":"/api/download/key/41cc","coverURL":"/cdn/41cc/1b8c32074d8915e938fa5fb6ee7fbdf6d4ec533c.jpg"},{"metadata":{"difficulties":{"easy":true,"normal":true,"hard":true,"expert":true,"expertPlus":false},"duration":0,"automapper":null,"characteristics":[{"name":"Standard","difficulties":{"easy":{"duration":501,"length":193,"bombs":0,"notes":231,"obstacles":8,"njs":10,"njsOffset":0},"normal":{"duration":501,"length":193,"bombs":0,"notes":259,"obstacles":8,"njs":10,"njsOffset":0},"hard":{"duration":501,"length":193,"bombs":0,"notes":442,"obstacles":8,"njs":10,"njsOffset":0},"expert":{"duration":501,"length":193,"bombs":0,"notes":487,"obstacles":8,"njs":12,"njsOffset":0},"expertPlus":null}}],"songName":"Kamikaze","songSubName":"WALK THE MOON","songAuthorName":"BennyDaBeast","levelAuthorName":"bennydabeast","bpm":155},"stats":{"downloads":65596,"plays":0,"downVotes":23,"upVotes":878,"heat":647.9081883,"rating":0.9132933652430438},"description":"An older map that I have no idea why I never published. So here it is!","deletedAt":null,"_id":"5cff621648229f7d88fc8c9d","key":"42de","name":"Kamikaze - WALK THE MOON","uploader":{"_id":"5cff0b7298cc5a672c84e98d","username":"bennydabeast"},"uploaded":"2019-04-01T22:12:10.000Z","hash":"63d2d5920e6676f63bf997ae606b6d1590cd8c8d","directDownload":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.zip","downloadURL":"/api/download/key/42de","coverURL":"/cdn/42de/63d2d5920e6676f63bf997ae606b6d1590cd8c8d.png"}],"totalDocs":58,"lastPage":2,"prevPage":1,"nextPage":null}"#.into());
-        let client = FakeClientPaged::new(pages);
-
+        for key in &["1", "2"] {
+            pages.insert(
+                BEATSAVER_URL
+                    .join(format!("maps/id/{}", key).as_str())
+                    .unwrap(),
+                map_json(key).into(),
+            );
+        }
+        pages.insert(
+            BEATSAVER_URL.join("download/key/2").unwrap(),
+            Bytes::from("map #2"),
+        );
+        let mut client = FakeClientPaged::new(pages);
+        client.rate_limit_policy = RateLimitPolicy::WaitAndRetry { max_waits: 1 };
+        client.rate_limits.store(1, Ordering::SeqCst);
+        let ids = vec![MapId::from(1), MapId::from(2), MapId::Hash(map_hash("3"))];
+        let installed: HashSet<String> = vec![map_hash("1"), map_hash("3")].into_iter().collect();
+        let results: Vec<_> = client
+            .download_missing(&ids, &installed, 2)
+            .into_iter()
+            .map(|(id, outcome)| (id, outcome.unwrap()))
+            .collect();
         assert_eq!(
-            client
-                .search_advanced_page_iter(&"uploader.username:bennydabeast".to_string(), 1)
-                .map(|m| m.unwrap().key)
-                .collect::<Vec<String>>(),
+            results,
             vec![
-                "1c9".to_string(),
-                "636".to_string(),
-                "1bc4".to_string(),
-                "2556".to_string(),
-                "31f8".to_string(),
-                "3f8b".to_string(),
-                "40b2".to_string(),
-                "41cc".to_string(),
-                "4373".to_string(),
-                "4377".to_string(),
-                "fb".to_string(),
-                "155".to_string(),
-                "45e".to_string(),
-                "9d0".to_string(),
-                "efe".to_string(),
-                "10f3".to_string(),
-                "1ff6".to_string(),
-                "31f8".to_string(),
-                "41cc".to_string(),
-                "42de".to_string()
+                (MapId::from(1), DownloadOutcome::Skipped),
+                (MapId::from(2), DownloadOutcome::Downloaded("map #2".into())),
+                (MapId::Hash(map_hash("3")), DownloadOutcome::Skipped),
             ]
         );
     }
     #[test]
     fn test_download() {
         let client = FakeClient::new(
-            BEATSAVER_URL.join("api/download/key/1").unwrap(),
+            BEATSAVER_URL.join("download/key/1").unwrap(),
             "map #1".into(),
         );
-        let _: Bytes = client.download("1".try_into().unwrap()).unwrap();
+        assert_eq!(client.download("1".try_into().unwrap()).unwrap(), "map #1");
         let client = FakeClient::new(
             BEATSAVER_URL
-                .join("api/download/hash/fda568fc27c20d21f8dc6f3709b49b5cc96723be")
+                .join("download/hash/fda568fc27c20d21f8dc6f3709b49b5cc96723be")
                 .unwrap(),
             "map #1".into(),
         );
-        let _: Bytes = client
+        client
             .download(
                 "fda568fc27c20d21f8dc6f3709b49b5cc96723be"
                     .try_into()
@@ -0,0 +1,104 @@
+//! Fake model instances, matching the [fixtures][crate::testing::fixtures]
+//!
+//! Each model gets a `fake` constructor returning a realistic instance, and maps get `with_*`
+//! methods to build up versions and difficulties:
+//! ```
+//! # #[cfg(feature = "test-util")]
+//! # mod main {
+//! use beatsaver_rs::model::map::{MapDetail, MapDifficulty, MapVersion};
+//!
+//! # fn main() {
+//! let mut hard = MapDifficulty::fake("Standard", "Hard");
+//! hard.nps = 2.5;
+//! let map = MapDetail::fake("2144")
+//!     .with_version(MapVersion::fake("2144").with_difficulty(hard));
+//! assert_eq!(map.latest_version().unwrap().diffs.len(), 2);
+//! # }
+//! # }
+//! ```
+use crate::model::map::{MapDetail, MapDifficulty, MapVersion};
+use crate::model::playlist::PlaylistDetail;
+use crate::model::user::UserDetail;
+use crate::testing::fixtures;
+
+impl MapDetail {
+    /// Builds the map returned by [map_json][crate::testing::fixtures::map_json] for the key
+    ///
+    /// The map has a single published version, with an `Expert` difficulty of the `Standard`
+    /// characteristic.
+    pub fn fake(key: &str) -> Self {
+        serde_json::from_str(&fixtures::map_json(key)).unwrap()
+    }
+    /// Appends a version to the map
+    ///
+    /// Fake versions are all uploaded at the same time, so the last one appended is the
+    /// [latest][crate::model::map::MapDetail::latest_version].
+    pub fn with_version(mut self, version: MapVersion) -> Self {
+        self.versions.push(version);
+        self
+    }
+}
+
+impl MapVersion {
+    /// Builds the version of the [fake][crate::model::map::MapDetail::fake] map with the key
+    pub fn fake(key: &str) -> Self {
+        MapDetail::fake(key).versions.remove(0)
+    }
+    /// Appends a difficulty to the version
+    pub fn with_difficulty(mut self, diff: MapDifficulty) -> Self {
+        self.diffs.push(diff);
+        self
+    }
+}
+
+impl MapDifficulty {
+    /// Builds a difficulty of a [fake][crate::model::map::MapDetail::fake] map, with the
+    /// specified characteristic (e.g. `Standard`) and difficulty (e.g. `ExpertPlus`)
+    pub fn fake(characteristic: &str, difficulty: &str) -> Self {
+        let mut diff = MapVersion::fake("1").diffs.remove(0);
+        diff.characteristic = characteristic.to_string();
+        diff.difficulty = difficulty.to_string();
+        diff
+    }
+}
+
+impl UserDetail {
+    /// Builds the user returned by [user_json][crate::testing::fixtures::user_json]
+    pub fn fake(id: usize, name: &str) -> Self {
+        serde_json::from_str(&fixtures::user_json(id, name)).unwrap()
+    }
+}
+
+impl PlaylistDetail {
+    /// Builds the playlist returned by [playlist_json][crate::testing::fixtures::playlist_json]
+    pub fn fake(id: usize) -> Self {
+        serde_json::from_str(&fixtures::playlist_json(id)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::map::{MapDetail, MapDifficulty, MapVersion};
+    use crate::model::playlist::PlaylistDetail;
+    use crate::model::user::UserDetail;
+    use crate::testing::fixtures;
+
+    #[test]
+    fn test_fake() {
+        let map = MapDetail::fake("2144");
+        assert_eq!(map.name, "Map 2144");
+        assert_eq!(map.hash(), Some(fixtures::map_hash("2144").as_str()));
+        assert_eq!(map.versions[0].diffs[0].difficulty, "Expert");
+
+        let map = map.with_version(
+            MapVersion::fake("2145").with_difficulty(MapDifficulty::fake("OneSaber", "Hard")),
+        );
+        let latest = map.latest_version().unwrap();
+        assert_eq!(latest.hash, fixtures::map_hash("2145"));
+        assert_eq!(latest.diffs[1].characteristic, "OneSaber");
+        assert_eq!(latest.diffs[1].difficulty, "Hard");
+
+        assert_eq!(UserDetail::fake(58338, "bennydabeast").name, "bennydabeast");
+        assert_eq!(PlaylistDetail::fake(1234).name, "Playlist 1234");
+    }
+}
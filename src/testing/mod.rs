@@ -0,0 +1,482 @@
+//! # Testing
+//!
+//! This module contains a mock client and canned API responses, so crates depending on this one
+//! can unit test their use of the API without the network. Requires the `test-util` feature.
+//!
+//! [MockClient][crate::testing::MockClient] implements both API traits and answers requests
+//! with the responses registered for their URL:
+//! ```
+//! # #[cfg(all(feature = "test-util", feature = "sync"))]
+//! # mod main {
+//! use beatsaver_rs::testing::{fixtures, MockClient};
+//! use beatsaver_rs::{BeatSaverApiSync, BEATSAVER_URL};
+//!
+//! # fn main() {
+//! let client = MockClient::new().with_response(
+//!     BEATSAVER_URL.join("maps/id/2144").unwrap(),
+//!     fixtures::map_json("2144"),
+//! );
+//! let map = client.map_by_key("2144").unwrap();
+//! assert_eq!(map.name, "Map 2144");
+//! // URLs without a response are answered with a 404
+//! assert!(client.map_by_key("570").is_err());
+//! # }
+//! # }
+//! ```
+//!
+//! [fake][crate::testing::fake] adds `fake` constructors to the models, to build the same maps,
+//! users and playlists without going through JSON.
+//!
+//! Responses of the real API can also be recorded to a [Cassette][crate::testing::Cassette] with
+//! a [Recorder][crate::testing::Recorder], and served back by a
+//! [ReplayClient][crate::testing::ReplayClient] to test whole flows (paging, search, downloads)
+//! deterministically.
+//!
+//! With the `test-server` feature, [TestServer][crate::testing::TestServer] serves fake maps over
+//! HTTP on a local port, to test the real clients end to end.
+use crate::auth::Credentials;
+use crate::cache::ResponseCache;
+use crate::index::KeyIndex;
+use crate::retry::RetryPolicy;
+use crate::{BeatSaverApiError, DownloadRoute, RateLimitPolicy, RateLimitTracker, BEATSAVER_URL};
+use bytes::Bytes;
+use http::{header, Request, Response, StatusCode};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+pub mod fake;
+mod replay;
+#[cfg(any(test, feature = "test-server"))]
+mod server;
+pub use replay::{Cassette, Recorder, ReplayClient, ReplayError};
+#[cfg(any(test, feature = "test-server"))]
+pub use server::TestServer;
+
+pub mod fixtures {
+    //! Canned API responses
+    //!
+    //! Maps are derived from their key: the map with the key `2144` is named `Map 2144`, and its
+    //! hash is [map_hash("2144")][crate::testing::fixtures::map_hash].
+    use bytes::Bytes;
+    use http::{Response, StatusCode};
+    use std::time::Duration;
+
+    /// Builds a map response with the specified key, the hash is derived from the key
+    pub fn map_json(key: &str) -> String {
+        format!(
+            r#"{{"id":"{key}","name":"Map {key}","description":"","uploader":{{"id":58338,"name":"bennydabeast","avatar":"https://cdn.beatsaver.com/avatar/58338.png"}},"metadata":{{"bpm":128,"duration":195,"songName":"Song {key}","songSubName":"","songAuthorName":"Artist","levelAuthorName":"bennydabeast"}},"stats":{{"plays":0,"downloads":100,"upvotes":10,"downvotes":1,"score":0.8}},"uploaded":"2021-06-01T12:00:00Z","automapper":false,"ranked":false,"qualified":false,"versions":[{{"hash":"{hash}","key":"{key}","state":"Published","createdAt":"2021-06-01T12:00:00Z","sageScore":0,"diffs":[{{"njs":16,"offset":0,"notes":500,"bombs":0,"obstacles":10,"nps":4.2,"length":400,"characteristic":"Standard","difficulty":"Expert","events":800,"chroma":false,"me":false,"ne":false,"cinema":false,"seconds":195,"paritySummary":{{"errors":0,"warns":0,"resets":0}},"maxScore":450000}}],"downloadURL":"https://cdn.beatsaver.com/{hash}.zip","coverURL":"https://cdn.beatsaver.com/{hash}.jpg","previewURL":"https://cdn.beatsaver.com/{hash}.mp3"}}],"createdAt":"2021-06-01T12:00:00Z","updatedAt":"2021-06-01T12:00:00Z","lastPublishedAt":"2021-06-01T12:00:00Z"}}"#,
+            key = key,
+            hash = map_hash(key)
+        )
+    }
+    /// Hash used for the map with the specified key in [map_json]
+    pub fn map_hash(key: &str) -> String {
+        format!("{:0>40}", key)
+    }
+    /// Builds a paged response containing maps with the specified keys
+    pub fn page_json(keys: &[&str]) -> Bytes {
+        let docs: Vec<String> = keys.iter().map(|k| map_json(k)).collect();
+        format!(r#"{{"docs":[{}]}}"#, docs.join(",")).into()
+    }
+    /// Builds a user response with the specified id and name
+    pub fn user_json(id: usize, name: &str) -> String {
+        format!(
+            r#"{{"id":{id},"name":"{name}","avatar":"https://cdn.beatsaver.com/avatar/{id}.png"}}"#,
+            id = id,
+            name = name
+        )
+    }
+    /// Builds a playlist response with the specified id
+    pub fn playlist_json(id: usize) -> String {
+        format!(
+            r#"{{"playlistId":{id},"name":"Playlist {id}","description":"","playlistImage":"https://cdn.beatsaver.com/playlist/{id}.jpg","owner":{{"id":4285984,"name":"qwerty01","avatar":"https://cdn.beatsaver.com/avatar/4285984.png"}},"createdAt":"2021-08-01T12:00:00Z","updatedAt":"2021-08-01T12:00:00Z","downloadURL":"https://api.beatsaver.com/playlists/id/{id}/download","type":"Public"}}"#,
+            id = id
+        )
+    }
+    /// Builds a playlist page response with the specified id containing maps with the specified keys
+    pub fn playlist_maps_json(id: usize, keys: &[&str]) -> Bytes {
+        let maps: Vec<String> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| format!(r#"{{"map":{},"order":{}}}"#, map_json(k), i))
+            .collect();
+        format!(
+            r#"{{"playlist":{},"maps":[{}]}}"#,
+            playlist_json(id),
+            maps.join(",")
+        )
+        .into()
+    }
+    /// Builds a paged response containing playlists with the specified ids
+    pub fn playlist_page_json(ids: &[usize]) -> Bytes {
+        let docs: Vec<String> = ids.iter().map(|i| playlist_json(*i)).collect();
+        format!(r#"{{"docs":[{}]}}"#, docs.join(",")).into()
+    }
+    /// Builds a 429 response, with the rate limit expiring after the specified duration
+    pub fn rate_limit_response(reset_after: Duration) -> Response<Bytes> {
+        let body = format!(r#"{{"reset":0,"resetAfter":{}}}"#, reset_after.as_millis());
+        Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header("x-ratelimit-remaining", "0")
+            .header("x-ratelimit-reset", "1622548800")
+            .body(Bytes::from(body))
+            .unwrap()
+    }
+    /// Builds a response with the specified status, and its reason as the body
+    pub fn status_response(status: StatusCode) -> Response<Bytes> {
+        let reason = status.canonical_reason().unwrap_or_default();
+        let mut response = Response::new(Bytes::from(reason));
+        *response.status_mut() = status;
+        response
+    }
+}
+
+/// Error type of [MockClient][crate::testing::MockClient], which never fails to send requests
+#[derive(Debug)]
+pub enum MockError {}
+impl Display for MockError {
+    fn fmt(&self, _: &mut Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+impl Error for MockError {}
+impl From<MockError> for BeatSaverApiError<MockError> {
+    fn from(e: MockError) -> Self {
+        Self::RequestError(e)
+    }
+}
+
+/// Client answering requests with canned responses, implementing both
+/// [BeatSaverApiAsync][crate::BeatSaverApiAsync] and [BeatSaverApiSync][crate::BeatSaverApiSync]
+///
+/// Requests are answered with the response registered for their URL, or a 404 if there is
+/// none. Responses carry an `ETag`, so [response caches][crate::cache] get revalidated with 304
+/// responses. Rate limit waits and retry delays return immediately.
+pub struct MockClient {
+    responses: HashMap<Url, Bytes>,
+    index: KeyIndex,
+    credentials: Option<Credentials>,
+    rate_limits: AtomicUsize,
+    rate_limit_policy: RateLimitPolicy,
+    rate_limit_tracker: RateLimitTracker,
+    server_errors: AtomicUsize,
+    retry_policy: RetryPolicy,
+    download_route: DownloadRoute,
+    cache: Option<Arc<dyn ResponseCache>>,
+    requests: Mutex<Vec<Url>>,
+}
+impl MockClient {
+    /// Creates a new [MockClient][crate::testing::MockClient] without any response
+    pub fn new() -> Self {
+        Self {
+            responses: HashMap::new(),
+            index: KeyIndex::new(),
+            credentials: None,
+            rate_limits: AtomicUsize::new(0),
+            rate_limit_policy: RateLimitPolicy::default(),
+            rate_limit_tracker: RateLimitTracker::new(),
+            server_errors: AtomicUsize::new(0),
+            retry_policy: RetryPolicy::default(),
+            download_route: DownloadRoute::default(),
+            cache: None,
+            requests: Mutex::new(vec![]),
+        }
+    }
+    /// Answers requests to the provided [Url][url::Url] with the provided body
+    pub fn with_response<B: Into<Bytes>>(mut self, url: Url, body: B) -> Self {
+        self.responses.insert(url, body.into());
+        self
+    }
+    /// Answers requests for the maps with the provided keys with [fixtures][crate::testing::fixtures],
+    /// both by key and by hash
+    pub fn with_maps(mut self, keys: &[&str]) -> Self {
+        for key in keys {
+            let data = Bytes::from(fixtures::map_json(key));
+            let hash = fixtures::map_hash(key);
+            let by_key = BEATSAVER_URL.join(format!("maps/id/{}", key).as_str());
+            let by_hash = BEATSAVER_URL.join(format!("maps/hash/{}", hash).as_str());
+            self.responses.insert(by_key.unwrap(), data.clone());
+            self.responses.insert(by_hash.unwrap(), data);
+        }
+        self
+    }
+    /// Answers the upcoming `count` requests with a 429, the rate limit expiring right away
+    pub fn with_rate_limits(self, count: usize) -> Self {
+        self.rate_limits.store(count, Ordering::SeqCst);
+        self
+    }
+    /// Answers the upcoming `count` requests with a 503
+    pub fn with_server_errors(self, count: usize) -> Self {
+        self.server_errors.store(count, Ordering::SeqCst);
+        self
+    }
+    /// Attaches [Credentials][crate::auth::Credentials], for code that requires authentication
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+    /// Sets how requests that hit a rate limit are handled
+    pub fn with_rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limit_policy = policy;
+        self
+    }
+    /// Sets how requests that fail due to transient errors are retried
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+    /// Sets the route map archives are downloaded through
+    pub fn with_download_route(mut self, route: DownloadRoute) -> Self {
+        self.download_route = route;
+        self
+    }
+    /// Attaches a [ResponseCache][crate::cache::ResponseCache]
+    pub fn with_response_cache(mut self, cache: Arc<dyn ResponseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+    /// URLs requested so far, in order
+    pub fn requests(&self) -> Vec<Url> {
+        self.requests.lock().unwrap().clone()
+    }
+    /// Answers a request
+    fn respond(&self, request: &Request<Bytes>) -> Response<Bytes> {
+        let url = Url::parse(request.uri().to_string().as_str()).unwrap();
+        self.requests.lock().unwrap().push(url.clone());
+
+        let take = |n: &AtomicUsize| {
+            n.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+        };
+        if take(&self.rate_limits) {
+            return fixtures::rate_limit_response(Default::default());
+        }
+        if take(&self.server_errors) {
+            return fixtures::status_response(StatusCode::SERVICE_UNAVAILABLE);
+        }
+        let data = match self.responses.get(&url) {
+            Some(data) => data,
+            None => return fixtures::status_response(StatusCode::NOT_FOUND),
+        };
+        let etag = format!("\"{}\"", data.len());
+        if request.headers().get(header::IF_NONE_MATCH) == Some(&etag.parse().unwrap()) {
+            return fixtures::status_response(StatusCode::NOT_MODIFIED);
+        }
+        let mut response = Response::new(data.clone());
+        response
+            .headers_mut()
+            .insert(header::ETAG, etag.parse().unwrap());
+        response
+    }
+}
+impl Default for MockClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl fmt::Debug for MockClient {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MockClient")
+            .field("responses", &self.responses.keys().collect::<Vec<_>>())
+            .field("requests", &self.requests())
+            .finish()
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_client {
+    use super::{MockClient, MockError};
+    use crate::auth::Credentials;
+    use crate::cache::ResponseCache;
+    use crate::index::KeyIndex;
+    use crate::retry::RetryPolicy;
+    use crate::{
+        BeatSaverApiAsync, BeatSaverApiError, DownloadRoute, RateLimitPolicy, RateLimitTracker,
+    };
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::{Request, Response};
+    use std::time::Duration;
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl BeatSaverApiAsync<MockError> for MockClient {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            Some(&self.index)
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            self.credentials.as_ref()
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            Some(&self.rate_limit_tracker)
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.retry_policy
+        }
+        fn download_route(&self) -> DownloadRoute {
+            self.download_route
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.cache.as_deref()
+        }
+        async fn sleep(&self, _duration: Duration) {}
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<MockError>> {
+            Ok(self.respond(&request))
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+mod sync_client {
+    use super::{MockClient, MockError};
+    use crate::auth::Credentials;
+    use crate::cache::ResponseCache;
+    use crate::index::KeyIndex;
+    use crate::retry::RetryPolicy;
+    use crate::{
+        BeatSaverApiError, BeatSaverApiSync, DownloadRoute, RateLimitPolicy, RateLimitTracker,
+    };
+    use bytes::Bytes;
+    use http::{Request, Response};
+    use std::time::Duration;
+
+    impl BeatSaverApiSync<MockError> for MockClient {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            Some(&self.index)
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            self.credentials.as_ref()
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            Some(&self.rate_limit_tracker)
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.retry_policy
+        }
+        fn download_route(&self) -> DownloadRoute {
+            self.download_route
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.cache.as_deref()
+        }
+        fn sleep(&self, _duration: Duration) {}
+        fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<MockError>> {
+            Ok(self.respond(&request))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "sync", feature = "async"))]
+    use crate::testing::MockClient;
+    #[cfg(any(feature = "sync", feature = "async"))]
+    use crate::BeatSaverApiError;
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_mock_client() {
+        use crate::retry::RetryPolicy;
+        use crate::testing::fixtures;
+        use crate::{BeatSaverApiSync, RateLimitPolicy, BEATSAVER_URL};
+
+        let client = MockClient::new().with_maps(&["2144"]);
+        assert_eq!(client.map_by_key("2144").unwrap().name, "Map 2144");
+        let hash = fixtures::map_hash("2144");
+        assert_eq!(client.map_by_hash(&hash).unwrap().id, "2144");
+        assert!(matches!(
+            client.map_by_key("570"),
+            Err(BeatSaverApiError::NotFound(_))
+        ));
+        assert_eq!(
+            client.requests()[0],
+            BEATSAVER_URL.join("maps/id/2144").unwrap()
+        );
+
+        let client = MockClient::new()
+            .with_response(
+                BEATSAVER_URL.join("users/id/58338").unwrap(),
+                fixtures::user_json(58338, "bennydabeast"),
+            )
+            .with_rate_limits(1)
+            .with_server_errors(1)
+            .with_rate_limit_policy(RateLimitPolicy::WaitAndRetry { max_waits: 1 })
+            .with_retry_policy(RetryPolicy::new().with_max_attempts(2));
+        assert_eq!(client.user(58338).unwrap().name, "bennydabeast");
+        assert_eq!(client.requests().len(), 3);
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_download_route() {
+        use crate::model::map::MapDetail;
+        use crate::testing::fixtures;
+        use crate::{cdn_download_url, BeatSaverApiSync, DownloadRoute, MapId, BEATSAVER_URL};
+
+        let hash = fixtures::map_hash("1");
+        let cdn = cdn_download_url(&hash);
+        let api = BEATSAVER_URL.join(format!("download/hash/{}", hash).as_str());
+        let api = api.unwrap();
+        let client = MockClient::new()
+            .with_response(cdn.clone(), "cdn")
+            .with_response(api.clone(), "api");
+        assert_eq!(client.download(MapId::Hash(hash.clone())).unwrap(), "api");
+
+        let client = client.with_download_route(DownloadRoute::Cdn);
+        assert_eq!(client.download(MapId::Hash(hash.clone())).unwrap(), "cdn");
+        let map: MapDetail = serde_json::from_str(&fixtures::map_json("1")).unwrap();
+        assert_eq!(client.download_version(&map.versions[0]).unwrap(), "cdn");
+        assert_eq!(client.requests(), [api, cdn.clone(), cdn]);
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_mock_client_async() {
+        use crate::BeatSaverApiAsync;
+        use futures::executor::block_on;
+
+        let client = MockClient::new().with_rate_limits(1).with_maps(&["2144"]);
+        assert!(matches!(
+            block_on(client.map_by_key("2144")),
+            Err(BeatSaverApiError::RateLimitError(_))
+        ));
+        assert_eq!(
+            block_on(client.map_by_key("2144")).unwrap().name,
+            "Map 2144"
+        );
+    }
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_download_route_fallback() {
+        use crate::model::map::MapDetail;
+        use crate::testing::fixtures;
+        use crate::{cdn_download_url, BeatSaverApiAsync, DownloadRoute, BEATSAVER_URL};
+        use futures::executor::block_on;
+
+        let hash = fixtures::map_hash("1");
+        let api = BEATSAVER_URL.join(format!("download/hash/{}", hash).as_str());
+        let client = MockClient::new()
+            .with_response(api.clone().unwrap(), "api")
+            .with_download_route(DownloadRoute::Cdn);
+        let map: MapDetail = serde_json::from_str(&fixtures::map_json("1")).unwrap();
+        assert_eq!(
+            block_on(client.download_version(&map.versions[0])).unwrap(),
+            "api"
+        );
+        assert_eq!(client.requests(), [cdn_download_url(&hash), api.unwrap()]);
+    }
+}
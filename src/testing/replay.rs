@@ -0,0 +1,567 @@
+//! Record/replay of HTTP interactions
+//!
+//! A [Recorder][crate::testing::Recorder] wraps a real client and writes every response it
+//! receives to a [Cassette][crate::testing::Cassette] on disk. A
+//! [ReplayClient][crate::testing::ReplayClient] then serves the recorded responses back, so
+//! integration tests of paging, search and download flows run deterministically without the
+//! network.
+use crate::auth::Credentials;
+use crate::cache::ResponseCache;
+use crate::index::KeyIndex;
+use crate::retry::RetryPolicy;
+use crate::{BeatSaverApiError, DownloadRoute, RateLimitPolicy, RateLimitTracker};
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue};
+use http::{Method, Request, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+
+/// Body of a recorded response, stored as text when possible to keep cassettes readable
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Body {
+    Text(String),
+    Hex(String),
+}
+impl From<&Bytes> for Body {
+    fn from(body: &Bytes) -> Self {
+        match std::str::from_utf8(body) {
+            Ok(text) => Self::Text(text.to_string()),
+            Err(_) => Self::Hex(hex::encode(body)),
+        }
+    }
+}
+impl Body {
+    fn to_bytes(&self) -> io::Result<Bytes> {
+        match self {
+            Self::Text(text) => Ok(Bytes::from(text.clone())),
+            Self::Hex(data) => hex::decode(data)
+                .map(Bytes::from)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    }
+}
+
+/// Request and the response it received
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Interaction {
+    method: String,
+    url: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Body,
+}
+impl Interaction {
+    fn response(&self) -> io::Result<Response<Bytes>> {
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+        let mut response = Response::new(self.body.to_bytes()?);
+        *response.status_mut() =
+            StatusCode::from_u16(self.status).map_err(|e| invalid(e.to_string()))?;
+        for (name, value) in &self.headers {
+            response.headers_mut().append(
+                HeaderName::from_bytes(name.as_bytes()).map_err(|e| invalid(e.to_string()))?,
+                HeaderValue::from_str(value).map_err(|e| invalid(e.to_string()))?,
+            );
+        }
+        Ok(response)
+    }
+}
+
+/// Recorded HTTP interactions, in the order they happened
+///
+/// Only the method and URL of requests are recorded, so credentials never end up on disk.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    interactions: Vec<Interaction>,
+}
+impl Cassette {
+    /// Creates a new, empty [Cassette][crate::testing::Cassette]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Loads a cassette previously written with [save][crate::testing::Cassette::save]
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+    /// Writes the cassette to the specified path as JSON
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+    /// Records a request and the response it received
+    pub fn record(&mut self, request: &Request<Bytes>, response: &Response<Bytes>) {
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(k, v)| Some((k.to_string(), v.to_str().ok()?.to_string())))
+            .collect();
+        self.interactions.push(Interaction {
+            method: request.method().to_string(),
+            url: request.uri().to_string(),
+            status: response.status().as_u16(),
+            headers,
+            body: response.body().into(),
+        });
+    }
+    /// Number of recorded interactions
+    pub fn len(&self) -> usize {
+        self.interactions.len()
+    }
+    /// Returns `true` if no interaction was recorded
+    pub fn is_empty(&self) -> bool {
+        self.interactions.is_empty()
+    }
+}
+
+/// Client wrapper recording every response received by the wrapped client to a
+/// [Cassette][crate::testing::Cassette]
+///
+/// The cassette is written to disk after every response, failing the request with an
+/// [IoError][crate::BeatSaverApiError::IoError] if it can't be written. Every other behavior
+/// (rate limits, retries, caching) is delegated to the wrapped client.
+///
+/// Example:
+/// ```no_run
+/// # #[cfg(all(feature = "test-util", feature = "reqwest_backend"))]
+/// # mod main {
+/// use beatsaver_rs::client::BeatSaverReqwest;
+/// use beatsaver_rs::testing::{Recorder, ReplayClient};
+/// use beatsaver_rs::BeatSaverApiAsync;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let recorder = Recorder::new(BeatSaverReqwest::new(), "tests/fixtures/2144.json");
+/// let recorded = recorder.map_by_key("2144").await?;
+///
+/// let client = ReplayClient::load("tests/fixtures/2144.json")?;
+/// assert_eq!(client.map_by_key("2144").await?, recorded);
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Recorder<C> {
+    client: C,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+impl<C> Recorder<C> {
+    /// Creates a new [Recorder][crate::testing::Recorder] recording to the specified path
+    ///
+    /// Note: an existing cassette at that path is overwritten
+    pub fn new<P: Into<PathBuf>>(client: C, path: P) -> Self {
+        Self {
+            client,
+            path: path.into(),
+            cassette: Mutex::new(Cassette::new()),
+        }
+    }
+    /// Returns the wrapped client
+    pub fn inner(&self) -> &C {
+        &self.client
+    }
+    /// Path the cassette is written to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    /// Returns the interactions recorded so far
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap().clone()
+    }
+    /// Records an interaction and writes the cassette to disk
+    fn record(&self, request: &Request<Bytes>, response: &Response<Bytes>) -> io::Result<()> {
+        let mut cassette = self.cassette.lock().unwrap();
+        cassette.record(request, response);
+        cassette.save(&self.path)
+    }
+}
+
+/// Error returned by [ReplayClient][crate::testing::ReplayClient]
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    /// No response was recorded for the request
+    #[error("No recorded response for {0} {1}")]
+    Missing(Method, String),
+}
+impl From<ReplayError> for BeatSaverApiError<ReplayError> {
+    fn from(e: ReplayError) -> Self {
+        Self::RequestError(e)
+    }
+}
+
+/// Client serving the responses recorded in a [Cassette][crate::testing::Cassette]
+///
+/// Requests are matched by method and URL. Responses recorded for the same request are served
+/// in the order they were recorded, the last one being repeated once they run out. Requests
+/// without a recorded response fail with [Missing][crate::testing::ReplayError::Missing].
+/// Rate limit waits and retry delays return immediately.
+pub struct ReplayClient {
+    responses: HashMap<(String, String), Vec<Response<Bytes>>>,
+    served: Mutex<HashMap<(String, String), usize>>,
+    index: KeyIndex,
+    credentials: Option<Credentials>,
+    rate_limit_policy: RateLimitPolicy,
+    rate_limit_tracker: RateLimitTracker,
+    retry_policy: RetryPolicy,
+    download_route: DownloadRoute,
+    cache: Option<Arc<dyn ResponseCache>>,
+}
+impl ReplayClient {
+    /// Creates a new [ReplayClient][crate::testing::ReplayClient] serving the provided cassette
+    ///
+    /// Fails with [InvalidData][std::io::ErrorKind::InvalidData] if a recorded response is
+    /// malformed
+    pub fn new(cassette: Cassette) -> io::Result<Self> {
+        let mut responses: HashMap<_, Vec<_>> = HashMap::new();
+        for interaction in &cassette.interactions {
+            let request = (interaction.method.clone(), interaction.url.clone());
+            responses
+                .entry(request)
+                .or_default()
+                .push(interaction.response()?);
+        }
+        Ok(Self {
+            responses,
+            served: Mutex::new(HashMap::new()),
+            index: KeyIndex::new(),
+            credentials: None,
+            rate_limit_policy: RateLimitPolicy::default(),
+            rate_limit_tracker: RateLimitTracker::new(),
+            retry_policy: RetryPolicy::default(),
+            download_route: DownloadRoute::default(),
+            cache: None,
+        })
+    }
+    /// Creates a new [ReplayClient][crate::testing::ReplayClient] serving the cassette at the
+    /// specified path
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::new(Cassette::load(path)?)
+    }
+    /// Attaches [Credentials][crate::auth::Credentials], for code that requires authentication
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+    /// Sets how requests that hit a rate limit are handled
+    pub fn with_rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limit_policy = policy;
+        self
+    }
+    /// Sets how requests that fail due to transient errors are retried
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+    /// Sets the route map archives are downloaded through
+    pub fn with_download_route(mut self, route: DownloadRoute) -> Self {
+        self.download_route = route;
+        self
+    }
+    /// Attaches a [ResponseCache][crate::cache::ResponseCache]
+    pub fn with_response_cache(mut self, cache: Arc<dyn ResponseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+    /// Serves the next response recorded for the request
+    fn replay(&self, request: &Request<Bytes>) -> Result<Response<Bytes>, ReplayError> {
+        let key = (request.method().to_string(), request.uri().to_string());
+        let responses = match self.responses.get(&key) {
+            Some(responses) => responses,
+            None => return Err(ReplayError::Missing(request.method().clone(), key.1)),
+        };
+        let mut served = self.served.lock().unwrap();
+        let count = served.entry(key).or_insert(0);
+        let response = &responses[(*count).min(responses.len() - 1)];
+        *count += 1;
+
+        let mut replayed = Response::new(response.body().clone());
+        *replayed.status_mut() = response.status();
+        *replayed.headers_mut() = response.headers().clone();
+        Ok(replayed)
+    }
+}
+impl std::fmt::Debug for ReplayClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ReplayClient")
+            .field("requests", &self.responses.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_client {
+    use super::{Recorder, ReplayClient, ReplayError};
+    use crate::auth::Credentials;
+    use crate::cache::ResponseCache;
+    use crate::index::KeyIndex;
+    use crate::retry::RetryPolicy;
+    use crate::{
+        BeatSaverApiAsync, BeatSaverApiError, DownloadRoute, RateLimitPolicy, RateLimitTracker,
+    };
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::{Request, Response};
+    use std::error::Error;
+    use std::time::Duration;
+    use url::Url;
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl<C, T> BeatSaverApiAsync<T> for Recorder<C>
+    where
+        C: BeatSaverApiAsync<T> + Send + Sync,
+        T: Error + Send + 'static,
+        BeatSaverApiError<T>: From<T>,
+    {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            self.inner().key_index()
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            self.inner().credentials()
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.inner().rate_limit_policy()
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            self.inner().rate_limit_tracker()
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.inner().retry_policy()
+        }
+        fn is_transient(&self, error: &BeatSaverApiError<T>) -> bool {
+            self.inner().is_transient(error)
+        }
+        fn download_route(&self) -> DownloadRoute {
+            self.inner().download_route()
+        }
+        fn base_url(&self) -> &Url {
+            self.inner().base_url()
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.inner().response_cache()
+        }
+        async fn sleep(&self, duration: Duration) {
+            self.inner().sleep(duration).await
+        }
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<T>> {
+            let mut recorded = Request::new(Bytes::new());
+            *recorded.method_mut() = request.method().clone();
+            *recorded.uri_mut() = request.uri().clone();
+            let response = self.inner().send(request).await?;
+            self.record(&recorded, &response)?;
+            Ok(response)
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl BeatSaverApiAsync<ReplayError> for ReplayClient {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            Some(&self.index)
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            self.credentials.as_ref()
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            Some(&self.rate_limit_tracker)
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.retry_policy
+        }
+        fn download_route(&self) -> DownloadRoute {
+            self.download_route
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.cache.as_deref()
+        }
+        async fn sleep(&self, _duration: Duration) {}
+        async fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<ReplayError>> {
+            Ok(self.replay(&request)?)
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+mod sync_client {
+    use super::{Recorder, ReplayClient, ReplayError};
+    use crate::auth::Credentials;
+    use crate::cache::ResponseCache;
+    use crate::index::KeyIndex;
+    use crate::retry::RetryPolicy;
+    use crate::{
+        BeatSaverApiError, BeatSaverApiSync, DownloadRoute, RateLimitPolicy, RateLimitTracker,
+    };
+    use bytes::Bytes;
+    use http::{Request, Response};
+    use std::error::Error;
+    use std::time::Duration;
+    use url::Url;
+
+    impl<C, T> BeatSaverApiSync<T> for Recorder<C>
+    where
+        C: BeatSaverApiSync<T>,
+        T: Error + 'static,
+        BeatSaverApiError<T>: From<T>,
+    {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            self.inner().key_index()
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            self.inner().credentials()
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.inner().rate_limit_policy()
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            self.inner().rate_limit_tracker()
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.inner().retry_policy()
+        }
+        fn is_transient(&self, error: &BeatSaverApiError<T>) -> bool {
+            self.inner().is_transient(error)
+        }
+        fn download_route(&self) -> DownloadRoute {
+            self.inner().download_route()
+        }
+        fn base_url(&self) -> &Url {
+            self.inner().base_url()
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.inner().response_cache()
+        }
+        fn sleep(&self, duration: Duration) {
+            self.inner().sleep(duration)
+        }
+        fn send(&self, request: Request<Bytes>) -> Result<Response<Bytes>, BeatSaverApiError<T>> {
+            let mut recorded = Request::new(Bytes::new());
+            *recorded.method_mut() = request.method().clone();
+            *recorded.uri_mut() = request.uri().clone();
+            let response = self.inner().send(request)?;
+            self.record(&recorded, &response)?;
+            Ok(response)
+        }
+    }
+
+    impl BeatSaverApiSync<ReplayError> for ReplayClient {
+        fn key_index(&self) -> Option<&KeyIndex> {
+            Some(&self.index)
+        }
+        fn credentials(&self) -> Option<&Credentials> {
+            self.credentials.as_ref()
+        }
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+        fn rate_limit_tracker(&self) -> Option<&RateLimitTracker> {
+            Some(&self.rate_limit_tracker)
+        }
+        fn retry_policy(&self) -> RetryPolicy {
+            self.retry_policy
+        }
+        fn download_route(&self) -> DownloadRoute {
+            self.download_route
+        }
+        fn response_cache(&self) -> Option<&dyn ResponseCache> {
+            self.cache.as_deref()
+        }
+        fn sleep(&self, _duration: Duration) {}
+        fn send(
+            &self,
+            request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, BeatSaverApiError<ReplayError>> {
+            Ok(self.replay(&request)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{Cassette, ReplayClient, ReplayError};
+
+    #[test]
+    fn test_cassette_binary_body() {
+        use bytes::Bytes;
+        use http::{Request, Response};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let request = Request::get("https://cdn.beatsaver.com/1.zip")
+            .body(Bytes::new())
+            .unwrap();
+        let body = Bytes::from_static(&[0x50, 0x4b, 0xff, 0x00]);
+        let mut cassette = Cassette::new();
+        cassette.record(&request, &Response::new(body.clone()));
+        cassette.save(&path).unwrap();
+        assert_eq!(Cassette::load(&path).unwrap(), cassette);
+
+        let client = ReplayClient::new(cassette).unwrap();
+        assert_eq!(client.replay(&request).unwrap().into_body(), body);
+        let request = Request::get("https://cdn.beatsaver.com/2.zip")
+            .body(Bytes::new())
+            .unwrap();
+        assert!(matches!(
+            client.replay(&request),
+            Err(ReplayError::Missing(_, _))
+        ));
+    }
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_record_replay() {
+        use crate::testing::fixtures::page_json;
+        use crate::testing::{MockClient, Recorder};
+        use crate::{BeatSaverApiError, BeatSaverApiSync, BEATSAVER_URL};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let url = |page: usize| {
+            BEATSAVER_URL
+                .join(format!("maps/uploader/58338/{}", page).as_str())
+                .unwrap()
+        };
+        let client = MockClient::new()
+            .with_maps(&["2144"])
+            .with_response(url(0), page_json(&["1", "2"]))
+            .with_response(url(1), page_json(&["3"]))
+            .with_response(url(2), page_json(&[]));
+        let recorder = Recorder::new(client, &path);
+        let map = recorder.map_by_key("2144").unwrap();
+        let maps: Vec<_> = recorder
+            .maps_by_page_iter(58338, 0)
+            .map(|m| m.unwrap().id)
+            .collect();
+        assert!(recorder.map_by_key("570").is_err());
+        assert_eq!(recorder.cassette().len(), 5);
+
+        let client = ReplayClient::load(&path).unwrap();
+        assert_eq!(client.map_by_key("2144").unwrap(), map);
+        let replayed: Vec<_> = client
+            .maps_by_page_iter(58338, 0)
+            .map(|m| m.unwrap().id)
+            .collect();
+        assert_eq!(replayed, maps);
+        assert!(matches!(
+            client.map_by_key("570"),
+            Err(BeatSaverApiError::NotFound(_))
+        ));
+        assert!(matches!(
+            client.map_by_key("1"),
+            Err(BeatSaverApiError::RequestError(ReplayError::Missing(_, _)))
+        ));
+    }
+}
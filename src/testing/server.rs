@@ -0,0 +1,360 @@
+//! In-process fake BeatSaver server
+//!
+//! [TestServer][crate::testing::TestServer] implements the subset of the API used by this crate
+//! over HTTP on a local port, so the real clients can be tested end to end without sending a
+//! single request to the production API.
+use crate::testing::fixtures;
+use bytes::Bytes;
+use http::{header, Response, StatusCode};
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use url::Url;
+
+/// Number of maps in a page of search results
+const PAGE_SIZE: usize = 20;
+
+/// Map served by the [TestServer][crate::testing::TestServer]
+struct Map {
+    hash: String,
+    json: String,
+    archive: Option<Bytes>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Maps by key, sorted so search results are deterministic
+    maps: BTreeMap<String, Map>,
+    /// Number of upcoming requests answered with a 429
+    rate_limits: usize,
+    /// Number of upcoming requests answered with a 503
+    server_errors: usize,
+    requests: Vec<Url>,
+}
+impl State {
+    fn by_hash(&self, hash: &str) -> Option<(&String, &Map)> {
+        let hash = hash.to_lowercase();
+        self.maps.iter().find(|(_, m)| m.hash == hash)
+    }
+    /// Answers a request to the provided URL
+    fn respond(&mut self, url: &Url) -> Response<Bytes> {
+        self.requests.push(url.clone());
+        if self.rate_limits > 0 {
+            self.rate_limits -= 1;
+            return fixtures::rate_limit_response(Duration::default());
+        }
+        if self.server_errors > 0 {
+            self.server_errors -= 1;
+            return fixtures::status_response(StatusCode::SERVICE_UNAVAILABLE);
+        }
+        let segments: Vec<&str> = url.path_segments().map(|s| s.collect()).unwrap_or_default();
+        let found = match segments.as_slice() {
+            ["maps", "id", key] => self.maps.get(*key).map(|m| json(m.json.clone())),
+            ["maps", "ids", keys] => {
+                let maps: Vec<String> = keys
+                    .split(',')
+                    .filter_map(|k| Some(format!(r#""{}":{}"#, k, self.maps.get(k)?.json)))
+                    .collect();
+                Some(json(format!("{{{}}}", maps.join(","))))
+            }
+            ["maps", "hash", hash] if !hash.contains(',') => {
+                self.by_hash(hash).map(|(_, m)| json(m.json.clone()))
+            }
+            ["maps", "hash", hashes] => {
+                let maps: Vec<String> = hashes
+                    .split(',')
+                    .map(|h| match self.by_hash(h) {
+                        Some((_, m)) => format!(r#""{}":{}"#, h, m.json),
+                        None => format!(r#""{}":null"#, h),
+                    })
+                    .collect();
+                Some(json(format!("{{{}}}", maps.join(","))))
+            }
+            ["search", "text", page] => page.parse().ok().map(|page| self.search(url, page)),
+            ["download", "key", key] => self
+                .maps
+                .get(*key)
+                .and_then(|m| m.archive.clone())
+                .map(Response::new),
+            ["download", "hash", hash] => self
+                .by_hash(hash)
+                .and_then(|(_, m)| m.archive.clone())
+                .map(Response::new),
+            _ => None,
+        };
+        found.unwrap_or_else(|| fixtures::status_response(StatusCode::NOT_FOUND))
+    }
+    /// Returns the maps whose name contains the `q` parameter of the URL, ignoring case
+    fn search(&self, url: &Url, page: usize) -> Response<Bytes> {
+        let query = url
+            .query_pairs()
+            .find(|(k, _)| k == "q")
+            .map(|(_, q)| q.to_lowercase())
+            .unwrap_or_default();
+        let docs: Vec<&str> = self
+            .maps
+            .values()
+            .filter(|m| {
+                let name = serde_json::from_str::<serde_json::Value>(&m.json)
+                    .ok()
+                    .and_then(|v| Some(v.get("name")?.as_str()?.to_lowercase()))
+                    .unwrap_or_default();
+                name.contains(query.as_str())
+            })
+            .skip(page * PAGE_SIZE)
+            .take(PAGE_SIZE)
+            .map(|m| m.json.as_str())
+            .collect();
+        json(format!(r#"{{"docs":[{}]}}"#, docs.join(",")))
+    }
+}
+
+fn json(body: String) -> Response<Bytes> {
+    let mut response = Response::new(Bytes::from(body));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+/// Fake BeatSaver server listening on a local port
+///
+/// Serves maps by key, hash and in bulk, text search, downloads, and answers with 429 and 503
+/// responses on demand. Unknown maps and endpoints are answered with a 404. The server stops when
+/// dropped.
+///
+/// Example:
+/// ```
+/// # #[cfg(all(feature = "test-server", feature = "ureq_backend"))]
+/// # mod main {
+/// use beatsaver_rs::client::BeatSaverUreq;
+/// use beatsaver_rs::testing::TestServer;
+/// use beatsaver_rs::BeatSaverApiSync;
+///
+/// # fn main() {
+/// let server = TestServer::start().unwrap().with_maps(&["2144"]);
+/// let client = BeatSaverUreq::new().with_base_url(server.url());
+/// assert_eq!(client.map_by_key("2144").unwrap().name, "Map 2144");
+/// # }
+/// # }
+/// ```
+pub struct TestServer {
+    url: Url,
+    state: Arc<Mutex<State>>,
+    stopped: Arc<AtomicBool>,
+}
+impl TestServer {
+    /// Starts a new [TestServer][crate::testing::TestServer] without any map on a free local port
+    pub fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = Url::parse(format!("http://{}/", listener.local_addr()?).as_str()).unwrap();
+        let state = Arc::new(Mutex::new(State::default()));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let server = Self {
+            url: url.clone(),
+            state: state.clone(),
+            stopped: stopped.clone(),
+        };
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let (url, state) = (url.clone(), state.clone());
+                    thread::spawn(move || serve(stream, &url, &state));
+                }
+            }
+        });
+        Ok(server)
+    }
+    /// Serves the maps with the provided keys, built from [fixtures][crate::testing::fixtures]
+    pub fn with_maps(self, keys: &[&str]) -> Self {
+        {
+            let mut state = self.state.lock().unwrap();
+            for key in keys {
+                let map = Map {
+                    hash: fixtures::map_hash(key),
+                    json: fixtures::map_json(key),
+                    archive: None,
+                };
+                state.maps.insert(key.to_string(), map);
+            }
+        }
+        self
+    }
+    /// Serves the provided archive when the map with the provided key is downloaded
+    ///
+    /// Note: the hash of fixture maps is derived from their key, not from the archive
+    pub fn with_download<B: Into<Bytes>>(self, key: &str, archive: B) -> Self {
+        if let Some(map) = self.state.lock().unwrap().maps.get_mut(key) {
+            map.archive = Some(archive.into());
+        }
+        self
+    }
+    /// Answers the upcoming `count` requests with a 429, the rate limit expiring right away
+    pub fn with_rate_limits(self, count: usize) -> Self {
+        self.state.lock().unwrap().rate_limits = count;
+        self
+    }
+    /// Answers the upcoming `count` requests with a 503
+    pub fn with_server_errors(self, count: usize) -> Self {
+        self.state.lock().unwrap().server_errors = count;
+        self
+    }
+    /// Base URL of the server, to be passed to `with_base_url` on clients
+    pub fn url(&self) -> Url {
+        self.url.clone()
+    }
+    /// URLs requested so far, in order
+    pub fn requests(&self) -> Vec<Url> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wakes the listener up so it sees the server was stopped
+        let _ = TcpStream::connect(self.url.socket_addrs(|| None).unwrap()[0]);
+    }
+}
+impl std::fmt::Debug for TestServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TestServer")
+            .field("url", &self.url)
+            .finish()
+    }
+}
+
+/// Answers the requests sent on a connection until it is closed
+fn serve(stream: TcpStream, base: &Url, state: &Mutex<State>) {
+    let mut reader = BufReader::new(match stream.try_clone() {
+        Ok(stream) => stream,
+        Err(_) => return,
+    });
+    let mut writer = stream;
+    loop {
+        let mut line = String::new();
+        match reader.read_line(&mut line) {
+            Ok(0) | Err(_) => return,
+            Ok(_) => {}
+        }
+        let target = line.split_whitespace().nth(1).unwrap_or("/").to_string();
+        // Skips the headers and body, only the request line is used
+        let mut length = 0;
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => return,
+                Ok(_) if line.trim().is_empty() => break,
+                Ok(_) => {}
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+        if io::copy(&mut (&mut reader).take(length), &mut io::sink()).is_err() {
+            return;
+        }
+
+        let response = match base.join(target.as_str()) {
+            Ok(url) => state.lock().unwrap().respond(&url),
+            Err(_) => fixtures::status_response(StatusCode::BAD_REQUEST),
+        };
+        if write_response(&mut writer, response).is_err() {
+            return;
+        }
+    }
+}
+
+fn write_response(stream: &mut TcpStream, response: Response<Bytes>) -> io::Result<()> {
+    let status = response.status();
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or_default()
+    );
+    for (name, value) in response.headers() {
+        head.push_str(format!("{}: {}\r\n", name, value.to_str().unwrap_or_default()).as_str());
+    }
+    head.push_str(format!("content-length: {}\r\n\r\n", response.body().len()).as_str());
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(response.body())?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_server() {
+        use crate::client::BeatSaverUreq;
+        use crate::testing::fixtures::map_hash;
+        use crate::testing::TestServer;
+        use crate::{BeatSaverApiError, BeatSaverApiSync, MapId};
+
+        let server = TestServer::start()
+            .unwrap()
+            .with_maps(&["1", "2", "2144"])
+            .with_download("2144", &b"archive"[..]);
+        let client = BeatSaverUreq::new().with_base_url(server.url());
+
+        assert_eq!(client.map_by_key("2144").unwrap().id, "2144");
+        assert_eq!(client.map_by_hash(&map_hash("1")).unwrap().id, "1");
+        assert!(matches!(
+            client.map_by_key("570"),
+            Err(BeatSaverApiError::NotFound(_))
+        ));
+        let maps = client.maps_by_ids(["1", "2"].iter().map(|k| k.parse::<MapId>().unwrap()));
+        assert_eq!(maps.unwrap().len(), 2);
+        let maps = client.maps_by_hashes(&[map_hash("1"), map_hash("570")]);
+        assert_eq!(maps.unwrap().len(), 1);
+
+        let results: Vec<_> = client.search("map 214").map(|m| m.unwrap().id).collect();
+        assert_eq!(results, ["2144"]);
+        assert_eq!(client.search("").count(), 3);
+
+        let archive = client.download("2144".parse().unwrap()).unwrap();
+        assert_eq!(archive.as_ref(), b"archive");
+        assert!(client.download("1".parse().unwrap()).is_err());
+        assert_eq!(
+            server.requests()[0],
+            server.url().join("maps/id/2144").unwrap()
+        );
+    }
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_server_errors() {
+        use crate::client::BeatSaverUreq;
+        use crate::retry::RetryPolicy;
+        use crate::testing::TestServer;
+        use crate::{BeatSaverApiError, BeatSaverApiSync, RateLimitPolicy};
+
+        let server = TestServer::start().unwrap().with_maps(&["2144"]);
+        let client = BeatSaverUreq::new().with_base_url(server.url());
+        let server = server.with_rate_limits(1);
+        assert!(matches!(
+            client.map_by_key("2144"),
+            Err(BeatSaverApiError::RateLimitError(_))
+        ));
+
+        let server = server.with_rate_limits(1).with_server_errors(1);
+        let client = client
+            .with_rate_limit_policy(RateLimitPolicy::WaitAndRetry { max_waits: 1 })
+            .with_retry_policy(
+                RetryPolicy::new()
+                    .with_max_attempts(2)
+                    .with_base_delay(Default::default()),
+            );
+        assert_eq!(client.map_by_key("2144").unwrap().id, "2144");
+        assert_eq!(server.requests().len(), 4);
+    }
+}
@@ -0,0 +1,760 @@
+//! # Websocket
+//!
+//! This module implements the BeatSaver websocket API, which pushes map events as they happen
+//! instead of requiring clients to poll `maps_latest`.
+//!
+//! The event types and [decode_event][crate::ws::decode_event] are always available. Connecting
+//! requires either the `ws` feature (uses [Tokio](https://crates.io/crates/tokio), adds
+//! `BeatSaverReqwest::map_events`, and `BeatSaverBlocking::map_events_reconnecting` along with the
+//! `blocking` feature) or the `ws_async_std` feature (uses
+//! [async-std](https://crates.io/crates/async-std), adds `BeatSaverSurf::map_events`).
+//!
+//! [connect_tokio][crate::ws::connect_tokio] and [connect_async_std][crate::ws::connect_async_std]
+//! are always exported for their features; `connect` is an alias for whichever one is enabled,
+//! and is not available if both are. The same applies to the `reconnect` functions, which return
+//! a stream that transparently reconnects with exponential backoff (see
+//! [ReconnectOptions][crate::ws::ReconnectOptions]).
+//!
+//! If the client has a [response cache][crate::cache] attached, the events received through
+//! `map_events` evict the updated and deleted maps from it, so long running applications don't
+//! serve stale maps.
+//!
+//! Example:
+//! ```no_run
+//! # #[cfg(feature = "ws")]
+//! # mod main {
+//! use beatsaver_rs::client::BeatSaverReqwest;
+//! use beatsaver_rs::ws::MapEvent;
+//! use futures::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BeatSaverReqwest::new();
+//!     let mut events = client.map_events().await.unwrap();
+//!     while let Some(event) = events.next().await {
+//!         match event {
+//!             Ok(MapEvent::Update(map)) => println!("Updated: {}", map.name),
+//!             Ok(MapEvent::Delete(key)) => println!("Deleted: {}", key),
+//!             Ok(_) => {}
+//!             Err(e) => println!("Error: {}", e),
+//!         }
+//!     }
+//! }
+//! # }
+//! ```
+use crate::model::map::MapDetail;
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use serde_json::Value;
+use url::Url;
+
+lazy_static! {
+    /// URL of the beatsaver map event websocket
+    pub static ref BEATSAVER_WS_URL: Url = Url::parse("wss://ws.beatsaver.com/maps").unwrap();
+}
+
+/// Event received from the map websocket
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapEvent {
+    /// A map was published (`MAP_CREATE`)
+    Create(MapDetail),
+    /// A map was updated (`MAP_UPDATE`)
+    Update(MapDetail),
+    /// A map was deleted (`MAP_DELETE`), contains the key of the deleted map
+    Delete(String),
+    /// Event type not known by this version of the library
+    ///
+    /// Contains the event type and its raw payload
+    Unknown(String, Value),
+}
+
+#[derive(Deserialize)]
+struct RawEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    msg: Value,
+}
+
+/// Decodes the contents of a websocket text frame into a [MapEvent][crate::ws::MapEvent]
+pub fn decode_event(data: &str) -> Result<MapEvent, serde_json::Error> {
+    let raw: RawEvent = serde_json::from_str(data)?;
+    Ok(match raw.kind.as_str() {
+        "MAP_CREATE" => MapEvent::Create(serde_json::from_value(raw.msg)?),
+        "MAP_UPDATE" => MapEvent::Update(serde_json::from_value(raw.msg)?),
+        "MAP_DELETE" => MapEvent::Delete(serde_json::from_value(raw.msg)?),
+        _ => MapEvent::Unknown(raw.kind, raw.msg),
+    })
+}
+
+#[cfg(any(feature = "ws", feature = "ws_async_std"))]
+mod socket {
+    use super::{decode_event, MapEvent};
+    use crate::cache::{invalidate, ResponseCache};
+    use crate::BeatSaverApiError;
+    use futures::{future, Stream, StreamExt};
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use tungstenite::Message;
+    use url::Url;
+
+    /// Stream of events received from the map websocket
+    pub type MapEventStream =
+        Pin<Box<dyn Stream<Item = Result<MapEvent, BeatSaverApiError<tungstenite::Error>>> + Send>>;
+
+    impl From<tungstenite::Error> for BeatSaverApiError<tungstenite::Error> {
+        fn from(e: tungstenite::Error) -> Self {
+            Self::RequestError(e)
+        }
+    }
+
+    /// Decodes the messages of a connected websocket into map events
+    pub(crate) fn map_events<S>(socket: S) -> MapEventStream
+    where
+        S: Stream<Item = Result<Message, tungstenite::Error>> + Send + 'static,
+    {
+        Box::pin(socket.filter_map(|message| {
+            future::ready(match message {
+                Ok(Message::Text(text)) => Some(decode_event(text.as_str()).map_err(|e| e.into())),
+                // Pings are answered by tungstenite, close frames end the stream
+                Ok(_) => None,
+                Err(e) => Some(Err(e.into())),
+            })
+        }))
+    }
+
+    /// Evicts the maps of the events from the cache as they are received, see
+    /// [invalidate][crate::cache::invalidate]
+    ///
+    /// `base_url` is the base URL of the client the cache is attached to.
+    pub fn invalidate_cache(
+        events: MapEventStream,
+        cache: Arc<dyn ResponseCache>,
+        base_url: Url,
+    ) -> MapEventStream {
+        Box::pin(events.inspect(move |event| {
+            if let Ok(event) = event {
+                invalidate(cache.as_ref(), &base_url, event);
+            }
+        }))
+    }
+}
+#[cfg(any(feature = "ws", feature = "ws_async_std"))]
+pub use socket::{invalidate_cache, MapEventStream};
+
+#[cfg(any(feature = "ws", feature = "ws_async_std"))]
+mod reconnect {
+    use super::socket::MapEventStream;
+    use crate::BeatSaverApiError;
+    use futures::future::BoxFuture;
+    use futures::{stream, StreamExt};
+    use std::collections::hash_map::RandomState;
+    use std::fmt;
+    use std::hash::{BuildHasher, Hasher};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    type ConnectHook = Arc<dyn Fn() + Send + Sync>;
+    type DisconnectHook = Arc<dyn Fn(Option<&BeatSaverApiError<tungstenite::Error>>) + Send + Sync>;
+    pub(crate) type ConnectFn = Box<
+        dyn Fn()
+                -> BoxFuture<'static, Result<MapEventStream, BeatSaverApiError<tungstenite::Error>>>
+            + Send,
+    >;
+    pub(crate) type SleepFn = Box<dyn Fn(Duration) -> BoxFuture<'static, ()> + Send>;
+
+    /// Reconnection behaviour of the websocket client
+    ///
+    /// Delays grow exponentially from `initial_delay` up to `max_delay`, and are randomly
+    /// shortened by up to half when jitter is enabled so that many clients don't reconnect in lockstep.
+    ///
+    /// Example:
+    /// ```
+    /// use beatsaver_rs::ws::ReconnectOptions;
+    /// use std::time::Duration;
+    ///
+    /// let options = ReconnectOptions::new()
+    ///     .with_max_delay(Duration::from_secs(30))
+    ///     .on_connect(|| println!("Connected"))
+    ///     .on_disconnect(|e| println!("Disconnected: {:?}", e.map(|e| e.to_string())));
+    /// ```
+    #[derive(Clone)]
+    pub struct ReconnectOptions {
+        initial_delay: Duration,
+        max_delay: Duration,
+        max_attempts: Option<u32>,
+        jitter: bool,
+        on_connect: Option<ConnectHook>,
+        on_disconnect: Option<DisconnectHook>,
+    }
+    impl ReconnectOptions {
+        /// Creates the default options: 1 second initial delay, 60 second maximum delay, jitter
+        /// enabled and unlimited attempts
+        pub fn new() -> Self {
+            Self::default()
+        }
+        /// Sets the delay before the first reconnection attempt
+        pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+            self.initial_delay = delay;
+            self
+        }
+        /// Sets the upper bound of the reconnection delay
+        pub fn with_max_delay(mut self, delay: Duration) -> Self {
+            self.max_delay = delay;
+            self
+        }
+        /// Gives up after the specified number of consecutive reconnection attempts
+        ///
+        /// Attempts are counted until an event is received. When the limit is hit, the last
+        /// connection error is yielded and the stream ends.
+        pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+            self.max_attempts = Some(attempts);
+            self
+        }
+        /// Enables or disables random jitter of the reconnection delay
+        pub fn with_jitter(mut self, jitter: bool) -> Self {
+            self.jitter = jitter;
+            self
+        }
+        /// Sets a callback that is called every time a connection is established
+        pub fn on_connect<F: Fn() + Send + Sync + 'static>(mut self, hook: F) -> Self {
+            self.on_connect = Some(Arc::new(hook));
+            self
+        }
+        /// Sets a callback that is called every time an established connection is lost
+        ///
+        /// The callback receives the error that caused the disconnect, or `None` if the server
+        /// closed the connection.
+        pub fn on_disconnect<F>(mut self, hook: F) -> Self
+        where
+            F: Fn(Option<&BeatSaverApiError<tungstenite::Error>>) + Send + Sync + 'static,
+        {
+            self.on_disconnect = Some(Arc::new(hook));
+            self
+        }
+        /// Delay before the specified reconnection attempt (starting at 1)
+        pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+            let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+            let delay = self
+                .initial_delay
+                .checked_mul(factor)
+                .map_or(self.max_delay, |d| d.min(self.max_delay));
+            if self.jitter {
+                delay.mul_f64(0.5 + random() / 2.0)
+            } else {
+                delay
+            }
+        }
+    }
+    impl Default for ReconnectOptions {
+        fn default() -> Self {
+            Self {
+                initial_delay: Duration::from_secs(1),
+                max_delay: Duration::from_secs(60),
+                max_attempts: None,
+                jitter: true,
+                on_connect: None,
+                on_disconnect: None,
+            }
+        }
+    }
+    impl fmt::Debug for ReconnectOptions {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.debug_struct("ReconnectOptions")
+                .field("initial_delay", &self.initial_delay)
+                .field("max_delay", &self.max_delay)
+                .field("max_attempts", &self.max_attempts)
+                .field("jitter", &self.jitter)
+                .finish()
+        }
+    }
+
+    /// Random number in `[0, 1)`, good enough for jitter without pulling in `rand`
+    fn random() -> f64 {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u8(0);
+        (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    struct Reconnecting {
+        connect: ConnectFn,
+        sleep: SleepFn,
+        options: ReconnectOptions,
+        socket: Option<MapEventStream>,
+        attempt: u32,
+        done: bool,
+    }
+
+    /// Creates an event stream that reconnects whenever the underlying connection is lost
+    ///
+    /// Connection errors are passed to the disconnect hook instead of being yielded; errors
+    /// decoding individual messages are still yielded.
+    pub(crate) fn reconnecting(
+        connect: ConnectFn,
+        sleep: SleepFn,
+        options: ReconnectOptions,
+    ) -> MapEventStream {
+        let state = Reconnecting {
+            connect,
+            sleep,
+            options,
+            socket: None,
+            attempt: 0,
+            done: false,
+        };
+        Box::pin(stream::unfold(state, |mut state| async move {
+            loop {
+                if state.done {
+                    return None;
+                }
+                if let Some(socket) = state.socket.as_mut() {
+                    let error = match socket.next().await {
+                        Some(Err(BeatSaverApiError::RequestError(e))) => {
+                            Some(BeatSaverApiError::RequestError(e))
+                        }
+                        Some(event) => {
+                            state.attempt = 0;
+                            return Some((event, state));
+                        }
+                        None => None,
+                    };
+                    state.socket = None;
+                    state.attempt += 1;
+                    if let Some(hook) = &state.options.on_disconnect {
+                        hook(error.as_ref());
+                    }
+                    continue;
+                }
+
+                if state.attempt > 0 {
+                    (state.sleep)(state.options.backoff(state.attempt)).await;
+                }
+                match (state.connect)().await {
+                    Ok(socket) => {
+                        state.socket = Some(socket);
+                        if let Some(hook) = &state.options.on_connect {
+                            hook();
+                        }
+                    }
+                    Err(e) => {
+                        state.attempt += 1;
+                        if let Some(max) = state.options.max_attempts {
+                            if state.attempt >= max {
+                                state.done = true;
+                                return Some((Err(e), state));
+                            }
+                        }
+                    }
+                }
+            }
+        }))
+    }
+}
+#[cfg(any(feature = "ws", feature = "ws_async_std"))]
+pub use reconnect::ReconnectOptions;
+
+#[cfg(feature = "ws")]
+mod tokio_client {
+    use super::reconnect::{reconnecting, ReconnectOptions};
+    use super::socket::{invalidate_cache, map_events, MapEventStream};
+    use super::BEATSAVER_WS_URL;
+    use crate::client::BeatSaverReqwest;
+    #[cfg(feature = "blocking")]
+    use crate::client::{BeatSaverBlocking, BlockingStream};
+    #[cfg(feature = "blocking")]
+    use crate::BeatSaverApiSync;
+    use crate::{BeatSaverApiAsync, BeatSaverApiError};
+    use tokio_tungstenite::connect_async;
+    use url::Url;
+
+    /// Connects to the map websocket at the specified [Url][url::Url] using Tokio
+    ///
+    /// The stream ends when the server closes the connection.
+    pub async fn connect(
+        url: &Url,
+    ) -> Result<MapEventStream, BeatSaverApiError<tungstenite::Error>> {
+        let (socket, _) = connect_async(url.as_str()).await?;
+        Ok(map_events(socket))
+    }
+
+    /// Connects to the map websocket at the specified [Url][url::Url] using Tokio, reconnecting
+    /// whenever the connection is lost
+    ///
+    /// The connection is only established once the stream is first polled.
+    pub fn reconnect(url: Url, options: ReconnectOptions) -> MapEventStream {
+        let connect = Box::new(move || {
+            let url = url.clone();
+            Box::pin(async move { connect(&url).await }) as _
+        });
+        let sleep = Box::new(|delay| Box::pin(tokio::time::sleep(delay)) as _);
+        reconnecting(connect, sleep, options)
+    }
+
+    impl BeatSaverReqwest {
+        /// Connects to the beatsaver map websocket
+        ///
+        /// Events evict the maps from the client's response cache, if any.
+        pub async fn map_events(
+            &self,
+        ) -> Result<MapEventStream, BeatSaverApiError<tungstenite::Error>> {
+            Ok(self.invalidating(connect(&BEATSAVER_WS_URL).await?))
+        }
+        /// Connects to the beatsaver map websocket, reconnecting whenever the connection is lost
+        ///
+        /// Events evict the maps from the client's response cache, if any.
+        pub fn map_events_reconnecting(&self, options: ReconnectOptions) -> MapEventStream {
+            self.invalidating(reconnect(BEATSAVER_WS_URL.clone(), options))
+        }
+        fn invalidating(&self, events: MapEventStream) -> MapEventStream {
+            match self.shared_response_cache() {
+                Some(cache) => invalidate_cache(events, cache.clone(), self.base_url().clone()),
+                None => events,
+            }
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    impl BeatSaverBlocking {
+        /// Connects to the beatsaver map websocket, returning an iterator over the events that
+        /// reconnects whenever the connection is lost
+        ///
+        /// Events evict the maps from the client's response cache, if any. The connection is only
+        /// established once the iterator is first advanced.
+        pub fn map_events_reconnecting(
+            &self,
+            options: ReconnectOptions,
+        ) -> BlockingStream<MapEventStream> {
+            let events = reconnect(BEATSAVER_WS_URL.clone(), options);
+            BlockingStream::new(self.backend().runtime().clone(), self.invalidating(events))
+        }
+        fn invalidating(&self, events: MapEventStream) -> MapEventStream {
+            match self.shared_response_cache() {
+                Some(cache) => invalidate_cache(events, cache.clone(), self.base_url().clone()),
+                None => events,
+            }
+        }
+    }
+}
+#[cfg(feature = "ws")]
+pub use tokio_client::{connect as connect_tokio, reconnect as reconnect_tokio};
+#[cfg(all(feature = "ws", not(feature = "ws_async_std")))]
+pub use tokio_client::{connect, reconnect};
+
+#[cfg(feature = "ws_async_std")]
+mod async_std_client {
+    use super::reconnect::{reconnecting, ReconnectOptions};
+    use super::socket::{invalidate_cache, map_events, MapEventStream};
+    use super::BEATSAVER_WS_URL;
+    use crate::client::BeatSaverSurf;
+    use crate::{BeatSaverApiAsync, BeatSaverApiError};
+    use async_tungstenite::async_std::connect_async;
+    use url::Url;
+
+    /// Connects to the map websocket at the specified [Url][url::Url] using async-std
+    ///
+    /// The stream ends when the server closes the connection.
+    pub async fn connect(
+        url: &Url,
+    ) -> Result<MapEventStream, BeatSaverApiError<tungstenite::Error>> {
+        let (socket, _) = connect_async(url.as_str()).await?;
+        Ok(map_events(socket))
+    }
+
+    /// Connects to the map websocket at the specified [Url][url::Url] using async-std, reconnecting
+    /// whenever the connection is lost
+    ///
+    /// The connection is only established once the stream is first polled.
+    pub fn reconnect(url: Url, options: ReconnectOptions) -> MapEventStream {
+        let connect = Box::new(move || {
+            let url = url.clone();
+            Box::pin(async move { connect(&url).await }) as _
+        });
+        let sleep = Box::new(|delay| Box::pin(async_std::task::sleep(delay)) as _);
+        reconnecting(connect, sleep, options)
+    }
+
+    impl BeatSaverSurf {
+        /// Connects to the beatsaver map websocket
+        ///
+        /// Events evict the maps from the client's response cache, if any.
+        pub async fn map_events(
+            &self,
+        ) -> Result<MapEventStream, BeatSaverApiError<tungstenite::Error>> {
+            Ok(self.invalidating(connect(&BEATSAVER_WS_URL).await?))
+        }
+        /// Connects to the beatsaver map websocket, reconnecting whenever the connection is lost
+        ///
+        /// Events evict the maps from the client's response cache, if any.
+        pub fn map_events_reconnecting(&self, options: ReconnectOptions) -> MapEventStream {
+            self.invalidating(reconnect(BEATSAVER_WS_URL.clone(), options))
+        }
+        fn invalidating(&self, events: MapEventStream) -> MapEventStream {
+            match self.shared_response_cache() {
+                Some(cache) => invalidate_cache(events, cache.clone(), self.base_url().clone()),
+                None => events,
+            }
+        }
+    }
+}
+#[cfg(feature = "ws_async_std")]
+pub use async_std_client::{connect as connect_async_std, reconnect as reconnect_async_std};
+#[cfg(all(feature = "ws_async_std", not(feature = "ws")))]
+pub use async_std_client::{connect, reconnect};
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::map_json;
+    use crate::ws::{decode_event, MapEvent};
+    use serde_json::json;
+
+    #[test]
+    fn test_decode_update() {
+        let data = format!(r#"{{"type":"MAP_UPDATE","msg":{}}}"#, map_json("2144"));
+        match decode_event(data.as_str()).unwrap() {
+            MapEvent::Update(m) => assert_eq!(m.id, "2144"),
+            e => panic!("unexpected event: {:?}", e),
+        }
+        let data = format!(r#"{{"type":"MAP_CREATE","msg":{}}}"#, map_json("2144"));
+        match decode_event(data.as_str()).unwrap() {
+            MapEvent::Create(m) => assert_eq!(m.id, "2144"),
+            e => panic!("unexpected event: {:?}", e),
+        }
+    }
+    #[test]
+    fn test_decode_delete() {
+        assert_eq!(
+            decode_event(r#"{"type":"MAP_DELETE","msg":"2144"}"#).unwrap(),
+            MapEvent::Delete("2144".into())
+        );
+    }
+    #[test]
+    fn test_decode_unknown() {
+        assert_eq!(
+            decode_event(r#"{"type":"MAP_SOMETHING","msg":{"a":1}}"#).unwrap(),
+            MapEvent::Unknown("MAP_SOMETHING".into(), json!({"a": 1}))
+        );
+        assert_eq!(
+            decode_event(r#"{"type":"PING"}"#).unwrap(),
+            MapEvent::Unknown("PING".into(), json!(null))
+        );
+    }
+    #[cfg(any(feature = "ws", feature = "ws_async_std"))]
+    #[test]
+    fn test_invalidate_cache() {
+        use crate::cache::{CachedResponse, MemoryCache, ResponseCache};
+        use crate::ws::invalidate_cache;
+        use crate::BEATSAVER_URL;
+        use futures::executor::block_on;
+        use futures::{stream, StreamExt};
+        use http::Response;
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let url = BEATSAVER_URL.join("maps/id/2144").unwrap();
+        let cache = Arc::new(MemoryCache::new().with_ttl(Duration::from_secs(60)));
+        let response = Response::new(map_json("2144").into());
+        cache.insert(&url, CachedResponse::from_response(&response));
+        let events = Box::pin(stream::iter(vec![Ok(MapEvent::Delete("2144".into()))]));
+        let events = invalidate_cache(events, cache.clone(), BEATSAVER_URL.clone());
+        assert_eq!(block_on(events.count()), 1);
+        assert!(cache.is_empty());
+    }
+    #[test]
+    fn test_decode_invalid() {
+        assert!(decode_event("").is_err());
+        assert!(decode_event(r#"{"msg":"2144"}"#).is_err());
+        assert!(decode_event(r#"{"type":"MAP_UPDATE","msg":"2144"}"#).is_err());
+        assert!(decode_event(r#"{"type":"MAP_DELETE","msg":{}}"#).is_err());
+    }
+    #[cfg(feature = "ws")]
+    #[tokio::test]
+    async fn test_connect_tokio() {
+        use crate::ws::connect_tokio;
+        use futures::{SinkExt, StreamExt};
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::accept_async;
+        use tokio_tungstenite::tungstenite::Message;
+        use url::Url;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}/maps", listener.local_addr().unwrap())).unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = accept_async(stream).await.unwrap();
+            let update = format!(r#"{{"type":"MAP_UPDATE","msg":{}}}"#, map_json("2144"));
+            socket.send(Message::Text(update)).await.unwrap();
+            socket
+                .send(Message::Text(
+                    r#"{"type":"MAP_DELETE","msg":"2144"}"#.into(),
+                ))
+                .await
+                .unwrap();
+            socket.close(None).await.unwrap();
+        });
+
+        let events: Vec<MapEvent> = connect_tokio(&url)
+            .await
+            .unwrap()
+            .map(|e| e.unwrap())
+            .collect()
+            .await;
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], MapEvent::Update(m) if m.id == "2144"));
+        assert_eq!(events[1], MapEvent::Delete("2144".into()));
+    }
+    #[cfg(feature = "ws_async_std")]
+    #[async_std::test]
+    async fn test_connect_async_std() {
+        use crate::ws::connect_async_std;
+        use async_std::net::TcpListener;
+        use async_tungstenite::accept_async;
+        use async_tungstenite::tungstenite::Message;
+        use futures::{SinkExt, StreamExt};
+        use url::Url;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}/maps", listener.local_addr().unwrap())).unwrap();
+        async_std::task::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = accept_async(stream).await.unwrap();
+            socket
+                .send(Message::Text(
+                    r#"{"type":"MAP_DELETE","msg":"2144"}"#.into(),
+                ))
+                .await
+                .unwrap();
+            let create = format!(r#"{{"type":"MAP_CREATE","msg":{}}}"#, map_json("2144"));
+            socket.send(Message::Text(create)).await.unwrap();
+            socket.close(None).await.unwrap();
+        });
+
+        let events: Vec<MapEvent> = connect_async_std(&url)
+            .await
+            .unwrap()
+            .map(|e| e.unwrap())
+            .collect()
+            .await;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0], MapEvent::Delete("2144".into()));
+        assert!(matches!(&events[1], MapEvent::Create(m) if m.id == "2144"));
+    }
+    #[cfg(any(feature = "ws", feature = "ws_async_std"))]
+    #[test]
+    fn test_reconnect_backoff() {
+        use crate::ws::ReconnectOptions;
+        use std::time::Duration;
+
+        let options = ReconnectOptions::new()
+            .with_initial_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1))
+            .with_jitter(false);
+        assert_eq!(options.backoff(1), Duration::from_millis(100));
+        assert_eq!(options.backoff(2), Duration::from_millis(200));
+        assert_eq!(options.backoff(4), Duration::from_millis(800));
+        assert_eq!(options.backoff(5), Duration::from_secs(1));
+        assert_eq!(options.backoff(u32::MAX), Duration::from_secs(1));
+
+        let jittered = options.clone().with_jitter(true);
+        for attempt in 1..10 {
+            let delay = jittered.backoff(attempt);
+            assert!(delay <= options.backoff(attempt));
+            assert!(delay >= options.backoff(attempt) / 2);
+        }
+    }
+    #[cfg(any(feature = "ws", feature = "ws_async_std"))]
+    #[test]
+    fn test_reconnect_stream() {
+        use crate::ws::reconnect::reconnecting;
+        use crate::ws::{MapEventStream, ReconnectOptions};
+        use crate::BeatSaverApiError;
+        use futures::executor::block_on;
+        use futures::{future, stream, StreamExt};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::{Arc, Mutex};
+        use tungstenite::Error;
+
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = attempts.clone();
+        // Connection 1 fails, 2 drops with an error, 3 is closed by the server, 4+ fail
+        let connect = Box::new(move || {
+            let socket: Result<MapEventStream, _> = match counter.fetch_add(1, Ordering::SeqCst) {
+                1 => Ok(Box::pin(stream::iter(vec![
+                    Ok(MapEvent::Delete("1".into())),
+                    Err(BeatSaverApiError::RequestError(Error::ConnectionClosed)),
+                ])) as _),
+                2 => Ok(Box::pin(stream::iter(vec![Ok(MapEvent::Delete("2".into()))])) as _),
+                _ => Err(BeatSaverApiError::RequestError(Error::AlreadyClosed)),
+            };
+            Box::pin(future::ready(socket)) as _
+        });
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let recorded = delays.clone();
+        let sleep = Box::new(move |delay| {
+            recorded.lock().unwrap().push(delay);
+            Box::pin(future::ready(())) as _
+        });
+        let connects = Arc::new(AtomicUsize::new(0));
+        let disconnects = Arc::new(Mutex::new(Vec::new()));
+        let (c, d) = (connects.clone(), disconnects.clone());
+        let options = ReconnectOptions::new()
+            .with_jitter(false)
+            .with_max_attempts(3)
+            .on_connect(move || {
+                c.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_disconnect(move |e| d.lock().unwrap().push(e.is_some()));
+
+        let events: Vec<_> = block_on(reconnecting(connect, sleep, options).collect());
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].as_ref().unwrap(), &MapEvent::Delete("1".into()));
+        assert_eq!(events[1].as_ref().unwrap(), &MapEvent::Delete("2".into()));
+        assert!(matches!(
+            events[2],
+            Err(BeatSaverApiError::RequestError(Error::AlreadyClosed))
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 5);
+        assert_eq!(connects.load(Ordering::SeqCst), 2);
+        assert_eq!(*disconnects.lock().unwrap(), vec![true, false]);
+        assert_eq!(
+            *delays.lock().unwrap(),
+            vec![1, 1, 1, 2]
+                .into_iter()
+                .map(std::time::Duration::from_secs)
+                .collect::<Vec<_>>()
+        );
+    }
+    #[cfg(feature = "ws")]
+    #[tokio::test]
+    async fn test_reconnect_tokio() {
+        use crate::ws::{reconnect_tokio, ReconnectOptions};
+        use futures::{SinkExt, StreamExt};
+        use std::time::Duration;
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::accept_async;
+        use tokio_tungstenite::tungstenite::Message;
+        use url::Url;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}/maps", listener.local_addr().unwrap())).unwrap();
+        tokio::spawn(async move {
+            for key in &["1", "2"] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut socket = accept_async(stream).await.unwrap();
+                let delete = format!(r#"{{"type":"MAP_DELETE","msg":"{}"}}"#, key);
+                socket.send(Message::Text(delete)).await.unwrap();
+                socket.close(None).await.unwrap();
+            }
+        });
+
+        let options = ReconnectOptions::new().with_initial_delay(Duration::from_millis(10));
+        let events: Vec<MapEvent> = reconnect_tokio(url, options)
+            .take(2)
+            .map(|e| e.unwrap())
+            .collect()
+            .await;
+        assert_eq!(
+            events,
+            vec![MapEvent::Delete("1".into()), MapEvent::Delete("2".into())]
+        );
+    }
+}